@@ -84,14 +84,14 @@ fn main() {
             // Disease "invert" test
             if person.environment.game_time.minute.get() == 20 || person.environment.game_time.minute.get() == 42 {
                 if !is_disease_inverted {
-                    person.health.diseases.borrow().get("Flu").unwrap().invert(&person.environment.game_time.to_contract()).ok();
+                    person.health.disease(&format!("Flu")).unwrap().invert(&person.environment.game_time.to_contract()).ok();
                     is_disease_inverted = true;
                 }
             }
             // Disease "invert back" test
             if person.environment.game_time.minute.get() == 33 {
                 if is_disease_inverted {
-                    person.health.diseases.borrow().get("Flu").unwrap().invert_back(&person.environment.game_time.to_contract()).ok();
+                    person.health.disease(&format!("Flu")).unwrap().invert_back(&person.environment.game_time.to_contract()).ok();
                     is_disease_inverted = false;
                 }
             }
@@ -156,7 +156,7 @@ fn spawn_injuries(person: &zara::ZaraController<ZaraEventsListener>) {
     person.take_appliance(&format!("Bandage"), BodyPart::LeftShoulder).ok();
 
     if let Ok(key) = key_result {
-        person.health.injuries.borrow().get(&key).unwrap().stop_blood_loss();
+        person.health.injury(&key.injury, key.body_part).unwrap().stop_blood_loss();
     }
     //person.remove_appliance(&format!("Bandage"), BodyParts::LeftShoulder);
 }
\ No newline at end of file
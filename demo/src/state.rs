@@ -66,14 +66,14 @@ impl StateObject {
         self.injuries.clear();
 
         // Remember states of all diseases
-        for (key, disease) in controller.health.diseases.borrow().iter() {
-            self.diseases.insert(key.to_string(), disease.get_state());
-        }
+        controller.health.for_each_disease(|disease| {
+            self.diseases.insert(disease.disease.get_id(), disease.get_state());
+        });
 
         // Remember states of all injuries
-        for (key, injury) in controller.health.injuries.borrow().iter() {
-            self.injuries.insert(key.clone(), injury.get_state());
-        }
+        controller.health.for_each_injury(|injury| {
+            self.injuries.insert(InjuryKey { injury: injury.injury.get_id(), body_part: injury.body_part }, injury.get_state());
+        });
 
         // Remember states of all side effect monitors
         let monitors = controller.health.side_effects.borrow();
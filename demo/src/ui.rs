@@ -157,7 +157,8 @@ pub fn ui_frame<W>(w: &mut W, person: &zara::ZaraController<ZaraEventsListener>)
         style::SetForegroundColor(style::Color::Blue),
         cursor::MoveToNextLine(1),
     ).ok();
-    for (name, item) in person.inventory.items.borrow().iter() {
+    person.inventory.for_each_item(|item| {
+        let name = item.get_name();
         execute!(w,
             cursor::MoveToColumn(inv_col_base+4),
             style::Print(format!("{} ({})", name, item.get_count())),
@@ -243,7 +244,7 @@ pub fn ui_frame<W>(w: &mut W, person: &zara::ZaraController<ZaraEventsListener>)
             },
             _ => { }
         }
-    }
+    });
 
     // Diseases
     let dis_col_base = 86;
@@ -254,7 +255,8 @@ pub fn ui_frame<W>(w: &mut W, person: &zara::ZaraController<ZaraEventsListener>)
         style::SetForegroundColor(style::Color::Magenta),
         cursor::MoveToNextLine(1),
     ).ok();
-    for (name, item) in person.health.diseases.borrow().iter() {
+    person.health.for_each_disease(|item| {
+        let name = item.disease.get_name();
         let active = item.is_active(&gt);
         let is_healing = item.is_healing();
         let active_string = if is_healing { "active [healing]" } else { "active" };
@@ -304,7 +306,7 @@ pub fn ui_frame<W>(w: &mut W, person: &zara::ZaraController<ZaraEventsListener>)
             style::Print(format!("Needs treatment? {}", if item.needs_treatment { "yes" } else { "no (will self-heal)" })),
             cursor::MoveToNextLine(1),
         ).ok();
-    }
+    });
 
     // Injuries
     let inj_col_base = 86;
@@ -316,11 +318,12 @@ pub fn ui_frame<W>(w: &mut W, person: &zara::ZaraController<ZaraEventsListener>)
         style::SetForegroundColor(style::Color::Magenta),
         cursor::MoveToNextLine(1),
     ).ok();
-    for (key, item) in person.health.injuries.borrow().iter() {
+    person.health.for_each_injury(|item| {
+        let (injury_name, body_part) = (item.injury.get_name(), item.body_part);
         let active = item.is_active(&gt);
         let is_healing = item.is_healing();
         let active_string = if is_healing { "active [healing]" } else { "active" };
-        let info = format!("{} on {:?} ({})", key.injury, key.body_part, if active { active_string } else { "scheduled" });
+        let info = format!("{} on {:?} ({})", injury_name, body_part, if active { active_string } else { "scheduled" });
         execute!(w,
             cursor::MoveToColumn(inj_col_base+3),
             style::Print(format!("{}", info)),
@@ -376,7 +379,7 @@ pub fn ui_frame<W>(w: &mut W, person: &zara::ZaraController<ZaraEventsListener>)
             style::Print(format!("Blood forcibly stopped? {}", if item.is_blood_stopped() { "yes" } else { "no" })),
             cursor::MoveToNextLine(1),
         ).ok();
-    }
+    });
 
     // Medical Agents
     let medagent_col_base = 133;
@@ -428,24 +431,21 @@ pub fn ui_frame<W>(w: &mut W, person: &zara::ZaraController<ZaraEventsListener>)
             style::Print(format!("{}", item_name)),
             cursor::MoveToNextLine(1),
         ).ok();
-        match person.inventory.items.borrow().get(item_name) {
-            Some(item) => {
-                match item.clothes() {
-                    Some(c) => {
-                        execute!(w,
-                            cursor::MoveToColumn(cl_col_base + 6),
-                            style::Print(format!("Cold protection: {}%", c.cold_resistance())),
-                            cursor::MoveToNextLine(1),
-                            cursor::MoveToColumn(cl_col_base + 6),
-                            style::Print(format!("Water protection: {}%", c.water_resistance())),
-                            cursor::MoveToNextLine(1),
-                        ).ok();
-                    },
-                    _ => { }
-                }
-            },
-            _ => { }
-        }
+        person.inventory.with_item(item_name, |item| {
+            match item.clothes() {
+                Some(c) => {
+                    execute!(w,
+                        cursor::MoveToColumn(cl_col_base + 6),
+                        style::Print(format!("Cold protection: {}%", c.cold_resistance())),
+                        cursor::MoveToNextLine(1),
+                        cursor::MoveToColumn(cl_col_base + 6),
+                        style::Print(format!("Water protection: {}%", c.water_resistance())),
+                        cursor::MoveToNextLine(1),
+                    ).ok();
+                },
+                _ => { }
+            }
+        });
     }
     match person.body.clothes_group() {
         Some(group) => {
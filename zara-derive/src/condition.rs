@@ -0,0 +1,183 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::{Expr, ExprLit, ExprUnary, ItemFn, Lit, LitStr, Path, Stmt, Token, UnOp};
+
+/// Stage levels in the order of seriousness
+const STAGE_LEVELS: [&str; 4] = ["InitialStage", "Progressing", "Worrying", "Critical"];
+
+/// What kind of health condition is being declared
+pub(crate) enum Kind {
+    Disease,
+    Injury
+}
+
+/// Checks a numeric literal to be in range. Non-literal expressions are not checked
+pub(crate) fn check_literal_range(value: &Expr, min: f64, max: f64, option: &str) -> syn::Result<()> {
+    let number = match value {
+        Expr::Lit(ExprLit { lit: Lit::Float(f), .. }) => f.base10_parse::<f64>()?,
+        Expr::Lit(ExprLit { lit: Lit::Int(i), .. }) => i.base10_parse::<f64>()?,
+        Expr::Unary(ExprUnary { op: UnOp::Neg(_), expr, .. }) => match expr.as_ref() {
+            Expr::Lit(_) => -1.,
+            _ => return Ok(())
+        },
+        _ => return Ok(())
+    };
+
+    if number < min || number > max {
+        let message = if max == f64::MAX {
+            format!("`{}` must not be negative", option)
+        } else {
+            format!("`{}` must be in {}..{} range", option, min, max)
+        };
+
+        return Err(syn::Error::new_spanned(value, message));
+    }
+
+    Ok(())
+}
+
+/// `get_id` override when an explicit id is given; `get_name` is used as id otherwise
+pub(crate) fn id_method(id: &Option<LitStr>) -> TokenStream {
+    match id {
+        Some(i) => quote! { fn get_id(&self) -> String { String::from(#i) } },
+        None => quote! { }
+    }
+}
+
+/// Finds the `build_for(StageLevel::...)` call in a stage builder chain
+fn stage_level(expr: &Expr) -> Option<&Expr> {
+    match expr {
+        Expr::MethodCall(m) if m.method == "build_for" => m.args.first(),
+        Expr::MethodCall(m) => stage_level(&m.receiver),
+        _ => None
+    }
+}
+
+/// Validates a literal `vec![...]` of stages returned by the function. Stages that are built
+/// in some other way cannot be checked at compile time
+fn check_stages(function: &ItemFn) -> syn::Result<()> {
+    let tail = match function.block.stmts.last() {
+        Some(Stmt::Expr(Expr::Macro(m), None)) => &m.mac,
+        Some(Stmt::Macro(m)) => &m.mac,
+        _ => return Ok(())
+    };
+
+    if !tail.path.is_ident("vec") { return Ok(()); }
+
+    let stages = tail.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)?;
+
+    if stages.is_empty() {
+        return Err(syn::Error::new_spanned(tail, "at least one stage is needed"));
+    }
+
+    let mut seen: Vec<String> = Vec::new();
+
+    for stage in stages.iter() {
+        let level = match stage_level(stage) {
+            Some(l) => l,
+            None => return Err(syn::Error::new_spanned(stage,
+                "stage must be built with `StageBuilder::start().build_for(StageLevel::...)`"))
+        };
+        let name = match level {
+            Expr::Path(p) => p.path.segments.last().map(|s| s.ident.to_string()),
+            _ => None
+        };
+        let name = match name {
+            Some(n) => n,
+            None => continue
+        };
+
+        if !STAGE_LEVELS.contains(&name.as_str()) {
+            return Err(syn::Error::new_spanned(level, format!(
+                "stage level must be one of: {}", STAGE_LEVELS.join(", "))));
+        }
+        if seen.contains(&name) {
+            return Err(syn::Error::new_spanned(level, format!("stage `{}` is declared twice", name)));
+        }
+
+        seen.push(name);
+    }
+
+    Ok(())
+}
+
+pub(crate) fn expand(args: TokenStream, function: ItemFn, kind: Kind) -> syn::Result<TokenStream> {
+    let mut target: Option<Path> = None;
+    let mut id: Option<LitStr> = None;
+    let mut name: Option<LitStr> = None;
+    let mut treatment: Option<Path> = None;
+    let mut is_fracture = false;
+    let mut is_burn = false;
+
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("id") {
+            id = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("name") {
+            name = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("treatment") {
+            treatment = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("fracture") && matches!(kind, Kind::Injury) {
+            is_fracture = true;
+        } else if meta.path.is_ident("burn") && matches!(kind, Kind::Injury) {
+            is_burn = true;
+        } else if target.is_none() && (meta.input.is_empty() || meta.input.peek(Token![,])) {
+            target = Some(meta.path);
+        } else {
+            return Err(meta.error("unknown option"));
+        }
+
+        Ok(())
+    });
+
+    parser.parse2(args)?;
+
+    let target = match target {
+        Some(t) => t,
+        None => return Err(syn::Error::new_spanned(&function.sig.ident,
+            "type to implement is required as the first argument, like `#[disease(Flu)]`"))
+    };
+
+    check_stages(&function)?;
+
+    let name = name.unwrap_or_else(|| {
+        let ident = &target.segments.last().unwrap().ident;
+
+        LitStr::new(&ident.to_string(), ident.span())
+    });
+    let id = id_method(&id);
+    let stages = &function.sig.ident;
+    let treatment = match &treatment {
+        Some(p) => quote! { Some(Box::new(#p)) },
+        None => quote! { None }
+    };
+
+    let implementation = match kind {
+        Kind::Disease => quote! {
+            impl ::zara::health::disease::Disease for #target {
+                #id
+                fn get_name(&self) -> String { String::from(#name) }
+                fn get_stages(&self) -> Vec<::zara::health::disease::StageDescription> { #stages() }
+                fn get_treatment(&self) -> Option<Box<dyn ::zara::health::disease::DiseaseTreatment>> { #treatment }
+                fn as_any(&self) -> &dyn ::std::any::Any { self }
+            }
+        },
+        Kind::Injury => quote! {
+            impl ::zara::health::injury::Injury for #target {
+                #id
+                fn get_name(&self) -> String { String::from(#name) }
+                fn get_stages(&self) -> Vec<::zara::health::injury::StageDescription> { #stages() }
+                fn get_treatment(&self) -> Option<Box<dyn ::zara::health::injury::InjuryTreatment>> { #treatment }
+                fn get_is_fracture(&self) -> bool { #is_fracture }
+                fn get_is_burn(&self) -> bool { #is_burn }
+                fn as_any(&self) -> &dyn ::std::any::Any { self }
+            }
+        }
+    };
+
+    Ok(quote! {
+        #function
+        #implementation
+    })
+}
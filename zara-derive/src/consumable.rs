@@ -0,0 +1,118 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Expr, Path};
+
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let mut is_food = false;
+    let mut is_water = false;
+    let mut food_gain: Option<Expr> = None;
+    let mut water_gain: Option<Expr> = None;
+    let mut spoiling: Option<Path> = None;
+    let mut temperature: Option<Expr> = None;
+    let mut digestion: Option<Expr> = None;
+    let mut toxicity: Option<Expr> = None;
+    let mut nutrients: Vec<(&str, Expr)> = Vec::new();
+
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("consumable")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("food") {
+                is_food = true;
+            } else if meta.path.is_ident("water") {
+                is_water = true;
+            } else if meta.path.is_ident("food_gain") {
+                let value: Expr = meta.value()?.parse()?;
+
+                crate::condition::check_literal_range(&value, 0., 100., "food_gain")?;
+
+                food_gain = Some(value);
+            } else if meta.path.is_ident("water_gain") {
+                let value: Expr = meta.value()?.parse()?;
+
+                crate::condition::check_literal_range(&value, 0., 100., "water_gain")?;
+
+                water_gain = Some(value);
+            } else if meta.path.is_ident("spoiling") {
+                spoiling = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("temperature") {
+                temperature = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("digestion_minutes") {
+                let value: Expr = meta.value()?.parse()?;
+
+                crate::condition::check_literal_range(&value, 0., f64::MAX, "digestion_minutes")?;
+
+                digestion = Some(value);
+            } else if meta.path.is_ident("toxicity") {
+                let value: Expr = meta.value()?.parse()?;
+
+                crate::condition::check_literal_range(&value, 0., 100., "toxicity")?;
+
+                toxicity = Some(value);
+            } else if let Some(name) = ["protein", "fat", "carbs", "vitamin_c"].iter().find(|n| meta.path.is_ident(n)) {
+                let value: Expr = meta.value()?.parse()?;
+
+                crate::condition::check_literal_range(&value, 0., 100., name)?;
+
+                nutrients.push((*name, value));
+            } else {
+                return Err(meta.error("unknown `consumable` option"));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    if !is_food && !is_water {
+        return Err(syn::Error::new_spanned(&input.ident,
+            "consumable must be `food`, `water` or both: `#[consumable(food, ...)]`"));
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let food_gain = food_gain.map_or(quote! { 0. }, |x| quote! { #x });
+    let water_gain = water_gain.map_or(quote! { 0. }, |x| quote! { #x });
+    let spoiling = match &spoiling {
+        Some(p) => quote! { Some(&#p) },
+        None => quote! { None }
+    };
+    let temperature = match &temperature {
+        Some(t) => quote! { fn temperature(&self) -> Option<f32> { Some((#t) as f32) } },
+        None => quote! { }
+    };
+    let digestion = match &digestion {
+        Some(d) => quote! { fn digestion_minutes(&self) -> Option<f32> { Some((#d) as f32) } },
+        None => quote! { }
+    };
+    let toxicity = match &toxicity {
+        Some(t) => quote! { fn toxicity(&self) -> f32 { (#t) as f32 } },
+        None => quote! { }
+    };
+    let nutrients = if nutrients.is_empty() {
+        quote! { }
+    } else {
+        let fields = nutrients.iter().map(|(name, value)| {
+            let field = syn::Ident::new(name, proc_macro2::Span::call_site());
+
+            quote! { #field: (#value) as f32 }
+        });
+
+        quote! {
+            fn nutrients(&self) -> Option<::zara::health::nutrition::NutrientsC> {
+                Some(::zara::health::nutrition::NutrientsC { #(#fields,)* ..Default::default() })
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::zara::inventory::items::ConsumableDescription for #ident #type_generics #where_clause {
+            fn is_food(&self) -> bool { #is_food }
+            fn is_water(&self) -> bool { #is_water }
+            fn water_gain_per_dose(&self) -> f32 { (#water_gain) as f32 }
+            fn food_gain_per_dose(&self) -> f32 { (#food_gain) as f32 }
+            fn spoiling(&self) -> Option<&dyn ::zara::inventory::items::SpoilingBehavior> { #spoiling }
+            #temperature
+            #digestion
+            #toxicity
+            #nutrients
+        }
+    })
+}
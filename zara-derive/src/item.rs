@@ -0,0 +1,119 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Expr, Fields, LitStr, Path, Type};
+
+/// Description node that is returned as `Some(&Node)`, or `None` when not set
+fn node(path: &Option<Path>) -> TokenStream {
+    match path {
+        Some(p) => quote! { Some(&#p) },
+        None => quote! { None }
+    }
+}
+
+/// Checks that the struct has a `count: usize` field
+fn check_count_field(input: &DeriveInput) -> syn::Result<()> {
+    let fields = match &input.data {
+        Data::Struct(s) => match &s.fields {
+            Fields::Named(f) => &f.named,
+            _ => return Err(syn::Error::new_spanned(&input.ident, "inventory item must have named fields"))
+        },
+        _ => return Err(syn::Error::new_spanned(&input.ident, "inventory item must be a struct"))
+    };
+    let count = fields.iter().find(|f| f.ident.as_ref().is_some_and(|i| i == "count"));
+
+    match count.map(|f| &f.ty) {
+        Some(Type::Path(p)) if p.path.is_ident("usize") => Ok(()),
+        Some(t) => Err(syn::Error::new_spanned(t, "`count` field must be `usize`")),
+        None => Err(syn::Error::new_spanned(&input.ident, "inventory item must have a `count: usize` field"))
+    }
+}
+
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let mut id: Option<LitStr> = None;
+    let mut name: Option<LitStr> = None;
+    let mut weight: Option<Expr> = None;
+    let mut consumable: Option<Path> = None;
+    let mut appliance: Option<Path> = None;
+    let mut clothes: Option<Path> = None;
+    let mut requirements: Option<Path> = None;
+    let mut is_infinite = false;
+    let mut is_cloneable = false;
+
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("item")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("id") {
+                id = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("name") {
+                name = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("weight") {
+                let value: Expr = meta.value()?.parse()?;
+
+                crate::condition::check_literal_range(&value, 0., f64::MAX, "weight")?;
+
+                weight = Some(value);
+            } else if meta.path.is_ident("consumable") {
+                consumable = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("appliance") {
+                appliance = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("clothes") {
+                clothes = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("requirements") {
+                requirements = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("infinite") {
+                is_infinite = true;
+            } else if meta.path.is_ident("cloneable") {
+                is_cloneable = true;
+            } else {
+                return Err(meta.error("unknown `item` option"));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    check_count_field(&input)?;
+
+    let weight = match weight {
+        Some(w) => w,
+        None => return Err(syn::Error::new_spanned(&input.ident, "`#[item(weight = ...)]` is required"))
+    };
+    let ident = &input.ident;
+    let name = name.unwrap_or_else(|| LitStr::new(&ident.to_string(), ident.span()));
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let id = crate::condition::id_method(&id);
+    let consumable = node(&consumable);
+    let appliance = node(&appliance);
+    let clothes = node(&clothes);
+    let requirements = match &requirements {
+        Some(p) => quote! {
+            fn requirements(&self) -> Option<&dyn ::zara::inventory::items::UsageRequirements> { Some(&#p) }
+        },
+        None => quote! { }
+    };
+    let clone_boxed = if is_cloneable {
+        quote! {
+            fn clone_boxed(&self) -> Option<Box<dyn ::zara::inventory::items::InventoryItem>> {
+                Some(Box::new(::std::clone::Clone::clone(self)))
+            }
+        }
+    } else {
+        quote! { }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::zara::inventory::items::InventoryItem for #ident #type_generics #where_clause {
+            fn get_count(&self) -> usize { self.count }
+            fn set_count(&mut self, new_count: usize) { self.count = new_count; }
+            #id
+            fn get_name(&self) -> String { String::from(#name) }
+            fn get_is_infinite(&self) -> bool { #is_infinite }
+            fn get_total_weight(&self) -> f32 { self.count as f32 * (#weight) as f32 }
+            fn consumable(&self) -> Option<&dyn ::zara::inventory::items::ConsumableDescription> { #consumable }
+            fn appliance(&self) -> Option<&dyn ::zara::inventory::items::ApplianceDescription> { #appliance }
+            fn clothes(&self) -> Option<&dyn ::zara::inventory::items::ClothesDescription> { #clothes }
+            #requirements
+            #clone_boxed
+            fn as_any(&self) -> &dyn ::std::any::Any { self }
+        }
+    })
+}
@@ -0,0 +1,128 @@
+//! Derive macros for the [Zara](https://github.com/vagrod/zara-rust) survival engine.
+//!
+//! Do not depend on this crate directly. Enable the `derive` feature of the `zara` crate
+//! and use the macros from the `zara::derive` module.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, ItemFn};
+
+mod item;
+mod consumable;
+mod condition;
+
+/// Implements `zara::inventory::items::InventoryItem` for a struct with a `count: usize` field
+///
+/// # Options
+/// - `name = "..."`: item display name (or localization key). Struct name by default
+/// - `id = "..."`: stable unique item id. Same as `name` by default
+/// - `weight = ...`: weight of one item, grams. Required
+/// - `consumable = ...`, `appliance = ...`, `clothes = ...`, `requirements = ...`: unit
+///   structs that describe these item behaviors
+/// - `infinite`: item is an infinite resource
+/// - `cloneable`: item implements `Clone` and can be duplicated with `clone_boxed`
+///
+/// # Examples
+/// ```ignore
+/// use zara::derive::InventoryItem;
+///
+/// #[derive(InventoryItem)]
+/// #[item(name = "Meat", weight = 351., consumable = MeatConsumable)]
+/// struct Meat { count: usize }
+/// ```
+#[proc_macro_derive(InventoryItem, attributes(item))]
+pub fn derive_inventory_item(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    item::expand(input).unwrap_or_else(|e| e.to_compile_error()).into()
+}
+
+/// Implements `zara::inventory::items::ConsumableDescription`
+///
+/// # Options
+/// - `food`, `water`: what kind of consumable this is. At least one is required
+/// - `food_gain = ...`, `water_gain = ...`: gain per dose, 0..100. Zero by default
+/// - `spoiling = ...`: unit struct that describes spoiling behavior
+/// - `temperature = ...`: temperature of the consumable, degrees C. Not set by default
+/// - `digestion_minutes = ...`: food gain is digested over this many game minutes. Instant by default
+/// - `toxicity = ...`: toxicity one dose adds, 0..100. Zero by default
+/// - `protein = ...`, `fat = ...`, `carbs = ...`, `vitamin_c = ...`: nutrients per dose for the
+///   detailed nutrition, 0..100. Item gives no nutrients if none is set
+///
+/// # Examples
+/// ```ignore
+/// use zara::derive::Consumable;
+///
+/// #[derive(Consumable)]
+/// #[consumable(food, food_gain = 30., water_gain = 5., spoiling = MeatSpoiling)]
+/// struct MeatConsumable;
+/// ```
+#[proc_macro_derive(Consumable, attributes(consumable))]
+pub fn derive_consumable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    consumable::expand(input).unwrap_or_else(|e| e.to_compile_error()).into()
+}
+
+/// Implements `zara::health::disease::Disease` for a given type. Is put on a function that
+/// returns disease stages. Literal `vec![...]` of stages is validated at compile time: it
+/// must not be empty, and every stage must be built for its own stage level
+///
+/// # Options
+/// - first argument: type to implement the disease for. Required
+/// - `name = "..."`: disease display name (or localization key). Type name by default
+/// - `id = "..."`: stable unique disease id. Same as `name` by default
+/// - `treatment = ...`: unit struct that implements `DiseaseTreatment`
+///
+/// # Examples
+/// ```ignore
+/// #[zara::derive::disease(Flu, treatment = FluTreatment)]
+/// fn flu_stages() -> Vec<StageDescription> {
+///     vec![
+///         StageBuilder::start()
+///             .build_for(StageLevel::InitialStage)
+///             // ...
+///             .build()
+///     ]
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn disease(args: TokenStream, input: TokenStream) -> TokenStream {
+    let function = parse_macro_input!(input as ItemFn);
+
+    condition::expand(args.into(), function, condition::Kind::Disease)
+        .unwrap_or_else(|e| e.to_compile_error()).into()
+}
+
+/// Implements `zara::health::injury::Injury` for a given type. Is put on a function that
+/// returns injury stages. Literal `vec![...]` of stages is validated at compile time the same
+/// way as for [`disease`](attr.disease.html)
+///
+/// # Options
+/// - first argument: type to implement the injury for. Required
+/// - `name = "..."`: injury display name (or localization key). Type name by default
+/// - `id = "..."`: stable unique injury id. Same as `name` by default
+/// - `treatment = ...`: unit struct that implements `InjuryTreatment`
+/// - `fracture`: this injury is a fracture
+/// - `burn`: this injury is a burn
+///
+/// # Examples
+/// ```ignore
+/// #[zara::derive::injury(Cut, treatment = CutTreatment)]
+/// fn cut_stages() -> Vec<StageDescription> {
+///     vec![
+///         StageBuilder::start()
+///             .build_for(StageLevel::InitialStage)
+///             // ...
+///             .build()
+///     ]
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn injury(args: TokenStream, input: TokenStream) -> TokenStream {
+    let function = parse_macro_input!(input as ItemFn);
+
+    condition::expand(args.into(), function, condition::Kind::Injury)
+        .unwrap_or_else(|e| e.to_compile_error()).into()
+}
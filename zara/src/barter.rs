@@ -0,0 +1,240 @@
+use crate::ZaraController;
+use crate::error::BarterErr;
+use crate::inventory::{Inventory, InventoryChangeReason};
+use crate::inventory::items::InventoryItem;
+use crate::utils::event::{Event, Listener};
+
+use std::fmt;
+use std::collections::BTreeMap;
+
+/// Item kind and count that one side gives in a barter
+///
+/// # Examples
+/// ```
+/// use zara::barter::BarterItemC;
+///
+/// let item = BarterItemC::new("Meat", 2);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BarterItemC {
+    /// Unique name of the item (item kind)
+    pub item_name: String,
+    /// How many units are given
+    pub count: usize
+}
+impl BarterItemC {
+    /// Creates new `BarterItemC`
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    /// - `count`: how many units are given
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::barter::BarterItemC;
+    ///
+    /// let item = BarterItemC::new("Knife", 1);
+    /// ```
+    pub fn new(item_name: &str, count: usize) -> Self {
+        BarterItemC { item_name: item_name.to_string(), count }
+    }
+}
+impl fmt::Display for BarterItemC {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} x{}", self.item_name, self.count)
+    }
+}
+
+/// What one side of a barter gives, and how much its inventory can weigh after the exchange
+///
+/// # Examples
+/// ```
+/// use zara::barter::{BarterOfferC, BarterItemC};
+///
+/// let offer = BarterOfferC {
+///     items: vec![BarterItemC::new("Meat", 2)],
+///     max_weight: Some(30000.)
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct BarterOfferC {
+    /// Items this side gives
+    pub items: Vec<BarterItemC>,
+    /// Weight (g) this side's inventory must not exceed after the exchange. `None` means no limit
+    pub max_weight: Option<f32>
+}
+impl fmt::Display for BarterOfferC {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let items: Vec<String> = self.items.iter().map(|x| x.to_string()).collect();
+
+        write!(f, "[{}]", items.join(", "))
+    }
+}
+
+/// Side of a barter an error is about
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BarterSide {
+    /// Controller `barter` was called on
+    Own,
+    /// Controller passed to `barter`
+    Other
+}
+
+impl<E: Listener + 'static> ZaraController<E> {
+    /// Exchanges items between this character and another one. Both sides are checked first
+    /// (alive, not paused, enough units that are not worn, equipped or reserved, and weight
+    /// limits after the exchange); nothing is changed if any check fails. Items must support
+    /// `clone_boxed`. On success both controllers send
+    /// [`BarterCompleted`](crate::utils::event::Event::BarterCompleted) event
+    ///
+    /// # Parameters
+    /// - `offer`: what this character gives
+    /// - `other`: controller of the other character
+    /// - `other_offer`: what the other character gives
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::barter::{BarterOfferC, BarterItemC};
+    ///
+    /// person.barter(
+    ///     &BarterOfferC { items: vec![BarterItemC::new("Meat", 2)], max_weight: None },
+    ///     &trader,
+    ///     &BarterOfferC { items: vec![BarterItemC::new("Bandage", 1)], max_weight: None }
+    /// )?;
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collections of both inventories
+    pub fn barter<O: Listener + 'static>(&self, offer: &BarterOfferC, other: &ZaraController<O>,
+                                         other_offer: &BarterOfferC) -> Result<(), BarterErr> {
+        self.check_can_barter(BarterSide::Own)?;
+        other.check_can_barter(BarterSide::Other)?;
+
+        let own_gives = merge_offer(offer);
+        let other_gives = merge_offer(other_offer);
+        let own_weight = check_offer(&self.inventory, &own_gives, BarterSide::Own)?;
+        let other_weight = check_offer(&other.inventory, &other_gives, BarterSide::Other)?;
+
+        check_weight(&self.inventory, offer.max_weight, own_weight, other_weight, BarterSide::Own)?;
+        check_weight(&other.inventory, other_offer.max_weight, other_weight, own_weight, BarterSide::Other)?;
+
+        // Copies are made before anything is changed
+        let own_copies = copy_offer(&self.inventory, &own_gives, BarterSide::Own)?;
+        let other_copies = copy_offer(&other.inventory, &other_gives, BarterSide::Other)?;
+
+        take_offer(&self.inventory, &own_gives, BarterSide::Own)?;
+        if let Err(e) = take_offer(&other.inventory, &other_gives, BarterSide::Other) {
+            // Give back what this side already gave
+            for item in own_copies { self.inventory.receive_item(item); }
+
+            return Err(e);
+        }
+
+        for item in own_copies { other.inventory.receive_item(item); }
+        for item in other_copies { self.inventory.receive_item(item); }
+
+        let given: Vec<BarterItemC> = own_gives.iter().map(|(name, count)| BarterItemC::new(name, *count)).collect();
+        let received: Vec<BarterItemC> = other_gives.iter().map(|(name, count)| BarterItemC::new(name, *count)).collect();
+
+        other.notify_barter(received.clone(), given.clone());
+        self.notify_barter(given, received);
+
+        Ok(())
+    }
+
+    fn check_can_barter(&self, side: BarterSide) -> Result<(), BarterErr> {
+        if !self.health.is_alive() { return Err(BarterErr::CharacterIsDead(side)); }
+        if self.is_paused() { return Err(BarterErr::InstancePaused(side)); }
+
+        Ok(())
+    }
+
+    fn notify_barter(&self, given: Vec<BarterItemC>, received: Vec<BarterItemC>) {
+        let event = Event::BarterCompleted(given, received);
+
+        self.skills.on_event(&event);
+        self.day_summary_on_event(&event);
+        self.stats.on_event(&event);
+        self.dispatcher.borrow_mut().dispatch(event);
+        self.deliver_events();
+    }
+}
+
+/// Sums up counts of the same item kind, skipping zero counts
+fn merge_offer(offer: &BarterOfferC) -> BTreeMap<String, usize> {
+    let mut result = BTreeMap::new();
+
+    for item in offer.items.iter().filter(|x| x.count > 0) {
+        *result.entry(item.item_name.to_string()).or_insert(0) += item.count;
+    }
+
+    result
+}
+
+/// Checks that all offered units can be given. Returns weight of the offer
+fn check_offer(inventory: &Inventory, gives: &BTreeMap<String, usize>, side: BarterSide) -> Result<f32, BarterErr> {
+    let mut weight = 0.;
+
+    for (name, count) in gives.iter() {
+        let available = match inventory.available_count_of(name) {
+            Some(o) => o,
+            None => return Err(BarterErr::ItemNotFound(side, name.to_string()))
+        };
+
+        if available < *count { return Err(BarterErr::InsufficientResources(side, name.to_string())); }
+
+        let b = inventory.stored_items.borrow();
+        let item = match b.get(name) {
+            Some(o) => o,
+            None => return Err(BarterErr::ItemNotFound(side, name.to_string()))
+        };
+
+        if item.get_count() > 0 {
+            weight += item.get_total_weight() / item.get_count() as f32 * *count as f32;
+        }
+    }
+
+    Ok(weight)
+}
+
+/// Checks that inventory weight after the exchange does not exceed the limit
+fn check_weight(inventory: &Inventory, max_weight: Option<f32>, given: f32, received: f32,
+                side: BarterSide) -> Result<(), BarterErr> {
+    if let Some(max_weight) = max_weight {
+        if inventory.get_weight() - given + received > max_weight {
+            return Err(BarterErr::WeightLimitExceeded(side));
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates copies of the offered items with the offered counts
+fn copy_offer(inventory: &Inventory, gives: &BTreeMap<String, usize>,
+              side: BarterSide) -> Result<Vec<Box<dyn InventoryItem>>, BarterErr> {
+    gives.iter()
+        .map(|(name, count)| inventory.duplicate_item(name, *count)
+            .map_err(|_| BarterErr::ItemIsNotCloneable(side, name.to_string())))
+        .collect()
+}
+
+/// Takes offered units out of the inventory. Units already taken are given back on failure
+fn take_offer(inventory: &Inventory, gives: &BTreeMap<String, usize>, side: BarterSide) -> Result<(), BarterErr> {
+    let mut taken = Vec::new();
+
+    for (name, count) in gives.iter() {
+        // Copy is made before the item can be removed
+        let copy = inventory.duplicate_item(name, *count)
+            .map_err(|_| BarterErr::ItemIsNotCloneable(side, name.to_string()))?;
+
+        if let Err(e) = inventory.use_item_for(name, *count, InventoryChangeReason::Transferred) {
+            for item in taken { inventory.receive_item(item); }
+
+            return Err(BarterErr::CouldNotUseItem(side, e));
+        }
+
+        taken.push(copy);
+    }
+
+    Ok(())
+}
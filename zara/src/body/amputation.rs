@@ -0,0 +1,95 @@
+use crate::body::{Body, BodyPart};
+use crate::body::parts::{BodyPartStatus, BodyPartGroup};
+use crate::utils::event::{MessageQueue, Event};
+use crate::state::DirtyTracked;
+
+/// How much mobility one lost limb part takes
+const LIMB_MOBILITY_WEIGHT: f32 = 0.15;
+/// How much mobility one lost non-limb part takes
+const OTHER_MOBILITY_WEIGHT: f32 = 0.05;
+
+impl Body {
+    /// Returns status of a body part: healthy, lost or replaced with a prosthetic
+    ///
+    /// # Parameters
+    /// - `body_part`: body part to check
+    ///
+    /// # Examples
+    /// ```
+    /// let status = person.body.part_status(BodyPart::LeftFoot);
+    /// ```
+    pub fn part_status(&self, body_part: BodyPart) -> BodyPartStatus {
+        self.part_statuses.borrow().get(&body_part).cloned().unwrap_or(BodyPartStatus::Healthy)
+    }
+
+    /// Returns all permanently lost body parts, including the ones replaced with prosthetics
+    ///
+    /// # Examples
+    /// ```
+    /// let parts = person.body.lost_parts();
+    /// ```
+    pub fn lost_parts(&self) -> Vec<BodyPart> { self.part_statuses.borrow().keys().cloned().collect() }
+
+    /// Returns how well a body part functions, 0..1. Healthy part is `1.0`, lost part is `0.0`,
+    /// prosthetic restores function partially
+    ///
+    /// # Parameters
+    /// - `body_part`: body part to check
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.body.part_function(BodyPart::RightFoot);
+    /// ```
+    pub fn part_function(&self, body_part: BodyPart) -> f32 {
+        match self.part_status(body_part) {
+            BodyPartStatus::Healthy => 1.,
+            BodyPartStatus::Lost => 0.,
+            BodyPartStatus::Prosthetic(_, function) => crate::utils::clamp_01(function as f32 / 100.)
+        }
+    }
+
+    /// Returns character mobility, 0..1. Every lost body part lowers it, limbs more than others.
+    /// Prosthetics restore it partially. Stamina drains are divided by this value
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.body.mobility();
+    /// ```
+    pub fn mobility(&self) -> f32 {
+        let mut penalty = 0.;
+
+        for (part, _) in self.part_statuses.borrow().iter() {
+            let weight = match self.parts.group_of(*part) {
+                Some(BodyPartGroup::Limbs) => LIMB_MOBILITY_WEIGHT,
+                _ => OTHER_MOBILITY_WEIGHT
+            };
+
+            penalty += weight * (1. - self.part_function(*part));
+        }
+
+        crate::utils::clamp_01(1. - penalty)
+    }
+
+    pub(crate) fn on_part_lost(&self, body_part: BodyPart) {
+        self.mark_dirty();
+
+        self.part_statuses.borrow_mut().insert(body_part, BodyPartStatus::Lost);
+
+        self.queue_message(Event::BodyPartLost(body_part));
+    }
+
+    pub(crate) fn on_prosthetic_on(&self, item_name: &String, body_part: BodyPart, function: usize) {
+        self.mark_dirty();
+
+        self.part_statuses.borrow_mut().insert(body_part, BodyPartStatus::Prosthetic(item_name.to_string(), function));
+    }
+
+    /// Returns lost body part back to the `Lost` status if a given appliance was its prosthetic
+    pub(crate) fn on_prosthetic_off(&self, item_name: &String, body_part: BodyPart) {
+        let mut b = self.part_statuses.borrow_mut();
+
+        if let Some(BodyPartStatus::Prosthetic(name, _)) = b.get(&body_part) {
+            if name == item_name { b.insert(body_part, BodyPartStatus::Lost); }
+        }
+    }
+}
\ No newline at end of file
@@ -1,8 +1,11 @@
 use crate::body::{Body, BodyPart, BodyAppliance};
 use crate::utils::event::{MessageQueue, Event};
+use crate::state::DirtyTracked;
 
 impl Body {
     pub(crate) fn on_body_appliance_put_on(&self, item_name: &String, body_part: BodyPart) {
+        self.mark_dirty();
+
         // All checks are done before that. This is just in case
         if self.is_applied(item_name, body_part) { return; }
 
@@ -17,6 +20,8 @@ impl Body {
     }
 
     pub(crate) fn remove_appliance(&self, item_name: &String, body_part: BodyPart) -> bool {
+        self.mark_dirty();
+
         if !self.is_applied(item_name, body_part) { return false; }
 
         let mut b = self.appliances.borrow_mut();
@@ -25,6 +30,10 @@ impl Body {
             Some(ind) => {
                 b.remove(ind);
 
+                self.on_prosthetic_off(item_name, body_part);
+                self.on_tourniquet_off(item_name, body_part);
+                self.on_burn_dressing_off(item_name, body_part);
+
                 self.queue_message(Event::BodyApplianceOff(item_name.to_string(), body_part));
 
                 true
@@ -1,8 +1,10 @@
 use crate::body::{Body, ClothesItemC};
-use crate::error::{RequestClothesOffErr, RequestClothesOnErr};
+use crate::error::{RequestClothesOffErr, RequestClothesOnErr, UnregisterClothesGroupErr};
+use crate::inventory::Inventory;
 use crate::inventory::items::ClothesDescription;
 use crate::utils::ClothesGroupC;
 use crate::utils::event::{MessageQueue, Event};
+use crate::state::DirtyTracked;
 
 use std::collections::HashMap;
 use std::fmt;
@@ -10,6 +12,11 @@ use std::hash::{Hash, Hasher};
 
 mod warmth;
 mod wetness;
+mod outfit;
+
+pub(crate) use warmth::PERIPHERAL_TEMPERATURE_GRADIENT;
+pub use wetness::WetnessConfig;
+pub use warmth::WindChillConfig;
 
 pub mod fluent;
 
@@ -52,7 +59,116 @@ impl Body {
         }
     }
 
+    /// Unregisters a clothes group. Group bonus is removed immediately if this group was matched
+    ///
+    /// # Parameters
+    /// - `name`: unique name of the group
+    ///
+    /// # Returns
+    /// Ok on success
+    ///
+    /// # Examples
+    /// ```
+    /// person.body.unregister_clothes_group(&group_name);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Clothes-groups) for more info.
+    pub fn unregister_clothes_group(&self, name: &String) -> Result<(), UnregisterClothesGroupErr> {
+        if self.clothes_groups.borrow_mut().remove(name).is_none() {
+            return Err(UnregisterClothesGroupErr::GroupNotFound);
+        }
+
+        self.refresh_clothes_group();
+        self.recalculate_warmth_level();
+
+        Ok(())
+    }
+
+    /// Unregisters all clothes groups. Matched group bonus is removed immediately
+    ///
+    /// # Examples
+    /// ```
+    /// person.body.clear_clothes_groups();
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Clothes-groups) for more info.
+    pub fn clear_clothes_groups(&self) {
+        self.clothes_groups.borrow_mut().clear();
+
+        self.refresh_clothes_group();
+        self.recalculate_warmth_level();
+    }
+
+    /// Returns names of all registered clothes groups
+    ///
+    /// # Examples
+    /// ```
+    /// let groups = person.body.clothes_group_names();
+    /// ```
+    pub fn clothes_group_names(&self) -> Vec<String> {
+        let mut result: Vec<String> = self.clothes_groups.borrow().keys().cloned().collect();
+
+        result.sort();
+
+        result
+    }
+
+    /// Returns progress of every registered clothes group: which items are worn, which are
+    /// owned but not worn and which are missing from the inventory
+    ///
+    /// # Parameters
+    /// - `inventory`: inventory to check owned items against
+    ///
+    /// # Examples
+    /// ```
+    /// for progress in person.body.clothes_groups_progress(&person.inventory) {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Clothes-groups) for more info.
+    ///
+    /// ## Notes
+    /// Borrows `clothes` and `inventory.items` collections
+    pub fn clothes_groups_progress(&self, inventory: &Inventory) -> Vec<ClothesGroupProgress> {
+        let clothes = self.clothes.borrow();
+        let items = inventory.stored_items.borrow();
+        let mut result = Vec::new();
+
+        for (name, group) in self.clothes_groups.borrow().iter() {
+            let mut progress = ClothesGroupProgress {
+                name: name.to_string(),
+                ..Default::default()
+            };
+
+            for (item_name, _) in group.items.iter() {
+                if clothes.contains(item_name) {
+                    progress.worn.push(item_name.to_string());
+                } else if items.get(item_name).is_some_and(|i| i.get_count() > 0) {
+                    progress.owned.push(item_name.to_string());
+                } else {
+                    progress.missing.push(item_name.to_string());
+                }
+            }
+
+            progress.worn.sort();
+            progress.owned.sort();
+            progress.missing.sort();
+
+            result.push(progress);
+        }
+
+        result.sort_by(|a, b| a.name.cmp(&b.name));
+
+        result
+    }
+
     pub(crate) fn request_clothes_on(&self, item_name: &String, data: &dyn ClothesDescription) -> Result<(), RequestClothesOnErr> {
+        self.mark_dirty();
+
         {
             let mut clothes = self.clothes.borrow_mut();
             if clothes.contains(item_name) {
@@ -64,7 +180,8 @@ impl Body {
             let mut cdata = self.clothes_data.borrow_mut();
             cdata.insert(item_name.to_string(), ClothesItemC {
                 cold_resistance: data.cold_resistance(),
-                water_resistance: data.water_resistance()
+                water_resistance: data.water_resistance(),
+                coverage: data.coverage()
             });
         }
 
@@ -77,6 +194,8 @@ impl Body {
     }
 
     pub(crate) fn request_clothes_off(&self, item_name: &String) -> Result<(), RequestClothesOffErr> {
+        self.mark_dirty();
+
         {
             let mut clothes = self.clothes.borrow_mut();
             match clothes.iter().position(|x| x == item_name) {
@@ -117,6 +236,34 @@ impl Body {
     }
 }
 
+/// Describes how complete a clothes group is
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct ClothesGroupProgress {
+    /// Name of the group
+    pub name: String,
+    /// Group items character is wearing now
+    pub worn: Vec<String>,
+    /// Group items that are in the inventory, but are not worn
+    pub owned: Vec<String>,
+    /// Group items that are not in the inventory
+    pub missing: Vec<String>
+}
+impl fmt::Display for ClothesGroupProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} worn, {} owned, {} missing", self.name, self.worn.len(), self.owned.len(),
+               self.missing.len())
+    }
+}
+impl ClothesGroupProgress {
+    /// Returns `true` if all group items are worn
+    ///
+    /// # Examples
+    /// ```
+    /// let value = progress.is_complete();
+    /// ```
+    pub fn is_complete(&self) -> bool { self.owned.is_empty() && self.missing.is_empty() }
+}
+
 /// Holds the information about clothes item
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
 pub struct ClothesItem {
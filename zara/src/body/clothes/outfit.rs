@@ -0,0 +1,140 @@
+use crate::body::{Body, BodyPart};
+use crate::body::heat::HEAT_STRESS_WARMTH_THRESHOLD;
+use crate::inventory::Inventory;
+use crate::utils::EnvironmentC;
+
+/// Warmth level below which character is considered cold (lower bound of the comfort zone)
+const COMFORT_WARMTH_MIN: f32 = -5.;
+/// Only this many best owned clothes items take part in the outfit search
+const MAX_OUTFIT_CANDIDATES: usize = 16;
+
+struct OutfitCandidate {
+    name: String,
+    cold_resistance: usize,
+    water_resistance: usize,
+    coverage: Vec<BodyPart>
+}
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+struct OutfitScore {
+    /// Negative distance of the predicted warmth level from the comfort zone
+    comfort: f32,
+    /// Effective water resistance weighted by rain intensity
+    dryness: f32,
+    /// Negative count of items, so lighter outfits win ties
+    lightness: f32
+}
+
+impl Body {
+    /// Searches owned clothes for the outfit that keeps character closest to the comfort
+    /// zone under a given environment, preferring higher water resistance when it rains.
+    /// Registered clothes groups bonuses are taken into account.
+    ///
+    /// Layering rule: two items that cover the same body part cannot be worn together.
+    /// Items without coverage can be combined with anything.
+    ///
+    /// Does not change any state. Current wetness level and cold tolerance are taken into account
+    ///
+    /// # Parameters
+    /// - `environment`: environment to pick the outfit for
+    /// - `inventory`: inventory to pick clothes from
+    ///
+    /// # Returns
+    /// Unique names of the inventory items that form the best outfit
+    ///
+    /// # Examples
+    /// ```
+    /// let outfit = person.body.best_outfit_for(&storm, &person.inventory);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Clothes) for more info.
+    ///
+    /// ## Notes
+    /// Borrows `inventory.items` collection
+    pub fn best_outfit_for(&self, environment: &EnvironmentC, inventory: &Inventory) -> Vec<String> {
+        let mut candidates: Vec<OutfitCandidate> = Vec::new();
+
+        for (name, item) in inventory.stored_items.borrow().iter() {
+            if item.get_count() == 0 { continue; }
+
+            if let Some(c) = item.clothes() {
+                candidates.push(OutfitCandidate {
+                    name: name.to_string(),
+                    cold_resistance: c.cold_resistance(),
+                    water_resistance: c.water_resistance(),
+                    coverage: c.coverage()
+                });
+            }
+        }
+
+        // Strongest items first, name makes the order stable
+        candidates.sort_by(|a, b| (b.cold_resistance + b.water_resistance)
+            .cmp(&(a.cold_resistance + a.water_resistance))
+            .then(a.name.cmp(&b.name)));
+        candidates.truncate(MAX_OUTFIT_CANDIDATES);
+
+        let mut best: Option<(OutfitScore, Vec<usize>)> = None;
+        let mut current: Vec<usize> = Vec::new();
+
+        self.search_outfits(environment, &candidates, 0, &mut current, &mut best);
+
+        match best {
+            Some((_, indices)) => indices.iter().map(|i| candidates[*i].name.to_string()).collect(),
+            None => Vec::new()
+        }
+    }
+
+    fn search_outfits(&self, environment: &EnvironmentC, candidates: &[OutfitCandidate], from: usize,
+                      current: &mut Vec<usize>, best: &mut Option<(OutfitScore, Vec<usize>)>) {
+        let score = self.score_outfit(environment, candidates, current);
+        let is_better = match best {
+            Some((best_score, _)) => score > *best_score,
+            None => true
+        };
+
+        if is_better {
+            best.replace((score, current.clone()));
+        }
+
+        for i in from..candidates.len() {
+            let overlaps = current.iter().any(|c| candidates[*c].coverage.iter()
+                .any(|p| candidates[i].coverage.contains(p)));
+
+            if overlaps { continue; }
+
+            current.push(i);
+            self.search_outfits(environment, candidates, i + 1, current, best);
+            current.pop();
+        }
+    }
+
+    fn score_outfit(&self, environment: &EnvironmentC, candidates: &[OutfitCandidate], outfit: &[usize]) -> OutfitScore {
+        let names: Vec<String> = outfit.iter().map(|i| candidates[*i].name.to_string()).collect();
+        let mut cold_resistance: usize = outfit.iter().map(|i| candidates[*i].cold_resistance).sum();
+        let mut water_resistance: usize = outfit.iter().map(|i| candidates[*i].water_resistance).sum();
+
+        if let Some((_, g)) = self.clothes_groups.borrow().iter().find(|(_, g)| g.has_complete(names.clone())) {
+            cold_resistance += g.bonus_cold_resistance;
+            water_resistance += g.bonus_water_resistance;
+        }
+
+        let warmth = self.calculate_warmth_level(environment.temperature, environment.wind_speed,
+                                                 environment.humidity, self.wetness_level.get(),
+                                                 usize::min(cold_resistance, 100))
+            .unwrap_or(0.);
+        let discomfort = if warmth < COMFORT_WARMTH_MIN {
+            COMFORT_WARMTH_MIN - warmth
+        } else if warmth > HEAT_STRESS_WARMTH_THRESHOLD {
+            warmth - HEAT_STRESS_WARMTH_THRESHOLD
+        } else {
+            0.
+        };
+
+        OutfitScore {
+            comfort: -discomfort,
+            dryness: usize::min(water_resistance, 100) as f32 * environment.rain_intensity,
+            lightness: -(outfit.len() as f32)
+        }
+    }
+}
\ No newline at end of file
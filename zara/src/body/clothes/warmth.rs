@@ -1,20 +1,212 @@
-use crate::body::Body;
+use crate::body::{Body, BodyPart};
+use crate::body::parts::{BodyPartGroup, BodyPartStatus};
+use crate::utils::{clamp_01, EnvironmentC};
+
+use std::fmt;
+
+/// How much colder extremities are than the core in a comfort zone, degrees C
+pub(crate) const PERIPHERAL_TEMPERATURE_GRADIENT: f32 = 2.5;
+/// Extremities cooling time constant, game seconds
+const PERIPHERAL_COOLING_TIME: f32 = 10. * 60.;
+/// Extremities rewarming time constant, game seconds
+const PERIPHERAL_WARMING_TIME: f32 = 40. * 60.;
+/// How much one warmth level unit changes extremities target temperature
+const PERIPHERAL_WARMTH_FACTOR: f32 = 0.5;
+/// Clothes covering a part cut its exposure by this factor
+const COVERED_PART_EXPOSURE: f32 = 0.4;
+
+/// Describes how wind and humidity make the environment feel colder. Wind chill grows with
+/// the wind speed and gets weaker as temperature gets closer to `no_chill_temperature`. Humid
+/// air feels colder when temperature is below `humid_chill_temperature`
+///
+/// # Links
+/// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Warmth-level) for more info.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct WindChillConfig {
+    /// Wind speed at which wind chill is at `max_wind_chill` for 0 degrees C, m/s
+    pub wind_speed_for_max_chill: f32,
+    /// Temperature from which wind does not chill anymore, degrees C
+    pub no_chill_temperature: f32,
+    /// How much colder wind makes it feel at 0 degrees C and `wind_speed_for_max_chill`,
+    /// degrees C
+    pub max_wind_chill: f32,
+    /// Temperature below which humid air starts to feel colder, degrees C
+    pub humid_chill_temperature: f32,
+    /// Temperature at which humidity chill is the strongest, degrees C
+    pub full_humid_chill_temperature: f32,
+    /// How much colder fully humid air makes it feel, degrees C
+    pub max_humid_chill: f32
+}
+impl Default for WindChillConfig {
+    fn default() -> Self {
+        WindChillConfig {
+            wind_speed_for_max_chill: 35.,
+            no_chill_temperature: 35.,
+            max_wind_chill: 15.,
+            humid_chill_temperature: 10.,
+            full_humid_chill_temperature: 0.,
+            max_humid_chill: 4.
+        }
+    }
+}
+impl fmt::Display for WindChillConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Wind chill config: wind {:.1}C @{:.1} m/s, humidity {:.1}C", self.max_wind_chill,
+               self.wind_speed_for_max_chill, self.max_humid_chill)
+    }
+}
 
 impl Body {
-    pub(crate) fn update_warmth_level_if_needed(&self, world_temp: f32, wind_speed: f32) {
+    pub(crate) fn set_cold_tolerance(&self, value: f32) {
+        const EPS: f32 = 0.0001;
+
+        if f32::abs(self.cold_tolerance.get() - value) < EPS { return; }
+
+        self.cold_tolerance.set(value);
+
+        self.recalculate_warmth_level();
+    }
+
+    pub(crate) fn update_warmth_level_if_needed(&self, world_temp: f32, wind_speed: f32, humidity: f32) {
         const EPS: f32 = 0.0001;
 
         if f32::abs(self.cached_world_temp.get() - world_temp) > EPS ||
-            f32::abs(self.cached_wind_speed.get() - wind_speed) > EPS {
+            f32::abs(self.cached_wind_speed.get() - wind_speed) > EPS ||
+            f32::abs(self.cached_humidity.get() - humidity) > EPS {
             self.cached_world_temp.set(world_temp);
             self.cached_wind_speed.set(wind_speed);
+            self.cached_humidity.set(humidity);
 
             self.recalculate_warmth_level();
         }
     }
 
+    /// Progresses temperatures of the extremities (limbs group parts) towards their targets.
+    /// Extremities cool down fast when cold and rewarm slowly; parts covered by clothes are
+    /// less exposed
+    ///
+    /// # Parameters
+    /// - `core_temperature`: current core body temperature
+    /// - `game_time_delta`: game seconds passed since the last update
+    pub(crate) fn update_peripheral_temperatures(&self, core_temperature: f32, game_time_delta: f32) {
+        let warmth = self.warmth_level.get();
+        let limbs = self.parts.parts_in_group(&BodyPartGroup::Limbs);
+        let mut temps = self.part_temperatures.borrow_mut();
+
+        for part in limbs {
+            if self.part_status(part) != BodyPartStatus::Healthy {
+                temps.remove(&part);
+                continue;
+            }
+
+            let exposure = if self.is_body_part_covered(part) { COVERED_PART_EXPOSURE } else { 1. };
+            let target = f32::min(
+                core_temperature,
+                core_temperature - PERIPHERAL_TEMPERATURE_GRADIENT + warmth * PERIPHERAL_WARMTH_FACTOR * exposure
+            );
+            let current = *temps.entry(part).or_insert(core_temperature - PERIPHERAL_TEMPERATURE_GRADIENT);
+            let time_constant = if target < current { PERIPHERAL_COOLING_TIME } else { PERIPHERAL_WARMING_TIME };
+            let p = 1. - f32::exp(-game_time_delta / time_constant);
+
+            temps.insert(part, current + (target - current) * p);
+        }
+    }
+
+    /// Returns temperature of an extremity (limbs group body part). `None` if part is not
+    /// tracked yet or is lost
+    ///
+    /// # Parameters
+    /// - `body_part`: body part to check
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.body.part_temperature(BodyPart::LeftBrush);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Warmth-level) for more info.
+    pub fn part_temperature(&self, body_part: BodyPart) -> Option<f32> {
+        self.part_temperatures.borrow().get(&body_part).cloned()
+    }
+
+    /// Returns average temperature of all extremities, or `None` if none are tracked yet
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.body.peripheral_temperature();
+    /// ```
+    pub fn peripheral_temperature(&self) -> Option<f32> {
+        let temps = self.part_temperatures.borrow();
+
+        if temps.is_empty() { return None; }
+
+        Some(temps.values().sum::<f32>() / temps.len() as f32)
+    }
+
+    /// Returns the coldest extremity and its temperature, or `None` if none are tracked yet
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some((part, temp)) = person.body.coldest_part() {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn coldest_part(&self) -> Option<(BodyPart, f32)> {
+        self.part_temperatures.borrow().iter()
+            .min_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(p, t)| (*p, *t))
+    }
+
+    /// Sets how wind and humidity change the felt temperature. Takes effect on the next warmth
+    /// recalculation
+    ///
+    /// # Parameters
+    /// - `config`: new wind chill configuration
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::body::clothes::WindChillConfig;
+    ///
+    /// person.body.set_wind_chill_config(WindChillConfig {
+    ///     max_wind_chill: 20.,
+    ///     ..Default::default()
+    /// });
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Warmth-level) for more info.
+    pub fn set_wind_chill_config(&self, config: WindChillConfig) {
+        self.wind_chill_config.set(config);
+
+        self.recalculate_warmth_level();
+    }
+
+    /// Returns how wind and humidity change the felt temperature
+    ///
+    /// # Examples
+    /// ```
+    /// let config = person.body.wind_chill_config();
+    /// ```
+    pub fn wind_chill_config(&self) -> WindChillConfig { self.wind_chill_config.get() }
+
+    /// Returns temperature the environment feels like, as of the last `update` call: world
+    /// temperature lowered by wind chill and humid cold, degrees C. Clothes, wetness and cold
+    /// tolerance are not taken into account
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.body.feels_like_temperature();
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Warmth-level) for more info.
+    pub fn feels_like_temperature(&self) -> f32 {
+        self.calculate_feels_like(self.cached_world_temp.get(), self.cached_wind_speed.get(),
+                                  self.cached_humidity.get())
+    }
+
     /// Recalculates cached warmth level value. This is automatically called every time
-    /// environment temperature, wind speed or clothing changes
+    /// environment temperature, wind speed, humidity or clothing changes
     /// 
     /// # Examples
     /// ```
@@ -24,31 +216,102 @@ impl Body {
     /// # Links
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Warmth-level) for more info.
     pub fn recalculate_warmth_level(&self) {
+        let level = self.calculate_warmth_level(
+            self.cached_world_temp.get(),
+            self.cached_wind_speed.get(),
+            self.cached_humidity.get(),
+            self.wetness_level.get(),
+            self.total_cold_resistance()
+        );
+
+        if let Some(level) = level {
+            self.warmth_level.set(level);
+        }
+    }
+
+    /// Evaluates warmth level that the current outfit would yield under a given environment.
+    /// Current wetness level and cold tolerance are taken into account. Does not change any state
+    ///
+    /// # Parameters
+    /// - `environment`: hypothetical environment
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::utils::EnvironmentC;
+    ///
+    /// let storm = EnvironmentC::new(-5., 15., 0.8);
+    /// let warmth = person.body.forecast_warmth(&storm);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Warmth-level) for more info.
+    pub fn forecast_warmth(&self, environment: &EnvironmentC) -> f32 {
+        self.calculate_warmth_level(environment.temperature, environment.wind_speed, environment.humidity,
+                                    self.wetness_level.get(), self.total_cold_resistance())
+            .unwrap_or(self.warmth_level.get())
+    }
+
+    /// Evaluates warmth level that the current outfit would yield after spending a given
+    /// time under a given environment, including wetness gained or lost meanwhile.
+    /// Does not change any state
+    ///
+    /// # Parameters
+    /// - `environment`: hypothetical environment
+    /// - `game_seconds`: how long character stays in this environment, game seconds
+    ///
+    /// # Examples
+    /// ```
+    /// let warmth = person.body.forecast_warmth_after(&storm, 30. * 60.);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Warmth-level) for more info.
+    pub fn forecast_warmth_after(&self, environment: &EnvironmentC, game_seconds: f32) -> f32 {
+        let wetness = self.forecast_wetness(environment, game_seconds);
+
+        self.calculate_warmth_level(environment.temperature, environment.wind_speed, environment.humidity,
+                                    wetness, self.total_cold_resistance())
+            .unwrap_or(self.warmth_level.get())
+    }
+
+    /// World temperature lowered by wind chill and humid cold
+    fn calculate_feels_like(&self, temp: f32, wind_speed: f32, humidity: f32) -> f32 {
+        let config = self.wind_chill_config.get();
+        let wind_coldness = if config.wind_speed_for_max_chill > 0. && config.no_chill_temperature > 0. {
+            // -1..+1 scale for the usual temperatures and wind speeds
+            (wind_speed / config.wind_speed_for_max_chill) * (temp / config.no_chill_temperature - 1.)
+        } else {
+            0.
+        };
+        let wind_temperature_bonus = f32::min(wind_coldness * config.max_wind_chill, 0.); // only cold wind counts
+        let humid_range = config.humid_chill_temperature - config.full_humid_chill_temperature;
+        let humid_coldness = if humid_range > 0. {
+            clamp_01((config.humid_chill_temperature - temp) / humid_range)
+        } else if temp < config.humid_chill_temperature { 1. } else { 0. };
+        let humidity_temperature_bonus = -clamp_01(humidity) * humid_coldness * config.max_humid_chill;
+
+        temp + wind_temperature_bonus + humidity_temperature_bonus
+    }
+
+    pub(crate) fn calculate_warmth_level(&self, temp: f32, wind_speed: f32, humidity: f32, wetness: f32,
+                                         cold_resistance: usize) -> Option<f32> {
         const COMFORT_TEMPERATURE_NAKED: f32 = 22.; // degrees C
         const MAXIMUM_WETNESS_TEMPERATURE_DECREASE: f32 = 10.; // degrees C
-        const MAXIMUM_WIND_TEMPERATURE_DECREASE: f32 = 15.; // degrees C
-
-        let temp = self.cached_world_temp.get();
-        if temp < -500. { return; }
-        let wetness_temperature_bonus = -(self.wetness_level.get() / 100.) * MAXIMUM_WETNESS_TEMPERATURE_DECREASE;
-        let wind_speed = self.cached_wind_speed.get();
-        if wind_speed < 0. { return; }
-        let wind_coldness = (wind_speed * (temp / 35.) - wind_speed) / 35.; // -1..+1 scale
-        let mut wind_temperature_bonus = wind_coldness * MAXIMUM_WIND_TEMPERATURE_DECREASE;
-
-        if wind_temperature_bonus > 0. {
-            wind_temperature_bonus = 0.; // only cold wind counts
-        }
 
-        let final_temp = temp + wetness_temperature_bonus + wind_temperature_bonus;
+        if temp < -500. { return None; }
+        let wetness_temperature_bonus = -(wetness / 100.) * MAXIMUM_WETNESS_TEMPERATURE_DECREASE;
+        if wind_speed < 0. { return None; }
+
+        let final_temp = self.calculate_feels_like(temp, wind_speed, humidity) + wetness_temperature_bonus +
+            self.cold_tolerance.get();
 
         // Check for clothes cold resistance
-        let cold_resistance = self.total_cold_resistance() as f32;
+        let cold_resistance = cold_resistance as f32;
 
         // Calculate the final level
         let level = (final_temp * (1. - cold_resistance / 100.)) -
             (COMFORT_TEMPERATURE_NAKED - cold_resistance / 2.) + final_temp * (cold_resistance / 100.);
 
-        self.warmth_level.set(level);
+        Some(level)
     }
 }
\ No newline at end of file
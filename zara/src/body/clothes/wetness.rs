@@ -1,5 +1,66 @@
 use crate::body::Body;
-use crate::utils::{clamp, clamp_01, lerp};
+use crate::utils::{clamp, clamp_01, lerp, EnvironmentC};
+
+use std::fmt;
+
+/// Describes how fast character gets wet and dries. All rates are in percent per game second
+///
+/// # Links
+/// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Wetness-level) for more info.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct WetnessConfig {
+    /// Wetness gain at the maximum rain intensity, before clothes water resistance is applied
+    pub rain_gain_rate: f32,
+    /// Wetness gain when character is in water. `None` means instant saturation
+    pub swim_saturation_rate: Option<f32>,
+    /// Drying rate when temperature is at or above `hot_temperature`
+    pub hot_dry_rate: f32,
+    /// Drying rate when temperature is between `cold_temperature` and `hot_temperature`
+    pub normal_dry_rate: f32,
+    /// Drying rate when temperature is between `freeze_temperature` and `cold_temperature`
+    pub cold_dry_rate: f32,
+    /// Drying rate when temperature is at or below `freeze_temperature`
+    pub freeze_dry_rate: f32,
+    /// Temperature from which `hot_dry_rate` is used, degrees C
+    pub hot_temperature: f32,
+    /// Temperature up to which `cold_dry_rate` is used, degrees C
+    pub cold_temperature: f32,
+    /// Temperature up to which `freeze_dry_rate` is used, degrees C
+    pub freeze_temperature: f32,
+    /// Wind speed at which wind drying bonus reaches its maximum, m/s
+    pub wind_speed_for_max_drying: f32,
+    /// Maximum drying rate bonus from wind
+    pub max_wind_drying_rate: f32,
+    /// Multiplier applied to the final drying rate
+    pub drying_multiplier: f32,
+    /// Part of the drying rate that fully humid air takes away (0..1)
+    pub humidity_drying_slowdown: f32
+}
+impl Default for WetnessConfig {
+    fn default() -> Self {
+        WetnessConfig {
+            rain_gain_rate: 0.193,
+            swim_saturation_rate: None,
+            hot_dry_rate: 0.075,
+            normal_dry_rate: 0.0325,
+            cold_dry_rate: 0.011,
+            freeze_dry_rate: 0.0065,
+            hot_temperature: 30.,
+            cold_temperature: 10.,
+            freeze_temperature: -80.,
+            wind_speed_for_max_drying: 7.,
+            max_wind_drying_rate: 0.0422,
+            drying_multiplier: 1.,
+            humidity_drying_slowdown: 0.6
+        }
+    }
+}
+impl fmt::Display for WetnessConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Wetness config: rain {:.4}, dry {:.4}/{:.4}/{:.4}/{:.4}", self.rain_gain_rate,
+               self.hot_dry_rate, self.normal_dry_rate, self.cold_dry_rate, self.freeze_dry_rate)
+    }
+}
 
 impl Body {
     pub(crate) fn update_wetness_level_if_needed(&self, game_time_delta: f32, player_in_water: bool,
@@ -21,6 +82,33 @@ impl Body {
         self.recalculate_wetness_level(game_time_delta);
     }
 
+    /// Sets wetness gain and drying rates. Takes effect on the next update
+    ///
+    /// # Parameters
+    /// - `config`: new wetness configuration
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::body::clothes::WetnessConfig;
+    ///
+    /// person.body.set_wetness_config(WetnessConfig {
+    ///     rain_gain_rate: 0.1,
+    ///     ..Default::default()
+    /// });
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Wetness-level) for more info.
+    pub fn set_wetness_config(&self, config: WetnessConfig) { self.wetness_config.set(config); }
+
+    /// Returns current wetness gain and drying rates
+    ///
+    /// # Examples
+    /// ```
+    /// let config = person.body.wetness_config();
+    /// ```
+    pub fn wetness_config(&self) -> WetnessConfig { self.wetness_config.get() }
+
     /// Recalculates cached wetness level. This is automatically called every update.
     /// 
     /// # Examples
@@ -31,63 +119,117 @@ impl Body {
     /// # Links
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Wetness-level) for more info.
     pub fn recalculate_wetness_level(&self, game_time_delta: f32) {
-        if self.cached_player_in_water.get() {
-            self.wetness_level.set(100.);
-        } else {
-            if self.cached_rain_intensity.get() > 0.001 {
-                if self.wetness_level.get() >= 100. { return; }
+        let value = self.calculate_wetness_level(
+            self.wetness_level.get(),
+            self.cached_player_in_water.get(),
+            &self.cached_environment(self.cached_rain_intensity.get()),
+            game_time_delta
+        );
+
+        self.wetness_level.set(value);
+    }
+
+    /// Evaluates wetness level after spending a given time under a given environment with
+    /// the current outfit. Does not change any state
+    ///
+    /// # Parameters
+    /// - `environment`: hypothetical environment
+    /// - `game_seconds`: how long character stays in this environment, game seconds
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::utils::EnvironmentC;
+    ///
+    /// let storm = EnvironmentC::new(-5., 15., 0.8);
+    /// let wetness = person.body.forecast_wetness(&storm, 30. * 60.);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Wetness-level) for more info.
+    pub fn forecast_wetness(&self, environment: &EnvironmentC, game_seconds: f32) -> f32 {
+        self.calculate_wetness_level(
+            self.wetness_level.get(),
+            false,
+            environment,
+            game_seconds
+        )
+    }
+
+    /// Wetness of clothes that are carried and not worn after a given time. They are packed,
+    /// so they only dry at the current temperature and wind speed
+    pub(crate) fn dried_wetness(&self, current: f32, game_time_delta: f32) -> f32 {
+        self.calculate_wetness_level(
+            current,
+            false,
+            &self.cached_environment(0.),
+            game_time_delta
+        )
+    }
 
-                const RAIN_WETNESS_GAIN_RATE: f32 = 0.193; // max percent per real second
+    /// Wetness of clothes that lie unworn under a given environment after a given time, like
+    /// clothes in a stash
+    pub(crate) fn exposed_wetness(&self, current: f32, environment: &EnvironmentC, game_time_delta: f32) -> f32 {
+        self.calculate_wetness_level(current, false, environment, game_time_delta)
+    }
+
+    /// Environment as of the last update, with a given rain intensity
+    fn cached_environment(&self, rain_intensity: f32) -> EnvironmentC {
+        EnvironmentC::new(self.cached_world_temp.get(), self.cached_wind_speed.get(), rain_intensity)
+            .with_humidity(self.cached_humidity.get())
+    }
+
+    fn calculate_wetness_level(&self, current: f32, player_in_water: bool, environment: &EnvironmentC,
+                               game_time_delta: f32) -> f32 {
+        let config = self.wetness_config.get();
+        let (rain_intensity, world_temp, wind_speed, humidity) =
+            (environment.rain_intensity, environment.temperature, environment.wind_speed, environment.humidity);
 
-                // Wetness increase
-                let mut wet_rate = self.cached_rain_intensity.get() * RAIN_WETNESS_GAIN_RATE;
+        if player_in_water {
+            match config.swim_saturation_rate {
+                Some(rate) => clamp(current + rate * game_time_delta, 0., 100.),
+                None => 100.
+            }
+        } else if rain_intensity > 0.001 {
+            if current >= 100. { return current; }
 
-                // Check for clothes water resistance
-                let water_resistance = self.total_water_resistance() as f32;
+            // Wetness increase
+            let mut wet_rate = rain_intensity * config.rain_gain_rate;
 
-                wet_rate *= 1. - water_resistance / 100.;
+            // Check for clothes water resistance
+            let water_resistance = self.total_water_resistance() as f32;
 
-                let new_value = self.wetness_level.get() + wet_rate * game_time_delta;
+            wet_rate *= 1. - water_resistance / 100.;
 
-                self.wetness_level.set(clamp(new_value, 0., 100.));
+            clamp(current + wet_rate * game_time_delta, 0., 100.)
+        } else {
+            // Drying
+            if current <= 0. { return current; }
+
+            let current_rate = match world_temp {
+                t if t <= config.freeze_temperature => {
+                    config.freeze_dry_rate
+                },
+                t if t <= config.cold_temperature && t > config.freeze_temperature => {
+                    config.cold_dry_rate
+                },
+                t if t >= config.hot_temperature => {
+                    config.hot_dry_rate
+                }
+                _ => {
+                    config.normal_dry_rate
+                }
+            };
+
+            let wind_percent = if config.wind_speed_for_max_drying > 0. {
+                wind_speed / config.wind_speed_for_max_drying
             } else {
-                // Drying
-                if self.wetness_level.get() <= 0. { return; }
-
-                const HOT_DRY_RATE: f32    = 0.075;   // percent per real second
-                const NORMAL_DRY_RATE: f32 = 0.0325;  // percent per real second
-                const COLD_DRY_RATE: f32   = 0.011;   // percent per real second
-                const FREEZE_DRY_RATE: f32 = 0.0065;  // percent per real second
-
-                const HOT_TEMPERATURE: f32    = 30.;  // C (and higher)
-                const COLD_TEMPERATURE: f32   = 10.;  // C (and higher)
-                const FREEZE_TEMPERATURE: f32 = -80.; // C (and higher)
-
-                const WIND_SPEED_FOR_MAX_DRYING: f32 = 7.; // m/s
-                const MAX_WIND_DRYING_RATE: f32 = 0.0422;  // percent per real second
-
-                let current_rate = match self.cached_world_temp.get() {
-                    t if t <= FREEZE_TEMPERATURE => {
-                        FREEZE_DRY_RATE
-                    },
-                    t if t <= COLD_TEMPERATURE && t > FREEZE_TEMPERATURE => {
-                        COLD_DRY_RATE
-                    },
-                    t if t >= HOT_TEMPERATURE => {
-                        HOT_DRY_RATE
-                    }
-                    _ => {
-                        NORMAL_DRY_RATE
-                    }
-                };
-
-                let wind_percent = self.cached_wind_speed.get() / WIND_SPEED_FOR_MAX_DRYING;
-                let wind_bonus = lerp(0., MAX_WIND_DRYING_RATE, clamp_01(wind_percent));
-                let drying_rate = current_rate + wind_bonus;
-                let new_value = self.wetness_level.get() - drying_rate * game_time_delta;
-
-                self.wetness_level.set(clamp(new_value, 0., 100.));
-            }
+                1.
+            };
+            let wind_bonus = lerp(0., config.max_wind_drying_rate, clamp_01(wind_percent));
+            let humidity_factor = 1. - clamp_01(humidity) * clamp_01(config.humidity_drying_slowdown);
+            let drying_rate = (current_rate + wind_bonus) * humidity_factor * config.drying_multiplier;
+
+            clamp(current - drying_rate * game_time_delta, 0., 100.)
         }
     }
 }
\ No newline at end of file
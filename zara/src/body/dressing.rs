@@ -0,0 +1,65 @@
+use crate::body::{Body, BodyPart};
+use crate::state::DirtyTracked;
+
+use std::fmt;
+
+/// Burn dressing applied to a body part (like a cooling gel pad). Eases the pain of the burns
+/// on this body part until the dressing is removed
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
+pub struct BurnDressing {
+    /// Unique name of a dressing inventory item
+    pub item_name: String,
+    /// Body part where this dressing is located
+    pub body_part: BodyPart,
+    /// How much of the burn pain this dressing relieves (0..100 scale)
+    pub relief: usize
+}
+impl fmt::Display for BurnDressing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} on {} ({}% relief)", self.item_name, self.body_part, self.relief)
+    }
+}
+
+impl Body {
+    /// Returns all burn dressings currently applied
+    ///
+    /// # Examples
+    /// ```
+    /// let dressings = person.body.burn_dressings();
+    /// ```
+    pub fn burn_dressings(&self) -> Vec<BurnDressing> { self.burn_dressings.borrow().values().cloned().collect() }
+
+    /// Returns burn dressing applied to a given body part, if any
+    ///
+    /// # Parameters
+    /// - `body_part`: body part to check
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(d) = person.body.burn_dressing_on(BodyPart::LeftForearm) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn burn_dressing_on(&self, body_part: BodyPart) -> Option<BurnDressing> {
+        self.burn_dressings.borrow().get(&body_part).cloned()
+    }
+
+    pub(crate) fn on_burn_dressing_on(&self, item_name: &String, body_part: BodyPart, relief: usize) {
+        self.mark_dirty();
+
+        self.burn_dressings.borrow_mut().insert(body_part, BurnDressing {
+            item_name: item_name.to_string(),
+            body_part,
+            relief: usize::min(relief, 100)
+        });
+    }
+
+    /// Removes burn dressing record if a given appliance was a dressing on this body part
+    pub(crate) fn on_burn_dressing_off(&self, item_name: &String, body_part: BodyPart) {
+        let mut b = self.burn_dressings.borrow_mut();
+
+        if b.get(&body_part).is_some_and(|d| &d.item_name == item_name) {
+            b.remove(&body_part);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::body::Body;
+use crate::utils::{clamp, FrameSummaryC};
+
+/// Warmth level above which heat stress starts to accumulate (upper bound of the comfort zone)
+pub(crate) const HEAT_STRESS_WARMTH_THRESHOLD: f32 = 5.;
+/// Heat stress gain per warmth level unit above the threshold, per game second of running
+const HEAT_STRESS_GAIN_RATE: f32 = 0.01;
+/// Heat stress recovery, per game second
+const HEAT_STRESS_RECOVERY_RATE: f32 = 0.05;
+/// Heat stress recovery when in water, per game second
+const HEAT_STRESS_WATER_RECOVERY_RATE: f32 = 0.2;
+/// Wetness gain from sweating at the maximum heat stress, percent per game second
+const SWEAT_WETNESS_RATE: f32 = 0.01;
+/// Sweating alone cannot make character wetter than this
+const SWEAT_WETNESS_CAP: f32 = 25.;
+
+impl Body {
+    /// Returns current heat stress level (0..100). Heat stress accumulates when warmth level
+    /// stays high during exertion; it increases water drain and makes character sweat
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.body.heat_stress();
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Warmth-level) for more info.
+    pub fn heat_stress(&self) -> f32 { self.heat_stress.get() }
+
+    pub(crate) fn update_heat_stress(&self, frame_data: &FrameSummaryC) {
+        let dt = frame_data.game_time_delta;
+        let player = &frame_data.player;
+        let excess = self.warmth_level.get() - HEAT_STRESS_WARMTH_THRESHOLD;
+        let in_water = player.is_swimming || player.is_underwater;
+
+        let delta = if in_water {
+            -HEAT_STRESS_WATER_RECOVERY_RATE
+        } else if excess > 0. {
+            // Resting in the heat still accumulates stress, but slowly and with partial recovery
+            let (exertion, recovery) = match (player.is_running, player.is_walking) {
+                (true, _) => (1., 0.),
+                (false, true) => (0.4, 0.),
+                _ => (0.15, HEAT_STRESS_RECOVERY_RATE / 2.)
+            };
+
+            excess * HEAT_STRESS_GAIN_RATE * exertion - recovery
+        } else {
+            -HEAT_STRESS_RECOVERY_RATE
+        };
+
+        let stress = clamp(self.heat_stress.get() + delta * dt, 0., 100.);
+
+        self.heat_stress.set(stress);
+
+        // Sweating
+        if !in_water && stress > 0. && self.wetness_level.get() < SWEAT_WETNESS_CAP {
+            let wetness = self.wetness_level.get() + stress / 100. * SWEAT_WETNESS_RATE * dt;
+
+            self.wetness_level.set(f32::min(wetness, SWEAT_WETNESS_CAP));
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,107 @@
+use crate::body::Body;
+use crate::utils::{clamp, FrameSummaryC};
+
+use std::fmt;
+
+/// Hygiene loss, percent per game second (about 25% per game day)
+const HYGIENE_DECAY_RATE: f32 = 25. / 86_400.;
+/// Additional hygiene loss at the maximum heat stress, percent per game second
+const SWEAT_HYGIENE_DECAY_RATE: f32 = 40. / 86_400.;
+/// Additional hygiene loss while an injury is bleeding, percent per game second
+const BLOOD_HYGIENE_DECAY_RATE: f32 = 30. / 86_400.;
+/// Hygiene gain while swimming, percent per game second
+const SWIMMING_HYGIENE_GAIN_RATE: f32 = 0.05;
+/// How much poor hygiene multiplies infection and food poisoning chances at zero hygiene level
+const MAX_HYGIENE_INFECTION_FACTOR: f32 = 1.5;
+
+/// How thoroughly character washes
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum WashQuality {
+    /// Rinsing with water. Restores hygiene up to 60%. Swimming works the same way
+    Rinse,
+    /// Washing with soap. Restores hygiene up to 85%
+    Wash,
+    /// Full bath with soap and hot water. Restores hygiene completely
+    Bath
+}
+impl WashQuality {
+    /// Hygiene level this washing restores up to
+    fn restores_up_to(&self) -> f32 {
+        match self {
+            WashQuality::Rinse => 60.,
+            WashQuality::Wash => 85.,
+            WashQuality::Bath => 100.
+        }
+    }
+}
+impl fmt::Display for WashQuality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Body {
+    /// Returns current hygiene level (0..100, where 100 is perfectly clean). Hygiene decays
+    /// over game days, faster when sweating or bleeding, and is restored with
+    /// [`wash`](#method.wash) or by swimming. Poor hygiene raises infection and food poisoning
+    /// chances; disease monitors can read it from the frame summary `player.hygiene_level`
+    /// field to scale their own spawn chances
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.body.hygiene_level();
+    /// ```
+    pub fn hygiene_level(&self) -> f32 { self.hygiene_level.get() }
+
+    /// Washes the character. Hygiene level is raised up to the limit of a given washing
+    /// quality, and is never lowered
+    ///
+    /// # Parameters
+    /// - `quality`: how thoroughly character washes
+    ///
+    /// # Returns
+    /// New hygiene level (0..100)
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::body::hygiene::WashQuality;
+    ///
+    /// let value = person.body.wash(WashQuality::Wash);
+    /// ```
+    pub fn wash(&self, quality: WashQuality) -> f32 {
+        let value = f32::max(self.hygiene_level.get(), quality.restores_up_to());
+
+        self.hygiene_level.set(value);
+
+        value
+    }
+
+    /// Multiplier for the infection and food poisoning chances, from 1 (clean) up to
+    /// `MAX_HYGIENE_INFECTION_FACTOR` (filthy)
+    pub(crate) fn hygiene_infection_factor(&self) -> f32 {
+        1. + (100. - self.hygiene_level.get()) / 100. * (MAX_HYGIENE_INFECTION_FACTOR - 1.)
+    }
+
+    pub(crate) fn update_hygiene(&self, frame_data: &FrameSummaryC) {
+        let dt = frame_data.game_time_delta;
+        let player = &frame_data.player;
+        let level = self.hygiene_level.get();
+
+        if player.is_swimming || player.is_underwater {
+            let limit = WashQuality::Rinse.restores_up_to();
+
+            if level < limit {
+                self.hygiene_level.set(f32::min(level + SWIMMING_HYGIENE_GAIN_RATE * dt, limit));
+            }
+
+            return;
+        }
+
+        let is_bleeding = frame_data.health.injuries.iter().any(|i| i.is_active && !i.is_blood_stopped);
+        let mut decay = HYGIENE_DECAY_RATE + player.heat_stress / 100. * SWEAT_HYGIENE_DECAY_RATE;
+
+        if is_bleeding { decay += BLOOD_HYGIENE_DECAY_RATE; }
+
+        self.hygiene_level.set(clamp(level - decay * dt, 0., 100.));
+    }
+}
\ No newline at end of file
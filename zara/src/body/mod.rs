@@ -1,20 +1,32 @@
 use crate::utils::{FrameC, GameTimeC, ClothesGroupC};
 use crate::utils::event::{Dispatcher, Listener, Event, MessageQueue};
-use crate::body::clothes::{ClothesGroup, ClothesItem};
+use crate::body::clothes::{ClothesGroup, ClothesItem, WetnessConfig, WindChillConfig};
 use crate::body::clothes::fluent::ClothesGroupStart;
+use crate::body::parts::{BodyPartId, BodyPartGroup, BodyPartsRegistry, BodyPartStatus};
+use crate::body::tourniquet::Tourniquet;
+use crate::body::dressing::BurnDressing;
+use crate::player::Activity;
+use crate::state::DirtyTracked;
 
 use std::cell::{Cell, RefCell, RefMut};
 use std::time::Duration;
 use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
+use std::rc::Rc;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 
 mod status_methods;
 mod body_appliance;
+mod amputation;
+pub(crate) mod heat;
 
 pub(crate) mod state;
 pub mod clothes;
+pub mod parts;
+pub mod tourniquet;
+pub mod dressing;
+pub mod hygiene;
 
 /// Node that controls player body information. Containing clothes, 
 /// body appliances, player warmth and wetness levels
@@ -38,6 +50,8 @@ pub struct Body {
     /// # Links
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Body-Appliances) for more info.
     pub appliances: Arc<RefCell<Vec<BodyAppliance>>>,
+    /// Registered body parts and their groups
+    pub parts: Rc<BodyPartsRegistry>,
 
     /// Game time when player slept last time
     last_sleep_time: RefCell<Option<GameTimeC>>,
@@ -45,6 +59,14 @@ pub struct Body {
     last_sleep_duration: Cell<f32>,
     /// Is player sleeping now
     is_sleeping: Cell<bool>,
+    /// Temperatures of the extremities (degrees C)
+    part_temperatures: RefCell<BTreeMap<BodyPart, f32>>,
+    /// Lost body parts and their prosthetics. Healthy parts are not stored
+    part_statuses: RefCell<BTreeMap<BodyPart, BodyPartStatus>>,
+    /// Tourniquets applied to limbs
+    tourniquets: RefCell<BTreeMap<BodyPart, Tourniquet>>,
+    /// Burn dressings applied to body parts
+    burn_dressings: RefCell<BTreeMap<BodyPart, BurnDressing>>,
     /// Registered clothes groups
     clothes_groups: Arc<RefCell<HashMap<String, ClothesGroup>>>,
     /// Current matched clothes group
@@ -55,6 +77,16 @@ pub struct Body {
     warmth_level: Cell<f32>,
     /// Wetness level value
     wetness_level: Cell<f32>,
+    /// Wetness gain and drying rates
+    wetness_config: Cell<WetnessConfig>,
+    /// How wind and humidity change the felt temperature
+    wind_chill_config: Cell<WindChillConfig>,
+    /// Heat stress level value (0..100)
+    heat_stress: Cell<f32>,
+    /// How many degrees C warmer environment feels for this character
+    cold_tolerance: Cell<f32>,
+    /// Hygiene level value (0..100)
+    hygiene_level: Cell<f32>,
     
     // Counters and caches
     sleeping_counter: Cell<f64>,
@@ -62,6 +94,10 @@ pub struct Body {
     cached_wind_speed: Cell<f32>,
     cached_player_in_water: Cell<bool>,
     cached_rain_intensity: Cell<f32>,
+    cached_humidity: Cell<f32>,
+
+    /// Is node changed since the last state snapshot
+    is_dirty: Cell<bool>,
 
     /// Messages queued for sending on the next frame
     message_queue: RefCell<BTreeMap<usize, Event>>
@@ -70,6 +106,7 @@ pub struct Body {
 struct ClothesItemC {
     cold_resistance: usize,
     water_resistance: usize,
+    coverage: Vec<BodyPart>
 }
 
 /// Body appliance data
@@ -141,8 +178,10 @@ impl ClothesGroupBuilder {
     }
 }
 
-/// All body parts enum
+/// All body parts enum. Built-in values are the default set of parts; games with different
+/// anatomy can register more with [`BodyPartsRegistry`](crate::body::parts::BodyPartsRegistry)
 #[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[repr(i32)]
 pub enum BodyPart {
     Unknown = -1,
     Forehead = 0,
@@ -170,7 +209,9 @@ pub enum BodyPart {
     RightShin = 22,
     LeftFoot = 23,
     RightFoot = 24,
-    Back = 25
+    Back = 25,
+    /// Body part registered in the [`BodyPartsRegistry`](crate::body::parts::BodyPartsRegistry)
+    Custom(BodyPartId)
 }
 impl Default for BodyPart {
     fn default() -> Self {
@@ -182,12 +223,77 @@ impl fmt::Display for BodyPart {
         write!(f, "{:?}", self)
     }
 }
+impl BodyPart {
+    /// Built-in body parts, in the order of their ids
+    pub const BUILT_IN: [BodyPart; 26] = [
+        BodyPart::Forehead, BodyPart::Nape, BodyPart::Eye, BodyPart::Ear, BodyPart::Nose,
+        BodyPart::Throat, BodyPart::LeftShoulder, BodyPart::RightShoulder, BodyPart::LeftForearm,
+        BodyPart::RightForearm, BodyPart::LeftSpokebone, BodyPart::RightSpokebone,
+        BodyPart::LeftBrush, BodyPart::RightBrush, BodyPart::LeftChest, BodyPart::RightChest,
+        BodyPart::Belly, BodyPart::LeftHip, BodyPart::RightHip, BodyPart::LeftKnee,
+        BodyPart::RightKnee, BodyPart::LeftShin, BodyPart::RightShin, BodyPart::LeftFoot,
+        BodyPart::RightFoot, BodyPart::Back
+    ];
+
+    /// Returns id of this body part. `None` for `Unknown`
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::body::BodyPart;
+    ///
+    /// let id = BodyPart::LeftKnee.id();
+    /// ```
+    pub fn id(&self) -> Option<BodyPartId> {
+        match self {
+            BodyPart::Unknown => None,
+            BodyPart::Custom(id) => Some(*id),
+            part => BodyPart::BUILT_IN.iter().position(|p| p == part).map(|i| BodyPartId(i as u32))
+        }
+    }
+
+    /// Activities that use this body part. Empty for the parts that are not tied to any
+    /// activity (head, torso and custom parts)
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::body::BodyPart;
+    ///
+    /// let activities = BodyPart::LeftKnee.activities();
+    /// ```
+    pub fn activities(&self) -> Vec<Activity> {
+        match self {
+            BodyPart::LeftShoulder | BodyPart::RightShoulder | BodyPart::LeftForearm |
+            BodyPart::RightForearm | BodyPart::LeftSpokebone | BodyPart::RightSpokebone |
+            BodyPart::LeftBrush | BodyPart::RightBrush => vec![Activity::ArmsWork, Activity::Swimming],
+            BodyPart::LeftHip | BodyPart::RightHip | BodyPart::LeftKnee | BodyPart::RightKnee |
+            BodyPart::LeftShin | BodyPart::RightShin | BodyPart::LeftFoot |
+            BodyPart::RightFoot => vec![Activity::Walking, Activity::Running, Activity::Swimming],
+            _ => Vec::new()
+        }
+    }
+
+    /// Group of a built-in body part. Custom parts must be looked up in the registry
+    pub(crate) fn built_in_group(part: BodyPart) -> BodyPartGroup {
+        match part {
+            BodyPart::Forehead | BodyPart::Nape | BodyPart::Eye | BodyPart::Ear |
+            BodyPart::Nose | BodyPart::Throat => BodyPartGroup::Head,
+            BodyPart::LeftChest | BodyPart::RightChest | BodyPart::Belly |
+            BodyPart::Back => BodyPartGroup::Torso,
+            _ => BodyPartGroup::Limbs
+        }
+    }
+}
 
 impl Body {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(parts: Rc<BodyPartsRegistry>) -> Self {
         Body {
             clothes: Arc::new(RefCell::new(Vec::new())),
             appliances: Arc::new(RefCell::new(Vec::new())),
+            parts,
+            part_statuses: RefCell::new(BTreeMap::new()),
+            tourniquets: RefCell::new(BTreeMap::new()),
+            burn_dressings: RefCell::new(BTreeMap::new()),
+            part_temperatures: RefCell::new(BTreeMap::new()),
             last_sleep_time: RefCell::new(Option::None),
             is_sleeping: Cell::new(false),
             sleeping_counter: Cell::new(0.),
@@ -200,8 +306,15 @@ impl Body {
             cached_world_temp: Cell::new(-1000.),
             cached_rain_intensity: Cell::new(0.),
             cached_player_in_water: Cell::new(false),
+            cached_humidity: Cell::new(0.),
             warmth_level: Cell::new(0.),
-            wetness_level: Cell::new(0.)
+            wetness_level: Cell::new(0.),
+            wetness_config: Cell::new(WetnessConfig::default()),
+            wind_chill_config: Cell::new(WindChillConfig::default()),
+            heat_stress: Cell::new(0.),
+            cold_tolerance: Cell::new(0.),
+            hygiene_level: Cell::new(100.),
+            is_dirty: Cell::new(false)
         }
     }
 
@@ -210,16 +323,29 @@ impl Body {
     /// # Parameters
     /// - `frame`: summary information for this frame
     pub(crate) fn update<E: Listener + 'static>(&self, frame: &mut FrameC<E>){
+        self.mark_dirty();
+
+        // Shelter keeps the rain and wind out
+        let exposure = frame.data.player.shelter_exposure();
+
         self.update_warmth_level_if_needed(
             frame.data.environment.temperature,
-            frame.data.environment.wind_speed
+            frame.data.environment.wind_speed * exposure,
+            frame.data.environment.humidity
         );
         self.update_wetness_level_if_needed(
             frame.data.game_time_delta,
             frame.data.player.is_swimming || frame.data.player.is_underwater,
-            frame.data.environment.rain_intensity,
+            frame.data.environment.rain_intensity * exposure,
             frame.data.environment.temperature,
-            frame.data.environment.wind_speed
+            frame.data.environment.wind_speed * exposure
+        );
+        self.update_heat_stress(frame.data);
+        self.update_hygiene(frame.data);
+        self.check_tourniquets(&frame.data.game_time);
+        self.update_peripheral_temperatures(
+            frame.data.health.body_temperature,
+            frame.data.game_time_delta
         );
     }
 
@@ -227,22 +353,31 @@ impl Body {
     /// Cannot be called in `update` because we need time precision
     pub(crate) fn sleep_check<E: Listener + 'static>
             (&self, events: &mut Dispatcher<E>, game_time: &Duration, game_time_delta: f32) {
+        self.mark_dirty();
+
         if self.is_sleeping.get(){
             let left = self.sleeping_counter.get() - game_time_delta as f64;
 
             if left <= 0.
             {
-                self.is_sleeping.set(false);
-                self.sleeping_counter.set(0.);
-                self.last_sleep_time.replace(Option::Some(GameTimeC::from_duration(*game_time)));
-
-                events.dispatch(Event::WokeUp);
+                self.wake_up(events, game_time);
             } else {
                 self.sleeping_counter.set(left);
             }
         }
     }
 
+    /// Interrupts sleeping (if any) and dispatches `WokeUp` event
+    pub(crate) fn wake_up<E: Listener + 'static>(&self, events: &mut Dispatcher<E>, game_time: &Duration) {
+        if !self.is_sleeping.get() { return; }
+
+        self.is_sleeping.set(false);
+        self.sleeping_counter.set(0.);
+        self.last_sleep_time.replace(Option::Some(GameTimeC::from_duration(*game_time)));
+
+        events.dispatch(Event::WokeUp);
+    }
+
     /// Starts sleeping. `is_sleeping` will be set to `true`, and on wake up `WokeUp` event will
     /// be triggered
     ///
@@ -271,6 +406,8 @@ impl MessageQueue for Body {
     fn has_messages(&self) -> bool { self.message_queue.borrow().len() > 0 }
 
     fn queue_message(&self, message: Event) {
+        self.mark_dirty();
+
         let mut q = self.message_queue.borrow_mut();
         let id = q.len();
 
@@ -0,0 +1,255 @@
+use crate::body::BodyPart;
+use crate::error::{RegisterBodyPartErr, UnregisterBodyPartErr};
+
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// First id given to the custom body parts. Lower ids are reserved for the built-in parts
+pub const FIRST_CUSTOM_BODY_PART_ID: u32 = 1000;
+
+/// Unique id of a body part. Built-in parts have ids that match their `BodyPart` values,
+/// custom parts get ids starting from [`FIRST_CUSTOM_BODY_PART_ID`](crate::body::parts::FIRST_CUSTOM_BODY_PART_ID)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
+pub struct BodyPartId(pub u32);
+impl fmt::Display for BodyPartId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+/// Group of body parts
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub enum BodyPartGroup {
+    /// Head and neck
+    Head,
+    /// Chest, belly and back
+    Torso,
+    /// Arms and legs
+    Limbs,
+    /// Game-specific group (tail, wings, sensors, etc.)
+    Custom(String)
+}
+impl fmt::Display for BodyPartGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Status of a body part
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
+pub enum BodyPartStatus {
+    /// Body part is in place
+    #[default]
+    Healthy,
+    /// Body part is permanently lost
+    Lost,
+    /// Lost body part is replaced with a prosthetic
+    /// # Parameters
+    /// - Prosthetic appliance item unique name
+    /// - How much of the function is restored (0..100 scale)
+    Prosthetic(String, usize)
+}
+impl fmt::Display for BodyPartStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Registered body part description
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BodyPartInfo {
+    /// Body part
+    pub body_part: BodyPart,
+    /// Unique name of the body part
+    pub name: String,
+    /// Group this body part belongs to
+    pub group: BodyPartGroup
+}
+impl fmt::Display for BodyPartInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.name, self.group)
+    }
+}
+
+/// Registry of body parts and their groups. Built-in [`BodyPart`](crate::body::BodyPart) values
+/// are registered by default; games with different anatomy can register their own parts and
+/// use them with injuries, appliances and clothes coverage as `BodyPart::Custom`
+pub struct BodyPartsRegistry {
+    /// All registered body parts
+    parts: RefCell<BTreeMap<BodyPartId, BodyPartInfo>>,
+    /// Id for the next custom body part. Only goes up, so ids of unregistered parts are
+    /// never given out again
+    next_custom_id: Cell<u32>
+}
+impl fmt::Display for BodyPartsRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Body parts registry ({} parts)", self.parts.borrow().len())
+    }
+}
+impl BodyPartsRegistry {
+    pub(crate) fn new() -> Self {
+        let mut parts = BTreeMap::new();
+
+        for (i, part) in BodyPart::BUILT_IN.iter().enumerate() {
+            parts.insert(BodyPartId(i as u32), BodyPartInfo {
+                body_part: *part,
+                name: part.to_string(),
+                group: BodyPart::built_in_group(*part)
+            });
+        }
+
+        BodyPartsRegistry {
+            parts: RefCell::new(parts),
+            next_custom_id: Cell::new(FIRST_CUSTOM_BODY_PART_ID)
+        }
+    }
+
+    /// Registers a new custom body part
+    ///
+    /// # Parameters
+    /// - `name`: unique name of the body part
+    /// - `group`: group this body part belongs to
+    ///
+    /// # Returns
+    /// `BodyPart::Custom` value to use with injuries, appliances and clothes
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::body::parts::BodyPartGroup;
+    ///
+    /// let tail = person.body.parts.register_part("Tail", BodyPartGroup::Custom("Tail".to_string()));
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `parts` collection
+    pub fn register_part(&self, name: &str, group: BodyPartGroup) -> Result<BodyPart, RegisterBodyPartErr> {
+        let mut b = self.parts.borrow_mut();
+
+        if b.values().any(|p| p.name == name) {
+            return Err(RegisterBodyPartErr::NameAlreadyRegistered);
+        }
+
+        let id = BodyPartId(u32::max(
+            self.next_custom_id.get(),
+            b.keys().last().map(|k| k.0 + 1).unwrap_or(0)
+        ));
+        self.next_custom_id.set(id.0 + 1);
+        let body_part = BodyPart::Custom(id);
+
+        b.insert(id, BodyPartInfo {
+            body_part,
+            name: name.to_string(),
+            group
+        });
+
+        Ok(body_part)
+    }
+
+    /// Unregisters a custom body part. Built-in parts cannot be unregistered. Injuries and
+    /// appliances already located on this part are kept. Its id is never given out again, so they
+    /// cannot end up on a new part
+    ///
+    /// # Parameters
+    /// - `body_part`: custom body part to unregister
+    ///
+    /// # Examples
+    /// ```
+    /// person.body.parts.unregister_part(tail);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `parts` collection
+    pub fn unregister_part(&self, body_part: BodyPart) -> Result<(), UnregisterBodyPartErr> {
+        match body_part {
+            BodyPart::Custom(id) => match self.parts.borrow_mut().remove(&id) {
+                Some(_) => Ok(()),
+                None => Err(UnregisterBodyPartErr::BodyPartNotFound)
+            },
+            _ => Err(UnregisterBodyPartErr::BuiltInBodyPart)
+        }
+    }
+
+    /// Checks if a given body part is registered. `BodyPart::Unknown` is never registered
+    ///
+    /// # Parameters
+    /// - `body_part`: body part to check
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.body.parts.is_registered(body_part);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `parts` collection
+    pub fn is_registered(&self, body_part: BodyPart) -> bool {
+        match body_part.id() {
+            Some(id) => self.parts.borrow().contains_key(&id),
+            None => false
+        }
+    }
+
+    /// Returns description of a registered body part
+    ///
+    /// # Parameters
+    /// - `body_part`: body part to describe
+    ///
+    /// # Examples
+    /// ```
+    /// let info = person.body.parts.info(body_part);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `parts` collection
+    pub fn info(&self, body_part: BodyPart) -> Option<BodyPartInfo> {
+        body_part.id().and_then(|id| self.parts.borrow().get(&id).cloned())
+    }
+
+    /// Returns group of a registered body part
+    ///
+    /// # Parameters
+    /// - `body_part`: body part to check
+    ///
+    /// # Examples
+    /// ```
+    /// let group = person.body.parts.group_of(BodyPart::LeftKnee);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `parts` collection
+    pub fn group_of(&self, body_part: BodyPart) -> Option<BodyPartGroup> {
+        self.info(body_part).map(|p| p.group)
+    }
+
+    /// Returns all registered body parts of a given group
+    ///
+    /// # Parameters
+    /// - `group`: body parts group
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::body::parts::BodyPartGroup;
+    ///
+    /// let limbs = person.body.parts.parts_in_group(&BodyPartGroup::Limbs);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `parts` collection
+    pub fn parts_in_group(&self, group: &BodyPartGroup) -> Vec<BodyPart> {
+        self.parts.borrow().values()
+            .filter(|p| p.group == *group)
+            .map(|p| p.body_part)
+            .collect()
+    }
+
+    /// Returns all registered body parts, built-in first
+    ///
+    /// # Examples
+    /// ```
+    /// let parts = person.body.parts.all_parts();
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `parts` collection
+    pub fn all_parts(&self) -> Vec<BodyPartInfo> { self.parts.borrow().values().cloned().collect() }
+}
\ No newline at end of file
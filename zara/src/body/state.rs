@@ -1,13 +1,20 @@
 use crate::body::{Body, BodyPart, BodyAppliance, ClothesItemC};
+use crate::body::parts::BodyPartStatus;
+use crate::body::tourniquet::Tourniquet;
+use crate::body::dressing::BurnDressing;
 use crate::utils::{ClothesGroupC, GameTimeC};
+use crate::state::DirtyTracked;
 
 use std::time::Duration;
+use std::cell::Cell;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 
 /// State snippet for the `Body` node
 #[derive(Clone, Debug, Default)]
 pub struct BodyStateContract {
+    /// Schema version this state was captured with
+    pub version: u32,
     /// Captured state of the `clothes` field
     pub clothes: Vec<String>,
     /// Captured state of the `appliances` field
@@ -22,10 +29,22 @@ pub struct BodyStateContract {
     pub clothes_group: Option<ClothesGroupStateContract>,
     /// Captured state of the `clothes_data` field
     pub clothes_data: Vec<ClothesItemStateContract>,
+    /// Captured state of the `part_statuses` field
+    pub part_statuses: Vec<BodyPartStatusStateContract>,
+    /// Captured state of the `part_temperatures` field
+    pub part_temperatures: Vec<BodyPartTemperatureStateContract>,
+    /// Captured state of the `tourniquets` field
+    pub tourniquets: Vec<TourniquetStateContract>,
+    /// Captured state of the `burn_dressings` field
+    pub burn_dressings: Vec<BurnDressingStateContract>,
     /// Captured state of the `warmth_level` field
     pub warmth_level: f32,
     /// Captured state of the `wetness_level` field
     pub wetness_level: f32,
+    /// Captured state of the `heat_stress` field
+    pub heat_stress: f32,
+    /// Captured state of the `hygiene_level` field
+    pub hygiene_level: f32,
     /// Captured state of the `sleeping_counter` field
     pub sleeping_counter: f64,
     /// Captured state of the `cached_world_temp` field
@@ -36,6 +55,8 @@ pub struct BodyStateContract {
     pub cached_player_in_water: bool,
     /// Captured state of the `cached_rain_intensity` field
     pub cached_rain_intensity: f32,
+    /// Captured state of the `cached_humidity` field
+    pub cached_humidity: f32,
 }
 impl fmt::Display for BodyStateContract {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -48,38 +69,54 @@ impl PartialEq for BodyStateContract {
         const EPS_32: f32 = 0.0001;
         const EPS_64: f64 = 0.0001;
 
+        self.version == other.version &&
         self.clothes == other.clothes &&
         self.appliances == other.appliances &&
         self.last_sleep_time == other.last_sleep_time &&
         self.is_sleeping == other.is_sleeping &&
         self.clothes_group == other.clothes_group &&
         self.clothes_data == other.clothes_data &&
+        self.part_statuses == other.part_statuses &&
+        self.part_temperatures == other.part_temperatures &&
+        self.tourniquets == other.tourniquets &&
+        self.burn_dressings == other.burn_dressings &&
         self.cached_player_in_water == other.cached_player_in_water &&
         f32::abs(self.last_sleep_duration - other.last_sleep_duration) < EPS_32 &&
         f32::abs(self.warmth_level - other.warmth_level) < EPS_32 &&
         f32::abs(self.wetness_level - other.wetness_level) < EPS_32 &&
+        f32::abs(self.heat_stress - other.heat_stress) < EPS_32 &&
+        f32::abs(self.hygiene_level - other.hygiene_level) < EPS_32 &&
         f32::abs(self.cached_world_temp - other.cached_world_temp) < EPS_32 &&
         f32::abs(self.cached_wind_speed - other.cached_wind_speed) < EPS_32 &&
         f32::abs(self.cached_rain_intensity - other.cached_rain_intensity) < EPS_32 &&
+        f32::abs(self.cached_humidity - other.cached_humidity) < EPS_32 &&
         f64::abs(self.sleeping_counter - other.sleeping_counter) < EPS_64
     }
 }
 impl Hash for BodyStateContract {
     fn hash<H: Hasher>(&self, state: &mut H) {
+        self.version.hash(state);
         self.clothes.hash(state);
         self.appliances.hash(state);
         self.last_sleep_time.hash(state);
         self.is_sleeping.hash(state);
         self.clothes_group.hash(state);
         self.clothes_data.hash(state);
+        self.part_statuses.hash(state);
+        self.part_temperatures.hash(state);
+        self.tourniquets.hash(state);
+        self.burn_dressings.hash(state);
         self.cached_player_in_water.hash(state);
 
         state.write_u32((self.last_sleep_duration*10_000_f32) as u32);
         state.write_i32((self.warmth_level*10_000_f32) as i32);
         state.write_u32((self.wetness_level*10_000_f32) as u32);
+        state.write_u32((self.heat_stress*10_000_f32) as u32);
+        state.write_u32((self.hygiene_level*10_000_f32) as u32);
         state.write_i32((self.cached_world_temp*10_000_f32) as i32);
         state.write_u32((self.cached_wind_speed*10_000_f32) as u32);
         state.write_u32((self.cached_rain_intensity*10_000_f32) as u32);
+        state.write_u32((self.cached_humidity*10_000_f32) as u32);
         state.write_u64((self.sleeping_counter*1_000_f64) as u64);
     }
 }
@@ -93,6 +130,68 @@ pub struct BodyApplianceStateContract {
     pub body_part: BodyPart
 }
 
+/// State snippet for the lost body part
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
+pub struct BodyPartStatusStateContract {
+    /// Captured state of the `body_part` key
+    pub body_part: BodyPart,
+    /// Captured state of the body part status
+    pub status: BodyPartStatus
+}
+
+/// State snippet for the extremity temperature
+#[derive(Clone, Debug, Default)]
+pub struct BodyPartTemperatureStateContract {
+    /// Captured state of the `body_part` key
+    pub body_part: BodyPart,
+    /// Captured state of the temperature value
+    pub temperature: f32
+}
+impl Eq for BodyPartTemperatureStateContract { }
+impl PartialEq for BodyPartTemperatureStateContract {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.body_part == other.body_part &&
+        f32::abs(self.temperature - other.temperature) < EPS
+    }
+}
+impl Hash for BodyPartTemperatureStateContract {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.body_part.hash(state);
+
+        state.write_i32((self.temperature*10_000_f32) as i32);
+    }
+}
+
+/// State snippet for the applied tourniquet
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
+pub struct TourniquetStateContract {
+    /// Captured state of the `item_name` field
+    pub item_name: String,
+    /// Captured state of the `body_part` field
+    pub body_part: BodyPart,
+    /// Captured state of the `applied_at` field
+    pub applied_at: Duration,
+    /// Captured state of the `safe_time` field
+    pub safe_time: usize,
+    /// Captured state of the `is_warned` field
+    pub is_warned: bool,
+    /// Captured state of the `is_exceeded` field
+    pub is_exceeded: bool
+}
+
+/// State snippet for the applied burn dressing
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
+pub struct BurnDressingStateContract {
+    /// Captured state of the `item_name` field
+    pub item_name: String,
+    /// Captured state of the `body_part` field
+    pub body_part: BodyPart,
+    /// Captured state of the `relief` field
+    pub relief: usize
+}
+
 /// State snippet for the clothes group
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
 pub struct ClothesGroupStateContract {
@@ -113,6 +212,8 @@ pub struct ClothesItemStateContract {
     pub cold_resistance: usize,
     /// Captured state of the `water_resistance` field
     pub water_resistance: usize,
+    /// Captured state of the `coverage` field
+    pub coverage: Vec<BodyPart>
 }
 
 impl BodyAppliance {
@@ -124,6 +225,19 @@ impl BodyAppliance {
     }
 }
 
+impl Tourniquet {
+    pub(crate) fn get_state(&self) -> TourniquetStateContract {
+        TourniquetStateContract {
+            item_name: self.item_name.to_string(),
+            body_part: self.body_part,
+            applied_at: self.applied_at,
+            safe_time: self.safe_time,
+            is_warned: self.is_warned,
+            is_exceeded: self.is_exceeded
+        }
+    }
+}
+
 impl ClothesGroupC {
     pub(crate) fn get_state(&self) -> ClothesGroupStateContract {
         ClothesGroupStateContract {
@@ -139,18 +253,26 @@ impl ClothesItemC {
         ClothesItemStateContract {
             key,
             water_resistance: self.water_resistance,
-            cold_resistance: self.cold_resistance
+            cold_resistance: self.cold_resistance,
+            coverage: self.coverage.clone()
         }
     }
 }
 
+impl DirtyTracked for Body {
+    fn dirty_flag(&self) -> &Cell<bool> { &self.is_dirty }
+}
 impl Body {
     pub(crate) fn get_state(&self) -> BodyStateContract {
         BodyStateContract {
+            version: crate::state::STATE_CONTRACT_VERSION,
             wetness_level: self.wetness_level.get(),
+            heat_stress: self.heat_stress.get(),
+            hygiene_level: self.hygiene_level.get(),
             warmth_level: self.warmth_level.get(),
             cached_player_in_water: self.cached_player_in_water.get(),
             cached_rain_intensity: self.cached_rain_intensity.get(),
+            cached_humidity: self.cached_humidity.get(),
             cached_wind_speed: self.cached_wind_speed.get(),
             cached_world_temp: self.cached_world_temp.get(),
             is_sleeping: self.is_sleeping.get(),
@@ -161,15 +283,30 @@ impl Body {
             appliances: self.appliances.borrow().iter().map(|x| x.get_state()).collect(),
             clothes_group: self.clothes_group.borrow().as_ref().map(|x| x.get_state()),
             clothes_data: self.clothes_data.borrow().iter().map(|(k, x)| x.get_state(k.to_string())).collect(),
+            part_statuses: self.part_statuses.borrow().iter().map(|(k, x)|
+                BodyPartStatusStateContract { body_part: *k, status: x.clone() }
+            ).collect(),
+            part_temperatures: self.part_temperatures.borrow().iter().map(|(k, x)|
+                BodyPartTemperatureStateContract { body_part: *k, temperature: *x }
+            ).collect(),
+            tourniquets: self.tourniquets.borrow().values().map(|x| x.get_state()).collect(),
+            burn_dressings: self.burn_dressings.borrow().values().map(|x| BurnDressingStateContract {
+                item_name: x.item_name.to_string(),
+                body_part: x.body_part,
+                relief: x.relief
+            }).collect(),
             last_sleep_time: self.last_sleep_time.borrow().as_ref().map(|x| x.to_duration())
         }
     }
 
     pub(crate) fn restore_state(&self, state: &BodyStateContract) {
         self.wetness_level.set(state.wetness_level);
+        self.heat_stress.set(state.heat_stress);
+        self.hygiene_level.set(state.hygiene_level);
         self.warmth_level.set(state.warmth_level);
         self.cached_player_in_water.set(state.cached_player_in_water);
         self.cached_rain_intensity.set(state.cached_rain_intensity);
+        self.cached_humidity.set(state.cached_humidity);
         self.cached_wind_speed.set(state.cached_wind_speed);
         self.cached_world_temp.set(state.cached_world_temp);
         self.is_sleeping.set(state.is_sleeping);
@@ -215,7 +352,55 @@ impl Body {
             for d in &state.clothes_data {
                 b.insert(d.key.to_string(), ClothesItemC{
                     cold_resistance: d.cold_resistance,
-                    water_resistance: d.water_resistance
+                    water_resistance: d.water_resistance,
+                    coverage: d.coverage.clone()
+                });
+            }
+        }
+        {
+            let mut b = self.part_statuses.borrow_mut();
+
+            b.clear();
+
+            for s in &state.part_statuses {
+                b.insert(s.body_part, s.status.clone());
+            }
+        }
+        {
+            let mut b = self.part_temperatures.borrow_mut();
+
+            b.clear();
+
+            for t in &state.part_temperatures {
+                b.insert(t.body_part, t.temperature);
+            }
+        }
+        {
+            let mut b = self.tourniquets.borrow_mut();
+
+            b.clear();
+
+            for t in &state.tourniquets {
+                b.insert(t.body_part, Tourniquet {
+                    item_name: t.item_name.to_string(),
+                    body_part: t.body_part,
+                    applied_at: t.applied_at,
+                    safe_time: t.safe_time,
+                    is_warned: t.is_warned,
+                    is_exceeded: t.is_exceeded
+                });
+            }
+        }
+        {
+            let mut b = self.burn_dressings.borrow_mut();
+
+            b.clear();
+
+            for d in &state.burn_dressings {
+                b.insert(d.body_part, BurnDressing {
+                    item_name: d.item_name.to_string(),
+                    body_part: d.body_part,
+                    relief: d.relief
                 });
             }
         }
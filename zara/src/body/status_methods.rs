@@ -1,4 +1,5 @@
-use crate::body::Body;
+use crate::body::{Body, BodyPart};
+use crate::body::parts::BodyPartGroup;
 use crate::utils::{GameTimeC, ClothesGroupC};
 
 impl Body {
@@ -133,4 +134,57 @@ impl Body {
 
         result
     }
+
+    /// Returns all body parts covered by the clothes character is wearing now
+    ///
+    /// # Examples
+    /// ```
+    /// let parts = person.body.covered_body_parts();
+    /// ```
+    pub fn covered_body_parts(&self) -> Vec<BodyPart> {
+        let mut result: Vec<BodyPart> = Vec::new();
+
+        for (_, data) in self.clothes_data.borrow().iter() {
+            for part in data.coverage.iter() {
+                if !result.contains(part) { result.push(*part); }
+            }
+        }
+
+        result.sort();
+
+        result
+    }
+
+    /// Checks if a given body part is covered by any clothes character is wearing now
+    ///
+    /// # Parameters
+    /// - `body_part`: body part to check
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.body.is_body_part_covered(BodyPart::LeftFoot);
+    /// ```
+    pub fn is_body_part_covered(&self, body_part: BodyPart) -> bool {
+        self.clothes_data.borrow().values().any(|d| d.coverage.contains(&body_part))
+    }
+
+    /// Checks if all registered body parts of a given group are covered by clothes
+    ///
+    /// # Parameters
+    /// - `group`: body parts group
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::body::parts::BodyPartGroup;
+    ///
+    /// let value = person.body.is_group_covered(&BodyPartGroup::Head);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `parts` collection
+    pub fn is_group_covered(&self, group: &BodyPartGroup) -> bool {
+        let covered = self.covered_body_parts();
+
+        self.parts.parts_in_group(group).iter().all(|p| covered.contains(p))
+    }
 }
\ No newline at end of file
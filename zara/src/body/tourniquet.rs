@@ -0,0 +1,139 @@
+use crate::body::{Body, BodyPart};
+use crate::utils::GameTimeC;
+use crate::utils::event::{MessageQueue, Event};
+use crate::state::DirtyTracked;
+
+use std::time::Duration;
+use std::fmt;
+
+/// Part of the safe time after which "time is running out" event is sent
+const TOURNIQUET_WARNING_FRACTION: f64 = 0.75;
+
+/// Tourniquet applied to a limb. Stops all blood loss of the injuries on this limb
+/// until the tourniquet is removed
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
+pub struct Tourniquet {
+    /// Unique name of a tourniquet inventory item
+    pub item_name: String,
+    /// Limb where this tourniquet is located
+    pub body_part: BodyPart,
+    /// Game time when this tourniquet was applied
+    pub applied_at: Duration,
+    /// For how long (game minutes) tourniquet can stay on a limb before tissue damage begins
+    pub safe_time: usize,
+    /// Was "time is running out" event already sent
+    pub is_warned: bool,
+    /// Was safe time exceeded
+    pub is_exceeded: bool
+}
+impl fmt::Display for Tourniquet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} on {} (safe for {} minutes)", self.item_name, self.body_part, self.safe_time)
+    }
+}
+impl Tourniquet {
+    /// Returns game time when safe time of this tourniquet runs out
+    ///
+    /// # Examples
+    /// ```
+    /// let time = tourniquet.expires_at();
+    /// ```
+    pub fn expires_at(&self) -> Duration { self.applied_at + Duration::from_secs(self.safe_time as u64 * 60) }
+
+    /// Returns how many game minutes are left until tissue damage begins. Zero if safe time
+    /// is already exceeded
+    ///
+    /// # Parameters
+    /// - `game_time`: current game time
+    ///
+    /// # Examples
+    /// ```
+    /// let minutes = tourniquet.minutes_left(&game_time);
+    /// ```
+    pub fn minutes_left(&self, game_time: &GameTimeC) -> f32 {
+        self.expires_at().saturating_sub(game_time.to_duration()).as_secs_f32() / 60.
+    }
+
+    /// Checks if safe time of this tourniquet is exceeded
+    ///
+    /// # Parameters
+    /// - `game_time`: current game time
+    ///
+    /// # Examples
+    /// ```
+    /// let value = tourniquet.is_expired(&game_time);
+    /// ```
+    pub fn is_expired(&self, game_time: &GameTimeC) -> bool { game_time.to_duration() >= self.expires_at() }
+}
+
+impl Body {
+    /// Returns all tourniquets currently applied
+    ///
+    /// # Examples
+    /// ```
+    /// let tourniquets = person.body.tourniquets();
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Controlling-blood-loss) for more info.
+    pub fn tourniquets(&self) -> Vec<Tourniquet> { self.tourniquets.borrow().values().cloned().collect() }
+
+    /// Returns tourniquet applied to a given limb, if any
+    ///
+    /// # Parameters
+    /// - `body_part`: limb to check
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(t) = person.body.tourniquet_on(BodyPart::LeftForearm) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn tourniquet_on(&self, body_part: BodyPart) -> Option<Tourniquet> {
+        self.tourniquets.borrow().get(&body_part).cloned()
+    }
+
+    pub(crate) fn on_tourniquet_on(&self, item_name: &String, body_part: BodyPart, safe_time: usize, game_time: Duration) {
+        self.mark_dirty();
+
+        self.tourniquets.borrow_mut().insert(body_part, Tourniquet {
+            item_name: item_name.to_string(),
+            body_part,
+            applied_at: game_time,
+            safe_time,
+            ..Default::default()
+        });
+    }
+
+    /// Removes tourniquet record if a given appliance was a tourniquet on this limb
+    pub(crate) fn on_tourniquet_off(&self, item_name: &String, body_part: BodyPart) {
+        let mut b = self.tourniquets.borrow_mut();
+
+        if b.get(&body_part).is_some_and(|t| &t.item_name == item_name) {
+            b.remove(&body_part);
+        }
+    }
+
+    /// Sends warning events as tourniquets safe time approaches and runs out
+    pub(crate) fn check_tourniquets(&self, game_time: &GameTimeC) {
+        let now = game_time.to_duration();
+
+        for (_, t) in self.tourniquets.borrow_mut().iter_mut() {
+            let elapsed = now.saturating_sub(t.applied_at).as_secs_f64() / 60.;
+            let safe_time = t.safe_time as f64;
+
+            if !t.is_warned && elapsed >= safe_time * TOURNIQUET_WARNING_FRACTION {
+                t.is_warned = true;
+
+                self.queue_message(Event::TourniquetTimeRunningOut(
+                    t.item_name.to_string(), t.body_part, t.minutes_left(game_time)
+                ));
+            }
+            if !t.is_exceeded && elapsed >= safe_time {
+                t.is_exceeded = true;
+
+                self.queue_message(Event::TourniquetSafeTimeExceeded(t.item_name.to_string(), t.body_part));
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,502 @@
+use crate::content::{DoseTreatment, DoseFilter, ANTIVIRAL_PILLS, ACTIVATED_CHARCOAL};
+use crate::health::disease::{Disease, DiseaseTreatment, StageBuilder, StageDescription};
+use crate::health::StageLevel;
+
+use std::any::Any;
+
+/// Viral infection that slowly builds up a fever over two days and stays critical until
+/// treated. Two [`ANTIVIRAL_PILLS`](crate::content::ANTIVIRAL_PILLS) within six game hours
+/// start the healing
+pub struct Flu;
+impl Disease for Flu {
+    fn get_name(&self) -> String { "Flu".to_string() }
+    fn get_stages(&self) -> Vec<StageDescription> {
+        vec![
+            StageBuilder::start()
+                .build_for(StageLevel::InitialStage)
+                    .self_heal(10)
+                    .vitals()
+                        .with_target_body_temp(37.4)
+                        .with_target_heart_rate(80.)
+                        .with_target_blood_pressure(125., 82.)
+                        .will_reach_target_in(6.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.0005)
+                        .water_level(0.0002)
+                    .affects_fatigue(5.)
+                    .no_death_probability()
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Progressing)
+                    .self_heal(5)
+                    .vitals()
+                        .with_target_body_temp(38.3)
+                        .with_target_heart_rate(88.)
+                        .with_target_blood_pressure(128., 85.)
+                        .will_reach_target_in(12.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.001)
+                        .food_level(0.0002)
+                        .water_level(0.0005)
+                    .affects_fatigue(15.)
+                    .no_death_probability()
+                    .with_appetite_loss(30.)
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Worrying)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(39.2)
+                        .with_target_heart_rate(95.)
+                        .with_target_blood_pressure(130., 88.)
+                        .will_reach_target_in(24.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.002)
+                        .food_level(0.0003)
+                        .water_level(0.0008)
+                    .affects_fatigue(25.)
+                    .no_death_probability()
+                    .with_appetite_loss(50.)
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Critical)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(40.1)
+                        .with_target_heart_rate(110.)
+                        .with_target_blood_pressure(135., 90.)
+                        .will_reach_target_in(24.)
+                        .will_last_forever()
+                    .drains()
+                        .stamina(0.004)
+                        .food_level(0.0005)
+                        .water_level(0.0015)
+                    .affects_fatigue(40.)
+                    .no_death_probability()
+                    .with_nausea(30.)
+                    .with_appetite_loss(70.)
+                .build()
+        ]
+    }
+    fn get_treatment(&self) -> Option<Box<dyn DiseaseTreatment>> {
+        Some(Box::new(DoseTreatment::new(DoseFilter::Items(vec![ANTIVIRAL_PILLS.to_string()]), 2, 6. * 60.)))
+    }
+    fn as_any(&self) -> &dyn Any { self }
+}
+
+/// Poisoning by spoiled food or dirty water: nausea and vomiting within hours, then fever and
+/// fast water loss. Two doses of [`ACTIVATED_CHARCOAL`](crate::content::ACTIVATED_CHARCOAL)
+/// within two game hours start the healing
+pub struct FoodPoisoning;
+impl Disease for FoodPoisoning {
+    fn get_name(&self) -> String { "Food Poisoning".to_string() }
+    fn get_stages(&self) -> Vec<StageDescription> {
+        vec![
+            StageBuilder::start()
+                .build_for(StageLevel::InitialStage)
+                    .self_heal(30)
+                    .vitals()
+                        .with_target_body_temp(37.2)
+                        .with_target_heart_rate(85.)
+                        .with_target_blood_pressure(120., 80.)
+                        .will_reach_target_in(1.)
+                        .will_end()
+                    .drains()
+                        .water_level(0.0005)
+                    .no_fatigue_effect()
+                    .no_death_probability()
+                    .with_nausea(40.)
+                    .with_appetite_loss(50.)
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Progressing)
+                    .self_heal(15)
+                    .vitals()
+                        .with_target_body_temp(37.8)
+                        .with_target_heart_rate(92.)
+                        .with_target_blood_pressure(115., 76.)
+                        .will_reach_target_in(3.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.001)
+                        .food_level(0.0005)
+                        .water_level(0.0015)
+                    .affects_fatigue(15.)
+                    .no_death_probability()
+                    .with_nausea(85.)
+                    .with_appetite_loss(90.)
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Worrying)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(38.4)
+                        .with_target_heart_rate(100.)
+                        .with_target_blood_pressure(108., 70.)
+                        .will_reach_target_in(6.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.002)
+                        .food_level(0.0005)
+                        .water_level(0.002)
+                    .affects_fatigue(30.)
+                    .no_death_probability()
+                    .with_nausea(60.)
+                    .with_appetite_loss(80.)
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Critical)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(38.9)
+                        .with_target_heart_rate(110.)
+                        .with_target_blood_pressure(100., 62.)
+                        .will_reach_target_in(12.)
+                        .will_last_forever()
+                    .drains()
+                        .stamina(0.003)
+                        .food_level(0.0008)
+                        .water_level(0.003)
+                    .affects_fatigue(45.)
+                    .no_death_probability()
+                    .with_nausea(50.)
+                    .with_appetite_loss(90.)
+                .build()
+        ]
+    }
+    fn get_treatment(&self) -> Option<Box<dyn DiseaseTreatment>> {
+        Some(Box::new(DoseTreatment::new(DoseFilter::Items(vec![ACTIVATED_CHARCOAL.to_string()]), 2, 2. * 60.)))
+    }
+    fn as_any(&self) -> &dyn Any { self }
+}
+
+/// Heart rate goes up and blood pressure drops as the body loses water. Three drinks within
+/// a game hour start the healing
+pub struct Dehydration;
+impl Disease for Dehydration {
+    fn get_name(&self) -> String { "Dehydration".to_string() }
+    fn get_stages(&self) -> Vec<StageDescription> {
+        vec![
+            StageBuilder::start()
+                .build_for(StageLevel::InitialStage)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(37.)
+                        .with_target_heart_rate(90.)
+                        .with_target_blood_pressure(115., 75.)
+                        .will_reach_target_in(2.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.001)
+                    .affects_fatigue(10.)
+                    .no_death_probability()
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Progressing)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(37.3)
+                        .with_target_heart_rate(100.)
+                        .with_target_blood_pressure(105., 70.)
+                        .will_reach_target_in(4.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.002)
+                    .affects_fatigue(25.)
+                    .no_death_probability()
+                    .with_appetite_loss(20.)
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Worrying)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(37.8)
+                        .with_target_heart_rate(115.)
+                        .with_target_blood_pressure(95., 60.)
+                        .will_reach_target_in(6.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.003)
+                        .water_level(0.0005)
+                    .affects_fatigue(45.)
+                    .no_death_probability()
+                    .with_nausea(20.)
+                    .with_appetite_loss(40.)
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Critical)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(38.5)
+                        .with_target_heart_rate(130.)
+                        .with_target_blood_pressure(85., 50.)
+                        .will_reach_target_in(6.)
+                        .will_last_forever()
+                    .drains()
+                        .stamina(0.005)
+                        .water_level(0.001)
+                    .affects_fatigue(70.)
+                    .no_death_probability()
+                    .with_nausea(40.)
+                    .with_appetite_loss(60.)
+                .build()
+        ]
+    }
+    fn get_treatment(&self) -> Option<Box<dyn DiseaseTreatment>> {
+        Some(Box::new(DoseTreatment::new(DoseFilter::Water, 3, 60.)))
+    }
+    fn as_any(&self) -> &dyn Any { self }
+}
+
+/// Body temperature falls, heart slows down and the character gets exhausted. Two hot meals
+/// or drinks (40 degrees C or warmer) within a game hour start the healing
+pub struct Hypothermia;
+impl Disease for Hypothermia {
+    fn get_name(&self) -> String { "Hypothermia".to_string() }
+    fn get_stages(&self) -> Vec<StageDescription> {
+        vec![
+            StageBuilder::start()
+                .build_for(StageLevel::InitialStage)
+                    .self_heal(20)
+                    .vitals()
+                        .with_target_body_temp(35.8)
+                        .with_target_heart_rate(90.)
+                        .with_target_blood_pressure(130., 85.)
+                        .will_reach_target_in(1.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.001)
+                        .food_level(0.0003)
+                    .affects_fatigue(10.)
+                    .no_death_probability()
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Progressing)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(34.8)
+                        .with_target_heart_rate(70.)
+                        .with_target_blood_pressure(120., 80.)
+                        .will_reach_target_in(2.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.002)
+                        .food_level(0.0005)
+                    .affects_fatigue(20.)
+                    .no_death_probability()
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Worrying)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(33.5)
+                        .with_target_heart_rate(55.)
+                        .with_target_blood_pressure(110., 70.)
+                        .will_reach_target_in(3.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.003)
+                        .food_level(0.0006)
+                    .affects_fatigue(40.)
+                    .no_death_probability()
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Critical)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(31.5)
+                        .with_target_heart_rate(40.)
+                        .with_target_blood_pressure(90., 55.)
+                        .will_reach_target_in(3.)
+                        .will_last_forever()
+                    .drains()
+                        .stamina(0.005)
+                        .food_level(0.0008)
+                    .affects_fatigue(60.)
+                    .no_death_probability()
+                .build()
+        ]
+    }
+    fn get_treatment(&self) -> Option<Box<dyn DiseaseTreatment>> {
+        Some(Box::new(DoseTreatment::new(DoseFilter::Warm(40.), 2, 60.)))
+    }
+    fn as_any(&self) -> &dyn Any { self }
+}
+
+/// Viral infection carried by an [`AnimalBite`](crate::content::AnimalBite). Starts after a
+/// long incubation with a mild fever, then brings agitation, hydrophobia and death. Cannot be
+/// treated once started; see [`RABIES_VACCINE`](crate::content::RABIES_VACCINE) for prevention
+pub struct Rabies;
+impl Disease for Rabies {
+    fn get_name(&self) -> String { "Rabies".to_string() }
+    fn get_stages(&self) -> Vec<StageDescription> {
+        vec![
+            StageBuilder::start()
+                .build_for(StageLevel::InitialStage)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(37.6)
+                        .with_target_heart_rate(85.)
+                        .with_target_blood_pressure(125., 82.)
+                        .will_reach_target_in(24.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.0005)
+                    .affects_fatigue(10.)
+                    .no_death_probability()
+                    .with_appetite_loss(20.)
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Progressing)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(38.5)
+                        .with_target_heart_rate(105.)
+                        .with_target_blood_pressure(140., 90.)
+                        .will_reach_target_in(24.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.002)
+                        .water_level(0.001)
+                    .affects_fatigue(25.)
+                    .no_death_probability()
+                    .with_nausea(40.)
+                    .with_appetite_loss(60.)
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Worrying)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(39.5)
+                        .with_target_heart_rate(125.)
+                        .with_target_blood_pressure(150., 95.)
+                        .will_reach_target_in(24.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.004)
+                        .water_level(0.002)
+                    .affects_fatigue(45.)
+                    .no_death_probability()
+                    .with_nausea(60.)
+                    .with_appetite_loss(90.)
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Critical)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(40.5)
+                        .with_target_heart_rate(140.)
+                        .with_target_blood_pressure(90., 55.)
+                        .will_reach_target_in(12.)
+                        .will_last_forever()
+                    .drains()
+                        .stamina(0.006)
+                        .water_level(0.002)
+                    .affects_fatigue(60.)
+                    .with_chance_of_death(1)
+                    .with_nausea(60.)
+                    .with_appetite_loss(100.)
+                .build()
+        ]
+    }
+    fn get_treatment(&self) -> Option<Box<dyn DiseaseTreatment>> { None }
+    fn as_any(&self) -> &dyn Any { self }
+}
+
+/// Vitamin C deficiency: fatigue, then aching joints and bleeding gums, then exhaustion.
+/// Spawned by the detailed nutrition (see
+/// [`set_nutrition`](crate::health::Health::set_nutrition)) and goes back once vitamin C
+/// levels are restored
+pub struct Scurvy;
+impl Disease for Scurvy {
+    fn get_name(&self) -> String { "Scurvy".to_string() }
+    fn get_stages(&self) -> Vec<StageDescription> {
+        vec![
+            StageBuilder::start()
+                .build_for(StageLevel::InitialStage)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(36.8)
+                        .with_target_heart_rate(80.)
+                        .with_target_blood_pressure(120., 80.)
+                        .will_reach_target_in(24.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.0005)
+                    .affects_fatigue(15.)
+                    .no_death_probability()
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Progressing)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(36.8)
+                        .with_target_heart_rate(85.)
+                        .with_target_blood_pressure(115., 75.)
+                        .will_reach_target_in(48.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.001)
+                        .blood_level(0.00005)
+                    .affects_fatigue(30.)
+                    .no_death_probability()
+                    .with_appetite_loss(20.)
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Worrying)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(37.)
+                        .with_target_heart_rate(95.)
+                        .with_target_blood_pressure(105., 70.)
+                        .will_reach_target_in(72.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.002)
+                        .blood_level(0.0001)
+                    .affects_fatigue(50.)
+                    .no_death_probability()
+                    .with_appetite_loss(40.)
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Critical)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(37.2)
+                        .with_target_heart_rate(110.)
+                        .with_target_blood_pressure(95., 60.)
+                        .will_reach_target_in(72.)
+                        .will_last_forever()
+                    .drains()
+                        .stamina(0.003)
+                        .blood_level(0.0002)
+                    .affects_fatigue(70.)
+                    .no_death_probability()
+                    .with_appetite_loss(60.)
+                .build()
+        ]
+    }
+    fn get_treatment(&self) -> Option<Box<dyn DiseaseTreatment>> { None }
+    fn as_any(&self) -> &dyn Any { self }
+}
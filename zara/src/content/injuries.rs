@@ -0,0 +1,240 @@
+use crate::content::{ApplianceTreatment, Rabies, BANDAGE, SPLINT, BURN_OINTMENT, RABIES_VACCINE};
+use crate::health::injury::{Injury, InjuryTreatment, StageBuilder, StageDescription};
+use crate::health::StageLevel;
+use crate::health::latent::LatentDisease;
+use crate::health::healing::HealingConditions;
+
+use std::any::Any;
+
+/// Bleeding cut that closes by itself in a few hours. A [`BANDAGE`](crate::content::BANDAGE)
+/// on the cut body part stops the bleeding and starts the healing
+pub struct Cut;
+impl Injury for Cut {
+    fn get_name(&self) -> String { "Cut".to_string() }
+    fn get_stages(&self) -> Vec<StageDescription> {
+        vec![
+            StageBuilder::start()
+                .build_for(StageLevel::InitialStage)
+                    .self_heal(10)
+                    .drains()
+                        .stamina_when_used(0.001)
+                        .blood_level(0.002)
+                    .vitals()
+                        .with_target_body_temp(36.6)
+                        .with_target_heart_rate(85.)
+                        .with_target_blood_pressure(120., 80.)
+                        .affects_fatigue(0.)
+                    .no_death_probability()
+                    .will_reach_target_in(0.5)
+                    .will_end()
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Progressing)
+                    .self_heal(40)
+                    .drains()
+                        .stamina_when_used(0.0005)
+                        .blood_level(0.0005)
+                    .vitals()
+                        .with_target_body_temp(36.6)
+                        .with_target_heart_rate(80.)
+                        .with_target_blood_pressure(118., 78.)
+                        .affects_fatigue(5.)
+                    .no_death_probability()
+                    .will_reach_target_in(4.)
+                    .will_end()
+                .build()
+        ]
+    }
+    fn get_treatment(&self) -> Option<Box<dyn InjuryTreatment>> {
+        Some(Box::new(ApplianceTreatment::new(vec![BANDAGE.to_string()], true)))
+    }
+    fn get_is_fracture(&self) -> bool { false }
+    fn as_any(&self) -> &dyn Any { self }
+}
+
+/// Broken bone that makes using the body part exhausting and never heals by itself.
+/// A [`SPLINT`](crate::content::SPLINT) on the broken body part starts the healing, which
+/// pauses while running
+pub struct Fracture;
+impl Injury for Fracture {
+    fn get_name(&self) -> String { "Fracture".to_string() }
+    fn get_stages(&self) -> Vec<StageDescription> {
+        vec![
+            StageBuilder::start()
+                .build_for(StageLevel::InitialStage)
+                    .no_self_heal()
+                    .drains()
+                        .stamina_when_used(0.004)
+                        .blood_level(0.)
+                    .vitals()
+                        .with_target_body_temp(36.8)
+                        .with_target_heart_rate(95.)
+                        .with_target_blood_pressure(135., 88.)
+                        .affects_fatigue(10.)
+                    .no_death_probability()
+                    .will_reach_target_in(1.)
+                    .will_end()
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Progressing)
+                    .no_self_heal()
+                    .drains()
+                        .stamina_when_used(0.006)
+                        .blood_level(0.)
+                    .vitals()
+                        .with_target_body_temp(37.)
+                        .with_target_heart_rate(100.)
+                        .with_target_blood_pressure(138., 90.)
+                        .affects_fatigue(20.)
+                    .no_death_probability()
+                    .will_reach_target_in(12.)
+                    .will_end()
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Worrying)
+                    .no_self_heal()
+                    .drains()
+                        .stamina_when_used(0.008)
+                        .blood_level(0.)
+                    .vitals()
+                        .with_target_body_temp(37.4)
+                        .with_target_heart_rate(100.)
+                        .with_target_blood_pressure(135., 88.)
+                        .affects_fatigue(30.)
+                    .no_death_probability()
+                    .will_reach_target_in(24.)
+                    .will_last_forever()
+                .build()
+        ]
+    }
+    fn get_treatment(&self) -> Option<Box<dyn InjuryTreatment>> {
+        Some(Box::new(ApplianceTreatment::new(vec![SPLINT.to_string()], false)))
+    }
+    fn get_healing_conditions(&self) -> Option<HealingConditions> {
+        Some(HealingConditions::new().not_running())
+    }
+    fn get_is_fracture(&self) -> bool { true }
+    fn as_any(&self) -> &dyn Any { self }
+}
+
+/// Skin burn that may heal by itself early, but gets infected and causes a fever if left
+/// untreated. Severity depends on the burned area (see [`spawn_burn`](crate::health::Health::spawn_burn)).
+/// [`BURN_OINTMENT`](crate::content::BURN_OINTMENT) on the burned body part starts the healing
+pub struct Burn;
+impl Injury for Burn {
+    fn get_name(&self) -> String { "Burn".to_string() }
+    fn get_stages(&self) -> Vec<StageDescription> {
+        vec![
+            StageBuilder::start()
+                .build_for(StageLevel::InitialStage)
+                    .self_heal(15)
+                    .drains()
+                        .stamina_when_used(0.002)
+                        .blood_level(0.)
+                    .vitals()
+                        .with_target_body_temp(36.8)
+                        .with_target_heart_rate(90.)
+                        .with_target_blood_pressure(130., 85.)
+                        .affects_fatigue(5.)
+                    .no_death_probability()
+                    .will_reach_target_in(0.5)
+                    .will_end()
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Progressing)
+                    .self_heal(5)
+                    .drains()
+                        .stamina_when_used(0.003)
+                        .blood_level(0.)
+                    .vitals()
+                        .with_target_body_temp(37.3)
+                        .with_target_heart_rate(92.)
+                        .with_target_blood_pressure(128., 84.)
+                        .affects_fatigue(10.)
+                    .no_death_probability()
+                    .will_reach_target_in(6.)
+                    .will_end()
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Worrying)
+                    .no_self_heal()
+                    .drains()
+                        .stamina(0.002)
+                        .blood_level(0.)
+                    .vitals()
+                        .with_target_body_temp(38.2)
+                        .with_target_heart_rate(100.)
+                        .with_target_blood_pressure(125., 82.)
+                        .affects_fatigue(25.)
+                    .no_death_probability()
+                    .will_reach_target_in(24.)
+                    .will_last_forever()
+                .build()
+        ]
+    }
+    fn get_treatment(&self) -> Option<Box<dyn InjuryTreatment>> {
+        Some(Box::new(ApplianceTreatment::new(vec![BURN_OINTMENT.to_string()], false)))
+    }
+    fn get_is_fracture(&self) -> bool { false }
+    fn get_is_burn(&self) -> bool { true }
+    fn as_any(&self) -> &dyn Any { self }
+}
+
+/// Bleeding bite of a wild animal. Heals like a [`Cut`] with a [`BANDAGE`](crate::content::BANDAGE),
+/// but carries [`Rabies`] with a 30% chance. Rabies starts three to ten game days later; three
+/// doses of [`RABIES_VACCINE`](crate::content::RABIES_VACCINE) prevent it, unless taken less
+/// than a day before the onset
+pub struct AnimalBite;
+impl Injury for AnimalBite {
+    fn get_name(&self) -> String { "Animal Bite".to_string() }
+    fn get_stages(&self) -> Vec<StageDescription> {
+        vec![
+            StageBuilder::start()
+                .build_for(StageLevel::InitialStage)
+                    .self_heal(5)
+                    .drains()
+                        .stamina_when_used(0.002)
+                        .blood_level(0.003)
+                    .vitals()
+                        .with_target_body_temp(36.8)
+                        .with_target_heart_rate(90.)
+                        .with_target_blood_pressure(125., 82.)
+                        .affects_fatigue(5.)
+                    .no_death_probability()
+                    .will_reach_target_in(0.5)
+                    .will_end()
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Progressing)
+                    .no_self_heal()
+                    .drains()
+                        .stamina_when_used(0.001)
+                        .blood_level(0.001)
+                    .vitals()
+                        .with_target_body_temp(37.2)
+                        .with_target_heart_rate(85.)
+                        .with_target_blood_pressure(120., 80.)
+                        .affects_fatigue(10.)
+                    .no_death_probability()
+                    .will_reach_target_in(12.)
+                    .will_last_forever()
+                .build()
+        ]
+    }
+    fn get_treatment(&self) -> Option<Box<dyn InjuryTreatment>> {
+        Some(Box::new(ApplianceTreatment::new(vec![BANDAGE.to_string()], true)))
+    }
+    fn get_is_fracture(&self) -> bool { false }
+    fn get_latent_disease(&self) -> Option<LatentDisease> {
+        Some(LatentDisease::new(Box::new(|| Box::new(Rabies)), 30, 3. * 24., 10. * 24.)
+            .prevented_by(vec![RABIES_VACCINE], 3)
+            .no_return_before_onset(24.))
+    }
+    fn as_any(&self) -> &dyn Any { self }
+}
@@ -0,0 +1,150 @@
+//! Ready-made diseases, injuries, treatments and medical agents, tuned for a realistic game
+//! time scale. Gives a playable baseline without authoring stage chains first. Requires the
+//! `content` feature.
+//!
+//! Everything here is built on the same public builders and traits a game would use, so any
+//! definition can be copied and retuned. Treatments react to the item names listed in this
+//! module; give your inventory items these names (or use [`DoseTreatment`] and
+//! [`ApplianceTreatment`] with your own).
+//!
+//! # Examples
+//! ```
+//! use zara::content;
+//!
+//! content::register(&zara);
+//!
+//! zara.health.spawn_disease(Box::new(content::Flu), game_time);
+//! zara.health.spawn_injury(Box::new(content::Cut), BodyPart::LeftForearm, game_time);
+//! zara.health.spawn_injury(Box::new(content::AnimalBite), BodyPart::RightShin, game_time);
+//! ```
+
+use crate::ZaraController;
+use crate::utils::event::Listener;
+use crate::health::MedicalAgentBuilder;
+use crate::health::medagent::{MedicalAgent, CurveType};
+use crate::health::procedure::{Procedure, ProcedureStep, StepFailure};
+use crate::health::nutrition::Nutrient;
+
+mod treatment;
+mod diseases;
+mod injuries;
+
+pub use treatment::{DoseFilter, DoseTreatment, ApplianceTreatment};
+pub use diseases::{Flu, FoodPoisoning, Dehydration, Hypothermia, Rabies, Scurvy};
+pub use injuries::{Cut, Fracture, Burn, AnimalBite};
+
+/// Pills that cure [`Flu`]
+pub const ANTIVIRAL_PILLS: &str = "Antiviral Pills";
+/// Pills that cure [`FoodPoisoning`]
+pub const ACTIVATED_CHARCOAL: &str = "Activated Charcoal";
+/// Pills that ease pain of [`Fracture`] and [`Burn`]. Do not cure anything and make the
+/// character drowsy
+pub const PAINKILLERS: &str = "Painkillers";
+/// Appliance that stops the bleeding and heals a [`Cut`] or an [`AnimalBite`]
+pub const BANDAGE: &str = "Bandage";
+/// Appliance that heals a [`Fracture`]
+pub const SPLINT: &str = "Splint";
+/// Appliance that heals a [`Burn`]
+pub const BURN_OINTMENT: &str = "Burn Ointment";
+/// Consumable or appliance (injection) that prevents [`Rabies`] carried by an [`AnimalBite`].
+/// Three doses are needed before the point of no return
+pub const RABIES_VACCINE: &str = "Rabies Vaccine";
+/// Consumed by the cleaning step of the [`FIELD_SURGERY`] procedure
+pub const ANTISEPTIC: &str = "Antiseptic";
+/// Consumed by the stitching step of the [`FIELD_SURGERY`] procedure
+pub const SUTURE_KIT: &str = "Suture Kit";
+
+/// Name of the procedure that closes a deep wound: clean, stitch and bandage. Stops the
+/// bleeding and heals the injury when done
+pub const FIELD_SURGERY: &str = "Field Surgery";
+
+/// Name of the antiviral medical agent
+pub const ANTIVIRAL_AGENT: &str = "Antiviral";
+/// Name of the adsorbent medical agent
+pub const ADSORBENT_AGENT: &str = "Adsorbent";
+/// Name of the analgesic medical agent
+pub const ANALGESIC_AGENT: &str = "Analgesic";
+
+/// Returns medical agents that match the content items
+///
+/// # Examples
+/// ```
+/// zara.health.register_medical_agents(zara::content::medical_agents());
+/// ```
+pub fn medical_agents() -> Vec<MedicalAgent> {
+    vec![
+        MedicalAgentBuilder::start()
+            .for_agent(ANTIVIRAL_AGENT)
+                .activates(CurveType::Linearly)
+                .and_lasts_for_minutes(240.)
+                .includes(vec![ANTIVIRAL_PILLS])
+            .build(),
+        MedicalAgentBuilder::start()
+            .for_agent(ADSORBENT_AGENT)
+                .activates(CurveType::Immediately)
+                .and_lasts_for_minutes(120.)
+                .includes(vec![ACTIVATED_CHARCOAL])
+            .build(),
+        MedicalAgentBuilder::start()
+            .for_agent(ANALGESIC_AGENT)
+                .activates(CurveType::MostActiveInSecondHalf)
+                .and_lasts_for_minutes(180.)
+                .includes(vec![PAINKILLERS])
+                .causes_drowsiness(15.)
+                .eases_pain(60.)
+            .build()
+    ]
+}
+
+/// Returns procedures that match the content items
+///
+/// # Examples
+/// ```
+/// zara.health.register_procedures(zara::content::procedures());
+/// ```
+pub fn procedures() -> Vec<Procedure> {
+    vec![
+        Procedure::new(FIELD_SURGERY, vec![
+            ProcedureStep::new("Clean", 10.).consumes(vec![ANTISEPTIC]),
+            ProcedureStep::new("Stitch", 30.).consumes(vec![SUTURE_KIT])
+                .may_fail(25, StepFailure::BloodLoss(5.)),
+            ProcedureStep::new("Bandage", 5.).consumes(vec![BANDAGE])
+        ])
+    ]
+}
+
+/// Registers content medical agents and procedures on a controller, sets [`Burn`] as the burn factory,
+/// [`FoodPoisoning`] as the food poisoning and dirty water factory and [`Scurvy`] as the vitamin C
+/// deficiency factory. Other diseases and
+/// injuries are spawned on demand; their factories are registered so that
+/// [`restore_full_state`](crate::ZaraController::restore_full_state) can recreate them
+///
+/// # Parameters
+/// - `zara`: controller to register content on
+///
+/// # Examples
+/// ```
+/// zara::content::register(&zara);
+/// ```
+pub fn register<E: Listener + 'static>(zara: &ZaraController<E>) {
+    zara.health.register_medical_agents(medical_agents());
+    zara.health.register_procedures(procedures());
+    zara.health.register_disease_factories(vec![
+        Box::new(|| Box::new(Flu)),
+        Box::new(|| Box::new(FoodPoisoning)),
+        Box::new(|| Box::new(Dehydration)),
+        Box::new(|| Box::new(Hypothermia)),
+        Box::new(|| Box::new(Rabies)),
+        Box::new(|| Box::new(Scurvy))
+    ]);
+    zara.health.register_injury_factories(vec![
+        Box::new(|| Box::new(Cut)),
+        Box::new(|| Box::new(Fracture)),
+        Box::new(|| Box::new(Burn)),
+        Box::new(|| Box::new(AnimalBite))
+    ]);
+    zara.health.set_burn_factory(Some(Box::new(|_| Box::new(Burn))));
+    zara.health.set_food_poisoning_factory(Some(Box::new(|_| Box::new(FoodPoisoning))));
+    zara.health.set_dirty_water_factory(Some(Box::new(|_| Box::new(FoodPoisoning))));
+    zara.health.set_deficiency_factory(Nutrient::VitaminC, Some(Box::new(|| Box::new(Scurvy))));
+}
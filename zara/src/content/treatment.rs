@@ -0,0 +1,137 @@
+use crate::health::disease::{DiseaseTreatment, ActiveDisease};
+use crate::health::disease;
+use crate::health::injury::{InjuryTreatment, ActiveInjury};
+use crate::health::injury;
+use crate::inventory::items::{InventoryItem, ConsumableC, ApplianceC};
+use crate::body::BodyPart;
+use crate::utils::GameTimeC;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Describes what counts as a dose for the [`DoseTreatment`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DoseFilter {
+    /// Consumables or appliances (like injections) with one of these names
+    Items(Vec<String>),
+    /// Any drink with water gain
+    Water,
+    /// Any consumable that is at least this warm, degrees C
+    Warm(f32)
+}
+impl DoseFilter {
+    fn matches_consumable(&self, item: &ConsumableC) -> bool {
+        match self {
+            DoseFilter::Items(names) => names.contains(&item.name),
+            DoseFilter::Water => item.is_water && item.water_gain > 0.,
+            DoseFilter::Warm(t) => item.temperature.is_some_and(|x| x >= *t)
+        }
+    }
+
+    fn matches_appliance(&self, item: &ApplianceC) -> bool {
+        match self {
+            DoseFilter::Items(names) => names.contains(&item.name),
+            _ => false
+        }
+    }
+}
+
+/// Disease treatment that inverts the disease when enough doses were taken within a time
+/// window, like "two antiviral pills within six hours". Treatment keeps track of the doses,
+/// so a new instance is needed for every disease
+///
+/// # Examples
+/// ```
+/// use zara::content::{DoseTreatment, DoseFilter};
+///
+/// let treatment = DoseTreatment::new(DoseFilter::Items(vec!["Antibiotic".to_string()]), 3, 12. * 60.);
+/// ```
+pub struct DoseTreatment {
+    filter: DoseFilter,
+    doses: usize,
+    window_minutes: f32,
+    taken: RefCell<Vec<f32>>
+}
+impl DoseTreatment {
+    /// Creates a new dose treatment
+    ///
+    /// # Parameters
+    /// - `filter`: what counts as a dose
+    /// - `doses`: how many doses are needed to start healing
+    /// - `window_minutes`: doses must be taken within this many game minutes
+    pub fn new(filter: DoseFilter, doses: usize, window_minutes: f32) -> Self {
+        DoseTreatment { filter, doses, window_minutes, taken: RefCell::new(Vec::new()) }
+    }
+
+    fn on_dose(&self, game_time: &GameTimeC, disease: &ActiveDisease) {
+        if disease.is_healing() { return; }
+
+        let now = game_time.as_secs_f32();
+        let since = now - self.window_minutes * 60.;
+        let count = {
+            let mut b = self.taken.borrow_mut();
+
+            b.retain(|t| *t >= since);
+            b.push(now);
+            b.len()
+        };
+
+        if count >= self.doses && disease.invert(game_time).is_ok() {
+            self.taken.borrow_mut().clear();
+        }
+    }
+}
+impl DiseaseTreatment for DoseTreatment {
+    fn on_consumed(&self, game_time: &GameTimeC, item: &ConsumableC, _active_stage: &disease::ActiveStage,
+                   disease: &ActiveDisease, _inventory_items: &HashMap<String, Box<dyn InventoryItem>>) {
+        if self.filter.matches_consumable(item) {
+            self.on_dose(game_time, disease);
+        }
+    }
+
+    fn on_appliance_taken(&self, game_time: &GameTimeC, item: &ApplianceC, _body_part: BodyPart,
+                          _active_stage: &disease::ActiveStage, disease: &ActiveDisease,
+                          _inventory_items: &HashMap<String, Box<dyn InventoryItem>>) {
+        if self.filter.matches_appliance(item) {
+            self.on_dose(game_time, disease);
+        }
+    }
+}
+
+/// Injury treatment that inverts the injury when one of the given appliances is applied
+/// to the injured body part, and optionally stops its bleeding
+///
+/// # Examples
+/// ```
+/// use zara::content::ApplianceTreatment;
+///
+/// let treatment = ApplianceTreatment::new(vec!["Tourniquet".to_string()], true);
+/// ```
+pub struct ApplianceTreatment {
+    items: Vec<String>,
+    stops_bleeding: bool
+}
+impl ApplianceTreatment {
+    /// Creates a new appliance treatment
+    ///
+    /// # Parameters
+    /// - `items`: names of the appliances that treat the injury
+    /// - `stops_bleeding`: `true` if an appliance also stops the blood loss
+    pub fn new(items: Vec<String>, stops_bleeding: bool) -> Self {
+        ApplianceTreatment { items, stops_bleeding }
+    }
+}
+impl InjuryTreatment for ApplianceTreatment {
+    fn on_appliance_taken(&self, game_time: &GameTimeC, item: &ApplianceC, body_part: BodyPart,
+                          _active_stage: &injury::ActiveStage, injury: &ActiveInjury,
+                          _inventory_items: &HashMap<String, Box<dyn InventoryItem>>) {
+        if body_part != injury.body_part || !self.items.contains(&item.name) { return; }
+
+        if self.stops_bleeding {
+            injury.stop_blood_loss();
+        }
+        if !injury.is_healing() {
+            injury.invert(game_time).ok();
+        }
+    }
+}
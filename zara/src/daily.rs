@@ -0,0 +1,151 @@
+use crate::ZaraController;
+use crate::utils::FrameSummaryC;
+use crate::utils::event::{Listener, Event, Dispatcher};
+use crate::health::{InjuryKey, RemovalReason};
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Aggregates of one game day. Is sent with the `DayPassed` event at each game day rollover
+#[derive(Clone, Debug, Default)]
+pub struct DaySummaryC {
+    /// Game day this summary describes
+    pub day: u64,
+    /// Food points gained from consumed items
+    pub food_gained: f32,
+    /// Water points gained from consumed items
+    pub water_gained: f32,
+    /// How many game hours character slept
+    pub hours_slept: f32,
+    /// Lowest warmth level of the day
+    pub lowest_warmth: f32,
+    /// Diseases spawned this day
+    pub diseases_gained: Vec<String>,
+    /// Diseases removed or expired this day
+    pub diseases_cured: Vec<String>,
+    /// Injuries spawned this day
+    pub injuries_gained: Vec<InjuryKey>,
+    /// Injuries removed or expired this day
+    pub injuries_cured: Vec<InjuryKey>,
+    /// Long-term conditions started this day
+    pub conditions_started: Vec<String>,
+    /// Long-term conditions ended this day
+    pub conditions_ended: Vec<String>
+}
+impl fmt::Display for DaySummaryC {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {}: food +{:.0}, water +{:.0}, slept {:.1}h, {} diseases, {} injuries", self.day,
+               self.food_gained, self.water_gained, self.hours_slept, self.diseases_gained.len(),
+               self.injuries_gained.len())
+    }
+}
+impl Eq for DaySummaryC { }
+impl PartialEq for DaySummaryC {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.day == other.day &&
+        self.diseases_gained == other.diseases_gained &&
+        self.diseases_cured == other.diseases_cured &&
+        self.injuries_gained == other.injuries_gained &&
+        self.injuries_cured == other.injuries_cured &&
+        self.conditions_started == other.conditions_started &&
+        self.conditions_ended == other.conditions_ended &&
+        f32::abs(self.food_gained - other.food_gained) < EPS &&
+        f32::abs(self.water_gained - other.water_gained) < EPS &&
+        f32::abs(self.hours_slept - other.hours_slept) < EPS &&
+        f32::abs(self.lowest_warmth - other.lowest_warmth) < EPS
+    }
+}
+impl Hash for DaySummaryC {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.day.hash(state);
+        self.diseases_gained.hash(state);
+        self.diseases_cured.hash(state);
+        self.injuries_gained.hash(state);
+        self.injuries_cured.hash(state);
+        self.conditions_started.hash(state);
+        self.conditions_ended.hash(state);
+
+        state.write_u32((self.food_gained*10_000_f32) as u32);
+        state.write_u32((self.water_gained*10_000_f32) as u32);
+        state.write_u32((self.hours_slept*10_000_f32) as u32);
+        state.write_i32((self.lowest_warmth*10_000_f32) as i32);
+    }
+}
+impl DaySummaryC {
+    pub(crate) fn new(day: u64, warmth_level: f32) -> Self {
+        DaySummaryC {
+            day,
+            lowest_warmth: warmth_level,
+            ..Default::default()
+        }
+    }
+}
+
+impl<E: Listener + 'static> ZaraController<E> {
+    /// Returns aggregates collected so far for the current game day. The same structure is
+    /// sent with the `DayPassed` event when the day ends
+    ///
+    /// # Examples
+    /// ```
+    /// let summary = person.today_summary();
+    /// ```
+    ///
+    /// ## Notes
+    /// Day aggregates are not a part of the state snapshot. Restoring a state starts collecting
+    /// them anew for the restored day
+    pub fn today_summary(&self) -> DaySummaryC { self.day_summary.borrow().clone() }
+
+    /// Restarts day aggregates for the current game day
+    pub(crate) fn reset_day_summary(&self) {
+        self.day_summary.replace(DaySummaryC::new(self.environment.game_time.day.get(), self.body.warmth_level()));
+    }
+
+    /// Updates time-based day aggregates and sends the `DayPassed` event on the day rollover
+    pub(crate) fn track_day(&self, frame_data: &FrameSummaryC, events: &mut Dispatcher<E>) {
+        let day = frame_data.game_time.day;
+        let tracked_day = self.day_summary.borrow().day;
+
+        if day != tracked_day {
+            let finished = self.day_summary.replace(DaySummaryC::new(day, frame_data.player.warmth_level));
+
+            // Game time may be moved back by the game, there is no day to report then
+            if day > tracked_day {
+                events.dispatch(Event::DayPassed(finished));
+            }
+
+            return;
+        }
+
+        let mut b = self.day_summary.borrow_mut();
+
+        if frame_data.player.is_sleeping {
+            b.hours_slept += frame_data.game_time_delta / 3600.;
+        }
+
+        b.lowest_warmth = f32::min(b.lowest_warmth, frame_data.player.warmth_level);
+    }
+
+    /// Updates event-based day aggregates
+    pub(crate) fn day_summary_on_event(&self, event: &Event) {
+        let mut b = self.day_summary.borrow_mut();
+
+        match event {
+            Event::ItemConsumed(c) => {
+                b.food_gained += c.food_gain * c.consumed_count as f32;
+                b.water_gained += c.water_gain * c.consumed_count as f32;
+            },
+            Event::DiseaseSpawned(name) => b.diseases_gained.push(name.to_string()),
+            Event::DiseaseRemoved(name, RemovalReason::Cured | RemovalReason::Expired, _) =>
+                b.diseases_cured.push(name.to_string()),
+            Event::InjurySpawned(name, body_part) =>
+                b.injuries_gained.push(InjuryKey::new(name.to_string(), *body_part)),
+            Event::InjuryRemoved(name, body_part, RemovalReason::Cured | RemovalReason::Expired, _) =>
+                b.injuries_cured.push(InjuryKey::new(name.to_string(), *body_part)),
+            Event::ConditionStarted(name) => b.conditions_started.push(name.to_string()),
+            Event::ConditionEnded(name) => b.conditions_ended.push(name.to_string()),
+            _ => { }
+        }
+    }
+}
\ No newline at end of file
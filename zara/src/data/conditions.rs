@@ -0,0 +1,93 @@
+use crate::health::disease::{Disease, DiseaseTreatment};
+use crate::health::injury::{Injury, InjuryTreatment};
+use crate::health::{disease, injury};
+use crate::health::healing::HealingConditions;
+
+use std::rc::Rc;
+use std::any::Any;
+
+/// Disease that was built from a data file. Treatment logic cannot be described in data, so
+/// it can be attached with [`with_treatment`](DataDisease::with_treatment)
+#[derive(Clone)]
+pub struct DataDisease {
+    pub(crate) name: String,
+    display_name: Option<String>,
+    stages: Vec<disease::StageDescription>,
+    healing_conditions: Option<HealingConditions>,
+    treatment: Option<Rc<dyn Fn() -> Box<dyn DiseaseTreatment>>>
+}
+impl DataDisease {
+    pub(crate) fn new(name: String, display_name: Option<String>, stages: Vec<disease::StageDescription>,
+                      healing_conditions: Option<HealingConditions>) -> Self {
+        DataDisease { name, display_name, stages, healing_conditions, treatment: None }
+    }
+
+    /// Attaches treatment to this disease
+    ///
+    /// # Parameters
+    /// - `create`: a function that returns a new treatment instance
+    ///
+    /// # Examples
+    /// ```
+    /// let flu = content.disease("Flu").unwrap()
+    ///     .with_treatment(Rc::new(|| Box::new(FluTreatment)));
+    /// ```
+    pub fn with_treatment(mut self, create: Rc<dyn Fn() -> Box<dyn DiseaseTreatment>>) -> Self {
+        self.treatment = Some(create);
+
+        self
+    }
+}
+impl Disease for DataDisease {
+    fn get_id(&self) -> String { self.name.to_string() }
+    fn get_name(&self) -> String { self.display_name.as_ref().unwrap_or(&self.name).to_string() }
+    fn get_stages(&self) -> Vec<disease::StageDescription> { self.stages.clone() }
+    fn get_treatment(&self) -> Option<Box<dyn DiseaseTreatment>> { self.treatment.as_ref().map(|t| t()) }
+    fn get_healing_conditions(&self) -> Option<HealingConditions> { self.healing_conditions }
+    fn as_any(&self) -> &dyn Any { self }
+}
+
+/// Injury that was built from a data file. Treatment logic cannot be described in data, so
+/// it can be attached with [`with_treatment`](DataInjury::with_treatment)
+#[derive(Clone)]
+pub struct DataInjury {
+    pub(crate) name: String,
+    display_name: Option<String>,
+    is_fracture: bool,
+    is_burn: bool,
+    stages: Vec<injury::StageDescription>,
+    healing_conditions: Option<HealingConditions>,
+    treatment: Option<Rc<dyn Fn() -> Box<dyn InjuryTreatment>>>
+}
+impl DataInjury {
+    pub(crate) fn new(name: String, display_name: Option<String>, is_fracture: bool, is_burn: bool,
+                      stages: Vec<injury::StageDescription>, healing_conditions: Option<HealingConditions>) -> Self {
+        DataInjury { name, display_name, is_fracture, is_burn, stages, healing_conditions, treatment: None }
+    }
+
+    /// Attaches treatment to this injury
+    ///
+    /// # Parameters
+    /// - `create`: a function that returns a new treatment instance
+    ///
+    /// # Examples
+    /// ```
+    /// let cut = content.injury("Cut").unwrap()
+    ///     .with_treatment(Rc::new(|| Box::new(CutTreatment)));
+    /// ```
+    pub fn with_treatment(mut self, create: Rc<dyn Fn() -> Box<dyn InjuryTreatment>>) -> Self {
+        self.treatment = Some(create);
+
+        self
+    }
+}
+impl Injury for DataInjury {
+    fn get_id(&self) -> String { self.name.to_string() }
+    fn get_name(&self) -> String { self.display_name.as_ref().unwrap_or(&self.name).to_string() }
+    fn get_stages(&self) -> Vec<injury::StageDescription> { self.stages.clone() }
+    fn get_treatment(&self) -> Option<Box<dyn InjuryTreatment>> { self.treatment.as_ref().map(|t| t()) }
+    fn get_is_fracture(&self) -> bool { self.is_fracture }
+    fn get_is_burn(&self) -> bool { self.is_burn }
+    fn get_healing_conditions(&self) -> Option<HealingConditions> { self.healing_conditions }
+    fn as_any(&self) -> &dyn Any { self }
+}
\ No newline at end of file
@@ -0,0 +1,298 @@
+use crate::health::StageLevel;
+use crate::health::medagent::CurveType;
+
+use serde::Deserialize;
+
+fn one() -> usize { 1 }
+
+/// All definitions that one data file can contain. Every section is optional
+///
+/// # Examples
+/// ```json
+/// {
+///     "diseases": [ ... ],
+///     "injuries": [ ... ],
+///     "medical_agents": [ ... ],
+///     "clothes_groups": [ ... ],
+///     "recipes": [ ... ]
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DefinitionsSet {
+    /// Disease definitions
+    pub diseases: Vec<DiseaseDef>,
+    /// Injury definitions
+    pub injuries: Vec<InjuryDef>,
+    /// Medical agent definitions
+    pub medical_agents: Vec<MedicalAgentDef>,
+    /// Clothes group definitions
+    pub clothes_groups: Vec<ClothesGroupDef>,
+    /// Crafting recipe definitions
+    pub recipes: Vec<RecipeDef>
+}
+
+/// Disease or injury stage level as it is written in a data file
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize)]
+pub enum StageLevelDef {
+    InitialStage,
+    Progressing,
+    Worrying,
+    Critical
+}
+impl From<StageLevelDef> for StageLevel {
+    fn from(level: StageLevelDef) -> Self {
+        match level {
+            StageLevelDef::InitialStage => StageLevel::InitialStage,
+            StageLevelDef::Progressing => StageLevel::Progressing,
+            StageLevelDef::Worrying => StageLevel::Worrying,
+            StageLevelDef::Critical => StageLevel::Critical
+        }
+    }
+}
+
+/// Disease definition
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DiseaseDef {
+    /// Unique name of the disease. Is used as its id
+    pub name: String,
+    /// Display name (or localization key). `name` is used when not set
+    #[serde(default)]
+    pub display_name: Option<String>,
+    /// Disease stages, in order of seriousness
+    pub stages: Vec<DiseaseStageDef>,
+    /// Conditions the healing disease needs to progress
+    #[serde(default)]
+    pub healing_conditions: Option<HealingConditionsDef>
+}
+
+/// Disease stage definition. Field meanings are the same as in the
+/// [`disease::StageDescription`](crate::health::disease::StageDescription). Zero target means
+/// "no effect"
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DiseaseStageDef {
+    pub level: StageLevelDef,
+    #[serde(default)]
+    pub self_heal_chance: Option<usize>,
+    #[serde(default)]
+    pub chance_of_death: Option<usize>,
+    pub reaches_peak_in_hours: f32,
+    #[serde(default)]
+    pub is_endless: bool,
+    #[serde(default)]
+    pub target_body_temp: f32,
+    #[serde(default)]
+    pub target_heart_rate: f32,
+    #[serde(default)]
+    pub target_pressure_top: f32,
+    #[serde(default)]
+    pub target_pressure_bottom: f32,
+    #[serde(default)]
+    pub target_fatigue_delta: f32,
+    #[serde(default)]
+    pub target_stamina_drain: f32,
+    #[serde(default)]
+    pub target_food_drain: f32,
+    #[serde(default)]
+    pub target_water_drain: f32,
+    #[serde(default)]
+    pub target_oxygen_drain: f32,
+    #[serde(default)]
+    pub target_blood_drain: f32,
+    #[serde(default)]
+    pub nausea: f32,
+    #[serde(default)]
+    pub appetite_loss: f32
+}
+
+/// Injury definition
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InjuryDef {
+    /// Unique name of the injury. Is used as its id
+    pub name: String,
+    /// Display name (or localization key). `name` is used when not set
+    #[serde(default)]
+    pub display_name: Option<String>,
+    /// Is this injury a fracture
+    #[serde(default)]
+    pub is_fracture: bool,
+    /// Is this injury a burn
+    #[serde(default)]
+    pub is_burn: bool,
+    /// Injury stages, in order of seriousness
+    pub stages: Vec<InjuryStageDef>,
+    /// Conditions the healing injury needs to progress
+    #[serde(default)]
+    pub healing_conditions: Option<HealingConditionsDef>
+}
+
+/// Healing conditions definition. Field meanings are the same as in the
+/// [`HealingConditions`](crate::health::healing::HealingConditions)
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HealingConditionsDef {
+    #[serde(default)]
+    pub needs_sleep: bool,
+    #[serde(default)]
+    pub min_warmth: Option<f32>,
+    #[serde(default)]
+    pub no_running: bool
+}
+
+/// Injury stage definition. Field meanings are the same as in the
+/// [`injury::StageDescription`](crate::health::injury::StageDescription). Zero target means
+/// "no effect"
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InjuryStageDef {
+    pub level: StageLevelDef,
+    #[serde(default)]
+    pub self_heal_chance: Option<usize>,
+    #[serde(default)]
+    pub chance_of_death: Option<usize>,
+    pub reaches_peak_in_hours: f32,
+    #[serde(default)]
+    pub is_endless: bool,
+    #[serde(default)]
+    pub target_blood_drain: f32,
+    #[serde(default)]
+    pub target_stamina_drain: f32,
+    #[serde(default)]
+    pub is_stamina_drain_localized: bool,
+    #[serde(default)]
+    pub target_body_temp: f32,
+    #[serde(default)]
+    pub target_heart_rate: f32,
+    #[serde(default)]
+    pub target_pressure_top: f32,
+    #[serde(default)]
+    pub target_pressure_bottom: f32,
+    #[serde(default)]
+    pub target_fatigue_delta: f32
+}
+
+/// Medical agent activation curve preset as it is written in a data file
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize)]
+pub enum CurveTypeDef {
+    Immediately,
+    MostActiveInSecondHalf,
+    Linearly
+}
+impl From<CurveTypeDef> for CurveType {
+    fn from(curve: CurveTypeDef) -> Self {
+        match curve {
+            CurveTypeDef::Immediately => CurveType::Immediately,
+            CurveTypeDef::MostActiveInSecondHalf => CurveType::MostActiveInSecondHalf,
+            CurveTypeDef::Linearly => CurveType::Linearly
+        }
+    }
+}
+
+/// Medical agent definition. Exactly one of `curve` or `keyframes` must be set
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MedicalAgentDef {
+    /// Unique name of the agent. Is used as its id
+    pub name: String,
+    /// Display name (or localization key). `name` is used when not set
+    #[serde(default)]
+    pub display_name: Option<String>,
+    /// Preset activation curve
+    #[serde(default)]
+    pub curve: Option<CurveTypeDef>,
+    /// Custom activation curve. Time is a fraction of the dose duration (0..1), value is a
+    /// percent of activity (0..100)
+    #[serde(default)]
+    pub keyframes: Vec<KeyFrameDef>,
+    /// Duration of a single dose, game minutes
+    pub duration_minutes: f32,
+    /// Names of the items that contain this agent
+    pub items: Vec<String>,
+    /// Fatigue added at the full agent activity (0..100)
+    #[serde(default)]
+    pub drowsiness: f32,
+    /// Part of the food gain lost at the full agent activity (0..100 percents)
+    #[serde(default)]
+    pub nausea: f32,
+    /// Pain eased at the full agent activity (0..100)
+    #[serde(default)]
+    pub pain_relief: f32,
+    /// Toxicity added per game hour at the full agent activity (0..100)
+    #[serde(default)]
+    pub toxicity: f32
+}
+
+/// Keyframe of a custom medical agent activation curve
+#[derive(Copy, Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KeyFrameDef {
+    pub time: f32,
+    pub value: f32
+}
+
+/// Clothes group definition
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClothesGroupDef {
+    /// Unique name of the group
+    pub name: String,
+    /// Clothes that form the group
+    pub items: Vec<ClothesItemDef>,
+    /// Cold resistance bonus when the whole group is on
+    #[serde(default)]
+    pub bonus_cold_resistance: usize,
+    /// Water resistance bonus when the whole group is on
+    #[serde(default)]
+    pub bonus_water_resistance: usize
+}
+
+/// Clothes group item definition
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClothesItemDef {
+    pub name: String,
+    #[serde(default)]
+    pub water_resistance: usize,
+    #[serde(default)]
+    pub cold_resistance: usize
+}
+
+/// Crafting recipe definition. Result item must be registered as a prototype on the
+/// [`DataLoader`](crate::data::DataLoader)
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RecipeDef {
+    /// Name of the resulting inventory item kind
+    pub result_item: String,
+    /// How many result items this recipe produces. 1 by default
+    #[serde(default = "one")]
+    pub result_count: usize,
+    /// Items that are spent
+    pub items: Vec<RecipeItemDef>,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub category: Option<String>,
+    /// How long crafting takes, game minutes
+    #[serde(default)]
+    pub crafting_time: Option<f32>,
+    #[serde(default)]
+    pub requires_daylight: bool,
+    #[serde(default)]
+    pub requires_shelter: bool,
+    #[serde(default)]
+    pub requires_heat_source: bool
+}
+
+/// Crafting recipe item definition
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RecipeItemDef {
+    pub name: String,
+    pub count: usize
+}
\ No newline at end of file
@@ -0,0 +1,551 @@
+//! Loads diseases, injuries, medical agents, clothes groups and crafting recipes from data
+//! files, so content can be tuned without recompiling the game. Requires the `data` feature.
+//!
+//! JSON files are read with [`DataLoader::load_file`]. Definition structs from the
+//! [`definitions`] module are plain `serde` types, so any other format (like RON) can be
+//! deserialized into a [`DefinitionsSet`] by the game itself and then passed to
+//! [`DataLoader::build`].
+
+use crate::ZaraController;
+use crate::error::DataLoadErr;
+use crate::utils::event::Listener;
+use crate::health::{disease, injury, MedicalAgentBuilder};
+use crate::health::medagent::{MedicalAgent, KeyFrame};
+use crate::health::healing::HealingConditions;
+use crate::health::factory::{DiseaseFactory, InjuryFactory};
+use crate::body::clothes::{ClothesGroup, ClothesItem};
+use crate::inventory::items::InventoryItem;
+use crate::inventory::crafting::{CraftingCombination, ItemInCombination, RecipeInfoC, RecipeConstraintsC};
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::fs;
+
+mod conditions;
+
+pub mod definitions;
+
+pub use conditions::{DataDisease, DataInjury};
+pub use definitions::DefinitionsSet;
+
+use definitions::*;
+
+/// Builds game content from data files
+///
+/// # Examples
+/// ```
+/// use zara::data::DataLoader;
+///
+/// let mut content = DataLoader::new()
+///     .with_item_prototype(Box::new(StoneAxe { count: 1 }))
+///     .load_file("content/survival.json")?;
+///
+/// content.register(&zara);
+///
+/// zara.health.spawn_disease(Box::new(content.disease("Flu").unwrap()), game_time);
+/// ```
+#[derive(Default)]
+pub struct DataLoader {
+    prototypes: HashMap<String, Box<dyn InventoryItem>>
+}
+
+/// Content that was built from a data file
+#[derive(Default)]
+pub struct LoadedContent {
+    /// Loaded diseases
+    pub diseases: Vec<DataDisease>,
+    /// Loaded injuries
+    pub injuries: Vec<DataInjury>,
+    /// Loaded medical agents
+    pub medical_agents: Vec<MedicalAgent>,
+    /// Loaded clothes groups
+    pub clothes_groups: Vec<ClothesGroup>,
+    /// Loaded crafting combinations
+    pub recipes: Vec<CraftingCombination>
+}
+
+impl LoadedContent {
+    /// Returns a copy of a loaded disease
+    ///
+    /// # Parameters
+    /// - `name`: disease name
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(flu) = content.disease("Flu") {
+    ///     zara.health.spawn_disease(Box::new(flu), game_time);
+    /// }
+    /// ```
+    pub fn disease(&self, name: &str) -> Option<DataDisease> {
+        self.diseases.iter().find(|d| d.name == name).cloned()
+    }
+
+    /// Returns a copy of a loaded injury
+    ///
+    /// # Parameters
+    /// - `name`: injury name
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(cut) = content.injury("Cut") {
+    ///     zara.health.spawn_injury(Box::new(cut), body_part, game_time);
+    /// }
+    /// ```
+    pub fn injury(&self, name: &str) -> Option<DataInjury> {
+        self.injuries.iter().find(|d| d.name == name).cloned()
+    }
+
+    /// Registers loaded medical agents, clothes groups and crafting combinations on a
+    /// controller. They are moved out of this object; diseases and injuries stay here, because
+    /// they are spawned on demand. Their factories are registered, so that
+    /// [`restore_full_state`](crate::ZaraController::restore_full_state) can recreate them
+    ///
+    /// # Parameters
+    /// - `zara`: controller to register content on
+    ///
+    /// # Examples
+    /// ```
+    /// content.register(&zara);
+    /// ```
+    pub fn register<E: Listener + 'static>(&mut self, zara: &ZaraController<E>) {
+        zara.health.register_medical_agents(self.medical_agents.drain(..).collect());
+        zara.body.register_clothes_groups(self.clothes_groups.drain(..).collect());
+        zara.inventory.register_crafting_combinations(self.recipes.drain(..).collect());
+        zara.health.register_disease_factories(self.diseases.iter().map(|d| {
+            let d = d.clone();
+            Box::new(move || Box::new(d.clone()) as Box<dyn disease::Disease>) as DiseaseFactory
+        }).collect());
+        zara.health.register_injury_factories(self.injuries.iter().map(|i| {
+            let i = i.clone();
+            Box::new(move || Box::new(i.clone()) as Box<dyn injury::Injury>) as InjuryFactory
+        }).collect());
+    }
+}
+
+/// Finds definitions in the source text to give errors a line number
+struct Locator<'a> {
+    file: &'a str,
+    source: &'a str
+}
+impl<'a> Locator<'a> {
+    /// Offset of the `nth` occurrence of `needle` after `from`, or `from` if not found
+    fn find(&self, from: usize, needle: &str, nth: usize) -> usize {
+        let mut offset = from;
+
+        for i in 0..=nth {
+            match self.source.get(offset..).and_then(|s| s.find(needle)) {
+                Some(o) => offset += o + if i < nth { needle.len() } else { 0 },
+                None => return from
+            }
+        }
+
+        offset
+    }
+
+    /// Offset of the section entry that has `value` as its key field. `nth` is the number
+    /// of earlier entries in the section with the same value
+    fn entry(&self, section: &str, value: &str, nth: usize) -> usize {
+        let start = self.find(0, section, 0);
+
+        self.find(start, &format!("\"{}\"", value), nth)
+    }
+
+    fn line(&self, offset: usize) -> usize {
+        self.source[..offset.min(self.source.len())].matches('\n').count() + 1
+    }
+
+    fn error(&self, offset: usize, path: String, reason: String) -> DataLoadErr {
+        DataLoadErr::InvalidDefinition(self.file.to_string(), self.line(offset), path, reason)
+    }
+}
+
+fn check_name(l: &Locator, at: usize, path: &str, name: &str, seen: &mut Vec<String>) -> Result<(), DataLoadErr> {
+    if name.trim().is_empty() {
+        return Err(l.error(at, path.to_string(), String::from("name must not be empty")));
+    }
+    if seen.iter().any(|n| n == name) {
+        return Err(l.error(at, path.to_string(), format!("`{}` is declared twice", name)));
+    }
+
+    seen.push(name.to_string());
+
+    Ok(())
+}
+
+fn check_chance(l: &Locator, at: usize, path: &str, field: &str, value: Option<usize>) -> Result<(), DataLoadErr> {
+    match value {
+        Some(v) if v > 100 => Err(l.error(at, path.to_string(), format!("`{}` must be in 0..100 range", field))),
+        _ => Ok(())
+    }
+}
+
+fn check_positive(l: &Locator, at: usize, path: &str, field: &str, value: f32) -> Result<(), DataLoadErr> {
+    if !value.is_finite() || value < 0. {
+        return Err(l.error(at, path.to_string(), format!("`{}` must not be negative", field)));
+    }
+
+    Ok(())
+}
+
+/// Checks stage list of a disease or an injury: level, self-heal chance, death chance and time to peak
+fn check_stages(l: &Locator, at: usize, path: &str, stages: Vec<(StageLevelDef, Option<usize>, Option<usize>, f32)>)
+    -> Result<(), DataLoadErr> {
+    if stages.is_empty() {
+        return Err(l.error(at, path.to_string(), String::from("at least one stage is needed")));
+    }
+
+    let mut seen: Vec<StageLevelDef> = Vec::new();
+
+    for (i, (level, self_heal, death, hours)) in stages.iter().enumerate() {
+        let stage_at = l.find(at, "level", i);
+        let stage_path = format!("{}.stages[{}]", path, i);
+
+        if seen.contains(level) {
+            return Err(l.error(stage_at, stage_path, format!("stage `{:?}` is declared twice", level)));
+        }
+
+        check_chance(l, stage_at, &stage_path, "self_heal_chance", *self_heal)?;
+        check_chance(l, stage_at, &stage_path, "chance_of_death", *death)?;
+        check_positive(l, stage_at, &stage_path, "reaches_peak_in_hours", *hours)?;
+
+        seen.push(*level);
+    }
+
+    Ok(())
+}
+
+fn build_disease_stage(s: &DiseaseStageDef) -> disease::StageDescription {
+    let b = disease::StageBuilder::start();
+    let node = b.build_for(s.level.into());
+    let vitals = match s.self_heal_chance {
+        Some(p) => node.self_heal(p),
+        None => node.no_self_heal()
+    }.vitals();
+
+    vitals
+        .with_target_body_temp(s.target_body_temp)
+        .with_target_heart_rate(s.target_heart_rate)
+        .with_target_blood_pressure(s.target_pressure_top, s.target_pressure_bottom)
+        .will_reach_target_in(s.reaches_peak_in_hours);
+
+    let drains = if s.is_endless { vitals.will_last_forever() } else { vitals.will_end() }
+        .drains()
+        .stamina(s.target_stamina_drain)
+        .food_level(s.target_food_drain)
+        .water_level(s.target_water_drain)
+        .oxygen_level(s.target_oxygen_drain)
+        .blood_level(s.target_blood_drain);
+    let death = if s.target_fatigue_delta != 0. {
+        drains.affects_fatigue(s.target_fatigue_delta)
+    } else {
+        drains.no_fatigue_effect()
+    };
+
+    match s.chance_of_death {
+        Some(c) => death.with_chance_of_death(c),
+        None => death.no_death_probability()
+    }.with_nausea(s.nausea).with_appetite_loss(s.appetite_loss).build()
+}
+
+fn build_injury_stage(s: &InjuryStageDef) -> injury::StageDescription {
+    let b = injury::StageBuilder::start();
+    let node = b.build_for(s.level.into());
+    let drains = match s.self_heal_chance {
+        Some(p) => node.self_heal(p),
+        None => node.no_self_heal()
+    }.drains();
+
+    if s.is_stamina_drain_localized {
+        drains.stamina_when_used(s.target_stamina_drain);
+    } else {
+        drains.stamina(s.target_stamina_drain);
+    }
+
+    let vitals = drains.blood_level(s.target_blood_drain).vitals()
+        .with_target_body_temp(s.target_body_temp)
+        .with_target_heart_rate(s.target_heart_rate)
+        .with_target_blood_pressure(s.target_pressure_top, s.target_pressure_bottom)
+        .affects_fatigue(s.target_fatigue_delta);
+    let targets = match s.chance_of_death {
+        Some(c) => vitals.deadly().with_chance_of_death(c),
+        None => vitals.no_death_probability()
+    };
+    let duration = targets.will_reach_target_in(s.reaches_peak_in_hours);
+
+    if s.is_endless { duration.will_last_forever() } else { duration.will_end() }.build()
+}
+
+fn build_healing_conditions(c: &HealingConditionsDef) -> HealingConditions {
+    HealingConditions {
+        needs_sleep: c.needs_sleep,
+        min_warmth: c.min_warmth,
+        no_running: c.no_running
+    }
+}
+
+impl DataLoader {
+    /// Creates a new loader without item prototypes
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::data::DataLoader;
+    ///
+    /// let loader = DataLoader::new();
+    /// ```
+    pub fn new() -> Self {
+        DataLoader::default()
+    }
+
+    /// Registers an inventory item prototype that crafting recipes can produce. Prototype
+    /// must support cloning (`clone_boxed` must return `Some`)
+    ///
+    /// # Parameters
+    /// - `item`: item prototype. Its name is used as a recipe `result_item`
+    ///
+    /// # Examples
+    /// ```
+    /// let loader = DataLoader::new()
+    ///     .with_item_prototype(Box::new(StoneAxe { count: 1 }));
+    /// ```
+    pub fn with_item_prototype(mut self, item: Box<dyn InventoryItem>) -> Self {
+        self.prototypes.insert(item.get_id(), item);
+
+        self
+    }
+
+    /// Reads and builds a JSON data file
+    ///
+    /// # Parameters
+    /// - `path`: path to the file
+    ///
+    /// # Examples
+    /// ```
+    /// let content = loader.load_file("content/diseases.json")?;
+    /// ```
+    pub fn load_file<P: AsRef<Path>>(&self, path: P) -> Result<LoadedContent, DataLoadErr> {
+        let file = path.as_ref().display().to_string();
+        let source = fs::read_to_string(path.as_ref())
+            .map_err(|e| DataLoadErr::CannotReadFile(file.to_string(), e.to_string()))?;
+
+        self.load_str(&file, &source)
+    }
+
+    /// Builds JSON data that is already in memory
+    ///
+    /// # Parameters
+    /// - `file`: file name to use in errors
+    /// - `source`: JSON text
+    ///
+    /// # Examples
+    /// ```
+    /// let content = loader.load_str("diseases.json", &text)?;
+    /// ```
+    pub fn load_str(&self, file: &str, source: &str) -> Result<LoadedContent, DataLoadErr> {
+        let definitions: DefinitionsSet = serde_json::from_str(source)
+            .map_err(|e| DataLoadErr::SyntaxError(file.to_string(), e.line(), e.column(), e.to_string()))?;
+
+        self.build(file, source, &definitions)
+    }
+
+    /// Validates and builds definitions that were deserialized by the game (from RON, for
+    /// example). Source text is only used to find line numbers for errors
+    ///
+    /// # Parameters
+    /// - `file`: file name to use in errors
+    /// - `source`: text the definitions were read from
+    /// - `definitions`: deserialized definitions
+    ///
+    /// # Examples
+    /// ```
+    /// let definitions: DefinitionsSet = ron::from_str(&text)?;
+    /// let content = loader.build("diseases.ron", &text, &definitions)?;
+    /// ```
+    pub fn build(&self, file: &str, source: &str, definitions: &DefinitionsSet) -> Result<LoadedContent, DataLoadErr> {
+        let l = Locator { file, source };
+
+        Ok(LoadedContent {
+            diseases: self.build_diseases(&l, &definitions.diseases)?,
+            injuries: self.build_injuries(&l, &definitions.injuries)?,
+            medical_agents: self.build_medical_agents(&l, &definitions.medical_agents)?,
+            clothes_groups: self.build_clothes_groups(&l, &definitions.clothes_groups)?,
+            recipes: self.build_recipes(&l, &definitions.recipes)?
+        })
+    }
+
+    fn build_diseases(&self, l: &Locator, defs: &[DiseaseDef]) -> Result<Vec<DataDisease>, DataLoadErr> {
+        let mut seen = Vec::new();
+        let mut result = Vec::new();
+
+        for (i, d) in defs.iter().enumerate() {
+            let at = l.entry("diseases", &d.name, seen.iter().filter(|n| **n == d.name).count());
+            let path = format!("diseases[{}]", i);
+
+            check_name(l, at, &path, &d.name, &mut seen)?;
+            check_stages(l, at, &path, d.stages.iter()
+                .map(|s| (s.level, s.self_heal_chance, s.chance_of_death, s.reaches_peak_in_hours)).collect())?;
+
+            result.push(DataDisease::new(d.name.to_string(), d.display_name.clone(), d.stages.iter().map(build_disease_stage).collect(),
+                                         d.healing_conditions.as_ref().map(build_healing_conditions)));
+        }
+
+        Ok(result)
+    }
+
+    fn build_injuries(&self, l: &Locator, defs: &[InjuryDef]) -> Result<Vec<DataInjury>, DataLoadErr> {
+        let mut seen = Vec::new();
+        let mut result = Vec::new();
+
+        for (i, d) in defs.iter().enumerate() {
+            let at = l.entry("injuries", &d.name, seen.iter().filter(|n| **n == d.name).count());
+            let path = format!("injuries[{}]", i);
+
+            check_name(l, at, &path, &d.name, &mut seen)?;
+            check_stages(l, at, &path, d.stages.iter()
+                .map(|s| (s.level, s.self_heal_chance, s.chance_of_death, s.reaches_peak_in_hours)).collect())?;
+
+            result.push(DataInjury::new(d.name.to_string(), d.display_name.clone(), d.is_fracture, d.is_burn,
+                                        d.stages.iter().map(build_injury_stage).collect(),
+                                        d.healing_conditions.as_ref().map(build_healing_conditions)));
+        }
+
+        Ok(result)
+    }
+
+    fn build_medical_agents(&self, l: &Locator, defs: &[MedicalAgentDef]) -> Result<Vec<MedicalAgent>, DataLoadErr> {
+        let mut seen = Vec::new();
+        let mut result = Vec::new();
+
+        for (i, d) in defs.iter().enumerate() {
+            let at = l.entry("medical_agents", &d.name, seen.iter().filter(|n| **n == d.name).count());
+            let path = format!("medical_agents[{}]", i);
+
+            check_name(l, at, &path, &d.name, &mut seen)?;
+
+            if d.duration_minutes <= 0. || !d.duration_minutes.is_finite() {
+                return Err(l.error(at, path, String::from("`duration_minutes` must be greater than zero")));
+            }
+            if d.curve.is_some() != d.keyframes.is_empty() {
+                return Err(l.error(at, path, String::from("exactly one of `curve` or `keyframes` must be set")));
+            }
+            if !(0. ..=100.).contains(&d.drowsiness) || !(0. ..=100.).contains(&d.nausea) ||
+               !(0. ..=100.).contains(&d.pain_relief) || !(0. ..=100.).contains(&d.toxicity) {
+                return Err(l.error(at, path, String::from("`drowsiness`, `nausea`, `pain_relief` and `toxicity` must be in 0..100 range")));
+            }
+            for (k, f) in d.keyframes.iter().enumerate() {
+                if !(0. ..=1.).contains(&f.time) || !(0. ..=100.).contains(&f.value) {
+                    return Err(l.error(l.find(at, "time", k), format!("{}.keyframes[{}]", path, k),
+                                       String::from("keyframe time must be in 0..1 and value in 0..100 range")));
+                }
+            }
+
+            let b = MedicalAgentBuilder::start();
+            let agent = b.for_agent(&d.name);
+            let duration = match d.curve {
+                Some(c) => agent.activates(c.into()),
+                None => agent.activates_with_curve(d.keyframes.iter().map(|f| KeyFrame::new(f.time, f.value)).collect())
+            };
+
+            let end = duration
+                .and_lasts_for_minutes(d.duration_minutes)
+                .includes(d.items.iter().map(|x| x.as_str()).collect());
+
+            if let Some(display_name) = &d.display_name {
+                end.displayed_as(display_name);
+            }
+            end.causes_drowsiness(d.drowsiness).causes_nausea(d.nausea).eases_pain(d.pain_relief)
+                .raises_toxicity(d.toxicity);
+
+            result.push(end.build());
+        }
+
+        Ok(result)
+    }
+
+    fn build_clothes_groups(&self, l: &Locator, defs: &[ClothesGroupDef]) -> Result<Vec<ClothesGroup>, DataLoadErr> {
+        let mut seen = Vec::new();
+        let mut result = Vec::new();
+
+        for (i, d) in defs.iter().enumerate() {
+            let at = l.entry("clothes_groups", &d.name, seen.iter().filter(|n| **n == d.name).count());
+            let path = format!("clothes_groups[{}]", i);
+            let mut items_seen = Vec::new();
+
+            check_name(l, at, &path, &d.name, &mut seen)?;
+
+            if d.items.is_empty() {
+                return Err(l.error(at, path, String::from("at least one clothes item is needed")));
+            }
+            for (k, c) in d.items.iter().enumerate() {
+                check_name(l, l.find(at, &format!("\"{}\"", c.name), 0),
+                           &format!("{}.items[{}]", path, k), &c.name, &mut items_seen)?;
+            }
+
+            result.push(ClothesGroup::new(d.name.to_string(),
+                d.items.iter().map(|c| ClothesItem::new(c.name.to_string(), c.water_resistance, c.cold_resistance)).collect(),
+                d.bonus_cold_resistance, d.bonus_water_resistance));
+        }
+
+        Ok(result)
+    }
+
+    fn build_recipes(&self, l: &Locator, defs: &[RecipeDef]) -> Result<Vec<CraftingCombination>, DataLoadErr> {
+        let mut result = Vec::new();
+
+        for (i, d) in defs.iter().enumerate() {
+            let nth = defs[..i].iter().filter(|r| r.result_item == d.result_item).count();
+            let at = l.entry("recipes", &d.result_item, nth);
+            let path = format!("recipes[{}]", i);
+            let mut items_seen = Vec::new();
+
+            let prototype = match self.prototypes.get(&d.result_item).and_then(|p| p.clone_boxed()) {
+                Some(p) => p,
+                None => return Err(l.error(at, path, format!(
+                    "no cloneable item prototype `{}` is registered on the loader", d.result_item)))
+            };
+            if d.result_count == 0 {
+                return Err(l.error(at, path, String::from("`result_count` must be greater than zero")));
+            }
+            if let Some(t) = d.crafting_time {
+                check_positive(l, at, &path, "crafting_time", t)?;
+            }
+            if d.items.is_empty() {
+                return Err(l.error(at, path, String::from("at least one recipe item is needed")));
+            }
+            for (k, x) in d.items.iter().enumerate() {
+                let item_at = l.find(at, &format!("\"{}\"", x.name), 0);
+                let item_path = format!("{}.items[{}]", path, k);
+
+                check_name(l, item_at, &item_path, &x.name, &mut items_seen)?;
+
+                if x.count == 0 {
+                    return Err(l.error(item_at, item_path, String::from("`count` must be greater than zero")));
+                }
+            }
+
+            let count = d.result_count;
+            let create = move || {
+                let mut item = prototype.clone_boxed().unwrap();
+
+                item.set_count(count);
+
+                item
+            };
+
+            result.push(CraftingCombination::new(d.result_item.to_string(),
+                    d.items.iter().map(|x| ItemInCombination::new(&x.name, x.count)).collect(), Box::new(create))
+                .with_info(RecipeInfoC {
+                    display_name: d.display_name.clone(),
+                    description: d.description.clone(),
+                    category: d.category.clone(),
+                    crafting_time: d.crafting_time
+                })
+                .with_constraints(RecipeConstraintsC {
+                    requires_daylight: d.requires_daylight,
+                    requires_shelter: d.requires_shelter,
+                    requires_heat_source: d.requires_heat_source
+                }));
+        }
+
+        Ok(result)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,145 @@
+use crate::ZaraController;
+use crate::utils::event::{Listener, MessageQueue};
+
+use std::fmt::Write;
+
+impl<E: Listener + 'static> ZaraController<E> {
+    /// Builds a structured plain-text report of the whole controller state: all vitals,
+    /// diseases and injuries with their stage timings and lerp targets, medical agents and
+    /// their doses, body and inventory state and all queued messages.
+    ///
+    /// Useful for attaching to bug reports. Format of this report is not stable and should not
+    /// be parsed.
+    ///
+    /// # Examples
+    /// ```
+    /// let report = person.dump_debug_report();
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management) for more info.
+    ///
+    /// ## Notes
+    /// Borrows `health.diseases`, `health.injuries`, `health.conditions`, `health.medical_agents.agents`, `inventory.items`
+    /// `skills.skills`, `traits.traits` collections and all message queues
+    pub fn dump_debug_report(&self) -> String {
+        let mut r = String::new();
+        let game_time = self.environment.game_time.to_contract();
+
+        // Writing to a String never fails
+        writeln!(r, "=== Zara debug report @{} ===", game_time).ok();
+
+        writeln!(r, "\n[controller]").ok();
+        writeln!(r, "is_paused: {}", self.is_paused.get()).ok();
+        writeln!(r, "death_time: {:?}", self.death_time.get()).ok();
+        writeln!(r, "pending_actions: {:?}", self.pending_actions.borrow()).ok();
+        writeln!(r, "update_counter: {:.4}", self.update_counter.get()).ok();
+        writeln!(r, "queue_counter: {:.4}", self.queue_counter.get()).ok();
+        writeln!(r, "last_update_game_time: {:?}", self.last_update_game_time.get()).ok();
+        writeln!(r, "last_frame_game_time: {:?}", self.last_frame_game_time.get()).ok();
+
+        writeln!(r, "\n[environment]").ok();
+        writeln!(r, "temperature: {:.2}", self.environment.temperature.get()).ok();
+        writeln!(r, "wind_speed: {:.2}", self.environment.wind_speed.get()).ok();
+        writeln!(r, "rain_intensity: {:.2}", self.environment.rain_intensity.get()).ok();
+        writeln!(r, "is_daylight: {}", self.environment.is_daylight()).ok();
+
+        writeln!(r, "\n[player]").ok();
+        writeln!(r, "is_walking: {}", self.player_state.is_walking.get()).ok();
+        writeln!(r, "is_running: {}", self.player_state.is_running.get()).ok();
+        writeln!(r, "is_swimming: {}", self.player_state.is_swimming.get()).ok();
+        writeln!(r, "is_underwater: {}", self.player_state.is_underwater.get()).ok();
+        writeln!(r, "is_using_arms: {}", self.player_state.is_using_arms.get()).ok();
+        writeln!(r, "busy_hands: {}", self.player_state.busy_hands.get()).ok();
+        writeln!(r, "is_inside_shelter: {}", self.player_state.is_inside_shelter.get()).ok();
+        writeln!(r, "shelter_quality: {:.2}", self.player_state.shelter_quality.get()).ok();
+        writeln!(r, "is_near_heat_source: {}", self.player_state.is_near_heat_source.get()).ok();
+        writeln!(r, "reported_movement: {}", self.player_state.reported_movement()).ok();
+        writeln!(r, "exertion_noise: {}", self.player_state.exertion_noise()).ok();
+        writeln!(r, "are_hands_bloodied: {}", self.player_state.are_hands_bloodied()).ok();
+
+        writeln!(r, "\n[vitals]").ok();
+        writeln!(r, "profile: {:?}", self.health.profile()).ok();
+        writeln!(r, "traits: {:?}", self.traits.trait_names()).ok();
+        writeln!(r, "is_alive: {}", self.health.is_alive()).ok();
+        writeln!(r, "body_temperature: {:.4}", self.health.body_temperature()).ok();
+        writeln!(r, "heart_rate: {:.4}", self.health.heart_rate()).ok();
+        writeln!(r, "top_pressure: {:.4}", self.health.top_pressure()).ok();
+        writeln!(r, "bottom_pressure: {:.4}", self.health.bottom_pressure()).ok();
+        writeln!(r, "blood_level: {:.4}", self.health.blood_level()).ok();
+        writeln!(r, "food_level: {:.4}", self.health.food_level()).ok();
+        writeln!(r, "water_level: {:.4}", self.health.water_level()).ok();
+        writeln!(r, "stamina_level: {:.4}", self.health.stamina_level()).ok();
+        writeln!(r, "fatigue_level: {:.4}", self.health.fatigue_level()).ok();
+        writeln!(r, "true_fatigue_level: {:.4}", self.health.true_fatigue_level()).ok();
+        writeln!(r, "fatigue_debt: {:.4}", self.health.fatigue_debt()).ok();
+        writeln!(r, "oxygen_level: {:.4}", self.health.oxygen_level()).ok();
+        writeln!(r, "toxicity_level: {:.4}", self.health.toxicity_level()).ok();
+        writeln!(r, "has_blood_loss: {}", self.health.is_blood_loss()).ok();
+        writeln!(r, "stamina_regain_rate: {:.4}", self.health.stamina_regain_rate.get()).ok();
+        writeln!(r, "blood_regain_rate: {:.4}", self.health.blood_regain_rate.get()).ok();
+        writeln!(r, "oxygen_regain_rate: {:.4}", self.health.oxygen_regain_rate.get()).ok();
+
+        writeln!(r, "\n[diseases]").ok();
+        for (name, disease) in self.health.active_diseases.borrow().iter() {
+            writeln!(r, "- {}: active_level={:?}, is_healing={}, activation_time={}, end_time={:?}",
+                     name, disease.active_level(&game_time), disease.is_healing(),
+                     disease.activation_time(), disease.end_time()).ok();
+            writeln!(r, "{:#?}", disease.get_state()).ok();
+        }
+
+        writeln!(r, "\n[injuries]").ok();
+        for (key, injury) in self.health.active_injuries.borrow().iter() {
+            writeln!(r, "- {} on {}: active_level={:?}, is_healing={}, blood_stopped={}, activation_time={}, end_time={:?}",
+                     key.injury, key.body_part, injury.active_level(&game_time), injury.is_healing(),
+                     injury.is_blood_stopped(), injury.activation_time(), injury.end_time()).ok();
+            writeln!(r, "{:#?}", injury.get_state()).ok();
+        }
+
+        writeln!(r, "\n[conditions]").ok();
+        for (name, condition) in self.health.conditions.borrow().iter() {
+            writeln!(r, "- {}: activation_time={}, day={}", name, condition.activation_time(),
+                     condition.day(&game_time)).ok();
+        }
+
+        writeln!(r, "\n[medical agents]").ok();
+        writeln!(r, "{:#?}", self.health.medical_agents.get_state()).ok();
+
+        writeln!(r, "\n[body]").ok();
+        writeln!(r, "{:#?}", self.body.get_state()).ok();
+        writeln!(r, "mobility: {:.4}", self.body.mobility()).ok();
+        writeln!(r, "heat_stress: {:.4}", self.body.heat_stress()).ok();
+        writeln!(r, "peripheral_temperature: {:?}", self.body.peripheral_temperature()).ok();
+        writeln!(r, "custom body parts: {:?}", self.body.parts.all_parts().iter()
+            .filter(|p| matches!(p.body_part, crate::body::BodyPart::Custom(_))).collect::<Vec<_>>()).ok();
+
+        writeln!(r, "\n[inventory]").ok();
+        writeln!(r, "weight: {:.2}", self.inventory.get_weight()).ok();
+        for (name, item) in self.inventory.stored_items.borrow().iter() {
+            writeln!(r, "- {}: count={}, infinite={}, weight={:.2}", name, item.get_count(),
+                     item.get_is_infinite(), item.get_total_weight()).ok();
+        }
+
+        writeln!(r, "\n[skills]").ok();
+        for (name, skill) in self.skills.skills.borrow().iter() {
+            writeln!(r, "- {}: level={}, xp={:.2}", name, skill.level(), skill.xp()).ok();
+        }
+
+        writeln!(r, "\n[stats]").ok();
+        writeln!(r, "{:#?}", self.stats.get_state()).ok();
+
+        writeln!(r, "\n[queued messages]").ok();
+        for (node, q) in [
+            ("health", self.health.get_message_queue()),
+            ("body", self.body.get_message_queue()),
+            ("inventory", self.inventory.get_message_queue()),
+            ("skills", self.skills.get_message_queue())
+        ] {
+            for (id, event) in q.iter() {
+                writeln!(r, "- {} #{}: {:?}", node, id, event).ok();
+            }
+        }
+
+        r
+    }
+}
@@ -1,4 +1,5 @@
 /// Is used by `ActiveDisease/ActiveInjury.invert()` method
+#[derive(Debug)]
 pub enum ChainInvertErr {
     /// When calling `invert()` on already inverted chain
     AlreadyInverted,
@@ -11,6 +12,7 @@ pub enum ChainInvertErr {
 }
 
 /// Is used by `ActiveDisease/ActiveInjury.invert_back()` method
+#[derive(Debug)]
 pub enum ChainInvertBackErr {
     /// When calling `invert_back()` on already inverted back chain
     AlreadyInvertedBack,
@@ -22,7 +24,24 @@ pub enum ChainInvertBackErr {
     NoActiveStageAtGivenTime
 }
 
+/// Is used by `ActiveDisease/ActiveInjury.downgrade()` method
+#[derive(Debug)]
+pub enum ChainDowngradeErr {
+    /// When calling `downgrade()` on a chain that is already at its lowest stage
+    AlreadyAtLowestStage,
+    /// When calling `downgrade()` with time that is outside of disease
+    /// active time
+    DiseaseNotActiveAtGivenTime,
+    /// When calling `downgrade()` with time that is outside of injury
+    /// active time
+    InjuryNotActiveAtGivenTime,
+    /// When calling `downgrade()` with time that cannot be mapped to any
+    /// active stage
+    NoActiveStageAtGivenTime
+}
+
 /// Is used by `Health.spawn_disease` method
+#[derive(Debug)]
 pub enum SpawnDiseaseErr {
     /// When `spawn_disease` called on a dead character
     CharacterIsDead,
@@ -31,14 +50,20 @@ pub enum SpawnDiseaseErr {
 }
 
 /// Is used by `Health.spawn_injury` method
+#[derive(Debug)]
 pub enum SpawnInjuryErr {
     /// When `spawn_injury` called on a dead character
     CharacterIsDead,
     /// When injury you trying to spawn was already spawned on this body part
-    InjuryAlreadyAdded
+    InjuryAlreadyAdded,
+    /// When custom body part is not registered
+    UnknownBodyPart,
+    /// When `spawn_burn` is called with an injury that is not a burn
+    NotABurn
 }
 
 /// Is used by `Health.remove_disease` method
+#[derive(Debug)]
 pub enum RemoveDiseaseErr {
     /// When `remove_disease` called on a dead character
     CharacterIsDead,
@@ -47,6 +72,7 @@ pub enum RemoveDiseaseErr {
 }
 
 /// Is used by `Health.remove_injury` method
+#[derive(Debug)]
 pub enum RemoveInjuryErr {
     /// When `remove_injury` called on a dead character
     CharacterIsDead,
@@ -56,26 +82,143 @@ pub enum RemoveInjuryErr {
 
 /// Is used by `Health.unregister_disease_monitor`, `unregister_side_effect_monitor`,
 /// `Inventory.unregister_monitor` methods
+#[derive(Debug)]
 pub enum UnregisterMonitorErr {
     /// When trying to unregister the monitor which id is nt registered
     MonitorIdNotFound
 }
 
+/// Is used by `Health.enable_monitor_pack`, `disable_monitor_pack` and
+/// `unregister_monitor_pack` methods
+#[derive(Debug)]
+pub enum MonitorPackErr {
+    /// When given pack handle is not registered
+    PackNotFound
+}
+
 /// Is used by `Inventory.remove_item` methods
+#[derive(Debug)]
 pub enum InventoryItemAccessErr {
     /// When given item key was not found in the inventory
     ItemNotFound
 }
 
 /// Is used by `Inventory.use_item` methods
+#[derive(Debug)]
 pub enum InventoryUseErr {
     /// When given item key was not found in the inventory
     ItemNotFound,
     /// When requested amount is greater that the actual items count
-    InsufficientResources
+    InsufficientResources,
+    /// When requested amount can only be taken from the locked units
+    /// # Parameters
+    /// - Worn or equipped units count (see `Inventory.equip_item`)
+    /// - Reserved units count (see `Inventory.reserve_item`)
+    ItemLocked(usize, usize)
+}
+
+/// Is used by `Inventory.reserve_item` and `Inventory.release_item` methods
+#[derive(Debug)]
+pub enum ItemReserveErr {
+    /// When given item key was not found in the inventory
+    ItemNotFound,
+    /// When there are not enough unlocked units to reserve
+    InsufficientResources,
+    /// When given item kind has no reserved units
+    NotReserved
+}
+
+/// Is used by `Inventory.equip_item` and `Inventory.unequip_item` methods
+#[derive(Debug)]
+pub enum ItemEquipErr {
+    /// When given item key was not found in the inventory
+    ItemNotFound,
+    /// When all units of the given item kind are worn or reserved
+    ItemLocked,
+    /// When given item kind is already equipped
+    AlreadyEquipped,
+    /// When given item kind is not equipped
+    NotEquipped
+}
+
+/// Is used by `Inventory.check_requirements` method
+#[derive(Debug)]
+pub enum UsageRequirementErr {
+    /// When given item key was not found in the inventory
+    ItemNotFound,
+    /// When skill level is lower than needed
+    /// # Parameters
+    /// - Skill unique name
+    /// - Minimum skill level needed
+    SkillLevelTooLow(String, usize),
+    /// When item needs both hands free, but at least one is busy or lost
+    NoFreeHands,
+    /// When item cannot be used under the water
+    UsedUnderwater
+}
+
+/// Is used by `ZaraController.try_use_tool` method
+#[derive(Debug)]
+pub enum ToolUseErr {
+    /// When tool is used by a dead character
+    CharacterIsDead,
+    /// When instance is paused
+    InstancePaused,
+    /// When given item key was not found in the inventory
+    ItemNotFound,
+    /// When item is not a tool
+    ItemIsNotTool,
+    /// When item requirements are not met
+    RequirementsNotMet(UsageRequirementErr),
+    /// When tool that is used up could not be used
+    CouldNotUseItem(InventoryUseErr)
+}
+
+/// Is used by `Inventory.set_active_light` method
+#[derive(Debug)]
+pub enum LightErr {
+    /// When given item key was not found in the inventory
+    ItemNotFound,
+    /// When item is not a light source
+    ItemIsNotLight,
+    /// When light burns fuel that is not in the inventory
+    NoFuel,
+    /// When light cannot burn under the water
+    Underwater,
+    /// When rain is too heavy for this light outside of a shelter
+    TooRainy
+}
+
+/// Is used by `Inventory.set_active_electronic` method
+#[derive(Debug)]
+pub enum ElectronicErr {
+    /// When given item key was not found in the inventory
+    ItemNotFound,
+    /// When item is not an electronic device
+    ItemIsNotElectronic,
+    /// When device has no charge left
+    NoCharge
+}
+
+/// Is used by `Inventory.recharge` method
+#[derive(Debug)]
+pub enum RechargeErr {
+    /// When given item key was not found in the inventory
+    ItemNotFound,
+    /// When item is not an electronic device
+    ItemIsNotElectronic,
+    /// When charge source item was not found in the inventory
+    SourceNotFound,
+    /// When source item is not a charge source or cannot recharge this device
+    SourceNotCompatible,
+    /// When device is fully charged already
+    FullyCharged,
+    /// When charge source that is used up could not be used
+    CouldNotUseItem(InventoryUseErr)
 }
 
 /// Is used by `ZaraController.consume` method
+#[derive(Debug)]
 pub enum ItemConsumeErr {
     /// When `consume` called on a dead character
     CharacterIsDead,
@@ -88,10 +231,13 @@ pub enum ItemConsumeErr {
     /// When could not update item count
     CouldNotUseItem(InventoryUseErr),
     /// When controller is paused
-    InstancePaused
+    InstancePaused,
+    /// When item usage requirements are not met
+    RequirementsNotMet(UsageRequirementErr)
 }
 
 /// Is used by `ZaraController.take_appliance` method
+#[derive(Debug)]
 pub enum ApplianceTakeErr {
     /// When `take_appliance` called on a dead character
     CharacterIsDead,
@@ -108,20 +254,34 @@ pub enum ApplianceTakeErr {
     /// Is this kind of body appliance already applied to a given body part
     AlreadyApplied,
     /// When controller is paused
-    InstancePaused
+    InstancePaused,
+    /// When body part is lost (or already has a prosthetic) and appliance is not a prosthetic
+    BodyPartLost,
+    /// When prosthetic is applied to a body part that is not lost
+    BodyPartNotLost,
+    /// When tourniquet is applied to a body part that is not a limb
+    NotALimb,
+    /// When limb already has a tourniquet
+    TourniquetAlreadyApplied,
+    /// When item usage requirements are not met
+    RequirementsNotMet(UsageRequirementErr)
 }
 
-/// Is used by `ZaraController.remove_appliance` method
+/// Is used by `ZaraController.remove_appliance` and `ZaraController.remove_appliance_with_return` methods
+#[derive(Debug)]
 pub enum ApplianceRemoveErr {
     /// When `remove_appliance` called on a dead character
     CharacterIsDead,
     /// When given appliance kind is not found on a body part
     ApplianceNotFound,
+    /// When appliance should go back to the inventory, but no return factory is registered for it
+    NotReturnable,
     /// When controller is paused
     InstancePaused
 }
 
 /// Is used by `ZaraController.update` method
+#[derive(Debug)]
 pub enum ZaraUpdateErr {
     /// When `update` called on a dead character
     CharacterIsDead,
@@ -129,17 +289,55 @@ pub enum ZaraUpdateErr {
     InstancePaused
 }
 
-/// Is used by `MedicalAgentsMonitor.is_active` method
+/// Is used by `CharacterManager.add_character` method
+#[derive(Debug)]
+pub enum CharacterAddErr {
+    /// When character with this id is already managed
+    AlreadyExists
+}
+
+/// Is used by `ZaraController.barter` method. Every variant tells which side of the barter
+/// it is about
+#[derive(Debug)]
+pub enum BarterErr {
+    /// When one of the characters is dead
+    CharacterIsDead(crate::barter::BarterSide),
+    /// When one of the instances is paused
+    InstancePaused(crate::barter::BarterSide),
+    /// When offered item was not found in the inventory
+    ItemNotFound(crate::barter::BarterSide, String),
+    /// When there are not enough units that are not worn, equipped or reserved
+    InsufficientResources(crate::barter::BarterSide, String),
+    /// When offered item does not support `clone_boxed`
+    ItemIsNotCloneable(crate::barter::BarterSide, String),
+    /// When inventory would be heavier than its limit after the exchange
+    WeightLimitExceeded(crate::barter::BarterSide),
+    /// When offered item could not be taken out of the inventory
+    CouldNotUseItem(crate::barter::BarterSide, InventoryUseErr)
+}
+
+/// Is used by `MedicalAgentsMonitor.is_active` and `Health.unregister_medical_agent` methods
+#[derive(Debug)]
 pub enum MedicalAgentErr {
     /// When given medical agent key was not found
     AgentNotFound
 }
 
+/// Is used by `Health.register_stimulant` and `Health.unregister_stimulant` methods
+#[derive(Debug)]
+pub enum StimulantErr {
+    /// When given medical agent key was not found
+    AgentNotFound,
+    /// When given medical agent has no stimulant effect bound
+    StimulantNotFound
+}
+
 /// Is used by `ZaraController.put_on_clothes` method
+#[derive(Debug)]
 pub enum ClothesOnActionErr {
     /// When given item key was not found
     ItemNotFound,
-    /// When item count is zero
+    /// When item count is zero or all units are reserved (see `Inventory.reserve_item`)
     InsufficientResources,
     /// When given clothes is already on
     AlreadyHaveThisItemOn,
@@ -152,6 +350,7 @@ pub enum ClothesOnActionErr {
 }
 
 /// Is used by `ZaraController.take_off_clothes` method
+#[derive(Debug)]
 pub enum ClothesOffActionErr {
     /// When given item key was not found
     ItemNotFound,
@@ -176,12 +375,14 @@ pub(crate) enum RequestClothesOffErr {
 }
 
 /// Is used by `declare_dead` method
+#[derive(Debug)]
 pub enum DeclareDeadErr {
     /// When controller is paused
     InstancePaused
 }
 
 /// Is used by `Inventory.check_for_resources` method
+#[derive(Debug)]
 pub enum CheckForResourcesErr {
     /// When given combination key was not found
     CombinationNotFound,
@@ -189,15 +390,507 @@ pub enum CheckForResourcesErr {
     ItemNotFound(String),
     /// When a particular item in a combination recipe count is less that the count needed
     /// for this combination to be executed
-    InsufficientResources(String)
+    InsufficientResources(String),
+    /// When a particular item in a combination recipe has enough count, but some of it
+    /// is worn or reserved (see `Inventory.reserve_item`)
+    ItemLocked(String)
+}
+
+/// Is used by `Inventory.check_constraints` method
+#[derive(Debug)]
+pub enum RecipeConstraintErr {
+    /// When given combination key was not found
+    CombinationNotFound,
+    /// When combination needs daylight, but it is night now
+    NoDaylight,
+    /// When combination needs shelter from rain, but it is raining and player is not in a shelter
+    NoShelter,
+    /// When combination needs a heat source, but player is not near one
+    NoHeatSource
 }
 
 /// Is used by `Inventory.execute_combination` method
+#[derive(Debug)]
 pub enum CombinationExecuteErr {
     /// When resources check failed
     ResourceError(CheckForResourcesErr),
+    /// When recipe constraints check failed
+    ConstraintError(RecipeConstraintErr),
     /// When failed to properly use an item (count mismatch for example)
     UseItemError(InventoryUseErr),
+    /// When given combination key was not found
+    CombinationNotFound,
+    /// When usage requirements of a combination item are not met
+    /// # Parameters
+    /// - Item unique name
+    /// - Requirement that is not met
+    RequirementsNotMet(String, UsageRequirementErr)
+}
+
+/// Is used by `ZaraController.get_state_since` and `ZaraController.apply_delta` methods
+#[derive(Debug)]
+pub enum StateDeltaErr {
+    /// When snapshot with the given id was never captured or is too old
+    SnapshotNotFound,
+    /// When delta was taken against a snapshot other than the one restored or applied last
+    BaseSnapshotMismatch
+}
+
+/// Is used by `ZaraController.restore_full_state` method
+#[derive(Debug)]
+pub enum FullStateRestoreErr {
+    /// When some diseases, injuries or inventory items could not be recreated. Everything else
+    /// is restored
+    /// # Parameters
+    /// - Ids of the diseases and injuries that have no registered factory
+    /// - Names of the inventory items that are not in the inventory
+    PartiallyRestored(Vec<String>, Vec<String>)
+}
+
+/// Is used by `StateMigrator.migrate` method
+#[derive(Debug)]
+pub enum MigrationErr {
+    /// When serialized state version is newer than the target version
+    VersionNotSupported(u32),
+    /// When there is no registered migration step for the given source version
+    StepNotFound(u32),
+    /// When serialized data cannot be read or converted
+    InvalidData(String),
+    /// When state format cannot encode states, but a migration step needs it
+    EncodeNotSupported
+}
+
+/// Is used by `ZaraController.resume_with_results` method
+#[derive(Debug)]
+pub enum PendingActionErr {
+    /// When queued `consume` call failed
+    Consume(ItemConsumeErr),
+    /// When queued `take_appliance` call failed
+    TakeAppliance(ApplianceTakeErr),
+    /// When queued `remove_appliance` call failed
+    RemoveAppliance(ApplianceRemoveErr),
+    /// When queued `put_on_clothes` call failed
+    PutOnClothes(ClothesOnActionErr),
+    /// When queued `take_off_clothes` call failed
+    TakeOffClothes(ClothesOffActionErr)
+}
+
+/// Is used by `ZaraController.revive` method
+#[derive(Debug)]
+pub enum ReviveErr {
+    /// When `revive` called on a living character
+    CharacterIsAlive
+}
+
+/// Is used by `Skills.add_xp` method
+#[derive(Debug)]
+pub enum SkillXpErr {
+    /// When skill with the given name is not registered
+    SkillNotFound
+}
+
+/// Is used by `Health.start_condition` method
+#[derive(Debug)]
+pub enum StartConditionErr {
+    /// When `start_condition` called on a dead character
+    CharacterIsDead,
+    /// When condition you trying to start was already started
+    ConditionAlreadyAdded
+}
+
+/// Is used by `Health.end_condition` method
+#[derive(Debug)]
+pub enum EndConditionErr {
+    /// When `end_condition` called on a dead character
+    CharacterIsDead,
+    /// When condition you trying to end was not found
+    ConditionNotFound
+}
+
+/// Is used by `BodyPartsRegistry.register_part` method
+#[derive(Debug)]
+pub enum RegisterBodyPartErr {
+    /// When body part with this name is already registered
+    NameAlreadyRegistered
+}
+
+/// Is used by `BodyPartsRegistry.unregister_part` method
+#[derive(Debug)]
+pub enum UnregisterBodyPartErr {
+    /// When body part is not registered
+    BodyPartNotFound,
+    /// When trying to unregister a built-in body part
+    BuiltInBodyPart
+}
+
+/// Is used by `ZaraController.amputate` method
+#[derive(Debug)]
+pub enum AmputateErr {
+    /// When `amputate` called on a dead character
+    CharacterIsDead,
+    /// When controller is paused
+    InstancePaused,
+    /// When body part is not registered
+    UnknownBodyPart,
+    /// When body part is already lost
+    AlreadyLost,
+    /// When phantom pain condition could not be started
+    CouldNotStartCondition(StartConditionErr)
+}
+
+/// Is used by `ZaraController.burn` method
+#[derive(Debug)]
+pub enum BurnErr {
+    /// When `burn` called on a dead character
+    CharacterIsDead,
+    /// When controller is paused
+    InstancePaused,
+    /// When body part is not registered
+    UnknownBodyPart,
+    /// When body part is lost
+    BodyPartLost,
+    /// When burn source is a heat source, but player is not near one
+    NoHeatSource,
+    /// When burn factory is not set
+    NoBurnFactory,
+    /// When burn injury could not be spawned
+    CouldNotSpawn(SpawnInjuryErr)
+}
+
+/// Is used by `Body.unregister_clothes_group` method
+#[derive(Debug)]
+pub enum UnregisterClothesGroupErr {
+    /// When clothes group with this name is not registered
+    GroupNotFound
+}
+
+/// Is used by `Inventory.unregister_combination` method
+#[derive(Debug)]
+pub enum UnregisterCombinationErr {
+    /// When given combination key was not found
+    CombinationNotFound
+}
+
+/// Is used by `Inventory.replace_combination` method
+#[derive(Debug)]
+pub enum ReplaceCombinationErr {
     /// When given combination key was not found
     CombinationNotFound
-}
\ No newline at end of file
+}
+/// Is used by `Health.perform_burst_action`, `hold_breath` and `shout` methods
+#[derive(Debug)]
+pub enum BurstActionErr {
+    /// When action is performed by a dead character
+    CharacterIsDead,
+    /// When stamina level is lower than the action cost
+    NotEnoughStamina,
+    /// When oxygen level is lower than the action cost
+    NotEnoughOxygen,
+    /// When an active injury on a body part needed for this action blocks it
+    /// # Parameters
+    /// - Injury name
+    /// - Injured body part
+    BlockedByInjury(String, crate::body::BodyPart)
+}
+
+/// Is used by `Inventory.duplicate_item` method
+#[derive(Debug)]
+pub enum ItemDuplicateErr {
+    /// When given item key was not found in the inventory
+    ItemNotFound,
+    /// When item does not support duplication (`clone_boxed` returns `None`)
+    ItemIsNotCloneable
+}
+
+/// Is used by `Inventory.transfer_item` method
+#[derive(Debug)]
+pub enum ItemTransferErr {
+    /// When source and target are the same inventory
+    SameInventory,
+    /// When given item key was not found in the inventory
+    ItemNotFound,
+    /// When there are not enough units that are not worn, equipped or reserved
+    InsufficientResources,
+    /// When item does not support duplication (`clone_boxed` returns `None`)
+    ItemIsNotCloneable,
+    /// When units could not be taken out of the inventory
+    CouldNotUseItem(InventoryUseErr)
+}
+
+/// Is used by `Stash.restore_state` method
+#[derive(Debug)]
+pub enum StashRestoreErr {
+    /// When some items could not be restored. Everything else is restored
+    /// # Parameters
+    /// - Names of the items that are not in the stash
+    PartiallyRestored(Vec<String>)
+}
+
+/// Is used by `Inventory.set_cleanliness` and `Inventory.clean_item` methods
+#[derive(Debug)]
+pub enum ItemCleanErr {
+    /// When given item key was not found in the inventory
+    ItemNotFound,
+    /// When item is boiled while player is not near a heat source
+    NoHeatSource
+}
+
+/// Is used by `Inventory.set_age` method
+#[derive(Debug)]
+pub enum ItemFreshnessErr {
+    /// When given item key was not found in the inventory
+    ItemNotFound,
+    /// When item is not a spoiling or expiring consumable
+    ItemDoesNotSpoil
+}
+
+/// Is used by `Inventory.set_contamination` method
+#[derive(Debug)]
+pub enum ItemContaminationErr {
+    /// When given item key was not found in the inventory
+    ItemNotFound,
+    /// When item is not a water consumable
+    ItemIsNotWater
+}
+
+/// Is used by `ZaraController.perform_procedure_step` and `Health.cancel_procedure` methods
+#[derive(Debug)]
+pub enum ProcedureStepErr {
+    /// When procedure is performed by a dead character
+    CharacterIsDead,
+    /// When instance is paused
+    InstancePaused,
+    /// When procedure with a given name is not registered
+    ProcedureNotFound,
+    /// When character has no such injury on a given body part
+    InjuryNotFound,
+    /// When another procedure is already started on this injury
+    AnotherProcedureInProgress,
+    /// When the current step of the procedure is still being performed
+    StepInProgress,
+    /// When no procedure is started on this injury
+    ProcedureNotStarted,
+    /// When inventory does not have all items the step consumes
+    InsufficientResources,
+    /// When one of the step items could not be used
+    CouldNotUseItem(InventoryUseErr)
+}
+
+/// Is used by `DataLoader` methods. Requires the `data` feature
+#[cfg(feature = "data")]
+#[derive(Debug)]
+pub enum DataLoadErr {
+    /// When data file cannot be read
+    /// # Parameters
+    /// - File name
+    /// - Reason
+    CannotReadFile(String, String),
+    /// When data file is not a valid document or does not match the expected layout
+    /// # Parameters
+    /// - File name
+    /// - Line
+    /// - Column
+    /// - Reason
+    SyntaxError(String, usize, usize, String),
+    /// When a definition is well-formed but describes invalid content
+    /// # Parameters
+    /// - File name
+    /// - Line of the definition
+    /// - Path to the definition, like `diseases[0].stages[2]`
+    /// - Reason
+    InvalidDefinition(String, usize, String, String)
+}
+#[cfg(feature = "data")]
+impl std::fmt::Display for DataLoadErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataLoadErr::CannotReadFile(file, reason) => write!(f, "{}: {}", file, reason),
+            DataLoadErr::SyntaxError(file, line, column, reason) =>
+                write!(f, "{}:{}:{}: {}", file, line, column, reason),
+            DataLoadErr::InvalidDefinition(file, line, path, reason) =>
+                write!(f, "{}:{}: {}: {}", file, line, path, reason)
+        }
+    }
+}
+
+/// Builds [`ZaraError`] with a variant and a `From` conversion for every error type, and makes
+/// every error type a `std::error::Error`. Error types with a message get `Display` that shows
+/// the message and the error case
+macro_rules! zara_error {
+    ($($(#[doc = $doc:literal])* $(#[cfg($cfg:meta)])? $variant:ident($err:ident) $(=> $what:literal)?),* $(,)?) => {
+        /// Any Zara error. Every error type converts into it, so Zara calls can be chained with
+        /// `?` in functions that return `Result<_, ZaraError>`. Every error type is also a
+        /// `std::error::Error`, so `?` works with any error based result (like `anyhow::Result`)
+        ///
+        /// # Examples
+        /// ```
+        /// use zara::error::ZaraError;
+        ///
+        /// fn eat_and_bandage(person: &ZaraController<L>) -> Result<(), ZaraError> {
+        ///     person.consume(&meat_name)?;
+        ///     person.take_appliance(&bandage_name, BodyPart::Forearm)?;
+        ///
+        ///     Ok(())
+        /// }
+        /// ```
+        #[derive(Debug)]
+        pub enum ZaraError {
+            $(
+                $(#[doc = $doc])*
+                $(#[cfg($cfg)])?
+                $variant($err)
+            ),*
+        }
+        impl std::fmt::Display for ZaraError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(
+                        $(#[cfg($cfg)])?
+                        ZaraError::$variant(e) => write!(f, "{}", e)
+                    ),*
+                }
+            }
+        }
+        impl std::error::Error for ZaraError {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                match self {
+                    $(
+                        $(#[cfg($cfg)])?
+                        ZaraError::$variant(e) => Some(e)
+                    ),*
+                }
+            }
+        }
+        $(
+            $(#[cfg($cfg)])?
+            impl From<$err> for ZaraError {
+                fn from(e: $err) -> Self { ZaraError::$variant(e) }
+            }
+            $(#[cfg($cfg)])?
+            impl std::error::Error for $err { }
+            $(
+                impl std::fmt::Display for $err {
+                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(f, "{}: {:?}", $what, self)
+                    }
+                }
+            )?
+        )*
+    };
+}
+
+zara_error! {
+    /// Disease or injury chain could not be inverted
+    ChainInvert(ChainInvertErr) => "Could not invert the chain",
+    /// Disease or injury chain could not be inverted back
+    ChainInvertBack(ChainInvertBackErr) => "Could not invert the chain back",
+    /// Disease or injury chain could not be downgraded
+    ChainDowngrade(ChainDowngradeErr) => "Could not downgrade the chain",
+    /// Disease could not be spawned
+    SpawnDisease(SpawnDiseaseErr) => "Could not spawn the disease",
+    /// Injury could not be spawned
+    SpawnInjury(SpawnInjuryErr) => "Could not spawn the injury",
+    /// Disease could not be removed
+    RemoveDisease(RemoveDiseaseErr) => "Could not remove the disease",
+    /// Injury could not be removed
+    RemoveInjury(RemoveInjuryErr) => "Could not remove the injury",
+    /// Monitor could not be unregistered
+    UnregisterMonitor(UnregisterMonitorErr) => "Could not unregister the monitor",
+    /// Monitor pack could not be changed
+    MonitorPack(MonitorPackErr) => "Could not change the monitor pack",
+    /// Inventory item could not be accessed
+    InventoryItemAccess(InventoryItemAccessErr) => "Could not access the inventory item",
+    /// Inventory item could not be used
+    InventoryUse(InventoryUseErr) => "Could not use the inventory item",
+    /// Item could not be reserved or released
+    ItemReserve(ItemReserveErr) => "Could not reserve the item",
+    /// Item could not be equipped or unequipped
+    ItemEquip(ItemEquipErr) => "Could not equip the item",
+    /// Item usage requirements are not met
+    UsageRequirement(UsageRequirementErr) => "Item requirements are not met",
+    /// Tool could not be used
+    ToolUse(ToolUseErr) => "Could not use the tool",
+    /// Light could not be turned on or off
+    Light(LightErr) => "Could not switch the light",
+    /// Electronic device could not be turned on or off
+    Electronic(ElectronicErr) => "Could not switch the device",
+    /// Electronic device could not be recharged
+    Recharge(RechargeErr) => "Could not recharge the device",
+    /// Item could not be consumed
+    ItemConsume(ItemConsumeErr) => "Could not consume the item",
+    /// Appliance could not be taken
+    ApplianceTake(ApplianceTakeErr) => "Could not take the appliance",
+    /// Appliance could not be removed
+    ApplianceRemove(ApplianceRemoveErr) => "Could not remove the appliance",
+    /// Controller could not be updated
+    ZaraUpdate(ZaraUpdateErr) => "Could not update the controller",
+    /// Character could not be added to the manager
+    CharacterAdd(CharacterAddErr) => "Could not add the character",
+    /// Items could not be bartered
+    Barter(BarterErr) => "Could not barter the items",
+    /// Medical agent could not be checked or unregistered
+    MedicalAgent(MedicalAgentErr) => "Medical agent error",
+    /// Stimulant could not be registered or unregistered
+    Stimulant(StimulantErr) => "Stimulant error",
+    /// Clothes could not be put on
+    ClothesOnAction(ClothesOnActionErr) => "Could not put on the clothes",
+    /// Clothes could not be taken off
+    ClothesOffAction(ClothesOffActionErr) => "Could not take off the clothes",
+    /// Character could not be declared dead
+    DeclareDead(DeclareDeadErr) => "Could not declare the character dead",
+    /// Crafting resources check failed
+    CheckForResources(CheckForResourcesErr) => "Not enough resources",
+    /// Recipe constraints are not met
+    RecipeConstraint(RecipeConstraintErr) => "Recipe constraints are not met",
+    /// Crafting combination could not be executed
+    CombinationExecute(CombinationExecuteErr) => "Could not execute the combination",
+    /// State delta could not be captured
+    StateDelta(StateDeltaErr) => "Could not capture or apply the state delta",
+    /// Full state could not be restored completely
+    FullStateRestore(FullStateRestoreErr) => "Could not restore the full state",
+    /// State could not be migrated
+    Migration(MigrationErr) => "Could not migrate the state",
+    /// Queued action failed
+    PendingAction(PendingActionErr) => "Queued action failed",
+    /// Character could not be revived
+    Revive(ReviveErr) => "Could not revive the character",
+    /// Skill experience could not be added
+    SkillXp(SkillXpErr) => "Could not add skill experience",
+    /// Condition could not be started
+    StartCondition(StartConditionErr) => "Could not start the condition",
+    /// Condition could not be ended
+    EndCondition(EndConditionErr) => "Could not end the condition",
+    /// Body part could not be registered
+    RegisterBodyPart(RegisterBodyPartErr) => "Could not register the body part",
+    /// Body part could not be unregistered
+    UnregisterBodyPart(UnregisterBodyPartErr) => "Could not unregister the body part",
+    /// Body part could not be amputated
+    Amputate(AmputateErr) => "Could not amputate the body part",
+    /// Burn could not be applied
+    Burn(BurnErr) => "Could not apply the burn",
+    /// Clothes group could not be unregistered
+    UnregisterClothesGroup(UnregisterClothesGroupErr) => "Could not unregister the clothes group",
+    /// Crafting combination could not be unregistered
+    UnregisterCombination(UnregisterCombinationErr) => "Could not unregister the combination",
+    /// Crafting combination could not be replaced
+    ReplaceCombination(ReplaceCombinationErr) => "Could not replace the combination",
+    /// Burst action could not be performed
+    BurstAction(BurstActionErr) => "Could not perform the burst action",
+    /// Item could not be duplicated
+    ItemDuplicate(ItemDuplicateErr) => "Could not duplicate the item",
+    /// Item could not be moved to another inventory
+    ItemTransfer(ItemTransferErr) => "Could not transfer the item",
+    /// Stash state could not be restored completely
+    StashRestore(StashRestoreErr) => "Could not restore the stash",
+    /// Item cleanliness could not be changed
+    ItemClean(ItemCleanErr) => "Could not clean the item",
+    /// Item freshness could not be changed
+    ItemFreshness(ItemFreshnessErr) => "Could not change the item freshness",
+    /// Item contamination could not be changed
+    ItemContamination(ItemContaminationErr) => "Could not change the item contamination",
+    /// Procedure step could not be performed
+    ProcedureStep(ProcedureStepErr) => "Could not perform the procedure step",
+    /// Data could not be loaded. Requires the `data` feature
+    #[cfg(feature = "data")]
+    DataLoad(DataLoadErr)
+}
@@ -0,0 +1,206 @@
+use crate::health::Health;
+use crate::body::BodyPart;
+use crate::error::BurstActionErr;
+use crate::state::DirtyTracked;
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Describes a short action that costs a burst of stamina and/or oxygen, like shouting
+/// or diving. After the action, regain of the spent vitals restores linearly during the
+/// recovery time
+#[derive(Clone, Debug, Default)]
+pub struct BurstActionC {
+    /// Stamina spent at once (0..100)
+    pub stamina_cost: f32,
+    /// Oxygen spent at once (0..100)
+    pub oxygen_cost: f32,
+    /// For how long (game seconds) regain of the spent vitals is suppressed. Regain rate grows
+    /// linearly from zero to normal during this time
+    pub recovery_time: f32,
+    /// Body parts needed for this action. Active injury on any of them blocks the action
+    pub body_parts: Vec<BodyPart>
+}
+impl fmt::Display for BurstActionC {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Burst action: stamina -{:.1}, oxygen -{:.1}, recovery {:.0}s", self.stamina_cost,
+               self.oxygen_cost, self.recovery_time)
+    }
+}
+impl Eq for BurstActionC { }
+impl PartialEq for BurstActionC {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.body_parts == other.body_parts &&
+        f32::abs(self.stamina_cost - other.stamina_cost) < EPS &&
+        f32::abs(self.oxygen_cost - other.oxygen_cost) < EPS &&
+        f32::abs(self.recovery_time - other.recovery_time) < EPS
+    }
+}
+impl Hash for BurstActionC {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.body_parts.hash(state);
+
+        state.write_u32((self.stamina_cost*10_000_f32) as u32);
+        state.write_u32((self.oxygen_cost*10_000_f32) as u32);
+        state.write_u32((self.recovery_time*10_000_f32) as u32);
+    }
+}
+impl BurstActionC {
+    /// Holding breath before diving: big oxygen cost, blocked by chest and throat injuries
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::actions::BurstActionC;
+    ///
+    /// let o = BurstActionC::hold_breath();
+    /// ```
+    pub fn hold_breath() -> Self {
+        BurstActionC {
+            stamina_cost: 2.,
+            oxygen_cost: 15.,
+            recovery_time: 30.,
+            body_parts: vec![BodyPart::Throat, BodyPart::LeftChest, BodyPart::RightChest]
+        }
+    }
+
+    /// Shouting: stamina and oxygen cost, blocked by throat injuries
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::actions::BurstActionC;
+    ///
+    /// let o = BurstActionC::shout();
+    /// ```
+    pub fn shout() -> Self {
+        BurstActionC {
+            stamina_cost: 5.,
+            oxygen_cost: 5.,
+            recovery_time: 10.,
+            body_parts: vec![BodyPart::Throat]
+        }
+    }
+}
+
+/// Regain suppression left after a burst action
+#[derive(Copy, Clone, Debug, Default)]
+pub(crate) struct BurstRecovery {
+    /// Game seconds left until regain is normal again
+    left: f32,
+    /// Full recovery time, game seconds
+    duration: f32
+}
+impl BurstRecovery {
+    /// Regain rate multiplier (0..1)
+    fn regain_factor(&self) -> f32 {
+        if self.duration <= 0. { return 1.; }
+
+        crate::utils::clamp_01(1. - self.left / self.duration)
+    }
+
+    fn progress(&self, game_time_delta: f32) -> Self {
+        BurstRecovery {
+            left: crate::utils::clamp_bottom(self.left - game_time_delta, 0.),
+            duration: self.duration
+        }
+    }
+
+    /// Longer of the current and the new recovery wins
+    fn restart(&self, duration: f32) -> Self {
+        if self.left > duration { *self } else { BurstRecovery { left: duration, duration } }
+    }
+}
+
+impl Health {
+    /// Holds breath, for example before diving. Spends a burst of oxygen that recovers slowly
+    ///
+    /// # Returns
+    /// Ok on success, or a reason why character cannot do it now
+    ///
+    /// # Examples
+    /// ```
+    /// if person.health.hold_breath().is_ok() {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn hold_breath(&self) -> Result<(), BurstActionErr> {
+        self.perform_burst_action(&BurstActionC::hold_breath())
+    }
+
+    /// Shouts. Spends a burst of stamina and oxygen that recover slowly
+    ///
+    /// # Returns
+    /// Ok on success, or a reason why character cannot do it now
+    ///
+    /// # Examples
+    /// ```
+    /// if person.health.shout().is_ok() {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn shout(&self) -> Result<(), BurstActionErr> {
+        self.perform_burst_action(&BurstActionC::shout())
+    }
+
+    /// Performs a custom short action that costs a burst of stamina and/or oxygen
+    ///
+    /// # Parameters
+    /// - `action`: action costs and needed body parts
+    ///
+    /// # Returns
+    /// Ok on success, or a reason why character cannot do it now
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::actions::BurstActionC;
+    ///
+    /// let jump = BurstActionC { stamina_cost: 8., recovery_time: 5., ..Default::default() };
+    ///
+    /// person.health.perform_burst_action(&jump);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `injuries` collection
+    pub fn perform_burst_action(&self, action: &BurstActionC) -> Result<(), BurstActionErr> {
+        self.mark_dirty();
+
+        if !self.is_alive() { return Err(BurstActionErr::CharacterIsDead); }
+
+        {
+            let game_time = self.last_update_game_time.borrow();
+
+            for (key, injury) in self.active_injuries.borrow().iter() {
+                if action.body_parts.contains(&key.body_part) && injury.is_active(&game_time) {
+                    return Err(BurstActionErr::BlockedByInjury(key.injury.to_string(), key.body_part));
+                }
+            }
+        }
+
+        if self.stamina_level.get() < action.stamina_cost { return Err(BurstActionErr::NotEnoughStamina); }
+        if self.oxygen_level.get() < action.oxygen_cost { return Err(BurstActionErr::NotEnoughOxygen); }
+
+        if action.stamina_cost > 0. {
+            self.stamina_level.set(self.stamina_level.get() - action.stamina_cost);
+            self.stamina_recovery.set(self.stamina_recovery.get().restart(action.recovery_time));
+        }
+        if action.oxygen_cost > 0. {
+            self.oxygen_level.set(self.oxygen_level.get() - action.oxygen_cost);
+            self.oxygen_recovery.set(self.oxygen_recovery.get().restart(action.recovery_time));
+        }
+
+        Ok(())
+    }
+
+    /// Stamina regain multiplier after burst actions (0..1)
+    pub(crate) fn stamina_recovery_factor(&self) -> f32 { self.stamina_recovery.get().regain_factor() }
+
+    /// Oxygen regain multiplier after burst actions (0..1)
+    pub(crate) fn oxygen_recovery_factor(&self) -> f32 { self.oxygen_recovery.get().regain_factor() }
+
+    /// Advances burst actions recovery
+    pub(crate) fn progress_burst_recovery(&self, game_time_delta: f32) {
+        self.stamina_recovery.set(self.stamina_recovery.get().progress(game_time_delta));
+        self.oxygen_recovery.set(self.oxygen_recovery.get().progress(game_time_delta));
+    }
+}
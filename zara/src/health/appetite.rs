@@ -0,0 +1,194 @@
+use crate::health::Health;
+use crate::utils::GameTimeC;
+use crate::utils::event::{Dispatcher, Event, Listener};
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// How strongly character wants to eat or drink
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
+pub enum Urgency {
+    /// Character is fed (or watered) enough
+    #[default]
+    None,
+    /// Character could eat (or drink) something
+    Mild,
+    /// Character needs to eat (or drink) soon
+    Strong,
+    /// Character must eat (or drink) right now
+    Severe
+}
+impl fmt::Display for Urgency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Food or water levels (0..100) below which a given urgency is signaled
+///
+/// # Examples
+/// ```
+/// use zara::health::appetite::UrgencyThresholds;
+///
+/// person.health.set_hunger_thresholds(UrgencyThresholds {
+///     mild: 50.,
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct UrgencyThresholds {
+    /// Level below which urgency is `Mild`. 60 by default
+    pub mild: f32,
+    /// Level below which urgency is `Strong`. 30 by default
+    pub strong: f32,
+    /// Level below which urgency is `Severe`. 10 by default
+    pub severe: f32
+}
+impl Default for UrgencyThresholds {
+    fn default() -> Self {
+        UrgencyThresholds {
+            mild: 60.,
+            strong: 30.,
+            severe: 10.
+        }
+    }
+}
+impl fmt::Display for UrgencyThresholds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Mild <{:.0}, strong <{:.0}, severe <{:.0}", self.mild, self.strong, self.severe)
+    }
+}
+impl Eq for UrgencyThresholds { }
+impl PartialEq for UrgencyThresholds {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        f32::abs(self.mild - other.mild) < EPS &&
+        f32::abs(self.strong - other.strong) < EPS &&
+        f32::abs(self.severe - other.severe) < EPS
+    }
+}
+impl Hash for UrgencyThresholds {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u32((self.mild*10_000_f32) as u32);
+        state.write_u32((self.strong*10_000_f32) as u32);
+        state.write_u32((self.severe*10_000_f32) as u32);
+    }
+}
+impl UrgencyThresholds {
+    /// Returns urgency for a given food or water level
+    ///
+    /// # Parameters
+    /// - `level`: food or water level (0..100)
+    ///
+    /// # Examples
+    /// ```
+    /// let urgency = thresholds.urgency_of(person.health.food_level());
+    /// ```
+    pub fn urgency_of(&self, level: f32) -> Urgency {
+        if level < self.severe { return Urgency::Severe; }
+        if level < self.strong { return Urgency::Strong; }
+        if level < self.mild { return Urgency::Mild; }
+
+        Urgency::None
+    }
+}
+
+impl Health {
+    /// Returns how strongly character wants to eat
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.health.hunger_urgency();
+    /// ```
+    pub fn hunger_urgency(&self) -> Urgency {
+        self.hunger_thresholds.get().urgency_of(self.food_level.get())
+    }
+
+    /// Returns how strongly character wants to drink
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.health.thirst_urgency();
+    /// ```
+    pub fn thirst_urgency(&self) -> Urgency {
+        self.thirst_thresholds.get().urgency_of(self.water_level.get())
+    }
+
+    /// Sets food levels at which `HungerUrgencyChanged` event is sent
+    ///
+    /// # Parameters
+    /// - `thresholds`: food level thresholds
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.set_hunger_thresholds(UrgencyThresholds::default());
+    /// ```
+    pub fn set_hunger_thresholds(&self, thresholds: UrgencyThresholds) { self.hunger_thresholds.set(thresholds); }
+
+    /// Returns food levels at which `HungerUrgencyChanged` event is sent
+    ///
+    /// # Examples
+    /// ```
+    /// let thresholds = person.health.hunger_thresholds();
+    /// ```
+    pub fn hunger_thresholds(&self) -> UrgencyThresholds { self.hunger_thresholds.get() }
+
+    /// Sets water levels at which `ThirstUrgencyChanged` event is sent
+    ///
+    /// # Parameters
+    /// - `thresholds`: water level thresholds
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.set_thirst_thresholds(UrgencyThresholds::default());
+    /// ```
+    pub fn set_thirst_thresholds(&self, thresholds: UrgencyThresholds) { self.thirst_thresholds.set(thresholds); }
+
+    /// Returns water levels at which `ThirstUrgencyChanged` event is sent
+    ///
+    /// # Examples
+    /// ```
+    /// let thresholds = person.health.thirst_thresholds();
+    /// ```
+    pub fn thirst_thresholds(&self) -> UrgencyThresholds { self.thirst_thresholds.get() }
+
+    /// Returns part (0..1) of the food gain that character gets when eating. Active disease
+    /// stages with [`with_appetite_loss`](crate::health::disease::fluent::StageEnd::with_appetite_loss)
+    /// make eating while sick less effective; the strongest appetite loss wins
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.health.appetite_factor();
+    /// ```
+    pub fn appetite_factor(&self) -> f32 {
+        let game_time = *self.last_update_game_time.borrow();
+
+        self.appetite_factor_at(&game_time)
+    }
+
+    pub(crate) fn appetite_factor_at(&self, game_time: &GameTimeC) -> f32 {
+        let loss = self.active_diseases.borrow().values()
+            .filter(|d| d.is_active(game_time))
+            .filter_map(|d| d.get_active_stage(game_time))
+            .map(|s| s.info.appetite_loss)
+            .fold(0., f32::max);
+        // Nauseous medicine spoils appetite the same way
+        let loss = f32::max(loss, self.agent_side_effects.get().nausea);
+
+        crate::utils::clamp_01(1. - loss / 100.)
+    }
+
+    /// Sends hunger and thirst urgency events when urgency changes
+    pub(crate) fn dispatch_urgency_events<E: Listener + 'static>(&self, events: &mut Dispatcher<E>) {
+        let hunger = self.hunger_urgency();
+        let thirst = self.thirst_urgency();
+
+        if self.last_hunger_urgency.replace(hunger) != hunger {
+            events.dispatch(Event::HungerUrgencyChanged(hunger));
+        }
+        if self.last_thirst_urgency.replace(thirst) != thirst {
+            events.dispatch(Event::ThirstUrgencyChanged(thirst));
+        }
+    }
+}
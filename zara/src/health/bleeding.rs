@@ -0,0 +1,148 @@
+use crate::health::Health;
+use crate::health::injury::ActiveInjury;
+use crate::utils::PlayerStatusC;
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Multipliers for the injuries blood drain depending on what player is doing. When player does
+/// several things at once, the biggest multiplier is used
+///
+/// # Links
+/// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Controlling-blood-loss) for more info.
+#[derive(Copy, Clone, Debug)]
+pub struct BleedingMultipliers {
+    /// Multiplier when player is not doing any of the activities below
+    pub resting: f32,
+    /// Multiplier when player is walking
+    pub walking: f32,
+    /// Multiplier when player is running
+    pub running: f32,
+    /// Multiplier when player is swimming or is under the water
+    pub swimming: f32,
+    /// Multiplier when player is doing some arm-intensive activity
+    pub arms_work: f32
+}
+impl Default for BleedingMultipliers {
+    fn default() -> Self {
+        BleedingMultipliers {
+            resting: 1.,
+            walking: 1.25,
+            running: 2.,
+            swimming: 1.5,
+            arms_work: 1.25
+        }
+    }
+}
+impl fmt::Display for BleedingMultipliers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Bleeding multipliers: resting x{:.2}, walking x{:.2}, running x{:.2}, swimming x{:.2}, arms work x{:.2}",
+               self.resting, self.walking, self.running, self.swimming, self.arms_work)
+    }
+}
+impl Eq for BleedingMultipliers { }
+impl PartialEq for BleedingMultipliers {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        f32::abs(self.resting - other.resting) < EPS &&
+        f32::abs(self.walking - other.walking) < EPS &&
+        f32::abs(self.running - other.running) < EPS &&
+        f32::abs(self.swimming - other.swimming) < EPS &&
+        f32::abs(self.arms_work - other.arms_work) < EPS
+    }
+}
+impl Hash for BleedingMultipliers {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u32((self.resting*10_000_f32) as u32);
+        state.write_u32((self.walking*10_000_f32) as u32);
+        state.write_u32((self.running*10_000_f32) as u32);
+        state.write_u32((self.swimming*10_000_f32) as u32);
+        state.write_u32((self.arms_work*10_000_f32) as u32);
+    }
+}
+impl BleedingMultipliers {
+    /// Returns multiplier for the current player activity
+    ///
+    /// # Parameters
+    /// - `player`: player status
+    ///
+    /// # Examples
+    /// ```
+    /// let value = multipliers.multiplier_for(&frame_data.player);
+    /// ```
+    pub fn multiplier_for(&self, player: &PlayerStatusC) -> f32 {
+        let mut result: Option<f32> = None;
+        let mut take = |active: bool, value: f32| {
+            if active && result.is_none_or(|r| value > r) { result = Some(value); }
+        };
+
+        take(player.is_walking, self.walking);
+        take(player.is_running, self.running);
+        take(player.is_swimming || player.is_underwater, self.swimming);
+        take(player.is_using_arms, self.arms_work);
+
+        result.unwrap_or(self.resting)
+    }
+}
+
+impl Health {
+    /// Sets blood drain multipliers that are used for all injuries that do not have their own
+    ///
+    /// # Parameters
+    /// - `multipliers`: new multipliers table
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::bleeding::BleedingMultipliers;
+    ///
+    /// person.health.set_bleeding_multipliers(BleedingMultipliers { running: 3., ..Default::default() });
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Controlling-blood-loss) for more info.
+    pub fn set_bleeding_multipliers(&self, multipliers: BleedingMultipliers) {
+        self.bleeding_multipliers.set(multipliers);
+    }
+
+    /// Returns blood drain multipliers that are used for all injuries that do not have their own
+    ///
+    /// # Examples
+    /// ```
+    /// let multipliers = person.health.bleeding_multipliers();
+    /// ```
+    pub fn bleeding_multipliers(&self) -> BleedingMultipliers { self.bleeding_multipliers.get() }
+
+    /// Returns blood drain multiplier for a given injury and the current player activity
+    pub(crate) fn bleeding_multiplier_for(&self, injury: &ActiveInjury, player: &PlayerStatusC) -> f32 {
+        injury.bleeding_multipliers()
+            .unwrap_or_else(|| self.bleeding_multipliers.get())
+            .multiplier_for(player)
+    }
+}
+
+impl ActiveInjury {
+    /// Overrides global blood drain multipliers for this injury. Pass `None` to use global ones
+    ///
+    /// # Parameters
+    /// - `multipliers`: multipliers table for this injury
+    ///
+    /// # Examples
+    /// ```
+    /// injury.set_bleeding_multipliers(Some(BleedingMultipliers { running: 4., ..Default::default() }));
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Controlling-blood-loss) for more info.
+    pub fn set_bleeding_multipliers(&self, multipliers: Option<BleedingMultipliers>) {
+        self.bleeding_multipliers.set(multipliers);
+    }
+
+    /// Returns blood drain multipliers of this injury, if overridden
+    ///
+    /// # Examples
+    /// ```
+    /// let multipliers = injury.bleeding_multipliers();
+    /// ```
+    pub fn bleeding_multipliers(&self) -> Option<BleedingMultipliers> { self.bleeding_multipliers.get() }
+}
\ No newline at end of file
@@ -0,0 +1,126 @@
+use crate::health::{Health, InjuryKey};
+use crate::health::injury::{Injury, ActiveInjury, InjuryDeltasC};
+use crate::body::BodyPart;
+use crate::utils::{GameTimeC, PlayerStatusC, clamp_01};
+use crate::utils::event::{Event, MessageQueue};
+use crate::error::SpawnInjuryErr;
+use crate::state::DirtyTracked;
+
+use std::fmt;
+
+/// Burned area (percents of the body part) that burn stages are described for. Burn deltas
+/// scale linearly with the area, so a burn over the whole body part is twice as severe
+pub(crate) const DEFAULT_BURN_AREA: usize = 50;
+/// Part of the burn pain relieved when the character is fully wet
+const WETNESS_BURN_RELIEF: f32 = 0.5;
+
+/// Describes what caused a burn
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum BurnSource {
+    /// Touching a campfire, a stove or other heat source. Player must be near a heat source
+    HeatSource,
+    /// Spilled boiling water or steam
+    BoilingWater,
+    /// Anything else, like chemicals or sunburn
+    #[default]
+    Other
+}
+impl fmt::Display for BurnSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Function that creates a burn injury instance for a given burn source
+pub type BurnFactory = Box<dyn Fn(BurnSource) -> Box<dyn Injury>>;
+
+impl Health {
+    /// Sets a factory for the burn injury that is spawned by
+    /// [`ZaraController.burn`](crate::ZaraController::burn). Pass `None` to disable burns
+    ///
+    /// # Parameters
+    /// - `factory`: function that creates burn injury for a given burn source
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.set_burn_factory(Some(Box::new(|_| Box::new(Burn))));
+    /// ```
+    pub fn set_burn_factory(&self, factory: Option<BurnFactory>) {
+        self.burn_factory.replace(factory);
+    }
+
+    /// Spawns a burn injury that covers a given part of the body part area. Burn stages describe
+    /// a burn over half of the body part; deltas scale with the burned area. If this burn is
+    /// already active on this body part, nothing will happen, and `Err` will be returned
+    ///
+    /// # Parameters
+    /// - `injury`: instance of an injury with [`get_is_burn`](crate::health::injury::Injury::get_is_burn) set
+    /// - `body_part`: burned body part
+    /// - `area`: burned area of the body part (0..100 percents)
+    /// - `activation_time`: game time when this burn will activate
+    ///
+    /// # Returns
+    /// Injury instance key on success
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.spawn_burn(Box::new(Burn), BodyPart::LeftForearm, 30, game_time);
+    /// ```
+    ///
+    /// ## Notes
+    /// This method borrows the `injuries` collection
+    pub fn spawn_burn(&self, injury: Box<dyn Injury>, body_part: BodyPart, area: usize, activation_time: GameTimeC)
+                      -> Result<InjuryKey, SpawnInjuryErr> {
+        if !injury.get_is_burn() { return Err(SpawnInjuryErr::NotABurn); }
+
+        let key = self.spawn_injury(injury, body_part, activation_time)?;
+
+        if let Some(injury) = self.active_injuries.borrow().get(&key) {
+            injury.burn_area.set(usize::min(area, 100));
+        }
+
+        Ok(key)
+    }
+
+    /// Spawns a burn made by the burn factory and sends the `Burned` event
+    pub(crate) fn burn(&self, body_part: BodyPart, area: usize, source: BurnSource,
+                       game_time: GameTimeC) -> Option<Result<InjuryKey, SpawnInjuryErr>> {
+        self.mark_dirty();
+
+        let injury = match self.burn_factory.borrow().as_ref() {
+            Some(factory) => factory(source),
+            None => return None
+        };
+        let result = self.spawn_burn(injury, body_part, area, game_time);
+
+        if result.is_ok() {
+            self.queue_message(Event::Burned(body_part, usize::min(area, 100), source));
+        }
+
+        Some(result)
+    }
+
+    /// Updates how much of the burn pain is relieved by wetness and dressings
+    pub(crate) fn update_burn_relief(&self, injury: &ActiveInjury, player: &PlayerStatusC) {
+        if !injury.is_burn { return; }
+
+        let wetness = clamp_01(player.wetness_level / 100.) * WETNESS_BURN_RELIEF;
+        let dressing = clamp_01(player.burn_dressing_relief(injury.body_part) as f32 / 100.);
+
+        injury.burn_relief.set(1. - (1. - wetness) * (1. - dressing));
+    }
+}
+
+/// Scales burn deltas by the burned area and eases its pain (stamina drain and fatigue)
+pub(crate) fn apply_burn_severity(deltas: &mut InjuryDeltasC, area: usize, relief: f32) {
+    let severity = area as f32 / DEFAULT_BURN_AREA as f32;
+    let pain = severity * (1. - clamp_01(relief));
+
+    deltas.stamina_drain *= pain;
+    deltas.fatigue_delta *= pain;
+    deltas.blood_drain *= severity;
+    deltas.body_temperature_delta *= severity;
+    deltas.heart_rate_delta *= severity;
+    deltas.pressure_top_delta *= severity;
+    deltas.pressure_bottom_delta *= severity;
+}
@@ -0,0 +1,124 @@
+use crate::utils::event::{Event, MessageQueue};
+use crate::health::Health;
+use crate::health::condition::{ActiveCondition, LongTermCondition, ConditionRestriction};
+use crate::utils::GameTimeC;
+use crate::error::{StartConditionErr, EndConditionErr};
+
+use std::rc::Rc;
+
+impl Health {
+    /// Starts a new long-term condition. If condition is already started, nothing will happen,
+    /// and `Err` will be returned
+    ///
+    /// # Parameters
+    /// - `condition`: instance of an object with the [`LongTermCondition`](crate::health::condition::LongTermCondition) trait
+    /// - `activation_time`: game time when this condition starts
+    ///
+    /// # Returns
+    /// Condition key on success
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.start_condition(Box::new(Pregnancy::new()), game_time);
+    /// ```
+    ///
+    /// ## Notes
+    /// This method borrows the `conditions` collection
+    pub fn start_condition(&self, condition: Box<dyn LongTermCondition>, activation_time: GameTimeC)
+                                                                    -> Result<String, StartConditionErr> {
+        if !self.is_alive.get() { return Err(StartConditionErr::CharacterIsDead); }
+
+        let mut b = self.conditions.borrow_mut();
+        let condition_name = condition.get_name();
+
+        if b.contains_key(&condition_name) {
+            return Err(StartConditionErr::ConditionAlreadyAdded);
+        }
+
+        self.queue_message(Event::ConditionStarted(condition_name.to_string()));
+
+        b.insert(condition_name.to_string(), Rc::new(ActiveCondition::new(condition, activation_time)));
+
+        Ok(condition_name)
+    }
+
+    /// Ends a long-term condition if exists. Returns `Err` if not.
+    ///
+    /// # Parameters
+    /// - `condition_name`: unique name of the condition
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.end_condition(&condition_name);
+    /// ```
+    ///
+    /// ## Notes
+    /// This method borrows the `conditions` collection
+    pub fn end_condition(&self, condition_name: &String) -> Result<(), EndConditionErr> {
+        if !self.is_alive.get() { return Err(EndConditionErr::CharacterIsDead); }
+
+        if self.conditions.borrow_mut().remove(condition_name).is_none() {
+            return Err(EndConditionErr::ConditionNotFound);
+        }
+
+        self.queue_message(Event::ConditionEnded(condition_name.to_string()));
+
+        Ok(())
+    }
+
+    /// Removes all long-term conditions.
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.clear_conditions();
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `conditions` collection
+    pub fn clear_conditions(&self) {
+        self.conditions.borrow_mut().clear();
+    }
+
+    /// Checks if character has a given long-term condition
+    ///
+    /// # Parameters
+    /// - `condition_name`: unique name of the condition
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.health.has_condition(&condition_name);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `conditions` collection
+    pub fn has_condition(&self, condition_name: &String) -> bool {
+        self.conditions.borrow().contains_key(condition_name)
+    }
+
+    /// Returns restrictions of all active long-term conditions as of the last `update` call.
+    /// Zara does not enforce them, game should
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::condition::ConditionRestriction;
+    ///
+    /// let can_run = !person.health.restrictions().contains(&ConditionRestriction::NoRunning);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `conditions` collection
+    pub fn restrictions(&self) -> Vec<ConditionRestriction> {
+        let game_time = *self.last_update_game_time.borrow();
+        let mut result = Vec::new();
+
+        for (_, condition) in self.conditions.borrow().iter() {
+            if !condition.is_active(&game_time) { continue; }
+
+            for r in condition.condition.restrictions(condition.day(&game_time)) {
+                if !result.contains(&r) { result.push(r); }
+            }
+        }
+
+        result
+    }
+}
\ No newline at end of file
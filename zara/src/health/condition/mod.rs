@@ -0,0 +1,176 @@
+use crate::health::Health;
+use crate::utils::GameTimeC;
+
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+mod crud;
+
+/// Game seconds in one game day
+pub(crate) const SECONDS_IN_DAY: f32 = 24. * 60. * 60.;
+/// Game days in one "month" of a long-term condition
+pub const DAYS_IN_MONTH: usize = 30;
+
+/// Trait that must be implemented by all long-term conditions, like pregnancy or recovery
+/// after an intensive care. Unlike diseases, conditions do not have stages and are progressed
+/// in game days and months.
+pub trait LongTermCondition {
+    /// Gets the unique name of this condition
+    fn get_name(&self) -> String;
+
+    /// For how many game days this condition lasts. `None` if condition lasts until removed
+    fn duration_days(&self) -> Option<usize>;
+
+    /// Needs of the character with this condition. Is called once a `UPDATE_INTERVAL` real seconds
+    ///
+    /// # Parameters
+    /// - `day`: game days passed since condition activation
+    fn needs(&self, day: usize) -> ConditionNeedsC;
+
+    /// Restrictions that game should respect while this condition is active. Zara does not
+    /// enforce them, they are surfaced with the `Health.restrictions` method
+    ///
+    /// # Parameters
+    /// - `day`: game days passed since condition activation
+    fn restrictions(&self, day: usize) -> Vec<ConditionRestriction>;
+
+    /// Is called once every game day since condition activation
+    ///
+    /// # Parameters
+    /// - `health`: health node
+    /// - `day`: game days passed since condition activation, starting from 1
+    fn on_day(&self, _health: &Health, _day: usize) { }
+
+    /// Is called once every [`DAYS_IN_MONTH`](crate::health::condition::DAYS_IN_MONTH) game days
+    /// since condition activation
+    ///
+    /// # Parameters
+    /// - `health`: health node
+    /// - `month`: months passed since condition activation, starting from 1
+    fn on_month(&self, _health: &Health, _month: usize) { }
+
+    /// For downcasting
+    fn as_any(&self) -> &dyn Any;
+}
+
+/// Needs of a character with a long-term condition. Drains are per game second and are
+/// subtracted from the current values; bonuses are added to the healthy values
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ConditionNeedsC {
+    /// Extra food drain, per game second
+    pub food_drain: f32,
+    /// Extra water drain, per game second
+    pub water_drain: f32,
+    /// Extra stamina drain, per game second
+    pub stamina_drain: f32,
+    /// Delta that will be added to the healthy fatigue value (absolute delta)
+    pub fatigue_bonus: f32,
+    /// Delta that will be added to the healthy heart rate value (absolute delta)
+    pub heart_rate_bonus: f32,
+    /// Delta that will be added to the healthy body temperature value (absolute delta)
+    pub body_temp_bonus: f32
+}
+impl fmt::Display for ConditionNeedsC {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Condition needs")
+    }
+}
+impl Eq for ConditionNeedsC { }
+impl PartialEq for ConditionNeedsC {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        f32::abs(self.food_drain - other.food_drain) < EPS &&
+        f32::abs(self.water_drain - other.water_drain) < EPS &&
+        f32::abs(self.stamina_drain - other.stamina_drain) < EPS &&
+        f32::abs(self.fatigue_bonus - other.fatigue_bonus) < EPS &&
+        f32::abs(self.heart_rate_bonus - other.heart_rate_bonus) < EPS &&
+        f32::abs(self.body_temp_bonus - other.body_temp_bonus) < EPS
+    }
+}
+impl Hash for ConditionNeedsC {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_i32((self.food_drain*10_000_f32) as i32);
+        state.write_i32((self.water_drain*10_000_f32) as i32);
+        state.write_i32((self.stamina_drain*10_000_f32) as i32);
+        state.write_i32((self.fatigue_bonus*10_000_f32) as i32);
+        state.write_i32((self.heart_rate_bonus*10_000_f32) as i32);
+        state.write_i32((self.body_temp_bonus*10_000_f32) as i32);
+    }
+}
+
+/// Restriction that a long-term condition puts on the character
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub enum ConditionRestriction {
+    /// Character should not run
+    NoRunning,
+    /// Character should not swim
+    NoSwimming,
+    /// Character should not carry more than a given weight (grams)
+    MaxInventoryWeight(usize),
+    /// Game-specific restriction
+    Custom(String)
+}
+impl fmt::Display for ConditionRestriction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Long-term condition that is active on a character
+pub struct ActiveCondition {
+    /// Condition object
+    pub condition: Box<dyn LongTermCondition>,
+    /// Game time when condition was started
+    activation_time: GameTimeC,
+    /// Last game day that was processed
+    pub(crate) last_day: Cell<usize>
+}
+impl fmt::Display for ActiveCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (since {})", self.condition.get_name(), self.activation_time)
+    }
+}
+impl ActiveCondition {
+    pub(crate) fn new(condition: Box<dyn LongTermCondition>, activation_time: GameTimeC) -> Self {
+        ActiveCondition {
+            condition,
+            activation_time,
+            last_day: Cell::new(0)
+        }
+    }
+
+    /// Game time when condition was started
+    pub fn activation_time(&self) -> GameTimeC { self.activation_time }
+
+    /// Game days passed since condition activation for a given game time
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to check
+    pub fn day(&self, game_time: &GameTimeC) -> usize {
+        let passed = game_time.as_secs_f32() - self.activation_time.as_secs_f32();
+
+        if passed <= 0. { 0 } else { (passed / SECONDS_IN_DAY) as usize }
+    }
+
+    /// Is condition over for a given game time
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to check
+    pub fn is_over(&self, game_time: &GameTimeC) -> bool {
+        match self.condition.duration_days() {
+            Some(d) => self.day(game_time) >= d,
+            None => false
+        }
+    }
+
+    /// Is condition started for a given game time
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to check
+    pub fn is_active(&self, game_time: &GameTimeC) -> bool {
+        game_time.as_secs_f32() >= self.activation_time.as_secs_f32()
+    }
+}
\ No newline at end of file
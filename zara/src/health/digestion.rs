@@ -0,0 +1,78 @@
+use crate::health::Health;
+use crate::health::side::SideEffectDeltasC;
+use crate::inventory::items::ConsumableC;
+use crate::utils::FrameSummaryC;
+
+/// Food that is being digested
+#[derive(Clone, Debug, Default)]
+pub(crate) struct DigestingMeal {
+    /// Unique name of the consumed item
+    pub(crate) item_name: String,
+    /// Total food gain of the meal (0..100)
+    pub(crate) food_gain: f32,
+    /// Digestion duration, game seconds
+    pub(crate) duration: f32,
+    /// Game seconds passed since the meal was consumed
+    pub(crate) elapsed: f32
+}
+impl DigestingMeal {
+    /// Part of the food gain that was digested by a given time. Most of the food is
+    /// digested in the beginning
+    fn digested_part(&self, elapsed: f32) -> f32 {
+        let t = crate::utils::clamp_01(elapsed / self.duration);
+
+        1. - (1. - t) * (1. - t)
+    }
+
+    /// Food gain remaining to be digested
+    fn remaining(&self) -> f32 { self.food_gain * (1. - self.digested_part(self.elapsed)) }
+}
+
+impl Health {
+    /// Returns food gain (0..100) of the consumed meals that is not yet digested. Consumables
+    /// with the [`digestion_minutes`](crate::inventory::items::ConsumableDescription::digestion_minutes)
+    /// option give their food gain gradually; games can use this value to stop player from
+    /// overeating
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.health.pending_food_gain();
+    /// ```
+    pub fn pending_food_gain(&self) -> f32 {
+        self.digestion.borrow().iter().map(|m| m.remaining()).sum()
+    }
+
+    /// Schedules food gain of a consumed item. Returns `false` if item gives its food
+    /// gain instantly
+    pub(crate) fn start_digestion(&self, item: &ConsumableC, food_gain: f32) -> bool {
+        let minutes = match item.digestion_time {
+            Some(m) if m > 0. => m,
+            _ => return false
+        };
+
+        self.digestion.borrow_mut().push(DigestingMeal {
+            item_name: item.name.to_string(),
+            food_gain,
+            duration: minutes * 60.,
+            elapsed: 0.
+        });
+
+        true
+    }
+
+    /// Adds digested food to the side effects deltas
+    pub(crate) fn apply_digestion(&self, deltas: &mut SideEffectDeltasC, frame_data: &FrameSummaryC) {
+        let mut meals = self.digestion.borrow_mut();
+
+        if meals.is_empty() { return; }
+
+        for meal in meals.iter_mut() {
+            let elapsed = meal.elapsed + frame_data.game_time_delta;
+
+            deltas.food_level_bonus += meal.food_gain * (meal.digested_part(elapsed) - meal.digested_part(meal.elapsed));
+            meal.elapsed = elapsed;
+        }
+
+        meals.retain(|m| m.elapsed < m.duration);
+    }
+}
\ No newline at end of file
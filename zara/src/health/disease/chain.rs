@@ -1,7 +1,7 @@
 use crate::utils::event::{MessageQueue, Event};
 use crate::health::disease::{ActiveDisease, ActiveStage, StageLevel};
-use crate::utils::{GameTimeC, clamp_bottom};
-use crate::error::{ChainInvertErr, ChainInvertBackErr};
+use crate::utils::{GameTimeC, PlayerStatusC, clamp_bottom, clamp_01};
+use crate::error::{ChainInvertErr, ChainInvertBackErr, ChainDowngradeErr};
 
 use std::time::Duration;
 use std::collections::{BTreeMap};
@@ -144,12 +144,195 @@ impl ActiveDisease {
         self.end_time.replace(Some(GameTimeC::from_duration(Duration::from_secs_f32(t))));
         self.will_end.set(true);
         self.is_inverted.set(true);
+        self.scheduled_invert.replace(None);
 
-        self.queue_message(Event::DiseaseInverted(self.disease.get_name()));
+        self.queue_message(Event::DiseaseInverted(self.disease.get_id()));
 
         Ok(())
     }
 
+    /// Schedules [`invert`] to happen at a given game time, for example when the medicine
+    /// kicks in only 30 minutes after it was taken. The chain is inverted on the first
+    /// update at or after this time, using the scheduled time as a "pivot point"
+    ///
+    /// # Parameters
+    /// - `game_time`: game time when the disease starts to heal
+    ///
+    /// # Returns
+    /// Ok on success.
+    ///
+    /// # Examples
+    /// ```
+    /// let kicks_in = GameTimeC::from_duration(game_time.to_duration() + Duration::from_secs(30*60));
+    ///
+    /// disease.schedule_invert_at(&kicks_in);
+    /// ```
+    ///
+    /// ## Notes
+    /// Will return `ChainInvertErr::AlreadyInverted` error if disease is already healing.
+    /// Calling it again replaces the previously scheduled time. Calling [`invert`] cancels the
+    /// schedule.
+    ///
+    /// [`invert`]: #method.invert
+    pub fn schedule_invert_at(&self, game_time: &GameTimeC) -> Result<(), ChainInvertErr> {
+        if self.is_inverted.get() { return Err(ChainInvertErr::AlreadyInverted); }
+
+        self.scheduled_invert.replace(Some(*game_time));
+
+        Ok(())
+    }
+
+    /// Cancels the invert scheduled by [`schedule_invert_at`]
+    ///
+    /// # Examples
+    /// ```
+    /// disease.cancel_scheduled_invert();
+    /// ```
+    ///
+    /// [`schedule_invert_at`]: #method.schedule_invert_at
+    pub fn cancel_scheduled_invert(&self) {
+        self.scheduled_invert.replace(None);
+    }
+
+    /// Returns game time when the chain is scheduled to be inverted, if any
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(game_time) = disease.scheduled_invert_time() {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn scheduled_invert_time(&self) -> Option<GameTimeC> { *self.scheduled_invert.borrow() }
+
+    /// Inverts the chain if its scheduled invert time has come
+    pub(crate) fn process_scheduled_invert(&self, game_time: &GameTimeC) {
+        let pivot = match *self.scheduled_invert.borrow() {
+            Some(t) if t.as_secs_f32() <= game_time.as_secs_f32() => t,
+            _ => return
+        };
+
+        self.scheduled_invert.replace(None);
+
+        // Disease could have ended or been inverted by other means in between
+        self.invert(&pivot).ok();
+    }
+
+    /// Shifts disease one stage level down, keeping the progress of the current stage. For
+    /// example, disease that is 40% into its `Worrying` stage becomes 40% into its `Progressing`
+    /// stage. Timings of the whole chain are recomputed, so the disease keeps going in its
+    /// current direction (getting worse, or healing if inverted) from the new point.
+    ///
+    /// Use this for treatments that reduce severity without starting the full recovery
+    ///
+    /// ``` none
+    /// |InitialStage------>|Progressing--------->|Worrying--------------->|Critical...
+    ///                                                          ^
+    ///                                                     current time
+    ///                                                     (Worrying 40%)
+    /// ```
+    /// After the `downgrade` call:
+    /// ``` none
+    /// |InitialStage------>|Progressing--------->|Worrying--------------->|Critical...
+    ///                                  ^
+    ///                             current time
+    ///                           (Progressing 40%)
+    /// ```
+    ///
+    /// # Parameters
+    /// - `game_time`: game time of the downgrade
+    ///
+    /// # Returns
+    /// New active stage level on success.
+    ///
+    /// # Examples
+    /// ```
+    /// disease.downgrade(&game_time);
+    /// ```
+    ///
+    /// ## Notes
+    /// Will return `ChainDowngradeErr::AlreadyAtLowestStage` error if disease is already at its
+    /// lowest stage. Use [`invert`] to cure it instead.
+    ///
+    /// [`invert`]: #method.invert
+    pub fn downgrade(&self, game_time: &GameTimeC) -> Result<StageLevel, ChainDowngradeErr> {
+        if !self.is_active(game_time) { return Err(ChainDowngradeErr::DiseaseNotActiveAtGivenTime); }
+        let active_stage = match self.get_active_stage(game_time) {
+            Some(o) => o,
+            None => return Err(ChainDowngradeErr::NoActiveStageAtGivenTime)
+        };
+        let lower_stage = match self.stages.borrow().range(..active_stage.info.level).next_back() {
+            Some((_, o)) => *o,
+            None => return Err(ChainDowngradeErr::AlreadyAtLowestStage)
+        };
+
+        let gt = game_time.as_secs_f32();
+        let start = active_stage.start_time.as_secs_f32();
+        let duration = active_stage.duration.as_secs_f32();
+        let progress = if duration > 0. { clamp_01((gt - start) / duration) } else { 1. };
+
+        // Move the whole chain so that the same progress of the lower stage falls on `gt`
+        let target = lower_stage.start_time.as_secs_f32() + lower_stage.duration.as_secs_f32() * progress;
+
+        self.shift_chain(gt - target);
+
+        self.queue_message(Event::DiseaseDowngraded(self.disease.get_id(), lower_stage.info.level));
+
+        Ok(lower_stage.info.level)
+    }
+
+    /// Pauses healing for the last frame if healing conditions are not met
+    pub(crate) fn process_healing_conditions(&self, player: &PlayerStatusC, game_time_delta: f32) {
+        if !self.is_inverted.get() {
+            self.is_healing_paused.set(false);
+            return;
+        }
+
+        let are_met = match self.disease.get_healing_conditions() {
+            Some(c) => c.are_met(player),
+            None => true
+        };
+
+        if !are_met {
+            // Undo the healing progress of the last frame
+            self.shift_chain(game_time_delta);
+
+            if !self.is_healing_paused.get() {
+                self.is_healing_paused.set(true);
+                self.queue_message(Event::DiseaseHealingPaused(self.disease.get_id()));
+            }
+        } else if self.is_healing_paused.get() {
+            self.is_healing_paused.set(false);
+            self.queue_message(Event::DiseaseHealingResumed(self.disease.get_id()));
+        }
+    }
+
+    /// Moves all chain timings by a given amount of game seconds. Stages that would end before
+    /// the start of the game time are dropped, and a stage that would start before it is cut at zero
+    fn shift_chain(&self, seconds: f32) {
+        let to_time = |t: f32| GameTimeC::from_duration(Duration::from_secs_f32(clamp_bottom(t, 0.)));
+        let shifted = |t: &GameTimeC| to_time(t.as_secs_f32() + seconds);
+
+        self.stages.borrow_mut().retain(|_, stage| {
+            let start_time = stage.start_time.as_secs_f32() + seconds;
+            let peak_time = stage.peak_time.as_secs_f32() + seconds;
+
+            if peak_time < 0. { return false; }
+            if start_time < 0. { stage.duration = Duration::from_secs_f32(peak_time); }
+
+            stage.start_time = to_time(start_time);
+            stage.peak_time = to_time(peak_time);
+
+            true
+        });
+
+        let activation_time = shifted(&self.activation_time.borrow());
+        let end_time = self.end_time.borrow().as_ref().map(&shifted);
+
+        self.activation_time.replace(activation_time);
+        self.end_time.replace(end_time);
+        self.lerp_data.replace(None);
+    }
+
     /// Only if disease is now healing. Inverts disease stages back so that disease goes from the
     /// current state to its end. Use this to cancel the "curing" process and make disease getting
     /// "worse" again.
@@ -303,7 +486,7 @@ impl ActiveDisease {
         self.will_end.set(will_end);
         self.is_inverted.set(false);
 
-        self.queue_message(Event::DiseaseResumed(self.disease.get_name()));
+        self.queue_message(Event::DiseaseResumed(self.disease.get_id()));
 
         Ok(())
     }
@@ -1,6 +1,6 @@
 use crate::utils::event::{Event, MessageQueue};
-use crate::health::Health;
-use crate::health::disease::{ActiveDisease, Disease};
+use crate::health::{Health, RemovalReason};
+use crate::health::disease::{ActiveDisease, Disease, DiseaseInfoC};
 use crate::utils::GameTimeC;
 use crate::error::{SpawnDiseaseErr, RemoveDiseaseErr};
 
@@ -32,8 +32,8 @@ impl Health {
                                                                     -> Result<String, SpawnDiseaseErr> {
         if !self.is_alive.get() { return Err(SpawnDiseaseErr::CharacterIsDead); }
 
-        let mut b = self.diseases.borrow_mut();
-        let disease_name = disease.get_name();
+        let mut b = self.active_diseases.borrow_mut();
+        let disease_name = disease.get_id();
 
         if b.contains_key(&disease_name) {
             return Err(SpawnDiseaseErr::DiseaseAlreadyAdded);
@@ -65,18 +65,140 @@ impl Health {
     /// ## Notes
     /// This method borrows the `diseases` collection
     pub fn remove_disease(&self, disease_name: &String) -> Result<(), RemoveDiseaseErr> {
+        self.remove_disease_for(disease_name, RemovalReason::Cleared)
+    }
+
+    /// Same as [`remove_disease`](#method.remove_disease), but sends a given removal reason with
+    /// the [`DiseaseRemoved`](crate::utils::event::Event::DiseaseRemoved) event. Use it, for
+    /// example, when a scripted treatment cures the disease
+    ///
+    /// # Parameters
+    /// - `disease_name`: unique name of the disease
+    /// - `reason`: why disease is removed
+    ///
+    /// # Returns
+    /// Ok on success
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::RemovalReason;
+    ///
+    /// person.health.remove_disease_for(disease_name, RemovalReason::Cured);
+    /// ```
+    ///
+    /// ## Notes
+    /// This method borrows the `diseases` collection
+    pub fn remove_disease_for(&self, disease_name: &String, reason: RemovalReason) -> Result<(), RemoveDiseaseErr> {
         if !self.is_alive.get() { return Err(RemoveDiseaseErr::CharacterIsDead); }
 
-        let mut b = self.diseases.borrow_mut();
+        let removed = match self.active_diseases.borrow_mut().remove(disease_name) {
+            Some(o) => o,
+            None => return Err(RemoveDiseaseErr::DiseaseNotFound)
+        };
 
-        if !b.contains_key(disease_name) {
-            return Err(RemoveDiseaseErr::DiseaseNotFound);
+        self.forget_latent_infection(disease_name);
+
+        self.queue_message(Event::DiseaseRemoved(disease_name.to_string(), reason, removed.worst_level_reached()));
+
+        Ok(())
+    }
+
+    /// Removes all diseases that match a predicate, like the scripted ones on a new chapter.
+    /// [`DiseaseRemoved`](crate::utils::event::Event::DiseaseRemoved) events are sent with
+    /// [`RemovalReason::Cleared`]
+    ///
+    /// # Parameters
+    /// - `predicate`: returns `true` for the diseases to remove
+    ///
+    /// # Returns
+    /// Ids of the removed diseases
+    ///
+    /// # Examples
+    /// ```
+    /// let removed = person.health.clear_diseases_where(|d| !d.is_healing());
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows the `diseases` collection. Predicate is called while the collection is not
+    /// borrowed, so it can safely read other diseases
+    pub fn clear_diseases_where<F: FnMut(&ActiveDisease) -> bool>(&self, mut predicate: F) -> Vec<String> {
+        let diseases: Vec<Rc<ActiveDisease>> = self.active_diseases.borrow().values().cloned().collect();
+
+        diseases.iter()
+            .filter(|d| predicate(d))
+            .map(|d| d.disease.get_id())
+            .filter(|name| self.remove_disease(name).is_ok())
+            .collect()
+    }
+
+    /// Returns an active or scheduled disease by its id. `None` if there is no such disease
+    ///
+    /// # Parameters
+    /// - `disease_name`: disease id
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(flu) = person.health.disease(&"Flu".to_string()) {
+    ///     flu.invert(&game_time).ok();
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows the `diseases` collection only for the lookup
+    pub fn disease(&self, disease_name: &String) -> Option<Rc<ActiveDisease>> {
+        self.active_diseases.borrow().get(disease_name).cloned()
+    }
+
+    /// Calls a function for every active or scheduled disease, ordered by disease id
+    ///
+    /// # Parameters
+    /// - `f`: function to call
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.for_each_disease(|d| println!("{}", d.disease.get_name()));
+    /// ```
+    ///
+    /// ## Notes
+    /// Function is called while the `diseases` collection is not borrowed, so it can safely
+    /// spawn or remove diseases
+    pub fn for_each_disease<F: FnMut(&ActiveDisease)>(&self, mut f: F) {
+        let diseases: Vec<Rc<ActiveDisease>> = self.active_diseases.borrow().values().cloned().collect();
+
+        for disease in diseases.iter() {
+            f(disease);
         }
+    }
 
-        b.remove(disease_name);
+    /// Returns owned read-only views of all active or scheduled diseases as of the last
+    /// `update` call, ordered by disease id. Views can be kept and passed around freely
+    ///
+    /// # Examples
+    /// ```
+    /// for disease in person.health.snapshot_diseases() {
+    ///     println!("{} {:?}", disease.name, disease.active_level);
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows the `diseases` collection
+    pub fn snapshot_diseases(&self) -> Vec<DiseaseInfoC> {
+        let game_time = self.last_update_game_time.borrow();
 
-        self.queue_message(Event::DiseaseRemoved(disease_name.to_string()));
+        self.active_diseases.borrow().iter().map(|(name, d)| {
+            let stage = d.get_active_stage(&game_time);
 
-        Ok(())
+            DiseaseInfoC {
+                name: name.to_string(),
+                is_active: d.is_active(&game_time),
+                is_healing: d.is_healing(),
+                activation_time: d.activation_time(),
+                active_level: stage.as_ref().map(|s| s.info.level),
+                stage_percent: stage.as_ref().map_or(0, |s| s.percent_active(&game_time)),
+                end_time: d.end_time(),
+                needs_treatment: d.needs_treatment,
+                worst_level_reached: d.worst_level_reached()
+            }
+        }).collect()
     }
-}
\ No newline at end of file
+}
@@ -97,6 +97,18 @@ pub trait StageDrainsValues {
     /// # Parameters
     /// - `value`: max drain value for this stage (0..100 percents per game second)
     fn water_level(&self, value: f32) -> &dyn StageDrainsValues;
+    /// Set the static drain rate for the oxygen level for this stage. 0..100 percents per game second.
+    /// Useful for respiratory diseases
+    ///
+    /// # Parameters
+    /// - `value`: max drain value for this stage (0..100 percents per game second)
+    fn oxygen_level(&self, value: f32) -> &dyn StageDrainsValues;
+    /// Set the static drain rate for the blood level for this stage. 0..100 percents per game second.
+    /// Useful for hemorrhagic diseases
+    ///
+    /// # Parameters
+    /// - `value`: max drain value for this stage (0..100 percents per game second)
+    fn blood_level(&self, value: f32) -> &dyn StageDrainsValues;
 
     /// Choose this if you want this stage to affect fatigue.
     ///
@@ -118,6 +130,16 @@ pub trait StageDeathChance {
 
 /// Stage fluent step trait
 pub trait StageEnd {
+    /// Makes character nauseous during this stage
+    ///
+    /// # Parameters
+    /// - `value`: nausea level (0..100) this stage keeps character at
+    fn with_nausea(&self, value: f32) -> &dyn StageEnd;
+    /// Makes food less nourishing during this stage
+    ///
+    /// # Parameters
+    /// - `value`: percent (0..100) of the food gain that character loses when eating
+    fn with_appetite_loss(&self, value: f32) -> &dyn StageEnd;
     /// Builds disease stage object with all the information provided
     fn build(&self) -> StageDescription;
 }
@@ -194,6 +216,8 @@ impl StageDrainsNode for StageBuilder {
         self.target_stamina_drain.set(0.00001);
         self.target_food_drain.set(0.00001);
         self.target_water_drain.set(0.00001);
+        self.target_oxygen_drain.set(0.00001);
+        self.target_blood_drain.set(0.00001);
 
         self.as_stage_death_chance()
     }
@@ -218,6 +242,18 @@ impl StageDrainsValues for StageBuilder {
         self.as_drains_values()
     }
 
+    fn oxygen_level(&self, value: f32) -> &dyn StageDrainsValues {
+        self.target_oxygen_drain.set(value);
+
+        self.as_drains_values()
+    }
+
+    fn blood_level(&self, value: f32) -> &dyn StageDrainsValues {
+        self.target_blood_drain.set(value);
+
+        self.as_drains_values()
+    }
+
     fn affects_fatigue(&self, target_delta: f32) -> &dyn StageDeathChance {
         self.target_fatigue_delta.set(target_delta);
 
@@ -246,6 +282,18 @@ impl StageDeathChance for StageBuilder {
 }
 
 impl StageEnd for StageBuilder {
+    fn with_nausea(&self, value: f32) -> &dyn StageEnd {
+        self.nausea.set(crate::utils::clamp(value, 0., 100.));
+
+        self.as_stage_end()
+    }
+
+    fn with_appetite_loss(&self, value: f32) -> &dyn StageEnd {
+        self.appetite_loss.set(crate::utils::clamp(value, 0., 100.));
+
+        self.as_stage_end()
+    }
+
     fn build(&self) -> StageDescription {
         let self_heal_chance = match self.self_heal_chance.borrow().as_ref() {
             Some(c) => Some(*c),
@@ -269,7 +317,11 @@ impl StageEnd for StageBuilder {
             target_fatigue_delta: self.target_fatigue_delta.get(),
             target_stamina_drain: self.target_stamina_drain.get(),
             target_food_drain: self.target_food_drain.get(),
-            target_water_drain: self.target_water_drain.get()
+            target_water_drain: self.target_water_drain.get(),
+            target_oxygen_drain: self.target_oxygen_drain.get(),
+            target_blood_drain: self.target_blood_drain.get(),
+            nausea: self.nausea.get(),
+            appetite_loss: self.appetite_loss.get()
         }
     }
 }
\ No newline at end of file
@@ -19,13 +19,161 @@ impl LerpDataNodeC {
             stamina_data: Vec::new(),
             food_data: Vec::new(),
             water_data: Vec::new(),
+            oxygen_data: Vec::new(),
+            blood_data: Vec::new(),
             fatigue_data: Vec::new()
         }
     }
+
+    fn covers(&self, inverted: bool, gt: f32) -> bool {
+        if inverted != self.is_for_inverted {
+            return false;
+        }
+
+        (gt >= self.start_time && self.is_endless) || (gt >= self.start_time && gt <= self.end_time)
+    }
+
+    fn deltas_at(&self, gt: f32) -> DiseaseDeltasC {
+        let mut result = DiseaseDeltasC::empty();
+        let lerp_data = self;
+
+        { // Body Temperature
+            let mut ld = None;
+            for data in lerp_data.body_temp_data.iter() {
+                if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
+                    ld = Some(data);
+                    break;
+                }
+            }
+            if let Some(d) = ld {
+                let p = clamp_01((gt - d.start_time) / d.duration);
+                result.body_temperature_delta = lerp(d.start_value, d.end_value, p);
+            }
+        }
+        { // Heart Rate
+            let mut ld = None;
+            for data in lerp_data.heart_rate_data.iter() {
+                if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
+                    ld = Some(data);
+                    break;
+                }
+            }
+            if let Some(d) = ld {
+                let p = clamp_01((gt - d.start_time) / d.duration);
+                result.heart_rate_delta = lerp(d.start_value, d.end_value, p);
+            }
+        }
+        { // Top Pressure
+            let mut ld = None;
+            for data in lerp_data.pressure_top_data.iter() {
+                if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
+                    ld = Some(data);
+                    break;
+                }
+            }
+            if let Some(d) = ld {
+                let p = clamp_01((gt - d.start_time) / d.duration);
+                result.pressure_top_delta = lerp(d.start_value, d.end_value, p);
+            }
+        }
+        { // Bottom Pressure
+            let mut ld = None;
+            for data in lerp_data.pressure_bottom_data.iter() {
+                if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
+                    ld = Some(data);
+                    break;
+                }
+            }
+            if let Some(d) = ld {
+                let p = clamp_01((gt - d.start_time) / d.duration);
+                result.pressure_bottom_delta = lerp(d.start_value, d.end_value, p);
+            }
+        }
+        { // Fatigue
+            let mut ld = None;
+            for data in lerp_data.fatigue_data.iter() {
+                if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
+                    ld = Some(data);
+                    break;
+                }
+            }
+            if let Some(d) = ld {
+                let p = clamp_01((gt - d.start_time) / d.duration);
+                result.fatigue_delta = lerp(d.start_value, d.end_value, p);
+            }
+        }
+        { // Stamina
+            let mut ld = None;
+            for data in lerp_data.stamina_data.iter() {
+                if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
+                    ld = Some(data);
+                    break;
+                }
+            }
+            if let Some(d) = ld {
+                let p = clamp_01((gt - d.start_time) / d.duration);
+                result.stamina_drain = lerp(d.start_value, d.end_value, p);
+            }
+        }
+        { // Food
+            let mut ld = None;
+            for data in lerp_data.food_data.iter() {
+                if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
+                    ld = Some(data);
+                    break;
+                }
+            }
+            if let Some(d) = ld {
+                let p = clamp_01((gt - d.start_time) / d.duration);
+                result.food_drain = lerp(d.start_value, d.end_value, p);
+            }
+        }
+        { // Water
+            let mut ld = None;
+            for data in lerp_data.water_data.iter() {
+                if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
+                    ld = Some(data);
+                    break;
+                }
+            }
+            if let Some(d) = ld {
+                let p = clamp_01((gt - d.start_time) / d.duration);
+                result.water_drain = lerp(d.start_value, d.end_value, p);
+            }
+        }
+        { // Oxygen
+            let mut ld = None;
+            for data in lerp_data.oxygen_data.iter() {
+                if gt >= data.start_time && (data.is_endless || gt <= data.end_time) {
+                    ld = Some(data);
+                    break;
+                }
+            }
+            if let Some(d) = ld {
+                let p = clamp_01((gt - d.start_time) / d.duration);
+                result.oxygen_drain = lerp(d.start_value, d.end_value, p);
+            }
+        }
+        { // Blood
+            let mut ld = None;
+            for data in lerp_data.blood_data.iter() {
+                if gt >= data.start_time && (data.is_endless || gt <= data.end_time) {
+                    ld = Some(data);
+                    break;
+                }
+            }
+            if let Some(d) = ld {
+                let p = clamp_01((gt - d.start_time) / d.duration);
+                result.blood_drain = lerp(d.start_value, d.end_value, p);
+            }
+        }
+
+        result
+    }
 }
 
 impl ActiveDisease {
-    fn generate_lerp_data(&self, game_time: &GameTimeC) {
+    fn build_lerp_data(&self, game_time: &GameTimeC) -> LerpDataNodeC {
         let inverted = self.is_inverted.get();
         let healthy = HealthC::healthy();
         let gt = game_time.as_secs_f32();
@@ -39,25 +187,14 @@ impl ActiveDisease {
         let mut last_start_stamina_delta = last_deltas.stamina_drain;
         let mut last_start_food_delta = last_deltas.food_drain;
         let mut last_start_water_delta = last_deltas.water_drain;
+        let mut last_start_oxygen_delta = last_deltas.oxygen_drain;
+        let mut last_start_blood_delta = last_deltas.blood_drain;
 
         // Creating our lerp data object
         let mut lerp_data = LerpDataNodeC::new();
         lerp_data.is_for_inverted = self.is_inverted.get();
         lerp_data.start_time = gt;
 
-        // Clear the old structure
-        if let Some(m) = self.lerp_data.borrow_mut().as_mut() {
-            m.body_temp_data.clear();
-            m.heart_rate_data.clear();
-            m.pressure_top_data.clear();
-            m.pressure_bottom_data.clear();
-            m.fatigue_data.clear();
-            m.stamina_data.clear();
-            m.food_data.clear();
-            m.water_data.clear();
-        }
-        self.lerp_data.replace(None);
-
         let healthy_stage = ActiveStage {
             info: StageDescription {
                 level: StageLevel::Undefined,
@@ -69,6 +206,10 @@ impl ActiveDisease {
                 target_stamina_drain: 0.,
                 target_food_drain: 0.,
                 target_water_drain: 0.,
+                target_oxygen_drain: 0.,
+                target_blood_drain: 0.,
+                nausea: 0.,
+                appetite_loss: 0.,
                 target_body_temp: healthy.body_temperature,
                 target_heart_rate: healthy.heart_rate,
                 target_pressure_top: healthy.top_pressure,
@@ -251,6 +392,42 @@ impl ActiveDisease {
                 last_start_water_delta = ld.end_value;
                 lerp_data.water_data.push(ld);
             }
+            // Oxygen
+            if stage.info.target_oxygen_drain > 0. {
+                let end_value = match next_stage {
+                    Some(st) => st.info.target_oxygen_drain,
+                    None => stage.info.target_oxygen_drain
+                };
+                let ld = LerpDataC {
+                    start_time,
+                    end_time: end,
+                    start_value: last_start_oxygen_delta,
+                    end_value,
+                    duration: end - start_time,
+                    is_endless: stage.info.is_endless
+                };
+
+                last_start_oxygen_delta = ld.end_value;
+                lerp_data.oxygen_data.push(ld);
+            }
+            // Blood
+            if stage.info.target_blood_drain > 0. {
+                let end_value = match next_stage {
+                    Some(st) => st.info.target_blood_drain,
+                    None => stage.info.target_blood_drain
+                };
+                let ld = LerpDataC {
+                    start_time,
+                    end_time: end,
+                    start_value: last_start_blood_delta,
+                    end_value,
+                    duration: end - start_time,
+                    is_endless: stage.info.is_endless
+                };
+
+                last_start_blood_delta = ld.end_value;
+                lerp_data.blood_data.push(ld);
+            }
 
             return true;
         };
@@ -269,34 +446,37 @@ impl ActiveDisease {
 
         lerp_data.is_endless = has_endless_child;
 
-        self.lerp_data.replace(Some(lerp_data));
+        lerp_data
     }
 
-    fn has_lerp_data_for(&self, game_time: &GameTimeC) -> bool {
-        let b = self.lerp_data.borrow();
-        let ld = match b.as_ref() {
-            Some(o) => o,
-            None => return false
-        };
-
-        if self.is_inverted.get() != ld.is_for_inverted {
-            return false;
+    fn generate_lerp_data(&self, game_time: &GameTimeC) {
+        // Clear the old structure
+        if let Some(m) = self.lerp_data.borrow_mut().as_mut() {
+            m.body_temp_data.clear();
+            m.heart_rate_data.clear();
+            m.pressure_top_data.clear();
+            m.pressure_bottom_data.clear();
+            m.fatigue_data.clear();
+            m.stamina_data.clear();
+            m.food_data.clear();
+            m.water_data.clear();
+            m.oxygen_data.clear();
+            m.blood_data.clear();
         }
+        self.lerp_data.replace(None);
 
-        let gt = game_time.as_secs_f32();
+        self.lerp_data.replace(Some(self.build_lerp_data(game_time)));
+    }
 
-        if (gt >= ld.start_time && ld.is_endless) || (gt >= ld.start_time && gt <= ld.end_time)
-        {
-            return true;
+    fn has_lerp_data_for(&self, game_time: &GameTimeC) -> bool {
+        match self.lerp_data.borrow().as_ref() {
+            Some(ld) => ld.covers(self.is_inverted.get(), game_time.as_secs_f32()),
+            None => false
         }
-
-        false
     }
 
     /// Gets disease vitals delta for a given time
     pub(crate) fn get_vitals_deltas(&self, game_time: &GameTimeC) -> DiseaseDeltasC {
-        let mut result = DiseaseDeltasC::empty();
-
         if !self.has_lerp_data_for(game_time) {
             self.generate_lerp_data(game_time);
 
@@ -304,120 +484,34 @@ impl ActiveDisease {
             if !self.has_lerp_data_for(game_time) { return DiseaseDeltasC::empty(); }
         }
 
-        let b = self.lerp_data.borrow();
-        let lerp_data = match b.as_ref() {
-            Some(o) => o,
+        let result = match self.lerp_data.borrow().as_ref() {
+            Some(o) => o.deltas_at(game_time.as_secs_f32()),
             None => return DiseaseDeltasC::empty()
         };
-        let gt = game_time.as_secs_f32();
-
-        { // Body Temperature
-            let mut ld = None;
-            for data in lerp_data.body_temp_data.iter() {
-                if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
-                    ld = Some(data);
-                    break;
-                }
-            }
-            if let Some(d) = ld {
-                let p = clamp_01((gt - d.start_time) / d.duration);
-                result.body_temperature_delta = lerp(d.start_value, d.end_value, p);
-            }
-        }
-        { // Heart Rate
-            let mut ld = None;
-            for data in lerp_data.heart_rate_data.iter() {
-                if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
-                    ld = Some(data);
-                    break;
-                }
-            }
-            if let Some(d) = ld {
-                let p = clamp_01((gt - d.start_time) / d.duration);
-                result.heart_rate_delta = lerp(d.start_value, d.end_value, p);
-            }
-        }
-        { // Top Pressure
-            let mut ld = None;
-            for data in lerp_data.pressure_top_data.iter() {
-                if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
-                    ld = Some(data);
-                    break;
-                }
-            }
-            if let Some(d) = ld {
-                let p = clamp_01((gt - d.start_time) / d.duration);
-                result.pressure_top_delta = lerp(d.start_value, d.end_value, p);
-            }
-        }
-        { // Bottom Pressure
-            let mut ld = None;
-            for data in lerp_data.pressure_bottom_data.iter() {
-                if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
-                    ld = Some(data);
-                    break;
-                }
-            }
-            if let Some(d) = ld {
-                let p = clamp_01((gt - d.start_time) / d.duration);
-                result.pressure_bottom_delta = lerp(d.start_value, d.end_value, p);
-            }
-        }
-        { // Fatigue
-            let mut ld = None;
-            for data in lerp_data.fatigue_data.iter() {
-                if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
-                    ld = Some(data);
-                    break;
-                }
-            }
-            if let Some(d) = ld {
-                let p = clamp_01((gt - d.start_time) / d.duration);
-                result.fatigue_delta = lerp(d.start_value, d.end_value, p);
-            }
-        }
-        { // Stamina
-            let mut ld = None;
-            for data in lerp_data.stamina_data.iter() {
-                if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
-                    ld = Some(data);
-                    break;
-                }
-            }
-            if let Some(d) = ld {
-                let p = clamp_01((gt - d.start_time) / d.duration);
-                result.stamina_drain = lerp(d.start_value, d.end_value, p);
-            }
-        }
-        { // Food
-            let mut ld = None;
-            for data in lerp_data.food_data.iter() {
-                if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
-                    ld = Some(data);
-                    break;
-                }
-            }
-            if let Some(d) = ld {
-                let p = clamp_01((gt - d.start_time) / d.duration);
-                result.food_drain = lerp(d.start_value, d.end_value, p);
-            }
-        }
-        { // Water
-            let mut ld = None;
-            for data in lerp_data.water_data.iter() {
-                if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
-                    ld = Some(data);
-                    break;
-                }
-            }
-            if let Some(d) = ld {
-                let p = clamp_01((gt - d.start_time) / d.duration);
-                result.water_drain = lerp(d.start_value, d.end_value, p);
-            }
-        }
 
         self.last_deltas.replace(result.clone());
 
         result
     }
+
+    /// Gets disease vitals delta for a given time without altering the disease state. When cached lerp
+    /// data does not cover the given time, temporary one is built starting from `now`
+    pub(crate) fn project_vitals_deltas(&self, now: &GameTimeC, game_time: &GameTimeC) -> DiseaseDeltasC {
+        let gt = game_time.as_secs_f32();
+        let inverted = self.is_inverted.get();
+        let cached = match self.lerp_data.borrow().as_ref() {
+            Some(o) if o.covers(inverted, gt) => Some(o.deltas_at(gt)),
+            _ => None
+        };
+        match cached {
+            Some(d) => d,
+            None => {
+                let lerp_data = self.build_lerp_data(now);
+
+                if !lerp_data.covers(inverted, gt) { return DiseaseDeltasC::empty(); }
+
+                lerp_data.deltas_at(gt)
+            }
+        }
+    }
 }
\ No newline at end of file
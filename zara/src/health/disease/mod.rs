@@ -4,6 +4,7 @@ use crate::utils::{FrameSummaryC, GameTimeC};
 use crate::health::disease::fluent::{StageInit};
 use crate::inventory::items::{InventoryItem, ConsumableC, ApplianceC};
 use crate::body::BodyPart;
+use crate::health::healing::HealingConditions;
 
 use std::rc::Rc;
 use std::cell::{Cell, RefCell, RefMut};
@@ -114,6 +115,10 @@ pub struct StageBuilder {
     target_stamina_drain: Cell<f32>,
     target_food_drain: Cell<f32>,
     target_water_drain: Cell<f32>,
+    target_oxygen_drain: Cell<f32>,
+    target_blood_drain: Cell<f32>,
+    nausea: Cell<f32>,
+    appetite_loss: Cell<f32>,
     chance_of_death: RefCell<Option<usize>>
 }
 
@@ -134,7 +139,11 @@ impl StageBuilder {
                 target_fatigue_delta: Cell::new(0.),
                 target_stamina_drain: Cell::new(0.),
                 target_food_drain: Cell::new(0.),
-                target_water_drain: Cell::new(0.)
+                target_water_drain: Cell::new(0.),
+                target_oxygen_drain: Cell::new(0.),
+                target_blood_drain: Cell::new(0.),
+                nausea: Cell::new(0.),
+                appetite_loss: Cell::new(0.)
             }
         )
     }
@@ -203,7 +212,15 @@ pub struct StageDescription {
     /// Target water drain for this stage (0..100 percents per game second)
     pub target_water_drain: f32,
     /// Target stamina drain for this stage (0..100 percents per game second)
-    pub target_stamina_drain: f32
+    pub target_stamina_drain: f32,
+    /// Target oxygen drain for this stage (0..100 percents per game second)
+    pub target_oxygen_drain: f32,
+    /// Target blood drain for this stage (0..100 percents per game second)
+    pub target_blood_drain: f32,
+    /// Nausea level (0..100) this stage keeps character at. Zero means no nausea
+    pub nausea: f32,
+    /// Percent (0..100) of the food gain that character loses when eating during this stage
+    pub appetite_loss: f32
 }
 impl fmt::Display for StageDescription {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -226,6 +243,39 @@ impl Hash for StageDescription {
         state.write_i32((self.target_stamina_drain*10_000_f32) as i32);
         state.write_i32((self.target_food_drain*10_000_f32) as i32);
         state.write_i32((self.target_water_drain*10_000_f32) as i32);
+        state.write_i32((self.target_oxygen_drain*10_000_f32) as i32);
+        state.write_i32((self.target_blood_drain*10_000_f32) as i32);
+        state.write_u32((self.nausea*10_000_f32) as u32);
+        state.write_u32((self.appetite_loss*10_000_f32) as u32);
+    }
+}
+
+/// Describes a read-only view of an active or scheduled disease, taken with
+/// [`snapshot_diseases`](crate::health::Health::snapshot_diseases)
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct DiseaseInfoC {
+    /// Disease id
+    pub name: String,
+    /// Is disease active as of the last `update` call
+    pub is_active: bool,
+    /// Is disease healing (chain is inverted)
+    pub is_healing: bool,
+    /// When this disease becomes (or became) active
+    pub activation_time: GameTimeC,
+    /// Level of the active stage, `None` if disease is not active
+    pub active_level: Option<StageLevel>,
+    /// How far the active stage is from its peak (0..100)
+    pub stage_percent: usize,
+    /// When this disease will end, `None` if it will not
+    pub end_time: Option<GameTimeC>,
+    /// Disease needs treatment or will self-heal
+    pub needs_treatment: bool,
+    /// Worst stage level this disease has reached so far
+    pub worst_level_reached: Option<StageLevel>
+}
+impl fmt::Display for DiseaseInfoC {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({:?})", self.name, self.active_level)
     }
 }
 
@@ -249,7 +299,9 @@ pub struct DiseaseDeltasC {
     /// Delta value for the food level (relative drain, 0..100 per game second)
     pub food_drain: f32,
     /// Delta value for the water level (relative drain, 0..100 per game second)
-    pub water_drain: f32
+    pub water_drain: f32,
+    /// Delta value for the blood level (relative drain, 0..100 per game second)
+    pub blood_drain: f32
 }
 impl fmt::Display for DiseaseDeltasC {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -269,7 +321,8 @@ impl PartialEq for DiseaseDeltasC {
         f32::abs(self.stamina_drain - other.stamina_drain) < EPS &&
         f32::abs(self.oxygen_drain - other.oxygen_drain) < EPS &&
         f32::abs(self.food_drain - other.food_drain) < EPS &&
-        f32::abs(self.water_drain - other.water_drain) < EPS
+        f32::abs(self.water_drain - other.water_drain) < EPS &&
+        f32::abs(self.blood_drain - other.blood_drain) < EPS
     }
 }
 impl Hash for DiseaseDeltasC {
@@ -283,6 +336,7 @@ impl Hash for DiseaseDeltasC {
         state.write_i32((self.oxygen_drain*10_000_f32) as i32);
         state.write_i32((self.food_drain*10_000_f32) as i32);
         state.write_i32((self.water_drain*10_000_f32) as i32);
+        state.write_i32((self.blood_drain*10_000_f32) as i32);
     }
 }
 impl DiseaseDeltasC {
@@ -304,7 +358,8 @@ impl DiseaseDeltasC {
             stamina_drain: 0.,
             oxygen_drain: 0.,
             food_drain: 0.,
-            water_drain: 0.
+            water_drain: 0.,
+            blood_drain: 0.
         }
     }
     pub(crate) fn for_related() -> Self {
@@ -317,7 +372,8 @@ impl DiseaseDeltasC {
             stamina_drain: 0.,
             food_drain: 0.,
             water_drain: 0.,
-            oxygen_drain: 0.
+            oxygen_drain: 0.,
+            blood_drain: 0.
         }
     }
     pub(crate) fn cleanup(&mut self){
@@ -469,7 +525,17 @@ pub trait DiseaseMonitor {
 /// # Links
 /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Declaring-a-Disease) for more info.
 pub trait Disease {
-    /// Gets the unique name of this disease kind
+    /// Gets stable unique id of this disease kind. Health node and events use it as a key.
+    /// Same as [`get_name`](Disease::get_name) by default; override it when the name is a
+    /// display or localization key that may change
+    ///
+    /// # Examples
+    /// ```
+    /// let id = disease.get_id();
+    /// ```
+    fn get_id(&self) -> String { self.get_name() }
+
+    /// Gets the display name (or localization key) of this disease kind
     /// 
     /// # Examples
     /// ```
@@ -494,6 +560,16 @@ pub trait Disease {
     /// # Links
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Disease-Treatment) for more info.
     fn get_treatment(&self) -> Option<Box<dyn DiseaseTreatment>>;
+    /// Conditions the healing (inverted) chain needs to progress, like rest or warmth (see
+    /// [`HealingConditions`]). `None` by default
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(conditions) = disease.get_healing_conditions() {
+    ///     // ...
+    /// }
+    /// ```
+    fn get_healing_conditions(&self) -> Option<HealingConditions> { None }
     /// For downcasting
     fn as_any(&self) -> &dyn Any;
 }
@@ -509,6 +585,8 @@ struct LerpDataNodeC {
     stamina_data: Vec<LerpDataC>,
     food_data: Vec<LerpDataC>,
     water_data: Vec<LerpDataC>,
+    oxygen_data: Vec<LerpDataC>,
+    blood_data: Vec<LerpDataC>,
     is_endless: bool,
     is_for_inverted: bool
 }
@@ -551,6 +629,12 @@ pub struct ActiveDisease {
     will_end: Cell<bool>,
     /// Disease end time, if applicable
     end_time: RefCell<Option<GameTimeC>>,
+    /// When the chain will be inverted, if `schedule_invert_at` was called
+    scheduled_invert: RefCell<Option<GameTimeC>>,
+    /// Is healing paused because healing conditions are not met
+    is_healing_paused: Cell<bool>,
+    /// The most serious stage level this disease has reached
+    worst_level_reached: Cell<StageLevel>,
     /// Treatment object associated with this disease
     treatment: Rc<Option<Box<dyn DiseaseTreatment>>>,
 
@@ -559,7 +643,7 @@ pub struct ActiveDisease {
 }
 impl fmt::Display for ActiveDisease {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} @{}", self.disease.get_name(), self.activation_time.borrow())
+        write!(f, "{} @{}", self.disease.get_id(), self.activation_time.borrow())
     }
 }
 impl Ord for ActiveDisease {
@@ -575,7 +659,7 @@ impl PartialOrd for ActiveDisease {
 }
 impl PartialEq for ActiveDisease {
     fn eq(&self, other: &Self) -> bool {
-        self.disease.get_name() == other.disease.get_name() &&
+        self.disease.get_id() == other.disease.get_id() &&
         self.activation_time == other.activation_time &&
         self.total_duration == other.total_duration &&
         self.will_self_heal_on == other.will_self_heal_on
@@ -583,7 +667,7 @@ impl PartialEq for ActiveDisease {
 }
 impl Hash for ActiveDisease {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.disease.get_name().hash(state);
+        self.disease.get_id().hash(state);
         self.disease.get_stages().hash(state);
         self.activation_time.borrow().hash(state);
         self.total_duration.hash(state);
@@ -651,6 +735,9 @@ impl ActiveDisease {
             stages: RefCell::new(stages),
             will_end: Cell::new(will_end),
             end_time: RefCell::new(end_time),
+            scheduled_invert: RefCell::new(None),
+            is_healing_paused: Cell::new(false),
+            worst_level_reached: Cell::new(StageLevel::Undefined),
             needs_treatment: !self_heal,
             will_self_heal_on: self_heal_level,
             lerp_data: RefCell::new(None), // will be calculated on first get_vitals_deltas
@@ -39,7 +39,15 @@ pub struct StageDescriptionStateContract {
     /// Captured state of the `target_water_drain` field
     pub target_water_drain: f32,
     /// Captured state of the `target_stamina_drain` field
-    pub target_stamina_drain: f32
+    pub target_stamina_drain: f32,
+    /// Captured state of the `target_oxygen_drain` field
+    pub target_oxygen_drain: f32,
+    /// Captured state of the `target_blood_drain` field
+    pub target_blood_drain: f32,
+    /// Captured state of the `nausea` field
+    pub nausea: f32,
+    /// Captured state of the `appetite_loss` field
+    pub appetite_loss: f32
 }
 impl fmt::Display for StageDescriptionStateContract {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -72,6 +80,10 @@ impl PartialEq for StageDescriptionStateContract {
         f32::abs(self.target_fatigue_delta - other.target_fatigue_delta) < EPS &&
         f32::abs(self.target_food_drain - other.target_food_drain) < EPS &&
         f32::abs(self.target_water_drain - other.target_water_drain) < EPS &&
+        f32::abs(self.target_oxygen_drain - other.target_oxygen_drain) < EPS &&
+        f32::abs(self.target_blood_drain - other.target_blood_drain) < EPS &&
+        f32::abs(self.nausea - other.nausea) < EPS &&
+        f32::abs(self.appetite_loss - other.appetite_loss) < EPS &&
         f32::abs(self.target_stamina_drain - other.target_stamina_drain) < EPS
     }
 }
@@ -91,7 +103,11 @@ impl Hash for StageDescriptionStateContract {
         state.write_i32((self.target_fatigue_delta*10_000_f32) as i32);
         state.write_i32((self.target_food_drain*10_000_f32) as i32);
         state.write_i32((self.target_water_drain*10_000_f32) as i32);
+        state.write_i32((self.target_oxygen_drain*10_000_f32) as i32);
+        state.write_i32((self.target_blood_drain*10_000_f32) as i32);
         state.write_i32((self.target_stamina_drain*10_000_f32) as i32);
+        state.write_u32((self.nausea*10_000_f32) as u32);
+        state.write_u32((self.appetite_loss*10_000_f32) as u32);
     }
 }
 
@@ -133,6 +149,10 @@ pub struct LerpDataNodeStateContract {
     pub food_data: Vec<LerpDataStateContract>,
     /// Captured state of the `water_data` field
     pub water_data: Vec<LerpDataStateContract>,
+    /// Captured state of the `oxygen_data` field
+    pub oxygen_data: Vec<LerpDataStateContract>,
+    /// Captured state of the `blood_data` field
+    pub blood_data: Vec<LerpDataStateContract>,
     /// Captured state of the `is_endless` field
     pub is_endless: bool,
     /// Captured state of the `is_for_inverted` field
@@ -174,6 +194,8 @@ impl PartialEq for LerpDataNodeStateContract {
         self.stamina_data == other.stamina_data &&
         self.food_data == other.food_data &&
         self.water_data == other.water_data &&
+        self.oxygen_data == other.oxygen_data &&
+        self.blood_data == other.blood_data &&
         f32::abs(self.start_time - other.start_time) < EPS &&
         f32::abs(self.end_time - other.end_time) < EPS
     }
@@ -191,6 +213,8 @@ impl Hash for LerpDataNodeStateContract {
         self.stamina_data.hash(state);
         self.food_data.hash(state);
         self.water_data.hash(state);
+        self.oxygen_data.hash(state);
+        self.blood_data.hash(state);
 
         state.write_u32(self.start_time as u32);
         state.write_u32(self.end_time as u32);
@@ -280,7 +304,9 @@ pub struct DiseaseDeltasStateContract {
     /// Captured state of the `food_drain` field
     pub food_drain: f32,
     /// Captured state of the `water_drain` field
-    pub water_drain: f32
+    pub water_drain: f32,
+    /// Captured state of the `blood_drain` field
+    pub blood_drain: f32
 }
 impl fmt::Display for DiseaseDeltasStateContract {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -300,7 +326,8 @@ impl PartialEq for DiseaseDeltasStateContract {
         f32::abs(self.stamina_drain - other.stamina_drain) < EPS &&
         f32::abs(self.oxygen_drain - other.oxygen_drain) < EPS &&
         f32::abs(self.food_drain - other.food_drain) < EPS &&
-        f32::abs(self.water_drain - other.water_drain) < EPS
+        f32::abs(self.water_drain - other.water_drain) < EPS &&
+        f32::abs(self.blood_drain - other.blood_drain) < EPS
     }
 }
 impl Hash for DiseaseDeltasStateContract {
@@ -314,6 +341,7 @@ impl Hash for DiseaseDeltasStateContract {
         state.write_i32((self.oxygen_drain*10_000_f32) as i32);
         state.write_i32((self.food_drain*10_000_f32) as i32);
         state.write_i32((self.water_drain*10_000_f32) as i32);
+        state.write_i32((self.blood_drain*10_000_f32) as i32);
     }
 }
 
@@ -325,6 +353,8 @@ impl StageDescription {
             reaches_peak_in_hours: self.reaches_peak_in_hours,
             chance_of_death: self.chance_of_death.clone(),
             target_water_drain: self.target_water_drain,
+            target_oxygen_drain: self.target_oxygen_drain,
+            target_blood_drain: self.target_blood_drain,
             target_food_drain: self.target_food_drain,
             target_stamina_drain: self.target_stamina_drain,
             target_fatigue_delta: self.target_fatigue_delta,
@@ -332,7 +362,9 @@ impl StageDescription {
             target_pressure_bottom: self.target_pressure_bottom,
             target_heart_rate: self.target_heart_rate,
             target_body_temp: self.target_body_temp,
-            self_heal_chance: self.self_heal_chance.clone()
+            self_heal_chance: self.self_heal_chance,
+            nausea: self.nausea,
+            appetite_loss: self.appetite_loss
         }
     }
 }
@@ -346,6 +378,8 @@ impl LerpDataNodeC {
             is_for_inverted: self.is_for_inverted,
             water_data: self.water_data.iter().map(|x| x.get_state()).collect(),
             food_data: self.food_data.iter().map(|x| x.get_state()).collect(),
+            oxygen_data: self.oxygen_data.iter().map(|x| x.get_state()).collect(),
+            blood_data: self.blood_data.iter().map(|x| x.get_state()).collect(),
             stamina_data: self.stamina_data.iter().map(|x| x.get_state()).collect(),
             fatigue_data: self.fatigue_data.iter().map(|x| x.get_state()).collect(),
             pressure_top_data: self.pressure_top_data.iter().map(|x| x.get_state()).collect(),
@@ -387,9 +421,9 @@ impl Health {
     /// ## Notes
     /// Borrows `diseases` collection
     pub fn restore_disease(&self, disease_data: &ActiveDiseaseStateContract, disease: Box<dyn Disease>) {
-        let mut b = self.diseases.borrow_mut();
+        let mut b = self.active_diseases.borrow_mut();
         let treatment = disease.get_treatment();
-        let name = disease.get_name().to_string();
+        let name = disease.get_id().to_string();
         let d = ActiveDisease {
             disease: Rc::new(disease),
             needs_treatment: disease_data.needs_treatment,
@@ -400,6 +434,9 @@ impl Health {
             last_deltas: RefCell::new(DiseaseDeltasC::empty()),
             initial_data: RefCell::new(Vec::new()),
             end_time: RefCell::new(None),
+            scheduled_invert: RefCell::new(None),
+            is_healing_paused: Cell::new(false),
+            worst_level_reached: Cell::new(StageLevel::Undefined),
             lerp_data: RefCell::new(None),
             is_inverted: Cell::new(false),
             activation_time: RefCell::new(GameTimeC::empty()),
@@ -426,12 +463,16 @@ impl ActiveDisease {
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management#saving-and-restoring-diseases-and-injuries) for more info.
     pub fn get_state(&self) -> ActiveDiseaseStateContract {
         ActiveDiseaseStateContract {
+            version: crate::state::STATE_CONTRACT_VERSION,
             needs_treatment: self.needs_treatment,
             activation_time: self.activation_time.borrow().to_duration(),
             will_end: self.will_end.get(),
             end_time: self.end_time.borrow().as_ref().map(|x| x.to_duration()),
+            scheduled_invert: self.scheduled_invert.borrow().as_ref().map(|x| x.to_duration()),
             will_self_heal_on: self.will_self_heal_on,
             is_inverted: self.is_inverted.get(),
+            is_healing_paused: self.is_healing_paused.get(),
+            worst_level_reached: self.worst_level_reached.get(),
             total_duration: self.total_duration,
 
             lerp_data: self.lerp_data.borrow().as_ref().map(|x| x.get_state()),
@@ -446,7 +487,10 @@ impl ActiveDisease {
         self.will_end.set(state.will_end);
 
         self.end_time.replace(state.end_time.map(|x| GameTimeC::from_duration(x)));
+        self.scheduled_invert.replace(state.scheduled_invert.map(GameTimeC::from_duration));
         self.is_inverted.set(state.is_inverted);
+        self.is_healing_paused.set(state.is_healing_paused);
+        self.worst_level_reached.set(state.worst_level_reached);
 
         self.initial_data.replace(state.initial_data.iter().map(|x| StageDescription{
             is_endless: x.is_endless,
@@ -461,7 +505,11 @@ impl ActiveDisease {
             target_stamina_drain: x.target_stamina_drain,
             target_food_drain: x.target_food_drain,
             target_water_drain: x.target_water_drain,
-            reaches_peak_in_hours: x.reaches_peak_in_hours
+            target_oxygen_drain: x.target_oxygen_drain,
+            target_blood_drain: x.target_blood_drain,
+            reaches_peak_in_hours: x.reaches_peak_in_hours,
+            nausea: x.nausea,
+            appetite_loss: x.appetite_loss
         }).collect());
 
         {
@@ -477,6 +525,8 @@ impl ActiveDisease {
                     info: StageDescription {
                         reaches_peak_in_hours: stage.info.reaches_peak_in_hours,
                         target_water_drain: stage.info.target_water_drain,
+                        target_oxygen_drain: stage.info.target_oxygen_drain,
+                        target_blood_drain: stage.info.target_blood_drain,
                         target_food_drain: stage.info.target_food_drain,
                         target_stamina_drain: stage.info.target_stamina_drain,
                         target_fatigue_delta: stage.info.target_fatigue_delta,
@@ -488,6 +538,8 @@ impl ActiveDisease {
                         level: stage.info.level.clone(),
                         chance_of_death: stage.info.chance_of_death.clone(),
                         self_heal_chance: stage.info.self_heal_chance.clone(),
+                        nausea: stage.info.nausea,
+                        appetite_loss: stage.info.appetite_loss
                     }
                 });
             }
@@ -502,7 +554,8 @@ impl ActiveDisease {
             food_drain: state.last_deltas.food_drain,
             stamina_drain: state.last_deltas.stamina_drain,
             water_drain: state.last_deltas.water_drain,
-            oxygen_drain: state.last_deltas.oxygen_drain
+            oxygen_drain: state.last_deltas.oxygen_drain,
+            blood_drain: state.last_deltas.blood_drain
         });
 
         match &state.lerp_data {
@@ -519,6 +572,22 @@ impl ActiveDisease {
                     end_value: x.end_value,
                     is_endless: x.is_endless
                 }).collect(),
+                oxygen_data: l.oxygen_data.iter().map(|x| LerpDataC {
+                    start_time: x.start_time,
+                    end_time: x.end_time,
+                    duration: x.duration,
+                    start_value: x.start_value,
+                    end_value: x.end_value,
+                    is_endless: x.is_endless
+                }).collect(),
+                blood_data: l.blood_data.iter().map(|x| LerpDataC {
+                    start_time: x.start_time,
+                    end_time: x.end_time,
+                    duration: x.duration,
+                    start_value: x.start_value,
+                    end_value: x.end_value,
+                    is_endless: x.is_endless
+                }).collect(),
                 food_data: l.food_data.iter().map(|x| LerpDataC {
                     start_time: x.start_time,
                     end_time: x.end_time,
@@ -585,6 +654,7 @@ impl DiseaseDeltasC {
     pub(crate) fn get_state(&self) -> DiseaseDeltasStateContract {
         DiseaseDeltasStateContract {
             oxygen_drain: self.oxygen_drain,
+            blood_drain: self.blood_drain,
             water_drain: self.water_drain,
             stamina_drain: self.stamina_drain,
             food_drain: self.food_drain,
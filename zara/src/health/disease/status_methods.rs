@@ -1,7 +1,9 @@
-use crate::health::disease::{ActiveDisease, ActiveStage};
+use crate::health::disease::{ActiveDisease, ActiveStage, DiseaseDeltasC};
 use crate::utils::GameTimeC;
 use crate::health::StageLevel;
 
+use std::time::Duration;
+
 impl ActiveDisease {
     /// Gets if this disease will end (is it finite)
     /// 
@@ -19,6 +21,34 @@ impl ActiveDisease {
     /// ```
     pub fn is_healing(&self) -> bool { self.is_inverted.get() }
 
+    /// Gets if healing of this disease is paused because its healing conditions are not met
+    /// 
+    /// # Examples
+    /// ```
+    /// let value = disease.is_healing_paused();
+    /// ```
+    pub fn is_healing_paused(&self) -> bool { self.is_healing_paused.get() }
+
+    /// Gets the most serious stage level this disease has reached, `None` if it was not active yet
+    /// 
+    /// # Examples
+    /// ```
+    /// let value = disease.worst_level_reached();
+    /// ```
+    pub fn worst_level_reached(&self) -> Option<StageLevel> {
+        match self.worst_level_reached.get() {
+            StageLevel::Undefined => None,
+            level => Some(level)
+        }
+    }
+
+    /// Remembers the active stage level if it is the most serious one so far
+    pub(crate) fn note_active_level(&self, game_time: &GameTimeC) {
+        if let Some(level) = self.active_level(game_time) {
+            if level > self.worst_level_reached.get() { self.worst_level_reached.set(level); }
+        }
+    }
+
     /// Gets the end time of this disease, if it is finite
     /// 
     /// # Examples
@@ -86,6 +116,19 @@ impl ActiveDisease {
         None
     }
 
+    /// Returns a copy of the worst (last) stage of this disease chain, which peak is
+    /// the disease peak
+    /// 
+    /// # Examples
+    /// ```
+    /// if let Some(stage) = disease.get_worst_stage() {
+    ///     let peak_time = stage.peak_time;
+    /// }
+    /// ```
+    pub fn get_worst_stage(&self) -> Option<ActiveStage> {
+        self.stages.borrow().values().next_back().cloned()
+    }
+
     /// Gets whether disease is active or not for a given time
     /// 
     /// # Examples
@@ -123,4 +166,193 @@ impl ActiveDisease {
             None => false
         }
     }
+
+    /// Returns how much game time is left until the next stage of this disease starts, for a given
+    /// game time. `None` if there are no more stages ahead (current stage is the last or endless one)
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to count from
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(time_left) = disease.time_until_next_stage(&game_time) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn time_until_next_stage(&self, game_time: &GameTimeC) -> Option<GameTimeC> {
+        let gt = game_time.as_secs_f32();
+        let mut result = None;
+
+        for (_, stage) in self.stages.borrow().iter() {
+            let start = stage.start_time.as_secs_f32();
+
+            if start <= gt { continue; }
+
+            match result {
+                Some(r) if r <= start => { },
+                _ => result = Some(start)
+            }
+        }
+
+        result.map(|start| GameTimeC::from_duration(Duration::from_secs_f32(start - gt)))
+    }
+
+    /// Returns current interpolated contribution of this disease to the player vitals for a given
+    /// game time. Empty deltas if the disease is not active at this time
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to get deltas for
+    ///
+    /// # Examples
+    /// ```
+    /// let deltas = disease.current_deltas(&game_time);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `lerp_data` and `stages` collections
+    pub fn current_deltas(&self, game_time: &GameTimeC) -> DiseaseDeltasC {
+        if !self.is_active(game_time) { return DiseaseDeltasC::empty(); }
+
+        self.project_vitals_deltas(game_time, game_time)
+    }
+
+    /// Returns current body temperature delta of this disease for a given game time
+    /// (degrees C per game second)
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to get delta for
+    ///
+    /// # Examples
+    /// ```
+    /// let value = disease.current_body_temp_delta(&game_time);
+    /// ```
+    pub fn current_body_temp_delta(&self, game_time: &GameTimeC) -> f32 {
+        self.current_deltas(game_time).body_temperature_delta
+    }
+
+    /// Returns current heart rate delta of this disease for a given game time
+    /// (bpm per game second)
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to get delta for
+    ///
+    /// # Examples
+    /// ```
+    /// let value = disease.current_heart_rate_delta(&game_time);
+    /// ```
+    pub fn current_heart_rate_delta(&self, game_time: &GameTimeC) -> f32 {
+        self.current_deltas(game_time).heart_rate_delta
+    }
+
+    /// Returns current top blood pressure delta of this disease for a given game time
+    /// (mmHg per game second)
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to get delta for
+    ///
+    /// # Examples
+    /// ```
+    /// let value = disease.current_top_pressure_delta(&game_time);
+    /// ```
+    pub fn current_top_pressure_delta(&self, game_time: &GameTimeC) -> f32 {
+        self.current_deltas(game_time).pressure_top_delta
+    }
+
+    /// Returns current bottom blood pressure delta of this disease for a given game time
+    /// (mmHg per game second)
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to get delta for
+    ///
+    /// # Examples
+    /// ```
+    /// let value = disease.current_bottom_pressure_delta(&game_time);
+    /// ```
+    pub fn current_bottom_pressure_delta(&self, game_time: &GameTimeC) -> f32 {
+        self.current_deltas(game_time).pressure_bottom_delta
+    }
+
+    /// Returns current fatigue delta of this disease for a given game time
+    /// (0..100 per game second)
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to get delta for
+    ///
+    /// # Examples
+    /// ```
+    /// let value = disease.current_fatigue_delta(&game_time);
+    /// ```
+    pub fn current_fatigue_delta(&self, game_time: &GameTimeC) -> f32 {
+        self.current_deltas(game_time).fatigue_delta
+    }
+
+    /// Returns current stamina drain of this disease for a given game time
+    /// (0..100 per game second)
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to get drain for
+    ///
+    /// # Examples
+    /// ```
+    /// let value = disease.current_stamina_drain(&game_time);
+    /// ```
+    pub fn current_stamina_drain(&self, game_time: &GameTimeC) -> f32 {
+        self.current_deltas(game_time).stamina_drain
+    }
+
+    /// Returns current oxygen drain of this disease for a given game time
+    /// (0..100 per game second)
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to get drain for
+    ///
+    /// # Examples
+    /// ```
+    /// let value = disease.current_oxygen_drain(&game_time);
+    /// ```
+    pub fn current_oxygen_drain(&self, game_time: &GameTimeC) -> f32 {
+        self.current_deltas(game_time).oxygen_drain
+    }
+
+    /// Returns current blood drain of this disease for a given game time
+    /// (0..100 per game second)
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to get drain for
+    ///
+    /// # Examples
+    /// ```
+    /// let value = disease.current_blood_drain(&game_time);
+    /// ```
+    pub fn current_blood_drain(&self, game_time: &GameTimeC) -> f32 {
+        self.current_deltas(game_time).blood_drain
+    }
+
+    /// Returns current food drain of this disease for a given game time
+    /// (0..100 per game second)
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to get drain for
+    ///
+    /// # Examples
+    /// ```
+    /// let value = disease.current_food_drain(&game_time);
+    /// ```
+    pub fn current_food_drain(&self, game_time: &GameTimeC) -> f32 {
+        self.current_deltas(game_time).food_drain
+    }
+
+    /// Returns current water drain of this disease for a given game time
+    /// (0..100 per game second)
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to get drain for
+    ///
+    /// # Examples
+    /// ```
+    /// let value = disease.current_water_drain(&game_time);
+    /// ```
+    pub fn current_water_drain(&self, game_time: &GameTimeC) -> f32 {
+        self.current_deltas(game_time).water_drain
+    }
 }
\ No newline at end of file
@@ -0,0 +1,93 @@
+use crate::health::Health;
+use crate::health::disease::Disease;
+use crate::health::injury::Injury;
+
+use std::collections::BTreeMap;
+
+/// Function that creates a fresh instance of a disease kind
+pub type DiseaseFactory = Box<dyn Fn() -> Box<dyn Disease>>;
+/// Function that creates a fresh instance of an injury kind
+pub type InjuryFactory = Box<dyn Fn() -> Box<dyn Injury>>;
+
+impl Health {
+    /// Ids of the registered disease and injury kinds by their display names, for the kinds
+    /// whose ids differ from their names
+    pub(crate) fn kind_ids_by_names(&self) -> BTreeMap<String, String> {
+        let mut ids = BTreeMap::new();
+
+        for factory in self.disease_factories.borrow().values() {
+            let disease = factory();
+
+            if disease.get_id() != disease.get_name() { ids.insert(disease.get_name(), disease.get_id()); }
+        }
+        for factory in self.injury_factories.borrow().values() {
+            let injury = factory();
+
+            if injury.get_id() != injury.get_name() { ids.insert(injury.get_name(), injury.get_id()); }
+        }
+
+        ids
+    }
+
+    /// Registers factories of the disease kinds, so that
+    /// [`restore_full_state`](crate::ZaraController::restore_full_state) can recreate active
+    /// diseases. Factories are keyed by the `get_id` of the disease they create; factory with the
+    /// same key is replaced
+    ///
+    /// # Parameters
+    /// - `factories`: disease factories
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.register_disease_factories(vec![
+    ///     Box::new(|| Box::new(Flu)),
+    ///     Box::new(|| Box::new(Rabies))
+    /// ]);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `disease_factories` collection
+    pub fn register_disease_factories(&self, factories: Vec<DiseaseFactory>) {
+        let mut b = self.disease_factories.borrow_mut();
+
+        for factory in factories {
+            b.insert(factory().get_id(), factory);
+        }
+    }
+
+    /// Registers factories of the injury kinds, so that
+    /// [`restore_full_state`](crate::ZaraController::restore_full_state) can recreate active
+    /// injuries. Factories are keyed by the `get_id` of the injury they create; factory with the
+    /// same key is replaced
+    ///
+    /// # Parameters
+    /// - `factories`: injury factories
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.register_injury_factories(vec![
+    ///     Box::new(|| Box::new(Cut)),
+    ///     Box::new(|| Box::new(Fracture))
+    /// ]);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `injury_factories` collection
+    pub fn register_injury_factories(&self, factories: Vec<InjuryFactory>) {
+        let mut b = self.injury_factories.borrow_mut();
+
+        for factory in factories {
+            b.insert(factory().get_id(), factory);
+        }
+    }
+
+    /// Creates a disease instance by its id using registered factories
+    pub(crate) fn create_disease(&self, disease_id: &String) -> Option<Box<dyn Disease>> {
+        self.disease_factories.borrow().get(disease_id).map(|f| f())
+    }
+
+    /// Creates an injury instance by its id using registered factories
+    pub(crate) fn create_injury(&self, injury_id: &String) -> Option<Box<dyn Injury>> {
+        self.injury_factories.borrow().get(injury_id).map(|f| f())
+    }
+}
@@ -0,0 +1,100 @@
+use crate::utils::PlayerStatusC;
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Conditions a healing (inverted) disease or injury chain needs to progress, like rest and
+/// warmth. When any of them is not met, healing pauses until it is.
+///
+/// Return it from [`Disease::get_healing_conditions`](crate::health::disease::Disease::get_healing_conditions)
+/// or [`Injury::get_healing_conditions`](crate::health::injury::Injury::get_healing_conditions)
+///
+/// # Examples
+/// ```
+/// use zara::health::healing::HealingConditions;
+///
+/// let conditions = HealingConditions::new()
+///     .while_sleeping()
+///     .warmth_above(-5.)
+///     .not_running();
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct HealingConditions {
+    /// Healing progresses only while player is sleeping
+    pub needs_sleep: bool,
+    /// Healing progresses only while player warmth level is above this value
+    pub min_warmth: Option<f32>,
+    /// Healing pauses while player is running
+    pub no_running: bool
+}
+impl fmt::Display for HealingConditions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Healing conditions: sleep {}, min warmth {:?}, no running {}",
+               self.needs_sleep, self.min_warmth, self.no_running)
+    }
+}
+impl Eq for HealingConditions { }
+impl PartialEq for HealingConditions {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.needs_sleep == other.needs_sleep &&
+        self.no_running == other.no_running &&
+        match (self.min_warmth, other.min_warmth) {
+            (Some(a), Some(b)) => f32::abs(a - b) < EPS,
+            (None, None) => true,
+            _ => false
+        }
+    }
+}
+impl Hash for HealingConditions {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.needs_sleep.hash(state);
+        self.no_running.hash(state);
+        self.min_warmth.map(|x| (x*10_000_f32) as i32).hash(state);
+    }
+}
+impl HealingConditions {
+    /// Creates an empty condition set. Healing always progresses with it
+    pub fn new() -> Self { HealingConditions::default() }
+
+    /// Healing progresses only while player is sleeping
+    pub fn while_sleeping(mut self) -> Self {
+        self.needs_sleep = true;
+        self
+    }
+
+    /// Healing progresses only while player warmth level is above a given value
+    ///
+    /// # Parameters
+    /// - `value`: warmth level (like `-5.`)
+    pub fn warmth_above(mut self, value: f32) -> Self {
+        self.min_warmth = Some(value);
+        self
+    }
+
+    /// Healing pauses while player is running
+    pub fn not_running(mut self) -> Self {
+        self.no_running = true;
+        self
+    }
+
+    /// Returns `true` if player status satisfies all conditions
+    ///
+    /// # Parameters
+    /// - `player`: player status
+    ///
+    /// # Examples
+    /// ```
+    /// let value = conditions.are_met(&frame_data.player);
+    /// ```
+    pub fn are_met(&self, player: &PlayerStatusC) -> bool {
+        if self.needs_sleep && !player.is_sleeping { return false; }
+        if self.no_running && player.is_running { return false; }
+        if let Some(min) = self.min_warmth {
+            if player.warmth_level <= min { return false; }
+        }
+
+        true
+    }
+}
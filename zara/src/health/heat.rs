@@ -0,0 +1,97 @@
+use crate::health::Health;
+use crate::health::disease::Disease;
+use crate::health::side::SideEffectDeltasC;
+use crate::inventory::items::ConsumableC;
+use crate::utils::{clamp, clamp_01, FrameSummaryC};
+
+/// Water drain from sweating at the maximum heat stress, percent per game second
+const MAX_SWEAT_WATER_DRAIN: f32 = 0.004;
+/// Heat stress level at which heat exhaustion disease is spawned
+const HEAT_EXHAUSTION_THRESHOLD: f32 = 100.;
+/// Temperature of the consumed item that does not change body temperature, degrees C
+const NEUTRAL_CONSUMED_TEMPERATURE: f32 = 37.;
+/// Body temperature change per degree C of the consumed item temperature, for a full dose
+const CONSUMED_HEAT_PER_DEGREE: f32 = 0.01;
+/// Food and water gain of a full dose. Smaller doses warm or chill proportionally less
+const CONSUMED_HEAT_FULL_DOSE: f32 = 10.;
+/// Maximum body temperature change from the consumed items, degrees C
+const MAX_CONSUMED_HEAT: f32 = 0.8;
+/// Consumed items warmth effect half-life, game seconds
+const CONSUMED_HEAT_HALF_LIFE: f32 = 15. * 60.;
+
+/// Function that creates a heat exhaustion disease instance
+pub type HeatExhaustionFactory = Box<dyn Fn() -> Box<dyn Disease>>;
+
+impl Health {
+    /// Sets a factory for the disease that will be spawned when heat stress reaches its maximum.
+    /// Pass `None` to never spawn heat exhaustion
+    ///
+    /// # Parameters
+    /// - `factory`: function that creates heat exhaustion disease
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.set_heat_exhaustion_factory(Some(Box::new(|| Box::new(HeatExhaustion))));
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Warmth-level) for more info.
+    pub fn set_heat_exhaustion_factory(&self, factory: Option<HeatExhaustionFactory>) {
+        self.heat_exhaustion_factory.replace(factory);
+    }
+
+    /// Returns current body temperature change caused by hot or cold consumed items, degrees C.
+    /// Effect is short-lived: it halves every 15 game minutes
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.health.consumed_heat();
+    /// ```
+    pub fn consumed_heat(&self) -> f32 { self.consumed_heat.get() }
+
+    /// Adds warmth delta of a consumed item with known temperature
+    pub(crate) fn add_consumed_heat(&self, item: &ConsumableC) {
+        let temperature = match item.temperature {
+            Some(t) => t,
+            None => return
+        };
+        let dose = clamp_01((item.food_gain + item.water_gain) / CONSUMED_HEAT_FULL_DOSE) * item.consumed_count as f32;
+        let delta = (temperature - NEUTRAL_CONSUMED_TEMPERATURE) * CONSUMED_HEAT_PER_DEGREE * dose;
+
+        self.consumed_heat.set(clamp(self.consumed_heat.get() + delta, -MAX_CONSUMED_HEAT, MAX_CONSUMED_HEAT));
+    }
+
+    /// Adds consumed items warmth to the side effects deltas and lets it fade
+    pub(crate) fn apply_consumed_heat(&self, deltas: &mut SideEffectDeltasC, frame_data: &FrameSummaryC) {
+        let heat = self.consumed_heat.get();
+
+        if heat == 0. { return; }
+
+        deltas.body_temp_bonus += heat;
+
+        let faded = heat * f32::powf(0.5, frame_data.game_time_delta / CONSUMED_HEAT_HALF_LIFE);
+
+        self.consumed_heat.set(if f32::abs(faded) < 0.001 { 0. } else { faded });
+    }
+
+    /// Adds sweating water drain to the side effects deltas
+    pub(crate) fn apply_heat_stress(&self, deltas: &mut SideEffectDeltasC, frame_data: &FrameSummaryC) {
+        let stress = frame_data.player.heat_stress / 100.;
+
+        deltas.water_level_bonus -= stress * MAX_SWEAT_WATER_DRAIN * frame_data.game_time_delta;
+    }
+
+    /// Spawns heat exhaustion disease if heat stress is at its maximum
+    pub(crate) fn check_heat_exhaustion(&self, frame_data: &FrameSummaryC) {
+        if frame_data.player.heat_stress < HEAT_EXHAUSTION_THRESHOLD { return; }
+
+        let disease = match self.heat_exhaustion_factory.borrow().as_ref() {
+            Some(factory) => factory(),
+            None => return
+        };
+
+        if self.active_diseases.borrow().contains_key(&disease.get_id()) { return; }
+
+        self.spawn_disease(disease, frame_data.game_time).ok();
+    }
+}
\ No newline at end of file
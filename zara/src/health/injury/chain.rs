@@ -1,7 +1,7 @@
 use crate::utils::event::{MessageQueue, Event};
 use crate::health::injury::{ActiveInjury, ActiveStage, StageLevel};
-use crate::utils::{GameTimeC, clamp_bottom};
-use crate::error::{ChainInvertErr, ChainInvertBackErr};
+use crate::utils::{GameTimeC, PlayerStatusC, clamp_bottom, clamp_01};
+use crate::error::{ChainInvertErr, ChainInvertBackErr, ChainDowngradeErr};
 
 use std::time::Duration;
 use std::collections::{BTreeMap};
@@ -145,11 +145,127 @@ impl ActiveInjury {
         self.will_end.set(true);
         self.is_inverted.set(true);
 
-        self.queue_message(Event::InjuryInverted(self.injury.get_name(), self.body_part));
+        self.queue_message(Event::InjuryInverted(self.injury.get_id(), self.body_part));
 
         Ok(())
     }
 
+    /// Shifts injury one stage level down, keeping the progress of the current stage. For
+    /// example, injury that is 40% into its `Worrying` stage becomes 40% into its `Progressing`
+    /// stage. Timings of the whole chain are recomputed, so the injury keeps going in its
+    /// current direction (getting worse, or healing if inverted) from the new point.
+    ///
+    /// Use this for treatments that reduce severity without starting the full recovery
+    ///
+    /// ``` none
+    /// |InitialStage------>|Progressing--------->|Worrying--------------->|Critical...
+    ///                                                          ^
+    ///                                                     current time
+    ///                                                     (Worrying 40%)
+    /// ```
+    /// After the `downgrade` call:
+    /// ``` none
+    /// |InitialStage------>|Progressing--------->|Worrying--------------->|Critical...
+    ///                                  ^
+    ///                             current time
+    ///                           (Progressing 40%)
+    /// ```
+    ///
+    /// # Parameters
+    /// - `game_time`: game time of the downgrade
+    ///
+    /// # Returns
+    /// New active stage level on success.
+    ///
+    /// # Examples
+    /// ```
+    /// injury.downgrade(&game_time);
+    /// ```
+    ///
+    /// ## Notes
+    /// Will return `ChainDowngradeErr::AlreadyAtLowestStage` error if injury is already at its
+    /// lowest stage. Use [`invert`] to cure it instead.
+    ///
+    /// [`invert`]: #method.invert
+    pub fn downgrade(&self, game_time: &GameTimeC) -> Result<StageLevel, ChainDowngradeErr> {
+        if !self.is_active(game_time) { return Err(ChainDowngradeErr::InjuryNotActiveAtGivenTime); }
+        let active_stage = match self.get_active_stage(game_time) {
+            Some(o) => o,
+            None => return Err(ChainDowngradeErr::NoActiveStageAtGivenTime)
+        };
+        let lower_stage = match self.stages.borrow().range(..active_stage.info.level).next_back() {
+            Some((_, o)) => *o,
+            None => return Err(ChainDowngradeErr::AlreadyAtLowestStage)
+        };
+
+        let gt = game_time.as_secs_f32();
+        let start = active_stage.start_time.as_secs_f32();
+        let duration = active_stage.duration.as_secs_f32();
+        let progress = if duration > 0. { clamp_01((gt - start) / duration) } else { 1. };
+
+        // Move the whole chain so that the same progress of the lower stage falls on `gt`
+        let target = lower_stage.start_time.as_secs_f32() + lower_stage.duration.as_secs_f32() * progress;
+
+        self.shift_chain(gt - target);
+
+        self.queue_message(Event::InjuryDowngraded(self.injury.get_id(), self.body_part, lower_stage.info.level));
+
+        Ok(lower_stage.info.level)
+    }
+
+    /// Pauses healing for the last frame if healing conditions are not met
+    pub(crate) fn process_healing_conditions(&self, player: &PlayerStatusC, game_time_delta: f32) {
+        if !self.is_inverted.get() {
+            self.is_healing_paused.set(false);
+            return;
+        }
+
+        let are_met = match self.injury.get_healing_conditions() {
+            Some(c) => c.are_met(player),
+            None => true
+        };
+
+        if !are_met {
+            // Undo the healing progress of the last frame
+            self.shift_chain(game_time_delta);
+
+            if !self.is_healing_paused.get() {
+                self.is_healing_paused.set(true);
+                self.queue_message(Event::InjuryHealingPaused(self.injury.get_id(), self.body_part));
+            }
+        } else if self.is_healing_paused.get() {
+            self.is_healing_paused.set(false);
+            self.queue_message(Event::InjuryHealingResumed(self.injury.get_id(), self.body_part));
+        }
+    }
+
+    /// Moves all chain timings by a given amount of game seconds. Stages that would end before
+    /// the start of the game time are dropped, and a stage that would start before it is cut at zero
+    fn shift_chain(&self, seconds: f32) {
+        let to_time = |t: f32| GameTimeC::from_duration(Duration::from_secs_f32(clamp_bottom(t, 0.)));
+        let shifted = |t: &GameTimeC| to_time(t.as_secs_f32() + seconds);
+
+        self.stages.borrow_mut().retain(|_, stage| {
+            let start_time = stage.start_time.as_secs_f32() + seconds;
+            let peak_time = stage.peak_time.as_secs_f32() + seconds;
+
+            if peak_time < 0. { return false; }
+            if start_time < 0. { stage.duration = Duration::from_secs_f32(peak_time); }
+
+            stage.start_time = to_time(start_time);
+            stage.peak_time = to_time(peak_time);
+
+            true
+        });
+
+        let activation_time = shifted(&self.activation_time.borrow());
+        let end_time = self.end_time.borrow().as_ref().map(&shifted);
+
+        self.activation_time.replace(activation_time);
+        self.end_time.replace(end_time);
+        self.lerp_data.replace(None);
+    }
+
     /// Only if injury is now healing. Inverts injury stages back so that injury goes from the
     /// current state to its end. Use this to cancel the "curing" process and make injury getting
     /// "worse" again.
@@ -303,7 +419,7 @@ impl ActiveInjury {
         self.will_end.set(will_end);
         self.is_inverted.set(false);
 
-        self.queue_message(Event::InjuryResumed(self.injury.get_name(), self.body_part));
+        self.queue_message(Event::InjuryResumed(self.injury.get_id(), self.body_part));
 
         Ok(())
     }
@@ -1,9 +1,10 @@
 use crate::utils::event::{Event, MessageQueue};
-use crate::health::{Health, InjuryKey};
-use crate::health::injury::{ActiveInjury, Injury};
+use crate::health::{Health, InjuryKey, RemovalReason};
+use crate::health::injury::{ActiveInjury, Injury, InjuryInfoC};
 use crate::utils::GameTimeC;
 use crate::error::{SpawnInjuryErr, RemoveInjuryErr};
 use crate::body::BodyPart;
+use crate::state::DirtyTracked;
 
 use std::rc::Rc;
 
@@ -33,10 +34,14 @@ impl Health {
     pub fn spawn_injury(&self, injury: Box<dyn Injury>, body_part: BodyPart, activation_time: GameTimeC)
                         -> Result<InjuryKey, SpawnInjuryErr> {
         if !self.is_alive.get() { return Err(SpawnInjuryErr::CharacterIsDead); }
+        if let BodyPart::Custom(_) = body_part {
+            if !self.body_parts.is_registered(body_part) { return Err(SpawnInjuryErr::UnknownBodyPart); }
+        }
 
-        let mut b = self.injuries.borrow_mut();
-        let injury_name = injury.get_name();
-        let name_for_message= injury.get_name().to_string();
+        let mut b = self.active_injuries.borrow_mut();
+        let injury_name = injury.get_id();
+        let name_for_message= injury.get_id().to_string();
+        let latent = injury.get_latent_disease();
         let key = InjuryKey::new(injury_name, body_part);
         let result = key.clone();
 
@@ -52,6 +57,10 @@ impl Health {
 
         self.queue_message(Event::InjurySpawned(name_for_message, body_part));
 
+        if let Some(latent) = latent {
+            self.contract_latent_disease(latent, &result, &activation_time);
+        }
+
         Ok(result)
     }
 
@@ -72,19 +81,161 @@ impl Health {
     /// ## Notes
     /// This method borrows the `injuries` collection
     pub fn remove_injury(&self, injury_name: String, body_part: BodyPart) -> Result<(), RemoveInjuryErr> {
+        self.remove_injury_for(injury_name, body_part, RemovalReason::Cleared)
+    }
+
+    /// Same as [`remove_injury`](#method.remove_injury), but sends a given removal reason with
+    /// the [`InjuryRemoved`](crate::utils::event::Event::InjuryRemoved) event. Use it, for
+    /// example, when a scripted treatment heals the injury
+    ///
+    /// # Parameters
+    /// - `injury_name`: unique name of the injury
+    /// - `body_part`: body part to remove injury from
+    /// - `reason`: why injury is removed
+    ///
+    /// # Returns
+    /// Ok on success
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::RemovalReason;
+    ///
+    /// person.health.remove_injury_for(injury_name, body_part, RemovalReason::Cured);
+    /// ```
+    ///
+    /// ## Notes
+    /// This method borrows the `injuries` collection
+    pub fn remove_injury_for(&self, injury_name: String, body_part: BodyPart,
+                             reason: RemovalReason) -> Result<(), RemoveInjuryErr> {
         if !self.is_alive.get() { return Err(RemoveInjuryErr::CharacterIsDead); }
 
-        let mut b = self.injuries.borrow_mut();
         let key = InjuryKey::new(injury_name, body_part);
+        let removed = match self.active_injuries.borrow_mut().remove(&key) {
+            Some(o) => o,
+            None => return Err(RemoveInjuryErr::InjuryNotFound)
+        };
+
+        self.queue_message(Event::InjuryRemoved(key.injury, key.body_part, reason, removed.worst_level_reached()));
+
+        Ok(())
+    }
+
+    /// Removes all injuries located on a given body part, like the scripted wounds on a new
+    /// chapter. Is also called when body part is lost.
+    /// [`InjuryRemoved`](crate::utils::event::Event::InjuryRemoved) events are sent with
+    /// [`RemovalReason::Cleared`]
+    ///
+    /// # Parameters
+    /// - `body_part`: body part to remove injuries from
+    ///
+    /// # Returns
+    /// Ids of the removed injuries
+    ///
+    /// # Examples
+    /// ```
+    /// let removed = person.health.clear_injuries_on(BodyPart::LeftForearm);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows the `injuries` collection
+    pub fn clear_injuries_on(&self, body_part: BodyPart) -> Vec<String> {
+        self.mark_dirty();
+
+        let mut removed = Vec::new();
 
-        if !b.contains_key(&key) {
-            return Err(RemoveInjuryErr::InjuryNotFound);
+        self.active_injuries.borrow_mut().retain(|key, injury| {
+            if key.body_part != body_part { return true; }
+
+            removed.push((key.injury.to_string(), injury.worst_level_reached()));
+
+            false
+        });
+
+        for (name, level) in removed.iter() {
+            self.queue_message(Event::InjuryRemoved(name.to_string(), body_part, RemovalReason::Cleared, *level));
         }
 
-        b.remove(&key);
+        removed.into_iter().map(|(name, _)| name).collect()
+    }
+
+    /// Returns an active or scheduled injury by its id and body part. `None` if there is no
+    /// such injury
+    ///
+    /// # Parameters
+    /// - `injury_name`: injury id
+    /// - `body_part`: body part the injury is on
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(cut) = person.health.injury(&"Cut".to_string(), BodyPart::LeftForearm) {
+    ///     cut.stop_blood_loss();
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows the `injuries` collection only for the lookup
+    pub fn injury(&self, injury_name: &String, body_part: BodyPart) -> Option<Rc<ActiveInjury>> {
+        let key = InjuryKey { injury: injury_name.to_string(), body_part };
 
-        self.queue_message(Event::InjuryRemoved(key.injury.to_string(), key.body_part));
+        self.active_injuries.borrow().get(&key).cloned()
+    }
 
-        Ok(())
+    /// Calls a function for every active or scheduled injury, ordered by injury id and then by
+    /// body part
+    ///
+    /// # Parameters
+    /// - `f`: function to call
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.for_each_injury(|i| println!("{} on {}", i.injury.get_name(), i.body_part));
+    /// ```
+    ///
+    /// ## Notes
+    /// Function is called while the `injuries` collection is not borrowed, so it can safely
+    /// spawn or remove injuries
+    pub fn for_each_injury<F: FnMut(&ActiveInjury)>(&self, mut f: F) {
+        let injuries: Vec<Rc<ActiveInjury>> = self.active_injuries.borrow().values().cloned().collect();
+
+        for injury in injuries.iter() {
+            f(injury);
+        }
+    }
+
+    /// Returns owned read-only views of all active or scheduled injuries as of the last
+    /// `update` call, ordered by injury id and then by body part. Views can be kept and passed
+    /// around freely
+    ///
+    /// # Examples
+    /// ```
+    /// for injury in person.health.snapshot_injuries() {
+    ///     println!("{} on {} {:?}", injury.name, injury.body_part, injury.active_level);
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows the `injuries` collection
+    pub fn snapshot_injuries(&self) -> Vec<InjuryInfoC> {
+        let game_time = self.last_update_game_time.borrow();
+
+        self.active_injuries.borrow().iter().map(|(key, i)| {
+            let stage = i.get_active_stage(&game_time);
+
+            InjuryInfoC {
+                name: key.injury.to_string(),
+                body_part: key.body_part,
+                is_active: i.is_active(&game_time),
+                is_healing: i.is_healing(),
+                activation_time: i.activation_time(),
+                active_level: stage.as_ref().map(|s| s.info.level),
+                stage_percent: stage.as_ref().map_or(0, |s| s.percent_active(&game_time)),
+                end_time: i.end_time(),
+                needs_treatment: i.needs_treatment,
+                worst_level_reached: i.worst_level_reached(),
+                is_fracture: i.is_fracture,
+                is_burn: i.is_burn,
+                is_blood_stopped: i.is_blood_stopped()
+            }
+        }).collect()
     }
-}
\ No newline at end of file
+}
@@ -6,6 +6,7 @@ impl StageBuilder {
     fn as_drains_node(&self) -> &dyn StageDrainsNode { self }
     fn as_after_no_drains(&self) -> &dyn StageAfterNoDrains { self }
     fn as_drains_values(&self) -> &dyn StageDrainsValues { self }
+    fn as_vitals_values(&self) -> &dyn StageVitalsValues { self }
     fn as_stage_death_chance(&self) -> &dyn StageDeathChance { self }
     fn as_stage_targets(&self) -> &dyn StageTargets { self }
     fn as_stage_duration(&self) -> &dyn StageDuration { self }
@@ -47,11 +48,20 @@ pub trait StageDrainsValues {
     /// # Parameters
     /// - `value`: max drain value for this stage (0..100 percents per game second)
     fn stamina(&self, value: f32) -> &dyn StageDrainsValues;
+    /// Set the static drain rate for the stamina for this stage that is applied only while
+    /// injured body part is in use (arm injury when doing arm-intensive work, leg injury when
+    /// walking or running). 0..100 percents per game second.
+    ///
+    /// # Parameters
+    /// - `value`: max drain value for this stage (0..100 percents per game second)
+    fn stamina_when_used(&self, value: f32) -> &dyn StageDrainsValues;
     /// Set the static drain rate for the food level for this stage. 0..100 percents per game second.
     ///
     /// # Parameters
     /// - `value`: max drain value for this stage (0..100 percents per game second)
     fn blood_level(&self, value: f32) -> &dyn StageDrainsValues;
+    /// Describe how this stage affects vitals
+    fn vitals(&self) -> &dyn StageVitalsValues;
     /// This stage is not deadly
     fn no_death_probability(&self) -> &dyn StageTargets;
     /// This stage will have death probability
@@ -60,6 +70,37 @@ pub trait StageDrainsValues {
 
 /// Stage fluent step trait
 pub trait StageAfterNoDrains {
+    /// Describe how this stage affects vitals
+    fn vitals(&self) -> &dyn StageVitalsValues;
+    /// This stage is not deadly
+    fn no_death_probability(&self) -> &dyn StageTargets;
+    /// This stage will have death probability
+    fn deadly(&self) -> &dyn StageDeathChance;
+}
+
+/// Stage fluent step trait
+pub trait StageVitalsValues {
+    /// Set the target body temperature for this stage
+    ///
+    /// # Parameters
+    /// - `value`: absolute value (like 37.3)
+    fn with_target_body_temp(&self, value: f32) -> &dyn StageVitalsValues;
+    /// Set the target heart rate for this stage
+    ///
+    /// # Parameters
+    /// - `value`: absolute value (like 84.)
+    fn with_target_heart_rate(&self, value: f32) -> &dyn StageVitalsValues;
+    /// Set the target blood pressure for this stage
+    ///
+    /// # Parameters
+    /// - `top`: absolute value (like 120.)
+    /// - `bottom`: absolute value (like 70.)
+    fn with_target_blood_pressure(&self, top: f32, bottom: f32) -> &dyn StageVitalsValues;
+    /// Choose this if you want this stage to affect fatigue.
+    ///
+    /// # Parameters
+    /// - `target_delta`: maximum impact on fatigue at the end of this stage (0..100 percents)
+    fn affects_fatigue(&self, target_delta: f32) -> &dyn StageVitalsValues;
     /// This stage is not deadly
     fn no_death_probability(&self) -> &dyn StageTargets;
     /// This stage will have death probability
@@ -122,6 +163,10 @@ impl StageSelfHeal for StageBuilder {
 }
 
 impl StageAfterNoDrains for StageBuilder {
+    fn vitals(&self) -> &dyn StageVitalsValues {
+        self.as_vitals_values()
+    }
+
     fn no_death_probability(&self) -> &dyn StageTargets {
         self.chance_of_death.replace(None);
 
@@ -153,12 +198,60 @@ impl StageDrainsValues for StageBuilder {
         self.as_drains_values()
     }
 
+    fn stamina_when_used(&self, value: f32) -> &dyn StageDrainsValues {
+        self.target_stamina_drain.set(value);
+        self.is_stamina_drain_localized.set(true);
+
+        self.as_drains_values()
+    }
+
     fn blood_level(&self, value: f32) -> &dyn StageDrainsValues {
         self.target_blood_drain.set(value);
 
         self.as_drains_values()
     }
 
+    fn vitals(&self) -> &dyn StageVitalsValues {
+        self.as_vitals_values()
+    }
+
+    fn no_death_probability(&self) -> &dyn StageTargets {
+        self.chance_of_death.replace(None);
+
+        self.as_stage_targets()
+    }
+
+    fn deadly(&self) -> &dyn StageDeathChance {
+        self.as_stage_death_chance()
+    }
+}
+
+impl StageVitalsValues for StageBuilder {
+    fn with_target_body_temp(&self, value: f32) -> &dyn StageVitalsValues {
+        self.target_body_temp.set(value);
+
+        self.as_vitals_values()
+    }
+
+    fn with_target_heart_rate(&self, value: f32) -> &dyn StageVitalsValues {
+        self.target_heart_rate.set(value);
+
+        self.as_vitals_values()
+    }
+
+    fn with_target_blood_pressure(&self, top: f32, bottom: f32) -> &dyn StageVitalsValues {
+        self.target_pressure_top.set(top);
+        self.target_pressure_bottom.set(bottom);
+
+        self.as_vitals_values()
+    }
+
+    fn affects_fatigue(&self, target_delta: f32) -> &dyn StageVitalsValues {
+        self.target_fatigue_delta.set(target_delta);
+
+        self.as_vitals_values()
+    }
+
     fn no_death_probability(&self) -> &dyn StageTargets {
         self.chance_of_death.replace(None);
 
@@ -216,7 +309,13 @@ impl StageEnd for StageBuilder {
             is_endless: self.is_endless.get(),
             reaches_peak_in_hours: self.reaches_peak_in_hours.get(),
             target_stamina_drain: self.target_stamina_drain.get(),
-            target_blood_drain: self.target_blood_drain.get()
+            target_blood_drain: self.target_blood_drain.get(),
+            is_stamina_drain_localized: self.is_stamina_drain_localized.get(),
+            target_body_temp: self.target_body_temp.get(),
+            target_heart_rate: self.target_heart_rate.get(),
+            target_pressure_top: self.target_pressure_top.get(),
+            target_pressure_bottom: self.target_pressure_bottom.get(),
+            target_fatigue_delta: self.target_fatigue_delta.get()
         }
     }
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
 use crate::health::injury::{ActiveInjury, InjuryDeltasC, LerpDataNodeC, LerpDataC, ActiveStage, StageLevel, StageDescription};
-use crate::utils::{lerp, clamp_01, GameTimeC};
+use crate::utils::{lerp, clamp_01, GameTimeC, HealthC};
 
 use std::collections::BTreeMap;
 use std::convert::TryFrom;
@@ -13,32 +13,143 @@ impl LerpDataNodeC {
             is_endless: false,
             is_for_inverted: false,
             stamina_data: Vec::new(),
-            blood_data: Vec::new()
+            blood_data: Vec::new(),
+            body_temp_data: Vec::new(),
+            heart_rate_data: Vec::new(),
+            pressure_top_data: Vec::new(),
+            pressure_bottom_data: Vec::new(),
+            fatigue_data: Vec::new()
         }
     }
+
+    fn covers(&self, inverted: bool, gt: f32) -> bool {
+        if inverted != self.is_for_inverted {
+            return false;
+        }
+
+        (gt >= self.start_time && self.is_endless) || (gt >= self.start_time && gt <= self.end_time)
+    }
+
+    fn deltas_at(&self, gt: f32) -> InjuryDeltasC {
+        let mut result = InjuryDeltasC::empty();
+        let lerp_data = self;
+
+        { // Stamina
+            let mut ld = None;
+            for data in lerp_data.stamina_data.iter() {
+                if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
+                    ld = Some(data);
+                    break;
+                }
+            }
+            if let Some(d) = ld {
+                let p = clamp_01((gt - d.start_time) / d.duration);
+                result.stamina_drain = lerp(d.start_value, d.end_value, p);
+            }
+        }
+        { // Blood
+            let mut ld = None;
+            for data in lerp_data.blood_data.iter() {
+                if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
+                    ld = Some(data);
+                    break;
+                }
+            }
+            if let Some(d) = ld {
+                let p = clamp_01((gt - d.start_time) / d.duration);
+                result.blood_drain = lerp(d.start_value, d.end_value, p);
+            }
+        }
+        { // Body Temperature
+            let mut ld = None;
+            for data in lerp_data.body_temp_data.iter() {
+                if gt >= data.start_time && (data.is_endless || gt <= data.end_time) {
+                    ld = Some(data);
+                    break;
+                }
+            }
+            if let Some(d) = ld {
+                let p = clamp_01((gt - d.start_time) / d.duration);
+                result.body_temperature_delta = lerp(d.start_value, d.end_value, p);
+            }
+        }
+        { // Heart Rate
+            let mut ld = None;
+            for data in lerp_data.heart_rate_data.iter() {
+                if gt >= data.start_time && (data.is_endless || gt <= data.end_time) {
+                    ld = Some(data);
+                    break;
+                }
+            }
+            if let Some(d) = ld {
+                let p = clamp_01((gt - d.start_time) / d.duration);
+                result.heart_rate_delta = lerp(d.start_value, d.end_value, p);
+            }
+        }
+        { // Pressure Top
+            let mut ld = None;
+            for data in lerp_data.pressure_top_data.iter() {
+                if gt >= data.start_time && (data.is_endless || gt <= data.end_time) {
+                    ld = Some(data);
+                    break;
+                }
+            }
+            if let Some(d) = ld {
+                let p = clamp_01((gt - d.start_time) / d.duration);
+                result.pressure_top_delta = lerp(d.start_value, d.end_value, p);
+            }
+        }
+        { // Pressure Bottom
+            let mut ld = None;
+            for data in lerp_data.pressure_bottom_data.iter() {
+                if gt >= data.start_time && (data.is_endless || gt <= data.end_time) {
+                    ld = Some(data);
+                    break;
+                }
+            }
+            if let Some(d) = ld {
+                let p = clamp_01((gt - d.start_time) / d.duration);
+                result.pressure_bottom_delta = lerp(d.start_value, d.end_value, p);
+            }
+        }
+        { // Fatigue
+            let mut ld = None;
+            for data in lerp_data.fatigue_data.iter() {
+                if gt >= data.start_time && (data.is_endless || gt <= data.end_time) {
+                    ld = Some(data);
+                    break;
+                }
+            }
+            if let Some(d) = ld {
+                let p = clamp_01((gt - d.start_time) / d.duration);
+                result.fatigue_delta = lerp(d.start_value, d.end_value, p);
+            }
+        }
+
+        result
+    }
 }
 
 impl ActiveInjury {
-    fn generate_lerp_data(&self, game_time: &GameTimeC) {
+    fn build_lerp_data(&self, game_time: &GameTimeC) -> LerpDataNodeC {
         let inverted = self.is_inverted.get();
+        let healthy = HealthC::healthy();
         let gt = game_time.as_secs_f32();
         let last_deltas = self.last_deltas.borrow();
         let mut has_endless_child = false;
         let mut last_start_stamina_delta = last_deltas.stamina_drain;
         let mut last_start_blood_delta = last_deltas.blood_drain;
+        let mut last_start_body_temp = last_deltas.body_temperature_delta;
+        let mut last_start_heart_rate = last_deltas.heart_rate_delta;
+        let mut last_start_pressure_top = last_deltas.pressure_top_delta;
+        let mut last_start_pressure_bottom = last_deltas.pressure_bottom_delta;
+        let mut last_start_fatigue_delta = last_deltas.fatigue_delta;
 
         // Creating our lerp data object
         let mut lerp_data = LerpDataNodeC::new();
         lerp_data.is_for_inverted = self.is_inverted.get();
         lerp_data.start_time = gt;
 
-        // Clear the old structure
-        if let Some(m) = self.lerp_data.borrow_mut().as_mut() {
-            m.stamina_data.clear();
-            m.blood_data.clear();
-        }
-        self.lerp_data.replace(None);
-
         let healthy_stage = ActiveStage {
             info: StageDescription {
                 level: StageLevel::Undefined,
@@ -47,7 +158,13 @@ impl ActiveInjury {
                 self_heal_chance: None,
                 chance_of_death: None,
                 target_stamina_drain: 0.,
-                target_blood_drain: 0.
+                target_blood_drain: 0.,
+                is_stamina_drain_localized: false,
+                target_body_temp: healthy.body_temperature,
+                target_heart_rate: healthy.heart_rate,
+                target_pressure_top: healthy.top_pressure,
+                target_pressure_bottom: healthy.bottom_pressure,
+                target_fatigue_delta: 0.
             },
             duration: Duration::new(0,0),
             start_time: GameTimeC::empty(),
@@ -118,6 +235,104 @@ impl ActiveInjury {
                 last_start_blood_delta = ld.end_value;
                 lerp_data.blood_data.push(ld);
             }
+            // Body Temperature
+            if stage.info.target_body_temp > 0. {
+                let end_value = match next_stage {
+                    // Stages that do not affect this vital lerp back to the healthy value
+                    Some(st) if st.info.target_body_temp > 0. => st.info.target_body_temp,
+                    Some(_) => healthy.body_temperature,
+                    None => stage.info.target_body_temp
+                } - healthy.body_temperature;
+                let ld = LerpDataC {
+                    start_time,
+                    end_time: end,
+                    start_value: last_start_body_temp,
+                    end_value,
+                    duration: end - start_time,
+                    is_endless: stage.info.is_endless
+                };
+
+                last_start_body_temp = ld.end_value;
+                lerp_data.body_temp_data.push(ld);
+            }
+            // Heart Rate
+            if stage.info.target_heart_rate > 0. {
+                let end_value = match next_stage {
+                    // Stages that do not affect this vital lerp back to the healthy value
+                    Some(st) if st.info.target_heart_rate > 0. => st.info.target_heart_rate,
+                    Some(_) => healthy.heart_rate,
+                    None => stage.info.target_heart_rate
+                } - healthy.heart_rate;
+                let ld = LerpDataC {
+                    start_time,
+                    end_time: end,
+                    start_value: last_start_heart_rate,
+                    end_value,
+                    duration: end - start_time,
+                    is_endless: stage.info.is_endless
+                };
+
+                last_start_heart_rate = ld.end_value;
+                lerp_data.heart_rate_data.push(ld);
+            }
+            // Pressure Top
+            if stage.info.target_pressure_top > 0. {
+                let end_value = match next_stage {
+                    // Stages that do not affect this vital lerp back to the healthy value
+                    Some(st) if st.info.target_pressure_top > 0. => st.info.target_pressure_top,
+                    Some(_) => healthy.top_pressure,
+                    None => stage.info.target_pressure_top
+                } - healthy.top_pressure;
+                let ld = LerpDataC {
+                    start_time,
+                    end_time: end,
+                    start_value: last_start_pressure_top,
+                    end_value,
+                    duration: end - start_time,
+                    is_endless: stage.info.is_endless
+                };
+
+                last_start_pressure_top = ld.end_value;
+                lerp_data.pressure_top_data.push(ld);
+            }
+            // Pressure Bottom
+            if stage.info.target_pressure_bottom > 0. {
+                let end_value = match next_stage {
+                    // Stages that do not affect this vital lerp back to the healthy value
+                    Some(st) if st.info.target_pressure_bottom > 0. => st.info.target_pressure_bottom,
+                    Some(_) => healthy.bottom_pressure,
+                    None => stage.info.target_pressure_bottom
+                } - healthy.bottom_pressure;
+                let ld = LerpDataC {
+                    start_time,
+                    end_time: end,
+                    start_value: last_start_pressure_bottom,
+                    end_value,
+                    duration: end - start_time,
+                    is_endless: stage.info.is_endless
+                };
+
+                last_start_pressure_bottom = ld.end_value;
+                lerp_data.pressure_bottom_data.push(ld);
+            }
+            // Fatigue
+            if stage.info.target_fatigue_delta > 0. {
+                let end_value = match next_stage {
+                    Some(st) => st.info.target_fatigue_delta,
+                    None => stage.info.target_fatigue_delta
+                };
+                let ld = LerpDataC {
+                    start_time,
+                    end_time: end,
+                    start_value: last_start_fatigue_delta,
+                    end_value,
+                    duration: end - start_time,
+                    is_endless: stage.info.is_endless
+                };
+
+                last_start_fatigue_delta = ld.end_value;
+                lerp_data.fatigue_data.push(ld);
+            }
 
             return true;
         };
@@ -136,34 +351,34 @@ impl ActiveInjury {
 
         lerp_data.is_endless = has_endless_child;
 
-        self.lerp_data.replace(Some(lerp_data));
+        lerp_data
     }
 
-    fn has_lerp_data_for(&self, game_time: &GameTimeC) -> bool {
-        let b = self.lerp_data.borrow();
-        let ld = match b.as_ref() {
-            Some(o) => o,
-            None => return false
-        };
-
-        if self.is_inverted.get() != ld.is_for_inverted {
-            return false;
+    fn generate_lerp_data(&self, game_time: &GameTimeC) {
+        // Clear the old structure
+        if let Some(m) = self.lerp_data.borrow_mut().as_mut() {
+            m.stamina_data.clear();
+            m.blood_data.clear();
+            m.body_temp_data.clear();
+            m.heart_rate_data.clear();
+            m.pressure_top_data.clear();
+            m.pressure_bottom_data.clear();
+            m.fatigue_data.clear();
         }
+        self.lerp_data.replace(None);
 
-        let gt = game_time.as_secs_f32();
+        self.lerp_data.replace(Some(self.build_lerp_data(game_time)));
+    }
 
-        if (gt >= ld.start_time && ld.is_endless) || (gt >= ld.start_time && gt <= ld.end_time)
-        {
-            return true;
+    fn has_lerp_data_for(&self, game_time: &GameTimeC) -> bool {
+        match self.lerp_data.borrow().as_ref() {
+            Some(ld) => ld.covers(self.is_inverted.get(), game_time.as_secs_f32()),
+            None => false
         }
-
-        false
     }
 
     /// Gets injury drain delta for a given time
     pub(crate) fn get_drains_deltas(&self, game_time: &GameTimeC) -> InjuryDeltasC {
-        let mut result = InjuryDeltasC::empty();
-
         if !self.has_lerp_data_for(game_time) {
             self.generate_lerp_data(game_time);
 
@@ -171,46 +386,67 @@ impl ActiveInjury {
             if !self.has_lerp_data_for(game_time) { return InjuryDeltasC::empty(); }
         }
 
-        let b = self.lerp_data.borrow();
-        let lerp_data = match b.as_ref() {
-            Some(o) => o,
+        let mut result = match self.lerp_data.borrow().as_ref() {
+            Some(o) => o.deltas_at(game_time.as_secs_f32()),
             None => return InjuryDeltasC::empty()
         };
-        let gt = game_time.as_secs_f32();
 
-        { // Stamina
-            let mut ld = None;
-            for data in lerp_data.stamina_data.iter() {
-                if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
-                    ld = Some(data);
-                    break;
-                }
-            }
-            if let Some(d) = ld {
-                let p = clamp_01((gt - d.start_time) / d.duration);
-                result.stamina_drain = lerp(d.start_value, d.end_value, p);
-            }
+        if self.blood_loss_stop.get() {
+            result.blood_drain = 0.;
         }
-        { // Blood
-            if self.blood_loss_stop.get() {
-                result.blood_drain = 0.;
-            } else {
-                let mut ld = None;
-                for data in lerp_data.blood_data.iter() {
-                    if (gt >= data.start_time && data.is_endless) || (gt >= data.start_time && gt <= data.end_time) {
-                        ld = Some(data);
-                        break;
-                    }
-                }
-                if let Some(d) = ld {
-                    let p = clamp_01((gt - d.start_time) / d.duration);
-                    result.blood_drain = lerp(d.start_value, d.end_value, p);
-                }
+
+        self.last_deltas.replace(result.clone());
+
+        self.apply_activity(&mut result, game_time);
+
+        result
+    }
+
+    /// Gets injury drain delta for a given time without altering the injury state. When cached lerp
+    /// data does not cover the given time, temporary one is built starting from `now`
+    pub(crate) fn project_drains_deltas(&self, now: &GameTimeC, game_time: &GameTimeC) -> InjuryDeltasC {
+        let gt = game_time.as_secs_f32();
+        let inverted = self.is_inverted.get();
+        let cached = match self.lerp_data.borrow().as_ref() {
+            Some(o) if o.covers(inverted, gt) => Some(o.deltas_at(gt)),
+            _ => None
+        };
+        let mut result = match cached {
+            Some(d) => d,
+            None => {
+                let lerp_data = self.build_lerp_data(now);
+
+                if !lerp_data.covers(inverted, gt) { return InjuryDeltasC::empty(); }
+
+                lerp_data.deltas_at(gt)
             }
+        };
+
+        if self.blood_loss_stop.get() {
+            result.blood_drain = 0.;
         }
 
-        self.last_deltas.replace(result.clone());
+        self.apply_activity(&mut result, game_time);
 
         result
     }
+
+    /// Scales blood drain by the player activity. Localized stamina drain is applied only
+    /// while injured body part is in use
+    fn apply_activity(&self, deltas: &mut InjuryDeltasC, game_time: &GameTimeC) {
+        deltas.blood_drain *= self.activity_bleeding.get();
+
+        if self.is_burn {
+            crate::health::burns::apply_burn_severity(deltas, self.burn_area.get(), self.burn_relief.get());
+        }
+
+        if self.body_part_in_use.get() { return; }
+
+        let is_localized = self.get_active_stage(game_time)
+            .is_some_and(|st| st.info.is_stamina_drain_localized);
+
+        if is_localized {
+            deltas.stamina_drain = 0.;
+        }
+    }
 }
\ No newline at end of file
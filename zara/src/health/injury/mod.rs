@@ -4,6 +4,9 @@ use crate::health::{StageLevel, InjuryKey};
 use crate::health::injury::fluent::{StageInit};
 use crate::inventory::items::{InventoryItem, ApplianceC};
 use crate::body::{BodyPart};
+use crate::health::bleeding::BleedingMultipliers;
+use crate::health::latent::LatentDisease;
+use crate::health::healing::HealingConditions;
 
 use std::rc::Rc;
 use std::cell::{Cell, RefCell, RefMut};
@@ -86,6 +89,10 @@ macro_rules! injury(
 ///                 .drains()
 ///                     .stamina(0.2)
 ///                     .blood_level(0.08)
+///                 .vitals()
+///                     .with_target_heart_rate(95.)
+///                     .with_target_body_temp(37.8)
+///                     .affects_fatigue(10.)
 ///                 .deadly()
 ///                     .with_chance_of_death(0)
 ///                 .will_reach_target_in(0.3)
@@ -115,6 +122,53 @@ macro_rules! fracture(
     );
 );
 
+/// Macro for declaring a burn injury. Stages describe a burn over half of the body part;
+/// use [`spawn_burn`](crate::health::Health::spawn_burn) to spawn a burn of a given area
+///
+/// # Examples
+///
+/// ```
+/// use zara::health::injury::StageBuilder;
+/// use zara::health::StageLevel;
+///
+/// zara::burn!(Burn, "Burn",
+///     Some(Box::new(BurnTreatment)),
+///     vec![
+///         StageBuilder::start()
+///             .build_for(StageLevel::InitialStage)
+///                 .self_heal(15)
+///                 .drains()
+///                     .stamina_when_used(0.2)
+///                     .blood_level(0.)
+///                 .vitals()
+///                     .with_target_heart_rate(90.)
+///                     .affects_fatigue(5.)
+///                 .no_death_probability()
+///                 .will_reach_target_in(0.5)
+///                 .will_end()
+///             .build(),
+///         // and so on...
+///     ]
+/// );
+/// ```
+#[macro_export]
+macro_rules! burn(
+    ($t:ty, $nm:expr, $trt:expr, $st:expr) => (
+        impl zara::health::injury::Injury for $t {
+            fn get_name(&self) -> String { format!($nm) }
+            fn get_stages(&self) -> Vec<zara::health::injury::StageDescription> {
+                $st as Vec<zara::health::injury::StageDescription>
+            }
+            fn get_treatment(&self) -> Option<Box<dyn zara::health::injury::InjuryTreatment>> {
+                $trt
+            }
+            fn get_is_fracture(&self) -> bool { false }
+            fn get_is_burn(&self) -> bool { true }
+            fn as_any(&self) -> &dyn std::any::Any { self }
+        }
+    );
+);
+
 impl InjuryKey {
     /// Creates new injury key containing injury name and a body part
     /// 
@@ -159,6 +213,12 @@ pub struct StageBuilder {
     is_endless: Cell<bool>,
     target_stamina_drain: Cell<f32>,
     target_blood_drain: Cell<f32>,
+    is_stamina_drain_localized: Cell<bool>,
+    target_body_temp: Cell<f32>,
+    target_heart_rate: Cell<f32>,
+    target_pressure_top: Cell<f32>,
+    target_pressure_bottom: Cell<f32>,
+    target_fatigue_delta: Cell<f32>,
     chance_of_death: RefCell<Option<usize>>
 }
 
@@ -173,7 +233,13 @@ impl StageBuilder {
                 is_endless: Cell::new(false),
                 reaches_peak_in_hours: Cell::new(0.),
                 target_stamina_drain: Cell::new(0.),
-                target_blood_drain: Cell::new(0.)
+                target_blood_drain: Cell::new(0.),
+                is_stamina_drain_localized: Cell::new(false),
+                target_body_temp: Cell::new(0.),
+                target_heart_rate: Cell::new(0.),
+                target_pressure_top: Cell::new(0.),
+                target_pressure_bottom: Cell::new(0.),
+                target_fatigue_delta: Cell::new(0.)
             }
         )
     }
@@ -219,7 +285,19 @@ pub struct StageDescription {
     /// Target blood drain for this stage (0..100 percents per game second)
     pub target_blood_drain: f32,
     /// Target stamina drain for this stage (0..100 percents per game second)
-    pub target_stamina_drain: f32
+    pub target_stamina_drain: f32,
+    /// Stamina drain is applied only while injured body part is in use
+    pub is_stamina_drain_localized: bool,
+    /// Stage's target body temperature (0 if this stage does not affect it)
+    pub target_body_temp: f32,
+    /// Stage's target heart rate (0 if this stage does not affect it)
+    pub target_heart_rate: f32,
+    /// Stage's target body pressure (top, 0 if this stage does not affect it)
+    pub target_pressure_top: f32,
+    /// Stage's target body pressure (bottom, 0 if this stage does not affect it)
+    pub target_pressure_bottom: f32,
+    /// Target fatigue delta value (0..100 percents) at the end of this stage
+    pub target_fatigue_delta: f32
 }
 impl fmt::Display for StageDescription {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -232,10 +310,16 @@ impl Hash for StageDescription {
         self.self_heal_chance.hash(state);
         self.chance_of_death.hash(state);
         self.is_endless.hash(state);
+        self.is_stamina_drain_localized.hash(state);
 
         state.write_u32((self.reaches_peak_in_hours*10_000_f32) as u32);
         state.write_i32((self.target_blood_drain*10_000_f32) as i32);
         state.write_i32((self.target_stamina_drain*10_000_f32) as i32);
+        state.write_u32((self.target_body_temp*10_000_f32) as u32);
+        state.write_u32((self.target_heart_rate*10_000_f32) as u32);
+        state.write_u32((self.target_pressure_top*10_000_f32) as u32);
+        state.write_u32((self.target_pressure_bottom*10_000_f32) as u32);
+        state.write_i32((self.target_fatigue_delta*10_000_f32) as i32);
     }
 }
 
@@ -252,13 +336,60 @@ pub struct ActiveStage {
     pub duration: Duration
 }
 
+/// Describes a read-only view of an active or scheduled injury, taken with
+/// [`snapshot_injuries`](crate::health::Health::snapshot_injuries)
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct InjuryInfoC {
+    /// Injury id
+    pub name: String,
+    /// Body part the injury is on
+    pub body_part: BodyPart,
+    /// Is injury active as of the last `update` call
+    pub is_active: bool,
+    /// Is injury healing (chain is inverted)
+    pub is_healing: bool,
+    /// When this injury becomes (or became) active
+    pub activation_time: GameTimeC,
+    /// Level of the active stage, `None` if injury is not active
+    pub active_level: Option<StageLevel>,
+    /// How far the active stage is from its peak (0..100)
+    pub stage_percent: usize,
+    /// When this injury will end, `None` if it will not
+    pub end_time: Option<GameTimeC>,
+    /// Injury needs treatment or will self-heal
+    pub needs_treatment: bool,
+    /// Worst stage level this injury has reached so far
+    pub worst_level_reached: Option<StageLevel>,
+    /// Is this injury a fracture
+    pub is_fracture: bool,
+    /// Is this injury a burn
+    pub is_burn: bool,
+    /// Was blood loss forcibly stopped
+    pub is_blood_stopped: bool
+}
+impl fmt::Display for InjuryInfoC {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} on {} ({:?})", self.name, self.body_part, self.active_level)
+    }
+}
+
 /// Describes deltas calculated by the active injury
 #[derive(Copy, Clone, Debug, Default)]
 pub struct InjuryDeltasC {
     /// Delta value for the stamina (relative drain, 0..100 per game second)
     pub stamina_drain: f32,
     /// Delta value for the blood level (relative drain, 0..100 per game second)
-    pub blood_drain: f32
+    pub blood_drain: f32,
+    /// Delta value for the body temperature (absolute delta, degrees C per game second)
+    pub body_temperature_delta: f32,
+    /// Delta value for the heart rate (absolute delta, bpm per game second)
+    pub heart_rate_delta: f32,
+    /// Delta value for the top blood pressure (absolute delta, mmHg per game second)
+    pub pressure_top_delta: f32,
+    /// Delta value for the bottom blood pressure (absolute delta, mmHg per game second)
+    pub pressure_bottom_delta: f32,
+    /// Delta value for the fatigue (absolute delta, 0..100 per game second)
+    pub fatigue_delta: f32
 }
 impl InjuryDeltasC {
     /// Returns a new object with empty deltas
@@ -272,17 +403,30 @@ impl InjuryDeltasC {
     pub fn empty() -> Self {
         InjuryDeltasC {
             stamina_drain: 0.,
-            blood_drain: 0.
+            blood_drain: 0.,
+            body_temperature_delta: 0.,
+            heart_rate_delta: 0.,
+            pressure_top_delta: 0.,
+            pressure_bottom_delta: 0.,
+            fatigue_delta: 0.
         }
     }
     pub(crate) fn for_related() -> Self {
         InjuryDeltasC {
             stamina_drain: 0.,
-            blood_drain: 0.
+            blood_drain: 0.,
+            body_temperature_delta: -1000.,
+            heart_rate_delta: -1000.,
+            pressure_top_delta: -1000.,
+            pressure_bottom_delta: -1000.,
+            fatigue_delta: 0.
         }
     }
     pub(crate) fn cleanup(&mut self){
-        // No "max" logic here (yet?)
+        if self.heart_rate_delta < -900. { self.heart_rate_delta = 0.; }
+        if self.body_temperature_delta < -900. { self.body_temperature_delta = 0.; }
+        if self.pressure_top_delta < -900. { self.pressure_top_delta = 0.; }
+        if self.pressure_bottom_delta < -900. { self.pressure_bottom_delta = 0.; }
     }
 }
 
@@ -329,7 +473,17 @@ impl ActiveStage {
 
 /// Trait that must be implemented by all injuries
 pub trait Injury {
-    /// Gets the unique name of this injury kind
+    /// Gets stable unique id of this injury kind. Health node and events use it as a key.
+    /// Same as [`get_name`](Injury::get_name) by default; override it when the name is a
+    /// display or localization key that may change
+    ///
+    /// # Examples
+    /// ```
+    /// let id = injury.get_id();
+    /// ```
+    fn get_id(&self) -> String { self.get_name() }
+
+    /// Gets the display name (or localization key) of this injury kind
     /// 
     /// # Examples
     /// ```
@@ -367,6 +521,38 @@ pub trait Injury {
     /// ```
     fn get_is_fracture(&self) -> bool;
 
+    /// True if injury is a burn. Burn severity depends on the burned area (see
+    /// [`spawn_burn`](crate::health::Health::spawn_burn)), and its pain is eased by wetness
+    /// and burn dressings. `false` by default
+    ///
+    /// # Examples
+    /// ```
+    /// let value = injury.get_is_burn();
+    /// ```
+    fn get_is_burn(&self) -> bool { false }
+
+    /// Disease this injury can carry, like rabies carried by an animal bite (see
+    /// [`LatentDisease`]). `None` by default
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(latent) = injury.get_latent_disease() {
+    ///     // ...
+    /// }
+    /// ```
+    fn get_latent_disease(&self) -> Option<LatentDisease> { None }
+
+    /// Conditions the healing (inverted) chain needs to progress, like rest or warmth (see
+    /// [`HealingConditions`]). `None` by default
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(conditions) = injury.get_healing_conditions() {
+    ///     // ...
+    /// }
+    /// ```
+    fn get_healing_conditions(&self) -> Option<HealingConditions> { None }
+
     /// For downcasting
     fn as_any(&self) -> &dyn Any;
 }
@@ -376,6 +562,11 @@ struct LerpDataNodeC {
     end_time: f32,
     stamina_data: Vec<LerpDataC>,
     blood_data: Vec<LerpDataC>,
+    body_temp_data: Vec<LerpDataC>,
+    heart_rate_data: Vec<LerpDataC>,
+    pressure_top_data: Vec<LerpDataC>,
+    pressure_bottom_data: Vec<LerpDataC>,
+    fatigue_data: Vec<LerpDataC>,
     is_endless: bool,
     is_for_inverted: bool
 }
@@ -404,6 +595,8 @@ pub struct ActiveInjury {
     pub body_part: BodyPart,
     /// Is this injury a fracture
     pub is_fracture: bool,
+    /// Is this injury a burn
+    pub is_burn: bool,
 
     // Private fields
     /// Initial stages data given by user
@@ -422,17 +615,31 @@ pub struct ActiveInjury {
     will_end: Cell<bool>,
     /// Disease end time, if applicable
     end_time: RefCell<Option<GameTimeC>>,
+    /// Is healing paused because healing conditions are not met
+    is_healing_paused: Cell<bool>,
+    /// The most serious stage level this injury has reached
+    worst_level_reached: Cell<StageLevel>,
     /// Treatment object associated with this disease
     treatment: Rc<Option<Box<dyn InjuryTreatment>>>,
     /// Blood loss stopped from "outside"
     blood_loss_stop: Cell<bool>,
+    /// Was injured body part in use on the last frame
+    pub(crate) body_part_in_use: Cell<bool>,
+    /// Blood drain multipliers overriding the global ones
+    pub(crate) bleeding_multipliers: Cell<Option<BleedingMultipliers>>,
+    /// Blood drain multiplier for the player activity and tourniquets on the last frame
+    pub(crate) activity_bleeding: Cell<f32>,
+    /// Burned area of the body part (0..100 percents), for burns only
+    pub(crate) burn_area: Cell<usize>,
+    /// Part (0..1) of the burn pain relieved by wetness and dressings on the last frame
+    pub(crate) burn_relief: Cell<f32>,
 
     // Messages queued for sending on the next frame
     message_queue: RefCell<BTreeMap<usize, Event>>
 }
 impl fmt::Display for ActiveInjury {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} on {} @{}", self.injury.get_name(), self.body_part, self.activation_time.borrow())
+        write!(f, "{} on {} @{}", self.injury.get_id(), self.body_part, self.activation_time.borrow())
     }
 }
 impl Ord for ActiveInjury {
@@ -448,7 +655,7 @@ impl PartialOrd for ActiveInjury {
 }
 impl PartialEq for ActiveInjury {
     fn eq(&self, other: &Self) -> bool {
-        self.injury.get_name() == other.injury.get_name() &&
+        self.injury.get_id() == other.injury.get_id() &&
         self.activation_time == other.activation_time &&
         self.total_duration == other.total_duration &&
         self.will_self_heal_on == other.will_self_heal_on
@@ -456,7 +663,7 @@ impl PartialEq for ActiveInjury {
 }
 impl Hash for ActiveInjury {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.injury.get_name().hash(state);
+        self.injury.get_id().hash(state);
         self.injury.get_stages().hash(state);
         self.activation_time.borrow().hash(state);
         self.total_duration.hash(state);
@@ -518,11 +725,13 @@ impl ActiveInjury {
         let end_time = if will_end { Some(GameTimeC::from_duration(time_elapsed)) } else { None };
         let treatment = injury.get_treatment();
         let is_fracture = injury.get_is_fracture();
+        let is_burn = injury.get_is_burn();
 
         ActiveInjury {
             injury: Rc::new(injury),
             body_part,
             is_fracture,
+            is_burn,
             treatment: Rc::new(treatment),
             initial_data: RefCell::new(initial_data),
             is_inverted: Cell::new(false),
@@ -531,11 +740,18 @@ impl ActiveInjury {
             stages: RefCell::new(stages),
             will_end: Cell::new(will_end),
             end_time: RefCell::new(end_time),
+            is_healing_paused: Cell::new(false),
+            worst_level_reached: Cell::new(StageLevel::Undefined),
             needs_treatment: !self_heal,
             will_self_heal_on: self_heal_level,
             lerp_data: RefCell::new(None), // will be calculated on first get_drain_deltas
             last_deltas: RefCell::new(InjuryDeltasC::empty()),
             blood_loss_stop: Cell::new(false),
+            body_part_in_use: Cell::new(true),
+            bleeding_multipliers: Cell::new(None),
+            activity_bleeding: Cell::new(1.),
+            burn_area: Cell::new(crate::health::burns::DEFAULT_BURN_AREA),
+            burn_relief: Cell::new(0.),
             message_queue: RefCell::new(BTreeMap::new())
         }
     }
@@ -566,7 +782,7 @@ impl ActiveInjury {
     pub fn stop_blood_loss(&self) {
         self.blood_loss_stop.set(true);
 
-        self.queue_message(Event::BloodLossStopped(self.injury.get_name().to_string(), self.body_part));
+        self.queue_message(Event::BloodLossStopped(self.injury.get_id().to_string(), self.body_part));
     }
 
     /// Resumes stopped by the [`stop_blood_loss`] call blood drain
@@ -583,7 +799,7 @@ impl ActiveInjury {
     pub fn resume_blood_loss(&self) {
         self.blood_loss_stop.set(false);
 
-        self.queue_message(Event::BloodLossResumed(self.injury.get_name().to_string(), self.body_part));
+        self.queue_message(Event::BloodLossResumed(self.injury.get_id().to_string(), self.body_part));
     }
 }
 
@@ -27,7 +27,19 @@ pub struct StageDescriptionStateContract {
     /// Captured state of the `target_blood_drain` field
     pub target_blood_drain: f32,
     /// Captured state of the `target_stamina_drain` field
-    pub target_stamina_drain: f32
+    pub target_stamina_drain: f32,
+    /// Captured state of the `is_stamina_drain_localized` field
+    pub is_stamina_drain_localized: bool,
+    /// Captured state of the `target_body_temp` field
+    pub target_body_temp: f32,
+    /// Captured state of the `target_heart_rate` field
+    pub target_heart_rate: f32,
+    /// Captured state of the `target_pressure_top` field
+    pub target_pressure_top: f32,
+    /// Captured state of the `target_pressure_bottom` field
+    pub target_pressure_bottom: f32,
+    /// Captured state of the `target_fatigue_delta` field
+    pub target_fatigue_delta: f32
 }
 impl fmt::Display for StageDescriptionStateContract {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -53,9 +65,15 @@ impl PartialEq for StageDescriptionStateContract {
         self.self_heal_chance == other.self_heal_chance &&
         self.chance_of_death == other.chance_of_death &&
         self.is_endless == other.is_endless &&
+        self.is_stamina_drain_localized == other.is_stamina_drain_localized &&
         f32::abs(self.reaches_peak_in_hours - other.reaches_peak_in_hours) < EPS &&
         f32::abs(self.target_blood_drain - other.target_blood_drain) < EPS &&
-        f32::abs(self.target_stamina_drain - other.target_stamina_drain) < EPS
+        f32::abs(self.target_stamina_drain - other.target_stamina_drain) < EPS &&
+        f32::abs(self.target_body_temp - other.target_body_temp) < EPS &&
+        f32::abs(self.target_heart_rate - other.target_heart_rate) < EPS &&
+        f32::abs(self.target_pressure_top - other.target_pressure_top) < EPS &&
+        f32::abs(self.target_pressure_bottom - other.target_pressure_bottom) < EPS &&
+        f32::abs(self.target_fatigue_delta - other.target_fatigue_delta) < EPS
     }
 }
 impl Hash for StageDescriptionStateContract {
@@ -64,10 +82,16 @@ impl Hash for StageDescriptionStateContract {
         self.self_heal_chance.hash(state);
         self.chance_of_death.hash(state);
         self.is_endless.hash(state);
+        self.is_stamina_drain_localized.hash(state);
 
         state.write_u32((self.reaches_peak_in_hours*10_000_f32) as u32);
         state.write_i32((self.target_blood_drain*10_000_f32) as i32);
         state.write_i32((self.target_stamina_drain*10_000_f32) as i32);
+        state.write_u32((self.target_body_temp*10_000_f32) as u32);
+        state.write_u32((self.target_heart_rate*10_000_f32) as u32);
+        state.write_u32((self.target_pressure_top*10_000_f32) as u32);
+        state.write_u32((self.target_pressure_bottom*10_000_f32) as u32);
+        state.write_i32((self.target_fatigue_delta*10_000_f32) as i32);
     }
 }
 
@@ -97,6 +121,16 @@ pub struct LerpDataNodeStateContract {
     pub stamina_data: Vec<LerpDataStateContract>,
     /// Captured state of the `blood_data` field
     pub blood_data: Vec<LerpDataStateContract>,
+    /// Captured state of the `body_temp_data` field
+    pub body_temp_data: Vec<LerpDataStateContract>,
+    /// Captured state of the `heart_rate_data` field
+    pub heart_rate_data: Vec<LerpDataStateContract>,
+    /// Captured state of the `pressure_top_data` field
+    pub pressure_top_data: Vec<LerpDataStateContract>,
+    /// Captured state of the `pressure_bottom_data` field
+    pub pressure_bottom_data: Vec<LerpDataStateContract>,
+    /// Captured state of the `fatigue_data` field
+    pub fatigue_data: Vec<LerpDataStateContract>,
     /// Captured state of the `is_endless` field
     pub is_endless: bool,
     /// Captured state of the `is_for_inverted` field
@@ -131,6 +165,11 @@ impl PartialEq for LerpDataNodeStateContract {
 
         self.stamina_data == other.stamina_data &&
         self.blood_data == other.blood_data &&
+        self.body_temp_data == other.body_temp_data &&
+        self.heart_rate_data == other.heart_rate_data &&
+        self.pressure_top_data == other.pressure_top_data &&
+        self.pressure_bottom_data == other.pressure_bottom_data &&
+        self.fatigue_data == other.fatigue_data &&
         self.is_for_inverted == other.is_for_inverted &&
         self.is_endless == other.is_endless &&
         f32::abs(self.start_time - other.start_time) < EPS &&
@@ -141,6 +180,11 @@ impl Hash for LerpDataNodeStateContract {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.stamina_data.hash(state);
         self.blood_data.hash(state);
+        self.body_temp_data.hash(state);
+        self.heart_rate_data.hash(state);
+        self.pressure_top_data.hash(state);
+        self.pressure_bottom_data.hash(state);
+        self.fatigue_data.hash(state);
         self.is_for_inverted.hash(state);
         self.is_endless.hash(state);
 
@@ -218,7 +262,17 @@ pub struct InjuryDeltasStateContract {
     /// Captured state of the `stamina_drain` field
     pub stamina_drain: f32,
     /// Captured state of the `blood_drain` field
-    pub blood_drain: f32
+    pub blood_drain: f32,
+    /// Captured state of the `body_temperature_delta` field
+    pub body_temperature_delta: f32,
+    /// Captured state of the `heart_rate_delta` field
+    pub heart_rate_delta: f32,
+    /// Captured state of the `pressure_top_delta` field
+    pub pressure_top_delta: f32,
+    /// Captured state of the `pressure_bottom_delta` field
+    pub pressure_bottom_delta: f32,
+    /// Captured state of the `fatigue_delta` field
+    pub fatigue_delta: f32
 }
 impl fmt::Display for InjuryDeltasStateContract {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -231,13 +285,23 @@ impl PartialEq for InjuryDeltasStateContract {
         const EPS: f32 = 0.0001;
 
         f32::abs(self.stamina_drain - other.stamina_drain) < EPS &&
-        f32::abs(self.blood_drain - other.blood_drain) < EPS
+        f32::abs(self.blood_drain - other.blood_drain) < EPS &&
+        f32::abs(self.body_temperature_delta - other.body_temperature_delta) < EPS &&
+        f32::abs(self.heart_rate_delta - other.heart_rate_delta) < EPS &&
+        f32::abs(self.pressure_top_delta - other.pressure_top_delta) < EPS &&
+        f32::abs(self.pressure_bottom_delta - other.pressure_bottom_delta) < EPS &&
+        f32::abs(self.fatigue_delta - other.fatigue_delta) < EPS
     }
 }
 impl Hash for InjuryDeltasStateContract {
     fn hash<H: Hasher>(&self, state: &mut H) {
         state.write_i32((self.stamina_drain*10_000_f32) as i32);
         state.write_i32((self.blood_drain*10_000_f32) as i32);
+        state.write_i32((self.body_temperature_delta*10_000_f32) as i32);
+        state.write_i32((self.heart_rate_delta*10_000_f32) as i32);
+        state.write_i32((self.pressure_top_delta*10_000_f32) as i32);
+        state.write_i32((self.pressure_bottom_delta*10_000_f32) as i32);
+        state.write_i32((self.fatigue_delta*10_000_f32) as i32);
     }
 }
 
@@ -249,8 +313,14 @@ impl StageDescription {
             reaches_peak_in_hours: self.reaches_peak_in_hours,
             chance_of_death: self.chance_of_death.clone(),
             target_stamina_drain: self.target_stamina_drain,
+            is_stamina_drain_localized: self.is_stamina_drain_localized,
             self_heal_chance: self.self_heal_chance.clone(),
-            target_blood_drain: self.target_blood_drain
+            target_blood_drain: self.target_blood_drain,
+            target_body_temp: self.target_body_temp,
+            target_heart_rate: self.target_heart_rate,
+            target_pressure_top: self.target_pressure_top,
+            target_pressure_bottom: self.target_pressure_bottom,
+            target_fatigue_delta: self.target_fatigue_delta
         }
     }
 }
@@ -263,6 +333,11 @@ impl LerpDataNodeC {
             is_endless: self.is_endless,
             is_for_inverted: self.is_for_inverted,
             blood_data: self.blood_data.iter().map(|x| x.get_state()).collect(),
+            body_temp_data: self.body_temp_data.iter().map(|x| x.get_state()).collect(),
+            heart_rate_data: self.heart_rate_data.iter().map(|x| x.get_state()).collect(),
+            pressure_top_data: self.pressure_top_data.iter().map(|x| x.get_state()).collect(),
+            pressure_bottom_data: self.pressure_bottom_data.iter().map(|x| x.get_state()).collect(),
+            fatigue_data: self.fatigue_data.iter().map(|x| x.get_state()).collect(),
             stamina_data: self.stamina_data.iter().map(|x| x.get_state()).collect()
         }
     }
@@ -299,9 +374,9 @@ impl Health {
     /// ## Notes
     /// Borrows `injuries` collection
     pub fn restore_injury(&self, injury_data: &ActiveInjuryStateContract, injury: Box<dyn Injury>) {
-        let mut b = self.injuries.borrow_mut();
+        let mut b = self.active_injuries.borrow_mut();
         let treatment = injury.get_treatment();
-        let name = injury.get_name().to_string();
+        let name = injury.get_id().to_string();
         let body_part = injury_data.body_part.clone();
         let i = ActiveInjury {
             injury: Rc::new(injury),
@@ -309,18 +384,26 @@ impl Health {
             will_self_heal_on: injury_data.will_self_heal_on,
             total_duration: injury_data.total_duration,
             is_fracture: injury_data.is_fracture,
+            is_burn: injury_data.is_burn,
             body_part: injury_data.body_part.clone(),
 
             stages: RefCell::new(BTreeMap::new()),
             last_deltas: RefCell::new(InjuryDeltasC::empty()),
             initial_data: RefCell::new(Vec::new()),
             end_time: RefCell::new(None),
+            is_healing_paused: Cell::new(false),
+            worst_level_reached: Cell::new(StageLevel::Undefined),
             lerp_data: RefCell::new(None),
             is_inverted: Cell::new(false),
             activation_time: RefCell::new(GameTimeC::empty()),
             will_end: Cell::new(false),
             treatment: Rc::new(treatment),
             blood_loss_stop: Cell::new(false),
+            body_part_in_use: Cell::new(true),
+            bleeding_multipliers: Cell::new(None),
+            activity_bleeding: Cell::new(1.),
+            burn_area: Cell::new(injury_data.burn_area),
+            burn_relief: Cell::new(0.),
             message_queue: RefCell::new(BTreeMap::new())
         };
 
@@ -345,18 +428,24 @@ impl ActiveInjury {
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management#saving-and-restoring-diseases-and-injuries) for more info.
     pub fn get_state(&self) -> ActiveInjuryStateContract {
         ActiveInjuryStateContract {
+            version: crate::state::STATE_CONTRACT_VERSION,
             needs_treatment: self.needs_treatment,
             is_fracture: self.is_fracture,
+            is_burn: self.is_burn,
+            burn_area: self.burn_area.get(),
             body_part: self.body_part.clone(),
             activation_time: self.activation_time.borrow().to_duration(),
             will_end: self.will_end.get(),
             end_time: self.end_time.borrow().as_ref().map(|x| x.to_duration()),
             will_self_heal_on: self.will_self_heal_on,
             is_inverted: self.is_inverted.get(),
+            is_healing_paused: self.is_healing_paused.get(),
+            worst_level_reached: self.worst_level_reached.get(),
             total_duration: self.total_duration,
             lerp_data: self.lerp_data.borrow().as_ref().map(|x| x.get_state()),
             initial_data: self.initial_data.borrow().iter().map(|x| x.get_state()).collect(),
             last_deltas: self.last_deltas.borrow().get_state(),
+            bleeding_multipliers: self.bleeding_multipliers.get(),
             stages: self.stages.borrow().iter().map(|(k,x)| x.get_state(k)).collect()
         }
     }
@@ -366,6 +455,9 @@ impl ActiveInjury {
         self.will_end.set(state.will_end);
         self.end_time.replace(state.end_time.map(|x| GameTimeC::from_duration(x)));
         self.is_inverted.set(state.is_inverted);
+        self.is_healing_paused.set(state.is_healing_paused);
+        self.worst_level_reached.set(state.worst_level_reached);
+        self.bleeding_multipliers.set(state.bleeding_multipliers);
 
         self.initial_data.replace(state.initial_data.iter().map(|x| StageDescription{
             is_endless: x.is_endless,
@@ -373,8 +465,14 @@ impl ActiveInjury {
             chance_of_death: x.chance_of_death.clone(),
             level: x.level.clone(),
             target_stamina_drain: x.target_stamina_drain,
+            is_stamina_drain_localized: x.is_stamina_drain_localized,
             reaches_peak_in_hours: x.reaches_peak_in_hours,
-            target_blood_drain: x.target_blood_drain
+            target_blood_drain: x.target_blood_drain,
+            target_body_temp: x.target_body_temp,
+            target_heart_rate: x.target_heart_rate,
+            target_pressure_top: x.target_pressure_top,
+            target_pressure_bottom: x.target_pressure_bottom,
+            target_fatigue_delta: x.target_fatigue_delta
         }).collect());
 
         {
@@ -390,11 +488,17 @@ impl ActiveInjury {
                     info: StageDescription {
                         reaches_peak_in_hours: stage.info.reaches_peak_in_hours,
                         target_stamina_drain: stage.info.target_stamina_drain,
+                        is_stamina_drain_localized: stage.info.is_stamina_drain_localized,
                         is_endless: stage.info.is_endless,
                         level: stage.info.level.clone(),
                         chance_of_death: stage.info.chance_of_death.clone(),
                         self_heal_chance: stage.info.self_heal_chance.clone(),
-                        target_blood_drain: stage.info.target_blood_drain
+                        target_blood_drain: stage.info.target_blood_drain,
+                        target_body_temp: stage.info.target_body_temp,
+                        target_heart_rate: stage.info.target_heart_rate,
+                        target_pressure_top: stage.info.target_pressure_top,
+                        target_pressure_bottom: stage.info.target_pressure_bottom,
+                        target_fatigue_delta: stage.info.target_fatigue_delta
                     }
                 });
             }
@@ -402,7 +506,12 @@ impl ActiveInjury {
 
         self.last_deltas.replace(InjuryDeltasC {
             stamina_drain: state.last_deltas.stamina_drain,
-            blood_drain: state.last_deltas.blood_drain
+            blood_drain: state.last_deltas.blood_drain,
+            body_temperature_delta: state.last_deltas.body_temperature_delta,
+            heart_rate_delta: state.last_deltas.heart_rate_delta,
+            pressure_top_delta: state.last_deltas.pressure_top_delta,
+            pressure_bottom_delta: state.last_deltas.pressure_bottom_delta,
+            fatigue_delta: state.last_deltas.fatigue_delta
         });
 
         match &state.lerp_data {
@@ -419,6 +528,46 @@ impl ActiveInjury {
                     end_value: x.end_value,
                     is_endless: x.is_endless
                 }).collect(),
+                body_temp_data: l.body_temp_data.iter().map(|x| LerpDataC {
+                    start_time: x.start_time,
+                    end_time: x.end_time,
+                    duration: x.duration,
+                    start_value: x.start_value,
+                    end_value: x.end_value,
+                    is_endless: x.is_endless
+                }).collect(),
+                heart_rate_data: l.heart_rate_data.iter().map(|x| LerpDataC {
+                    start_time: x.start_time,
+                    end_time: x.end_time,
+                    duration: x.duration,
+                    start_value: x.start_value,
+                    end_value: x.end_value,
+                    is_endless: x.is_endless
+                }).collect(),
+                pressure_top_data: l.pressure_top_data.iter().map(|x| LerpDataC {
+                    start_time: x.start_time,
+                    end_time: x.end_time,
+                    duration: x.duration,
+                    start_value: x.start_value,
+                    end_value: x.end_value,
+                    is_endless: x.is_endless
+                }).collect(),
+                pressure_bottom_data: l.pressure_bottom_data.iter().map(|x| LerpDataC {
+                    start_time: x.start_time,
+                    end_time: x.end_time,
+                    duration: x.duration,
+                    start_value: x.start_value,
+                    end_value: x.end_value,
+                    is_endless: x.is_endless
+                }).collect(),
+                fatigue_data: l.fatigue_data.iter().map(|x| LerpDataC {
+                    start_time: x.start_time,
+                    end_time: x.end_time,
+                    duration: x.duration,
+                    start_value: x.start_value,
+                    end_value: x.end_value,
+                    is_endless: x.is_endless
+                }).collect(),
                 stamina_data: l.stamina_data.iter().map(|x| LerpDataC {
                     start_time: x.start_time,
                     end_time: x.end_time,
@@ -437,7 +586,12 @@ impl InjuryDeltasC {
     pub(crate) fn get_state(&self) -> InjuryDeltasStateContract {
         InjuryDeltasStateContract {
             blood_drain: self.blood_drain,
-            stamina_drain: self.stamina_drain
+            stamina_drain: self.stamina_drain,
+            body_temperature_delta: self.body_temperature_delta,
+            heart_rate_delta: self.heart_rate_delta,
+            pressure_top_delta: self.pressure_top_delta,
+            pressure_bottom_delta: self.pressure_bottom_delta,
+            fatigue_delta: self.fatigue_delta
         }
     }
 }
@@ -1,7 +1,9 @@
-use crate::health::injury::{ActiveInjury, ActiveStage};
+use crate::health::injury::{ActiveInjury, ActiveStage, InjuryDeltasC};
 use crate::utils::GameTimeC;
 use crate::health::StageLevel;
 
+use std::time::Duration;
+
 impl ActiveInjury {
     /// Gets if this injury will end (is it finite)
     /// 
@@ -20,6 +22,34 @@ impl ActiveInjury {
     /// ```
     pub fn is_healing(&self) -> bool { self.is_inverted.get() }
 
+    /// Gets if healing of this injury is paused because its healing conditions are not met
+    /// 
+    /// # Examples
+    /// ```
+    /// let value = injury.is_healing_paused();
+    /// ```
+    pub fn is_healing_paused(&self) -> bool { self.is_healing_paused.get() }
+
+    /// Gets the most serious stage level this injury has reached, `None` if it was not active yet
+    /// 
+    /// # Examples
+    /// ```
+    /// let value = injury.worst_level_reached();
+    /// ```
+    pub fn worst_level_reached(&self) -> Option<StageLevel> {
+        match self.worst_level_reached.get() {
+            StageLevel::Undefined => None,
+            level => Some(level)
+        }
+    }
+
+    /// Remembers the active stage level if it is the most serious one so far
+    pub(crate) fn note_active_level(&self, game_time: &GameTimeC) {
+        if let Some(level) = self.active_level(game_time) {
+            if level > self.worst_level_reached.get() { self.worst_level_reached.set(level); }
+        }
+    }
+
     /// Gets the end time of this injury, if it is finite
     /// 
     /// # Examples
@@ -86,6 +116,19 @@ impl ActiveInjury {
         None
     }
 
+    /// Returns a copy of the worst (last) stage of this injury chain, which peak is
+    /// the injury peak
+    /// 
+    /// # Examples
+    /// ```
+    /// if let Some(stage) = injury.get_worst_stage() {
+    ///     let peak_time = stage.peak_time;
+    /// }
+    /// ```
+    pub fn get_worst_stage(&self) -> Option<ActiveStage> {
+        self.stages.borrow().values().next_back().cloned()
+    }
+
     /// Gets whether injury is active or not for a given time
     /// 
     /// # Examples
@@ -133,4 +176,112 @@ impl ActiveInjury {
     /// let value = injury.is_blood_stopped();
     /// ```
     pub fn is_blood_stopped(&self) -> bool { self.blood_loss_stop.get() }
+
+    /// Returns how much game time is left until the next stage of this injury starts, for a given
+    /// game time. `None` if there are no more stages ahead (current stage is the last or endless one)
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to count from
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(time_left) = injury.time_until_next_stage(&game_time) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn time_until_next_stage(&self, game_time: &GameTimeC) -> Option<GameTimeC> {
+        let gt = game_time.as_secs_f32();
+        let mut result = None;
+
+        for (_, stage) in self.stages.borrow().iter() {
+            let start = stage.start_time.as_secs_f32();
+
+            if start <= gt { continue; }
+
+            match result {
+                Some(r) if r <= start => { },
+                _ => result = Some(start)
+            }
+        }
+
+        result.map(|start| GameTimeC::from_duration(Duration::from_secs_f32(start - gt)))
+    }
+
+    /// Returns current interpolated contribution of this injury to the player vitals for a given
+    /// game time. Empty deltas if the injury is not active at this time. Blood drain is zero
+    /// if the blood loss was stopped
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to get deltas for
+    ///
+    /// # Examples
+    /// ```
+    /// let deltas = injury.current_deltas(&game_time);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `lerp_data` and `stages` collections
+    pub fn current_deltas(&self, game_time: &GameTimeC) -> InjuryDeltasC {
+        if !self.is_active(game_time) { return InjuryDeltasC::empty(); }
+
+        self.project_drains_deltas(game_time, game_time)
+    }
+
+    /// Was injured body part in use on the last Zara update. Localized stamina drains
+    /// (see `stamina_when_used`) are applied only while body part is in use
+    ///
+    /// # Examples
+    /// ```
+    /// let value = injury.is_body_part_in_use();
+    /// ```
+    pub fn is_body_part_in_use(&self) -> bool { self.body_part_in_use.get() }
+
+    /// Returns burned area of the body part (0..100 percents). `None` if this injury is not a burn
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(area) = injury.burn_area() {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn burn_area(&self) -> Option<usize> {
+        if self.is_burn { Some(self.burn_area.get()) } else { None }
+    }
+
+    /// Returns part (0..1) of the burn pain that was relieved by wetness and burn dressings
+    /// on the last Zara update. Always 0 if this injury is not a burn
+    ///
+    /// # Examples
+    /// ```
+    /// let value = injury.burn_relief();
+    /// ```
+    pub fn burn_relief(&self) -> f32 { self.burn_relief.get() }
+
+    /// Returns current stamina drain of this injury for a given game time
+    /// (0..100 per game second)
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to get drain for
+    ///
+    /// # Examples
+    /// ```
+    /// let value = injury.current_stamina_drain(&game_time);
+    /// ```
+    pub fn current_stamina_drain(&self, game_time: &GameTimeC) -> f32 {
+        self.current_deltas(game_time).stamina_drain
+    }
+
+    /// Returns current blood drain of this injury for a given game time
+    /// (0..100 per game second)
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to get drain for
+    ///
+    /// # Examples
+    /// ```
+    /// let value = injury.current_blood_drain(&game_time);
+    /// ```
+    pub fn current_blood_drain(&self, game_time: &GameTimeC) -> f32 {
+        self.current_deltas(game_time).blood_drain
+    }
 }
\ No newline at end of file
@@ -0,0 +1,280 @@
+use crate::health::{Health, InjuryKey};
+use crate::health::disease::{Disease, ActiveDisease};
+use crate::body::BodyPart;
+use crate::utils::{GameTimeC, roll_dice, range};
+use crate::utils::event::{Event, MessageQueue};
+
+use std::rc::Rc;
+use std::time::Duration;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Function that creates an instance of a disease carried by an injury
+pub type LatentDiseaseFactory = Box<dyn Fn() -> Box<dyn Disease>>;
+
+/// Describes a disease that an injury (like an animal bite) can carry. When such injury is
+/// spawned, the character may get infected. The disease is then silently scheduled to start
+/// after a randomized incubation period. Prophylaxis doses taken during the incubation prevent
+/// the disease, but only until the point of no return
+///
+/// # Examples
+/// ```
+/// use zara::health::latent::LatentDisease;
+///
+/// let latent = LatentDisease::new(Box::new(|| Box::new(Rabies)), 30, 72., 240.)
+///     .prevented_by(vec!["Rabies Vaccine"], 3)
+///     .no_return_before_onset(24.);
+/// ```
+pub struct LatentDisease {
+    /// Creates the carried disease
+    pub factory: LatentDiseaseFactory,
+    /// Chance of getting infected when the injury is spawned (0..100)
+    pub chance: usize,
+    /// Shortest incubation period, game hours
+    pub min_incubation_hours: f32,
+    /// Longest incubation period, game hours
+    pub max_incubation_hours: f32,
+    /// Unique names of the consumables or appliances that count as prophylaxis doses
+    pub prophylaxis: Vec<String>,
+    /// How many prophylaxis doses prevent the disease
+    pub doses: usize,
+    /// How many game hours before the disease onset prophylaxis stops working
+    pub no_return_hours: f32
+}
+impl LatentDisease {
+    /// Creates a new latent disease description without prophylaxis. Point of no return is
+    /// the disease onset
+    ///
+    /// # Parameters
+    /// - `factory`: function that creates the carried disease
+    /// - `chance`: chance of getting infected (0..100)
+    /// - `min_incubation_hours`: shortest incubation period, game hours
+    /// - `max_incubation_hours`: longest incubation period, game hours
+    pub fn new(factory: LatentDiseaseFactory, chance: usize, min_incubation_hours: f32,
+               max_incubation_hours: f32) -> Self {
+        LatentDisease {
+            factory,
+            chance,
+            min_incubation_hours,
+            max_incubation_hours,
+            prophylaxis: Vec::new(),
+            doses: 1,
+            no_return_hours: 0.
+        }
+    }
+
+    /// Sets items that prevent the disease during the incubation
+    ///
+    /// # Parameters
+    /// - `items`: unique names of the consumables or appliances that count as doses
+    /// - `doses`: how many doses prevent the disease
+    pub fn prevented_by(mut self, items: Vec<&str>, doses: usize) -> Self {
+        self.prophylaxis = items.iter().map(|x| x.to_string()).collect();
+        self.doses = usize::max(doses, 1);
+        self
+    }
+
+    /// Moves the point of no return before the disease onset
+    ///
+    /// # Parameters
+    /// - `hours`: how many game hours before the onset prophylaxis stops working
+    pub fn no_return_before_onset(mut self, hours: f32) -> Self {
+        self.no_return_hours = f32::max(hours, 0.);
+        self
+    }
+}
+
+/// Disease the character got infected with and that did not start yet, or was not prevented
+#[derive(Clone, Debug, Default)]
+pub struct LatentInfection {
+    /// Unique name of the carried disease
+    pub disease_name: String,
+    /// Unique name of the injury that carried the disease
+    pub injury_name: String,
+    /// Body part of the injury that carried the disease
+    pub body_part: BodyPart,
+    /// Game time in seconds when the disease starts
+    pub onset_time: f32,
+    /// Game time in seconds when prophylaxis stops working
+    pub no_return_time: f32,
+    /// Unique names of the consumables or appliances that count as prophylaxis doses
+    pub prophylaxis: Vec<String>,
+    /// How many prophylaxis doses prevent the disease
+    pub doses_needed: usize,
+    /// How many prophylaxis doses were taken so far
+    pub doses_taken: usize
+}
+impl LatentInfection {
+    /// Returns `true` if prophylaxis does not work anymore
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to check
+    ///
+    /// # Examples
+    /// ```
+    /// if infection.is_past_no_return(&game_time) {
+    ///     // Nothing can be done
+    /// }
+    /// ```
+    pub fn is_past_no_return(&self, game_time: &GameTimeC) -> bool {
+        game_time.as_secs_f32() >= self.no_return_time
+    }
+
+    /// Returns prophylaxis progress (0..1)
+    ///
+    /// # Examples
+    /// ```
+    /// let progress = infection.progress();
+    /// ```
+    pub fn progress(&self) -> f32 {
+        if self.doses_needed == 0 { return 1.; }
+
+        f32::min(self.doses_taken as f32 / self.doses_needed as f32, 1.)
+    }
+}
+impl fmt::Display for LatentInfection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} from {} on {} ({}/{} doses)", self.disease_name, self.injury_name, self.body_part,
+               self.doses_taken, self.doses_needed)
+    }
+}
+impl Eq for LatentInfection { }
+impl PartialEq for LatentInfection {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.disease_name == other.disease_name &&
+        self.injury_name == other.injury_name &&
+        self.body_part == other.body_part &&
+        self.prophylaxis == other.prophylaxis &&
+        self.doses_needed == other.doses_needed &&
+        self.doses_taken == other.doses_taken &&
+        f32::abs(self.onset_time - other.onset_time) < EPS &&
+        f32::abs(self.no_return_time - other.no_return_time) < EPS
+    }
+}
+impl Hash for LatentInfection {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.disease_name.hash(state);
+        self.injury_name.hash(state);
+        self.body_part.hash(state);
+        self.prophylaxis.hash(state);
+        self.doses_needed.hash(state);
+        self.doses_taken.hash(state);
+
+        state.write_u32((self.onset_time*10_000_f32) as u32);
+        state.write_u32((self.no_return_time*10_000_f32) as u32);
+    }
+}
+
+impl Health {
+    /// Returns all latent infections, sorted by the disease name
+    ///
+    /// # Examples
+    /// ```
+    /// for infection in person.health.latent_infections() {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `latent_infections` collection
+    pub fn latent_infections(&self) -> Vec<LatentInfection> {
+        let mut result: Vec<LatentInfection> = self.latent_infections.borrow().values().cloned().collect();
+
+        result.sort_by(|a, b| a.disease_name.cmp(&b.disease_name));
+        result
+    }
+
+    /// Returns latent infection of a given disease, if any
+    ///
+    /// # Parameters
+    /// - `disease_name`: unique name of the carried disease
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(infection) = person.health.latent_infection(&disease_name) {
+    ///     let progress = infection.progress();
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `latent_infections` collection
+    pub fn latent_infection(&self, disease_name: &String) -> Option<LatentInfection> {
+        self.latent_infections.borrow().get(disease_name).cloned()
+    }
+
+    /// Rolls the infection chance and silently schedules the carried disease
+    pub(crate) fn contract_latent_disease(&self, latent: LatentDisease, injury_key: &InjuryKey,
+                                          activation_time: &GameTimeC) {
+        if !roll_dice(latent.chance) { return; }
+
+        let incubation = if latent.max_incubation_hours > latent.min_incubation_hours {
+            range(latent.min_incubation_hours, latent.max_incubation_hours)
+        } else {
+            latent.min_incubation_hours
+        };
+        let start = activation_time.as_secs_f32();
+        let onset_time = start + f32::max(incubation, 0.) * 60. * 60.;
+        let no_return_time = f32::max(onset_time - latent.no_return_hours * 60. * 60., start);
+
+        let disease = (latent.factory)();
+        let disease_name = disease.get_id();
+
+        {
+            let mut b = self.active_diseases.borrow_mut();
+
+            // Already infected or sick
+            if b.contains_key(&disease_name) { return; }
+
+            // No spawn event, the disease stays hidden until it starts
+            b.insert(disease_name.to_string(), Rc::new(ActiveDisease::new(
+                disease,
+                GameTimeC::from_duration(Duration::from_secs_f32(onset_time))
+            )));
+        }
+
+        self.latent_infections.borrow_mut().insert(disease_name.to_string(), LatentInfection {
+            disease_name,
+            injury_name: injury_key.injury.to_string(),
+            body_part: injury_key.body_part,
+            onset_time,
+            no_return_time,
+            prophylaxis: latent.prophylaxis,
+            doses_needed: latent.doses,
+            doses_taken: 0
+        });
+    }
+
+    /// Counts prophylaxis doses and removes prevented diseases
+    pub(crate) fn on_prophylaxis_taken(&self, game_time: &GameTimeC, item_name: &String) {
+        let mut prevented = Vec::new();
+
+        for (name, infection) in self.latent_infections.borrow_mut().iter_mut() {
+            if !infection.prophylaxis.contains(item_name) { continue; }
+
+            if infection.is_past_no_return(game_time) {
+                self.queue_message(Event::LatentDiseaseTreatmentFailed(name.to_string()));
+                continue;
+            }
+
+            infection.doses_taken += 1;
+
+            if infection.doses_taken >= infection.doses_needed {
+                prevented.push(name.to_string());
+            }
+        }
+
+        for name in prevented.iter() {
+            self.latent_infections.borrow_mut().remove(name);
+            self.active_diseases.borrow_mut().remove(name);
+
+            self.queue_message(Event::LatentDiseasePrevented(name.to_string()));
+        }
+    }
+
+    /// Forgets latent infection of a removed disease
+    pub(crate) fn forget_latent_infection(&self, disease_name: &String) {
+        self.latent_infections.borrow_mut().remove(disease_name);
+    }
+}
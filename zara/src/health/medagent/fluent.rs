@@ -1,4 +1,4 @@
-use crate::health::medagent::{CurveType, MedicalAgent, MedicalAgentGroup};
+use crate::health::medagent::{CurveType, MedicalAgent, MedicalAgentGroup, KeyFrame, AgentSideEffects};
 use crate::health::MedicalAgentBuilder;
 
 impl MedicalAgentBuilder {
@@ -18,6 +18,25 @@ pub trait AgentStart {
 pub trait AgentCurve {
     /// Activation curve type for this agent
     fn activates(&self, curve_type: CurveType) -> &dyn AgentDuration;
+    /// Custom activation curve for this agent
+    ///
+    /// # Parameters
+    /// - `keyframes`: activation keyframes. Time is a fraction of the dose duration (0..1), value is a percent of activity (0..100)
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::medagent::KeyFrame;
+    ///
+    /// activates_with_curve(
+    ///     vec![
+    ///         KeyFrame::new(0., 0.),
+    ///         KeyFrame::new(0.1, 100.),
+    ///         KeyFrame::new(0.3, 40.),
+    ///         KeyFrame::new(1., 0.)
+    ///     ]
+    /// )
+    /// ```
+    fn activates_with_curve(&self, keyframes: Vec<KeyFrame>) -> &dyn AgentDuration;
 }
 
 /// Agent fluent step trait
@@ -46,6 +65,28 @@ pub trait AgentItems {
 
 /// Agent fluent step trait
 pub trait AgentEnd {
+    /// Display name (or localization key) of this agent. Agent is still keyed by its name
+    fn displayed_as(&self, display_name: &str) -> &dyn AgentEnd;
+    /// Agent makes character drowsy while active
+    ///
+    /// # Parameters
+    /// - `fatigue`: fatigue added at the full agent activity (0..100)
+    fn causes_drowsiness(&self, fatigue: f32) -> &dyn AgentEnd;
+    /// Agent makes character nauseous while active, so food gives less
+    ///
+    /// # Parameters
+    /// - `food_gain_loss`: part of the food gain lost at the full agent activity (0..100 percents)
+    fn causes_nausea(&self, food_gain_loss: f32) -> &dyn AgentEnd;
+    /// Agent eases pain while active
+    ///
+    /// # Parameters
+    /// - `pain`: pain eased at the full agent activity (0..100)
+    fn eases_pain(&self, pain: f32) -> &dyn AgentEnd;
+    /// Agent raises the toxicity level while active
+    ///
+    /// # Parameters
+    /// - `per_hour`: toxicity added per game hour at the full agent activity (0..100)
+    fn raises_toxicity(&self, per_hour: f32) -> &dyn AgentEnd;
     /// Builds resulted medical agent according with the information provided
     fn build(&self) -> MedicalAgent;
 }
@@ -60,6 +101,13 @@ impl AgentStart for MedicalAgentBuilder {
 impl AgentCurve for MedicalAgentBuilder {
     fn activates(&self, curve_type: CurveType) -> &dyn AgentDuration {
         self.curve_type.replace(curve_type);
+        self.keyframes.replace(None);
+
+        self.as_agent_duration()
+    }
+
+    fn activates_with_curve(&self, keyframes: Vec<KeyFrame>) -> &dyn AgentDuration {
+        self.keyframes.replace(Some(keyframes));
 
         self.as_agent_duration()
     }
@@ -79,13 +127,61 @@ impl AgentItems for MedicalAgentBuilder {
     }
 }
 impl AgentEnd for MedicalAgentBuilder {
+    fn displayed_as(&self, display_name: &str) -> &dyn AgentEnd {
+        self.display_name.replace(Some(display_name.to_string()));
+
+        self.as_agent_end()
+    }
+
+    fn causes_drowsiness(&self, fatigue: f32) -> &dyn AgentEnd {
+        self.side_effects.set(AgentSideEffects { drowsiness: fatigue, ..self.side_effects.get() });
+
+        self.as_agent_end()
+    }
+
+    fn causes_nausea(&self, food_gain_loss: f32) -> &dyn AgentEnd {
+        self.side_effects.set(AgentSideEffects { nausea: food_gain_loss, ..self.side_effects.get() });
+
+        self.as_agent_end()
+    }
+
+    fn eases_pain(&self, pain: f32) -> &dyn AgentEnd {
+        self.side_effects.set(AgentSideEffects { pain_relief: pain, ..self.side_effects.get() });
+
+        self.as_agent_end()
+    }
+
+    fn raises_toxicity(&self, per_hour: f32) -> &dyn AgentEnd {
+        self.side_effects.set(AgentSideEffects { toxicity: per_hour, ..self.side_effects.get() });
+
+        self.as_agent_end()
+    }
+
     fn build(&self) -> MedicalAgent {
-        MedicalAgent::new(
-            self.name.borrow().to_string(),
-            *self.curve_type.borrow(),
-            self.duration_minutes.get(),
-            MedicalAgentGroup::new(
-                self.items.borrow().iter().map(|x| x.to_string()).collect()
-            ))
+        let group = MedicalAgentGroup::new(
+            self.items.borrow().iter().map(|x| x.to_string()).collect()
+        );
+
+        let agent = match self.keyframes.borrow().as_ref() {
+            Some(keyframes) => MedicalAgent::with_keyframes(
+                self.name.borrow().to_string(),
+                keyframes.clone(),
+                self.duration_minutes.get(),
+                group
+            ),
+            None => MedicalAgent::new(
+                self.name.borrow().to_string(),
+                *self.curve_type.borrow(),
+                self.duration_minutes.get(),
+                group
+            )
+        };
+
+        let agent = agent.with_side_effects(self.side_effects.get());
+
+        match self.display_name.borrow().as_ref() {
+            Some(display_name) => agent.with_display_name(display_name.to_string()),
+            None => agent
+        }
     }
 }
\ No newline at end of file
@@ -1,7 +1,8 @@
 use crate::error::MedicalAgentErr;
 use crate::health::Health;
-use crate::health::medagent::lerp::{MultiKeyedLerp, KeyFrame};
-use crate::utils::GameTimeC;
+use crate::health::side::SideEffectDeltasC;
+use crate::health::medagent::lerp::MultiKeyedLerp;
+use crate::utils::{GameTimeC, FrameSummaryC};
 use crate::utils::event::{Event, MessageQueue};
 
 use std::collections::{HashMap, BTreeMap};
@@ -14,6 +15,8 @@ use std::hash::{Hasher, Hash};
 
 mod lerp;
 
+pub use lerp::KeyFrame;
+
 pub(crate) mod state;
 pub mod fluent;
 
@@ -77,6 +80,58 @@ impl Health {
             b.insert(agent.name.to_string(), agent);
         }
     }
+
+    /// Unregisters a medical agent. All its doses are dropped. If agent was active,
+    /// `MedicalAgentDeactivated` event will be sent
+    ///
+    /// # Parameters
+    /// - `agent_name`: unique name of the medical agent
+    ///
+    /// # Returns
+    /// Ok on success
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.unregister_medical_agent(&agent_name);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Medical-Agents) for more info.
+    ///
+    /// ## Notes
+    /// Borrows `medical_agents.agents` collection
+    pub fn unregister_medical_agent(&self, agent_name: &String) -> Result<(), MedicalAgentErr> {
+        match self.medical_agents.agents.borrow_mut().remove(agent_name) {
+            Some(agent) => {
+                if agent.is_active() {
+                    self.medical_agents.queue_message(Event::MedicalAgentDeactivated(agent_name.to_string()));
+                }
+
+                Ok(())
+            },
+            None => Err(MedicalAgentErr::AgentNotFound)
+        }
+    }
+
+    /// Returns combined side effects of all active medical agents, scaled by their activity
+    ///
+    /// # Examples
+    /// ```
+    /// let effects = person.health.agent_side_effects();
+    /// ```
+    pub fn agent_side_effects(&self) -> AgentSideEffects { self.agent_side_effects.get() }
+
+    pub(crate) fn set_agent_side_effects(&self, value: AgentSideEffects) {
+        self.agent_side_effects.set(value);
+    }
+
+    /// Adds fatigue from the drowsy medical agents
+    pub(crate) fn apply_agent_drowsiness(&self, deltas: &mut SideEffectDeltasC, frame_data: &FrameSummaryC) {
+        // Fatigue is frozen while sleeping
+        if frame_data.player.is_sleeping { return; }
+
+        deltas.fatigue_bonus += self.agent_side_effects.get().drowsiness;
+    }
 }
 
 /// Medical agents group. Contains a list of inventory items keys.
@@ -148,6 +203,45 @@ struct AgentUpdateResult {
     is_active: bool
 }
 
+/// Side effects of a medical agent, like drowsiness after a painkiller. Effects are at full
+/// strength when the agent is fully active, and scale down with its activity
+#[derive(Copy, Clone, Debug, Default)]
+pub struct AgentSideEffects {
+    /// Fatigue added while the agent is active (0..100)
+    pub drowsiness: f32,
+    /// Part of the food gain lost while the agent is active (0..100 percents)
+    pub nausea: f32,
+    /// Pain eased while the agent is active (0..100)
+    pub pain_relief: f32,
+    /// Toxicity added per game hour while the agent is active (0..100)
+    pub toxicity: f32
+}
+impl fmt::Display for AgentSideEffects {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Drowsiness {:.0}, nausea {:.0}, pain relief {:.0}, toxicity {:.0}", self.drowsiness, self.nausea,
+               self.pain_relief, self.toxicity)
+    }
+}
+impl Eq for AgentSideEffects { }
+impl PartialEq for AgentSideEffects {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        f32::abs(self.drowsiness - other.drowsiness) < EPS &&
+        f32::abs(self.nausea - other.nausea) < EPS &&
+        f32::abs(self.pain_relief - other.pain_relief) < EPS &&
+        f32::abs(self.toxicity - other.toxicity) < EPS
+    }
+}
+impl Hash for AgentSideEffects {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u32((self.drowsiness*10_000_f32) as u32);
+        state.write_u32((self.nausea*10_000_f32) as u32);
+        state.write_u32((self.pain_relief*10_000_f32) as u32);
+        state.write_u32((self.toxicity*10_000_f32) as u32);
+    }
+}
+
 impl AgentUpdateResult {
     /// Returns a new empty agent update result (inactive)
     /// 
@@ -167,14 +261,21 @@ impl AgentUpdateResult {
 /// Describes medical agent
 #[derive(Clone, Debug, Default)]
 pub struct MedicalAgent {
-    /// Unique name of a medical agent
+    /// Unique name of a medical agent. Is used as its id
     pub name: String,
+    /// Display name (or localization key) of a medical agent. When not set, `name` is used
+    pub display_name: Option<String>,
     /// Group of items associated with this agent
     pub group: MedicalAgentGroup,
     /// Type of activation curve
     pub activation_curve: CurveType,
     /// Duration of a single dose, in game minutes
     pub duration_minutes: f32,
+    /// Custom activation keyframes. When set, `activation_curve` is ignored.
+    /// Keyframe time is a fraction of the dose duration (0..1), value is a percent of activity (0..100)
+    pub activation_keyframes: Option<Vec<KeyFrame>>,
+    /// Side effects while this agent is active
+    pub side_effects: AgentSideEffects,
 
     // Private fields
     percent_of_activity: Cell<f32>,
@@ -212,18 +313,35 @@ impl PartialOrd for MedicalAgent {
 impl PartialEq for MedicalAgent {
     fn eq(&self, other: &Self) -> bool {
         self.name == other.name &&
+        self.display_name == other.display_name &&
         self.activation_curve == other.activation_curve &&
         self.duration_minutes == other.duration_minutes &&
-        self.group == other.group
+        self.group == other.group &&
+        self.side_effects == other.side_effects &&
+        match (&self.activation_keyframes, &other.activation_keyframes) {
+            (Some(a), Some(b)) => a.len() == b.len() &&
+                a.iter().zip(b.iter()).all(|(x, y)| x.time == y.time && x.value == y.value),
+            (None, None) => true,
+            _ => false
+        }
     }
 }
 impl Hash for MedicalAgent {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.name.hash(state);
+        self.display_name.hash(state);
         self.activation_curve.hash(state);
         self.group.hash(state);
+        self.side_effects.hash(state);
 
         state.write_u32((self.duration_minutes*10_000_f32) as u32);
+
+        if let Some(keyframes) = &self.activation_keyframes {
+            for k in keyframes.iter() {
+                state.write_u32((k.time*10_000_f32) as u32);
+                state.write_u32((k.value*10_000_f32) as u32);
+            }
+        }
     }
 }
 impl MedicalAgent {
@@ -248,9 +366,12 @@ impl MedicalAgent {
     pub fn new(name: String, activation_curve: CurveType, duration_minutes: f32, group: MedicalAgentGroup) -> Self {
         MedicalAgent {
             name: name.to_string(),
+            display_name: None,
             activation_curve,
             duration_minutes,
             group,
+            activation_keyframes: None,
+            side_effects: AgentSideEffects::default(),
             is_active: Cell::new(false),
             percent_of_activity: Cell::new(0.),
             percent_of_presence: Cell::new(0.),
@@ -260,12 +381,113 @@ impl MedicalAgent {
         }
     }
 
-    pub(crate) fn on_consumed(&self, game_time: &GameTimeC, item_name: String) {
-        self.add_dose_if_needed(game_time, item_name);
+    /// Creates a new medical agent with a custom activation curve. You can use
+    /// [`MedicalAgentBuilder`](crate::health::MedicalAgentBuilder) to construct new medical agent.
+    ///
+    /// # Parameters
+    /// - `name`: name of the medical agent. Will be used as its key
+    /// - `keyframes`: activation keyframes. Time is a fraction of the dose duration (0..1), value is a percent of activity (0..100)
+    /// - `duration_minutes`: duration, in game minutes, of a single agent dose effect
+    /// - `group`: medical agent group associated with this medical agent
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::medagent::{MedicalAgent, KeyFrame};
+    ///
+    /// let agent = MedicalAgent::with_keyframes(agent_name, vec![
+    ///     KeyFrame::new(0., 0.),
+    ///     KeyFrame::new(0.1, 100.),
+    ///     KeyFrame::new(0.3, 40.),
+    ///     KeyFrame::new(1., 0.)
+    /// ], 60., group);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Medical-Agents) for more info.
+    pub fn with_keyframes(name: String, keyframes: Vec<KeyFrame>, duration_minutes: f32, group: MedicalAgentGroup) -> Self {
+        MedicalAgent {
+            activation_keyframes: Some(keyframes),
+            ..MedicalAgent::new(name, CurveType::default(), duration_minutes, group)
+        }
+    }
+
+    /// Sets display name (or localization key) of this agent. Agent is still keyed by its `name`
+    ///
+    /// # Parameters
+    /// - `display_name`: display name or localization key
+    ///
+    /// # Examples
+    /// ```
+    /// let agent = MedicalAgent::new(agent_name, curve, 32., group)
+    ///     .with_display_name(format!("agents.morphine"));
+    /// ```
+    pub fn with_display_name(mut self, display_name: String) -> Self {
+        self.display_name = Some(display_name);
+
+        self
+    }
+
+    /// Sets side effects of this agent
+    ///
+    /// # Parameters
+    /// - `side_effects`: side effects at the full agent activity
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::medagent::AgentSideEffects;
+    ///
+    /// let agent = MedicalAgent::new(agent_name, curve, 32., group)
+    ///     .with_side_effects(AgentSideEffects { drowsiness: 20., ..Default::default() });
+    /// ```
+    pub fn with_side_effects(mut self, side_effects: AgentSideEffects) -> Self {
+        self.side_effects = side_effects;
+
+        self
+    }
+
+    /// Gets unique id of this agent (its `name`)
+    ///
+    /// # Examples
+    /// ```
+    /// let id = agent.get_id();
+    /// ```
+    pub fn get_id(&self) -> String { self.name.to_string() }
+
+    /// Gets display name (or localization key) of this agent. Is `name` when no display name is set
+    ///
+    /// # Examples
+    /// ```
+    /// let name = agent.get_name();
+    /// ```
+    pub fn get_name(&self) -> String {
+        self.display_name.as_ref().unwrap_or(&self.name).to_string()
+    }
+
+    /// Adds a dose. `potency` (0..1) scales the dose activity, for example for expired pills
+    pub(crate) fn on_consumed(&self, game_time: &GameTimeC, item_name: String, potency: f32) {
+        self.add_dose_if_needed(game_time, item_name, potency);
     }
 
     pub(crate) fn on_appliance_taken(&self, game_time: &GameTimeC, item_name: String) {
-        self.add_dose_if_needed(game_time, item_name);
+        self.add_dose_if_needed(game_time, item_name, 1.);
+    }
+
+    /// Cancels doses received from the given items since the given game time (in game seconds).
+    /// Returns `true` if any dose was cancelled
+    pub(crate) fn cancel_doses(&self, items: &[String], since: f32) -> bool {
+        let mut doses = self.doses.borrow_mut();
+        let count = doses.len();
+
+        doses.retain(|key, dose| dose.start_time < since || !items.contains(&key.item));
+
+        if doses.len() == count { return false; }
+        if doses.is_empty() {
+            drop(doses);
+
+            self.reset();
+        }
+
+        true
     }
 
     fn update(&self, game_time: &GameTimeC) -> AgentUpdateResult {
@@ -325,12 +547,19 @@ impl MedicalAgent {
         }
     }
 
-    fn add_dose_if_needed(&self, game_time: &GameTimeC, item_name: String) {
+    fn add_dose_if_needed(&self, game_time: &GameTimeC, item_name: String, potency: f32) {
         if self.group.contains(&item_name) {
             let gt = game_time.as_secs_f32();
             let duration_secs = self.duration_minutes*60.;
+            let potency = crate::utils::clamp_01(potency);
 
-            let frames = MedicalAgent::generate_frames(gt, duration_secs, self.activation_curve);
+            let frames: Vec<KeyFrame> = match &self.activation_keyframes {
+                Some(keyframes) => keyframes.iter()
+                    .map(|k| KeyFrame::new(gt + duration_secs * k.time, k.value))
+                    .collect(),
+                None => MedicalAgent::generate_frames(gt, duration_secs, self.activation_curve)
+            };
+            let frames = frames.iter().map(|k| KeyFrame::new(k.time, k.value * potency)).collect();
             let key = AgentDoseKey {
                 item: item_name.to_string(),
                 timestamp: gt as i32
@@ -389,6 +618,28 @@ impl MedicalAgent {
         }
     }
 
+    /// Clears all doses and activity of this agent. If agent was active,
+    /// `MedicalAgentDeactivated` event will be sent
+    ///
+    /// # Examples
+    /// ```
+    /// agent.reset();
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Medical-Agents) for more info.
+    pub fn reset(&self) {
+        if self.is_active.get() {
+            self.queue_message(Event::MedicalAgentDeactivated(self.name.to_string()));
+        }
+
+        self.doses.borrow_mut().clear();
+        self.last_dose_end_time.replace(None);
+        self.is_active.set(false);
+        self.percent_of_activity.set(0.);
+        self.percent_of_presence.set(0.);
+    }
+
     fn generate_frames(gt: f32, duration_secs: f32, curve: CurveType) -> Vec<KeyFrame> {
         match curve {
             CurveType::Linearly => {
@@ -470,8 +721,9 @@ impl MedicalAgentsMonitor {
         }
     }
 
-    pub(crate) fn update(&self, game_time: &GameTimeC) {
+    pub(crate) fn update(&self, health: &Health, game_time: &GameTimeC) {
         let mut active_count = 0;
+        let mut side_effects = AgentSideEffects::default();
         for (_, agent) in self.agents.borrow().iter() {
             let result = agent.update(game_time);
 
@@ -479,9 +731,41 @@ impl MedicalAgentsMonitor {
                 self.flush_queue(agent.get_message_queue());
             }
 
-            if result.is_active { active_count += 1; }
+            if result.is_active {
+                active_count += 1;
+
+                // Drowsiness and toxicity add up, nausea and pain relief of the strongest agent win
+                let activity = agent.percent_of_activity.get() / 100.;
+
+                side_effects.drowsiness += agent.side_effects.drowsiness * activity;
+                side_effects.nausea = f32::max(side_effects.nausea, agent.side_effects.nausea * activity);
+                side_effects.pain_relief = f32::max(side_effects.pain_relief, agent.side_effects.pain_relief * activity);
+                side_effects.toxicity += agent.side_effects.toxicity * activity;
+            }
         }
         self.active_count.set(active_count);
+
+        health.set_agent_side_effects(AgentSideEffects {
+            drowsiness: crate::utils::clamp(side_effects.drowsiness, 0., 100.),
+            nausea: crate::utils::clamp(side_effects.nausea, 0., 100.),
+            pain_relief: crate::utils::clamp(side_effects.pain_relief, 0., 100.),
+            toxicity: crate::utils::clamp(side_effects.toxicity, 0., 100.)
+        });
+    }
+
+    /// Clears doses and activity of all registered medical agents
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.medical_agents.reset_all();
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `agents` collection
+    pub fn reset_all(&self) {
+        for (_, agent) in self.agents.borrow().iter() {
+            agent.reset();
+        }
     }
 
     /// Returns number of active medical agents
@@ -1,4 +1,4 @@
-use crate::health::medagent::{MedicalAgentsMonitor, MedicalAgent, CurveType, MedicalAgentGroup, AgentDose, AgentDoseKey};
+use crate::health::medagent::{MedicalAgentsMonitor, MedicalAgent, CurveType, MedicalAgentGroup, AgentDose, AgentDoseKey, AgentSideEffects};
 use crate::utils::GameTimeC;
 use crate::health::medagent::lerp::{MultiKeyedLerp, KeyFrame};
 
@@ -27,6 +27,8 @@ pub struct MedicalAgentStateContract {
     pub activation_curve: CurveType,
     /// Captured state of the `duration_minutes` field
     pub duration_minutes: f32,
+    /// Captured state of the `activation_keyframes` field
+    pub activation_keyframes: Option<Vec<KeyFrameStateContract>>,
     /// Captured state of the `percent_of_activity` field
     pub percent_of_activity: f32,
     /// Captured state of the `percent_of_presence` field
@@ -37,6 +39,8 @@ pub struct MedicalAgentStateContract {
     pub last_dose_end_time: Option<Duration>,
     /// Captured state of the `doses` field
     pub doses: Vec<AgentDoseStateContract>,
+    /// Captured state of the `side_effects` field
+    pub side_effects: AgentSideEffects
 }
 impl fmt::Display for MedicalAgentStateContract {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -51,9 +55,11 @@ impl PartialEq for MedicalAgentStateContract {
         self.name == other.name &&
         self.group == other.group &&
         self.activation_curve == other.activation_curve &&
+        self.activation_keyframes == other.activation_keyframes &&
         self.is_active == other.is_active &&
         self.last_dose_end_time == other.last_dose_end_time &&
         self.doses == other.doses &&
+        self.side_effects == other.side_effects &&
         f32::abs(self.duration_minutes - other.duration_minutes) < EPS &&
         f32::abs(self.percent_of_activity - other.percent_of_activity) < EPS &&
         f32::abs(self.percent_of_presence - other.percent_of_presence) < EPS
@@ -64,9 +70,11 @@ impl Hash for MedicalAgentStateContract {
         self.name.hash(state);
         self.group.hash(state);
         self.activation_curve.hash(state);
+        self.activation_keyframes.hash(state);
         self.is_active.hash(state);
         self.last_dose_end_time.hash(state);
         self.doses.hash(state);
+        self.side_effects.hash(state);
 
         state.write_u32((self.duration_minutes*10_000_f32) as u32);
         state.write_u32((self.percent_of_activity*10_000_f32) as u32);
@@ -246,7 +254,10 @@ impl MedicalAgent {
             percent_of_presence: self.percent_of_presence.get(),
             percent_of_activity: self.percent_of_activity.get(),
             activation_curve: self.activation_curve,
-            duration_minutes: self.duration_minutes
+            activation_keyframes: self.activation_keyframes.as_ref()
+                .map(|k| k.iter().map(|x| x.get_state()).collect()),
+            duration_minutes: self.duration_minutes,
+            side_effects: self.side_effects
         }
     }
     pub(crate) fn set_state(&self, state: &MedicalAgentStateContract) {
@@ -288,8 +299,12 @@ impl MedicalAgentsMonitor {
         b.clear();
 
         for agent in &state.agents {
-            let a = MedicalAgent::new(agent.name.to_string(), agent.activation_curve, agent.duration_minutes,
-                                     MedicalAgentGroup::new(agent.group.items.clone()));
+            let group = MedicalAgentGroup::new(agent.group.items.clone());
+            let a = match &agent.activation_keyframes {
+                Some(keyframes) => MedicalAgent::with_keyframes(agent.name.to_string(),
+                    keyframes.iter().map(|x| KeyFrame::new(x.time, x.value)).collect(), agent.duration_minutes, group),
+                None => MedicalAgent::new(agent.name.to_string(), agent.activation_curve, agent.duration_minutes, group)
+            }.with_side_effects(agent.side_effects);
             a.set_state(&agent);
             b.insert(a.name.to_string(), a);
         }
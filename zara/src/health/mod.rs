@@ -1,14 +1,25 @@
 use crate::utils::event::{Event, MessageQueue};
-use crate::utils::{GameTimeC, HealthC};
+use crate::utils::{GameTimeC, HealthC, MonitorPriorities};
 use crate::health::disease::{DiseaseMonitor, ActiveDisease};
 use crate::health::injury::{ActiveInjury};
-use crate::health::side::{SideEffectsMonitor};
-use crate::health::medagent::{MedicalAgentsMonitor, CurveType};
+use crate::health::condition::ActiveCondition;
+use crate::health::heat::HeatExhaustionFactory;
+use crate::health::nutrition::{DeficiencyDisease, Nutrient, NutrientsC};
+use crate::health::tourniquet::TissueDamageFactory;
+use crate::health::burns::BurnFactory;
+use crate::health::poisoning::{FoodPoisoningFactory, DirtyWaterFactory};
+use crate::health::bleeding::BleedingMultipliers;
+use crate::health::side::{SideEffectsMonitor, SideEffectDeltasC};
+use crate::health::medagent::{MedicalAgentsMonitor, CurveType, KeyFrame, AgentSideEffects};
 use crate::health::medagent::fluent::{AgentStart};
 use crate::inventory::items::{InventoryItem, ConsumableC, ApplianceC};
 use crate::body::BodyPart;
+use crate::body::parts::BodyPartsRegistry;
+use crate::traits::TraitRegistry;
+use crate::player::CharacterProfile;
+use crate::state::DirtyTracked;
 
-use std::collections::{HashMap, BTreeMap};
+use std::collections::{HashMap, HashSet, BTreeMap, BTreeSet};
 use std::cell::{RefCell, Cell, RefMut};
 use std::rc::Rc;
 use std::sync::Arc;
@@ -19,6 +30,27 @@ use std::hash::{Hash, Hasher};
 mod update;
 mod status_methods;
 mod monitors;
+mod projection;
+mod profile;
+mod digestion;
+mod orientation;
+pub mod nausea;
+pub mod appetite;
+pub mod stimulant;
+pub mod heat;
+pub mod thermal;
+pub mod nutrition;
+pub mod toxicity;
+pub mod bleeding;
+pub mod tourniquet;
+pub mod burns;
+pub mod latent;
+pub mod healing;
+pub mod procedure;
+pub mod factory;
+pub mod pain;
+pub mod poisoning;
+pub mod actions;
 
 pub(crate) mod state;
 
@@ -26,6 +58,7 @@ pub mod disease;
 pub mod injury;
 pub mod side;
 pub mod medagent;
+pub mod condition;
 
 /// Node that describes and controls player's health. It contains
 /// vitals data, active disease, active injuries, registered medical
@@ -47,10 +80,24 @@ pub struct Health {
     /// # Links
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Changing-regain-rates) for more info.
     pub oxygen_regain_rate: Cell<f32>,
-    /// All active or scheduled diseases
-    pub diseases: Arc<RefCell<HashMap<String, Rc<ActiveDisease>>>>,
-    /// All active or scheduled injuries
-    pub injuries: Arc<RefCell<HashMap<InjuryKey, Rc<ActiveInjury>>>>,
+    /// All active or scheduled diseases, ordered by disease name. Read them with
+    /// [`disease`](#method.disease) or [`for_each_disease`](#method.for_each_disease)
+    /// 
+    /// Iteration order is stable, so vitals deltas and UI listings are deterministic
+    #[deprecated(since = "1.0.8", note = "will become private; use `disease`, `for_each_disease` or `snapshot_diseases`")]
+    pub diseases: Arc<RefCell<BTreeMap<String, Rc<ActiveDisease>>>>,
+    /// All active or scheduled injuries, ordered by injury name and then by body part. Read
+    /// them with [`injury`](#method.injury) or [`for_each_injury`](#method.for_each_injury)
+    /// 
+    /// Iteration order is stable, so vitals deltas and UI listings are deterministic
+    #[deprecated(since = "1.0.8", note = "will become private; use `injury`, `for_each_injury` or `snapshot_injuries`")]
+    pub injuries: Arc<RefCell<BTreeMap<InjuryKey, Rc<ActiveInjury>>>>,
+    /// Same collection as `diseases`, used by Zara itself
+    pub(crate) active_diseases: Arc<RefCell<BTreeMap<String, Rc<ActiveDisease>>>>,
+    /// Same collection as `injuries`, used by Zara itself
+    pub(crate) active_injuries: Arc<RefCell<BTreeMap<InjuryKey, Rc<ActiveInjury>>>>,
+    /// All active long-term conditions, ordered by condition name
+    pub conditions: Arc<RefCell<BTreeMap<String, Rc<ActiveCondition>>>>,
     /// Registered medical agents
     /// 
     /// # Links
@@ -80,6 +127,14 @@ pub struct Health {
     /// # Links
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Side-effects-Monitors) for more info.
     pub side_effects: Rc<RefCell<HashMap<usize, Box<dyn SideEffectsMonitor>>>>,
+    /// Execution order of the disease monitors
+    disease_monitor_priorities: MonitorPriorities,
+    /// Execution order of the side effects monitors
+    side_effect_priorities: MonitorPriorities,
+    /// Side effects monitor packs: monitor keys by pack id
+    monitor_packs: RefCell<HashMap<usize, Vec<usize>>>,
+    /// Keys of the side effects monitors that belong to the disabled packs
+    disabled_side_effects: RefCell<HashSet<usize>>,
 
     // Health state fields
     /// Body temperature (degrees C)
@@ -102,17 +157,118 @@ pub struct Health {
     fatigue_level: Cell<f32>,
     /// Oxygen level (0..100)
     oxygen_level: Cell<f32>,
+    /// Toxicity level (0..100)
+    toxicity_level: Cell<f32>,
     /// Is character alive
     is_alive: Cell<bool>,
     /// Has any injury active blood loss
     has_blood_loss: Cell<bool>,
+    /// Physiology profile of this character
+    profile: Cell<CharacterProfile>,
+    /// Traits of this character
+    traits: Rc<TraitRegistry>,
+    /// Registered body parts
+    body_parts: Rc<BodyPartsRegistry>,
+    /// Creates heat exhaustion disease
+    heat_exhaustion_factory: RefCell<Option<HeatExhaustionFactory>>,
+    /// Creates tissue damage injury for the limbs with tourniquet on for too long
+    tissue_damage_factory: RefCell<Option<TissueDamageFactory>>,
+    /// Creates burn injury for the `ZaraController.burn` calls
+    burn_factory: RefCell<Option<BurnFactory>>,
+    /// Creates food poisoning disease for the spoiling items that failed the poisoning roll
+    food_poisoning_factory: RefCell<Option<FoodPoisoningFactory>>,
+    /// Creates a disease for the contaminated water that failed the roll
+    dirty_water_factory: RefCell<Option<DirtyWaterFactory>>,
+    /// Diseases carried by the injuries that did not start yet
+    latent_infections: RefCell<HashMap<String, latent::LatentInfection>>,
+    /// Registered multi-step procedures
+    procedures: RefCell<HashMap<String, procedure::Procedure>>,
+    /// Procedures started on the injuries and not finished yet
+    active_procedures: RefCell<HashMap<InjuryKey, procedure::ActiveProcedure>>,
+    /// Disease kinds that can be recreated by id
+    disease_factories: RefCell<HashMap<String, factory::DiseaseFactory>>,
+    /// Injury kinds that can be recreated by id
+    injury_factories: RefCell<HashMap<String, factory::InjuryFactory>>,
+    /// Injuries blood drain multipliers by activity
+    bleeding_multipliers: Cell<BleedingMultipliers>,
+    /// Stamina regain suppression after burst actions
+    stamina_recovery: Cell<actions::BurstRecovery>,
+    /// Oxygen regain suppression after burst actions
+    oxygen_recovery: Cell<actions::BurstRecovery>,
+    /// Body temperature change from hot or cold consumed items, degrees C
+    consumed_heat: Cell<f32>,
+    /// Meals that are being digested
+    digestion: RefCell<Vec<digestion::DigestingMeal>>,
+    /// Nausea level (0..100)
+    nausea_level: Cell<f32>,
+    /// How vomiting works
+    vomiting_config: Cell<nausea::VomitingConfig>,
+    /// Food and drinks that can be thrown up
+    recent_meals: RefCell<Vec<nausea::RecentMeal>>,
+    /// Food levels at which hunger urgency changes
+    hunger_thresholds: Cell<appetite::UrgencyThresholds>,
+    /// Water levels at which thirst urgency changes
+    thirst_thresholds: Cell<appetite::UrgencyThresholds>,
+    /// Hunger urgency that was signaled last
+    last_hunger_urgency: Cell<appetite::Urgency>,
+    /// Thirst urgency that was signaled last
+    last_thirst_urgency: Cell<appetite::Urgency>,
+    /// How sustained cold and heat turn into diseases. `None` if thermal conditions are off
+    thermal_conditions: Cell<Option<thermal::ThermalConditionsConfig>>,
+    /// Game seconds of the sustained cold
+    cold_exposure: Cell<f32>,
+    /// Game seconds of the sustained heat
+    heat_exposure: Cell<f32>,
+    /// Cold exposure disease stage that was signaled last
+    hypothermia_stage: Cell<Option<StageLevel>>,
+    /// Heat exposure disease stage that was signaled last
+    hyperthermia_stage: Cell<Option<StageLevel>>,
+    /// How per-nutrient levels drain. `None` if detailed nutrition is off
+    nutrition: Cell<Option<nutrition::NutritionConfig>>,
+    /// Per-nutrient levels (0..100)
+    nutrient_levels: Cell<NutrientsC>,
+    /// Nutrients character is deficient in
+    nutrient_deficiencies: RefCell<BTreeSet<Nutrient>>,
+    /// Diseases spawned by the nutrient deficiencies
+    deficiency_factories: RefCell<HashMap<Nutrient, DeficiencyDisease>>,
+    /// Nutrients whose deficiency diseases were spawned by the deficiency and are active
+    deficiency_diseases: RefCell<BTreeSet<Nutrient>>,
+    /// How toxicity decays and at which levels it affects vitals
+    toxicity_config: Cell<toxicity::ToxicityConfig>,
+    /// Toxicity stage that was signaled last
+    toxicity_stage: Cell<toxicity::ToxicityStage>,
+    /// Stimulant effects bound to the medical agents
+    stimulants: RefCell<HashMap<String, stimulant::StimulantBinding>>,
+    /// Fatigue hidden by the active stimulants (0..100)
+    fatigue_mask: Cell<f32>,
+    /// Extra fatigue left from the stimulant crashes (0..100)
+    fatigue_debt: Cell<f32>,
+    /// Combined side effects of the active medical agents
+    agent_side_effects: Cell<AgentSideEffects>,
+    /// Pain levels at which actions are blocked
+    pain_thresholds: Cell<pain::PainThresholds>,
+    /// Actions blocked as of the last `update` call
+    action_restrictions: Cell<pain::ActionRestrictions>,
+    /// Disorientation (0..100) as of the last `update` call
+    disorientation_level: Cell<f32>,
+    /// How much of the disorientation carried items negate (0..100)
+    navigation_aid: Cell<f32>,
+
+    // Projection caches
+    /// Game time of the last `update` call
+    last_update_game_time: RefCell<GameTimeC>,
+    /// Side effects deltas of the last `update` call. Level bonuses are per game second
+    last_side_effects: RefCell<SideEffectDeltasC>,
+
+    /// Is node changed since the last state snapshot
+    is_dirty: Cell<bool>,
 
     /// Messages queued for sending on the next frame
     message_queue: RefCell<BTreeMap<usize, Event>>
 }
 
 /// Compound injury key that consists of a "injury name"-"body part" pair
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct InjuryKey {
     /// Inventory item name (key)
     pub injury: String,
@@ -160,12 +316,52 @@ impl TryFrom<i32> for StageLevel {
         }
     }
 }
+
+/// Why a disease or an injury was removed
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub enum RemovalReason {
+    /// Passed its lifetime without being treated
+    Expired,
+    /// Healed out after the treatment or self-healing
+    Cured,
+    /// Removed by the game or with the lost body part
+    Cleared
+}
+impl fmt::Display for RemovalReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Health vitals enum
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub enum Vital {
+    BodyTemperature,
+    HeartRate,
+    TopPressure,
+    BottomPressure,
+    Blood,
+    Food,
+    Water,
+    Stamina,
+    Fatigue,
+    Oxygen
+}
+impl fmt::Display for Vital {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
 /// Used to describe a new medical agent. Use `start` method to begin.
 pub struct MedicalAgentBuilder {
     pub(crate) name: RefCell<String>,
+    pub(crate) display_name: RefCell<Option<String>>,
     pub(crate) duration_minutes: Cell<f32>,
     pub(crate) curve_type: RefCell<CurveType>,
-    pub(crate) items: RefCell<Vec<String>>
+    pub(crate) keyframes: RefCell<Option<Vec<KeyFrame>>>,
+    pub(crate) items: RefCell<Vec<String>>,
+    pub(crate) side_effects: Cell<AgentSideEffects>
 }
 impl MedicalAgentBuilder {
     /// Starts building process for a new medical agent
@@ -194,30 +390,91 @@ impl MedicalAgentBuilder {
     pub fn start() -> Box<dyn AgentStart> {
         Box::new(MedicalAgentBuilder {
             name: RefCell::new(String::new()),
+            display_name: RefCell::new(None),
             curve_type: RefCell::new(CurveType::Linearly),
+            keyframes: RefCell::new(None),
             duration_minutes: Cell::new(0.),
-            items: RefCell::new(Vec::new())
+            items: RefCell::new(Vec::new()),
+            side_effects: Cell::new(AgentSideEffects::default())
         })
     }
 }
 
 impl Health {
-    pub(crate) fn new() -> Self {
+    // Deprecated public collections are still shared with the new accessors
+    #[allow(deprecated)]
+    pub(crate) fn new(traits: Rc<TraitRegistry>, body_parts: Rc<BodyPartsRegistry>) -> Self {
         let healthy = HealthC::healthy();
+        let diseases = Arc::new(RefCell::new(BTreeMap::new()));
+        let injuries = Arc::new(RefCell::new(BTreeMap::new()));
 
         Health {
             disease_monitors: Rc::new(RefCell::new(HashMap::new())),
             side_effects: Rc::new(RefCell::new(HashMap::new())),
-            diseases: Arc::new(RefCell::new(HashMap::new())),
-            injuries: Arc::new(RefCell::new(HashMap::new())),
+            disease_monitor_priorities: MonitorPriorities::default(),
+            side_effect_priorities: MonitorPriorities::default(),
+            monitor_packs: RefCell::new(HashMap::new()),
+            disabled_side_effects: RefCell::new(HashSet::new()),
+            diseases: diseases.clone(),
+            injuries: injuries.clone(),
+            active_diseases: diseases,
+            active_injuries: injuries,
+            conditions: Arc::new(RefCell::new(BTreeMap::new())),
             stamina_regain_rate: Cell::new(0.1),
             blood_regain_rate: Cell::new(0.006),
             oxygen_regain_rate: Cell::new(0.05),
             message_queue: RefCell::new(BTreeMap::new()),
             medical_agents: Arc::new(MedicalAgentsMonitor::new()),
+            last_update_game_time: RefCell::new(GameTimeC::empty()),
+            last_side_effects: RefCell::new(SideEffectDeltasC::default()),
 
             // Healthy values by default
             has_blood_loss: Cell::new(false),
+            profile: Cell::new(CharacterProfile::default()),
+            traits,
+            body_parts,
+            heat_exhaustion_factory: RefCell::new(None),
+            tissue_damage_factory: RefCell::new(None),
+            burn_factory: RefCell::new(None),
+            food_poisoning_factory: RefCell::new(None),
+            dirty_water_factory: RefCell::new(None),
+            latent_infections: RefCell::new(HashMap::new()),
+            procedures: RefCell::new(HashMap::new()),
+            active_procedures: RefCell::new(HashMap::new()),
+            disease_factories: RefCell::new(HashMap::new()),
+            injury_factories: RefCell::new(HashMap::new()),
+            bleeding_multipliers: Cell::new(BleedingMultipliers::default()),
+            stamina_recovery: Cell::new(actions::BurstRecovery::default()),
+            oxygen_recovery: Cell::new(actions::BurstRecovery::default()),
+            consumed_heat: Cell::new(0.),
+            digestion: RefCell::new(Vec::new()),
+            nausea_level: Cell::new(0.),
+            vomiting_config: Cell::new(nausea::VomitingConfig::default()),
+            recent_meals: RefCell::new(Vec::new()),
+            hunger_thresholds: Cell::new(appetite::UrgencyThresholds::default()),
+            thirst_thresholds: Cell::new(appetite::UrgencyThresholds::default()),
+            last_hunger_urgency: Cell::new(appetite::Urgency::None),
+            last_thirst_urgency: Cell::new(appetite::Urgency::None),
+            thermal_conditions: Cell::new(None),
+            cold_exposure: Cell::new(0.),
+            heat_exposure: Cell::new(0.),
+            hypothermia_stage: Cell::new(None),
+            hyperthermia_stage: Cell::new(None),
+            nutrition: Cell::new(None),
+            nutrient_levels: Cell::new(NutrientsC::full()),
+            nutrient_deficiencies: RefCell::new(BTreeSet::new()),
+            deficiency_factories: RefCell::new(HashMap::new()),
+            deficiency_diseases: RefCell::new(BTreeSet::new()),
+            toxicity_config: Cell::new(toxicity::ToxicityConfig::default()),
+            toxicity_stage: Cell::new(toxicity::ToxicityStage::Clean),
+            stimulants: RefCell::new(HashMap::new()),
+            fatigue_mask: Cell::new(0.),
+            fatigue_debt: Cell::new(0.),
+            agent_side_effects: Cell::new(AgentSideEffects::default()),
+            pain_thresholds: Cell::new(pain::PainThresholds::default()),
+            action_restrictions: Cell::new(pain::ActionRestrictions::default()),
+            disorientation_level: Cell::new(0.),
+            navigation_aid: Cell::new(0.),
             is_alive: Cell::new(true),
             blood_level: Cell::new(healthy.blood_level),
             body_temperature: Cell::new(healthy.body_temperature),
@@ -225,27 +482,52 @@ impl Health {
             bottom_pressure: Cell::new(healthy.bottom_pressure),
             food_level: Cell::new(healthy.food_level),
             oxygen_level: Cell::new(healthy.oxygen_level),
+            toxicity_level: Cell::new(healthy.toxicity_level),
             water_level: Cell::new(healthy.water_level),
             heart_rate: Cell::new(healthy.heart_rate),
             stamina_level: Cell::new(healthy.stamina_level),
-            fatigue_level: Cell::new(healthy.fatigue_level)
+            fatigue_level: Cell::new(healthy.fatigue_level),
+            is_dirty: Cell::new(false)
         }
     }
 
     /// Called by zara controller when item is consumed as food or water
     pub(crate) fn on_consumed(&self, game_time: &GameTimeC, item: &ConsumableC,
                        inventory_items: &HashMap<String, Box<dyn InventoryItem>>){
-        // Affect water- and food levels
-        self.food_level.set(crate::utils::clamp(self.food_level.get() + item.food_gain, 0., 100.));
+        self.mark_dirty();
+
+        // Affect water- and food levels. Food with digestion time gives its gain gradually.
+        // Sick character does not benefit fully from food
+        let food_gain = item.food_gain * self.appetite_factor_at(game_time);
+        let food_before = self.food_level.get();
+        let is_digested = self.start_digestion(item, food_gain);
+        if !is_digested {
+            self.food_level.set(crate::utils::clamp(food_before + food_gain, 0., 100.));
+        }
         self.water_level.set(crate::utils::clamp(self.water_level.get() + item.water_gain, 0., 100.));
 
+        // Overeating makes character nauseous, recent meals can be thrown up
+        self.on_meal(game_time, item, food_gain, food_before, is_digested);
+
+        // Hot tea warms, icy water chills
+        self.add_consumed_heat(item);
+
+        // Detailed nutrition, if on
+        self.add_consumed_nutrients(item);
+
+        // Toxic or spoiled food poisons
+        self.add_consumed_toxicity(item);
+
         // Notify disease monitors
-        for (_, monitor) in self.disease_monitors.borrow().iter() {
+        for (_, monitor) in self.disease_monitor_priorities.ordered(&self.disease_monitors.borrow()) {
             monitor.on_consumed(self, game_time, item, inventory_items);
         }
 
+        // Count prophylaxis doses of the latent diseases
+        self.on_prophylaxis_taken(game_time, &item.name);
+
         // Notify diseases
-        for (_, disease) in self.diseases.borrow().iter() {
+        for (_, disease) in self.active_diseases.borrow().iter() {
             if disease.is_active(game_time) {
                 disease.on_consumed(game_time, item, inventory_items);
             }
@@ -253,27 +535,32 @@ impl Health {
 
         // Notify medical agents
         for (_, agent) in self.medical_agents.agents.borrow().iter() {
-            agent.on_consumed(game_time, item.name.to_string())
+            agent.on_consumed(game_time, item.name.to_string(), item.potency / 100.)
         }
     }
 
     /// Called by zara controller when appliance item is taken
     pub(crate) fn on_appliance_taken(&self, game_time: &GameTimeC, item: &ApplianceC,
                                      body_part: BodyPart, inventory_items: &HashMap<String, Box<dyn InventoryItem>>){
+        self.mark_dirty();
+
         // Notify disease monitors
-        for (_, monitor) in self.disease_monitors.borrow().iter() {
+        for (_, monitor) in self.disease_monitor_priorities.ordered(&self.disease_monitors.borrow()) {
             monitor.on_appliance_taken(self, game_time, item, body_part, inventory_items);
         }
 
+        // Count prophylaxis doses of the latent diseases
+        self.on_prophylaxis_taken(game_time, &item.name);
+
         // Notify diseases
-        for (_, disease) in self.diseases.borrow().iter() {
+        for (_, disease) in self.active_diseases.borrow().iter() {
             if disease.is_active(game_time) {
                 disease.on_appliance_taken(game_time, item, body_part, inventory_items);
             }
         }
 
         // Notify injuries
-        for (_, injury) in self.injuries.borrow().iter() {
+        for (_, injury) in self.active_injuries.borrow().iter() {
             if injury.is_active(game_time) {
                 injury.on_appliance_taken(game_time, item, body_part, inventory_items);
             }
@@ -286,7 +573,37 @@ impl Health {
     }
 
     /// Sets controller alive state to `false`
-    pub(crate) fn declare_dead(&self) { self.is_alive.set(false); }
+    pub(crate) fn declare_dead(&self) {
+        self.is_alive.set(false);
+        self.mark_dirty();
+    }
+
+    /// Sets controller alive state back to `true`, optionally resetting all vitals to the
+    /// healthy values
+    pub(crate) fn revive(&self, restore_vitals: bool) {
+        self.mark_dirty();
+
+        self.is_alive.set(true);
+
+        if !restore_vitals { return; }
+
+        let healthy = self.baseline();
+
+        self.has_blood_loss.set(false);
+        self.blood_level.set(healthy.blood_level);
+        self.body_temperature.set(healthy.body_temperature);
+        self.top_pressure.set(healthy.top_pressure);
+        self.bottom_pressure.set(healthy.bottom_pressure);
+        self.food_level.set(healthy.food_level);
+        self.oxygen_level.set(healthy.oxygen_level);
+        self.water_level.set(healthy.water_level);
+        self.heart_rate.set(healthy.heart_rate);
+        self.stamina_level.set(healthy.stamina_level);
+        self.fatigue_level.set(healthy.fatigue_level);
+        self.fatigue_debt.set(0.);
+        self.stamina_recovery.set(actions::BurstRecovery::default());
+        self.oxygen_recovery.set(actions::BurstRecovery::default());
+    }
 
     /// Removes all diseases.
     ///
@@ -298,7 +615,23 @@ impl Health {
     /// ## Notes
     /// Borrows `diseases` collection
     pub fn clear_diseases(&self) {
-        self.diseases.borrow_mut().clear();
+        self.mark_dirty();
+
+        let removed = std::mem::take(&mut *self.active_diseases.borrow_mut());
+
+        self.latent_infections.borrow_mut().clear();
+
+        for (name, disease) in removed {
+            self.queue_message(Event::DiseaseRemoved(name, RemovalReason::Cleared, disease.worst_level_reached()));
+        }
+    }
+
+    /// Removes all diseases and latent infections without sending any events
+    pub(crate) fn reset_diseases(&self) {
+        self.mark_dirty();
+
+        self.active_diseases.borrow_mut().clear();
+        self.latent_infections.borrow_mut().clear();
     }
 
     /// Removes all injuries.
@@ -311,7 +644,23 @@ impl Health {
     /// ## Notes
     /// Borrows `injuries` collection
     pub fn clear_injuries(&self) {
-        self.injuries.borrow_mut().clear();
+        self.mark_dirty();
+
+        let removed = std::mem::take(&mut *self.active_injuries.borrow_mut());
+
+        self.active_procedures.borrow_mut().clear();
+
+        for (key, injury) in removed {
+            self.queue_message(Event::InjuryRemoved(key.injury, key.body_part, RemovalReason::Cleared, injury.worst_level_reached()));
+        }
+    }
+
+    /// Removes all injuries and active procedures without sending any events
+    pub(crate) fn reset_injuries(&self) {
+        self.mark_dirty();
+
+        self.active_injuries.borrow_mut().clear();
+        self.active_procedures.borrow_mut().clear();
     }
 }
 
@@ -319,6 +668,8 @@ impl MessageQueue for Health {
     fn has_messages(&self) -> bool { self.message_queue.borrow().len() > 0 }
 
     fn queue_message(&self, message: Event) {
+        self.mark_dirty();
+
         let mut q = self.message_queue.borrow_mut();
         let id = q.len();
 
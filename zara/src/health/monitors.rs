@@ -1,7 +1,7 @@
 use crate::health::Health;
-use crate::health::side::SideEffectsMonitor;
+use crate::health::side::{SideEffectsMonitor, PackHandle};
 use crate::health::disease::DiseaseMonitor;
-use crate::error::UnregisterMonitorErr;
+use crate::error::{UnregisterMonitorErr, MonitorPackErr};
 
 impl Health {
     /// Registers new disease monitor instance
@@ -21,10 +21,32 @@ impl Health {
     /// # Links
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Disease-Monitors) for more info.
     pub fn register_disease_monitor(&self, monitor: Box<dyn DiseaseMonitor>) -> usize {
+        self.register_disease_monitor_with_priority(monitor, 0)
+    }
+
+    /// Registers new disease monitor instance with a given execution priority. Monitors with
+    /// higher priority are checked first; monitors with equal priority are checked in the
+    /// order of registration. [`register_disease_monitor`](#method.register_disease_monitor)
+    /// uses priority `0`
+    ///
+    /// # Parameters
+    /// - `monitor`: an instance of an object that implements
+    ///   [`DiseaseMonitor`](crate::health::disease::DiseaseMonitor) trait
+    /// - `priority`: execution priority
+    ///
+    /// # Returns
+    /// `usize`: unique key of this registered instance
+    ///
+    /// # Examples
+    /// ```
+    /// let mid = person.health.register_disease_monitor_with_priority(boxed_monitor, 10);
+    /// ```
+    pub fn register_disease_monitor_with_priority(&self, monitor: Box<dyn DiseaseMonitor>, priority: i32) -> usize {
         let mut b = self.disease_monitors.borrow_mut();
-        let key = b.keys().max().unwrap_or(&0) + 1;
+        let key = self.disease_monitor_priorities.next_key();
 
         b.insert(key, monitor);
+        self.disease_monitor_priorities.set(key, priority);
 
         key
     }
@@ -52,11 +74,13 @@ impl Health {
         }
 
         b.remove(&key);
+        self.disease_monitor_priorities.remove(key);
 
         Ok(())
     }
 
-    /// Registers new side effects monitor instance
+    /// Registers new side effects monitor instance. Monitor states are restored by their keys,
+    /// so monitors must be registered in the same order before the health state is restored
     ///
     /// # Parameters
     /// - `monitor`: an instance of an object that implements
@@ -72,10 +96,34 @@ impl Health {
     /// # Links
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Side-effects-Monitors) for more info.
     pub fn register_side_effect_monitor(&self, monitor: Box<dyn SideEffectsMonitor>) -> usize {
+        self.register_side_effect_monitor_with_priority(monitor, 0)
+    }
+
+    /// Registers new side effects monitor instance with a given execution priority. Monitors
+    /// with higher priority are checked first; monitors with equal priority are checked in
+    /// the order of registration. [`register_side_effect_monitor`](#method.register_side_effect_monitor)
+    /// uses priority `0`
+    ///
+    /// # Parameters
+    /// - `monitor`: an instance of an object that implements
+    ///   [`SideEffectsMonitor`](crate::health::side::SideEffectsMonitor) trait
+    /// - `priority`: execution priority
+    ///
+    /// # Returns
+    /// `usize`: unique key of this registered instance
+    ///
+    /// # Examples
+    /// ```
+    /// let mid = person.health.register_side_effect_monitor_with_priority(Box::new(RunningMonitor::new()), 10);
+    /// ```
+    pub fn register_side_effect_monitor_with_priority(&self, monitor: Box<dyn SideEffectsMonitor>, priority: i32) -> usize {
+        monitor.on_registered(self);
+
         let mut b = self.side_effects.borrow_mut();
-        let key = b.keys().max().unwrap_or(&0) + 1;
+        let key = self.side_effect_priorities.next_key();
 
         b.insert(key, monitor);
+        self.side_effect_priorities.set(key, priority);
 
         key
     }
@@ -95,15 +143,135 @@ impl Health {
     /// # Links
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Side-effects-Monitors) for more info.
     pub fn unregister_side_effect_monitor(&self, key: usize) -> Result<(), UnregisterMonitorErr> {
-        let mut b = self.side_effects.borrow_mut();
+        let monitor = match self.side_effects.borrow_mut().remove(&key) {
+            Some(m) => m,
+            None => return Err(UnregisterMonitorErr::MonitorIdNotFound)
+        };
 
-        if !b.contains_key(&key)
-        {
-            return Err(UnregisterMonitorErr::MonitorIdNotFound);
+        self.side_effect_priorities.remove(key);
+        self.disabled_side_effects.borrow_mut().remove(&key);
+        for (_, keys) in self.monitor_packs.borrow_mut().iter_mut() {
+            keys.retain(|k| *k != key);
         }
 
-        b.remove(&key);
+        monitor.on_removed();
 
         Ok(())
     }
+
+    /// Registers a set of side effects monitors that can be enabled, disabled and unregistered
+    /// as one unit, like a "realistic survival" or an "arcade" preset. Pack is enabled
+    ///
+    /// # Parameters
+    /// - `monitors`: monitors of the pack
+    ///
+    /// # Returns
+    /// [`PackHandle`](crate::health::side::PackHandle) of the registered pack
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::side::builtin;
+    ///
+    /// let pack = person.health.register_monitor_pack(vec![
+    ///     Box::new(builtin::RunningSideEffects::new(0.22, 0.009)),
+    ///     Box::new(builtin::FatigueSideEffects::new(18))
+    /// ]);
+    /// ```
+    pub fn register_monitor_pack(&self, monitors: Vec<Box<dyn SideEffectsMonitor>>) -> PackHandle {
+        let keys = monitors.into_iter().map(|m| self.register_side_effect_monitor(m)).collect();
+        let mut b = self.monitor_packs.borrow_mut();
+        let id = b.keys().max().unwrap_or(&0) + 1;
+
+        b.insert(id, keys);
+
+        PackHandle { id }
+    }
+
+    /// Resumes side effects monitors of a pack
+    ///
+    /// # Parameters
+    /// - `pack`: handle given by [`register_monitor_pack`](#method.register_monitor_pack)
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.enable_monitor_pack(&pack);
+    /// ```
+    pub fn enable_monitor_pack(&self, pack: &PackHandle) -> Result<(), MonitorPackErr> {
+        let keys = self.monitor_pack_keys(pack).ok_or(MonitorPackErr::PackNotFound)?;
+        let mut b = self.disabled_side_effects.borrow_mut();
+
+        for key in keys.iter() {
+            b.remove(key);
+        }
+
+        Ok(())
+    }
+
+    /// Pauses side effects monitors of a pack. Disabled monitors are not checked and do not
+    /// affect vitals, but stay registered
+    ///
+    /// # Parameters
+    /// - `pack`: handle given by [`register_monitor_pack`](#method.register_monitor_pack)
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.disable_monitor_pack(&pack);
+    /// ```
+    pub fn disable_monitor_pack(&self, pack: &PackHandle) -> Result<(), MonitorPackErr> {
+        let keys = self.monitor_pack_keys(pack).ok_or(MonitorPackErr::PackNotFound)?;
+
+        self.disabled_side_effects.borrow_mut().extend(keys);
+
+        Ok(())
+    }
+
+    /// Unregisters all side effects monitors of a pack
+    ///
+    /// # Parameters
+    /// - `pack`: handle given by [`register_monitor_pack`](#method.register_monitor_pack)
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.unregister_monitor_pack(&pack);
+    /// ```
+    pub fn unregister_monitor_pack(&self, pack: &PackHandle) -> Result<(), MonitorPackErr> {
+        let keys = self.monitor_packs.borrow_mut().remove(&pack.id).ok_or(MonitorPackErr::PackNotFound)?;
+
+        for key in keys {
+            self.unregister_side_effect_monitor(key).ok();
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if pack monitors are enabled, `None` if pack is not registered
+    ///
+    /// # Parameters
+    /// - `pack`: handle given by [`register_monitor_pack`](#method.register_monitor_pack)
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.health.is_monitor_pack_enabled(&pack);
+    /// ```
+    pub fn is_monitor_pack_enabled(&self, pack: &PackHandle) -> Option<bool> {
+        let keys = self.monitor_pack_keys(pack)?;
+        let b = self.disabled_side_effects.borrow();
+
+        Some(!keys.iter().any(|k| b.contains(k)))
+    }
+
+    /// Returns keys of the side effects monitors of a pack, `None` if pack is not registered
+    ///
+    /// # Parameters
+    /// - `pack`: handle given by [`register_monitor_pack`](#method.register_monitor_pack)
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(keys) = person.health.monitor_pack_keys(&pack) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn monitor_pack_keys(&self, pack: &PackHandle) -> Option<Vec<usize>> {
+        self.monitor_packs.borrow().get(&pack.id).cloned()
+    }
 }
\ No newline at end of file
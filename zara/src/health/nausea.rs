@@ -0,0 +1,209 @@
+use crate::health::Health;
+use crate::inventory::items::ConsumableC;
+use crate::utils::GameTimeC;
+use crate::utils::event::{Event, MessageQueue};
+use crate::state::DirtyTracked;
+
+use std::fmt;
+
+/// Nausea gain when moving to the disease stage nausea level, per game second
+const NAUSEA_GAIN_RATE: f32 = 0.05;
+/// Nausea decay, per game second
+const NAUSEA_DECAY_RATE: f32 = 0.02;
+/// Nausea gained per food point eaten over the full stomach
+const OVEREATING_NAUSEA_PER_POINT: f32 = 2.;
+
+/// Describes how vomiting works
+///
+/// # Examples
+/// ```
+/// use zara::health::nausea::VomitingConfig;
+///
+/// person.health.set_vomiting_config(VomitingConfig {
+///     voided_part: 0.8,
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct VomitingConfig {
+    /// Nausea level (0..100) at which character vomits. 80 by default
+    pub threshold: f32,
+    /// Food and drinks consumed within this many game minutes can be thrown up. 60 by default
+    pub recent_minutes: f32,
+    /// Part of the recent food and water gains that is voided (0..1). 0.5 by default
+    pub voided_part: f32,
+    /// Nausea level (0..100) right after vomiting. 20 by default
+    pub nausea_after: f32
+}
+impl Default for VomitingConfig {
+    fn default() -> Self {
+        VomitingConfig {
+            threshold: 80.,
+            recent_minutes: 60.,
+            voided_part: 0.5,
+            nausea_after: 20.
+        }
+    }
+}
+impl fmt::Display for VomitingConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Vomiting at {:.0} nausea, voids {:.0}% of the last {:.0} minutes",
+               self.threshold, self.voided_part * 100., self.recent_minutes)
+    }
+}
+
+/// Food or drink that was consumed recently and can be thrown up
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RecentMeal {
+    /// Unique name of the consumed item
+    pub(crate) item_name: String,
+    /// Food gain that was applied instantly
+    pub(crate) food_gain: f32,
+    /// Water gain
+    pub(crate) water_gain: f32,
+    /// Game time of consuming, game seconds
+    pub(crate) time: f32
+}
+
+impl Health {
+    /// Returns current nausea level (0..100). Nausea comes from disease stages
+    /// (see [`with_nausea`](crate::health::disease::fluent::StageEnd::with_nausea)), overeating
+    /// and [`add_nausea`](#method.add_nausea) calls. When it reaches the
+    /// [`VomitingConfig`](crate::health::nausea::VomitingConfig) threshold, character vomits
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.health.nausea_level();
+    /// ```
+    pub fn nausea_level(&self) -> f32 { self.nausea_level.get() }
+
+    /// Makes character more nauseous, for example after a spoiled meal or a rough boat ride
+    ///
+    /// # Parameters
+    /// - `amount`: nausea to add (0..100)
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.add_nausea(30.);
+    /// ```
+    pub fn add_nausea(&self, amount: f32) {
+        self.mark_dirty();
+
+        self.nausea_level.set(crate::utils::clamp(self.nausea_level.get() + amount, 0., 100.));
+    }
+
+    /// Sets how vomiting works
+    ///
+    /// # Parameters
+    /// - `config`: vomiting configuration
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.set_vomiting_config(VomitingConfig::default());
+    /// ```
+    pub fn set_vomiting_config(&self, config: VomitingConfig) { self.vomiting_config.set(config); }
+
+    /// Returns vomiting configuration
+    ///
+    /// # Examples
+    /// ```
+    /// let config = person.health.vomiting_config();
+    /// ```
+    pub fn vomiting_config(&self) -> VomitingConfig { self.vomiting_config.get() }
+
+    /// Makes character vomit right now: part of the recent food and water gains is voided,
+    /// food that is being digested is lost, and medical agent doses received from the recent
+    /// consumables are cancelled. `Vomited` event is sent
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.induce_vomiting();
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `medical_agents.agents` collection
+    pub fn induce_vomiting(&self) {
+        self.mark_dirty();
+
+        let game_time = *self.last_update_game_time.borrow();
+
+        self.vomit(&game_time);
+    }
+
+    /// Remembers consumed item and adds nausea from overeating
+    pub(crate) fn on_meal(&self, game_time: &GameTimeC, item: &ConsumableC, food_gain: f32,
+                          food_before: f32, is_digested: bool) {
+        // Digested meal is already counted in the pending food gain
+        let overeaten = food_before + self.pending_food_gain() + if is_digested { 0. } else { food_gain } - 100.;
+
+        if item.is_food && overeaten > 0. {
+            self.add_nausea(f32::min(overeaten, food_gain) * OVEREATING_NAUSEA_PER_POINT);
+        }
+
+        self.recent_meals.borrow_mut().push(RecentMeal {
+            item_name: item.name.to_string(),
+            food_gain: if is_digested { 0. } else { food_gain },
+            water_gain: item.water_gain,
+            time: game_time.as_secs_f32()
+        });
+    }
+
+    /// Moves nausea towards the disease stages level and vomits when needed
+    pub(crate) fn update_nausea(&self, game_time: &GameTimeC, game_time_delta: f32) {
+        let config = self.vomiting_config.get();
+        let since = game_time.as_secs_f32() - config.recent_minutes * 60.;
+
+        self.recent_meals.borrow_mut().retain(|m| m.time >= since);
+
+        let target = self.active_diseases.borrow().values()
+            .filter(|d| d.is_active(game_time))
+            .filter_map(|d| d.get_active_stage(game_time))
+            .map(|s| s.info.nausea)
+            .fold(0., f32::max);
+        let before = self.nausea_level.get();
+        let level = if before < target {
+            f32::min(before + NAUSEA_GAIN_RATE * game_time_delta, target)
+        } else {
+            f32::max(before - NAUSEA_DECAY_RATE * game_time_delta, target)
+        };
+
+        self.nausea_level.set(level);
+
+        // Overeating can push nausea over the threshold between the updates
+        if f32::max(before, level) >= config.threshold {
+            self.vomit(game_time);
+        }
+    }
+
+    fn vomit(&self, game_time: &GameTimeC) {
+        let config = self.vomiting_config.get();
+        let part = crate::utils::clamp_01(config.voided_part);
+        let since = game_time.as_secs_f32() - config.recent_minutes * 60.;
+        let meals: Vec<RecentMeal> = self.recent_meals.borrow_mut().drain(..)
+            .filter(|m| m.time >= since).collect();
+
+        // Stomach is emptied, undigested food is lost
+        let digesting = self.pending_food_gain();
+        self.digestion.borrow_mut().clear();
+
+        let food = meals.iter().fold(0., |a, m| a + m.food_gain) * part;
+        let water = meals.iter().fold(0., |a, m| a + m.water_gain) * part;
+        let (food_before, water_before) = (self.food_level.get(), self.water_level.get());
+
+        self.food_level.set(crate::utils::clamp(food_before - food, 0., 100.));
+        self.water_level.set(crate::utils::clamp(water_before - water, 0., 100.));
+        self.nausea_level.set(f32::min(self.nausea_level.get(), config.nausea_after));
+
+        let items: Vec<String> = meals.iter().map(|m| m.item_name.to_string()).collect();
+        let mut cancelled = Vec::new();
+
+        for (_, agent) in self.medical_agents.agents.borrow().iter() {
+            if agent.cancel_doses(&items, since) {
+                cancelled.push(agent.get_id());
+            }
+        }
+
+        self.queue_message(Event::Vomited(food_before - self.food_level.get() + digesting,
+                                          water_before - self.water_level.get(), cancelled));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,347 @@
+use crate::health::Health;
+use crate::health::disease::Disease;
+use crate::inventory::items::ConsumableC;
+use crate::utils::{clamp, FrameSummaryC};
+use crate::utils::event::{Event, MessageQueue};
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Game seconds in a game day
+const SECONDS_IN_DAY: f32 = 24. * 60. * 60.;
+
+/// Function that creates a disease instance (like scurvy) for a nutrient deficiency
+pub type DeficiencyFactory = Box<dyn Fn() -> Box<dyn Disease>>;
+
+/// Registered deficiency disease of a nutrient
+pub(crate) struct DeficiencyDisease {
+    /// Id of the disease the factory creates
+    disease_id: String,
+    /// Factory that creates the disease
+    factory: DeficiencyFactory
+}
+
+/// Nutrient tracked by the detailed nutrition model
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Nutrient {
+    /// Proteins, mostly from meat, fish and nuts
+    Protein,
+    /// Fats, mostly from meat and fish
+    Fat,
+    /// Carbohydrates, mostly from grains, berries and fruits
+    Carbs,
+    /// Vitamin C, lack of it leads to scurvy
+    VitaminC
+}
+impl Nutrient {
+    /// All nutrients, in order
+    pub const ALL: [Nutrient; 4] = [Nutrient::Protein, Nutrient::Fat, Nutrient::Carbs, Nutrient::VitaminC];
+}
+impl fmt::Display for Nutrient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Nutrient::Protein => write!(f, "Protein"),
+            Nutrient::Fat => write!(f, "Fat"),
+            Nutrient::Carbs => write!(f, "Carbs"),
+            Nutrient::VitaminC => write!(f, "Vitamin C")
+        }
+    }
+}
+
+/// Describes nutrients contract: content of a consumable dose, per-nutrient levels or drain
+/// per game day (0..100 scale, where 100 is a full store of the nutrient)
+#[derive(Copy, Clone, Debug, Default)]
+pub struct NutrientsC {
+    /// Protein points
+    pub protein: f32,
+    /// Fat points
+    pub fat: f32,
+    /// Carbohydrates points
+    pub carbs: f32,
+    /// Vitamin C points
+    pub vitamin_c: f32
+}
+impl NutrientsC {
+    /// Creates a new instance of `NutrientsC` with all nutrients at 0
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::nutrition::NutrientsC;
+    ///
+    /// let o = NutrientsC::new();
+    /// ```
+    pub fn new() -> Self { NutrientsC::default() }
+
+    /// Creates a new instance of `NutrientsC` with all nutrients at 100
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::nutrition::NutrientsC;
+    ///
+    /// let o = NutrientsC::full();
+    /// ```
+    pub fn full() -> Self {
+        NutrientsC { protein: 100., fat: 100., carbs: 100., vitamin_c: 100. }
+    }
+
+    /// Returns value of the given nutrient
+    ///
+    /// # Parameters
+    /// - `nutrient`: nutrient to get
+    ///
+    /// # Examples
+    /// ```
+    /// let value = levels.get(Nutrient::VitaminC);
+    /// ```
+    pub fn get(&self, nutrient: Nutrient) -> f32 {
+        match nutrient {
+            Nutrient::Protein => self.protein,
+            Nutrient::Fat => self.fat,
+            Nutrient::Carbs => self.carbs,
+            Nutrient::VitaminC => self.vitamin_c
+        }
+    }
+
+    /// Returns a copy with every nutrient changed by the given function
+    fn map<F: Fn(Nutrient, f32) -> f32>(&self, f: F) -> Self {
+        NutrientsC {
+            protein: f(Nutrient::Protein, self.protein),
+            fat: f(Nutrient::Fat, self.fat),
+            carbs: f(Nutrient::Carbs, self.carbs),
+            vitamin_c: f(Nutrient::VitaminC, self.vitamin_c)
+        }
+    }
+}
+impl fmt::Display for NutrientsC {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Protein {:.1}, fat {:.1}, carbs {:.1}, vitamin C {:.1}", self.protein, self.fat,
+               self.carbs, self.vitamin_c)
+    }
+}
+impl Eq for NutrientsC { }
+impl PartialEq for NutrientsC {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        f32::abs(self.protein - other.protein) < EPS &&
+        f32::abs(self.fat - other.fat) < EPS &&
+        f32::abs(self.carbs - other.carbs) < EPS &&
+        f32::abs(self.vitamin_c - other.vitamin_c) < EPS
+    }
+}
+impl Hash for NutrientsC {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u32((self.protein*10_000_f32) as u32);
+        state.write_u32((self.fat*10_000_f32) as u32);
+        state.write_u32((self.carbs*10_000_f32) as u32);
+        state.write_u32((self.vitamin_c*10_000_f32) as u32);
+    }
+}
+
+/// How per-nutrient levels drain and when a deficiency starts. Detailed nutrition is off
+/// until set with [`set_nutrition`](crate::health::Health::set_nutrition)
+///
+/// # Examples
+/// ```
+/// use zara::health::nutrition::{NutritionConfig, NutrientsC};
+///
+/// person.health.set_nutrition(Some(NutritionConfig {
+///     drain_per_day: NutrientsC { vitamin_c: 5., ..NutritionConfig::default().drain_per_day },
+///     ..Default::default()
+/// }));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NutritionConfig {
+    /// How many points of every nutrient are spent per game day
+    pub drain_per_day: NutrientsC,
+    /// Nutrient level below which a deficiency starts
+    pub deficiency_threshold: f32,
+    /// Nutrient level above which a deficiency ends
+    pub recovery_threshold: f32
+}
+impl Default for NutritionConfig {
+    fn default() -> Self {
+        NutritionConfig {
+            drain_per_day: NutrientsC { protein: 40., fat: 30., carbs: 50., vitamin_c: 8. },
+            deficiency_threshold: 20.,
+            recovery_threshold: 35.
+        }
+    }
+}
+impl fmt::Display for NutritionConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Drain per day: {}, deficiency below {:.1}, recovery above {:.1}", self.drain_per_day,
+               self.deficiency_threshold, self.recovery_threshold)
+    }
+}
+
+impl Health {
+    /// Turns on the detailed nutrition model. Per-nutrient levels drain over game time and are
+    /// restored by consumables that report their [`nutrients`](crate::inventory::items::ConsumableDescription::nutrients).
+    /// [`NutrientDeficiency`](crate::utils::event::Event::NutrientDeficiency) and
+    /// [`NutrientDeficiencyEnded`](crate::utils::event::Event::NutrientDeficiencyEnded) events
+    /// are sent. Food level still comes from the food gain. Pass `None` to turn it off
+    ///
+    /// # Parameters
+    /// - `config`: nutrition config
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::nutrition::NutritionConfig;
+    ///
+    /// person.health.set_nutrition(Some(NutritionConfig::default()));
+    /// ```
+    pub fn set_nutrition(&self, config: Option<NutritionConfig>) {
+        self.nutrition.set(config);
+    }
+
+    /// Returns nutrition config, `None` if detailed nutrition is off
+    ///
+    /// # Examples
+    /// ```
+    /// let config = person.health.nutrition();
+    /// ```
+    pub fn nutrition(&self) -> Option<NutritionConfig> { self.nutrition.get() }
+
+    /// Sets a factory for the disease that is spawned when a deficiency of the given nutrient
+    /// starts. The disease goes back stage by stage once the deficiency ends. Pass `None` to
+    /// spawn nothing for this nutrient
+    ///
+    /// Factory is called once here to get the disease id. Only the disease spawned by the
+    /// deficiency is driven by it: the same disease spawned by the game is left alone
+    ///
+    /// # Parameters
+    /// - `nutrient`: nutrient to set the factory for
+    /// - `factory`: function that creates the deficiency disease
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.set_deficiency_factory(Nutrient::VitaminC, Some(Box::new(|| Box::new(Scurvy))));
+    /// ```
+    pub fn set_deficiency_factory(&self, nutrient: Nutrient, factory: Option<DeficiencyFactory>) {
+        let mut factories = self.deficiency_factories.borrow_mut();
+
+        match factory {
+            Some(f) => {
+                let disease_id = f().get_id();
+
+                factories.insert(nutrient, DeficiencyDisease { disease_id, factory: f });
+            },
+            None => { factories.remove(&nutrient); }
+        }
+    }
+
+    /// Returns per-nutrient levels (0..100)
+    ///
+    /// # Examples
+    /// ```
+    /// let levels = person.health.nutrient_levels();
+    /// ```
+    pub fn nutrient_levels(&self) -> NutrientsC { self.nutrient_levels.get() }
+
+    /// Returns level of the given nutrient (0..100)
+    ///
+    /// # Parameters
+    /// - `nutrient`: nutrient to check
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.health.nutrient_level(Nutrient::Protein);
+    /// ```
+    pub fn nutrient_level(&self, nutrient: Nutrient) -> f32 { self.nutrient_levels.get().get(nutrient) }
+
+    /// Is character deficient in the given nutrient as of the last `update` call
+    ///
+    /// # Parameters
+    /// - `nutrient`: nutrient to check
+    ///
+    /// # Examples
+    /// ```
+    /// if person.health.is_deficient(Nutrient::VitaminC) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn is_deficient(&self, nutrient: Nutrient) -> bool {
+        self.nutrient_deficiencies.borrow().contains(&nutrient)
+    }
+
+    /// Adds nutrients of the consumed item
+    pub(crate) fn add_consumed_nutrients(&self, item: &ConsumableC) {
+        if self.nutrition.get().is_none() { return; }
+
+        let gain = match item.nutrients {
+            Some(n) => n,
+            None => return
+        };
+        let count = item.consumed_count as f32;
+
+        self.nutrient_levels.set(self.nutrient_levels.get()
+            .map(|n, value| clamp(value + gain.get(n) * count, 0., 100.)));
+    }
+
+    /// Drains nutrient levels, starts and ends deficiencies and their diseases
+    pub(crate) fn check_nutrition(&self, frame_data: &FrameSummaryC) {
+        let config = match self.nutrition.get() {
+            Some(c) => c,
+            None => return
+        };
+        let days = frame_data.game_time_delta / SECONDS_IN_DAY;
+        let levels = self.nutrient_levels.get()
+            .map(|n, value| clamp(value - config.drain_per_day.get(n) * days, 0., 100.));
+
+        self.nutrient_levels.set(levels);
+
+        for nutrient in Nutrient::ALL.iter() {
+            let level = levels.get(*nutrient);
+            let was_deficient = self.is_deficient(*nutrient);
+
+            if !was_deficient && level < config.deficiency_threshold {
+                self.nutrient_deficiencies.borrow_mut().insert(*nutrient);
+                self.queue_message(Event::NutrientDeficiency(*nutrient));
+            } else if was_deficient && level > config.recovery_threshold {
+                self.nutrient_deficiencies.borrow_mut().remove(nutrient);
+                self.queue_message(Event::NutrientDeficiencyEnded(*nutrient));
+            }
+
+            self.check_deficiency_disease(*nutrient, frame_data);
+        }
+    }
+
+    fn check_deficiency_disease(&self, nutrient: Nutrient, frame_data: &FrameSummaryC) {
+        let disease_id = match self.deficiency_factories.borrow().get(&nutrient) {
+            Some(d) => d.disease_id.to_string(),
+            None => return
+        };
+        let is_deficient = self.is_deficient(nutrient);
+        let is_spawned = self.deficiency_diseases.borrow().contains(&nutrient);
+        let active = self.active_diseases.borrow().get(&disease_id).cloned();
+
+        match active {
+            Some(d) if is_spawned => {
+                // Stages go forward while deficient and back when not
+                if is_deficient && d.is_healing() {
+                    d.invert_back(&frame_data.game_time).ok();
+                } else if !is_deficient && !d.is_healing() {
+                    d.invert(&frame_data.game_time).ok();
+                }
+            },
+            // Spawned by the game, not by the deficiency
+            Some(_) => { },
+            None => {
+                // Disease spawned by the deficiency is over
+                self.deficiency_diseases.borrow_mut().remove(&nutrient);
+
+                if !is_deficient { return; }
+
+                let disease = match self.deficiency_factories.borrow().get(&nutrient) {
+                    Some(d) => (d.factory)(),
+                    None => return
+                };
+
+                if self.spawn_disease(disease, frame_data.game_time).is_ok() {
+                    self.deficiency_diseases.borrow_mut().insert(nutrient);
+                }
+            }
+        }
+    }
+}
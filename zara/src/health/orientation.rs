@@ -0,0 +1,55 @@
+use crate::health::Health;
+
+/// Fatigue level above which character starts to lose orientation
+const FATIGUE_DISORIENTATION_START: f32 = 50.;
+/// Body temperature above which fever makes character lose orientation
+const FEVER_DISORIENTATION_START: f32 = 37.5;
+/// Body temperature at which fever disorientation is the strongest
+const FEVER_DISORIENTATION_MAX: f32 = 40.;
+/// Water level below which dehydration makes character lose orientation
+const DEHYDRATION_DISORIENTATION_START: f32 = 50.;
+/// Most disorientation (0..100) each of the causes can give
+const MAX_DISORIENTATION_PER_CAUSE: f32 = 40.;
+
+impl Health {
+    /// Returns how disoriented character is (0..100), as of the last `update` call, before any
+    /// navigation aids are applied. Comes from fatigue, fever and dehydration
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.health.disorientation_level();
+    /// ```
+    pub fn disorientation_level(&self) -> f32 { self.disorientation_level.get() }
+
+    /// Returns how well character keeps orientation (0..1), as of the last `update` call.
+    /// `1` means no vertigo or disorientation. Carried navigation aids (see
+    /// [`NavigationDescription`](crate::inventory::items::NavigationDescription)) negate
+    /// the disorientation. Use this to scale difficulty of the navigation
+    ///
+    /// # Examples
+    /// ```
+    /// let drift = max_compass_drift * (1. - person.health.orientation_factor());
+    /// ```
+    pub fn orientation_factor(&self) -> f32 {
+        let negation = crate::utils::clamp_01(self.navigation_aid.get() / 100.);
+        let disorientation = crate::utils::clamp_01(self.disorientation_level.get() / 100.);
+
+        1. - disorientation * (1. - negation)
+    }
+
+    /// Recalculates disorientation level and remembers navigation aid of the carried items
+    ///
+    /// # Parameters
+    /// - `navigation_aid`: how much of the disorientation carried items negate (0..100)
+    pub(crate) fn update_orientation(&self, navigation_aid: f32) {
+        let part = |value: f32, start: f32, max: f32|
+            crate::utils::clamp_01((value - start) / (max - start)) * MAX_DISORIENTATION_PER_CAUSE;
+
+        let fatigue = part(self.fatigue_level(), FATIGUE_DISORIENTATION_START, 100.);
+        let fever = part(self.body_temperature.get(), FEVER_DISORIENTATION_START, FEVER_DISORIENTATION_MAX);
+        let dehydration = part(-self.water_level.get(), -DEHYDRATION_DISORIENTATION_START, 0.);
+
+        self.disorientation_level.set(crate::utils::clamp(fatigue + fever + dehydration, 0., 100.));
+        self.navigation_aid.set(crate::utils::clamp(navigation_aid, 0., 100.));
+    }
+}
@@ -0,0 +1,179 @@
+use crate::health::{Health, StageLevel};
+use crate::health::condition::ConditionRestriction;
+use crate::utils::GameTimeC;
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Pain levels (0..100) at which actions are blocked. `None` never blocks an action
+///
+/// # Examples
+/// ```
+/// use zara::health::pain::PainThresholds;
+///
+/// person.health.set_pain_thresholds(PainThresholds {
+///     steady_aim: None,
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct PainThresholds {
+    /// Pain level at which character cannot aim steady. 30 by default
+    pub steady_aim: Option<f32>,
+    /// Pain level at which character cannot sprint. 60 by default
+    pub sprint: Option<f32>
+}
+impl Default for PainThresholds {
+    fn default() -> Self {
+        PainThresholds {
+            steady_aim: Some(30.),
+            sprint: Some(60.)
+        }
+    }
+}
+impl fmt::Display for PainThresholds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Steady aim {:?}, sprint {:?}", self.steady_aim, self.sprint)
+    }
+}
+impl Eq for PainThresholds { }
+impl PartialEq for PainThresholds {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        let same = |a: Option<f32>, b: Option<f32>| match (a, b) {
+            (Some(a), Some(b)) => f32::abs(a - b) < EPS,
+            (None, None) => true,
+            _ => false
+        };
+
+        same(self.steady_aim, other.steady_aim) && same(self.sprint, other.sprint)
+    }
+}
+impl Hash for PainThresholds {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.steady_aim.map(|x| (x*10_000_f32) as u32).hash(state);
+        self.sprint.map(|x| (x*10_000_f32) as u32).hash(state);
+    }
+}
+
+/// Actions character cannot do right now, as of the last `update` call. Combines pain (eased by
+/// the painkilling medical agents) with the restrictions of the long-term conditions. Zara
+/// does not enforce them, game should
+///
+/// # Examples
+/// ```
+/// if person.health.action_restrictions().no_sprinting {
+///     // Stop the sprint
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ActionRestrictions {
+    /// Pain level (0..100) the restrictions were calculated with
+    pub pain_level: f32,
+    /// Character cannot aim steady
+    pub no_steady_aim: bool,
+    /// Character cannot sprint or run
+    pub no_sprinting: bool,
+    /// Character cannot swim
+    pub no_swimming: bool
+}
+impl fmt::Display for ActionRestrictions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Pain {:.0}: no steady aim {}, no sprinting {}, no swimming {}",
+               self.pain_level, self.no_steady_aim, self.no_sprinting, self.no_swimming)
+    }
+}
+impl Eq for ActionRestrictions { }
+impl PartialEq for ActionRestrictions {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.no_steady_aim == other.no_steady_aim &&
+        self.no_sprinting == other.no_sprinting &&
+        self.no_swimming == other.no_swimming &&
+        f32::abs(self.pain_level - other.pain_level) < EPS
+    }
+}
+impl Hash for ActionRestrictions {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.no_steady_aim.hash(state);
+        self.no_sprinting.hash(state);
+        self.no_swimming.hash(state);
+
+        state.write_u32((self.pain_level*10_000_f32) as u32);
+    }
+}
+
+/// Pain (0..100) an active injury stage causes
+fn stage_pain(level: StageLevel) -> f32 {
+    match level {
+        StageLevel::InitialStage => 15.,
+        StageLevel::Progressing => 30.,
+        StageLevel::Worrying => 50.,
+        StageLevel::Critical => 75.,
+        StageLevel::Undefined => 0.
+    }
+}
+
+impl Health {
+    /// Returns current pain level (0..100) as of the last `update` call. Pain comes from the
+    /// active injuries and is eased by the painkilling medical agents
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.health.pain_level();
+    /// ```
+    pub fn pain_level(&self) -> f32 { self.action_restrictions.get().pain_level }
+
+    /// Returns actions character cannot do right now, as of the last `update` call
+    ///
+    /// # Examples
+    /// ```
+    /// let can_aim = !person.health.action_restrictions().no_steady_aim;
+    /// ```
+    pub fn action_restrictions(&self) -> ActionRestrictions { self.action_restrictions.get() }
+
+    /// Sets pain levels at which actions are blocked
+    ///
+    /// # Parameters
+    /// - `thresholds`: pain level thresholds
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.set_pain_thresholds(PainThresholds::default());
+    /// ```
+    pub fn set_pain_thresholds(&self, thresholds: PainThresholds) { self.pain_thresholds.set(thresholds); }
+
+    /// Returns pain levels at which actions are blocked
+    ///
+    /// # Examples
+    /// ```
+    /// let thresholds = person.health.pain_thresholds();
+    /// ```
+    pub fn pain_thresholds(&self) -> PainThresholds { self.pain_thresholds.get() }
+
+    /// Recalculates pain and action restrictions
+    pub(crate) fn update_action_restrictions(&self, game_time: &GameTimeC) {
+        let mut pain = 0.;
+
+        for (_, injury) in self.active_injuries.borrow().iter() {
+            if let Some(level) = injury.active_level(game_time) {
+                // Cooled and dressed burns hurt less
+                pain += stage_pain(level) * (1. - crate::utils::clamp_01(injury.burn_relief()));
+            }
+        }
+
+        let pain = crate::utils::clamp(pain - self.agent_side_effects.get().pain_relief, 0., 100.);
+        let thresholds = self.pain_thresholds.get();
+        let conditions = self.restrictions();
+        let blocks = |threshold: Option<f32>| threshold.is_some_and(|t| pain >= t);
+
+        self.action_restrictions.set(ActionRestrictions {
+            pain_level: pain,
+            no_steady_aim: blocks(thresholds.steady_aim),
+            no_sprinting: blocks(thresholds.sprint) || conditions.contains(&ConditionRestriction::NoRunning),
+            no_swimming: conditions.contains(&ConditionRestriction::NoSwimming)
+        });
+    }
+}
@@ -0,0 +1,98 @@
+use crate::health::Health;
+use crate::health::disease::Disease;
+use crate::inventory::items::ConsumableC;
+use crate::utils::{GameTimeC, roll_dice};
+use crate::utils::event::{Event, MessageQueue};
+
+/// Water contamination (0..100) above which drinking can cause a disease
+pub const DIRTY_WATER_THRESHOLD: f32 = 20.;
+/// Water contamination (0..100) at which the water is visibly bad (murky or smelly)
+pub const VISIBLY_BAD_WATER: f32 = 60.;
+/// Chance of getting sick (0..100) after drinking fully contaminated water
+const DIRTY_WATER_DISEASE_CHANCE: f32 = 50.;
+
+/// Function that creates a food poisoning disease instance for a given consumed item
+pub type FoodPoisoningFactory = Box<dyn Fn(&ConsumableC) -> Box<dyn Disease>>;
+/// Function that creates a disease instance (like parasites or diarrhea) for a given
+/// contaminated water item
+pub type DirtyWaterFactory = Box<dyn Fn(&ConsumableC) -> Box<dyn Disease>>;
+
+impl Health {
+    /// Sets a factory for the disease that is spawned when eating a spoiling item or taking an
+    /// expired one fails its poisoning roll. Pass `None` to disable food poisoning
+    ///
+    /// # Parameters
+    /// - `factory`: function that creates food poisoning disease for a given consumed item
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.set_food_poisoning_factory(Some(Box::new(|_| Box::new(FoodPoisoning))));
+    /// ```
+    pub fn set_food_poisoning_factory(&self, factory: Option<FoodPoisoningFactory>) {
+        self.food_poisoning_factory.replace(factory);
+    }
+
+    /// Sets a factory for the disease that is spawned when drinking water contaminated above
+    /// [`DIRTY_WATER_THRESHOLD`] fails its roll. The chance grows with contamination. Pass `None`
+    /// to make contaminated water harmless
+    ///
+    /// # Parameters
+    /// - `factory`: function that creates a disease for a given contaminated water item
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.set_dirty_water_factory(Some(Box::new(|item| {
+    ///     if item.contamination > 80. { Box::new(Parasites) } else { Box::new(Diarrhea) }
+    /// })));
+    /// ```
+    pub fn set_dirty_water_factory(&self, factory: Option<DirtyWaterFactory>) {
+        self.dirty_water_factory.replace(factory);
+    }
+
+    /// Rolls the poisoning chance of a consumed item and spawns food poisoning on failure
+    pub(crate) fn roll_food_poisoning(&self, game_time: &GameTimeC, item: &ConsumableC) {
+        let disease = {
+            let factory = self.food_poisoning_factory.borrow();
+            let factory = match factory.as_ref() {
+                Some(f) => f,
+                None => return
+            };
+
+            if !roll_dice(item.poisoning_chance()) { return; }
+
+            factory(item)
+        };
+
+        // Does nothing if character is already poisoned
+        if let Ok(name) = self.spawn_disease(disease, *game_time) {
+            self.queue_message(Event::FoodPoisoned(item.name.to_string(), name));
+        }
+    }
+
+    /// Warns about visibly bad water and rolls the contaminated water disease chance
+    pub(crate) fn roll_dirty_water(&self, game_time: &GameTimeC, item: &ConsumableC) {
+        if !item.is_water || item.contamination <= DIRTY_WATER_THRESHOLD { return; }
+
+        if item.contamination >= VISIBLY_BAD_WATER {
+            self.queue_message(Event::BadWaterDrunk(item.name.to_string(), item.contamination.round() as usize));
+        }
+
+        let disease = {
+            let factory = self.dirty_water_factory.borrow();
+            let factory = match factory.as_ref() {
+                Some(f) => f,
+                None => return
+            };
+
+            let level = (item.contamination - DIRTY_WATER_THRESHOLD) / (100. - DIRTY_WATER_THRESHOLD);
+            let chance = level * DIRTY_WATER_DISEASE_CHANCE * self.profile().disease_susceptibility();
+
+            if !roll_dice(usize::min(100, chance.round() as usize)) { return; }
+
+            factory(item)
+        };
+
+        // Does nothing if character already has this disease
+        self.spawn_disease(disease, *game_time).ok();
+    }
+}
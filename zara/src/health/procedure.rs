@@ -0,0 +1,421 @@
+use crate::health::{Health, InjuryKey};
+use crate::health::disease::Disease;
+use crate::body::BodyPart;
+use crate::utils::{GameTimeC, roll_dice};
+use crate::utils::event::{Event, MessageQueue};
+use crate::error::ProcedureStepErr;
+use crate::state::DirtyTracked;
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Name of the custom skill modifier (see [`SkillEffect::Custom`](crate::skills::SkillEffect::Custom))
+/// that scales failure chances of the procedure steps. `-0.1` per level means 10% less chance
+/// to fail per level
+pub const PROCEDURE_FAILURE_MODIFIER: &str = "ProcedureFailure";
+
+/// Function that creates a disease caused by a failed procedure step, like a wound infection
+pub type ProcedureDiseaseFactory = Box<dyn Fn() -> Box<dyn Disease>>;
+
+/// What happens when a procedure step fails. Failed step must be performed again
+pub enum StepFailure {
+    /// Step time and items are just wasted
+    Wasted,
+    /// Character loses some blood (0..100)
+    BloodLoss(f32),
+    /// Character gets a disease
+    Disease(ProcedureDiseaseFactory)
+}
+
+/// Describes one step of a [`Procedure`]
+pub struct ProcedureStep {
+    /// Name of the step, like "Stitch"
+    pub name: String,
+    /// Unique names of the inventory items one of each is consumed when the step starts
+    pub items: Vec<String>,
+    /// How long the step takes, game minutes
+    pub minutes: f32,
+    /// Chance of the step failure (0..100) without skill modifiers
+    pub failure_chance: usize,
+    /// What happens when the step fails
+    pub on_failure: StepFailure
+}
+impl ProcedureStep {
+    /// Creates a step that consumes nothing and never fails
+    ///
+    /// # Parameters
+    /// - `name`: name of the step
+    /// - `minutes`: how long the step takes, game minutes
+    pub fn new(name: &str, minutes: f32) -> Self {
+        ProcedureStep {
+            name: name.to_string(),
+            items: Vec::new(),
+            minutes: f32::max(minutes, 0.),
+            failure_chance: 0,
+            on_failure: StepFailure::Wasted
+        }
+    }
+
+    /// Sets inventory items the step consumes (one of each)
+    ///
+    /// # Parameters
+    /// - `items`: unique names of the items
+    pub fn consumes(mut self, items: Vec<&str>) -> Self {
+        self.items = items.iter().map(|x| x.to_string()).collect();
+        self
+    }
+
+    /// Sets failure chance of the step and its consequence
+    ///
+    /// # Parameters
+    /// - `chance`: chance of failure (0..100) without skill modifiers
+    /// - `consequence`: what happens on failure
+    pub fn may_fail(mut self, chance: usize, consequence: StepFailure) -> Self {
+        self.failure_chance = usize::min(chance, 100);
+        self.on_failure = consequence;
+        self
+    }
+}
+
+/// Describes a multi-step procedure performed on an injury, like field surgery
+/// (clean → stitch → bandage). Every step consumes items, takes game time and can fail.
+/// When the last step succeeds, injury stops bleeding and starts healing
+///
+/// # Examples
+/// ```
+/// use zara::health::procedure::{Procedure, ProcedureStep, StepFailure};
+///
+/// let o = Procedure::new("Field Surgery", vec![
+///     ProcedureStep::new("Clean", 10.).consumes(vec!["Antiseptic"]),
+///     ProcedureStep::new("Stitch", 30.).consumes(vec!["Suture Kit"])
+///         .may_fail(25, StepFailure::BloodLoss(5.)),
+///     ProcedureStep::new("Bandage", 5.).consumes(vec!["Bandage"])
+/// ]);
+/// ```
+pub struct Procedure {
+    /// Unique name of the procedure
+    pub name: String,
+    /// Procedure steps, in order
+    pub steps: Vec<ProcedureStep>
+}
+impl Procedure {
+    /// Creates a new procedure
+    ///
+    /// # Parameters
+    /// - `name`: unique name of the procedure
+    /// - `steps`: procedure steps, in order
+    pub fn new(name: &str, steps: Vec<ProcedureStep>) -> Self {
+        Procedure { name: name.to_string(), steps }
+    }
+}
+
+/// Procedure that was started on an injury and is not finished yet
+#[derive(Clone, Debug, Default)]
+pub struct ActiveProcedure {
+    /// Unique name of the procedure
+    pub procedure_name: String,
+    /// Unique name of the injury the procedure is performed on
+    pub injury_name: String,
+    /// Body part of the injury
+    pub body_part: BodyPart,
+    /// Index of the current step
+    pub step: usize,
+    /// How many steps the procedure has
+    pub steps_count: usize,
+    /// Game time in seconds when the current step ends. `None` if the step is not started
+    pub step_end_time: Option<f32>,
+    /// Failure chance (0..100) of the step in progress, with skill modifiers applied
+    pub failure_chance: usize
+}
+impl ActiveProcedure {
+    /// Returns `true` if the current step is being performed
+    ///
+    /// # Examples
+    /// ```
+    /// if !procedure.is_step_in_progress() {
+    ///     // Offer the next step
+    /// }
+    /// ```
+    pub fn is_step_in_progress(&self) -> bool { self.step_end_time.is_some() }
+
+    /// Returns procedure progress (0..1), by finished steps
+    ///
+    /// # Examples
+    /// ```
+    /// let progress = procedure.progress();
+    /// ```
+    pub fn progress(&self) -> f32 {
+        if self.steps_count == 0 { return 1.; }
+
+        f32::min(self.step as f32 / self.steps_count as f32, 1.)
+    }
+}
+impl fmt::Display for ActiveProcedure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} on {} ({}), step {}/{}", self.procedure_name, self.injury_name, self.body_part,
+               self.step + 1, self.steps_count)
+    }
+}
+impl Eq for ActiveProcedure { }
+impl PartialEq for ActiveProcedure {
+    fn eq(&self, other: &Self) -> bool {
+        self.procedure_name == other.procedure_name &&
+        self.injury_name == other.injury_name &&
+        self.body_part == other.body_part &&
+        self.step == other.step &&
+        self.steps_count == other.steps_count &&
+        self.failure_chance == other.failure_chance &&
+        self.step_end_time.map(|t| (t*10_000_f32) as u32) == other.step_end_time.map(|t| (t*10_000_f32) as u32)
+    }
+}
+impl Hash for ActiveProcedure {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.procedure_name.hash(state);
+        self.injury_name.hash(state);
+        self.body_part.hash(state);
+        self.step.hash(state);
+        self.steps_count.hash(state);
+        self.failure_chance.hash(state);
+        self.step_end_time.map(|t| (t*10_000_f32) as u32).hash(state);
+    }
+}
+
+/// What to do with the active procedure after its step ended
+enum StepOutcome {
+    Failed(String),
+    Completed(String),
+    Finished(String)
+}
+
+impl Health {
+    /// Registers procedures that can be performed with
+    /// [`perform_procedure_step`](crate::ZaraController::perform_procedure_step).
+    /// Procedure with the same name is replaced
+    ///
+    /// # Parameters
+    /// - `procedures`: procedures to register
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.register_procedures(vec![field_surgery]);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `procedures` collection
+    pub fn register_procedures(&self, procedures: Vec<Procedure>) {
+        let mut b = self.procedures.borrow_mut();
+
+        for procedure in procedures {
+            b.insert(procedure.name.to_string(), procedure);
+        }
+    }
+
+    /// Returns all started and not finished procedures, sorted by the injury name
+    ///
+    /// # Examples
+    /// ```
+    /// for procedure in person.health.active_procedures() {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `active_procedures` collection
+    pub fn active_procedures(&self) -> Vec<ActiveProcedure> {
+        let mut result: Vec<ActiveProcedure> = self.active_procedures.borrow().values().cloned().collect();
+
+        result.sort_by(|a, b| a.injury_name.cmp(&b.injury_name).then(a.body_part.cmp(&b.body_part)));
+        result
+    }
+
+    /// Returns the procedure started on an injury, if any
+    ///
+    /// # Parameters
+    /// - `injury_name`: unique name of the injury
+    /// - `body_part`: body part of the injury
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(procedure) = person.health.active_procedure(&injury_name, body_part) {
+    ///     let progress = procedure.progress();
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `active_procedures` collection
+    pub fn active_procedure(&self, injury_name: &String, body_part: BodyPart) -> Option<ActiveProcedure> {
+        self.active_procedures.borrow().get(&InjuryKey::new(injury_name.to_string(), body_part)).cloned()
+    }
+
+    /// Cancels the procedure started on an injury. Consumed items are not returned
+    ///
+    /// # Parameters
+    /// - `injury_name`: unique name of the injury
+    /// - `body_part`: body part of the injury
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.cancel_procedure(&injury_name, body_part);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `active_procedures` collection
+    pub fn cancel_procedure(&self, injury_name: &String, body_part: BodyPart) -> Result<(), ProcedureStepErr> {
+        self.mark_dirty();
+
+        match self.active_procedures.borrow_mut().remove(&InjuryKey::new(injury_name.to_string(), body_part)) {
+            Some(_) => Ok(()),
+            None => Err(ProcedureStepErr::ProcedureNotStarted)
+        }
+    }
+
+    /// Checks that the next step of a procedure can be started on an injury and returns items
+    /// it consumes
+    pub(crate) fn next_procedure_step_items(&self, procedure_name: &String, injury_name: &String,
+                                            body_part: BodyPart) -> Result<Vec<String>, ProcedureStepErr> {
+        let key = InjuryKey::new(injury_name.to_string(), body_part);
+
+        if !self.active_injuries.borrow().contains_key(&key) { return Err(ProcedureStepErr::InjuryNotFound); }
+
+        let step = match self.active_procedures.borrow().get(&key) {
+            Some(p) if p.procedure_name != *procedure_name => return Err(ProcedureStepErr::AnotherProcedureInProgress),
+            Some(p) if p.is_step_in_progress() => return Err(ProcedureStepErr::StepInProgress),
+            Some(p) => p.step,
+            None => 0
+        };
+
+        let procedures = self.procedures.borrow();
+        let procedure = match procedures.get(procedure_name) {
+            Some(p) => p,
+            None => return Err(ProcedureStepErr::ProcedureNotFound)
+        };
+
+        match procedure.steps.get(step) {
+            Some(s) => Ok(s.items.clone()),
+            None => Err(ProcedureStepErr::ProcedureNotFound)
+        }
+    }
+
+    /// Starts the next step of a procedure on an injury. Items must be already consumed
+    pub(crate) fn start_procedure_step(&self, procedure_name: &String, injury_name: &String, body_part: BodyPart,
+                                       failure_modifier: f32, game_time: &GameTimeC) {
+        self.mark_dirty();
+
+        let procedures = self.procedures.borrow();
+        let procedure = match procedures.get(procedure_name) {
+            Some(p) => p,
+            None => return
+        };
+        let mut b = self.active_procedures.borrow_mut();
+        let active = b.entry(InjuryKey::new(injury_name.to_string(), body_part))
+            .or_insert_with(|| ActiveProcedure {
+                procedure_name: procedure_name.to_string(),
+                injury_name: injury_name.to_string(),
+                body_part,
+                steps_count: procedure.steps.len(),
+                ..Default::default()
+            });
+        let step = match procedure.steps.get(active.step) {
+            Some(s) => s,
+            None => return
+        };
+
+        active.step_end_time = Some(game_time.as_secs_f32() + step.minutes * 60.);
+        active.failure_chance = usize::min(100,
+            (step.failure_chance as f32 * f32::max(failure_modifier, 0.)).round() as usize);
+
+        self.queue_message(Event::ProcedureStepStarted(procedure_name.to_string(), step.name.to_string()));
+    }
+
+    /// Finishes procedure steps which time has come
+    pub(crate) fn process_procedures(&self, game_time: &GameTimeC) {
+        let gt = game_time.as_secs_f32();
+        let mut outcomes = Vec::new();
+
+        {
+            let injuries = self.active_injuries.borrow();
+            let procedures = self.procedures.borrow();
+            let mut b = self.active_procedures.borrow_mut();
+
+            // Injury could have been healed or removed in between
+            b.retain(|key, _| injuries.contains_key(key));
+
+            for (key, active) in b.iter_mut() {
+                match active.step_end_time {
+                    Some(t) if t <= gt => { },
+                    _ => continue
+                }
+
+                let step = match procedures.get(&active.procedure_name).and_then(|p| p.steps.get(active.step)) {
+                    Some(s) => s,
+                    None => continue
+                };
+
+                active.step_end_time = None;
+
+                if roll_dice(active.failure_chance) {
+                    outcomes.push((key.clone(), active.procedure_name.to_string(), StepOutcome::Failed(step.name.to_string())));
+                    continue;
+                }
+
+                active.step += 1;
+
+                outcomes.push((key.clone(), active.procedure_name.to_string(), if active.step >= active.steps_count {
+                    StepOutcome::Finished(step.name.to_string())
+                } else {
+                    StepOutcome::Completed(step.name.to_string())
+                }));
+            }
+        }
+
+        for (key, procedure_name, outcome) in outcomes {
+            match outcome {
+                StepOutcome::Failed(step_name) => {
+                    self.apply_step_failure(&procedure_name, &step_name, game_time);
+                    self.queue_message(Event::ProcedureStepFailed(procedure_name, step_name));
+                },
+                StepOutcome::Completed(step_name) => {
+                    self.queue_message(Event::ProcedureStepCompleted(procedure_name, step_name));
+                },
+                StepOutcome::Finished(step_name) => {
+                    self.active_procedures.borrow_mut().remove(&key);
+
+                    if let Some(injury) = self.active_injuries.borrow().get(&key) {
+                        injury.stop_blood_loss();
+                        if !injury.is_healing() {
+                            injury.invert(game_time).ok();
+                        }
+                    }
+
+                    self.queue_message(Event::ProcedureStepCompleted(procedure_name.to_string(), step_name));
+                    self.queue_message(Event::ProcedureCompleted(procedure_name, key.injury, key.body_part));
+                }
+            }
+        }
+    }
+
+    fn apply_step_failure(&self, procedure_name: &String, step_name: &String, game_time: &GameTimeC) {
+        let disease = {
+            let procedures = self.procedures.borrow();
+            let step = match procedures.get(procedure_name)
+                .and_then(|p| p.steps.iter().find(|s| s.name == *step_name)) {
+                Some(s) => s,
+                None => return
+            };
+
+            match &step.on_failure {
+                StepFailure::Wasted => None,
+                StepFailure::BloodLoss(value) => {
+                    self.blood_level.set(crate::utils::clamp(self.blood_level.get() - value, 0., 100.));
+                    None
+                },
+                StepFailure::Disease(factory) => Some(factory())
+            }
+        };
+
+        if let Some(disease) = disease {
+            // Does nothing if character already has this disease
+            self.spawn_disease(disease, *game_time).ok();
+        }
+    }
+}
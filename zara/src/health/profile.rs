@@ -0,0 +1,58 @@
+use crate::health::Health;
+use crate::health::side::SideEffectDeltasC;
+use crate::health::disease::DiseaseDeltasC;
+use crate::health::injury::InjuryDeltasC;
+use crate::player::CharacterProfile;
+use crate::utils::HealthC;
+
+impl Health {
+    /// Returns a copy of the character profile that is used in the health math
+    ///
+    /// # Examples
+    /// ```
+    /// let profile = person.health.profile();
+    /// ```
+    pub fn profile(&self) -> CharacterProfile { self.profile.get() }
+
+    pub(crate) fn set_profile(&self, profile: CharacterProfile) { self.profile.set(profile); }
+
+    /// "Healthy" vitals of this particular character
+    pub(crate) fn baseline(&self) -> HealthC { self.profile.get().healthy_vitals() }
+
+    /// Natural regain rate adjusted by the character recovery rate
+    pub(crate) fn regain_rate(&self, rate: f32) -> f32 { rate * self.profile.get().recovery_rate }
+
+    /// Scales side effects drains according to the character profile. Gains are left as is
+    pub(crate) fn apply_profile_to_side_effects(&self, deltas: &mut SideEffectDeltasC) {
+        let metabolic_rate = self.profile.get().metabolic_rate;
+
+        if deltas.food_level_bonus < 0. { deltas.food_level_bonus *= metabolic_rate; }
+        if deltas.water_level_bonus < 0. { deltas.water_level_bonus *= metabolic_rate; }
+        if deltas.stamina_bonus < 0. { deltas.stamina_bonus *= self.stamina_drain_factor(); }
+        if deltas.fatigue_bonus > 0. { deltas.fatigue_bonus *= self.profile.get().fatigue_rate(); }
+    }
+
+    /// Scales disease drains according to the character profile and traits
+    pub(crate) fn apply_profile_to_disease_deltas(&self, deltas: &mut DiseaseDeltasC) {
+        let metabolic_rate = self.profile.get().metabolic_rate;
+
+        deltas.food_drain *= metabolic_rate;
+        deltas.water_drain *= metabolic_rate;
+        deltas.stamina_drain *= self.stamina_drain_factor();
+        deltas.blood_drain *= self.traits.blood_drain_factor();
+        if deltas.fatigue_delta > 0. { deltas.fatigue_delta *= self.profile.get().fatigue_rate(); }
+    }
+
+    /// Scales injury drains according to the character profile and traits
+    pub(crate) fn apply_profile_to_injury_deltas(&self, deltas: &mut InjuryDeltasC) {
+        deltas.stamina_drain *= self.stamina_drain_factor();
+        deltas.blood_drain *= self.traits.blood_drain_factor();
+        if deltas.fatigue_delta > 0. { deltas.fatigue_delta *= self.profile.get().fatigue_rate(); }
+    }
+
+    fn stamina_drain_factor(&self) -> f32 {
+        let capacity = self.profile.get().stamina_capacity;
+
+        if capacity > 0. { 1. / capacity } else { 1. }
+    }
+}
\ No newline at end of file
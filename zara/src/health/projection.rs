@@ -0,0 +1,206 @@
+use crate::health::{Health, Vital};
+use crate::health::disease::DiseaseDeltasC;
+use crate::health::side::SideEffectDeltasC;
+use crate::utils::{GameTimeC, HealthC, clamp};
+
+use std::time::Duration;
+
+impl Health {
+    /// Projects health vitals to the given future game time without altering any state.
+    ///
+    /// Evaluates disease and injury curves and applies current side effects drain rates and regain
+    /// rates starting from the game time of the last `update`. Side effects are treated as constant
+    /// (as they were on the last `update`). Death chances, self-heal, medical agents and
+    /// treatments are not considered. `diseases` and `injuries` lists of the result are empty.
+    ///
+    /// # Parameters
+    /// - `game_time`: game time to project vitals to
+    ///
+    /// # Returns
+    /// Projected health snapshot
+    ///
+    /// # Examples
+    /// ```
+    /// let future = person.health.project(&game_time.add_minutes(40));
+    ///
+    /// if future.water_level <= 5. {
+    ///     // Warn the player
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `diseases` and `injuries` collections
+    pub fn project(&self, game_time: &GameTimeC) -> HealthC {
+        self.simulate(game_time, &mut |_, _| false)
+    }
+
+    /// Estimates how much game time is left until a given vital reaches a given value, based on
+    /// the same data [`project`] uses. Looks ahead for `MAX_TIME_UNTIL_HOURS` game hours at most.
+    ///
+    /// [`project`]: #method.project
+    ///
+    /// # Parameters
+    /// - `vital`: vital to watch
+    /// - `value`: threshold value. If vital is above it now, will wait for it to fall to this value,
+    ///   and vice versa
+    ///
+    /// # Returns
+    /// Game time left, or `None` if vital will not reach the given value in the look-ahead window
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::Vital;
+    ///
+    /// if let Some(time_left) = person.health.time_until(Vital::Water, 10.) {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `diseases` and `injuries` collections
+    pub fn time_until(&self, vital: Vital, value: f32) -> Option<GameTimeC> {
+        const MAX_TIME_UNTIL_HOURS: f32 = 72.;
+
+        let now = *self.last_update_game_time.borrow();
+        let current = self.vital_value(vital, &HealthC {
+            body_temperature: self.body_temperature.get(),
+            heart_rate: self.heart_rate.get(),
+            top_pressure: self.top_pressure.get(),
+            bottom_pressure: self.bottom_pressure.get(),
+            blood_level: self.blood_level.get(),
+            food_level: self.food_level.get(),
+            water_level: self.water_level.get(),
+            stamina_level: self.stamina_level.get(),
+            fatigue_level: self.fatigue_level.get(),
+            oxygen_level: self.oxygen_level.get(),
+            ..HealthC::healthy()
+        });
+
+        if f32::abs(current - value) < 0.0001 { return Some(GameTimeC::empty()); }
+
+        let falling = current > value;
+        let horizon = GameTimeC::from_duration(Duration::from_secs_f32(now.as_secs_f32() + MAX_TIME_UNTIL_HOURS * 60. * 60.));
+        let mut result = None;
+
+        self.simulate(&horizon, &mut |t, snapshot| {
+            let v = self.vital_value(vital, snapshot);
+
+            if (falling && v <= value) || (!falling && v >= value) {
+                result = Some(GameTimeC::from_duration(Duration::from_secs_f32(t - now.as_secs_f32())));
+
+                return true;
+            }
+
+            false
+        });
+
+        result
+    }
+
+    fn vital_value(&self, vital: Vital, snapshot: &HealthC) -> f32 {
+        match vital {
+            Vital::BodyTemperature => snapshot.body_temperature,
+            Vital::HeartRate => snapshot.heart_rate,
+            Vital::TopPressure => snapshot.top_pressure,
+            Vital::BottomPressure => snapshot.bottom_pressure,
+            Vital::Blood => snapshot.blood_level,
+            Vital::Food => snapshot.food_level,
+            Vital::Water => snapshot.water_level,
+            Vital::Stamina => snapshot.stamina_level,
+            Vital::Fatigue => snapshot.fatigue_level,
+            Vital::Oxygen => snapshot.oxygen_level
+        }
+    }
+
+    /// Steps vitals from the last `update` game time to the given one. `on_step` is called
+    /// with game time (in seconds) and a snapshot after every step; returning `true` from it
+    /// stops the simulation
+    fn simulate(&self, game_time: &GameTimeC, on_step: &mut dyn FnMut(f32, &HealthC) -> bool) -> HealthC {
+        const PROJECTION_STEP: f32 = 60.; // game seconds
+        const MAX_PROJECTION_STEPS: f32 = 10_000.;
+
+        let now = *self.last_update_game_time.borrow();
+        let side = *self.last_side_effects.borrow();
+        let from = now.as_secs_f32();
+        let to = game_time.as_secs_f32();
+        let span = to - from;
+        let step = if span / PROJECTION_STEP > MAX_PROJECTION_STEPS { span / MAX_PROJECTION_STEPS } else { PROJECTION_STEP };
+        let mut result = HealthC {
+            blood_level: self.blood_level.get(),
+            food_level: self.food_level.get(),
+            water_level: self.water_level.get(),
+            stamina_level: self.stamina_level.get(),
+            oxygen_level: self.oxygen_level.get(),
+            ..HealthC::healthy()
+        };
+        let healthy = self.baseline();
+        let mut disease_deltas = self.project_disease_deltas(&now, &now);
+        let mut t = from;
+
+        self.apply_absolute_projection(&mut result, &healthy, &side,
+            &self.merge_vitals_deltas(&disease_deltas, &self.project_injury_deltas(&now, &now)));
+
+        while t < to {
+            let dt = f32::min(step, to - t);
+
+            t += dt;
+
+            let time = GameTimeC::from_duration(Duration::from_secs_f32(t));
+            let injury_deltas = self.project_injury_deltas(&now, &time);
+
+            disease_deltas = self.project_disease_deltas(&now, &time);
+
+            result.food_level = clamp(result.food_level +
+                (side.food_level_bonus - disease_deltas.food_drain) * dt, 0., 100.);
+            result.water_level = clamp(result.water_level +
+                (side.water_level_bonus - disease_deltas.water_drain) * dt, 0., 100.);
+            result.stamina_level = clamp(result.stamina_level +
+                (side.stamina_bonus + self.regain_rate(self.stamina_regain_rate.get()) - disease_deltas.stamina_drain - injury_deltas.stamina_drain) * dt, 0., 100.);
+            result.oxygen_level = clamp(result.oxygen_level +
+                (side.oxygen_level_bonus + self.regain_rate(self.oxygen_regain_rate.get()) - disease_deltas.oxygen_drain) * dt, 0., 100.);
+            result.blood_level = clamp(result.blood_level +
+                (self.regain_rate(self.blood_regain_rate.get()) - injury_deltas.blood_drain - disease_deltas.blood_drain) * dt, 0., 100.);
+
+            self.apply_absolute_projection(&mut result, &healthy, &side,
+                &self.merge_vitals_deltas(&disease_deltas, &injury_deltas));
+
+            if on_step(t, &result) { break; }
+        }
+
+        result
+    }
+
+    fn apply_absolute_projection(&self, snapshot: &mut HealthC, healthy: &HealthC, side: &SideEffectDeltasC, disease_deltas: &DiseaseDeltasC) {
+        snapshot.body_temperature = healthy.body_temperature + side.body_temp_bonus + disease_deltas.body_temperature_delta;
+        snapshot.heart_rate = healthy.heart_rate + side.heart_rate_bonus + disease_deltas.heart_rate_delta;
+        snapshot.top_pressure = healthy.top_pressure + side.top_pressure_bonus + disease_deltas.pressure_top_delta;
+        snapshot.bottom_pressure = healthy.bottom_pressure + side.bottom_pressure_bonus + disease_deltas.pressure_bottom_delta;
+        snapshot.fatigue_level = clamp(side.fatigue_bonus + disease_deltas.fatigue_delta, 0., 100.);
+    }
+
+    /// Combined per game second disease deltas for a given time
+    fn project_disease_deltas(&self, now: &GameTimeC, game_time: &GameTimeC) -> DiseaseDeltasC {
+        let mut deltas = Vec::new();
+
+        for (_, disease) in self.active_diseases.borrow().iter() {
+            if disease.is_active(game_time) {
+                deltas.push(disease.project_vitals_deltas(now, game_time));
+            }
+        }
+
+        self.combine_disease_deltas(&deltas, 1.)
+    }
+
+    /// Combined per game second injury deltas for a given time
+    fn project_injury_deltas(&self, now: &GameTimeC, game_time: &GameTimeC) -> crate::health::injury::InjuryDeltasC {
+        let mut deltas = Vec::new();
+
+        for (_, injury) in self.active_injuries.borrow().iter() {
+            if injury.is_active(game_time) {
+                deltas.push(injury.project_drains_deltas(now, game_time));
+            }
+        }
+
+        self.combine_injury_deltas(&deltas, 1.)
+    }
+}
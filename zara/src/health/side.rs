@@ -1,8 +1,10 @@
 use crate::utils::FrameSummaryC;
+use crate::health::Health;
 
 use std::any::Any;
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::collections::BTreeMap;
 
 pub mod builtin;
 
@@ -26,6 +28,131 @@ pub trait SideEffectsMonitor {
 
     /// For downcasting
     fn as_any(&self) -> &dyn Any;
+
+    /// Is called when monitor is registered with
+    /// [`register_side_effect_monitor`](crate::health::Health::register_side_effect_monitor).
+    /// Does nothing by default
+    ///
+    /// # Parameters
+    /// - `health`: health node this monitor is registered on
+    fn on_registered(&self, _health: &Health) { }
+
+    /// Is called when monitor is unregistered with
+    /// [`unregister_side_effect_monitor`](crate::health::Health::unregister_side_effect_monitor).
+    /// Does nothing by default
+    fn on_removed(&self) { }
+
+    /// Returns snapshot of the monitor internal counters and timers. It is captured with the
+    /// health state, so stateful monitors continue where they were after save/load. `None`
+    /// (default) for monitors that have no state
+    fn get_state(&self) -> Option<SideEffectsMonitorStateContract> { None }
+
+    /// Restores monitor internal counters and timers from the snapshot captured by
+    /// [`get_state`](#method.get_state). Does nothing by default
+    ///
+    /// # Parameters
+    /// - `state`: captured earlier state
+    fn set_state(&self, _state: &SideEffectsMonitorStateContract) { }
+}
+
+/// Handle of a side effects monitors pack, given by
+/// [`register_monitor_pack`](crate::health::Health::register_monitor_pack)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct PackHandle {
+    pub(crate) id: usize
+}
+impl fmt::Display for PackHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Monitor pack {}", self.id)
+    }
+}
+
+/// Contains state snapshot for a stateful side effects monitor: named numeric values, like
+/// timers and counters. Flags are stored as `0` and `1`
+///
+/// # Examples
+/// ```
+/// use zara::health::side::SideEffectsMonitorStateContract;
+///
+/// let state = SideEffectsMonitorStateContract::default()
+///     .with("running_time", 12.)
+///     .with("is_running", 1.);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SideEffectsMonitorStateContract {
+    /// Named state values
+    pub values: BTreeMap<String, f32>
+}
+impl SideEffectsMonitorStateContract {
+    /// Returns this contract with a given value set
+    ///
+    /// # Parameters
+    /// - `name`: value name
+    /// - `value`: value
+    pub fn with(mut self, name: &str, value: f32) -> Self {
+        self.values.insert(name.to_string(), value);
+
+        self
+    }
+
+    /// Returns this contract with a given flag set
+    ///
+    /// # Parameters
+    /// - `name`: flag name
+    /// - `value`: flag value
+    pub fn with_flag(self, name: &str, value: bool) -> Self {
+        self.with(name, if value { 1. } else { 0. })
+    }
+
+    /// Returns value with a given name, or `default` if it was not captured
+    ///
+    /// # Parameters
+    /// - `name`: value name
+    /// - `default`: value to return if it was not captured
+    ///
+    /// # Examples
+    /// ```
+    /// self.running_time.set(state.get_or("running_time", 0.));
+    /// ```
+    pub fn get_or(&self, name: &str, default: f32) -> f32 {
+        self.values.get(name).copied().unwrap_or(default)
+    }
+
+    /// Returns flag with a given name, or `default` if it was not captured
+    ///
+    /// # Parameters
+    /// - `name`: flag name
+    /// - `default`: flag to return if it was not captured
+    pub fn flag_or(&self, name: &str, default: bool) -> bool {
+        match self.values.get(name) {
+            Some(v) => *v > 0.5,
+            None => default
+        }
+    }
+}
+impl fmt::Display for SideEffectsMonitorStateContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Side effects monitor state ({} values)", self.values.len())
+    }
+}
+impl Eq for SideEffectsMonitorStateContract { }
+impl PartialEq for SideEffectsMonitorStateContract {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.values.len() == other.values.len() &&
+        self.values.iter().zip(other.values.iter())
+            .all(|((k1, v1), (k2, v2))| k1 == k2 && f32::abs(v1 - v2) < EPS)
+    }
+}
+impl Hash for SideEffectsMonitorStateContract {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for (name, value) in self.values.iter() {
+            name.hash(state);
+
+            state.write_i32((value*10_000_f32) as i32);
+        }
+    }
 }
 
 /// Structure that contains result of a side effects monitor check for one frame.
@@ -1,5 +1,5 @@
 use crate::health::side::builtin::{FatigueSideEffects, FatigueSideEffectsStateContract};
-use crate::health::side::{SideEffectsMonitor, SideEffectDeltasC};
+use crate::health::side::{SideEffectsMonitor, SideEffectDeltasC, SideEffectsMonitorStateContract};
 use crate::utils::FrameSummaryC;
 
 use std::time::Duration;
@@ -55,6 +55,26 @@ impl FatigueSideEffects {
     pub fn restore_state(&self, state: &FatigueSideEffectsStateContract) {
         self.hours_until_exhausted.set(state.hours_until_exhausted);
     }
+    /// Creates new `FatigueSideEffects` instance with a given state
+    ///
+    /// # Parameters
+    /// - `state`: captured earlier state
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::side::builtin;
+    /// let o = builtin::FatigueSideEffects::from_state(&state);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management) for more info.
+    pub fn from_state(state: &FatigueSideEffectsStateContract) -> Self {
+        let o = FatigueSideEffects::new(state.hours_until_exhausted);
+
+        o.restore_state(state);
+
+        o
+    }
 }
 impl SideEffectsMonitor for FatigueSideEffects {
     fn check(&self, frame_data: &FrameSummaryC) -> SideEffectDeltasC {
@@ -85,4 +105,20 @@ impl SideEffectsMonitor for FatigueSideEffects {
     }
 
     fn as_any(&self) -> &dyn Any { self }
+
+    fn get_state(&self) -> Option<SideEffectsMonitorStateContract> {
+        let state = FatigueSideEffects::get_state(self);
+
+        Some(SideEffectsMonitorStateContract::default()
+            .with("hours_until_exhausted", state.hours_until_exhausted as f32))
+    }
+
+    fn set_state(&self, state: &SideEffectsMonitorStateContract) {
+        // Values that were not captured are kept
+        let current = FatigueSideEffects::get_state(self);
+
+        self.restore_state(&FatigueSideEffectsStateContract {
+            hours_until_exhausted: state.get_or("hours_until_exhausted", current.hours_until_exhausted as f32) as usize
+        });
+    }
 }
\ No newline at end of file
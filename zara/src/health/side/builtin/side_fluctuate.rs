@@ -1,5 +1,5 @@
 use crate::health::side::builtin::{DynamicVitalsSideEffect, DynamicVitalsSideEffectStateContract};
-use crate::health::side::{SideEffectsMonitor, SideEffectDeltasC};
+use crate::health::side::{SideEffectsMonitor, SideEffectDeltasC, SideEffectsMonitorStateContract};
 use crate::utils::{FrameSummaryC};
 
 use std::cell::Cell;
@@ -71,6 +71,26 @@ impl DynamicVitalsSideEffect {
         self.heart_rate_ceiling.set(state.heart_rate_ceiling);
         self.top_pressure_ceiling.set(state.top_pressure_ceiling);
     }
+    /// Creates new `DynamicVitalsSideEffect` instance with a given state
+    ///
+    /// # Parameters
+    /// - `state`: captured earlier state
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::side::builtin;
+    /// let o = builtin::DynamicVitalsSideEffect::from_state(&state);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management) for more info.
+    pub fn from_state(state: &DynamicVitalsSideEffectStateContract) -> Self {
+        let o = DynamicVitalsSideEffect::new();
+
+        o.restore_state(state);
+
+        o
+    }
 }
 impl SideEffectsMonitor for DynamicVitalsSideEffect {
     fn check(&self, frame_data: &FrameSummaryC) -> SideEffectDeltasC {
@@ -121,6 +141,36 @@ impl SideEffectsMonitor for DynamicVitalsSideEffect {
     }
 
     fn as_any(&self) -> &dyn Any { self }
+
+    fn get_state(&self) -> Option<SideEffectsMonitorStateContract> {
+        let state = DynamicVitalsSideEffect::get_state(self);
+
+        Some(SideEffectsMonitorStateContract::default()
+            .with_flag("first_iteration", state.first_iteration)
+            .with("counter", state.counter)
+            .with("half_duration", state.half_duration)
+            .with("direction", state.direction)
+            .with("body_temperature_ceiling", state.body_temperature_ceiling)
+            .with("heart_rate_ceiling", state.heart_rate_ceiling)
+            .with("top_pressure_ceiling", state.top_pressure_ceiling)
+            .with("bottom_pressure_ceiling", state.bottom_pressure_ceiling))
+    }
+
+    fn set_state(&self, state: &SideEffectsMonitorStateContract) {
+        // Values that were not captured are kept
+        let current = DynamicVitalsSideEffect::get_state(self);
+
+        self.restore_state(&DynamicVitalsSideEffectStateContract {
+            first_iteration: state.flag_or("first_iteration", current.first_iteration),
+            counter: state.get_or("counter", current.counter),
+            half_duration: state.get_or("half_duration", current.half_duration),
+            direction: state.get_or("direction", current.direction),
+            body_temperature_ceiling: state.get_or("body_temperature_ceiling", current.body_temperature_ceiling),
+            heart_rate_ceiling: state.get_or("heart_rate_ceiling", current.heart_rate_ceiling),
+            top_pressure_ceiling: state.get_or("top_pressure_ceiling", current.top_pressure_ceiling),
+            bottom_pressure_ceiling: state.get_or("bottom_pressure_ceiling", current.bottom_pressure_ceiling)
+        });
+    }
 }
 
 impl DynamicVitalsSideEffect {
@@ -1,5 +1,5 @@
 use crate::health::side::builtin::{FoodDrainOverTimeSideEffect, FoodDrainOverTimeSideEffectStateContract};
-use crate::health::side::{SideEffectsMonitor, SideEffectDeltasC};
+use crate::health::side::{SideEffectsMonitor, SideEffectDeltasC, SideEffectsMonitorStateContract};
 use crate::utils::FrameSummaryC;
 
 use std::cell::Cell;
@@ -53,6 +53,26 @@ impl FoodDrainOverTimeSideEffect {
     pub fn restore_state(&self, state: &FoodDrainOverTimeSideEffectStateContract) {
         self.drain_amount.set(state.drain_amount);
     }
+    /// Creates new `FoodDrainOverTimeSideEffect` instance with a given state
+    ///
+    /// # Parameters
+    /// - `state`: captured earlier state
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::side::builtin;
+    /// let o = builtin::FoodDrainOverTimeSideEffect::from_state(&state);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management) for more info.
+    pub fn from_state(state: &FoodDrainOverTimeSideEffectStateContract) -> Self {
+        let o = FoodDrainOverTimeSideEffect::new(state.drain_amount);
+
+        o.restore_state(state);
+
+        o
+    }
 }
 
 impl SideEffectsMonitor for FoodDrainOverTimeSideEffect {
@@ -64,4 +84,20 @@ impl SideEffectsMonitor for FoodDrainOverTimeSideEffect {
     }
 
     fn as_any(&self) -> &dyn Any { self }
+
+    fn get_state(&self) -> Option<SideEffectsMonitorStateContract> {
+        let state = FoodDrainOverTimeSideEffect::get_state(self);
+
+        Some(SideEffectsMonitorStateContract::default()
+            .with("drain_amount", state.drain_amount))
+    }
+
+    fn set_state(&self, state: &SideEffectsMonitorStateContract) {
+        // Values that were not captured are kept
+        let current = FoodDrainOverTimeSideEffect::get_state(self);
+
+        self.restore_state(&FoodDrainOverTimeSideEffectStateContract {
+            drain_amount: state.get_or("drain_amount", current.drain_amount)
+        });
+    }
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
 use crate::health::side::builtin::{RunningSideEffects, RunningSideEffectsStateContract};
-use crate::health::side::{SideEffectsMonitor, SideEffectDeltasC};
+use crate::health::side::{SideEffectsMonitor, SideEffectDeltasC, SideEffectsMonitorStateContract};
 use crate::utils::{FrameSummaryC, clamp_bottom};
 
 use std::cell::Cell;
@@ -12,6 +12,9 @@ impl RunningSideEffects
     /// # Parameters
     /// - `stamina_drain`: stamina drain when running, 0..100 percents per game second
     /// - `water_drain`: water level drain speed when running, 0..100 percents per game second
+    ///
+    /// When the game reports running distance with `player_state.report_movement`, drains are
+    /// applied per distance run instead, as if character was running at 4 meters per game second
     /// 
     /// # Examples
     /// ```
@@ -70,6 +73,26 @@ impl RunningSideEffects
         self.running_time.set(state.running_time);
         self.gained_fatigue.set(state.gained_fatigue);
     }
+    /// Creates new `RunningSideEffects` instance with a given state
+    ///
+    /// # Parameters
+    /// - `state`: captured earlier state
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::side::builtin;
+    /// let o = builtin::RunningSideEffects::from_state(&state);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management) for more info.
+    pub fn from_state(state: &RunningSideEffectsStateContract) -> Self {
+        let o = RunningSideEffects::new(state.stamina_drain_amount, state.water_drain_amount);
+
+        o.restore_state(state);
+
+        o
+    }
 }
 impl SideEffectsMonitor for RunningSideEffects {
     fn check(&self, frame_data: &FrameSummaryC) -> SideEffectDeltasC {
@@ -79,6 +102,15 @@ impl SideEffectsMonitor for RunningSideEffects {
         const MAX_BODY_TEMP_IMPACT: f32 = 0.3;
         const MAX_TOP_PRESSURE_IMPACT: f32 = 24.;
         const MAX_BOTTOM_PRESSURE_IMPACT: f32 = 16.;
+        const REFERENCE_RUNNING_SPEED: f32 = 4.; // meters per game second
+
+        let meters_run = frame_data.player.movement.meters_run;
+        // Reported distance is more accurate than the running flag sampled once per update
+        let (is_running, effort_time) = if meters_run > 0. {
+            (true, meters_run / REFERENCE_RUNNING_SPEED)
+        } else {
+            (frame_data.player.is_running, frame_data.game_time_delta)
+        };
 
         if !frame_data.player.is_sleeping && self.sleeping_state.get() {
             // Woke up
@@ -90,13 +122,13 @@ impl SideEffectsMonitor for RunningSideEffects {
             self.sleeping_state.set(true);
         }
 
-        if frame_data.player.is_running {
+        if is_running {
             if self.running_state.get() == false {
                 self.running_state.set(true);
                 self.running_time.set(0.);
             }
 
-            let running_time = self.running_time.get() + frame_data.game_time_delta;
+            let running_time = self.running_time.get() + effort_time;
             let running_time_capped = crate::utils::clamp_to(
                 running_time,
                 TIME_TO_REACH_RUNNING_EXHAUST);
@@ -113,8 +145,8 @@ impl SideEffectsMonitor for RunningSideEffects {
                 heart_rate_bonus: crate::utils::lerp(0., MAX_HEART_RATE_IMPACT, p),
                 top_pressure_bonus: crate::utils::lerp(0., MAX_TOP_PRESSURE_IMPACT, p),
                 bottom_pressure_bonus: crate::utils::lerp(0., MAX_BOTTOM_PRESSURE_IMPACT, p),
-                stamina_bonus: -self.stamina_drain_amount.get() * frame_data.game_time_delta,
-                water_level_bonus: -self.water_drain_amount.get() * frame_data.game_time_delta,
+                stamina_bonus: -self.stamina_drain_amount.get() * effort_time,
+                water_level_bonus: -self.water_drain_amount.get() * effort_time,
                 fatigue_bonus: self.gained_fatigue.get(),
 
                 ..Default::default()
@@ -154,4 +186,30 @@ impl SideEffectsMonitor for RunningSideEffects {
     }
 
     fn as_any(&self) -> &dyn Any { self }
+
+    fn get_state(&self) -> Option<SideEffectsMonitorStateContract> {
+        let state = RunningSideEffects::get_state(self);
+
+        Some(SideEffectsMonitorStateContract::default()
+            .with("stamina_drain_amount", state.stamina_drain_amount)
+            .with("water_drain_amount", state.water_drain_amount)
+            .with_flag("running_state", state.running_state)
+            .with_flag("sleeping_state", state.sleeping_state)
+            .with("running_time", state.running_time)
+            .with("gained_fatigue", state.gained_fatigue))
+    }
+
+    fn set_state(&self, state: &SideEffectsMonitorStateContract) {
+        // Values that were not captured are kept
+        let current = RunningSideEffects::get_state(self);
+
+        self.restore_state(&RunningSideEffectsStateContract {
+            stamina_drain_amount: state.get_or("stamina_drain_amount", current.stamina_drain_amount),
+            water_drain_amount: state.get_or("water_drain_amount", current.water_drain_amount),
+            running_state: state.flag_or("running_state", current.running_state),
+            sleeping_state: state.flag_or("sleeping_state", current.sleeping_state),
+            running_time: state.get_or("running_time", current.running_time),
+            gained_fatigue: state.get_or("gained_fatigue", current.gained_fatigue)
+        });
+    }
 }
\ No newline at end of file
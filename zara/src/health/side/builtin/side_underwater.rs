@@ -1,5 +1,5 @@
 use crate::health::side::builtin::{UnderwaterSideEffect, UnderwaterSideEffectStateContract};
-use crate::health::side::{SideEffectsMonitor, SideEffectDeltasC};
+use crate::health::side::{SideEffectsMonitor, SideEffectDeltasC, SideEffectsMonitorStateContract};
 use crate::utils::{FrameSummaryC, clamp_bottom};
 
 use std::cell::Cell;
@@ -69,6 +69,26 @@ impl UnderwaterSideEffect {
         self.underwater_state.set(state.underwater_state);
         self.time_under_water.set(state.time_under_water);
     }
+    /// Creates new `UnderwaterSideEffect` instance with a given state
+    ///
+    /// # Parameters
+    /// - `state`: captured earlier state
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::side::builtin;
+    /// let o = builtin::UnderwaterSideEffect::from_state(&state);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management) for more info.
+    pub fn from_state(state: &UnderwaterSideEffectStateContract) -> Self {
+        let o = UnderwaterSideEffect::new(state.oxygen_drain_amount, state.stamina_drain_amount);
+
+        o.restore_state(state);
+
+        o
+    }
 }
 
 impl SideEffectsMonitor for UnderwaterSideEffect {
@@ -150,4 +170,30 @@ impl SideEffectsMonitor for UnderwaterSideEffect {
     }
 
     fn as_any(&self) -> &dyn Any { self }
+
+    fn get_state(&self) -> Option<SideEffectsMonitorStateContract> {
+        let state = UnderwaterSideEffect::get_state(self);
+
+        Some(SideEffectsMonitorStateContract::default()
+            .with("oxygen_drain_amount", state.oxygen_drain_amount)
+            .with("stamina_drain_amount", state.stamina_drain_amount)
+            .with_flag("sleeping_state", state.sleeping_state)
+            .with("gained_fatigue", state.gained_fatigue)
+            .with_flag("underwater_state", state.underwater_state)
+            .with("time_under_water", state.time_under_water))
+    }
+
+    fn set_state(&self, state: &SideEffectsMonitorStateContract) {
+        // Values that were not captured are kept
+        let current = UnderwaterSideEffect::get_state(self);
+
+        self.restore_state(&UnderwaterSideEffectStateContract {
+            oxygen_drain_amount: state.get_or("oxygen_drain_amount", current.oxygen_drain_amount),
+            stamina_drain_amount: state.get_or("stamina_drain_amount", current.stamina_drain_amount),
+            sleeping_state: state.flag_or("sleeping_state", current.sleeping_state),
+            gained_fatigue: state.get_or("gained_fatigue", current.gained_fatigue),
+            underwater_state: state.flag_or("underwater_state", current.underwater_state),
+            time_under_water: state.get_or("time_under_water", current.time_under_water)
+        });
+    }
 }
\ No newline at end of file
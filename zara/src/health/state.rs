@@ -1,12 +1,26 @@
 use crate::health::Health;
+use crate::health::digestion::DigestingMeal;
+use crate::health::nausea::RecentMeal;
+use crate::health::appetite::Urgency;
+use crate::health::stimulant::{Stimulant, StimulantBinding};
 use crate::health::medagent::state::MedicalAgentsMonitorStateContract;
+use crate::health::side::SideEffectsMonitorStateContract;
+use crate::health::latent::LatentInfection;
+use crate::health::procedure::ActiveProcedure;
+use crate::health::nutrition::{Nutrient, NutrientsC};
+use crate::health::toxicity::ToxicityStage;
+use crate::health::{InjuryKey, StageLevel};
+use crate::state::DirtyTracked;
 
+use std::cell::Cell;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 
 /// Holds state snapshot data for the player's `Health` node
 #[derive(Clone, Debug, Default)]
 pub struct HealthStateContract {
+    /// Schema version this state was captured with
+    pub version: u32,
     /// Captured state of the `stamina_regain_rate` field
     pub stamina_regain_rate: f32,
     /// Captured state of the `blood_regain_rate` field
@@ -35,10 +49,52 @@ pub struct HealthStateContract {
     pub fatigue_level: f32,
     /// Captured state of the `oxygen_level` field
     pub oxygen_level: f32,
+    /// Captured state of the `toxicity_level` field
+    pub toxicity_level: f32,
+    /// Captured state of the `toxicity_stage` field
+    pub toxicity_stage: ToxicityStage,
     /// Captured state of the `is_alive` field
     pub is_alive: bool,
     /// Captured state of the `has_blood_loss` field
-    pub has_blood_loss: bool
+    pub has_blood_loss: bool,
+    /// Captured state of the `consumed_heat` field
+    pub consumed_heat: f32,
+    /// Captured state of the `digestion` field
+    pub digestion: Vec<DigestingMealStateContract>,
+    /// Captured state of the `nausea_level` field
+    pub nausea_level: f32,
+    /// Captured state of the `recent_meals` field
+    pub recent_meals: Vec<RecentMealStateContract>,
+    /// Captured state of the `last_hunger_urgency` field
+    pub last_hunger_urgency: Urgency,
+    /// Captured state of the `last_thirst_urgency` field
+    pub last_thirst_urgency: Urgency,
+    /// Captured state of the `cold_exposure` field
+    pub cold_exposure: f32,
+    /// Captured state of the `heat_exposure` field
+    pub heat_exposure: f32,
+    /// Captured state of the `hypothermia_stage` field
+    pub hypothermia_stage: Option<StageLevel>,
+    /// Captured state of the `hyperthermia_stage` field
+    pub hyperthermia_stage: Option<StageLevel>,
+    /// Captured state of the `nutrient_levels` field
+    pub nutrient_levels: NutrientsC,
+    /// Captured state of the `nutrient_deficiencies` field
+    pub nutrient_deficiencies: Vec<Nutrient>,
+    /// Captured state of the `deficiency_diseases` field
+    pub deficiency_diseases: Vec<Nutrient>,
+    /// Captured state of the `stimulants` field
+    pub stimulants: Vec<StimulantStateContract>,
+    /// Captured state of the `fatigue_mask` field
+    pub fatigue_mask: f32,
+    /// Captured state of the `fatigue_debt` field
+    pub fatigue_debt: f32,
+    /// Captured states of the stateful `side_effects` monitors
+    pub side_effects: Vec<SideEffectsMonitorStateEntryContract>,
+    /// Captured state of the `latent_infections` field
+    pub latent_infections: Vec<LatentInfection>,
+    /// Captured state of the `active_procedures` field
+    pub active_procedures: Vec<ActiveProcedure>
 }
 impl fmt::Display for HealthStateContract {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -50,9 +106,23 @@ impl PartialEq for HealthStateContract {
     fn eq(&self, other: &Self) -> bool {
         const EPS: f32 = 0.0001;
 
+        self.version == other.version &&
         self.medical_agents == other.medical_agents &&
         self.is_alive == other.is_alive &&
         self.has_blood_loss == other.has_blood_loss &&
+        self.digestion == other.digestion &&
+        self.recent_meals == other.recent_meals &&
+        self.last_hunger_urgency == other.last_hunger_urgency &&
+        self.last_thirst_urgency == other.last_thirst_urgency &&
+        self.hypothermia_stage == other.hypothermia_stage &&
+        self.hyperthermia_stage == other.hyperthermia_stage &&
+        self.nutrient_levels == other.nutrient_levels &&
+        self.nutrient_deficiencies == other.nutrient_deficiencies &&
+        self.deficiency_diseases == other.deficiency_diseases &&
+        self.stimulants == other.stimulants &&
+        self.side_effects == other.side_effects &&
+        self.latent_infections == other.latent_infections &&
+        self.active_procedures == other.active_procedures &&
         f32::abs(self.stamina_regain_rate - other.stamina_regain_rate) < EPS &&
         f32::abs(self.blood_regain_rate - other.blood_regain_rate) < EPS &&
         f32::abs(self.oxygen_regain_rate - other.oxygen_regain_rate) < EPS &&
@@ -65,14 +135,36 @@ impl PartialEq for HealthStateContract {
         f32::abs(self.water_level - other.water_level) < EPS &&
         f32::abs(self.stamina_level - other.stamina_level) < EPS &&
         f32::abs(self.fatigue_level - other.fatigue_level) < EPS &&
-        f32::abs(self.oxygen_level - other.oxygen_level) < EPS
+        f32::abs(self.oxygen_level - other.oxygen_level) < EPS &&
+        f32::abs(self.toxicity_level - other.toxicity_level) < EPS &&
+        self.toxicity_stage == other.toxicity_stage &&
+        f32::abs(self.consumed_heat - other.consumed_heat) < EPS &&
+        f32::abs(self.nausea_level - other.nausea_level) < EPS &&
+        f32::abs(self.fatigue_mask - other.fatigue_mask) < EPS &&
+        f32::abs(self.fatigue_debt - other.fatigue_debt) < EPS &&
+        f32::abs(self.cold_exposure - other.cold_exposure) < EPS &&
+        f32::abs(self.heat_exposure - other.heat_exposure) < EPS
     }
 }
 impl Hash for HealthStateContract {
     fn hash<H: Hasher>(&self, state: &mut H) {
+        self.version.hash(state);
         self.medical_agents.hash(state);
         self.is_alive.hash(state);
         self.has_blood_loss.hash(state);
+        self.digestion.hash(state);
+        self.recent_meals.hash(state);
+        self.last_hunger_urgency.hash(state);
+        self.last_thirst_urgency.hash(state);
+        self.hypothermia_stage.hash(state);
+        self.hyperthermia_stage.hash(state);
+        self.nutrient_levels.hash(state);
+        self.nutrient_deficiencies.hash(state);
+        self.deficiency_diseases.hash(state);
+        self.stimulants.hash(state);
+        self.side_effects.hash(state);
+        self.latent_infections.hash(state);
+        self.active_procedures.hash(state);
 
         state.write_i32((self.stamina_regain_rate*10_000_f32) as i32);
         state.write_i32((self.blood_regain_rate*10_000_f32) as i32);
@@ -87,12 +179,148 @@ impl Hash for HealthStateContract {
         state.write_u32((self.stamina_level*10_000_f32) as u32);
         state.write_u32((self.fatigue_level*10_000_f32) as u32);
         state.write_u32((self.oxygen_level*10_000_f32) as u32);
+        state.write_u32((self.toxicity_level*10_000_f32) as u32);
+        self.toxicity_stage.hash(state);
+        state.write_i32((self.consumed_heat*10_000_f32) as i32);
+        state.write_u32((self.nausea_level*10_000_f32) as u32);
+        state.write_u32((self.fatigue_mask*10_000_f32) as u32);
+        state.write_u32((self.fatigue_debt*10_000_f32) as u32);
+        state.write_u32((self.cold_exposure*10_000_f32) as u32);
+        state.write_u32((self.heat_exposure*10_000_f32) as u32);
     }
 }
 
+/// Holds state snapshot data for a meal that is being digested
+#[derive(Clone, Debug, Default)]
+pub struct DigestingMealStateContract {
+    /// Captured state of the `item_name` field
+    pub item_name: String,
+    /// Captured state of the `food_gain` field
+    pub food_gain: f32,
+    /// Captured state of the `duration` field
+    pub duration: f32,
+    /// Captured state of the `elapsed` field
+    pub elapsed: f32
+}
+impl fmt::Display for DigestingMealStateContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Digesting {} ({:.0}/{:.0}s)", self.item_name, self.elapsed, self.duration)
+    }
+}
+impl Eq for DigestingMealStateContract { }
+impl PartialEq for DigestingMealStateContract {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.item_name == other.item_name &&
+        f32::abs(self.food_gain - other.food_gain) < EPS &&
+        f32::abs(self.duration - other.duration) < EPS &&
+        f32::abs(self.elapsed - other.elapsed) < EPS
+    }
+}
+impl Hash for DigestingMealStateContract {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.item_name.hash(state);
+
+        state.write_u32((self.food_gain*10_000_f32) as u32);
+        state.write_u32((self.duration*10_000_f32) as u32);
+        state.write_u32((self.elapsed*10_000_f32) as u32);
+    }
+}
+
+/// Holds state snapshot data for a recently consumed item that can be thrown up
+#[derive(Clone, Debug, Default)]
+pub struct RecentMealStateContract {
+    /// Captured state of the `item_name` field
+    pub item_name: String,
+    /// Captured state of the `food_gain` field
+    pub food_gain: f32,
+    /// Captured state of the `water_gain` field
+    pub water_gain: f32,
+    /// Captured state of the `time` field
+    pub time: f32
+}
+impl fmt::Display for RecentMealStateContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Recent meal {} at {:.0}s", self.item_name, self.time)
+    }
+}
+impl Eq for RecentMealStateContract { }
+impl PartialEq for RecentMealStateContract {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.item_name == other.item_name &&
+        f32::abs(self.food_gain - other.food_gain) < EPS &&
+        f32::abs(self.water_gain - other.water_gain) < EPS &&
+        f32::abs(self.time - other.time) < EPS
+    }
+}
+impl Hash for RecentMealStateContract {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.item_name.hash(state);
+
+        state.write_u32((self.food_gain*10_000_f32) as u32);
+        state.write_u32((self.water_gain*10_000_f32) as u32);
+        state.write_u32((self.time*10_000_f32) as u32);
+    }
+}
+
+/// Holds state snapshot data for a stimulant bound to a medical agent
+#[derive(Clone, Debug, Default)]
+pub struct StimulantStateContract {
+    /// Medical agent name
+    pub agent_name: String,
+    /// Captured state of the `info` field
+    pub info: Stimulant,
+    /// Captured state of the `peak_mask` field
+    pub peak_mask: f32
+}
+impl fmt::Display for StimulantStateContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Stimulant {} ({})", self.agent_name, self.info)
+    }
+}
+impl Eq for StimulantStateContract { }
+impl PartialEq for StimulantStateContract {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.agent_name == other.agent_name &&
+        self.info == other.info &&
+        f32::abs(self.peak_mask - other.peak_mask) < EPS
+    }
+}
+impl Hash for StimulantStateContract {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.agent_name.hash(state);
+        self.info.hash(state);
+
+        state.write_u32((self.peak_mask*10_000_f32) as u32);
+    }
+}
+
+/// Holds state snapshot data for a registered side effects monitor
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct SideEffectsMonitorStateEntryContract {
+    /// Key of the monitor, given by `register_side_effect_monitor`
+    pub key: usize,
+    /// Monitor state
+    pub state: SideEffectsMonitorStateContract
+}
+impl fmt::Display for SideEffectsMonitorStateEntryContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Monitor {}: {}", self.key, self.state)
+    }
+}
+
+impl DirtyTracked for Health {
+    fn dirty_flag(&self) -> &Cell<bool> { &self.is_dirty }
+}
 impl Health {
     pub(crate) fn get_state(&self) -> HealthStateContract {
         HealthStateContract {
+            version: crate::state::STATE_CONTRACT_VERSION,
             medical_agents: self.medical_agents.get_state(),
 
             stamina_regain_rate: self.stamina_regain_rate.get(),
@@ -109,8 +337,58 @@ impl Health {
             stamina_level: self.stamina_level.get(),
             fatigue_level: self.fatigue_level.get(),
             oxygen_level: self.oxygen_level.get(),
+            toxicity_level: self.toxicity_level.get(),
+            toxicity_stage: self.toxicity_stage.get(),
             is_alive:  self.is_alive.get(),
-            has_blood_loss: self.has_blood_loss.get()
+            has_blood_loss: self.has_blood_loss.get(),
+            consumed_heat: self.consumed_heat.get(),
+            digestion: self.digestion.borrow().iter().map(|m| DigestingMealStateContract {
+                item_name: m.item_name.to_string(),
+                food_gain: m.food_gain,
+                duration: m.duration,
+                elapsed: m.elapsed
+            }).collect(),
+            nausea_level: self.nausea_level.get(),
+            recent_meals: self.recent_meals.borrow().iter().map(|m| RecentMealStateContract {
+                item_name: m.item_name.to_string(),
+                food_gain: m.food_gain,
+                water_gain: m.water_gain,
+                time: m.time
+            }).collect(),
+            last_hunger_urgency: self.last_hunger_urgency.get(),
+            last_thirst_urgency: self.last_thirst_urgency.get(),
+            cold_exposure: self.cold_exposure.get(),
+            heat_exposure: self.heat_exposure.get(),
+            hypothermia_stage: self.hypothermia_stage.get(),
+            hyperthermia_stage: self.hyperthermia_stage.get(),
+            nutrient_levels: self.nutrient_levels.get(),
+            nutrient_deficiencies: self.nutrient_deficiencies.borrow().iter().copied().collect(),
+            deficiency_diseases: self.deficiency_diseases.borrow().iter().copied().collect(),
+            stimulants: {
+                let mut stimulants: Vec<StimulantStateContract> = self.stimulants.borrow().iter()
+                    .map(|(name, s)| StimulantStateContract {
+                        agent_name: name.to_string(),
+                        info: s.info,
+                        peak_mask: s.peak_mask
+                    }).collect();
+
+                stimulants.sort_by(|a, b| a.agent_name.cmp(&b.agent_name));
+                stimulants
+            },
+            fatigue_mask: self.fatigue_mask.get(),
+            fatigue_debt: self.fatigue_debt.get(),
+            side_effects: {
+                let mut side_effects: Vec<SideEffectsMonitorStateEntryContract> = self.side_effects.borrow().iter()
+                    .filter_map(|(key, m)| m.get_state().map(|state| SideEffectsMonitorStateEntryContract {
+                        key: *key,
+                        state
+                    })).collect();
+
+                side_effects.sort_by_key(|s| s.key);
+                side_effects
+            },
+            latent_infections: self.latent_infections(),
+            active_procedures: self.active_procedures()
         }
     }
 
@@ -128,8 +406,50 @@ impl Health {
         self.stamina_level.set(state.stamina_level);
         self.fatigue_level.set(state.fatigue_level);
         self.oxygen_level.set(state.oxygen_level);
+        self.toxicity_level.set(state.toxicity_level);
+        self.toxicity_stage.set(state.toxicity_stage);
         self.is_alive.set(state.is_alive);
         self.has_blood_loss.set(state.has_blood_loss);
+        self.consumed_heat.set(state.consumed_heat);
+        self.digestion.replace(state.digestion.iter().map(|m| DigestingMeal {
+            item_name: m.item_name.to_string(),
+            food_gain: m.food_gain,
+            duration: m.duration,
+            elapsed: m.elapsed
+        }).collect());
+        self.nausea_level.set(state.nausea_level);
+        self.recent_meals.replace(state.recent_meals.iter().map(|m| RecentMeal {
+            item_name: m.item_name.to_string(),
+            food_gain: m.food_gain,
+            water_gain: m.water_gain,
+            time: m.time
+        }).collect());
+        self.last_hunger_urgency.set(state.last_hunger_urgency);
+        self.last_thirst_urgency.set(state.last_thirst_urgency);
+        self.cold_exposure.set(state.cold_exposure);
+        self.heat_exposure.set(state.heat_exposure);
+        self.hypothermia_stage.set(state.hypothermia_stage);
+        self.hyperthermia_stage.set(state.hyperthermia_stage);
+        self.nutrient_levels.set(state.nutrient_levels);
+        self.nutrient_deficiencies.replace(state.nutrient_deficiencies.iter().copied().collect());
+        self.deficiency_diseases.replace(state.deficiency_diseases.iter().copied().collect());
+        self.stimulants.replace(state.stimulants.iter().map(|s| (s.agent_name.to_string(), StimulantBinding {
+            info: s.info,
+            peak_mask: s.peak_mask
+        })).collect());
+        self.fatigue_mask.set(state.fatigue_mask);
+        self.fatigue_debt.set(state.fatigue_debt);
         self.medical_agents.set_state(&state.medical_agents);
+        self.latent_infections.replace(state.latent_infections.iter()
+            .map(|x| (x.disease_name.to_string(), x.clone())).collect());
+        self.active_procedures.replace(state.active_procedures.iter()
+            .map(|x| (InjuryKey::new(x.injury_name.to_string(), x.body_part), x.clone())).collect());
+
+        // Monitors are matched by their registration keys
+        for entry in state.side_effects.iter() {
+            if let Some(monitor) = self.side_effects.borrow().get(&entry.key) {
+                monitor.set_state(&entry.state);
+            }
+        }
     }
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 use crate::health::Health;
+use crate::body::BodyPart;
 
 impl Health {
     /// Is character alive
@@ -15,7 +16,7 @@ impl Health {
     /// ```
     /// let value = person.health.is_tired();
     /// ```
-    pub fn is_tired(&self) -> bool { self.fatigue_level.get() >= 70. }
+    pub fn is_tired(&self) -> bool { self.fatigue_level() >= 70. }
 
     /// Is player tired (`fatigue_level` more than 90%)
     /// 
@@ -23,7 +24,7 @@ impl Health {
     /// ```
     /// let value = person.health.is_exhausted();
     /// ```
-    pub fn is_exhausted(&self) -> bool { self.fatigue_level.get() >= 90. }
+    pub fn is_exhausted(&self) -> bool { self.fatigue_level() >= 90. }
 
     /// Player has low stamina (`stamina_level` 5% and less)
     /// 
@@ -137,13 +138,22 @@ impl Health {
     /// ```
     pub fn stamina_level(&self) -> f32 { self.stamina_level.get() }
 
-    /// Current fatigue level (0..100 percents)
+    /// Current fatigue level (0..100 percents) as character feels it. Active stimulants
+    /// make it lower than the [`true_fatigue_level`](#method.true_fatigue_level)
     /// 
     /// # Examples
     /// ```
     /// let value = person.health.fatigue_level();
     /// ```
-    pub fn fatigue_level(&self) -> f32 { self.fatigue_level.get() }
+    pub fn fatigue_level(&self) -> f32 { f32::max(self.fatigue_level.get() - self.fatigue_mask.get(), 0.) }
+
+    /// Current fatigue level (0..100 percents) without the stimulants mask
+    /// 
+    /// # Examples
+    /// ```
+    /// let value = person.health.true_fatigue_level();
+    /// ```
+    pub fn true_fatigue_level(&self) -> f32 { self.fatigue_level.get() }
 
     /// Current oxygen level (0..100 percents)
     /// 
@@ -152,4 +162,11 @@ impl Health {
     /// let value = person.health.oxygen_level();
     /// ```
     pub fn oxygen_level(&self) -> f32 { self.oxygen_level.get() }
+
+    /// Is there an active injury on a given body part
+    pub(crate) fn has_active_injury_on(&self, body_part: BodyPart) -> bool {
+        let game_time = self.last_update_game_time.borrow();
+
+        self.active_injuries.borrow().iter().any(|(key, injury)| key.body_part == body_part && injury.is_active(&game_time))
+    }
 }
\ No newline at end of file
@@ -0,0 +1,201 @@
+use crate::health::Health;
+use crate::health::side::SideEffectDeltasC;
+use crate::error::StimulantErr;
+use crate::utils::FrameSummaryC;
+use crate::utils::event::{Event, MessageQueue};
+use crate::state::DirtyTracked;
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// How fast fatigue debt goes away, fraction per game second (about 2 game hours half-life)
+const DEBT_RECOVERY_RATE: f32 = 0.0001;
+/// Debt recovery multiplier while sleeping
+const SLEEP_RECOVERY_MULTIPLIER: f32 = 4.;
+/// Debt below this value is forgiven
+const DEBT_EPSILON: f32 = 0.01;
+
+/// Describes how a medical agent masks fatigue
+///
+/// # Examples
+/// ```
+/// use zara::health::stimulant::Stimulant;
+///
+/// person.health.register_stimulant(&"Caffeine".to_string(), Stimulant {
+///     fatigue_mask: 30.,
+///     crash_interest: 0.5
+/// });
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Stimulant {
+    /// Fatigue (0..100) that is hidden when the agent is fully active
+    pub fatigue_mask: f32,
+    /// Part of the hidden fatigue that is added on top of the true fatigue when
+    /// the agent wears off. 0.5 means "repay 150%"
+    pub crash_interest: f32
+}
+impl fmt::Display for Stimulant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Masks {:.0} fatigue, {:.0}% interest", self.fatigue_mask, self.crash_interest * 100.)
+    }
+}
+impl Eq for Stimulant { }
+impl PartialEq for Stimulant {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        f32::abs(self.fatigue_mask - other.fatigue_mask) < EPS &&
+        f32::abs(self.crash_interest - other.crash_interest) < EPS
+    }
+}
+impl Hash for Stimulant {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u32((self.fatigue_mask*10_000_f32) as u32);
+        state.write_u32((self.crash_interest*10_000_f32) as u32);
+    }
+}
+
+/// Stimulant bound to a medical agent
+#[derive(Copy, Clone, Debug, Default)]
+pub(crate) struct StimulantBinding {
+    pub(crate) info: Stimulant,
+    /// The strongest mask during the current agent activity
+    pub(crate) peak_mask: f32
+}
+
+impl Health {
+    /// Binds a stimulant effect to a registered medical agent. While the agent is active,
+    /// [`fatigue_level`](#method.fatigue_level) shows lowered fatigue and `Tired` events
+    /// are delayed. When it wears off, hidden fatigue comes back with interest and
+    /// `StimulantCrashed` event is sent
+    ///
+    /// # Parameters
+    /// - `agent_name`: unique medical agent name
+    /// - `stimulant`: how the agent masks fatigue
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.register_stimulant(&"Caffeine".to_string(), Stimulant {
+    ///     fatigue_mask: 30.,
+    ///     crash_interest: 0.5
+    /// });
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `medical_agents.agents` collection
+    pub fn register_stimulant(&self, agent_name: &String, stimulant: Stimulant) -> Result<(), StimulantErr> {
+        self.mark_dirty();
+
+        if !self.medical_agents.agents.borrow().contains_key(agent_name) {
+            return Err(StimulantErr::AgentNotFound);
+        }
+
+        self.stimulants.borrow_mut().insert(agent_name.to_string(), StimulantBinding {
+            info: Stimulant {
+                fatigue_mask: crate::utils::clamp(stimulant.fatigue_mask, 0., 100.),
+                crash_interest: f32::max(stimulant.crash_interest, 0.)
+            },
+            peak_mask: 0.
+        });
+
+        Ok(())
+    }
+
+    /// Removes stimulant effect from a medical agent. Fatigue that is being hidden
+    /// by this agent is shown right away, without interest
+    ///
+    /// # Parameters
+    /// - `agent_name`: unique medical agent name
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.unregister_stimulant(&"Caffeine".to_string());
+    /// ```
+    pub fn unregister_stimulant(&self, agent_name: &String) -> Result<(), StimulantErr> {
+        self.mark_dirty();
+
+        match self.stimulants.borrow_mut().remove(agent_name) {
+            Some(_) => Ok(()),
+            None => Err(StimulantErr::StimulantNotFound)
+        }
+    }
+
+    /// Returns stimulant effect bound to a given medical agent
+    ///
+    /// # Parameters
+    /// - `agent_name`: unique medical agent name
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(stimulant) = person.health.stimulant(&"Caffeine".to_string()) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn stimulant(&self, agent_name: &String) -> Option<Stimulant> {
+        self.stimulants.borrow().get(agent_name).map(|s| s.info)
+    }
+
+    /// Returns fatigue (0..100) that stimulants are hiding right now
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.health.fatigue_mask();
+    /// ```
+    pub fn fatigue_mask(&self) -> f32 { self.fatigue_mask.get() }
+
+    /// Returns extra fatigue (0..100) left from the stimulant crashes. It goes away
+    /// with time, faster when sleeping
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.health.fatigue_debt();
+    /// ```
+    pub fn fatigue_debt(&self) -> f32 { self.fatigue_debt.get() }
+
+    /// Updates stimulant masks and sends crash events
+    pub(crate) fn update_stimulants(&self) {
+        let agents = self.medical_agents.agents.borrow();
+        let mut mask = 0.;
+
+        for (name, binding) in self.stimulants.borrow_mut().iter_mut() {
+            let current = match agents.get(name) {
+                Some(agent) if agent.is_active() =>
+                    binding.info.fatigue_mask * agent.percent_of_activity() as f32 / 100.,
+                _ => 0.
+            };
+
+            if current > binding.peak_mask { binding.peak_mask = current; }
+
+            if current <= 0. && binding.peak_mask > 0. {
+                // Hidden fatigue is back, and with interest
+                let debt = binding.peak_mask * binding.info.crash_interest;
+
+                self.fatigue_debt.set(crate::utils::clamp(self.fatigue_debt.get() + debt, 0., 100.));
+                self.queue_message(Event::StimulantCrashed(name.to_string(), debt));
+
+                binding.peak_mask = 0.;
+            }
+
+            mask += current;
+        }
+
+        self.fatigue_mask.set(crate::utils::clamp(mask, 0., 100.));
+    }
+
+    /// Adds fatigue debt to the side effects deltas
+    pub(crate) fn apply_fatigue_debt(&self, deltas: &mut SideEffectDeltasC, frame_data: &FrameSummaryC) {
+        let debt = self.fatigue_debt.get();
+
+        if debt <= 0. { return; }
+
+        // Fatigue is frozen while sleeping
+        if !frame_data.player.is_sleeping {
+            deltas.fatigue_bonus += debt;
+        }
+
+        let rate = if frame_data.player.is_sleeping { DEBT_RECOVERY_RATE * SLEEP_RECOVERY_MULTIPLIER } else { DEBT_RECOVERY_RATE };
+        let debt = debt * f32::max(1. - rate * frame_data.game_time_delta, 0.);
+
+        self.fatigue_debt.set(if debt < DEBT_EPSILON { 0. } else { debt });
+    }
+}
@@ -0,0 +1,349 @@
+use crate::health::{Health, StageLevel};
+use crate::health::disease::{Disease, DiseaseTreatment, StageBuilder, StageDescription};
+use crate::utils::{clamp, FrameSummaryC, GameTimeC};
+use crate::utils::event::{Event, MessageQueue};
+
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt;
+
+/// Id of the built-in disease spawned by the sustained cold
+pub const HYPOTHERMIA_DISEASE: &str = "Exposure Hypothermia";
+/// Id of the built-in disease spawned by the sustained heat
+pub const HYPERTHERMIA_DISEASE: &str = "Exposure Hyperthermia";
+
+/// How sustained cold and heat turn into the built-in [`ExposureHypothermia`] and
+/// [`ExposureHyperthermia`] diseases. Is off until set with
+/// [`set_thermal_conditions`](crate::health::Health::set_thermal_conditions)
+///
+/// # Examples
+/// ```
+/// use zara::health::thermal::ThermalConditionsConfig;
+///
+/// person.health.set_thermal_conditions(Some(ThermalConditionsConfig {
+///     onset_minutes: 20.,
+///     ..Default::default()
+/// }));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ThermalConditionsConfig {
+    /// Warmth level below which cold exposure builds up
+    pub cold_warmth_threshold: f32,
+    /// Warmth level above which heat exposure builds up
+    pub hot_warmth_threshold: f32,
+    /// Game minutes of the sustained cold or heat before the disease starts
+    pub onset_minutes: f32,
+    /// How many times faster exposure goes away when warmth is back in the comfort zone
+    pub recovery_rate: f32
+}
+impl Default for ThermalConditionsConfig {
+    fn default() -> Self {
+        ThermalConditionsConfig {
+            cold_warmth_threshold: -5.,
+            hot_warmth_threshold: 5.,
+            onset_minutes: 45.,
+            recovery_rate: 2.
+        }
+    }
+}
+impl fmt::Display for ThermalConditionsConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Comfort {:.1}..{:.1}, onset in {:.0} min, recovery x{:.1}", self.cold_warmth_threshold,
+               self.hot_warmth_threshold, self.onset_minutes, self.recovery_rate)
+    }
+}
+
+/// Cold exposure disease: shivering, then hypothermia that slows the heart down, then a
+/// critical state with a chance of death. Goes back stage by stage once the character is warm
+/// again, and gets worse again in the cold
+pub struct ExposureHypothermia;
+impl Disease for ExposureHypothermia {
+    fn get_name(&self) -> String { HYPOTHERMIA_DISEASE.to_string() }
+    fn get_stages(&self) -> Vec<StageDescription> {
+        vec![
+            // Shivering
+            StageBuilder::start()
+                .build_for(StageLevel::InitialStage)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(36.)
+                        .with_target_heart_rate(95.)
+                        .with_target_blood_pressure(130., 85.)
+                        .will_reach_target_in(1.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.001)
+                        .food_level(0.0004)
+                    .affects_fatigue(10.)
+                    .no_death_probability()
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Progressing)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(34.8)
+                        .with_target_heart_rate(70.)
+                        .with_target_blood_pressure(120., 80.)
+                        .will_reach_target_in(2.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.002)
+                        .food_level(0.0005)
+                    .affects_fatigue(25.)
+                    .no_death_probability()
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Worrying)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(33.)
+                        .with_target_heart_rate(50.)
+                        .with_target_blood_pressure(105., 65.)
+                        .will_reach_target_in(2.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.004)
+                        .food_level(0.0006)
+                    .affects_fatigue(45.)
+                    .no_death_probability()
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Critical)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(30.5)
+                        .with_target_heart_rate(35.)
+                        .with_target_blood_pressure(85., 50.)
+                        .will_reach_target_in(3.)
+                        .will_last_forever()
+                    .drains()
+                        .stamina(0.006)
+                        .food_level(0.0008)
+                    .affects_fatigue(70.)
+                    .with_chance_of_death(1)
+                .build()
+        ]
+    }
+    fn get_treatment(&self) -> Option<Box<dyn DiseaseTreatment>> { None }
+    fn as_any(&self) -> &dyn Any { self }
+}
+
+/// Heat exposure disease: heavy sweating, then hyperthermia with fever and fast water loss,
+/// then a critical state with a chance of death. Goes back stage by stage once the character
+/// has cooled down, and gets worse again in the heat
+pub struct ExposureHyperthermia;
+impl Disease for ExposureHyperthermia {
+    fn get_name(&self) -> String { HYPERTHERMIA_DISEASE.to_string() }
+    fn get_stages(&self) -> Vec<StageDescription> {
+        vec![
+            // Heavy sweating
+            StageBuilder::start()
+                .build_for(StageLevel::InitialStage)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(37.6)
+                        .with_target_heart_rate(95.)
+                        .with_target_blood_pressure(125., 80.)
+                        .will_reach_target_in(1.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.001)
+                        .water_level(0.0008)
+                    .affects_fatigue(10.)
+                    .no_death_probability()
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Progressing)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(38.8)
+                        .with_target_heart_rate(110.)
+                        .with_target_blood_pressure(115., 75.)
+                        .will_reach_target_in(2.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.002)
+                        .water_level(0.0015)
+                    .affects_fatigue(25.)
+                    .no_death_probability()
+                    .with_nausea(30.)
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Worrying)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(40.)
+                        .with_target_heart_rate(130.)
+                        .with_target_blood_pressure(100., 65.)
+                        .will_reach_target_in(2.)
+                        .will_end()
+                    .drains()
+                        .stamina(0.004)
+                        .water_level(0.002)
+                    .affects_fatigue(45.)
+                    .no_death_probability()
+                    .with_nausea(60.)
+                .build(),
+
+            StageBuilder::start()
+                .build_for(StageLevel::Critical)
+                    .no_self_heal()
+                    .vitals()
+                        .with_target_body_temp(41.5)
+                        .with_target_heart_rate(150.)
+                        .with_target_blood_pressure(85., 50.)
+                        .will_reach_target_in(3.)
+                        .will_last_forever()
+                    .drains()
+                        .stamina(0.006)
+                        .water_level(0.003)
+                    .affects_fatigue(70.)
+                    .with_chance_of_death(1)
+                    .with_nausea(80.)
+                .build()
+        ]
+    }
+    fn get_treatment(&self) -> Option<Box<dyn DiseaseTreatment>> { None }
+    fn as_any(&self) -> &dyn Any { self }
+}
+
+impl Health {
+    /// Turns on the built-in thermal conditions. Sustained warmth level out of the comfort zone
+    /// spawns [`ExposureHypothermia`] or [`ExposureHyperthermia`]; their stages go forward while
+    /// the character stays out of the comfort zone and back when warmth returns to it.
+    /// [`HypothermiaStageChanged`](crate::utils::event::Event::HypothermiaStageChanged) and
+    /// [`HyperthermiaStageChanged`](crate::utils::event::Event::HyperthermiaStageChanged) events
+    /// are sent. Pass `None` to turn it off; diseases that already started are not removed
+    ///
+    /// # Parameters
+    /// - `config`: thermal conditions config
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::thermal::ThermalConditionsConfig;
+    ///
+    /// person.health.set_thermal_conditions(Some(ThermalConditionsConfig::default()));
+    /// ```
+    ///
+    /// ## Notes
+    /// Registers factories of the built-in diseases, so that
+    /// [`restore_full_state`](crate::ZaraController::restore_full_state) can recreate them
+    pub fn set_thermal_conditions(&self, config: Option<ThermalConditionsConfig>) {
+        if config.is_some() {
+            self.register_disease_factories(vec![
+                Box::new(|| Box::new(ExposureHypothermia)),
+                Box::new(|| Box::new(ExposureHyperthermia))
+            ]);
+        }
+
+        self.thermal_conditions.set(config);
+    }
+
+    /// Returns thermal conditions config, `None` if thermal conditions are off
+    ///
+    /// # Examples
+    /// ```
+    /// let config = person.health.thermal_conditions();
+    /// ```
+    pub fn thermal_conditions(&self) -> Option<ThermalConditionsConfig> { self.thermal_conditions.get() }
+
+    /// Returns game seconds of the sustained cold counted toward [`ExposureHypothermia`]
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.health.cold_exposure();
+    /// ```
+    pub fn cold_exposure(&self) -> f32 { self.cold_exposure.get() }
+
+    /// Returns game seconds of the sustained heat counted toward [`ExposureHyperthermia`]
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.health.heat_exposure();
+    /// ```
+    pub fn heat_exposure(&self) -> f32 { self.heat_exposure.get() }
+
+    /// Returns [`ExposureHypothermia`] stage as of the last `update` call, `None` if character
+    /// does not have it
+    ///
+    /// # Examples
+    /// ```
+    /// if person.health.hypothermia_stage() == Some(StageLevel::Critical) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn hypothermia_stage(&self) -> Option<StageLevel> { self.hypothermia_stage.get() }
+
+    /// Returns [`ExposureHyperthermia`] stage as of the last `update` call, `None` if character
+    /// does not have it
+    ///
+    /// # Examples
+    /// ```
+    /// let stage = person.health.hyperthermia_stage();
+    /// ```
+    pub fn hyperthermia_stage(&self) -> Option<StageLevel> { self.hyperthermia_stage.get() }
+
+    /// Builds up cold and heat exposure, spawns the thermal diseases and turns their stages
+    /// forward or back depending on the warmth level
+    pub(crate) fn check_thermal_conditions(&self, frame_data: &FrameSummaryC) {
+        let config = match self.thermal_conditions.get() {
+            Some(c) => c,
+            None => return
+        };
+        let warmth = frame_data.player.warmth_level;
+
+        self.check_exposure(&self.cold_exposure, warmth < config.cold_warmth_threshold,
+                            Box::new(ExposureHypothermia), &config, frame_data);
+        self.check_exposure(&self.heat_exposure, warmth > config.hot_warmth_threshold,
+                            Box::new(ExposureHyperthermia), &config, frame_data);
+
+        let cold = self.thermal_stage(HYPOTHERMIA_DISEASE, &frame_data.game_time);
+        let heat = self.thermal_stage(HYPERTHERMIA_DISEASE, &frame_data.game_time);
+
+        if self.hypothermia_stage.replace(cold) != cold {
+            self.queue_message(Event::HypothermiaStageChanged(cold));
+        }
+        if self.hyperthermia_stage.replace(heat) != heat {
+            self.queue_message(Event::HyperthermiaStageChanged(heat));
+        }
+    }
+
+    fn check_exposure(&self, exposure: &Cell<f32>, is_exposed: bool, disease: Box<dyn Disease>,
+                      config: &ThermalConditionsConfig, frame_data: &FrameSummaryC) {
+        let onset = config.onset_minutes * 60.;
+        let delta = if is_exposed {
+            frame_data.game_time_delta
+        } else {
+            -frame_data.game_time_delta * config.recovery_rate
+        };
+
+        exposure.set(clamp(exposure.get() + delta, 0., onset));
+
+        let active = self.active_diseases.borrow().get(&disease.get_id()).cloned();
+
+        match active {
+            Some(d) => {
+                // Stages go forward while exposed and back when not
+                if is_exposed && d.is_healing() {
+                    d.invert_back(&frame_data.game_time).ok();
+                } else if !is_exposed && !d.is_healing() {
+                    d.invert(&frame_data.game_time).ok();
+                }
+            },
+            None => {
+                if is_exposed && exposure.get() >= onset {
+                    self.spawn_disease(disease, frame_data.game_time).ok();
+                }
+            }
+        }
+    }
+
+    fn thermal_stage(&self, disease_id: &str, game_time: &GameTimeC) -> Option<StageLevel> {
+        self.active_diseases.borrow().get(disease_id).and_then(|d| d.active_level(game_time))
+    }
+}
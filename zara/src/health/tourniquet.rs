@@ -0,0 +1,42 @@
+use crate::health::Health;
+use crate::health::injury::Injury;
+use crate::body::BodyPart;
+use crate::utils::FrameSummaryC;
+
+/// Function that creates a tissue damage injury instance for a given limb
+pub type TissueDamageFactory = Box<dyn Fn(BodyPart) -> Box<dyn Injury>>;
+
+impl Health {
+    /// Sets a factory for the injury that will be spawned on a limb when tourniquet stays on it
+    /// for longer than its safe time. Pass `None` to never spawn tissue damage
+    ///
+    /// # Parameters
+    /// - `factory`: function that creates tissue damage injury for a given limb
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.set_tissue_damage_factory(Some(Box::new(|_| Box::new(TissueDamage))));
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Controlling-blood-loss) for more info.
+    pub fn set_tissue_damage_factory(&self, factory: Option<TissueDamageFactory>) {
+        self.tissue_damage_factory.replace(factory);
+    }
+
+    /// Spawns tissue damage injury on every limb where tourniquet safe time is exceeded
+    pub(crate) fn check_tissue_damage(&self, frame_data: &FrameSummaryC) {
+        let factory = self.tissue_damage_factory.borrow();
+        let factory = match factory.as_ref() {
+            Some(f) => f,
+            None => return
+        };
+
+        for t in frame_data.player.tourniquets.iter() {
+            if !t.is_expired(&frame_data.game_time) { continue; }
+
+            // Does nothing if this limb already has this injury
+            self.spawn_injury(factory(t.body_part), t.body_part, frame_data.game_time).ok();
+        }
+    }
+}
\ No newline at end of file
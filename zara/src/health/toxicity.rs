@@ -0,0 +1,168 @@
+use crate::health::Health;
+use crate::health::side::SideEffectDeltasC;
+use crate::inventory::items::ConsumableC;
+use crate::utils::{clamp, clamp_01, FrameSummaryC};
+use crate::utils::event::{Event, MessageQueue};
+use crate::state::DirtyTracked;
+
+use std::fmt;
+
+/// Heart rate added at the maximum toxicity, bpm
+const MAX_TOXICITY_HEART_RATE: f32 = 20.;
+/// Fatigue added at the maximum toxicity (0..100)
+const MAX_TOXICITY_FATIGUE: f32 = 30.;
+/// Body temperature added at the maximum toxicity, degrees C
+const MAX_TOXICITY_BODY_TEMP: f32 = 1.2;
+/// Water drain at the maximum toxicity, percent per game second
+const MAX_TOXICITY_WATER_DRAIN: f32 = 0.002;
+/// Stamina drain at the maximum toxicity, percent per game second
+const MAX_TOXICITY_STAMINA_DRAIN: f32 = 0.01;
+
+/// How poisoned the character is, by the toxicity level thresholds
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
+pub enum ToxicityStage {
+    /// Toxicity is below the mild threshold and has no effect
+    #[default]
+    Clean,
+    /// Faster heart rate and fatigue
+    Mild,
+    /// On top of that fever, water loss and weakness
+    Severe
+}
+impl fmt::Display for ToxicityStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// How toxicity goes away and at which levels it starts to affect vitals
+///
+/// # Examples
+/// ```
+/// use zara::health::toxicity::ToxicityConfig;
+///
+/// person.health.set_toxicity_config(ToxicityConfig {
+///     decay_per_hour: 4.,
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ToxicityConfig {
+    /// How much toxicity goes away per game hour (0..100)
+    pub decay_per_hour: f32,
+    /// Toxicity level at which the mild effects start
+    pub mild_threshold: f32,
+    /// Toxicity level at which the severe effects start
+    pub severe_threshold: f32
+}
+impl Default for ToxicityConfig {
+    fn default() -> Self {
+        ToxicityConfig {
+            decay_per_hour: 8.,
+            mild_threshold: 25.,
+            severe_threshold: 60.
+        }
+    }
+}
+impl fmt::Display for ToxicityConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Decay {:.1}/h, mild at {:.1}, severe at {:.1}", self.decay_per_hour, self.mild_threshold,
+               self.severe_threshold)
+    }
+}
+
+impl Health {
+    /// Current toxicity level (0..100). Raised by toxic and spoiled consumables and by the
+    /// medical agents, goes away over time
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.health.toxicity_level();
+    /// ```
+    pub fn toxicity_level(&self) -> f32 { self.toxicity_level.get() }
+
+    /// Toxicity stage as of the last `update` call
+    ///
+    /// # Examples
+    /// ```
+    /// if person.health.toxicity_stage() == ToxicityStage::Severe {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn toxicity_stage(&self) -> ToxicityStage { self.toxicity_stage.get() }
+
+    /// Raises (or lowers, with a negative amount) the toxicity level, like for a snake bite or
+    /// an antidote
+    ///
+    /// # Parameters
+    /// - `amount`: toxicity to add (0..100 scale)
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.add_toxicity(40.);
+    /// ```
+    pub fn add_toxicity(&self, amount: f32) {
+        self.toxicity_level.set(clamp(self.toxicity_level.get() + amount, 0., 100.));
+        self.mark_dirty();
+    }
+
+    /// Sets how toxicity goes away and at which levels it affects vitals
+    ///
+    /// # Parameters
+    /// - `config`: toxicity config
+    ///
+    /// # Examples
+    /// ```
+    /// person.health.set_toxicity_config(ToxicityConfig::default());
+    /// ```
+    pub fn set_toxicity_config(&self, config: ToxicityConfig) { self.toxicity_config.set(config); }
+
+    /// Returns toxicity config
+    ///
+    /// # Examples
+    /// ```
+    /// let config = person.health.toxicity_config();
+    /// ```
+    pub fn toxicity_config(&self) -> ToxicityConfig { self.toxicity_config.get() }
+
+    /// Adds toxicity of the consumed item
+    pub(crate) fn add_consumed_toxicity(&self, item: &ConsumableC) {
+        self.add_toxicity(item.toxicity_gain());
+    }
+
+    /// Adds toxicity from the medical agents, lets it decay and adds its effects to the side
+    /// effects deltas
+    pub(crate) fn apply_toxicity(&self, deltas: &mut SideEffectDeltasC, frame_data: &FrameSummaryC) {
+        let config = self.toxicity_config.get();
+        let hours = frame_data.game_time_delta / 3600.;
+        let change = self.agent_side_effects.get().toxicity - config.decay_per_hour;
+
+        self.add_toxicity(change * hours);
+
+        let level = self.toxicity_level.get();
+        let stage = if level >= config.severe_threshold {
+            ToxicityStage::Severe
+        } else if level >= config.mild_threshold {
+            ToxicityStage::Mild
+        } else {
+            ToxicityStage::Clean
+        };
+
+        if self.toxicity_stage.replace(stage) != stage {
+            self.queue_message(Event::ToxicityStageChanged(stage));
+        }
+
+        let mild = clamp_01((level - config.mild_threshold) / (100. - config.mild_threshold));
+        let severe = clamp_01((level - config.severe_threshold) / (100. - config.severe_threshold));
+
+        deltas.heart_rate_bonus += mild * MAX_TOXICITY_HEART_RATE;
+        deltas.body_temp_bonus += severe * MAX_TOXICITY_BODY_TEMP;
+        deltas.water_level_bonus -= severe * MAX_TOXICITY_WATER_DRAIN * frame_data.game_time_delta;
+        deltas.stamina_bonus -= severe * MAX_TOXICITY_STAMINA_DRAIN * frame_data.game_time_delta;
+
+        // Fatigue is frozen while sleeping
+        if !frame_data.player.is_sleeping {
+            deltas.fatigue_bonus += mild * MAX_TOXICITY_FATIGUE;
+        }
+    }
+}
@@ -1,11 +1,14 @@
-use crate::health::{Health, StageLevel, InjuryKey};
+use crate::health::{Health, StageLevel, InjuryKey, RemovalReason};
 use crate::health::side::{SideEffectDeltasC};
 use crate::health::disease::{DiseaseDeltasC};
-use crate::utils::{HealthC, FrameC, GameTimeC, FrameSummaryC};
+use crate::utils::{HealthC, FrameC, GameTimeC, FrameSummaryC, PlayerStatusC};
 use crate::utils::event::{Event, Listener, Dispatcher, MessageQueue};
 use crate::health::injury::{InjuryDeltasC};
+use crate::health::condition::{ActiveCondition, ConditionNeedsC, DAYS_IN_MONTH};
+use crate::state::DirtyTracked;
 
 use std::cell::RefMut;
+use std::rc::Rc;
 use std::collections::BTreeMap;
 
 struct ProcessDiseasesResult {
@@ -23,18 +26,28 @@ impl Health {
     /// # Parameters
     /// - `frame`: summary information for this frame
     pub(crate) fn update<E: Listener + 'static>(&self, frame: &mut FrameC<E>) {
+        self.mark_dirty();
+
         // Update disease monitors
-        for (_, monitor) in self.disease_monitors.borrow().iter() {
+        for (_, monitor) in self.disease_monitor_priorities.ordered(&self.disease_monitors.borrow()) {
             monitor.check(self, &frame.data);
         }
 
+        self.check_heat_exhaustion(frame.data);
+        self.check_thermal_conditions(frame.data);
+        self.check_nutrition(frame.data);
+        self.check_tissue_damage(frame.data);
+
         // Update medical agents
-        self.medical_agents.update(&frame.data.game_time);
+        self.medical_agents.update(self, &frame.data.game_time);
         if self.medical_agents.has_messages() {
             self.flush_queue(self.medical_agents.get_message_queue());
         }
 
-        let mut snapshot = HealthC::healthy();
+        // Stimulants hide fatigue while their agents are active
+        self.update_stimulants();
+
+        let mut snapshot = self.baseline();
 
         // Stamina, blood, oxygen, food and water are relative
         snapshot.stamina_level = self.stamina_level.get();
@@ -51,41 +64,62 @@ impl Health {
         // Retrieve side effects deltas
         let side_effects_summary = self.process_side_effects(&frame.data);
 
+        // Remember side effects rates for projections
+        self.remember_side_effects(&side_effects_summary, &frame.data.game_time, frame.data.game_time_delta);
+
         // Apply side effects deltas
         self.apply_deltas(&mut snapshot, &side_effects_summary);
 
         // Process diseases and get vitals deltas from them
-        let diseases_result = self.process_diseases(&frame.data.game_time, frame.data.game_time_delta);
+        let diseases_result = self.process_diseases(&frame.data.game_time, &frame.data.player, frame.data.game_time_delta);
 
-        // Apply disease deltas
-        self.apply_disease_deltas(&mut snapshot, &diseases_result.deltas);
+        // Process injuries and get drain and vitals deltas from them
+        let injuries_result = self.process_injuries(&frame.data.game_time, &frame.data.player, frame.data.game_time_delta);
 
-        // Process injuries and get drain deltas from them
-        let injuries_result = self.process_injuries(&frame.data.game_time, frame.data.game_time_delta);
+        // Finish procedure steps whose time has come
+        self.process_procedures(&frame.data.game_time);
+
+        // Apply disease deltas. Injuries affect vitals the same way diseases do
+        self.apply_disease_deltas(&mut snapshot,
+            &self.merge_vitals_deltas(&diseases_result.deltas, &injuries_result.deltas));
 
         // Apply injuries deltas
         self.apply_injury_deltas(&mut snapshot, &injuries_result.deltas);
 
+        // Progress long-term conditions and apply their needs
+        let condition_needs = self.process_conditions(&frame.data.game_time);
+        self.apply_condition_needs(&mut snapshot, &condition_needs, frame.data.game_time_delta);
+
         // Will always regain stamina. Side effects must "fight" it
         {
-            let value = snapshot.stamina_level + self.stamina_regain_rate.get() * frame.data.game_time_delta;
+            let value = snapshot.stamina_level + self.regain_rate(self.stamina_regain_rate.get()) *
+                self.stamina_recovery_factor() * frame.data.game_time_delta;
             snapshot.stamina_level = crate::utils::clamp(value, 0., 100.);
         }
         // Will always regain blood. Side effects must "fight" it
         {
-            let value = snapshot.blood_level + self.blood_regain_rate.get() * frame.data.game_time_delta;
+            let value = snapshot.blood_level + self.regain_rate(self.blood_regain_rate.get()) * frame.data.game_time_delta;
             snapshot.blood_level = crate::utils::clamp(value, 0., 100.);
         }
         // Will always regain oxygen. Side effects must "fight" it
         {
-            let value = snapshot.oxygen_level + self.oxygen_regain_rate.get() * frame.data.game_time_delta;
+            let value = snapshot.oxygen_level + self.regain_rate(self.oxygen_regain_rate.get()) *
+                self.oxygen_recovery_factor() * frame.data.game_time_delta;
             snapshot.oxygen_level = crate::utils::clamp(value, 0., 100.);
         }
 
+        self.progress_burst_recovery(frame.data.game_time_delta);
+
         // Apply the resulted health snapshot
         self.apply_health_snapshot(&snapshot);
 
-        self.has_blood_loss.set(injuries_result.blood_loss);
+        // Nausea can make character vomit
+        self.update_nausea(&frame.data.game_time, frame.data.game_time_delta);
+
+        self.has_blood_loss.set(injuries_result.blood_loss || diseases_result.deltas.blood_drain > 0.);
+
+        // Pain and conditions block actions
+        self.update_action_restrictions(&frame.data.game_time);
 
         // Do the external events
         self.dispatch_events::<E>(frame.events);
@@ -145,13 +179,18 @@ impl Health {
         if self.heart_rate.get() >= HEART_RATE_HIGH_DANGER {
             events.dispatch(Event::HighHeartRateDanger);
         }
+
+        self.dispatch_urgency_events(events);
     }
 
     fn process_side_effects(&self, frame_data: &FrameSummaryC) -> SideEffectDeltasC {
         let mut side_effects_summary: SideEffectDeltasC = SideEffectDeltasC::default();
 
         // Collect side effects data
-        for (_, side_effect) in self.side_effects.borrow().iter() {
+        for (key, side_effect) in self.side_effect_priorities.ordered(&self.side_effects.borrow()) {
+            // Monitors of the disabled packs are skipped
+            if self.disabled_side_effects.borrow().contains(&key) { continue; }
+
             let res = side_effect.check(frame_data);
 
             side_effects_summary.body_temp_bonus += res.body_temp_bonus;
@@ -169,32 +208,81 @@ impl Health {
             }
         }
 
+        self.apply_profile_to_side_effects(&mut side_effects_summary);
+
+        // Sweating
+        self.apply_heat_stress(&mut side_effects_summary, frame_data);
+
+        // Hot or cold food and drinks
+        self.apply_consumed_heat(&mut side_effects_summary, frame_data);
+
+        // Meals being digested
+        self.apply_digestion(&mut side_effects_summary, frame_data);
+
+        // Stimulant crashes
+        self.apply_fatigue_debt(&mut side_effects_summary, frame_data);
+
+        // Drowsy medicine
+        self.apply_agent_drowsiness(&mut side_effects_summary, frame_data);
+
+        // Poisons in the blood
+        self.apply_toxicity(&mut side_effects_summary, frame_data);
+
+        // Lost body parts make every move harder
+        if side_effects_summary.stamina_bonus < 0. && frame_data.player.mobility > 0. {
+            side_effects_summary.stamina_bonus /= frame_data.player.mobility;
+        }
+
         side_effects_summary
     }
 
-    fn process_diseases(&self, game_time: &GameTimeC, game_time_delta: f32) -> ProcessDiseasesResult {
+    fn remember_side_effects(&self, deltas: &SideEffectDeltasC, game_time: &GameTimeC, game_time_delta: f32) {
+        let mut rates = *deltas;
+
+        // Levels are relative, convert them to per game second rates
+        if game_time_delta > 0. {
+            rates.food_level_bonus /= game_time_delta;
+            rates.water_level_bonus /= game_time_delta;
+            rates.stamina_bonus /= game_time_delta;
+            rates.oxygen_level_bonus /= game_time_delta;
+        }
+
+        self.last_update_game_time.replace(*game_time);
+        self.last_side_effects.replace(rates);
+    }
+
+    fn process_diseases(&self, game_time: &GameTimeC, player: &PlayerStatusC, game_time_delta: f32) -> ProcessDiseasesResult {
         // Clean up garbage diseases
         let mut diseases_to_remove = Vec::new();
         {
-            let diseases = self.diseases.borrow();
+            let diseases = self.active_diseases.borrow();
             for (name, disease) in diseases.iter() {
+                // Paused healing must not let the disease expire
+                disease.process_healing_conditions(player, game_time_delta);
+                disease.note_active_level(game_time);
+
+                if let Some(stage) = disease.get_worst_stage() {
+                    if !disease.is_healing() && Health::peak_reached(&stage.peak_time, game_time, game_time_delta) {
+                        self.queue_message(Event::DiseaseReachedPeak(disease.disease.get_id(), stage.info.level));
+                    }
+                }
                 if disease.is_old(game_time) {
-                    self.queue_message(Event::DiseaseExpired(disease.disease.get_name()));
-                    diseases_to_remove.push(name.clone());
+                    self.queue_message(Event::DiseaseExpired(disease.disease.get_id()));
+                    diseases_to_remove.push((name.clone(), Health::expiry_reason(disease.is_healing())));
                 }
             }
         }
-        for disease_name in diseases_to_remove {
-            self.remove_disease(&disease_name).ok(); // we don't really care here
+        for (disease_name, reason) in diseases_to_remove {
+            self.remove_disease_for(&disease_name, reason).ok(); // we don't really care here
         }
 
-        let mut result = DiseaseDeltasC::for_related();
-
         // Collect disease deltas
         let mut disease_deltas = Vec::new();
         {
-            let diseases = self.diseases.borrow();
+            let diseases = self.active_diseases.borrow();
             for (disease_name, disease) in diseases.iter() {
+                disease.process_scheduled_invert(game_time);
+
                 // Move messages from diseases to the main queue for further processing
                 if disease.has_messages() {
                     self.flush_queue(disease.get_message_queue());
@@ -238,6 +326,15 @@ impl Health {
             }
         }
 
+        ProcessDiseasesResult {
+            deltas: self.combine_disease_deltas(&disease_deltas, game_time_delta)
+        }
+    }
+
+    /// Combines deltas of all active diseases into one. Drains are multiplied by `game_time_delta`
+    pub(crate) fn combine_disease_deltas(&self, disease_deltas: &[DiseaseDeltasC], game_time_delta: f32) -> DiseaseDeltasC {
+        let mut result = DiseaseDeltasC::for_related();
+
         // Normalize disease deltas
         for d in disease_deltas.iter() {
             result.body_temperature_delta =
@@ -259,45 +356,77 @@ impl Health {
             result.oxygen_drain += d.oxygen_drain * game_time_delta; // oxygen drain is cumulative
             result.food_drain += d.food_drain * game_time_delta; // food drain is cumulative
             result.water_drain += d.water_drain * game_time_delta; // water drain is cumulative
+            result.blood_drain += d.blood_drain * game_time_delta; // blood drain is cumulative
         }
 
         result.cleanup();
+        self.apply_profile_to_disease_deltas(&mut result);
 
-        ProcessDiseasesResult {
-            deltas: result
-        }
+        result
+    }
+
+    /// Healing chain that passed its lifetime was cured; a chain that was not healing just ran its course
+    fn expiry_reason(is_healing: bool) -> RemovalReason {
+        if is_healing { RemovalReason::Cured } else { RemovalReason::Expired }
+    }
+
+    /// Returns `true` if a stage peak was passed during the last frame
+    fn peak_reached(peak_time: &GameTimeC, game_time: &GameTimeC, game_time_delta: f32) -> bool {
+        if game_time_delta <= 0. { return false; }
+
+        let peak = peak_time.as_secs_f32();
+        let gt = game_time.as_secs_f32();
+
+        gt >= peak && gt - game_time_delta < peak
     }
 
-    fn process_injuries(&self, game_time: &GameTimeC, game_time_delta: f32) -> ProcessInjuriesResult {
+    fn process_injuries(&self, game_time: &GameTimeC, player: &PlayerStatusC, game_time_delta: f32) -> ProcessInjuriesResult {
         let mut blood_loss = false;
 
         // Clean up garbage injuries
         let mut injuries_to_remove = Vec::new();
         {
-            let injuries = self.injuries.borrow();
+            let injuries = self.active_injuries.borrow();
             for (key, injury) in injuries.iter() {
+                // Paused healing must not let the injury expire
+                injury.process_healing_conditions(player, game_time_delta);
+                injury.note_active_level(game_time);
+
+                if let Some(stage) = injury.get_worst_stage() {
+                    if !injury.is_healing() && Health::peak_reached(&stage.peak_time, game_time, game_time_delta) {
+                        self.queue_message(Event::InjuryReachedPeak(injury.injury.get_id(), key.body_part));
+                    }
+                }
                 if injury.is_old(game_time) {
-                    self.queue_message(Event::InjuryExpired(injury.injury.get_name(), key.body_part));
-                    injuries_to_remove.push(InjuryKey::new(key.injury.to_string(), key.body_part));
+                    self.queue_message(Event::InjuryExpired(injury.injury.get_id(), key.body_part));
+                    injuries_to_remove.push((InjuryKey::new(key.injury.to_string(), key.body_part),
+                                             Health::expiry_reason(injury.is_healing())));
                 }
             }
         }
-        for injury_key in injuries_to_remove {
-            self.remove_injury(injury_key.injury, injury_key.body_part).ok(); // we don't really care here
+        for (injury_key, reason) in injuries_to_remove {
+            self.remove_injury_for(injury_key.injury, injury_key.body_part, reason).ok(); // we don't really care here
         }
 
-        let mut result = InjuryDeltasC::for_related();
-
         // Collect injury deltas
         let mut injury_deltas = Vec::new();
         {
-            let injuries = self.injuries.borrow();
+            let injuries = self.active_injuries.borrow();
             for (_, injury) in injuries.iter() {
                 // Move messages from injuries to the main queue for further processing
                 if injury.has_messages() {
                     self.flush_queue(injury.get_message_queue());
                 }
                 if injury.is_active(game_time) {
+                    injury.body_part_in_use.set(player.is_using(injury.body_part));
+                    injury.activity_bleeding.set(if player.has_tourniquet_on(injury.body_part) {
+                        // Tourniquet stops all blood loss on its limb
+                        0.
+                    } else {
+                        self.bleeding_multiplier_for(injury, player)
+                    });
+                    self.update_burn_relief(injury, player);
+
                     let d = injury.get_drains_deltas(game_time);
 
                     if !injury.is_blood_stopped() && d.blood_drain > 0. { blood_loss = true; }
@@ -318,7 +447,7 @@ impl Health {
                                 self.is_alive.set(false);
 
                                 self.queue_message(Event::DeathFromInjury(
-                                    injury.injury.get_name().to_string(),
+                                    injury.injury.get_id().to_string(),
                                     injury.body_part
                                 ));
                             }
@@ -336,7 +465,7 @@ impl Health {
                                 // Invoke the healing process
                                 injury.invert(game_time).ok(); // aren't interested in result
                                 self.queue_message(Event::InjurySelfHealStarted(
-                                    injury.injury.get_name().to_string(),
+                                    injury.injury.get_id().to_string(),
                                     injury.body_part
                                 ));
                             }
@@ -346,19 +475,57 @@ impl Health {
             }
         }
 
+        ProcessInjuriesResult {
+            deltas: self.combine_injury_deltas(&injury_deltas, game_time_delta),
+            blood_loss
+        }
+    }
+
+    /// Combines deltas of all active injuries into one. Drains are multiplied by `game_time_delta`
+    pub(crate) fn combine_injury_deltas(&self, injury_deltas: &[InjuryDeltasC], game_time_delta: f32) -> InjuryDeltasC {
+        let mut result = InjuryDeltasC::for_related();
+
         // Normalize injury deltas
         for d in injury_deltas.iter() {
+            result.body_temperature_delta =
+                if result.body_temperature_delta < d.body_temperature_delta
+                { d.body_temperature_delta } else { result.body_temperature_delta };
+            result.heart_rate_delta =
+                if result.heart_rate_delta < d.heart_rate_delta
+                { d.heart_rate_delta } else { result.heart_rate_delta };
+            result.pressure_top_delta =
+                if result.pressure_top_delta < d.pressure_top_delta
+                { d.pressure_top_delta } else { result.pressure_top_delta };
+            result.pressure_bottom_delta =
+                if result.pressure_bottom_delta < d.pressure_bottom_delta
+                { d.pressure_bottom_delta } else { result.pressure_bottom_delta };
+            result.fatigue_delta += d.fatigue_delta; // fatigue is cumulative
+
             // Those are % per game second drains
             result.stamina_drain += d.stamina_drain * game_time_delta; // stamina drain is cumulative
             result.blood_drain += d.blood_drain * game_time_delta; // blood drain is cumulative
         }
 
         result.cleanup();
+        self.apply_profile_to_injury_deltas(&mut result);
 
-        ProcessInjuriesResult {
-            deltas: result,
-            blood_loss
-        }
+        result
+    }
+
+    /// Merges vitals deltas of injuries into the disease deltas. Same as between diseases, the
+    /// strongest vitals effect wins and fatigue is cumulative
+    pub(crate) fn merge_vitals_deltas(&self, disease_deltas: &DiseaseDeltasC, injury_deltas: &InjuryDeltasC) -> DiseaseDeltasC {
+        // Zero delta means that no active disease or injury affects this vital
+        let strongest = |a: f32, b: f32| if a == 0. { b } else if b == 0. { a } else { f32::max(a, b) };
+        let mut result = *disease_deltas;
+
+        result.body_temperature_delta = strongest(result.body_temperature_delta, injury_deltas.body_temperature_delta);
+        result.heart_rate_delta = strongest(result.heart_rate_delta, injury_deltas.heart_rate_delta);
+        result.pressure_top_delta = strongest(result.pressure_top_delta, injury_deltas.pressure_top_delta);
+        result.pressure_bottom_delta = strongest(result.pressure_bottom_delta, injury_deltas.pressure_bottom_delta);
+        result.fatigue_delta += injury_deltas.fatigue_delta;
+
+        result
     }
 
     fn apply_deltas(&self, snapshot: &mut HealthC, deltas: &SideEffectDeltasC) {
@@ -383,6 +550,7 @@ impl Health {
         snapshot.water_level -= deltas.water_drain;
         snapshot.stamina_level -= deltas.stamina_drain;
         snapshot.oxygen_level -= deltas.oxygen_drain;
+        snapshot.blood_level -= deltas.blood_drain;
     }
 
     fn apply_injury_deltas(&self, snapshot: &mut HealthC, deltas: &InjuryDeltasC) {
@@ -390,6 +558,63 @@ impl Health {
         snapshot.stamina_level -= deltas.stamina_drain;
     }
 
+    fn process_conditions(&self, game_time: &GameTimeC) -> ConditionNeedsC {
+        let mut result = ConditionNeedsC::default();
+        let mut ended = Vec::new();
+
+        // Hooks may access health node, so conditions collection must not be borrowed
+        let conditions: Vec<Rc<ActiveCondition>> = self.conditions.borrow().values().cloned().collect();
+
+        for condition in conditions.iter() {
+            if !condition.is_active(game_time) { continue; }
+
+            let day = condition.day(game_time);
+
+            while condition.last_day.get() < day {
+                let next = condition.last_day.get() + 1;
+
+                condition.last_day.set(next);
+                condition.condition.on_day(self, next);
+
+                if next % DAYS_IN_MONTH == 0 {
+                    condition.condition.on_month(self, next / DAYS_IN_MONTH);
+                }
+            }
+
+            if condition.is_over(game_time) {
+                ended.push(condition.condition.get_name());
+                continue;
+            }
+
+            let needs = condition.condition.needs(day);
+
+            result.food_drain += needs.food_drain;
+            result.water_drain += needs.water_drain;
+            result.stamina_drain += needs.stamina_drain;
+            result.fatigue_bonus += needs.fatigue_bonus;
+            result.heart_rate_bonus += needs.heart_rate_bonus;
+            result.body_temp_bonus += needs.body_temp_bonus;
+        }
+
+        for name in ended {
+            self.conditions.borrow_mut().remove(&name);
+            self.queue_message(Event::ConditionEnded(name));
+        }
+
+        result
+    }
+
+    fn apply_condition_needs(&self, snapshot: &mut HealthC, needs: &ConditionNeedsC, game_time_delta: f32) {
+        let metabolic_rate = self.profile().metabolic_rate;
+
+        snapshot.body_temperature += needs.body_temp_bonus;
+        snapshot.heart_rate += needs.heart_rate_bonus;
+        snapshot.fatigue_level += needs.fatigue_bonus;
+        snapshot.food_level -= needs.food_drain * metabolic_rate * game_time_delta;
+        snapshot.water_level -= needs.water_drain * metabolic_rate * game_time_delta;
+        snapshot.stamina_level -= needs.stamina_drain * game_time_delta;
+    }
+
     fn apply_health_snapshot(&self, snapshot: &HealthC) {
         self.body_temperature.set(snapshot.body_temperature);
         self.heart_rate.set(snapshot.heart_rate);
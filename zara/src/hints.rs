@@ -0,0 +1,286 @@
+use crate::ZaraController;
+use crate::utils::event::Listener;
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Heart rate (bpm) at which heartbeat is not heard
+const HEARTBEAT_QUIET_RATE: f32 = 70.;
+/// Heart rate (bpm) at which heartbeat is the loudest
+const HEARTBEAT_LOUDEST_RATE: f32 = 180.;
+/// Oxygen level (0..100) at which breathing is the heaviest
+const BREATHING_HEAVIEST_OXYGEN: f32 = 40.;
+/// Warmth level below which character starts to shiver
+const SHIVERING_START_WARMTH: f32 = -1.;
+/// Warmth level at which shivering is the strongest
+const SHIVERING_STRONGEST_WARMTH: f32 = -12.;
+
+/// Normalized intensities (0..1) for the audio cues, as of the last `update` call. `0` means
+/// cue should be silent, `1` means it should be the loudest
+///
+/// # Examples
+/// ```
+/// let hints = person.audio_hints();
+///
+/// heartbeat_sound.set_volume(hints.heartbeat);
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct AudioHints {
+    /// Heartbeat intensity, from the heart rate
+    pub heartbeat: f32,
+    /// Breath heaviness, from the oxygen and stamina levels
+    pub breathing: f32,
+    /// Shiver intensity, from the warmth level
+    pub shivering: f32
+}
+impl fmt::Display for AudioHints {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Heartbeat {:.2}, breathing {:.2}, shivering {:.2}", self.heartbeat, self.breathing, self.shivering)
+    }
+}
+impl Eq for AudioHints { }
+impl PartialEq for AudioHints {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        f32::abs(self.heartbeat - other.heartbeat) < EPS &&
+        f32::abs(self.breathing - other.breathing) < EPS &&
+        f32::abs(self.shivering - other.shivering) < EPS
+    }
+}
+impl Hash for AudioHints {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u32((self.heartbeat*10_000_f32) as u32);
+        state.write_u32((self.breathing*10_000_f32) as u32);
+        state.write_u32((self.shivering*10_000_f32) as u32);
+    }
+}
+
+/// Curve that maps a vital to a visual effect strength (0..1). Effect starts at `start`, is at
+/// full strength at `full`, and `exponent` bends the curve between them (`1` is linear, bigger
+/// values keep the effect weak longer)
+///
+/// # Examples
+/// ```
+/// use zara::hints::HintCurve;
+///
+/// let curve = HintCurve::new(20., 80., 2.);
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct HintCurve {
+    /// Value at which the effect starts
+    pub start: f32,
+    /// Value at which the effect is at full strength
+    pub full: f32,
+    /// Curve bend. `1` is linear
+    pub exponent: f32
+}
+impl HintCurve {
+    /// Creates new `HintCurve`
+    ///
+    /// # Parameters
+    /// - `start`: value at which the effect starts
+    /// - `full`: value at which the effect is at full strength
+    /// - `exponent`: curve bend, `1` is linear
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::hints::HintCurve;
+    ///
+    /// let curve = HintCurve::new(10., 90., 1.);
+    /// ```
+    pub fn new(start: f32, full: f32, exponent: f32) -> Self {
+        HintCurve { start, full, exponent }
+    }
+
+    /// Returns effect strength (0..1) for a given value
+    ///
+    /// # Parameters
+    /// - `value`: value of the vital
+    ///
+    /// # Examples
+    /// ```
+    /// let strength = curve.evaluate(pain);
+    /// ```
+    pub fn evaluate(&self, value: f32) -> f32 {
+        f32::powf(intensity(value, self.start, self.full), f32::max(self.exponent, 0.))
+    }
+}
+impl fmt::Display for HintCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1}..{:.1} ^{:.1}", self.start, self.full, self.exponent)
+    }
+}
+impl Eq for HintCurve { }
+impl PartialEq for HintCurve {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        f32::abs(self.start - other.start) < EPS &&
+        f32::abs(self.full - other.full) < EPS &&
+        f32::abs(self.exponent - other.exponent) < EPS
+    }
+}
+impl Hash for HintCurve {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_i32((self.start*10_000_f32) as i32);
+        state.write_i32((self.full*10_000_f32) as i32);
+        state.write_i32((self.exponent*10_000_f32) as i32);
+    }
+}
+
+/// Curves used to calculate [`VisualHintsC`]
+///
+/// # Examples
+/// ```
+/// use zara::hints::{VisualHintCurves, HintCurve};
+///
+/// person.set_visual_hint_curves(VisualHintCurves {
+///     frost: HintCurve::new(2., 10., 1.),
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VisualHintCurves {
+    /// Pain vignette from the pain level (0..100)
+    pub pain_vignette: HintCurve,
+    /// Blur from the fatigue level (0..100)
+    pub fatigue_blur: HintCurve,
+    /// Blur from the intoxication (0..100): nausea or drowsiness of the medical agents
+    pub intoxication_blur: HintCurve,
+    /// Desaturation from the lost blood (0..100)
+    pub blood_loss_desaturation: HintCurve,
+    /// Frost overlay from the cold: negative warmth level
+    pub frost: HintCurve
+}
+impl Default for VisualHintCurves {
+    fn default() -> Self {
+        VisualHintCurves {
+            pain_vignette: HintCurve::new(10., 90., 1.),
+            fatigue_blur: HintCurve::new(60., 100., 2.),
+            intoxication_blur: HintCurve::new(10., 80., 1.),
+            blood_loss_desaturation: HintCurve::new(10., 60., 1.),
+            frost: HintCurve::new(5., 20., 1.)
+        }
+    }
+}
+impl fmt::Display for VisualHintCurves {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Vignette {}, fatigue blur {}, intoxication blur {}, desaturation {}, frost {}",
+               self.pain_vignette, self.fatigue_blur, self.intoxication_blur, self.blood_loss_desaturation, self.frost)
+    }
+}
+
+/// Normalized strengths (0..1) of the screen effects, as of the last `update` call
+///
+/// # Examples
+/// ```
+/// let hints = person.visual_hints();
+///
+/// post_process.set_vignette(hints.vignette);
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct VisualHintsC {
+    /// Pain vignette strength
+    pub vignette: f32,
+    /// Blur strength, from fatigue or intoxication, whichever is stronger
+    pub blur: f32,
+    /// Desaturation strength, from the blood loss
+    pub desaturation: f32,
+    /// Frost overlay strength, from the cold
+    pub frost: f32
+}
+impl fmt::Display for VisualHintsC {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Vignette {:.2}, blur {:.2}, desaturation {:.2}, frost {:.2}",
+               self.vignette, self.blur, self.desaturation, self.frost)
+    }
+}
+impl Eq for VisualHintsC { }
+impl PartialEq for VisualHintsC {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        f32::abs(self.vignette - other.vignette) < EPS &&
+        f32::abs(self.blur - other.blur) < EPS &&
+        f32::abs(self.desaturation - other.desaturation) < EPS &&
+        f32::abs(self.frost - other.frost) < EPS
+    }
+}
+impl Hash for VisualHintsC {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u32((self.vignette*10_000_f32) as u32);
+        state.write_u32((self.blur*10_000_f32) as u32);
+        state.write_u32((self.desaturation*10_000_f32) as u32);
+        state.write_u32((self.frost*10_000_f32) as u32);
+    }
+}
+
+/// Maps a value to 0..1 between the point where it starts to matter and the point where it
+/// matters the most
+fn intensity(value: f32, start: f32, max: f32) -> f32 {
+    crate::utils::clamp_01((value - start) / (max - start))
+}
+
+impl<E: Listener + 'static> ZaraController<E> {
+    /// Returns normalized intensities for the audio cues, as of the last `update` call
+    ///
+    /// # Examples
+    /// ```
+    /// let breathing = person.audio_hints().breathing;
+    /// ```
+    pub fn audio_hints(&self) -> AudioHints { self.audio_hints.get() }
+
+    /// Returns normalized strengths of the screen effects, as of the last `update` call
+    ///
+    /// # Examples
+    /// ```
+    /// let frost = person.visual_hints().frost;
+    /// ```
+    pub fn visual_hints(&self) -> VisualHintsC { self.visual_hints.get() }
+
+    /// Sets curves used to calculate the screen effects
+    ///
+    /// # Parameters
+    /// - `curves`: visual hint curves
+    ///
+    /// # Examples
+    /// ```
+    /// person.set_visual_hint_curves(VisualHintCurves::default());
+    /// ```
+    pub fn set_visual_hint_curves(&self, curves: VisualHintCurves) { self.visual_hint_curves.set(curves); }
+
+    /// Returns curves used to calculate the screen effects
+    ///
+    /// # Examples
+    /// ```
+    /// let curves = person.visual_hint_curves();
+    /// ```
+    pub fn visual_hint_curves(&self) -> VisualHintCurves { self.visual_hint_curves.get() }
+
+    /// Recalculates audio cue intensities and screen effect strengths from the current vitals
+    pub(crate) fn update_hints(&self) {
+        let heartbeat = intensity(self.health.heart_rate(), HEARTBEAT_QUIET_RATE, HEARTBEAT_LOUDEST_RATE);
+        let breathing = f32::max(
+            intensity(-self.health.oxygen_level(), -100., -BREATHING_HEAVIEST_OXYGEN),
+            intensity(-self.health.stamina_level(), -100., 0.)
+        );
+        let shivering = intensity(-self.body.warmth_level(), -SHIVERING_START_WARMTH, -SHIVERING_STRONGEST_WARMTH);
+
+        self.audio_hints.set(AudioHints { heartbeat, breathing, shivering });
+
+        let curves = self.visual_hint_curves.get();
+        let side_effects = self.health.agent_side_effects();
+        let intoxication = f32::max(self.health.nausea_level(), side_effects.drowsiness);
+
+        self.visual_hints.set(VisualHintsC {
+            vignette: curves.pain_vignette.evaluate(self.health.pain_level()),
+            blur: f32::max(
+                curves.fatigue_blur.evaluate(self.health.fatigue_level()),
+                curves.intoxication_blur.evaluate(intoxication)
+            ),
+            desaturation: curves.blood_loss_desaturation.evaluate(100. - self.health.blood_level()),
+            frost: curves.frost.evaluate(-self.body.warmth_level())
+        });
+    }
+}
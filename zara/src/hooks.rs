@@ -0,0 +1,112 @@
+use crate::ZaraController;
+use crate::utils::FrameSummaryC;
+use crate::utils::event::Listener;
+
+use std::collections::BTreeMap;
+
+/// Callback called before Zara processes a frame. Can change the frame summary, like add
+/// [`extensions`](crate::utils::FrameSummaryC::extensions) for this frame
+pub type BeforeUpdateHook = Box<dyn FnMut(&mut FrameSummaryC)>;
+/// Callback called after Zara processed a frame, with the frame summary that was used
+pub type AfterUpdateHook = Box<dyn FnMut(&FrameSummaryC)>;
+
+/// Registered update hooks by their ids
+#[derive(Default)]
+pub(crate) struct UpdateHooks {
+    /// Id of the next registered hook
+    next_id: usize,
+    /// Hooks called before the frame
+    before: BTreeMap<usize, BeforeUpdateHook>,
+    /// Hooks called after the frame
+    after: BTreeMap<usize, AfterUpdateHook>
+}
+
+impl<E: Listener + 'static> ZaraController<E> {
+    /// Registers a callback that is called every time Zara processes a frame, before any node
+    /// is updated. `update` processes a frame about once a second, not on every call
+    ///
+    /// # Parameters
+    /// - `hook`: callback that receives the frame summary
+    ///
+    /// # Returns
+    /// Id of the hook, to remove it with [`remove_update_hook`](#method.remove_update_hook)
+    ///
+    /// # Examples
+    /// ```
+    /// person.on_before_update(Box::new(|frame| {
+    ///     frame.extensions.insert(Terrain { altitude: 2500. });
+    /// }));
+    /// ```
+    pub fn on_before_update(&self, hook: BeforeUpdateHook) -> usize {
+        let mut b = self.update_hooks.borrow_mut();
+        let id = b.next_id;
+
+        b.next_id += 1;
+        b.before.insert(id, hook);
+
+        id
+    }
+
+    /// Registers a callback that is called every time Zara processed a frame, after all nodes
+    /// were updated. `update` processes a frame about once a second, not on every call
+    ///
+    /// # Parameters
+    /// - `hook`: callback that receives the frame summary that was used
+    ///
+    /// # Returns
+    /// Id of the hook, to remove it with [`remove_update_hook`](#method.remove_update_hook)
+    ///
+    /// # Examples
+    /// ```
+    /// person.on_after_update(Box::new(|frame| {
+    ///     ai.sample(frame.health.heart_rate);
+    /// }));
+    /// ```
+    pub fn on_after_update(&self, hook: AfterUpdateHook) -> usize {
+        let mut b = self.update_hooks.borrow_mut();
+        let id = b.next_id;
+
+        b.next_id += 1;
+        b.after.insert(id, hook);
+
+        id
+    }
+
+    /// Removes an update hook. Returns `false` if hook with this id was not registered
+    ///
+    /// # Parameters
+    /// - `hook_id`: id returned by `on_before_update` or `on_after_update`
+    ///
+    /// # Examples
+    /// ```
+    /// person.remove_update_hook(hook_id);
+    /// ```
+    pub fn remove_update_hook(&self, hook_id: usize) -> bool {
+        let mut b = self.update_hooks.borrow_mut();
+
+        b.before.remove(&hook_id).is_some() || b.after.remove(&hook_id).is_some()
+    }
+
+    /// Calls hooks registered with `on_before_update`
+    pub(crate) fn run_before_update_hooks(&self, summary: &mut FrameSummaryC) {
+        // Hooks are taken out, so that they can register or remove other hooks
+        let mut hooks = std::mem::take(&mut self.update_hooks.borrow_mut().before);
+
+        for (_, hook) in hooks.iter_mut() {
+            hook(summary);
+        }
+
+        self.update_hooks.borrow_mut().before.append(&mut hooks);
+    }
+
+    /// Calls hooks registered with `on_after_update`
+    pub(crate) fn run_after_update_hooks(&self, summary: &FrameSummaryC) {
+        let mut hooks = std::mem::take(&mut self.update_hooks.borrow_mut().after);
+
+        for (_, hook) in hooks.iter_mut() {
+            hook(summary);
+        }
+
+        self.update_hooks.borrow_mut().after.append(&mut hooks);
+    }
+}
@@ -0,0 +1,138 @@
+use crate::inventory::Inventory;
+use crate::error::ItemCleanErr;
+use crate::state::DirtyTracked;
+
+use std::fmt;
+
+/// Chance of infection (0..100) when a completely dirty appliance is taken
+pub(crate) const DIRTY_APPLIANCE_INFECTION_CHANCE: f32 = 60.;
+/// Chance of infection (0..100) when a completely dirty item is consumed
+pub(crate) const DIRTY_CONSUMABLE_INFECTION_CHANCE: f32 = 30.;
+/// Chance of infection (0..100) when something is eaten with bloodied hands
+pub(crate) const BLOODIED_HANDS_INFECTION_CHANCE: f32 = 15.;
+/// Rinsing with water cannot make an item cleaner than this
+const WATER_CLEANLINESS_LIMIT: f32 = 80.;
+/// How much cleaner rinsing with water makes an item
+const WATER_CLEANLINESS_GAIN: f32 = 50.;
+
+/// How an inventory item is cleaned
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum CleaningMethod {
+    /// Rinsing with water. Makes item cleaner, but cannot fully sterilize it
+    Water,
+    /// Boiling. Fully sterilizes the item; player must be near a heat source
+    Boiling
+}
+impl fmt::Display for CleaningMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Inventory {
+    /// Returns cleanliness of an item kind (0..100, where 100 is perfectly clean). Items are
+    /// clean unless made dirty with [`set_cleanliness`](#method.set_cleanliness). `None` if
+    /// item was not found
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(value) = person.inventory.cleanliness_of(item_name) {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn cleanliness_of(&self, item_name: &String) -> Option<f32> {
+        if !self.has_item(item_name) { return None; }
+
+        Some(self.cleanliness.borrow().get(item_name).copied().unwrap_or(100.))
+    }
+
+    /// Sets cleanliness of an item kind, for example when a bandage was dropped into the mud.
+    /// Using dirty appliances or eating dirty food raises the infection chance passed to the
+    /// disease monitors and treatments
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    /// - `value`: cleanliness, 0..100, where 100 is perfectly clean
+    ///
+    /// # Examples
+    /// ```
+    /// person.inventory.set_cleanliness(item_name, 20.);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn set_cleanliness(&self, item_name: &String, value: f32) -> Result<(), ItemCleanErr> {
+        if !self.has_item(item_name) { return Err(ItemCleanErr::ItemNotFound); }
+
+        let value = crate::utils::clamp(value, 0., 100.);
+        let mut b = self.cleanliness.borrow_mut();
+
+        if value >= 100. {
+            b.remove(item_name);
+        } else {
+            b.insert(item_name.to_string(), value);
+        }
+        self.mark_dirty();
+
+        Ok(())
+    }
+
+    /// Cleans an item kind. Boiling also purifies water items (see
+    /// [`contamination_of`](#method.contamination_of))
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    /// - `method`: cleaning method
+    ///
+    /// # Returns
+    /// New item cleanliness (0..100)
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::inventory::cleanliness::CleaningMethod;
+    ///
+    /// let value = person.inventory.clean_item(item_name, CleaningMethod::Boiling);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn clean_item(&self, item_name: &String, method: CleaningMethod) -> Result<f32, ItemCleanErr> {
+        let current = match self.cleanliness_of(item_name) {
+            Some(c) => c,
+            None => return Err(ItemCleanErr::ItemNotFound)
+        };
+        let value = match method {
+            CleaningMethod::Water => f32::max(current, f32::min(current + WATER_CLEANLINESS_GAIN, WATER_CLEANLINESS_LIMIT)),
+            CleaningMethod::Boiling => {
+                if !self.player.is_near_heat_source.get() { return Err(ItemCleanErr::NoHeatSource); }
+
+                100.
+            }
+        };
+
+        self.set_cleanliness(item_name, value)?;
+
+        if method == CleaningMethod::Boiling {
+            // Fails for non-water items, and that is fine
+            self.set_contamination(item_name, 0.).ok();
+        }
+
+        Ok(value)
+    }
+
+    /// How dirty an item kind is, 0..1
+    pub(crate) fn dirtiness_of(&self, item_name: &String) -> f32 {
+        (100. - self.cleanliness.borrow().get(item_name).copied().unwrap_or(100.)) / 100.
+    }
+
+    /// Forgets cleanliness of an item kind that was removed from the inventory
+    pub(crate) fn forget_cleanliness(&self, item_name: &String) {
+        self.cleanliness.borrow_mut().remove(item_name);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,68 @@
+use crate::inventory::Inventory;
+use crate::error::ItemContaminationErr;
+use crate::state::DirtyTracked;
+
+impl Inventory {
+    /// Returns contamination of a water item kind (0..100, where 0 is perfectly clean water).
+    /// Water is as contaminated as its [`ConsumableDescription`](crate::inventory::items::ConsumableDescription)
+    /// says, unless changed with [`set_contamination`](#method.set_contamination) or boiled.
+    /// `None` if item was not found or is not water
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(value) = person.inventory.contamination_of(item_name) {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn contamination_of(&self, item_name: &String) -> Option<f32> {
+        let base = self.base_contamination_of(item_name)?;
+
+        Some(self.contamination.borrow().get(item_name).copied().unwrap_or(base))
+    }
+
+    /// Sets contamination of a water item kind, for example when a canteen was filled from
+    /// a muddy pond. Drinking contaminated water can cause a disease (see
+    /// [`set_dirty_water_factory`](crate::health::Health::set_dirty_water_factory))
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    /// - `value`: contamination, 0..100, where 0 is perfectly clean water
+    ///
+    /// # Examples
+    /// ```
+    /// person.inventory.set_contamination(item_name, 70.);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn set_contamination(&self, item_name: &String, value: f32) -> Result<(), ItemContaminationErr> {
+        if !self.has_item(item_name) { return Err(ItemContaminationErr::ItemNotFound); }
+        if self.base_contamination_of(item_name).is_none() { return Err(ItemContaminationErr::ItemIsNotWater); }
+
+        self.contamination.borrow_mut().insert(item_name.to_string(), crate::utils::clamp(value, 0., 100.));
+        self.mark_dirty();
+
+        Ok(())
+    }
+
+    /// Forgets contamination of an item kind that was removed from the inventory
+    pub(crate) fn forget_contamination(&self, item_name: &String) {
+        self.contamination.borrow_mut().remove(item_name);
+    }
+
+    /// Contamination given by the item description, if item is water
+    fn base_contamination_of(&self, item_name: &String) -> Option<f32> {
+        let items = self.stored_items.borrow();
+        let consumable = items.get(item_name)?.consumable()?;
+
+        if !consumable.is_water() { return None; }
+
+        Some(crate::utils::clamp(consumable.contamination(), 0., 100.))
+    }
+}
@@ -1,4 +1,4 @@
-use crate::inventory::crafting::{CraftingCombination, ItemInCombination, Builder};
+use crate::inventory::crafting::{CraftingCombination, ItemInCombination, Builder, RecipeConstraintsC};
 use crate::inventory::items::InventoryItem;
 
 /// Macro to describe crafting combination resulting instance creation.
@@ -49,6 +49,33 @@ pub trait BuilderStepItemNode {
 
 /// Crafting fluent step trait
 pub trait BuilderStepDone {
+    /// Sets human-facing name of this recipe. Optional.
+    ///
+    /// # Parameters
+    /// - `name`: display name
+    fn named(&self, name: &str) -> &dyn BuilderStepDone;
+    /// Sets human-facing description of this recipe. Optional.
+    ///
+    /// # Parameters
+    /// - `description`: recipe description
+    fn described_as(&self, description: &str) -> &dyn BuilderStepDone;
+    /// Sets category of this recipe (like "Tools" or "Food"). Optional.
+    ///
+    /// # Parameters
+    /// - `category`: category name
+    fn in_category(&self, category: &str) -> &dyn BuilderStepDone;
+    /// Sets how long crafting by this recipe takes. Optional. Zara does not advance game time
+    /// by itself, this is a hint for the game
+    ///
+    /// # Parameters
+    /// - `minutes`: crafting time, game minutes
+    fn takes_minutes(&self, minutes: f32) -> &dyn BuilderStepDone;
+    /// This recipe can be executed only in daylight. Optional.
+    fn needs_daylight(&self) -> &dyn BuilderStepDone;
+    /// This recipe can be executed in the rain only inside a shelter. Optional.
+    fn needs_shelter(&self) -> &dyn BuilderStepDone;
+    /// This recipe can be executed only near a heat source. Optional.
+    fn needs_heat_source(&self) -> &dyn BuilderStepDone;
     /// Builds the crafting combination based on the info provided.
     ///
     /// # Parameters
@@ -97,6 +124,48 @@ impl BuilderStepItemNode for Builder {
 }
 
 impl BuilderStepDone for Builder {
+    fn named(&self, name: &str) -> &dyn BuilderStepDone {
+        self.info.borrow_mut().display_name = Some(String::from(name));
+
+        self.as_builder_step_done()
+    }
+
+    fn described_as(&self, description: &str) -> &dyn BuilderStepDone {
+        self.info.borrow_mut().description = Some(String::from(description));
+
+        self.as_builder_step_done()
+    }
+
+    fn in_category(&self, category: &str) -> &dyn BuilderStepDone {
+        self.info.borrow_mut().category = Some(String::from(category));
+
+        self.as_builder_step_done()
+    }
+
+    fn takes_minutes(&self, minutes: f32) -> &dyn BuilderStepDone {
+        self.info.borrow_mut().crafting_time = Some(minutes);
+
+        self.as_builder_step_done()
+    }
+
+    fn needs_daylight(&self) -> &dyn BuilderStepDone {
+        self.constraints.set(RecipeConstraintsC { requires_daylight: true, ..self.constraints.get() });
+
+        self.as_builder_step_done()
+    }
+
+    fn needs_shelter(&self) -> &dyn BuilderStepDone {
+        self.constraints.set(RecipeConstraintsC { requires_shelter: true, ..self.constraints.get() });
+
+        self.as_builder_step_done()
+    }
+
+    fn needs_heat_source(&self) -> &dyn BuilderStepDone {
+        self.constraints.set(RecipeConstraintsC { requires_heat_source: true, ..self.constraints.get() });
+
+        self.as_builder_step_done()
+    }
+
     fn build(&self, create: Box<dyn Fn() -> Box<dyn InventoryItem> + 'static>) -> CraftingCombination {
         let mut items = Vec::new();
 
@@ -105,5 +174,7 @@ impl BuilderStepDone for Builder {
         }
 
         CraftingCombination::new(self.result_item.borrow().to_string(), items, create)
+            .with_info(self.info.borrow().clone())
+            .with_constraints(self.constraints.get())
     }
 }
\ No newline at end of file
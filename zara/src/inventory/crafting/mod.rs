@@ -1,14 +1,16 @@
-use crate::error::{CheckForResourcesErr, CombinationExecuteErr};
+use crate::error::{CheckForResourcesErr, CombinationExecuteErr, UnregisterCombinationErr, ReplaceCombinationErr,
+                   RecipeConstraintErr};
 use crate::inventory::crafting::fluent::BuilderStepResultItem;
-use crate::inventory::Inventory;
+use crate::inventory::{Inventory, InventoryChangeReason};
 use crate::inventory::items::InventoryItem;
 use crate::utils::event::{MessageQueue, Event};
 
 use std::rc::Rc;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
 
 mod fluent;
 
@@ -27,6 +29,9 @@ impl Inventory {
     ///                .is("SharpenStone", 1)
     ///                .plus("Stick", 3)
     ///                .and("Rope", 2)
+    ///                .named("Stone Axe")
+    ///                .in_category("Tools")
+    ///                .needs_daylight()
     ///            .build(zara::inv_result!(StoneAxe { count: 1 })),
     ///
     ///        crafting::Builder::start()
@@ -55,6 +60,174 @@ impl Inventory {
         }
     }
 
+    /// Unregisters a crafting combination
+    ///
+    /// # Parameters
+    /// - `combination_id`: unique key of a combination to unregister
+    ///
+    /// # Returns
+    /// Ok on success
+    ///
+    /// # Examples
+    /// ```
+    /// person.inventory.unregister_combination(combination_id);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Register-crafting-combinations) for more info.
+    pub fn unregister_combination(&self, combination_id: &String) -> Result<(), UnregisterCombinationErr> {
+        match self.crafting_combinations.borrow_mut().remove(combination_id) {
+            Some(_) => Ok(()),
+            None => Err(UnregisterCombinationErr::CombinationNotFound)
+        }
+    }
+
+    /// Replaces a registered crafting combination with a new one. Unique key of the new
+    /// combination may differ from the replaced one
+    ///
+    /// # Parameters
+    /// - `combination_id`: unique key of a combination to replace
+    /// - `combination`: new combination
+    ///
+    /// # Returns
+    /// Ok with the unique key of the new combination on success
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::inventory::crafting;
+    ///
+    /// let new_id = person.inventory.replace_combination(combination_id,
+    ///     crafting::Builder::start()
+    ///         .build_for("StoneAxe")
+    ///             .is("SharpenStone", 1)
+    ///             .and("Stick", 2)
+    ///         .build(zara::inv_result!(StoneAxe { count: 1 }))
+    /// );
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Register-crafting-combinations) for more info.
+    pub fn replace_combination(&self, combination_id: &String, combination: CraftingCombination) -> Result<String, ReplaceCombinationErr> {
+        let mut b = self.crafting_combinations.borrow_mut();
+
+        if b.remove(combination_id).is_none() {
+            return Err(ReplaceCombinationErr::CombinationNotFound);
+        }
+
+        let key = combination.unique_key.to_string();
+
+        b.insert(key.to_string(), combination);
+
+        Ok(key)
+    }
+
+    /// Unregisters all crafting combinations
+    ///
+    /// # Examples
+    /// ```
+    /// person.inventory.clear_crafting_combinations();
+    /// ```
+    pub fn clear_crafting_combinations(&self) {
+        self.crafting_combinations.borrow_mut().clear();
+    }
+
+    /// Returns structured descriptions of all registered crafting combinations, ordered
+    /// by result item name. Useful for building crafting menus
+    ///
+    /// # Examples
+    /// ```
+    /// for recipe in person.inventory.crafting_combinations() {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Finding-crafting-combinations) for more info.
+    pub fn crafting_combinations(&self) -> Vec<CombinationC> {
+        let mut result: Vec<CombinationC> = self.crafting_combinations.borrow().values()
+            .map(|c| c.to_contract())
+            .collect();
+
+        result.sort_by(|a, b| a.result_item.cmp(&b.result_item).then(a.unique_key.cmp(&b.unique_key)));
+
+        result
+    }
+
+    /// Returns structured description of a crafting combination. `None` if it is not registered
+    ///
+    /// # Parameters
+    /// - `combination_id`: unique key of a combination
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(recipe) = person.inventory.crafting_combination(combination_id) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn crafting_combination(&self, combination_id: &String) -> Option<CombinationC> {
+        self.crafting_combinations.borrow().get(combination_id).map(|c| c.to_contract())
+    }
+
+    /// Returns structured descriptions of all registered combinations that produce a given item
+    ///
+    /// # Parameters
+    /// - `result_item`: unique name of the resulting inventory item kind
+    ///
+    /// # Examples
+    /// ```
+    /// let recipes = person.inventory.combinations_producing(&format!("StoneAxe"));
+    /// ```
+    pub fn combinations_producing(&self, result_item: &String) -> Vec<CombinationC> {
+        self.crafting_combinations().into_iter().filter(|c| &c.result_item == result_item).collect()
+    }
+
+    /// Returns human-facing metadata of a crafting combination. `None` if it is not registered
+    ///
+    /// # Parameters
+    /// - `combination_id`: unique key of a combination
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(info) = person.inventory.recipe_info(combination_id) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn recipe_info(&self, combination_id: &String) -> Option<RecipeInfoC> {
+        self.crafting_combinations.borrow().get(combination_id).map(|c| c.info.clone())
+    }
+
+    /// Returns structured descriptions of all registered combinations of a given category
+    ///
+    /// # Parameters
+    /// - `category`: recipe category
+    ///
+    /// # Examples
+    /// ```
+    /// let tools = person.inventory.combinations_in_category("Tools");
+    /// ```
+    pub fn combinations_in_category(&self, category: &str) -> Vec<CombinationC> {
+        self.crafting_combinations().into_iter()
+            .filter(|c| c.info.category.as_deref() == Some(category))
+            .collect()
+    }
+
+    /// Returns all distinct recipe categories, ordered by name
+    ///
+    /// # Examples
+    /// ```
+    /// let categories = person.inventory.recipe_categories();
+    /// ```
+    pub fn recipe_categories(&self) -> Vec<String> {
+        let mut result: Vec<String> = self.crafting_combinations.borrow().values()
+            .filter_map(|c| c.info.category.clone())
+            .collect();
+
+        result.sort();
+        result.dedup();
+
+        result
+    }
+
     /// Returns a list of `combination unique keys` for the combinations that can be done
     /// using a set of passed items (**without checking for resources availability**)
     ///
@@ -100,11 +273,15 @@ impl Inventory {
         match self.crafting_combinations.borrow().get(combination_id) {
             Some(cmb) => {
                 for (name, item_data) in cmb.items.borrow().iter() {
-                    match self.items.borrow().get(name) {
+                    match self.stored_items.borrow().get(name) {
                         Some(item) => {
                             if !item.get_is_infinite() && item.get_count() < item_data.count {
                                 return Err(CheckForResourcesErr::InsufficientResources(name.to_string()));
                             }
+                            if !item.get_is_infinite() &&
+                                self.check_locked(name, item.get_count(), item_data.count).is_err() {
+                                return Err(CheckForResourcesErr::ItemLocked(name.to_string()));
+                            }
                         },
                         None => return Err(CheckForResourcesErr::ItemNotFound(name.to_string()))
                     }
@@ -116,8 +293,40 @@ impl Inventory {
         }
     }
 
+    /// Checks if environment and player state allow to execute a given combination right now
+    /// (see [`RecipeConstraintsC`](crate::inventory::crafting::RecipeConstraintsC))
+    ///
+    /// # Parameters
+    /// - `combination_id`: unique id of a combination to check
+    ///
+    /// # Examples
+    /// ```
+    /// let result = person.inventory.check_constraints(combination_id);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Crafting-resources-availability) for more info.
+    pub fn check_constraints(&self, combination_id: &String) -> Result<(), RecipeConstraintErr> {
+        let c = match self.crafting_combinations.borrow().get(combination_id) {
+            Some(cmb) => cmb.constraints,
+            None => return Err(RecipeConstraintErr::CombinationNotFound)
+        };
+
+        if c.requires_daylight && !self.environment.is_daylight() {
+            return Err(RecipeConstraintErr::NoDaylight);
+        }
+        if c.requires_shelter && self.environment.rain_intensity.get() > 0. && !self.player.is_inside_shelter.get() {
+            return Err(RecipeConstraintErr::NoShelter);
+        }
+        if c.requires_heat_source && !self.player.is_near_heat_source.get() {
+            return Err(RecipeConstraintErr::NoHeatSource);
+        }
+
+        Ok(())
+    }
+
     /// Executes given crafting combination. This method will check for resources availability
-    /// before trying.
+    /// and recipe constraints before trying.
     ///
     /// # Parameters
     /// - `combination_id`: unique key of a combination to execute
@@ -131,7 +340,7 @@ impl Inventory {
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Executing-crafting-combinations) for more info.
     /// 
     /// ## Notes
-    /// Borrows `items` collection
+    /// Borrows `items` and `skills.skills` collections
     pub fn execute_combination(&self, combination_id: &String) -> Result<(), CombinationExecuteErr> {
         let cc = self.crafting_combinations.borrow();
         let cmb = match cc.get(combination_id) {
@@ -140,28 +349,48 @@ impl Inventory {
         };
 
         self.check_for_resources(combination_id).or_else(|e| Err(CombinationExecuteErr::ResourceError(e)))?;
+        self.check_constraints(combination_id).map_err(CombinationExecuteErr::ConstraintError)?;
+        {
+            let b = self.stored_items.borrow();
+            for (key, _) in cmb.items.borrow().iter() {
+                // Items are there, we just checked resources
+                if let Some(item) = b.get(key) {
+                    self.check_item_requirements(item.as_ref())
+                        .map_err(|e| CombinationExecuteErr::RequirementsNotMet(key.to_string(), e))?;
+                }
+            }
+        }
         {
-            let mut b = self.items.borrow_mut();
+            let mut b = self.stored_items.borrow_mut();
             for (key, item_data) in cmb.items.borrow().iter() {
                 // Properly use the item. It should return ok because we just checked resources
-                self.use_item_internal(key, item_data.count, &mut b)
-                    .or_else(|e| Err(CombinationExecuteErr::UseItemError(e)))?;
+                self.use_item_internal(key, item_data.count, InventoryChangeReason::Crafted, &mut b)
+                    .map_err(CombinationExecuteErr::UseItemError)?;
             }
 
             let resulted = (cmb.create)();
-            match b.get_mut(&cmb.result_item) {
+            let (count, weight) = match b.get_mut(&cmb.result_item) {
                 Some(item) => {
                     // Increase count if we have item already
-                    item.set_count(item.get_count() + resulted.get_count())
+                    item.set_count(item.get_count() + resulted.get_count());
+
+                    (item.get_count(), item.get_total_weight())
                 },
                 None => {
                     // Add a new instance otherwise
+                    let result = (resulted.get_count(), resulted.get_total_weight());
+
                     b.insert(cmb.result_item.to_string(), resulted);
+
+                    result
                 }
-            }
+            };
+
+            self.queue_message(Event::InventoryItemAdded(cmb.result_item.to_string(), count, weight,
+                                                         InventoryChangeReason::Crafted));
         }
 
-        self.recalculate_weight();
+        self.recalculate_weight_for(InventoryChangeReason::Crafted);
         self.queue_message(Event::CraftingCombinationExecuted(combination_id.to_string()));
 
         Ok(())
@@ -205,6 +434,90 @@ impl ItemInCombination {
     }
 }
 
+/// Human-facing crafting recipe metadata. All fields are optional
+#[derive(Clone, Debug, Default)]
+pub struct RecipeInfoC {
+    /// Display name of the recipe
+    pub display_name: Option<String>,
+    /// Description of the recipe
+    pub description: Option<String>,
+    /// Category of the recipe (like "Tools" or "Food")
+    pub category: Option<String>,
+    /// How long crafting takes, game minutes
+    pub crafting_time: Option<f32>
+}
+impl fmt::Display for RecipeInfoC {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.display_name.as_deref().unwrap_or("-"), self.category.as_deref().unwrap_or("-"))
+    }
+}
+impl Eq for RecipeInfoC { }
+impl PartialEq for RecipeInfoC {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.display_name == other.display_name &&
+        self.description == other.description &&
+        self.category == other.category &&
+        match (self.crafting_time, other.crafting_time) {
+            (Some(a), Some(b)) => f32::abs(a - b) < EPS,
+            (None, None) => true,
+            _ => false
+        }
+    }
+}
+impl Hash for RecipeInfoC {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.display_name.hash(state);
+        self.description.hash(state);
+        self.category.hash(state);
+        if let Some(t) = self.crafting_time {
+            state.write_u32((t*10_000_f32) as u32);
+        }
+    }
+}
+
+/// Conditions under which a crafting combination can be executed. All are off by default
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
+pub struct RecipeConstraintsC {
+    /// Combination can be executed only in daylight
+    pub requires_daylight: bool,
+    /// Combination can be executed in the rain only inside a shelter
+    pub requires_shelter: bool,
+    /// Combination can be executed only near a heat source
+    pub requires_heat_source: bool
+}
+impl fmt::Display for RecipeConstraintsC {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "daylight: {}, shelter: {}, heat source: {}", self.requires_daylight,
+               self.requires_shelter, self.requires_heat_source)
+    }
+}
+
+/// Structured description of a crafting combination
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct CombinationC {
+    /// Unique key of this combination
+    pub unique_key: String,
+    /// Result item kind
+    pub result_item: String,
+    /// How many result items one execution produces
+    pub result_count: usize,
+    /// Items involved, ordered by name
+    pub items: Vec<ItemInCombination>,
+    /// Human-facing recipe metadata
+    pub info: RecipeInfoC,
+    /// Conditions under which this combination can be executed
+    pub constraints: RecipeConstraintsC
+}
+impl fmt::Display for CombinationC {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let items: Vec<String> = self.items.iter().map(|i| i.to_string()).collect();
+
+        write!(f, "{} of {} from {}", self.result_count, self.result_item, items.join(", "))
+    }
+}
+
 /// Describes crafting recipe
 pub struct CraftingCombination {
     /// Unique key of this combination
@@ -215,6 +528,10 @@ pub struct CraftingCombination {
     pub result_item: String,
     /// Items involved
     pub items: Rc<RefCell<HashMap<String, ItemInCombination>>>,
+    /// Human-facing recipe metadata
+    pub info: RecipeInfoC,
+    /// Conditions under which this combination can be executed
+    pub constraints: RecipeConstraintsC,
     /// Function to instantiate the resulted item (hello reflection :)
     create: Box<dyn Fn() -> Box<dyn InventoryItem> + 'static>
 }
@@ -225,6 +542,8 @@ impl Debug for CraftingCombination {
             .field("match_key", &self.match_key)
             .field("result_item", &self.result_item)
             .field("items", &self.items)
+            .field("info", &self.info)
+            .field("constraints", &self.constraints)
         .finish()
     }
 }
@@ -271,9 +590,74 @@ impl CraftingCombination {
             match_key: get_match_key(item_names).to_string(),
             result_item,
             items: Rc::new(RefCell::new(mapped)),
+            info: RecipeInfoC::default(),
+            constraints: RecipeConstraintsC::default(),
             create
         }
     }
+
+    /// Sets human-facing metadata of this combination
+    ///
+    /// # Parameters
+    /// - `info`: recipe metadata
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::inventory::crafting::{CraftingCombination, RecipeInfoC};
+    ///
+    /// let o = CraftingCombination::new(result_item, items, create)
+    ///     .with_info(RecipeInfoC {
+    ///         display_name: Some(format!("Stone Axe")),
+    ///         ..Default::default()
+    ///     });
+    /// ```
+    pub fn with_info(mut self, info: RecipeInfoC) -> Self {
+        self.info = info;
+
+        self
+    }
+
+    /// Sets conditions under which this combination can be executed
+    ///
+    /// # Parameters
+    /// - `constraints`: recipe constraints
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::inventory::crafting::{CraftingCombination, RecipeConstraintsC};
+    ///
+    /// let o = CraftingCombination::new(result_item, items, create)
+    ///     .with_constraints(RecipeConstraintsC {
+    ///         requires_heat_source: true,
+    ///         ..Default::default()
+    ///     });
+    /// ```
+    pub fn with_constraints(mut self, constraints: RecipeConstraintsC) -> Self {
+        self.constraints = constraints;
+
+        self
+    }
+
+    /// Returns structured description of this combination
+    ///
+    /// # Examples
+    /// ```
+    /// let recipe = combination.to_contract();
+    /// ```
+    pub fn to_contract(&self) -> CombinationC {
+        let mut items: Vec<ItemInCombination> = self.items.borrow().values().cloned().collect();
+
+        items.sort();
+
+        CombinationC {
+            unique_key: self.unique_key.to_string(),
+            result_item: self.result_item.to_string(),
+            result_count: (self.create)().get_count(),
+            items,
+            info: self.info.clone(),
+            constraints: self.constraints
+        }
+    }
 }
 
 /// Used to build a crafting combination (crafting reciepe)
@@ -296,7 +680,9 @@ impl CraftingCombination {
 /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Register-crafting-combinations) for more info.
 pub struct Builder {
     result_item: RefCell<String>,
-    items: Rc<RefCell<Vec<ItemInCombination>>>
+    items: Rc<RefCell<Vec<ItemInCombination>>>,
+    info: RefCell<RecipeInfoC>,
+    constraints: Cell<RecipeConstraintsC>
 }
 
 impl Builder {
@@ -304,7 +690,9 @@ impl Builder {
     pub fn start() -> Box<dyn BuilderStepResultItem> {
         Box::new(Builder {
             result_item: RefCell::new(String::new()),
-            items: Rc::new(RefCell::new(Vec::new()))
+            items: Rc::new(RefCell::new(Vec::new())),
+            info: RefCell::new(RecipeInfoC::default()),
+            constraints: Cell::new(RecipeConstraintsC::default())
         })
     }
 }
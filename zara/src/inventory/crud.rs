@@ -1,6 +1,7 @@
-use crate::inventory::Inventory;
+use crate::inventory::{Inventory, InventoryChangeReason};
 use crate::inventory::items::InventoryItem;
-use crate::error::InventoryItemAccessErr;
+use crate::inventory::deferred::DeferredItemAction;
+use crate::error::{InventoryItemAccessErr, ItemDuplicateErr};
 use crate::utils::event::{MessageQueue, Event};
 
 impl Inventory {
@@ -17,7 +18,7 @@ impl Inventory {
     /// # Links
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Inventory) for more info.
     pub fn has_item(&self, item_name: &String) -> bool {
-        self.items.borrow().contains_key(item_name)
+        self.stored_items.borrow().contains_key(item_name)
     }
 
     /// Adds new item to the inventory and recalculates inventory weight
@@ -34,15 +35,27 @@ impl Inventory {
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Inventory) for more info.
     /// 
     /// ## Notes
-    /// Borrows the `items` collection
+    /// Borrows the `items` collection. When called while `items` collection is borrowed (for
+    /// example, from a disease `on_consumed` callback), item is added right after that callback returns
     pub fn add_item(&self, item: Box<dyn InventoryItem>) {
-        let key = item.get_name();
+        self.add_item_for(item, InventoryChangeReason::Added);
+    }
+
+    /// Adds new item to the inventory, sending a given reason with the inventory events
+    pub(crate) fn add_item_for(&self, item: Box<dyn InventoryItem>, reason: InventoryChangeReason) {
+        let key = item.get_id();
         let key_for_message = key.clone();
+        let count = item.get_count();
+        let weight = item.get_total_weight();
+
+        match self.stored_items.try_borrow_mut() {
+            Ok(mut b) => { b.insert(key, item); },
+            Err(_) => return self.defer(DeferredItemAction::Add(item, reason))
+        }
 
-        self.items.borrow_mut().insert(key, item);
-        self.recalculate_weight();
+        self.recalculate_weight_for(reason);
 
-        self.queue_message(Event::InventoryItemAdded(key_for_message));
+        self.queue_message(Event::InventoryItemAdded(key_for_message, count, weight, reason));
     }
 
     /// Removes item kind from the inventory and recalculates inventory weight
@@ -62,20 +75,151 @@ impl Inventory {
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Inventory) for more info.
     ///
     /// ## Notes
-    /// Borrows the `items` collection
+    /// Borrows the `items` collection. When called while `items` collection is borrowed (for
+    /// example, from a disease `on_consumed` callback), item is removed right after that callback returns
     pub fn remove_item(&self, item_kind: &String) -> Result<(), InventoryItemAccessErr> {
-        let mut b = self.items.borrow_mut();
+        {
+            let mut b = match self.stored_items.try_borrow_mut() {
+                Ok(b) => b,
+                Err(_) => {
+                    if !self.has_item(item_kind) && !self.is_deferred_add(item_kind) {
+                        return Err(InventoryItemAccessErr::ItemNotFound);
+                    }
+
+                    self.defer(DeferredItemAction::Remove(item_kind.to_string()));
+
+                    return Ok(());
+                }
+            };
+
+            if b.remove(item_kind).is_none() { return Err(InventoryItemAccessErr::ItemNotFound); }
+        }
+
+        self.forget_item(item_kind);
+
+        self.recalculate_weight_for(InventoryChangeReason::Removed);
+
+        self.queue_message(Event::InventoryItemRemoved(item_kind.to_string(), InventoryChangeReason::Removed));
+
+        Ok(())
+    }
+
+    /// Drops everything inventory remembers about a removed item kind
+    pub(crate) fn forget_item(&self, item_kind: &String) {
+        self.forget_cleanliness(item_kind);
+        self.forget_reservation(item_kind);
+        self.forget_wetness(item_kind);
+        self.forget_age(item_kind);
+        self.forget_contamination(item_kind);
+        self.forget_light(item_kind);
+        self.forget_charge(item_kind);
+        self.forget_equipment(item_kind);
+    }
+
+    /// Removes all item kinds that match a predicate, like quest items on a new chapter.
+    /// [`InventoryItemRemoved`](crate::utils::event::Event::InventoryItemRemoved) events are sent
+    /// for each of them
+    ///
+    /// # Parameters
+    /// - `predicate`: returns `true` for the items to remove
+    ///
+    /// # Returns
+    /// Unique names of the removed items
+    ///
+    /// # Examples
+    /// ```
+    /// let removed = person.inventory.remove_items_where(|item| item.get_name().starts_with("Quest"));
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Inventory) for more info.
+    ///
+    /// ## Notes
+    /// Borrows the `items` collection. When called while `items` collection is borrowed (for
+    /// example, from a disease `on_consumed` callback), items are removed right after that
+    /// callback returns
+    pub fn remove_items_where<F: FnMut(&dyn InventoryItem) -> bool>(&self, mut predicate: F) -> Vec<String> {
+        let mut names: Vec<String> = self.stored_items.borrow().iter()
+            .filter(|(_, item)| predicate(item.as_ref()))
+            .map(|(name, _)| name.to_string())
+            .collect();
+
+        names.sort();
+        names.into_iter().filter(|name| self.remove_item(name).is_ok()).collect()
+    }
 
-        if b.contains_key(item_kind) {
-            b.remove(item_kind);
+    /// Creates a copy of an inventory item with a given count. Inventory is not changed. Useful
+    /// for loot generation and moving items between inventories
+    ///
+    /// # Parameters
+    /// - `item_kind`: unique name of the item (`"InventoryItem.name"`)
+    /// - `count`: count of the copy
+    ///
+    /// # Returns
+    /// Boxed copy of the item on success
+    ///
+    /// # Examples
+    /// ```
+    /// let loot = person.inventory.duplicate_item(item_name, 2);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Inventory) for more info.
+    ///
+    /// ## Notes
+    /// Borrows the `items` collection
+    pub fn duplicate_item(&self, item_kind: &String, count: usize) -> Result<Box<dyn InventoryItem>, ItemDuplicateErr> {
+        let b = self.stored_items.borrow();
+        let item = match b.get(item_kind) {
+            Some(o) => o,
+            None => return Err(ItemDuplicateErr::ItemNotFound)
+        };
+        let mut copy = match item.clone_boxed() {
+            Some(o) => o,
+            None => return Err(ItemDuplicateErr::ItemIsNotCloneable)
+        };
 
-            self.recalculate_weight();
+        copy.set_count(count);
 
-            self.queue_message(Event::InventoryItemRemoved(item_kind.to_string()));
+        Ok(copy)
+    }
 
-            Ok(())
-        } else {
-            Err(InventoryItemAccessErr::ItemNotFound)
+    /// Creates copies of all inventory items that can be duplicated. Inventory is not changed
+    ///
+    /// # Examples
+    /// ```
+    /// let snapshot = person.inventory.clone_items();
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Inventory) for more info.
+    ///
+    /// ## Notes
+    /// Borrows the `items` collection
+    pub fn clone_items(&self) -> Vec<Box<dyn InventoryItem>> {
+        self.stored_items.borrow().values().filter_map(|x| x.clone_boxed()).collect()
+    }
+
+    /// Removes all items from the inventory
+    pub(crate) fn clear_items(&self) {
+        let names: Vec<String> = {
+            let mut b = self.stored_items.borrow_mut();
+            let names = b.keys().cloned().collect();
+
+            b.clear();
+
+            names
+        };
+
+        self.cleanliness.borrow_mut().clear();
+        self.reservations.borrow_mut().clear();
+        self.wet_clothes.borrow_mut().clear();
+        self.equipment_cache.borrow_mut().clear();
+
+        for name in names {
+            self.queue_message(Event::InventoryItemRemoved(name, InventoryChangeReason::Removed));
         }
+
+        self.update_clothes_cache(Vec::new());
     }
 }
\ No newline at end of file
@@ -0,0 +1,55 @@
+use crate::inventory::{Inventory, InventoryChangeReason};
+use crate::inventory::items::InventoryItem;
+
+/// Inventory change requested while the `items` collection was borrowed (for example,
+/// from a health callback that received the items map). Is applied after the borrow ends
+pub(crate) enum DeferredItemAction {
+    /// Add a new item
+    Add(Box<dyn InventoryItem>, InventoryChangeReason),
+    /// Remove an item kind
+    Remove(String),
+    /// Use given amount of an item kind
+    Use(String, usize, InventoryChangeReason)
+}
+
+impl Inventory {
+    /// Queues inventory change to be applied after the current `items` borrow ends
+    pub(crate) fn defer(&self, action: DeferredItemAction) {
+        self.deferred_actions.borrow_mut().push(action);
+    }
+
+    /// How many items of a given kind are already queued to be used
+    pub(crate) fn deferred_use_of(&self, name: &String) -> usize {
+        self.deferred_actions.borrow().iter().map(|a| match a {
+            DeferredItemAction::Use(n, amount, _) if n == name => *amount,
+            _ => 0
+        }).sum()
+    }
+
+    /// Is an item of a given kind queued to be added
+    pub(crate) fn is_deferred_add(&self, name: &String) -> bool {
+        self.deferred_actions.borrow().iter().any(|a| match a {
+            DeferredItemAction::Add(item, _) => &item.get_id() == name,
+            _ => false
+        })
+    }
+
+    /// Applies inventory changes that were requested while `items` collection was borrowed.
+    /// Is called by the controller right after it releases the borrow
+    pub(crate) fn apply_deferred(&self) {
+        loop {
+            let actions: Vec<DeferredItemAction> = self.deferred_actions.borrow_mut().drain(..).collect();
+
+            if actions.is_empty() { return; }
+
+            for action in actions {
+                match action {
+                    DeferredItemAction::Add(item, reason) => self.add_item_for(item, reason),
+                    // Was valid when requested; item could be removed by an earlier action since then
+                    DeferredItemAction::Remove(name) => { self.remove_item(&name).ok(); },
+                    DeferredItemAction::Use(name, amount, reason) => { self.use_item_for(&name, amount, reason).ok(); }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use crate::inventory::Inventory;
+use crate::utils::event::{Event, MessageQueue};
+use crate::error::{ElectronicErr, RechargeErr};
+use crate::state::DirtyTracked;
+
+/// Full charge of an electronic device
+const FULL_CHARGE: f32 = 100.;
+
+impl Inventory {
+    /// Turns an electronic device (see
+    /// [`ElectronicDescription`](crate::inventory::items::ElectronicDescription)) on or off.
+    /// Device that is on drains its charge with the game time and turns off when it runs out
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the electronic item (item kind)
+    /// - `on`: `true` to turn it on, `false` to turn it off
+    ///
+    /// # Examples
+    /// ```
+    /// person.inventory.set_active_electronic(&flashlight_name, true);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn set_active_electronic(&self, item_name: &String, on: bool) -> Result<(), ElectronicErr> {
+        match self.stored_items.borrow().get(item_name) {
+            Some(o) => if o.electronic().is_none() { return Err(ElectronicErr::ItemIsNotElectronic); },
+            None => return Err(ElectronicErr::ItemNotFound)
+        };
+
+        if on && self.charge_of(item_name) <= 0. { return Err(ElectronicErr::NoCharge); }
+
+        let mut b = self.active_electronics.borrow_mut();
+
+        b.retain(|x| x != item_name);
+        if on { b.push(item_name.to_string()); }
+        self.mark_dirty();
+
+        Ok(())
+    }
+
+    /// Returns `true` if an electronic device is on
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the electronic item (item kind)
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.inventory.is_electronic_on(&radio_name);
+    /// ```
+    pub fn is_electronic_on(&self, item_name: &String) -> bool {
+        self.active_electronics.borrow().contains(item_name)
+    }
+
+    /// Returns charge level (0..100) of an electronic device. `None` if item was not found or
+    /// is not electronic
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the electronic item (item kind)
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(charge) = person.inventory.charge_level(&gps_name) {
+    ///     // Show battery indicator
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn charge_level(&self, item_name: &String) -> Option<f32> {
+        self.stored_items.borrow().get(item_name)?.electronic()?;
+
+        Some(self.charge_of(item_name))
+    }
+
+    /// Recharges an electronic device from a charge source (see
+    /// [`ChargeSourceDescription`](crate::inventory::items::ChargeSourceDescription)). Source
+    /// must be listed in the device `charge_sources`. Sources that are used up lose one unit
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the electronic item (item kind)
+    /// - `source_name`: unique name of the charge source item (item kind)
+    ///
+    /// # Returns
+    /// Charge level (0..100) of the device after recharging
+    ///
+    /// # Examples
+    /// ```
+    /// person.inventory.recharge(&flashlight_name, &batteries_name);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn recharge(&self, item_name: &String, source_name: &String) -> Result<f32, RechargeErr> {
+        let (amount, consumed) = {
+            let items = self.stored_items.borrow();
+            let electronic = match items.get(item_name) {
+                Some(o) => match o.electronic() {
+                    Some(e) => e,
+                    None => return Err(RechargeErr::ItemIsNotElectronic)
+                },
+                None => return Err(RechargeErr::ItemNotFound)
+            };
+            let source = match items.get(source_name) {
+                Some(o) => match o.charge_source() {
+                    Some(s) => s,
+                    None => return Err(RechargeErr::SourceNotCompatible)
+                },
+                None => return Err(RechargeErr::SourceNotFound)
+            };
+
+            if !electronic.charge_sources().contains(source_name) { return Err(RechargeErr::SourceNotCompatible); }
+
+            (f32::max(source.charge_amount(), 0.), source.consumed_on_charge())
+        };
+
+        let charge = self.charge_of(item_name);
+
+        if charge >= FULL_CHARGE { return Err(RechargeErr::FullyCharged); }
+        if consumed {
+            self.use_item(source_name, 1).map_err(RechargeErr::CouldNotUseItem)?;
+        }
+
+        let charge = f32::min(charge + amount, FULL_CHARGE);
+
+        self.set_charge(item_name, charge);
+
+        Ok(charge)
+    }
+
+    /// Drains charge of the electronic devices that are on
+    pub(crate) fn update_electronics(&self, game_time_delta: f32) {
+        self.mark_dirty();
+
+        if self.active_electronics.borrow().is_empty() { return; }
+
+        let mut depleted = Vec::new();
+        {
+            let items = self.stored_items.borrow();
+
+            for name in self.active_electronics.borrow().iter() {
+                let electronic = match items.get(name).and_then(|o| o.electronic()) {
+                    Some(e) => e,
+                    None => continue
+                };
+                let drain = f32::max(electronic.drain_rate(), 0.) * f32::max(game_time_delta, 0.) / 3600.;
+                let charge = f32::max(self.charge_of(name) - drain, 0.);
+
+                self.set_charge(name, charge);
+
+                if charge <= 0. { depleted.push(name.to_string()); }
+            }
+        }
+
+        for name in depleted {
+            self.active_electronics.borrow_mut().retain(|x| *x != name);
+            self.queue_message(Event::ChargeDepleted(name));
+        }
+    }
+
+    /// Forgets charge state of an item kind that was removed from the inventory
+    pub(crate) fn forget_charge(&self, item_name: &String) {
+        self.active_electronics.borrow_mut().retain(|x| x != item_name);
+        self.charges.borrow_mut().remove(item_name);
+    }
+
+    fn charge_of(&self, item_name: &String) -> f32 {
+        self.charges.borrow().get(item_name).copied().unwrap_or(FULL_CHARGE)
+    }
+
+    fn set_charge(&self, item_name: &String, charge: f32) {
+        self.mark_dirty();
+
+        let mut b = self.charges.borrow_mut();
+
+        if charge >= FULL_CHARGE {
+            b.remove(item_name);
+        } else {
+            b.insert(item_name.to_string(), charge);
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use crate::inventory::Inventory;
+use crate::inventory::items::InventoryItem;
+use crate::error::ItemEquipErr;
+
+impl Inventory {
+    /// Puts one unit of an item kind into the character hands (or on the back), like a weapon,
+    /// a torch or a backpack. Equipped unit is locked like worn clothes, and its weight counts
+    /// toward the inventory weight with the
+    /// [`equipped_weight_factor`](#method.equipped_weight_factor)
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    ///
+    /// # Examples
+    /// ```
+    /// person.inventory.equip_item(&"Rifle".to_string());
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn equip_item(&self, item_name: &String) -> Result<(), ItemEquipErr> {
+        if self.is_equipped(item_name) { return Err(ItemEquipErr::AlreadyEquipped); }
+
+        match self.available_count_of(item_name) {
+            Some(0) => return Err(ItemEquipErr::ItemLocked),
+            Some(_) => { },
+            None => return Err(ItemEquipErr::ItemNotFound)
+        };
+
+        let mut new_equipment = self.equipment_cache.borrow().clone();
+
+        new_equipment.push(item_name.to_string());
+
+        self.update_equipment_cache(new_equipment);
+
+        Ok(())
+    }
+
+    /// Puts an equipped item back into the inventory
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    ///
+    /// # Examples
+    /// ```
+    /// person.inventory.unequip_item(&"Rifle".to_string());
+    /// ```
+    pub fn unequip_item(&self, item_name: &String) -> Result<(), ItemEquipErr> {
+        if !self.is_equipped(item_name) { return Err(ItemEquipErr::NotEquipped); }
+
+        let new_equipment = self.equipment_cache.borrow().iter()
+            .filter(|n| *n != item_name).cloned().collect();
+
+        self.update_equipment_cache(new_equipment);
+
+        Ok(())
+    }
+
+    /// Returns `true` if a unit of this item kind is equipped
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.inventory.is_equipped(&"Rifle".to_string());
+    /// ```
+    pub fn is_equipped(&self, item_name: &String) -> bool {
+        self.equipment_cache.borrow().contains(item_name)
+    }
+
+    /// Returns names of the equipped item kinds
+    ///
+    /// # Examples
+    /// ```
+    /// let items = person.inventory.equipped_items();
+    /// ```
+    pub fn equipped_items(&self) -> Vec<String> { self.equipment_cache.borrow().clone() }
+
+    /// Sets how much of the equipped items weight counts toward the inventory weight.
+    /// `0` means that equipped items are not counted at all (like worn clothes), `1` means
+    /// that they weigh as much as carried ones. 1 by default
+    ///
+    /// # Parameters
+    /// - `factor`: part (0..1) of the equipped items weight that is counted
+    ///
+    /// # Examples
+    /// ```
+    /// person.inventory.set_equipped_weight_factor(0.5);
+    /// ```
+    pub fn set_equipped_weight_factor(&self, factor: f32) {
+        self.equipped_weight_factor.set(crate::utils::clamp_01(factor));
+        self.recalculate_weight();
+    }
+
+    /// Returns how much of the equipped items weight counts toward the inventory weight
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.inventory.equipped_weight_factor();
+    /// ```
+    pub fn equipped_weight_factor(&self) -> f32 { self.equipped_weight_factor.get() }
+
+    /// Weight of an item kind that counts toward the inventory weight. Only one unit is
+    /// equipped, the rest is carried
+    pub(crate) fn carried_weight_of(&self, item: &dyn InventoryItem, is_equipped: bool) -> f32 {
+        let weight = item.get_total_weight();
+
+        if !is_equipped || item.get_count() == 0 { return weight; }
+
+        let unit_weight = weight / item.get_count() as f32;
+
+        weight - unit_weight * (1. - self.equipped_weight_factor.get())
+    }
+
+    /// Forgets equipped unit of an item kind that was removed from the inventory
+    pub(crate) fn forget_equipment(&self, item_name: &String) {
+        self.equipment_cache.borrow_mut().retain(|n| n != item_name);
+    }
+
+    pub(crate) fn equipped_count_of(&self, item_name: &String) -> usize {
+        if self.is_equipped(item_name) { 1 } else { 0 }
+    }
+}
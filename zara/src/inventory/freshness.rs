@@ -0,0 +1,201 @@
+use crate::inventory::Inventory;
+use crate::error::ItemFreshnessErr;
+use crate::state::DirtyTracked;
+
+/// Chance of a mild poisoning (0..100) when an expired item is consumed
+pub const EXPIRED_POISONING_CHANCE: usize = 10;
+/// Potency (0..100) an expired item loses its strength down to
+pub const MIN_EXPIRED_POTENCY: f32 = 25.;
+
+impl Inventory {
+    /// Returns freshness of a spoiling item kind (0..100, where 100 is fresh and 0 is fully
+    /// spoiled). Spoiling items age with the game time while carried. `None` if item was not
+    /// found or does not spoil
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(value) = person.inventory.freshness_of(item_name) {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn freshness_of(&self, item_name: &String) -> Option<f32> {
+        let spoil_time = self.spoil_time_of(item_name)?;
+        let age = self.item_ages.borrow().get(item_name).copied().unwrap_or(0.);
+
+        if spoil_time <= 0. { return Some(if age > 0. { 0. } else { 100. }); }
+
+        Some(crate::utils::clamp((1. - age / spoil_time) * 100., 0., 100.))
+    }
+
+    /// Returns for how many game seconds a spoiling or expiring item kind has been aging.
+    /// `None` if item was not found, or it neither spoils nor expires
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(seconds) = person.inventory.age_of(item_name) {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn age_of(&self, item_name: &String) -> Option<f32> {
+        if !self.is_aging(item_name) { return None; }
+
+        Some(self.item_ages.borrow().get(item_name).copied().unwrap_or(0.))
+    }
+
+    /// Sets age of a spoiling or expiring item kind, for example `0` when the meat was just
+    /// cooked, or a found medkit that is already years old
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    /// - `seconds`: item age, game seconds
+    ///
+    /// # Examples
+    /// ```
+    /// person.inventory.set_age(item_name, 0.);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn set_age(&self, item_name: &String, seconds: f32) -> Result<(), ItemFreshnessErr> {
+        if !self.has_item(item_name) { return Err(ItemFreshnessErr::ItemNotFound); }
+        if !self.is_aging(item_name) { return Err(ItemFreshnessErr::ItemDoesNotSpoil); }
+
+        let mut b = self.item_ages.borrow_mut();
+
+        if seconds <= 0. {
+            b.remove(item_name);
+        } else {
+            b.insert(item_name.to_string(), seconds);
+        }
+        self.mark_dirty();
+
+        Ok(())
+    }
+
+    /// Tells if an item kind is past its shelf life. `None` if item was not found or never
+    /// expires
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    ///
+    /// # Examples
+    /// ```
+    /// if person.inventory.is_expired(item_name) == Some(true) {
+    ///     // Show "expired" badge
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn is_expired(&self, item_name: &String) -> Option<bool> {
+        self.expires_in(item_name).map(|left| left <= 0.)
+    }
+
+    /// Returns how many game seconds are left until an item kind expires (`0` if it already
+    /// has). `None` if item was not found or never expires
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(seconds) = person.inventory.expires_in(item_name) {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn expires_in(&self, item_name: &String) -> Option<f32> {
+        let shelf_life = self.shelf_life_of(item_name)?;
+        let age = self.item_ages.borrow().get(item_name).copied().unwrap_or(0.);
+
+        Some(f32::max(shelf_life - age, 0.))
+    }
+
+    /// Returns strength of the medical agent doses an item kind gives (0..100). Expired items
+    /// lose strength over one more shelf life, down to [`MIN_EXPIRED_POTENCY`]. `None` if item
+    /// was not found or is not a consumable
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(value) = person.inventory.potency_of(item_name) {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn potency_of(&self, item_name: &String) -> Option<f32> {
+        self.stored_items.borrow().get(item_name)?.consumable()?;
+
+        let shelf_life = match self.shelf_life_of(item_name) {
+            Some(s) => s,
+            None => return Some(100.)
+        };
+        let age = self.item_ages.borrow().get(item_name).copied().unwrap_or(0.);
+
+        if age <= shelf_life { return Some(100.); }
+        if shelf_life <= 0. { return Some(MIN_EXPIRED_POTENCY); }
+
+        let decay = (age - shelf_life) / shelf_life;
+
+        Some(f32::max(100. - decay * 100., MIN_EXPIRED_POTENCY))
+    }
+
+    /// Ages all carried spoiling and expiring items
+    pub(crate) fn update_item_ages(&self, game_time_delta: f32) {
+        self.mark_dirty();
+
+        if game_time_delta <= 0. { return; }
+
+        let items = self.stored_items.borrow();
+        let mut b = self.item_ages.borrow_mut();
+
+        for (name, item) in items.iter() {
+            if item.consumable().is_some_and(|c| c.spoiling().is_some() || c.shelf_life().is_some()) {
+                *b.entry(name.to_string()).or_insert(0.) += game_time_delta;
+            }
+        }
+    }
+
+    /// Forgets age of an item kind that was removed from the inventory
+    pub(crate) fn forget_age(&self, item_name: &String) {
+        self.item_ages.borrow_mut().remove(item_name);
+    }
+
+    /// Full spoil time of an item kind in game seconds, if it spoils
+    fn spoil_time_of(&self, item_name: &String) -> Option<f32> {
+        let items = self.stored_items.borrow();
+        let spoiling = items.get(item_name)?.consumable()?.spoiling()?;
+
+        Some(spoiling.spoil_time().as_secs_f32())
+    }
+
+    /// Shelf life of an item kind in game seconds, if it expires
+    fn shelf_life_of(&self, item_name: &String) -> Option<f32> {
+        let items = self.stored_items.borrow();
+        let shelf_life = items.get(item_name)?.consumable()?.shelf_life()?;
+
+        Some(shelf_life.as_secs_f32())
+    }
+
+    fn is_aging(&self, item_name: &String) -> bool {
+        self.spoil_time_of(item_name).is_some() || self.shelf_life_of(item_name).is_some()
+    }
+}
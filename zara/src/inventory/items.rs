@@ -1,4 +1,6 @@
 use crate::utils::GameTimeC;
+use crate::body::BodyPart;
+use crate::health::nutrition::NutrientsC;
 
 use std::any::Any;
 use std::fmt;
@@ -295,6 +297,14 @@ macro_rules! inv_spoil(
 ///     /* cold resistance, 0..100% */ 1.,
 ///     /* water resistance, 0..100% */ 14.
 /// );
+///
+/// // With body parts coverage
+/// zara::inv_clothes!(
+///     BootsClothes,
+///     /* cold resistance, 0..100% */ 3.,
+///     /* water resistance, 0..100% */ 20.,
+///     /* coverage */ vec![BodyPart::LeftFoot, BodyPart::RightFoot]
+/// );
 /// ```
 /// 
 /// # Links
@@ -307,6 +317,13 @@ macro_rules! inv_clothes(
             fn water_resistance(&self) -> usize { $c2 as usize }
         }
     );
+    ($t:ty, $c1:expr, $c2:expr, $cov:expr) => (
+        impl zara::inventory::items::ClothesDescription for $t {
+            fn cold_resistance(&self) -> usize { $c1 as usize }
+            fn water_resistance(&self) -> usize { $c2 as usize }
+            fn coverage(&self) -> Vec<zara::body::BodyPart> { $cov }
+        }
+    );
 );
 
 /// Describes consumable contract
@@ -329,7 +346,27 @@ pub struct ConsumableC {
     /// Chance of poisoning by eating this spoiled (0..100)
     pub spoiled_poisoning_chance: usize,
     /// Time in which this item fully spoils
-    pub spoil_time: Option<GameTimeC>
+    pub spoil_time: Option<GameTimeC>,
+    /// Freshness of the consumed item (0..100, where 0 is fully spoiled), if it spoils
+    pub freshness: Option<f32>,
+    /// Contamination of the consumed water (0..100, where 0 is perfectly clean water)
+    pub contamination: f32,
+    /// Is this item past its shelf life
+    pub is_expired: bool,
+    /// Strength of the medical agent doses this item gives (0..100)
+    pub potency: f32,
+    /// Chance of infection caused by a dirty item or bloodied hands (0..100)
+    pub infection_chance: usize,
+    /// Temperature of the consumed item, degrees C (if matters)
+    pub temperature: Option<f32>,
+    /// For how many game minutes food gain is digested (if not instant)
+    pub digestion_time: Option<f32>,
+    /// Nutrients of one consumed item (if reported)
+    pub nutrients: Option<NutrientsC>,
+    /// Toxicity one fresh consumed item adds (0..100)
+    pub toxicity: f32,
+    /// Toxicity one fully spoiled consumed item adds on top (0..100)
+    pub spoiled_toxicity: f32
 }
 impl fmt::Display for ConsumableC {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -358,8 +395,18 @@ impl PartialEq for ConsumableC {
         self.fresh_poisoning_chance == other.fresh_poisoning_chance &&
         self.spoiled_poisoning_chance == other.spoiled_poisoning_chance &&
         self.spoil_time == other.spoil_time &&
+        self.freshness.map(|t| (t*10_000_f32) as i32) == other.freshness.map(|t| (t*10_000_f32) as i32) &&
+        self.infection_chance == other.infection_chance &&
+        self.temperature.map(|t| (t*10_000_f32) as i32) == other.temperature.map(|t| (t*10_000_f32) as i32) &&
+        self.digestion_time.map(|t| (t*10_000_f32) as i32) == other.digestion_time.map(|t| (t*10_000_f32) as i32) &&
+        self.nutrients == other.nutrients &&
+        f32::abs(self.toxicity - other.toxicity) < EPS &&
+        f32::abs(self.spoiled_toxicity - other.spoiled_toxicity) < EPS &&
         f32::abs(self.water_gain - other.water_gain) < EPS &&
-        f32::abs(self.food_gain - other.food_gain) < EPS
+        f32::abs(self.food_gain - other.food_gain) < EPS &&
+        f32::abs(self.contamination - other.contamination) < EPS &&
+        self.is_expired == other.is_expired &&
+        f32::abs(self.potency - other.potency) < EPS
     }
 }
 impl Hash for ConsumableC {
@@ -371,9 +418,19 @@ impl Hash for ConsumableC {
         self.fresh_poisoning_chance.hash(state);
         self.spoiled_poisoning_chance.hash(state);
         self.spoil_time.hash(state);
+        self.freshness.map(|t| (t*10_000_f32) as i32).hash(state);
+        self.infection_chance.hash(state);
+        self.temperature.map(|t| (t*10_000_f32) as i32).hash(state);
+        self.digestion_time.map(|t| (t*10_000_f32) as i32).hash(state);
+        self.nutrients.hash(state);
+        state.write_u32((self.toxicity*10_000_f32) as u32);
+        state.write_u32((self.spoiled_toxicity*10_000_f32) as u32);
 
         state.write_i32(self.food_gain as i32);
         state.write_i32(self.water_gain as i32);
+        state.write_u32((self.contamination*10_000_f32) as u32);
+        self.is_expired.hash(state);
+        state.write_u32((self.potency*10_000_f32) as u32);
     }
 }
 impl ConsumableC {
@@ -395,9 +452,158 @@ impl ConsumableC {
             consumed_count: 0,
             fresh_poisoning_chance: 0,
             spoiled_poisoning_chance: 0,
-            spoil_time: None
+            spoil_time: None,
+            freshness: None,
+            contamination: 0.,
+            is_expired: false,
+            potency: 100.,
+            infection_chance: 0,
+            temperature: None,
+            digestion_time: None,
+            nutrients: None,
+            toxicity: 0.,
+            spoiled_toxicity: 0.
         }
     }
+
+    /// Chance of poisoning by eating this item (0..100). Moves from the fresh to the spoiled
+    /// poisoning chance as the item spoils. Expired items have at least
+    /// [`EXPIRED_POISONING_CHANCE`](crate::inventory::EXPIRED_POISONING_CHANCE)
+    ///
+    /// # Examples
+    /// ```
+    /// let chance = item.poisoning_chance();
+    /// ```
+    pub fn poisoning_chance(&self) -> usize {
+        let expired = if self.is_expired { crate::inventory::EXPIRED_POISONING_CHANCE } else { 0 };
+
+        if self.spoil_time.is_none() { return expired; }
+
+        let spoiled = crate::utils::clamp_01(1. - self.freshness.unwrap_or(100.) / 100.);
+        let fresh = self.fresh_poisoning_chance as f32;
+        let chance = fresh + (self.spoiled_poisoning_chance as f32 - fresh) * spoiled;
+
+        usize::min(100, usize::max(chance.round() as usize, expired))
+    }
+
+    /// Toxicity all consumed items add (0..100). Spoiled part grows as the item spoils
+    ///
+    /// # Examples
+    /// ```
+    /// let value = item.toxicity_gain();
+    /// ```
+    pub fn toxicity_gain(&self) -> f32 {
+        let spoiled = match self.spoil_time {
+            Some(_) => crate::utils::clamp_01(1. - self.freshness.unwrap_or(100.) / 100.),
+            None => 0.
+        };
+
+        crate::utils::clamp((self.toxicity + self.spoiled_toxicity * spoiled) * self.consumed_count as f32, 0., 100.)
+    }
+}
+
+/// Describes a read-only view of an inventory item kind, taken with
+/// [`snapshot_items`](crate::inventory::Inventory::snapshot_items)
+#[derive(Clone, Debug, Default)]
+pub struct ItemInfoC {
+    /// Unique name of the item
+    pub name: String,
+    /// How many items of this kind there are
+    pub count: usize,
+    /// How many items of this kind can be used (not worn, equipped or reserved)
+    pub available_count: usize,
+    /// Total weight of all items of this kind, grams
+    pub total_weight: f32,
+    /// Is this item an infinite resource
+    pub is_infinite: bool,
+    /// Is this item a consumable
+    pub is_consumable: bool,
+    /// Is this consumable a food
+    pub is_food: bool,
+    /// Is this consumable a water
+    pub is_water: bool,
+    /// How many percents of food one consumed item gives
+    pub food_gain: f32,
+    /// How many percents of water one consumed item gives
+    pub water_gain: f32,
+    /// Time in which this item fully spoils, if it spoils
+    pub spoil_time: Option<GameTimeC>,
+    /// Chance of poisoning by eating this fresh (0..100)
+    pub fresh_poisoning_chance: usize,
+    /// Chance of poisoning by eating this spoiled (0..100)
+    pub spoiled_poisoning_chance: usize,
+    /// Appliance options, if item is an appliance
+    pub appliance: Option<ApplianceInfoC>,
+    /// Is this item a piece of clothes
+    pub is_clothes: bool,
+    /// Freshness of the item (0..100, where 0 is fully spoiled), if it spoils
+    pub freshness: Option<f32>,
+    /// Wetness of the clothes (0..100), if item is a piece of clothes
+    pub wetness: Option<f32>
+}
+impl fmt::Display for ItemInfoC {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} [{}]", self.name, self.count)
+    }
+}
+impl Eq for ItemInfoC { }
+impl PartialEq for ItemInfoC {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.name == other.name &&
+        self.count == other.count &&
+        self.available_count == other.available_count &&
+        f32::abs(self.total_weight - other.total_weight) < EPS &&
+        self.is_infinite == other.is_infinite &&
+        self.is_consumable == other.is_consumable &&
+        self.is_food == other.is_food &&
+        self.is_water == other.is_water &&
+        f32::abs(self.food_gain - other.food_gain) < EPS &&
+        f32::abs(self.water_gain - other.water_gain) < EPS &&
+        self.spoil_time == other.spoil_time &&
+        self.fresh_poisoning_chance == other.fresh_poisoning_chance &&
+        self.spoiled_poisoning_chance == other.spoiled_poisoning_chance &&
+        self.appliance == other.appliance &&
+        self.is_clothes == other.is_clothes &&
+        self.freshness.map(|t| (t*10_000_f32) as i32) == other.freshness.map(|t| (t*10_000_f32) as i32) &&
+        self.wetness.map(|t| (t*10_000_f32) as i32) == other.wetness.map(|t| (t*10_000_f32) as i32)
+    }
+}
+impl Hash for ItemInfoC {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.count.hash(state);
+        self.available_count.hash(state);
+        state.write_u32((self.total_weight*10_000_f32) as u32);
+        self.is_infinite.hash(state);
+        self.is_consumable.hash(state);
+        self.is_food.hash(state);
+        self.is_water.hash(state);
+        state.write_i32(self.food_gain as i32);
+        state.write_i32(self.water_gain as i32);
+        self.spoil_time.hash(state);
+        self.fresh_poisoning_chance.hash(state);
+        self.spoiled_poisoning_chance.hash(state);
+        self.appliance.hash(state);
+        self.is_clothes.hash(state);
+        self.freshness.map(|t| (t*10_000_f32) as i32).hash(state);
+        self.wetness.map(|t| (t*10_000_f32) as i32).hash(state);
+    }
+}
+
+/// Describes a read-only view of the appliance options of an inventory item
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct ApplianceInfoC {
+    /// Is this appliance applied to a body part (like a bandage)
+    pub is_body_appliance: bool,
+    /// Is this appliance an injection
+    pub is_injection: bool
+}
+impl fmt::Display for ApplianceInfoC {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Body appliance: {}, injection: {}", self.is_body_appliance, self.is_injection)
+    }
 }
 
 /// Describes appliance contract
@@ -410,7 +616,9 @@ pub struct ApplianceC {
     /// Is this item is an injection (like syringe with something)
     pub is_injection: bool,
     /// How many of these items has been applied
-    pub taken_count: usize
+    pub taken_count: usize,
+    /// Chance of infection caused by a dirty item (0..100)
+    pub infection_chance: usize
 }
 impl fmt::Display for ApplianceC {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -433,7 +641,8 @@ impl PartialEq for ApplianceC {
         self.name == other.name &&
         self.is_body_appliance == other.is_body_appliance &&
         self.is_injection == other.is_injection &&
-        self.taken_count == other.taken_count
+        self.taken_count == other.taken_count &&
+        self.infection_chance == other.infection_chance
     }
 }
 impl Hash for ApplianceC {
@@ -442,6 +651,7 @@ impl Hash for ApplianceC {
         self.is_body_appliance.hash(state);
         self.is_injection.hash(state);
         self.taken_count.hash(state);
+        self.infection_chance.hash(state);
     }
 }
 impl ApplianceC {
@@ -458,7 +668,8 @@ impl ApplianceC {
             name: String::new(),
             is_body_appliance: false,
             is_injection: false,
-            taken_count: 0
+            taken_count: 0,
+            infection_chance: 0
         }
     }
 }
@@ -482,7 +693,16 @@ pub trait InventoryItem {
     /// item.set_count(new_value);
     /// ```
     fn set_count(&mut self, new_count: usize);
-    /// Gets unique name for all items of this kind
+    /// Gets stable unique id of this item kind. Inventory, crafting and events use it as a key.
+    /// Same as [`get_name`](InventoryItem::get_name) by default; override it when the name is a
+    /// display or localization key that may change
+    ///
+    /// # Examples
+    /// ```
+    /// let id = item.get_id();
+    /// ```
+    fn get_id(&self) -> String { self.get_name() }
+    /// Gets display name (or localization key) for all items of this kind
     ///
     /// # Examples
     /// ```
@@ -511,10 +731,52 @@ pub trait InventoryItem {
     fn appliance(&self) -> Option<&dyn ApplianceDescription>;
     /// Node that describes clothes options for this item
     fn clothes(&self) -> Option<&dyn ClothesDescription>;
+    /// Node that describes what is needed to use this item. `None` by default
+    fn requirements(&self) -> Option<&dyn UsageRequirements> { None }
+    /// Node that describes this item as a tool that weather can make fail (like matches or
+    /// a flashlight). `None` by default
+    fn tool(&self) -> Option<&dyn ToolDescription> { None }
+    /// Node that describes this item as a light source (like a torch or a lantern).
+    /// `None` by default
+    fn light(&self) -> Option<&dyn LightDescription> { None }
+    /// Node that describes this item as an electronic device that runs on charge (like a
+    /// flashlight or a radio). `None` by default
+    fn electronic(&self) -> Option<&dyn ElectronicDescription> { None }
+    /// Node that describes this item as a source of charge for the electronic devices (like
+    /// batteries or a power bank). `None` by default
+    fn charge_source(&self) -> Option<&dyn ChargeSourceDescription> { None }
+    /// Node that describes this item as a navigation aid (like a compass or a GPS).
+    /// `None` by default
+    fn navigation(&self) -> Option<&dyn NavigationDescription> { None }
+    /// Boxed copy of this item. `None` by default, which means item cannot be duplicated.
+    /// For items that implement `Clone`, use the [`CloneInventoryItem`] helper
+    ///
+    /// # Examples
+    /// ```
+    /// fn clone_boxed(&self) -> Option<Box<dyn InventoryItem>> { Some(self.clone_item()) }
+    /// ```
+    fn clone_boxed(&self) -> Option<Box<dyn InventoryItem>> { None }
     /// For downcasting
     fn as_any(&self) -> &dyn Any;
 }
 
+/// Helper that boxes a copy of any inventory item that implements `Clone`.
+/// Is implemented automatically for all such items
+///
+/// # Examples
+/// ```
+/// use zara::inventory::items::CloneInventoryItem;
+///
+/// let copy = meat.clone_item();
+/// ```
+pub trait CloneInventoryItem {
+    /// Returns boxed copy of this item
+    fn clone_item(&self) -> Box<dyn InventoryItem>;
+}
+impl<T: InventoryItem + Clone + 'static> CloneInventoryItem for T {
+    fn clone_item(&self) -> Box<dyn InventoryItem> { Box::new(self.clone()) }
+}
+
 /// Trait to describe appliance behavior of the inventory item
 /// 
 /// # Links
@@ -524,6 +786,113 @@ pub trait ApplianceDescription {
     fn is_body_appliance(&self) -> bool;
     /// True if this appliance is an injection type (like syringe with something)
     fn is_injection(&self) -> bool;
+    /// If this body appliance is a prosthetic, how much of the lost body part function
+    /// it restores (0..100 scale). `None` by default
+    fn prosthetic_function(&self) -> Option<usize> { None }
+    /// If this body appliance is a tourniquet, for how long (game minutes) it can stay on a limb
+    /// before tissue damage begins. Tourniquet stops all blood loss on its limb. `None` by default
+    fn tourniquet_safe_time(&self) -> Option<usize> { None }
+    /// If this body appliance is a burn dressing (like a cooling gel pad), how much of the pain
+    /// of the burns on its body part it relieves (0..100 scale). Eased pain means less stamina
+    /// drain and fatigue. `None` by default
+    fn burn_relief(&self) -> Option<usize> { None }
+}
+
+/// Trait to describe what is needed to use the inventory item. Requirements are checked by
+/// `consume`, `take_appliance` and `execute_combination`
+///
+/// # Links
+/// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Implementing-inventory-items) for more info.
+pub trait UsageRequirements {
+    /// Unique name of a skill and its minimum level needed to use this item. `None` by default
+    fn min_skill_level(&self) -> Option<(String, usize)> { None }
+    /// True if both hands must be free to use this item. `false` by default
+    fn needs_free_hands(&self) -> bool { false }
+    /// True if this item can be used under the water. `true` by default
+    fn usable_underwater(&self) -> bool { true }
+}
+
+/// Trait to describe how weather affects a tool. Tools are used with
+/// [`try_use_tool`](crate::ZaraController::try_use_tool). All chances are 0..100
+///
+/// # Links
+/// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Implementing-inventory-items) for more info.
+pub trait ToolDescription {
+    /// True if one item is used up with every use attempt, successful or not (like a match).
+    /// `false` by default
+    fn consumed_on_use(&self) -> bool { false }
+    /// Chance to fail in the heaviest rain outside of a shelter. Scales with the rain
+    /// intensity. `0` by default
+    fn rain_failure_chance(&self) -> usize { 0 }
+    /// Chance to fail when the character is soaking wet. Scales with the wetness level.
+    /// `0` by default
+    fn wet_failure_chance(&self) -> usize { 0 }
+    /// Temperature (degrees C) below which the tool may fail (like a battery in the cold).
+    /// `None` by default
+    fn min_temperature(&self) -> Option<f32> { None }
+    /// Chance to fail below the [`min_temperature`](ToolDescription::min_temperature).
+    /// `100` by default
+    fn cold_failure_chance(&self) -> usize { 100 }
+}
+
+/// Trait to describe a light source. Light sources are turned on and off with
+/// [`set_active_light`](crate::inventory::Inventory::set_active_light) and burn their fuel
+/// while on
+///
+/// # Links
+/// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Implementing-inventory-items) for more info.
+pub trait LightDescription {
+    /// How much light this source gives (0..100)
+    fn output(&self) -> f32;
+    /// How long one unit of fuel burns. Light goes out when its fuel is used up
+    fn burn_time(&self) -> GameTimeC;
+    /// Unique name of the item this light burns (like lamp oil). `None` by default, which means
+    /// the light burns itself (like a torch) and goes out after one unit burns down
+    fn fuel_item(&self) -> Option<String> { None }
+    /// Rain intensity (0..1) above which the light goes out outside of a shelter. `None` by
+    /// default, which means rain does not affect it
+    fn max_rain_intensity(&self) -> Option<f32> { None }
+    /// True if the light goes out under the water. `true` by default
+    fn goes_out_underwater(&self) -> bool { true }
+}
+
+/// Trait to describe an electronic device. Devices are turned on and off with
+/// [`set_active_electronic`](crate::inventory::Inventory::set_active_electronic) and drain
+/// their charge (0..100) while on
+///
+/// # Links
+/// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Implementing-inventory-items) for more info.
+pub trait ElectronicDescription {
+    /// How much charge (0..100 scale) the device uses per game hour while on
+    fn drain_rate(&self) -> f32;
+    /// Unique names of the items that can recharge this device (see
+    /// [`ChargeSourceDescription`]). Empty by default, which means device cannot be recharged
+    fn charge_sources(&self) -> Vec<String> { Vec::new() }
+}
+
+/// Trait to describe a source of charge for the electronic devices. Devices are recharged with
+/// [`recharge`](crate::inventory::Inventory::recharge)
+///
+/// # Links
+/// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Implementing-inventory-items) for more info.
+pub trait ChargeSourceDescription {
+    /// How much charge (0..100 scale) one use of this source gives
+    fn charge_amount(&self) -> f32;
+    /// True if one item is used up with every recharge (like a battery). `true` by default
+    fn consumed_on_charge(&self) -> bool { true }
+}
+
+/// Trait to describe a navigation aid. Carried aids negate the disorientation (see
+/// [`orientation_factor`](crate::health::Health::orientation_factor)); the strongest one is used
+///
+/// # Links
+/// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Implementing-inventory-items) for more info.
+pub trait NavigationDescription {
+    /// How much of the disorientation this item negates (0..100 scale). `100` by default
+    fn disorientation_negation(&self) -> f32 { 100. }
+    /// True if this item helps only while it is an electronic device that is on (like a GPS).
+    /// `false` by default
+    fn needs_power(&self) -> bool { false }
 }
 
 /// Trait to describe consumable behavior of the inventory item
@@ -541,6 +910,25 @@ pub trait ConsumableDescription {
     fn food_gain_per_dose(&self) -> f32;
     /// Node that describes the spoiling options of this consumable
     fn spoiling(&self) -> Option<&dyn SpoilingBehavior>;
+    /// Temperature of this consumable, degrees C. Hot food and drinks warm the body for a
+    /// short time, cold ones chill it. `None` (default) means temperature does not matter
+    fn temperature(&self) -> Option<f32> { None }
+    /// For how many game minutes the food gain of this consumable is digested. Most of the
+    /// gain comes in the beginning. `None` (default) means food gain is applied instantly
+    fn digestion_minutes(&self) -> Option<f32> { None }
+    /// How contaminated this water is (0..100 scale), like 70 for a muddy pond. Drinking
+    /// contaminated water can cause a disease. Ignored for non-water items. 0 by default
+    fn contamination(&self) -> f32 { 0. }
+    /// For how long this item stays good while carried, like the medicine expiry date. Expired
+    /// item gives weaker medical agent doses and can cause a mild poisoning. `None` (default)
+    /// means item never expires
+    fn shelf_life(&self) -> Option<GameTimeC> { None }
+    /// Toxicity (0..100 scale) one dose adds, like for a strong alcohol or a poisonous
+    /// mushroom. `0` by default
+    fn toxicity(&self) -> f32 { 0. }
+    /// Protein, fat, carbs and vitamin content of one dose (0..100 scale of the full nutrient
+    /// store), used when detailed nutrition is on. `None` (default) means item gives no nutrients
+    fn nutrients(&self) -> Option<NutrientsC> { None }
 }
 
 /// Trait to describe the spoiling options of the consumable
@@ -555,6 +943,10 @@ pub trait SpoilingBehavior {
     /// Time that is needed for fresh item to become spoiled
     /// ([`GameTimeC`](crate::utils::GameTimeC) structure)
     fn spoil_time(&self) -> GameTimeC;
+    /// Toxicity (0..100 scale) one fully spoiled item adds. Partly spoiled items add
+    /// proportionally less. A quarter of the [`spoil_poisoning_chance`](SpoilingBehavior::spoil_poisoning_chance)
+    /// by default
+    fn spoil_toxicity(&self) -> f32 { self.spoil_poisoning_chance() as f32 / 4. }
 }
 
 /// Trait to describe clothes-related options of the item
@@ -566,4 +958,8 @@ pub trait ClothesDescription {
     fn cold_resistance(&self) -> usize;
     /// Water resistance value (0..100 scale)
     fn water_resistance(&self) -> usize;
+    /// Body parts this item covers. Empty by default
+    fn coverage(&self) -> Vec<BodyPart> { Vec::new() }
+    /// Part of the dry weight this item holds as water when soaked (0.5 is +50%). 0.5 by default
+    fn water_absorption(&self) -> f32 { 0.5 }
 }
\ No newline at end of file
@@ -0,0 +1,169 @@
+use crate::inventory::Inventory;
+use crate::utils::event::{Event, MessageQueue};
+use crate::error::LightErr;
+use crate::state::DirtyTracked;
+
+impl Inventory {
+    /// Turns a light source (see [`LightDescription`](crate::inventory::items::LightDescription))
+    /// on or off. Light that is on burns its fuel with the game time, and goes out in the rain
+    /// or under the water if it cannot stand it
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the light item (item kind)
+    /// - `on`: `true` to light it, `false` to put it out
+    ///
+    /// # Examples
+    /// ```
+    /// person.inventory.set_active_light(&torch_name, true);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn set_active_light(&self, item_name: &String, on: bool) -> Result<(), LightErr> {
+        {
+            let items = self.stored_items.borrow();
+            let light = match items.get(item_name) {
+                Some(o) => match o.light() {
+                    Some(l) => l,
+                    None => return Err(LightErr::ItemIsNotLight)
+                },
+                None => return Err(LightErr::ItemNotFound)
+            };
+
+            if on {
+                if let Some(fuel) = light.fuel_item() {
+                    if items.get(&fuel).is_none_or(|f| f.get_count() == 0) { return Err(LightErr::NoFuel); }
+                }
+                if light.goes_out_underwater() && self.player.is_underwater.get() {
+                    return Err(LightErr::Underwater);
+                }
+                if light.max_rain_intensity().is_some_and(|r| self.is_raining_on_light(r)) {
+                    return Err(LightErr::TooRainy);
+                }
+            }
+        }
+
+        let mut b = self.active_lights.borrow_mut();
+
+        b.retain(|x| x != item_name);
+        if on { b.push(item_name.to_string()); }
+        self.mark_dirty();
+
+        Ok(())
+    }
+
+    /// Returns `true` if a light source is on
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the light item (item kind)
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.inventory.is_light_on(&torch_name);
+    /// ```
+    pub fn is_light_on(&self, item_name: &String) -> bool {
+        self.active_lights.borrow().contains(item_name)
+    }
+
+    /// Returns for how many game seconds the current fuel unit of a light source will burn.
+    /// `None` if item was not found or is not a light
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the light item (item kind)
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(seconds) = person.inventory.light_time_left(&lantern_name) {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn light_time_left(&self, item_name: &String) -> Option<f32> {
+        let burn_time = self.stored_items.borrow().get(item_name)?.light()?.burn_time().as_secs_f32();
+        let burned = self.light_burn.borrow().get(item_name).copied().unwrap_or(0.);
+
+        Some(f32::max(burn_time - burned, 0.))
+    }
+
+    /// Returns light output of all light sources that are on (0..100)
+    ///
+    /// # Examples
+    /// ```
+    /// let light = person.inventory.current_light_output();
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn current_light_output(&self) -> f32 {
+        let items = self.stored_items.borrow();
+        let output: f32 = self.active_lights.borrow().iter()
+            .filter_map(|name| items.get(name).and_then(|o| o.light()).map(|l| f32::max(l.output(), 0.)))
+            .sum();
+
+        crate::utils::clamp(output, 0., 100.)
+    }
+
+    /// Burns fuel of the lights that are on and puts out lights that cannot stand the weather
+    pub(crate) fn update_lights(&self, game_time_delta: f32) {
+        self.mark_dirty();
+
+        if self.active_lights.borrow().is_empty() { return; }
+
+        let mut extinguished = Vec::new();
+        let mut burned_units = Vec::new();
+        {
+            let items = self.stored_items.borrow();
+            let mut burn = self.light_burn.borrow_mut();
+
+            for name in self.active_lights.borrow().iter() {
+                let light = match items.get(name).and_then(|o| o.light()) {
+                    Some(l) => l,
+                    None => continue
+                };
+
+                if (light.goes_out_underwater() && self.player.is_underwater.get()) ||
+                    light.max_rain_intensity().is_some_and(|r| self.is_raining_on_light(r)) {
+                    extinguished.push(name.to_string());
+                    continue;
+                }
+
+                let burned = burn.entry(name.to_string()).or_insert(0.);
+
+                *burned += f32::max(game_time_delta, 0.);
+
+                if *burned >= light.burn_time().as_secs_f32() {
+                    *burned = 0.;
+                    burned_units.push((name.to_string(), light.fuel_item()));
+                }
+            }
+        }
+
+        for name in extinguished {
+            self.active_lights.borrow_mut().retain(|x| *x != name);
+            self.queue_message(Event::LightExtinguished(name));
+        }
+        for (name, fuel) in burned_units {
+            let fuel_name = fuel.clone().unwrap_or(name.to_string());
+
+            self.use_item(&fuel_name, 1).ok();
+
+            // Torch goes out with its unit, lantern burns while there is fuel
+            if fuel.is_none() || self.get_count_of(&fuel_name).unwrap_or(0) == 0 {
+                self.active_lights.borrow_mut().retain(|x| *x != name);
+                self.queue_message(Event::LightBurnedOut(name));
+            }
+        }
+    }
+
+    /// Forgets light state of an item kind that was removed from the inventory
+    pub(crate) fn forget_light(&self, item_name: &String) {
+        self.active_lights.borrow_mut().retain(|x| x != item_name);
+        self.light_burn.borrow_mut().remove(item_name);
+    }
+
+    fn is_raining_on_light(&self, max_rain_intensity: f32) -> bool {
+        !self.player.is_inside_shelter.get() && self.environment.rain_intensity.get() > max_rain_intensity
+    }
+}
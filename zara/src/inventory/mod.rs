@@ -1,16 +1,41 @@
 use crate::utils::event::{Event, MessageQueue};
+use crate::utils::MonitorPriorities;
 use crate::inventory::items::InventoryItem;
 use crate::inventory::crafting::CraftingCombination;
 use crate::inventory::monitors::InventoryMonitor;
 use crate::error::InventoryUseErr;
+use crate::player::PlayerStatus;
+use crate::skills::Skills;
+use crate::body::Body;
+use crate::world::EnvironmentData;
+use crate::state::DirtyTracked;
 
 use std::collections::{HashMap, BTreeMap};
 use std::cell::{Cell, RefCell, RefMut};
 use std::sync::Arc;
 use std::rc::Rc;
+use std::fmt;
 
 mod crud;
 mod update;
+mod requirements;
+mod deferred;
+mod reservation;
+mod wetness;
+mod freshness;
+mod contamination;
+mod equipment;
+mod lights;
+mod electronics;
+mod navigation;
+mod transfer;
+mod snapshot;
+
+pub mod cleanliness;
+
+pub use freshness::{EXPIRED_POISONING_CHANCE, MIN_EXPIRED_POTENCY};
+pub mod returns;
+pub mod tools;
 
 pub(crate) mod state;
 
@@ -18,21 +43,56 @@ pub mod items;
 pub mod crafting;
 pub mod monitors;
 
+/// Why inventory contents or weight changed. Is sent with the inventory events
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub enum InventoryChangeReason {
+    /// Item was added by the game or returned from a removed appliance
+    Added,
+    /// Item kind was removed by the game
+    Removed,
+    /// Item was used by the game, a tool, a medical procedure, a light fuel or a charger
+    Used,
+    /// Item was eaten, drunk or taken as an appliance
+    Consumed,
+    /// Item was used as a crafting resource or made by crafting
+    Crafted,
+    /// Item was moved to or from another inventory, a stash or a barter side
+    Transferred,
+    /// Item was thrown away because it spoiled or expired
+    Spoiled,
+    /// Clothes, equipment or their wetness changed
+    Other
+}
+impl fmt::Display for InventoryChangeReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
 /// Controls player's inventory
 /// 
 /// # Links
 /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Inventory) for more info.
 pub struct Inventory {
-    /// All inventory items
+    /// All inventory items. Read them with [`snapshot_items`], [`with_item`] or [`for_each_item`],
+    /// change them with [`with_item_mut`] or [`for_each_item_mut`]
     ///
     /// # Important
     /// Do not add or remove elements by hand. Use the
     /// [`add_item`] and [`remove_item`] methods. Otherwise
     /// inventory weight will not be correctly recalculated
     ///
+    /// [`snapshot_items`]: #method.snapshot_items
+    /// [`with_item`]: #method.with_item
+    /// [`for_each_item`]: #method.for_each_item
+    /// [`with_item_mut`]: #method.with_item_mut
+    /// [`for_each_item_mut`]: #method.for_each_item_mut
     /// [`add_item`]: #method.add_item
     /// [`remove_item`]: #method.remove_item
+    #[deprecated(since = "1.0.8", note = "will become private; use `snapshot_items`, `with_item`, `for_each_item`, `with_item_mut` or `for_each_item_mut`")]
     pub items: Arc<RefCell<HashMap<String, Box<dyn InventoryItem>>>>,
+    /// Same collection as `items`, used by Zara itself
+    pub(crate) stored_items: Arc<RefCell<HashMap<String, Box<dyn InventoryItem>>>>,
     /// Registered inventory monitors.
     ///
     /// # Important
@@ -42,6 +102,8 @@ pub struct Inventory {
     /// [`register_monitor`]: #method.register_monitor
     /// [`unregister_monitor`]: #method.unregister_monitor
     pub inventory_monitors: Rc<RefCell<HashMap<usize, Box<dyn InventoryMonitor>>>>,
+    /// Execution order of the inventory monitors
+    monitor_priorities: MonitorPriorities,
 
     /// Weight of all inventory items (in grams)
     weight: Cell<f32>,
@@ -49,19 +111,83 @@ pub struct Inventory {
     crafting_combinations: Rc<RefCell<HashMap<String, CraftingCombination>>>,
     /// Clothes cache
     clothes_cache: RefCell<Vec<String>>,
+    /// Equipped (hand or back) items cache
+    equipment_cache: RefCell<Vec<String>>,
+    /// Part of the equipped items weight that counts toward the inventory weight
+    equipped_weight_factor: Cell<f32>,
+    /// Player status, is used to check item usage requirements
+    player: Arc<PlayerStatus>,
+    /// Skills node, is used to check item usage requirements
+    skills: Arc<Skills>,
+    /// Body node, is used to check item usage requirements
+    body: Arc<Body>,
+    /// Environment node, is used to check crafting constraints
+    environment: Arc<EnvironmentData>,
+    /// Cleanliness of the item kinds (0..100). Clean items are not stored
+    cleanliness: RefCell<HashMap<String, f32>>,
+    /// Factories of the items that go back to the inventory when appliances are removed
+    appliance_returns: RefCell<HashMap<String, returns::ApplianceReturnFactory>>,
+    /// Reserved (locked) units of the item kinds
+    reservations: RefCell<HashMap<String, usize>>,
+    /// Wetness of the clothes kinds that were taken off (0..100). Dry items are not stored
+    wet_clothes: RefCell<HashMap<String, f32>>,
+    /// Age of the carried spoiling and expiring item kinds, game seconds. Fresh items are not stored
+    item_ages: RefCell<HashMap<String, f32>>,
+    /// Contamination of the water item kinds (0..100) that differs from their descriptions
+    contamination: RefCell<HashMap<String, f32>>,
+    /// Light item kinds that are on
+    active_lights: RefCell<Vec<String>>,
+    /// How long the current fuel unit of the light item kinds has burned, game seconds
+    light_burn: RefCell<HashMap<String, f32>>,
+    /// Electronic item kinds that are on
+    active_electronics: RefCell<Vec<String>>,
+    /// Charge of the electronic item kinds (0..100). Fully charged items are not stored
+    charges: RefCell<HashMap<String, f32>>,
+    /// Weight of the water absorbed by wet clothes (in grams)
+    water_weight: Cell<f32>,
+    /// Inventory changes requested while `items` collection was borrowed
+    deferred_actions: RefCell<Vec<deferred::DeferredItemAction>>,
+    /// Is node changed since the last state snapshot
+    is_dirty: Cell<bool>,
     /// Messages queued for sending on the next frame
     message_queue: RefCell<BTreeMap<usize, Event>>
 }
 
 impl Inventory {
-    pub(crate) fn new() -> Self {
+    // Deprecated public collection is still shared with the new accessors
+    #[allow(deprecated)]
+    pub(crate) fn new(player: Arc<PlayerStatus>, skills: Arc<Skills>, body: Arc<Body>,
+                      environment: Arc<EnvironmentData>) -> Self {
+        let items = Arc::new(RefCell::new(HashMap::new()));
+
         Inventory {
-            items: Arc::new(RefCell::new(HashMap::new())),
+            items: items.clone(),
+            stored_items: items,
             crafting_combinations: Rc::new(RefCell::new(HashMap::new())),
             inventory_monitors: Rc::new(RefCell::new(HashMap::new())),
+            monitor_priorities: MonitorPriorities::default(),
             weight: Cell::new(0.),
             message_queue: RefCell::new(BTreeMap::new()),
-            clothes_cache: RefCell::new(Vec::new())
+            clothes_cache: RefCell::new(Vec::new()),
+            equipment_cache: RefCell::new(Vec::new()),
+            equipped_weight_factor: Cell::new(1.),
+            deferred_actions: RefCell::new(Vec::new()),
+            cleanliness: RefCell::new(HashMap::new()),
+            appliance_returns: RefCell::new(HashMap::new()),
+            reservations: RefCell::new(HashMap::new()),
+            wet_clothes: RefCell::new(HashMap::new()),
+            item_ages: RefCell::new(HashMap::new()),
+            contamination: RefCell::new(HashMap::new()),
+            active_lights: RefCell::new(Vec::new()),
+            light_burn: RefCell::new(HashMap::new()),
+            active_electronics: RefCell::new(Vec::new()),
+            charges: RefCell::new(HashMap::new()),
+            water_weight: Cell::new(0.),
+            player,
+            skills,
+            body,
+            environment,
+            is_dirty: Cell::new(false)
         }
     }
 
@@ -80,7 +206,7 @@ impl Inventory {
     /// # Links
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Inventory) for more info.
     pub fn get_count_of(&self, name: &String) -> Option<usize> {
-        self.items.borrow().get(name).map(|x| x.get_count())
+        self.stored_items.borrow().get(name).map(|x| x.get_count())
     }
 
     /// Returns total weight of a certain item. `None` if not found.
@@ -98,7 +224,7 @@ impl Inventory {
     /// # Links
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Inventory) for more info.
     pub fn get_weight_of(&self, name: &String) -> Option<f32> {
-        self.items.borrow().get(name).map(|x| x.get_total_weight())
+        self.stored_items.borrow().get(name).map(|x| x.get_total_weight())
     }
 
     /// Decreases item count for a given item kind. If count becomes zero, removes item from
@@ -119,37 +245,95 @@ impl Inventory {
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Using-inventory-items) for more info.
     /// 
     /// ## Notes
-    /// Borrows `items` collection
+    /// Borrows `items` collection. When called while `items` collection is borrowed (for example,
+    /// from a disease `on_consumed` callback), the change is validated and applied right after
+    /// that callback returns
     pub fn use_item(&self, name: &String, amount: usize) -> Result<(), InventoryUseErr> {
+        self.use_item_for(name, amount, InventoryChangeReason::Used)
+    }
+
+    /// Same as [`use_item`](#method.use_item), but sends the inventory events with a given
+    /// reason. Use it, for example, to throw away spoiled food with
+    /// [`InventoryChangeReason::Spoiled`]
+    ///
+    /// # Parameters
+    /// - `name`: unique inventory item name to use
+    /// - `amount`: this number will be subtracted from the count
+    /// - `reason`: why the item is used
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::inventory::InventoryChangeReason;
+    ///
+    /// person.inventory.use_item_for(item_name, 2, InventoryChangeReason::Spoiled);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Using-inventory-items) for more info.
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn use_item_for(&self, name: &String, amount: usize, reason: InventoryChangeReason) -> Result<(), InventoryUseErr> {
         {
-            let mut b = self.items.borrow_mut();
-            self.use_item_internal(name, amount, &mut b)?;
+            let mut b = match self.stored_items.try_borrow_mut() {
+                Ok(b) => b,
+                Err(_) => return self.defer_use_item(name, amount, reason)
+            };
+            self.use_item_internal(name, amount, reason, &mut b)?;
         }
 
-        self.recalculate_weight();
+        self.recalculate_weight_for(reason);
+
+        Ok(())
+    }
+
+    fn defer_use_item(&self, name: &String, amount: usize, reason: InventoryChangeReason) -> Result<(), InventoryUseErr> {
+        if let Ok(b) = self.stored_items.try_borrow() {
+            let item = match b.get(name) {
+                Some(o) => o,
+                None => return Err(InventoryUseErr::ItemNotFound)
+            };
+
+            if !item.get_is_infinite() {
+                let amount = amount + self.deferred_use_of(name);
+
+                if amount > item.get_count() { return Err(InventoryUseErr::InsufficientResources); }
+
+                self.check_locked(name, item.get_count(), amount)?;
+            }
+        }
+
+        self.defer(deferred::DeferredItemAction::Use(name.to_string(), amount, reason));
 
         Ok(())
     }
 
-    fn use_item_internal(&self, name: &String, amount: usize, items_mut: &mut HashMap<String, Box<dyn InventoryItem>>) -> Result<(), InventoryUseErr> {
+    fn use_item_internal(&self, name: &String, amount: usize, reason: InventoryChangeReason,
+                         items_mut: &mut HashMap<String, Box<dyn InventoryItem>>) -> Result<(), InventoryUseErr> {
         match items_mut.get_mut(name) {
             Some(o) => {
                 if o.get_is_infinite() {
-                    self.queue_message(Event::InventoryItemUsedPartially(name.to_string(), amount));
+                    self.queue_message(Event::InventoryItemUsedPartially(name.to_string(), amount, o.get_count(),
+                                                                         o.get_total_weight(), reason));
                     return Ok(());
                 }
 
                 let c = o.get_count();
                 if amount > c { return Err(InventoryUseErr::InsufficientResources) }
 
+                // Worn and reserved units cannot be used
+                self.check_locked(name, c, amount)?;
+
                 if c - amount == 0 {
                     // Need to clean up
                     items_mut.remove(name);
-                    self.queue_message(Event::InventoryItemUsedAll(name.to_string(), amount));
+                    self.forget_item(name);
+                    self.queue_message(Event::InventoryItemUsedAll(name.to_string(), amount, reason));
                 } else {
                     o.set_count(c - amount);
 
-                    self.queue_message(Event::InventoryItemUsedPartially(name.to_string(), amount));
+                    self.queue_message(Event::InventoryItemUsedPartially(name.to_string(), amount, c - amount,
+                                                                         o.get_total_weight(), reason));
                 }
             },
             None => return Err(InventoryUseErr::ItemNotFound)
@@ -170,7 +354,7 @@ impl Inventory {
     pub fn get_weight(&self) -> f32 { self.weight.get() }
 
     /// Recalculates the inventory weight. Is called automatically every time inventory
-    /// or clothes changes
+    /// or clothes or equipment changes, and while clothes are wet
     /// 
     /// # Examples
     /// ```
@@ -180,28 +364,51 @@ impl Inventory {
     /// # Links
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Inventory) for more info.
     pub fn recalculate_weight(&self) {
+        self.recalculate_weight_for(InventoryChangeReason::Other);
+    }
+
+    /// Recalculates the inventory weight, sending a given reason with the weight change event
+    pub(crate) fn recalculate_weight_for(&self, reason: InventoryChangeReason) {
+        self.mark_dirty();
+
         let old_weight = self.weight.get();
         let mut new_weight: f32;
+        let mut water_weight = 0.;
 
         new_weight = 0.;
 
         let cc = self.clothes_cache.borrow();
-        for (name, item) in self.items.borrow().iter() {
-            // Do not count clothes we're wearing
-            if !cc.contains(name) {
-                new_weight += item.get_total_weight();
+        let ec = self.equipment_cache.borrow();
+        for (name, item) in self.stored_items.borrow().iter() {
+            let is_worn = cc.contains(name);
+
+            // Do not count clothes we're wearing, but count water they absorbed
+            if !is_worn {
+                new_weight += self.carried_weight_of(item.as_ref(), ec.contains(name));
             }
+
+            water_weight += self.absorbed_water_weight(name, item.as_ref(), is_worn);
         }
 
+        new_weight += water_weight;
+
         self.weight.set(new_weight);
+        self.water_weight.set(water_weight);
 
         if old_weight != new_weight {
-            self.queue_message(Event::InventoryWeightChanged(old_weight, new_weight));
+            self.queue_message(Event::InventoryWeightChanged(old_weight, new_weight, reason));
         }
     }
 
     pub(crate) fn update_clothes_cache(&self, new_clothes: Vec<String>) {
-        self.clothes_cache.replace(new_clothes);
+        let old_clothes = self.clothes_cache.replace(new_clothes);
+
+        self.on_clothes_cache_changed(&old_clothes, &self.clothes_cache.borrow());
+        self.recalculate_weight();
+    }
+
+    pub(crate) fn update_equipment_cache(&self, new_equipment: Vec<String>) {
+        self.equipment_cache.replace(new_equipment);
         self.recalculate_weight();
     }
 }
@@ -212,6 +419,8 @@ impl MessageQueue for Inventory {
     }
 
     fn queue_message(&self, message: Event) {
+        self.mark_dirty();
+
         let mut q = self.message_queue.borrow_mut();
         let id = q.len();
 
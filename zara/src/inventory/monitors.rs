@@ -37,10 +37,31 @@ impl Inventory {
     /// # Links
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Inventory-Monitors) for more info.
     pub fn register_monitor(&self, monitor: Box<dyn InventoryMonitor>) -> usize {
+        self.register_monitor_with_priority(monitor, 0)
+    }
+
+    /// Registers new inventory monitor instance with a given execution priority. Monitors with
+    /// higher priority are checked first; monitors with equal priority are checked in the
+    /// order of registration. [`register_monitor`](#method.register_monitor) uses priority `0`
+    ///
+    /// # Parameters
+    /// - `monitor`: an instance of an object that implements
+    ///   [`InventoryMonitor`](crate::inventory::monitors::InventoryMonitor) trait
+    /// - `priority`: execution priority
+    ///
+    /// # Returns
+    /// `usize`: unique key of this registered instance
+    ///
+    /// # Examples
+    /// ```
+    /// let mid = person.inventory.register_monitor_with_priority(boxed_monitor, 10);
+    /// ```
+    pub fn register_monitor_with_priority(&self, monitor: Box<dyn InventoryMonitor>, priority: i32) -> usize {
         let mut b = self.inventory_monitors.borrow_mut();
-        let key = b.keys().max().unwrap_or(&0) + 1;
+        let key = self.monitor_priorities.next_key();
 
         b.insert(key, monitor);
+        self.monitor_priorities.set(key, priority);
 
         key
     }
@@ -68,6 +89,7 @@ impl Inventory {
         }
 
         b.remove(&key);
+        self.monitor_priorities.remove(key);
 
         Ok(())
     }
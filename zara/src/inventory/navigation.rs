@@ -0,0 +1,25 @@
+use crate::inventory::Inventory;
+
+impl Inventory {
+    /// Returns how much of the disorientation the carried navigation aids negate (0..100). The
+    /// strongest aid is used. Aids that need power help only while they are on
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.inventory.navigation_aid();
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn navigation_aid(&self) -> f32 {
+        let items = self.stored_items.borrow();
+        let aid = items.iter()
+            .filter(|(_, o)| o.get_count() > 0)
+            .filter_map(|(name, o)| o.navigation().map(|n| (name, n)))
+            .filter(|(name, n)| !n.needs_power() || self.is_electronic_on(name))
+            .map(|(_, n)| n.disorientation_negation())
+            .fold(0., f32::max);
+
+        crate::utils::clamp(aid, 0., 100.)
+    }
+}
@@ -0,0 +1,70 @@
+use crate::inventory::Inventory;
+use crate::inventory::items::InventoryItem;
+use crate::body::BodyPart;
+use crate::error::UsageRequirementErr;
+
+/// Body parts that count as hands
+const HANDS: [BodyPart; 2] = [BodyPart::LeftBrush, BodyPart::RightBrush];
+
+impl Inventory {
+    /// Checks if usage requirements of an item are met right now. Useful to disable the item
+    /// in the UI before player tries to use it
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item
+    ///
+    /// # Returns
+    /// Ok if item can be used
+    ///
+    /// # Examples
+    /// ```
+    /// if person.inventory.check_requirements(&item_name).is_ok() {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Implementing-inventory-items) for more info.
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn check_requirements(&self, item_name: &String) -> Result<(), UsageRequirementErr> {
+        match self.stored_items.borrow().get(item_name) {
+            Some(item) => self.check_item_requirements(item.as_ref()),
+            None => Err(UsageRequirementErr::ItemNotFound)
+        }
+    }
+
+    /// Returns how many hands are free now: not lost and not holding anything
+    ///
+    /// # Examples
+    /// ```
+    /// let hands = person.inventory.free_hands();
+    /// ```
+    pub fn free_hands(&self) -> usize {
+        let working = HANDS.iter().filter(|p| self.body.part_function(**p) > 0.).count();
+
+        working.saturating_sub(self.player.busy_hands.get())
+    }
+
+    pub(crate) fn check_item_requirements(&self, item: &dyn InventoryItem) -> Result<(), UsageRequirementErr> {
+        let r = match item.requirements() {
+            Some(r) => r,
+            None => return Ok(())
+        };
+
+        if let Some((skill_name, level)) = r.min_skill_level() {
+            if self.skills.level_of(&skill_name).unwrap_or(0) < level {
+                return Err(UsageRequirementErr::SkillLevelTooLow(skill_name, level));
+            }
+        }
+        if r.needs_free_hands() && self.free_hands() < HANDS.len() {
+            return Err(UsageRequirementErr::NoFreeHands);
+        }
+        if !r.usable_underwater() && self.player.is_underwater.get() {
+            return Err(UsageRequirementErr::UsedUnderwater);
+        }
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,121 @@
+use crate::inventory::Inventory;
+use crate::error::{ItemReserveErr, InventoryUseErr};
+use crate::state::DirtyTracked;
+
+impl Inventory {
+    /// Locks units of an item kind, for example a weapon or a flashlight the character has
+    /// prepared. Locked units cannot be used by [`use_item`](#method.use_item), consumed,
+    /// taken as appliances or spent by crafting. Worn clothes and equipped items are locked automatically
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    /// - `count`: how many units to lock
+    ///
+    /// # Examples
+    /// ```
+    /// person.inventory.reserve_item(&"Knife".to_string(), 1);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn reserve_item(&self, item_name: &String, count: usize) -> Result<(), ItemReserveErr> {
+        let available = match self.available_count_of(item_name) {
+            Some(c) => c,
+            None => return Err(ItemReserveErr::ItemNotFound)
+        };
+
+        if count > available { return Err(ItemReserveErr::InsufficientResources); }
+
+        *self.reservations.borrow_mut().entry(item_name.to_string()).or_insert(0) += count;
+        self.mark_dirty();
+
+        Ok(())
+    }
+
+    /// Unlocks units of an item kind that were locked by [`reserve_item`](#method.reserve_item)
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    /// - `count`: how many units to unlock
+    ///
+    /// # Examples
+    /// ```
+    /// person.inventory.release_item(&"Knife".to_string(), 1);
+    /// ```
+    pub fn release_item(&self, item_name: &String, count: usize) -> Result<(), ItemReserveErr> {
+        let mut b = self.reservations.borrow_mut();
+        let reserved = match b.get(item_name) {
+            Some(r) => *r,
+            None => return Err(ItemReserveErr::NotReserved)
+        };
+
+        if count >= reserved {
+            b.remove(item_name);
+        } else {
+            b.insert(item_name.to_string(), reserved - count);
+        }
+        self.mark_dirty();
+
+        Ok(())
+    }
+
+    /// Returns how many units of an item kind are locked: reserved with
+    /// [`reserve_item`](#method.reserve_item) plus one if it is worn and one if it is equipped
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.inventory.locked_count_of(&"Jacket".to_string());
+    /// ```
+    pub fn locked_count_of(&self, item_name: &String) -> usize {
+        self.worn_count_of(item_name) + self.equipped_count_of(item_name) + self.reserved_count_of(item_name)
+    }
+
+    /// Returns how many units of an item kind can be used. `None` if item was not found
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(count) = person.inventory.available_count_of(item_name) {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn available_count_of(&self, item_name: &String) -> Option<usize> {
+        let b = self.stored_items.borrow();
+        let item = b.get(item_name)?;
+
+        if item.get_is_infinite() { return Some(item.get_count()); }
+
+        Some(item.get_count().saturating_sub(self.locked_count_of(item_name)))
+    }
+
+    /// Checks that a given amount of an item kind does not touch locked units
+    pub(crate) fn check_locked(&self, item_name: &String, count: usize, amount: usize) -> Result<(), InventoryUseErr> {
+        let worn = self.worn_count_of(item_name) + self.equipped_count_of(item_name);
+        let reserved = self.reserved_count_of(item_name);
+
+        if amount > count || amount + worn + reserved <= count { return Ok(()); }
+
+        Err(InventoryUseErr::ItemLocked(worn, reserved))
+    }
+
+    /// Forgets reservations of an item kind that was removed from the inventory
+    pub(crate) fn forget_reservation(&self, item_name: &String) {
+        self.reservations.borrow_mut().remove(item_name);
+    }
+
+    fn worn_count_of(&self, item_name: &String) -> usize {
+        if self.clothes_cache.borrow().contains(item_name) { 1 } else { 0 }
+    }
+
+    pub(crate) fn reserved_count_of(&self, item_name: &String) -> usize {
+        self.reservations.borrow().get(item_name).copied().unwrap_or(0)
+    }
+}
@@ -0,0 +1,88 @@
+use crate::inventory::{Inventory, InventoryChangeReason};
+use crate::inventory::items::InventoryItem;
+use crate::body::BodyPart;
+use crate::utils::event::{MessageQueue, Event};
+
+/// Function that creates an item that goes back to the inventory when a body appliance
+/// is removed from a given body part, for example a "used tourniquet"
+pub type ApplianceReturnFactory = Box<dyn Fn(BodyPart) -> Box<dyn InventoryItem>>;
+
+impl Inventory {
+    /// Registers a factory for the item that goes back to the inventory when a reusable body
+    /// appliance (like a splint or a tourniquet) is removed with
+    /// [`remove_appliance_with_return`](crate::ZaraController::remove_appliance_with_return).
+    /// Factory can return the same item kind or a "used" variant of it
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the appliance item kind
+    /// - `factory`: function that creates returned item for a given body part
+    ///
+    /// # Examples
+    /// ```
+    /// person.inventory.register_appliance_return(&"Splint".to_string(),
+    ///     Box::new(|_| Box::new(UsedSplint { count: 1 })));
+    /// ```
+    pub fn register_appliance_return(&self, item_name: &String, factory: ApplianceReturnFactory) {
+        self.appliance_returns.borrow_mut().insert(item_name.to_string(), factory);
+    }
+
+    /// Removes appliance return factory. Returns `false` if it was not registered
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the appliance item kind
+    ///
+    /// # Examples
+    /// ```
+    /// person.inventory.unregister_appliance_return(&"Splint".to_string());
+    /// ```
+    pub fn unregister_appliance_return(&self, item_name: &String) -> bool {
+        self.appliance_returns.borrow_mut().remove(item_name).is_some()
+    }
+
+    /// Returns `true` if removed appliance of this kind can go back to the inventory
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the appliance item kind
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.inventory.is_appliance_returnable(&"Splint".to_string());
+    /// ```
+    pub fn is_appliance_returnable(&self, item_name: &String) -> bool {
+        self.appliance_returns.borrow().contains_key(item_name)
+    }
+
+    /// Puts the returned item for a removed appliance into the inventory. Item count is added
+    /// to the existing item of the same kind
+    pub(crate) fn return_appliance(&self, item_name: &String, body_part: BodyPart) {
+        let item = match self.appliance_returns.borrow().get(item_name) {
+            Some(factory) => factory(body_part),
+            None => return
+        };
+        let key = item.get_id();
+        let (count, weight) = {
+            let mut items = self.stored_items.borrow_mut();
+
+            match items.get_mut(&key) {
+                Some(existing) => {
+                    let count = existing.get_count() + item.get_count();
+
+                    existing.set_count(count);
+
+                    (count, existing.get_total_weight())
+                },
+                None => {
+                    let result = (item.get_count(), item.get_total_weight());
+
+                    items.insert(key.to_string(), item);
+
+                    result
+                }
+            }
+        };
+
+        self.recalculate_weight_for(InventoryChangeReason::Added);
+
+        self.queue_message(Event::InventoryItemAdded(key, count, weight, InventoryChangeReason::Added));
+    }
+}
@@ -0,0 +1,163 @@
+use crate::inventory::Inventory;
+use crate::inventory::items::{InventoryItem, ItemInfoC, ApplianceInfoC};
+
+impl Inventory {
+    /// Returns owned read-only views of all inventory items, ordered by item name. Views can be
+    /// kept and passed around freely; they are not updated when the inventory changes
+    ///
+    /// # Examples
+    /// ```
+    /// for item in person.inventory.snapshot_items() {
+    ///     println!("{} x{}", item.name, item.count);
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn snapshot_items(&self) -> Vec<ItemInfoC> {
+        let items = self.stored_items.borrow();
+        let mut result: Vec<ItemInfoC> = items.iter().map(|(name, item)| {
+            let mut info = ItemInfoC {
+                name: name.to_string(),
+                count: item.get_count(),
+                available_count: self.available_count_of(name).unwrap_or(0),
+                total_weight: item.get_total_weight(),
+                is_infinite: item.get_is_infinite(),
+                is_clothes: item.clothes().is_some(),
+                freshness: self.freshness_of(name),
+                wetness: self.wetness_of(name),
+                ..Default::default()
+            };
+
+            if let Some(c) = item.consumable() {
+                info.is_consumable = true;
+                info.is_food = c.is_food();
+                info.is_water = c.is_water();
+                info.food_gain = c.food_gain_per_dose();
+                info.water_gain = c.water_gain_per_dose();
+
+                if let Some(s) = c.spoiling() {
+                    info.spoil_time = Some(s.spoil_time());
+                    info.fresh_poisoning_chance = s.fresh_poisoning_chance();
+                    info.spoiled_poisoning_chance = s.spoil_poisoning_chance();
+                }
+            }
+
+            info.appliance = item.appliance().map(|a| ApplianceInfoC {
+                is_body_appliance: a.is_body_appliance(),
+                is_injection: a.is_injection()
+            });
+
+            info
+        }).collect();
+
+        result.sort_by(|a, b| a.name.cmp(&b.name));
+
+        result
+    }
+
+    /// Calls a function for every inventory item, ordered by item name
+    ///
+    /// # Parameters
+    /// - `f`: function to call
+    ///
+    /// # Examples
+    /// ```
+    /// person.inventory.for_each_item(|item| println!("{}", item.get_name()));
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection for the whole call, so the function must not add, remove
+    /// or use items. Use [`snapshot_items`](#method.snapshot_items) for that
+    pub fn for_each_item<F: FnMut(&dyn InventoryItem)>(&self, mut f: F) {
+        let items = self.stored_items.borrow();
+        let mut names: Vec<&String> = items.keys().collect();
+
+        names.sort();
+
+        for name in names {
+            f(items[name].as_ref());
+        }
+    }
+
+    /// Reads an inventory item with a function
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item
+    /// - `f`: function that reads the item
+    ///
+    /// # Returns
+    /// Result of the function, `None` if there is no such item
+    ///
+    /// # Examples
+    /// ```
+    /// let count = person.inventory.with_item(&"Water Bottle".to_string(), |item| item.get_count());
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection for the whole call, so the function must not add, remove
+    /// or use items
+    pub fn with_item<R, F: FnOnce(&dyn InventoryItem) -> R>(&self, item_name: &String, f: F) -> Option<R> {
+        self.stored_items.borrow().get(item_name).map(|item| f(item.as_ref()))
+    }
+
+    /// Changes an inventory item with a function. Inventory weight is recalculated afterwards
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item
+    /// - `f`: function that changes the item
+    ///
+    /// # Returns
+    /// Result of the function, `None` if there is no such item
+    ///
+    /// # Examples
+    /// ```
+    /// person.inventory.with_item_mut(&"Water Bottle".to_string(), |item| item.set_count(2));
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection for the whole call, so the function must not add, remove
+    /// or use items
+    pub fn with_item_mut<R, F: FnOnce(&mut dyn InventoryItem) -> R>(&self, item_name: &String, f: F) -> Option<R> {
+        let result = {
+            let mut items = self.stored_items.borrow_mut();
+
+            items.get_mut(item_name).map(|item| f(item.as_mut()))
+        };
+
+        if result.is_some() { self.recalculate_weight(); }
+
+        result
+    }
+
+    /// Changes every inventory item with a function, ordered by item name. Inventory weight is
+    /// recalculated afterwards
+    ///
+    /// # Parameters
+    /// - `f`: function that changes an item
+    ///
+    /// # Examples
+    /// ```
+    /// person.inventory.for_each_item_mut(|item| if item.get_count() > 10 { item.set_count(10); });
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection for the whole call, so the function must not add, remove
+    /// or use items
+    pub fn for_each_item_mut<F: FnMut(&mut dyn InventoryItem)>(&self, mut f: F) {
+        {
+            let mut items = self.stored_items.borrow_mut();
+            let mut names: Vec<String> = items.keys().cloned().collect();
+
+            names.sort();
+
+            for name in names.iter() {
+                if let Some(item) = items.get_mut(name) {
+                    f(item.as_mut());
+                }
+            }
+        }
+
+        self.recalculate_weight();
+    }
+}
@@ -1,15 +1,44 @@
 use crate::inventory::Inventory;
+use crate::state::DirtyTracked;
 
+use std::cell::Cell;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 
 /// Contains state snapshot for the inventory node (not containing items itself)
 #[derive(Clone, Debug, Default)]
 pub struct InventoryStateContract {
+    /// Schema version this state was captured with
+    pub version: u32,
     /// Captured state of the `weight` field
     pub weight: f32,
     /// Captured state of the `clothes_cache` field
     pub clothes_cache: Vec<String>,
+    /// Captured state of the `equipment_cache` field
+    pub equipment_cache: Vec<String>,
+    /// Captured state of the `equipped_weight_factor` field
+    pub equipped_weight_factor: f32,
+    /// Captured state of the `cleanliness` field. Clean items are not listed
+    pub cleanliness: Vec<ItemCleanlinessStateContract>,
+    /// Captured state of the `reservations` field
+    pub reservations: Vec<ItemReservationStateContract>,
+    /// Captured state of the `wet_clothes` field. Dry items are not listed
+    pub wet_clothes: Vec<ItemWetnessStateContract>,
+    /// Captured state of the `water_weight` field
+    pub water_weight: f32,
+    /// Captured state of the `item_ages` field. Fresh items are not listed
+    pub item_ages: Vec<ItemAgeStateContract>,
+    /// Captured state of the `contamination` field. Water items that are as contaminated as
+    /// their descriptions say are not listed
+    pub contamination: Vec<ItemContaminationStateContract>,
+    /// Captured state of the `active_lights` field
+    pub active_lights: Vec<String>,
+    /// Captured state of the `light_burn` field
+    pub light_burn: Vec<ItemLightBurnStateContract>,
+    /// Captured state of the `active_electronics` field
+    pub active_electronics: Vec<String>,
+    /// Captured state of the `charges` field. Fully charged items are not listed
+    pub charges: Vec<ItemChargeStateContract>
 }
 impl fmt::Display for InventoryStateContract {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -21,27 +50,338 @@ impl PartialEq for InventoryStateContract {
     fn eq(&self, other: &Self) -> bool {
         const EPS: f32 = 0.0001;
 
+        self.version == other.version &&
         self.clothes_cache == other.clothes_cache &&
-        f32::abs(self.weight - other.weight) < EPS
+        self.equipment_cache == other.equipment_cache &&
+        self.cleanliness == other.cleanliness &&
+        self.reservations == other.reservations &&
+        self.wet_clothes == other.wet_clothes &&
+        self.item_ages == other.item_ages &&
+        self.contamination == other.contamination &&
+        self.active_lights == other.active_lights &&
+        self.light_burn == other.light_burn &&
+        self.active_electronics == other.active_electronics &&
+        self.charges == other.charges &&
+        f32::abs(self.weight - other.weight) < EPS &&
+        f32::abs(self.water_weight - other.water_weight) < EPS &&
+        f32::abs(self.equipped_weight_factor - other.equipped_weight_factor) < EPS
     }
 }
 impl Hash for InventoryStateContract {
     fn hash<H: Hasher>(&self, state: &mut H) {
+        self.version.hash(state);
         self.clothes_cache.hash(state);
+        self.equipment_cache.hash(state);
+        self.cleanliness.hash(state);
+        self.reservations.hash(state);
+        self.wet_clothes.hash(state);
+        self.item_ages.hash(state);
+        self.contamination.hash(state);
+        self.active_lights.hash(state);
+        self.light_burn.hash(state);
+        self.active_electronics.hash(state);
+        self.charges.hash(state);
 
         state.write_u32((self.weight*1_000_f32) as u32);
+        state.write_u32((self.water_weight*1_000_f32) as u32);
+        state.write_u32((self.equipped_weight_factor*10_000_f32) as u32);
     }
 }
 
+/// Contains state snapshot for a dirty inventory item kind
+#[derive(Clone, Debug, Default)]
+pub struct ItemCleanlinessStateContract {
+    /// Unique name of the item kind
+    pub item_name: String,
+    /// Item cleanliness (0..100)
+    pub cleanliness: f32
+}
+impl fmt::Display for ItemCleanlinessStateContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} cleanliness {:.0}", self.item_name, self.cleanliness)
+    }
+}
+impl Eq for ItemCleanlinessStateContract { }
+impl PartialEq for ItemCleanlinessStateContract {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.item_name == other.item_name &&
+        f32::abs(self.cleanliness - other.cleanliness) < EPS
+    }
+}
+impl Hash for ItemCleanlinessStateContract {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.item_name.hash(state);
+
+        state.write_u32((self.cleanliness*10_000_f32) as u32);
+    }
+}
+
+/// Contains state snapshot for the reserved units of an inventory item kind
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct ItemReservationStateContract {
+    /// Unique name of the item kind
+    pub item_name: String,
+    /// Reserved units count
+    pub count: usize
+}
+impl fmt::Display for ItemReservationStateContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} reserved {}", self.item_name, self.count)
+    }
+}
+
+/// Contains state snapshot for wet clothes that were taken off
+#[derive(Clone, Debug, Default)]
+pub struct ItemWetnessStateContract {
+    /// Unique name of the item kind
+    pub item_name: String,
+    /// Item wetness (0..100)
+    pub wetness: f32
+}
+impl fmt::Display for ItemWetnessStateContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} wetness {:.0}", self.item_name, self.wetness)
+    }
+}
+impl Eq for ItemWetnessStateContract { }
+impl PartialEq for ItemWetnessStateContract {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.item_name == other.item_name &&
+        f32::abs(self.wetness - other.wetness) < EPS
+    }
+}
+impl Hash for ItemWetnessStateContract {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.item_name.hash(state);
+
+        state.write_u32((self.wetness*10_000_f32) as u32);
+    }
+}
+
+/// Contains state snapshot for a spoiling or expiring inventory item kind
+#[derive(Clone, Debug, Default)]
+pub struct ItemAgeStateContract {
+    /// Unique name of the item kind
+    pub item_name: String,
+    /// Item age, game seconds
+    pub age: f32
+}
+impl fmt::Display for ItemAgeStateContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} age {:.0}s", self.item_name, self.age)
+    }
+}
+impl Eq for ItemAgeStateContract { }
+impl PartialEq for ItemAgeStateContract {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.item_name == other.item_name &&
+        f32::abs(self.age - other.age) < EPS
+    }
+}
+impl Hash for ItemAgeStateContract {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.item_name.hash(state);
+
+        state.write_u32((self.age*10_000_f32) as u32);
+    }
+}
+
+/// Contains state snapshot for a water inventory item kind with changed contamination
+#[derive(Clone, Debug, Default)]
+pub struct ItemContaminationStateContract {
+    /// Unique name of the item kind
+    pub item_name: String,
+    /// Water contamination (0..100)
+    pub contamination: f32
+}
+impl fmt::Display for ItemContaminationStateContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} contamination {:.0}", self.item_name, self.contamination)
+    }
+}
+impl Eq for ItemContaminationStateContract { }
+impl PartialEq for ItemContaminationStateContract {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.item_name == other.item_name &&
+        f32::abs(self.contamination - other.contamination) < EPS
+    }
+}
+impl Hash for ItemContaminationStateContract {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.item_name.hash(state);
+
+        state.write_u32((self.contamination*10_000_f32) as u32);
+    }
+}
+
+/// Contains state snapshot for a light item kind that has burned some of its fuel
+#[derive(Clone, Debug, Default)]
+pub struct ItemLightBurnStateContract {
+    /// Unique name of the item kind
+    pub item_name: String,
+    /// How long the current fuel unit has burned, game seconds
+    pub burned: f32
+}
+impl fmt::Display for ItemLightBurnStateContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} burned {:.0}s", self.item_name, self.burned)
+    }
+}
+impl Eq for ItemLightBurnStateContract { }
+impl PartialEq for ItemLightBurnStateContract {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.item_name == other.item_name &&
+        f32::abs(self.burned - other.burned) < EPS
+    }
+}
+impl Hash for ItemLightBurnStateContract {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.item_name.hash(state);
+
+        state.write_u32((self.burned*10_000_f32) as u32);
+    }
+}
+
+/// Contains state snapshot for an electronic item kind that is not fully charged
+#[derive(Clone, Debug, Default)]
+pub struct ItemChargeStateContract {
+    /// Unique name of the item kind
+    pub item_name: String,
+    /// Charge level (0..100)
+    pub charge: f32
+}
+impl fmt::Display for ItemChargeStateContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} charge {:.0}", self.item_name, self.charge)
+    }
+}
+impl Eq for ItemChargeStateContract { }
+impl PartialEq for ItemChargeStateContract {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.item_name == other.item_name &&
+        f32::abs(self.charge - other.charge) < EPS
+    }
+}
+impl Hash for ItemChargeStateContract {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.item_name.hash(state);
+
+        state.write_u32((self.charge*10_000_f32) as u32);
+    }
+}
+
+impl DirtyTracked for Inventory {
+    fn dirty_flag(&self) -> &Cell<bool> { &self.is_dirty }
+}
 impl Inventory {
     pub(crate) fn get_state(&self) -> InventoryStateContract {
         InventoryStateContract {
+            version: crate::state::STATE_CONTRACT_VERSION,
             weight: self.weight.get(),
-            clothes_cache: self.clothes_cache.borrow().clone()
+            clothes_cache: self.clothes_cache.borrow().clone(),
+            equipment_cache: self.equipment_cache.borrow().clone(),
+            equipped_weight_factor: self.equipped_weight_factor.get(),
+            cleanliness: {
+                let mut v: Vec<ItemCleanlinessStateContract> = self.cleanliness.borrow().iter()
+                    .map(|(k, c)| ItemCleanlinessStateContract { item_name: k.to_string(), cleanliness: *c })
+                    .collect();
+
+                v.sort_by(|a, b| a.item_name.cmp(&b.item_name));
+
+                v
+            },
+            reservations: {
+                let mut v: Vec<ItemReservationStateContract> = self.reservations.borrow().iter()
+                    .map(|(k, c)| ItemReservationStateContract { item_name: k.to_string(), count: *c })
+                    .collect();
+
+                v.sort_by(|a, b| a.item_name.cmp(&b.item_name));
+
+                v
+            },
+            wet_clothes: {
+                let mut v: Vec<ItemWetnessStateContract> = self.wet_clothes.borrow().iter()
+                    .map(|(k, w)| ItemWetnessStateContract { item_name: k.to_string(), wetness: *w })
+                    .collect();
+
+                v.sort_by(|a, b| a.item_name.cmp(&b.item_name));
+
+                v
+            },
+            water_weight: self.water_weight.get(),
+            item_ages: {
+                let mut v: Vec<ItemAgeStateContract> = self.item_ages.borrow().iter()
+                    .map(|(k, a)| ItemAgeStateContract { item_name: k.to_string(), age: *a })
+                    .collect();
+
+                v.sort_by(|a, b| a.item_name.cmp(&b.item_name));
+
+                v
+            },
+            contamination: {
+                let mut v: Vec<ItemContaminationStateContract> = self.contamination.borrow().iter()
+                    .map(|(k, c)| ItemContaminationStateContract { item_name: k.to_string(), contamination: *c })
+                    .collect();
+
+                v.sort_by(|a, b| a.item_name.cmp(&b.item_name));
+
+                v
+            },
+            active_lights: self.active_lights.borrow().clone(),
+            light_burn: {
+                let mut v: Vec<ItemLightBurnStateContract> = self.light_burn.borrow().iter()
+                    .map(|(k, b)| ItemLightBurnStateContract { item_name: k.to_string(), burned: *b })
+                    .collect();
+
+                v.sort_by(|a, b| a.item_name.cmp(&b.item_name));
+
+                v
+            },
+            active_electronics: self.active_electronics.borrow().clone(),
+            charges: {
+                let mut v: Vec<ItemChargeStateContract> = self.charges.borrow().iter()
+                    .map(|(k, c)| ItemChargeStateContract { item_name: k.to_string(), charge: *c })
+                    .collect();
+
+                v.sort_by(|a, b| a.item_name.cmp(&b.item_name));
+
+                v
+            }
         }
     }
     pub(crate) fn restore_state(&self, state: &InventoryStateContract) {
         self.weight.set(state.weight);
         self.clothes_cache.replace(state.clothes_cache.clone());
+        self.equipment_cache.replace(state.equipment_cache.clone());
+        self.equipped_weight_factor.set(state.equipped_weight_factor);
+        self.cleanliness.replace(state.cleanliness.iter()
+            .map(|c| (c.item_name.to_string(), c.cleanliness)).collect());
+        self.reservations.replace(state.reservations.iter()
+            .map(|r| (r.item_name.to_string(), r.count)).collect());
+        self.wet_clothes.replace(state.wet_clothes.iter()
+            .map(|w| (w.item_name.to_string(), w.wetness)).collect());
+        self.water_weight.set(state.water_weight);
+        self.item_ages.replace(state.item_ages.iter()
+            .map(|a| (a.item_name.to_string(), a.age)).collect());
+        self.contamination.replace(state.contamination.iter()
+            .map(|c| (c.item_name.to_string(), c.contamination)).collect());
+        self.active_lights.replace(state.active_lights.clone());
+        self.light_burn.replace(state.light_burn.iter()
+            .map(|b| (b.item_name.to_string(), b.burned)).collect());
+        self.active_electronics.replace(state.active_electronics.clone());
+        self.charges.replace(state.charges.iter()
+            .map(|c| (c.item_name.to_string(), c.charge)).collect());
     }
 }
\ No newline at end of file
@@ -0,0 +1,84 @@
+use crate::inventory::Inventory;
+use crate::utils::EnvironmentC;
+
+use std::fmt;
+
+/// What made a tool fail
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub enum ToolFailureCause {
+    /// Too cold for the tool
+    Cold,
+    /// Rain outside of a shelter
+    Rain,
+    /// Character is wet
+    Wetness
+}
+impl fmt::Display for ToolFailureCause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Result of a [`try_use_tool`](crate::ZaraController::try_use_tool) call
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ToolUseOutcome {
+    /// Tool worked
+    Worked,
+    /// Tool failed because of the weather
+    Failed(ToolFailureCause)
+}
+impl fmt::Display for ToolUseOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Inventory {
+    /// Returns chance (0..100) that a tool fails if used in a given weather, combining all its
+    /// weather vulnerabilities. `None` if item was not found or is not a tool
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    /// - `environment`: weather to check against
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(chance) = person.inventory.tool_failure_chance(item_name, &environment) {
+    ///     // Show "might not light" hint
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn tool_failure_chance(&self, item_name: &String, environment: &EnvironmentC) -> Option<usize> {
+        let chances = self.tool_failure_chances(item_name, environment)?;
+        let works = chances.iter().fold(1., |acc, (_, chance)| acc * (1. - *chance as f32 / 100.));
+
+        Some(((1. - works) * 100.).round() as usize)
+    }
+
+    /// Failure chances (0..100) of a tool by their causes, in the order they are rolled
+    pub(crate) fn tool_failure_chances(&self, item_name: &String, environment: &EnvironmentC) -> Option<Vec<(ToolFailureCause, usize)>> {
+        let items = self.stored_items.borrow();
+        let tool = items.get(item_name)?.tool()?;
+        let mut result = Vec::new();
+
+        if tool.min_temperature().is_some_and(|t| environment.temperature < t) {
+            result.push((ToolFailureCause::Cold, usize::min(tool.cold_failure_chance(), 100)));
+        }
+        if !self.player.is_inside_shelter.get() {
+            let rain = crate::utils::clamp_01(environment.rain_intensity);
+
+            result.push((ToolFailureCause::Rain, (usize::min(tool.rain_failure_chance(), 100) as f32 * rain).round() as usize));
+        }
+        {
+            let wetness = crate::utils::clamp_01(self.body.wetness_level() / 100.);
+
+            result.push((ToolFailureCause::Wetness, (usize::min(tool.wet_failure_chance(), 100) as f32 * wetness).round() as usize));
+        }
+
+        result.retain(|(_, chance)| *chance > 0);
+
+        Some(result)
+    }
+}
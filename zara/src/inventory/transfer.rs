@@ -0,0 +1,109 @@
+use crate::inventory::{Inventory, InventoryChangeReason};
+use crate::inventory::items::InventoryItem;
+use crate::utils::event::{Event, MessageQueue};
+use crate::error::ItemTransferErr;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Per-kind state of an item that goes along with it to another inventory
+struct TransferredItemState {
+    cleanliness: Option<f32>,
+    age: Option<f32>,
+    contamination: Option<f32>,
+    wetness: Option<f32>,
+    light_burn: Option<f32>,
+    charge: Option<f32>
+}
+
+impl Inventory {
+    /// Moves units of an item kind to another inventory, like from a character inventory to a
+    /// [`Stash`](crate::stash::Stash) and back. Worn, equipped and reserved units cannot be moved.
+    /// If the other inventory does not have this item kind yet, item age, cleanliness,
+    /// contamination, wetness, fuel and charge go along with it; otherwise the state of the other
+    /// inventory is kept
+    ///
+    /// # Parameters
+    /// - `target`: inventory to move the units to
+    /// - `item_name`: unique name of the item (item kind)
+    /// - `count`: how many units to move
+    ///
+    /// # Examples
+    /// ```
+    /// person.inventory.transfer_item(&chest.inventory, &meat_name, 2);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Inventory) for more info.
+    ///
+    /// ## Notes
+    /// Borrows `items` collections of both inventories. Item must support `clone_boxed`
+    pub fn transfer_item(&self, target: &Inventory, item_name: &String, count: usize) -> Result<(), ItemTransferErr> {
+        if std::ptr::eq(self, target) { return Err(ItemTransferErr::SameInventory); }
+
+        let available = match self.available_count_of(item_name) {
+            Some(o) => o,
+            None => return Err(ItemTransferErr::ItemNotFound)
+        };
+
+        if available < count { return Err(ItemTransferErr::InsufficientResources); }
+        if count == 0 { return Ok(()); }
+
+        let copy = self.duplicate_item(item_name, count)
+            .map_err(|_| ItemTransferErr::ItemIsNotCloneable)?;
+        let state = if target.has_item(item_name) { None } else { Some(self.transferred_state_of(item_name)) };
+
+        self.use_item_for(item_name, count, InventoryChangeReason::Transferred).map_err(ItemTransferErr::CouldNotUseItem)?;
+
+        if let Some(state) = state {
+            target.apply_transferred_state(item_name, state);
+        }
+        target.receive_item(copy);
+
+        Ok(())
+    }
+
+    /// Adds a received item. If this item kind is already in the inventory, its count is increased
+    pub(crate) fn receive_item(&self, item: Box<dyn InventoryItem>) {
+        let key = item.get_id();
+        let merged = match self.stored_items.borrow_mut().get_mut(&key) {
+            Some(existing) => {
+                existing.set_count(existing.get_count() + item.get_count());
+                Some((existing.get_count(), existing.get_total_weight()))
+            },
+            None => None
+        };
+        let (count, weight) = match merged {
+            Some(o) => o,
+            None => return self.add_item_for(item, InventoryChangeReason::Transferred)
+        };
+
+        self.recalculate_weight_for(InventoryChangeReason::Transferred);
+
+        self.queue_message(Event::InventoryItemAdded(key, count, weight, InventoryChangeReason::Transferred));
+    }
+
+    fn transferred_state_of(&self, item_name: &String) -> TransferredItemState {
+        TransferredItemState {
+            cleanliness: self.cleanliness.borrow().get(item_name).copied(),
+            age: self.item_ages.borrow().get(item_name).copied(),
+            contamination: self.contamination.borrow().get(item_name).copied(),
+            wetness: self.wet_clothes.borrow().get(item_name).copied(),
+            light_burn: self.light_burn.borrow().get(item_name).copied(),
+            charge: self.charges.borrow().get(item_name).copied()
+        }
+    }
+
+    fn apply_transferred_state(&self, item_name: &String, state: TransferredItemState) {
+        let set = |map: &RefCell<HashMap<String, f32>>, value: Option<f32>| {
+            if let Some(value) = value { map.borrow_mut().insert(item_name.to_string(), value); }
+        };
+
+        set(&self.cleanliness, state.cleanliness);
+        set(&self.item_ages, state.age);
+        set(&self.contamination, state.contamination);
+        set(&self.wet_clothes, state.wetness);
+        set(&self.light_burn, state.light_burn);
+        set(&self.charges, state.charge);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::inventory::Inventory;
 use crate::utils::event::Listener;
 use crate::utils::FrameC;
+use crate::state::DirtyTracked;
 
 impl Inventory {
     /// This method is called every `UPDATE_INTERVAL` real seconds
@@ -8,8 +9,10 @@ impl Inventory {
     /// # Parameters
     /// - `frame`: summary information for this frame
     pub(crate) fn update<E: Listener + 'static>(&self, frame: &mut FrameC<E>) {
+        self.mark_dirty();
+
         // Check all inventory monitors
-        for (_, monitor) in self.inventory_monitors.borrow().iter() {
+        for (_, monitor) in self.monitor_priorities.ordered(&self.inventory_monitors.borrow()) {
             monitor.check(&self, &frame.data);
         }
     }
@@ -0,0 +1,132 @@
+use crate::inventory::Inventory;
+use crate::inventory::items::InventoryItem;
+use crate::utils::EnvironmentC;
+use crate::state::DirtyTracked;
+
+impl Inventory {
+    /// Returns wetness level (0..100) of a clothes item kind. Worn clothes are as wet as
+    /// the character; clothes taken off keep their wetness and dry with time. Wet clothes
+    /// are heavier (see [`water_absorption`](crate::inventory::items::ClothesDescription::water_absorption)).
+    /// `None` if item was not found or is not clothes
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item (item kind)
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(value) = person.inventory.wetness_of(item_name) {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items` collection
+    pub fn wetness_of(&self, item_name: &String) -> Option<f32> {
+        self.stored_items.borrow().get(item_name)?.clothes()?;
+
+        Some(self.wetness_of_internal(item_name))
+    }
+
+    /// Returns weight of the water absorbed by wet clothes (in grams), worn or carried. It is
+    /// included in the [`get_weight`](#method.get_weight) value
+    ///
+    /// # Examples
+    /// ```
+    /// let weight = person.inventory.get_water_weight();
+    /// ```
+    pub fn get_water_weight(&self) -> f32 { self.water_weight.get() }
+
+    /// Weight of the water (in grams) absorbed by a clothes item kind. For worn clothes only
+    /// the worn unit is wet
+    pub(crate) fn absorbed_water_weight(&self, name: &String, item: &dyn InventoryItem, is_worn: bool) -> f32 {
+        let absorption = match item.clothes() {
+            Some(c) => f32::max(c.water_absorption(), 0.),
+            None => return 0.
+        };
+        // Whole percents, so that weight does not change every frame
+        let wetness = f32::floor(self.wetness_of_internal(name)) / 100.;
+
+        if wetness <= 0. { return 0. }
+
+        let weight = if is_worn {
+            if item.get_count() == 0 { return 0. }
+
+            item.get_total_weight() / item.get_count() as f32
+        } else {
+            item.get_total_weight()
+        };
+
+        weight * absorption * wetness
+    }
+
+    /// Clothes taken off keep the character wetness, clothes put on are as wet as the character
+    pub(crate) fn on_clothes_cache_changed(&self, old_clothes: &[String], new_clothes: &[String]) {
+        let wetness = self.body.wetness_level();
+        let mut b = self.wet_clothes.borrow_mut();
+
+        for name in old_clothes.iter().filter(|n| !new_clothes.contains(n)) {
+            if wetness > 0. && self.stored_items.borrow().contains_key(name) {
+                b.insert(name.to_string(), wetness);
+            }
+        }
+        for name in new_clothes.iter() {
+            b.remove(name);
+        }
+    }
+
+    /// Dries carried clothes and updates weight of the wet clothes
+    pub(crate) fn update_wet_clothes(&self, game_time_delta: f32) {
+        self.mark_dirty();
+
+        {
+            let mut b = self.wet_clothes.borrow_mut();
+
+            if b.is_empty() && self.body.wetness_level() <= 0. && self.water_weight.get() <= 0. { return; }
+
+            for (_, wetness) in b.iter_mut() {
+                *wetness = self.body.dried_wetness(*wetness, game_time_delta);
+            }
+
+            b.retain(|_, w| *w > 0.);
+        }
+
+        self.recalculate_weight();
+    }
+
+    /// Wets or dries all clothes of the inventory under a given environment, like clothes lying
+    /// in a stash, and updates weight of the wet clothes
+    pub(crate) fn update_exposed_clothes(&self, game_time_delta: f32, environment: &EnvironmentC) {
+        self.mark_dirty();
+
+        {
+            let items = self.stored_items.borrow();
+            let mut b = self.wet_clothes.borrow_mut();
+
+            for (name, _) in items.iter().filter(|(_, o)| o.clothes().is_some()) {
+                let current = b.get(name).copied().unwrap_or(0.);
+                let wetness = self.body.exposed_wetness(current, environment, game_time_delta);
+
+                if wetness > 0. {
+                    b.insert(name.to_string(), wetness);
+                } else {
+                    b.remove(name);
+                }
+            }
+        }
+
+        self.recalculate_weight();
+    }
+
+    /// Forgets wetness of an item kind that was removed from the inventory
+    pub(crate) fn forget_wetness(&self, item_name: &String) {
+        self.wet_clothes.borrow_mut().remove(item_name);
+    }
+
+    fn wetness_of_internal(&self, name: &String) -> f32 {
+        if self.clothes_cache.borrow().contains(name) {
+            return self.body.wetness_level();
+        }
+
+        self.wet_clothes.borrow().get(name).copied().unwrap_or(0.)
+    }
+}
@@ -1,16 +1,29 @@
 use error::*;
 use utils::{GameTime, EnvironmentC};
-use utils::event::{Event, Listener, Dispatcher, Dispatchable};
-use player::{PlayerStatus};
+use utils::event::{Event, EventSeverity, Listener, Dispatcher, Dispatchable};
+use player::{PlayerStatus, CharacterProfile};
 use inventory::items::{ConsumableC, ApplianceC};
+use inventory::tools::ToolUseOutcome;
+use inventory::InventoryChangeReason;
+use inventory::cleanliness::{DIRTY_CONSUMABLE_INFECTION_CHANCE, DIRTY_APPLIANCE_INFECTION_CHANCE, BLOODIED_HANDS_INFECTION_CHANCE};
 use body::BodyPart;
+use body::parts::{BodyPartStatus, BodyPartGroup};
+use health::InjuryKey;
+use health::condition::LongTermCondition;
+use health::burns::BurnSource;
+use health::procedure::PROCEDURE_FAILURE_MODIFIER;
+use pending::{PausedActionsMode, PendingAction};
+use state::DirtyTracked;
 
 use std::sync::Arc;
+use std::rc::Rc;
 use std::cell::{Cell, RefCell};
 use std::time::Duration;
+use std::collections::BTreeMap;
 
 mod update;
 mod status_methods;
+mod debug;
 
 pub mod state;
 pub mod world;
@@ -20,6 +33,28 @@ pub mod health;
 pub mod inventory;
 pub mod body;
 pub mod player;
+pub mod skills;
+pub mod stats;
+pub mod traits;
+pub mod pending;
+pub mod revive;
+pub mod daily;
+pub mod manager;
+pub mod hints;
+pub mod hooks;
+pub mod barter;
+pub mod stash;
+#[cfg(feature = "data")]
+pub mod data;
+#[cfg(feature = "content")]
+pub mod content;
+
+/// Derive macros for inventory items, consumables, diseases and injuries.
+/// Requires the `derive` feature
+#[cfg(feature = "derive")]
+pub mod derive {
+    pub use zara_derive::{InventoryItem, Consumable, disease, injury};
+}
 
 /// Zara survival framework controller.
 ///
@@ -51,6 +86,18 @@ pub struct ZaraController<E: Listener + 'static> {
     ///
     /// Use this to tell Zara state of a player (is he running, walking, swimming etc.)
     pub player_state: Arc<PlayerStatus>,
+    /// Character traits registry.
+    ///
+    /// Use this to give character traits like `IronStomach` or `Hemophilia`.
+    pub traits: Rc<traits::TraitRegistry>,
+    /// Skills node.
+    ///
+    /// Use this to register skills and check their levels.
+    pub skills: Arc<skills::Skills>,
+    /// Statistics node.
+    ///
+    /// Use this to read lifetime counters of the character (for achievements and so on).
+    pub stats: Arc<stats::Stats>,
 
     // Private fields
     /// How many seconds passed since last `update` call
@@ -63,8 +110,42 @@ pub struct ZaraController<E: Listener + 'static> {
     last_frame_game_time: Cell<Duration>,
     /// Is controller paused
     is_paused: Cell<bool>,
+    /// How actions issued while paused are treated
+    paused_actions_mode: Cell<PausedActionsMode>,
+    /// Actions queued while paused, in the order of issue
+    pending_actions: RefCell<Vec<PendingAction>>,
+    /// Game time of the character death, `None` while character is alive
+    death_time: Cell<Option<Duration>>,
+    /// Base character profile, before skills are applied
+    profile: Cell<CharacterProfile>,
+    /// Aggregates of the current game day
+    day_summary: RefCell<daily::DaySummaryC>,
+    /// Game-specific data passed to the nodes with every frame
+    frame_extensions: RefCell<utils::FrameExtensionsC>,
+    /// Nodes changed by each of the last captured state snapshots, used to build state deltas
+    state_snapshots: RefCell<BTreeMap<usize, state::DirtyNodesC>>,
+    /// Id of the last captured, restored or applied state snapshot
+    last_snapshot_id: Cell<usize>,
+    /// Id of the snapshot that was restored or applied last
+    applied_snapshot_id: Cell<Option<usize>>,
+    /// Environment as of the last state snapshot
+    snapshot_environment: RefCell<state::EnvironmentStateContract>,
+    /// Player status as of the last state snapshot
+    snapshot_player_status: RefCell<state::PlayerStatusContract>,
     /// Events dispatcher
     dispatcher: Arc<RefCell<Dispatcher<E>>>,
+    /// Are dispatched events being delivered to the listener now
+    is_delivering_events: Cell<bool>,
+    /// Audio cue intensities as of the last `update` call
+    audio_hints: Cell<hints::AudioHints>,
+    /// Screen effect strengths as of the last `update` call
+    visual_hints: Cell<hints::VisualHintsC>,
+    /// Curves used to calculate the screen effects
+    visual_hint_curves: Cell<hints::VisualHintCurves>,
+    /// Callbacks called around processed frames
+    update_hooks: RefCell<hooks::UpdateHooks>,
+    /// Frame summary of the last processed frame
+    last_frame_summary: RefCell<Option<utils::FrameSummaryC>>,
     // Need this reference here to keep listener in memory
     // or else notifications won't dispatch
     #[allow(dead_code)]
@@ -89,7 +170,7 @@ impl<E: Listener + 'static> ZaraController<E> {
     ///
     /// let person = zara::ZaraController::new(listener);
     /// ```
-    pub fn new(listener : E) -> Self { ZaraController::init(listener, EnvironmentC::default()) }
+    pub fn new(listener : E) -> Self { ZaraController::init(listener, EnvironmentC::default(), CharacterProfile::default()) }
 
     /// Creates a new `ZaraController` with pre-defined environment.
     ///
@@ -108,37 +189,165 @@ impl<E: Listener + 'static> ZaraController<E> {
     ///
     /// let person = zara::ZaraController::with_environment(listener, env);
     /// ```
-    pub fn with_environment(listener : E, env: EnvironmentC) -> Self { ZaraController::init(listener, env) }
+    pub fn with_environment(listener : E, env: EnvironmentC) -> Self {
+        ZaraController::init(listener, env, CharacterProfile::default())
+    }
+
+    /// Creates a new `ZaraController` with pre-defined environment and character profile.
+    ///
+    /// # Parameters
+    /// - `listener`: [`Listener`](crate::utils::event::Listener) instance to notify about Zara events
+    /// - `env`: [`EnvironmentC`](crate::utils::EnvironmentC) object that describes initial state of the environment
+    /// - `profile`: [`CharacterProfile`](crate::player::CharacterProfile) that describes physiology of this character
+    ///
+    /// # Examples
+    /// ```
+    /// use zara;
+    /// use zara::player::CharacterProfile;
+    ///
+    /// let person = zara::ZaraController::with_profile(listener, env, CharacterProfile::athlete());
+    /// ```
+    pub fn with_profile(listener : E, env: EnvironmentC, profile: CharacterProfile) -> Self {
+        ZaraController::init(listener, env, profile)
+    }
 
     /// Private initialization function
-    fn init(listener : E, env: EnvironmentC) -> Self {
+    fn init(listener : E, env: EnvironmentC, profile: CharacterProfile) -> Self {
         // Register external events listener
         let mut dispatcher: Dispatcher<E> = Dispatcher::<E>::new();
         let listener_rc = Arc::new(RefCell::new(listener));
 
         dispatcher.register_listener(listener_rc.clone());
 
-        ZaraController {
-            environment: Arc::new(world::EnvironmentData::from_description(env)),
-            health: Arc::new(health::Health::new()),
-            inventory: Arc::new(inventory::Inventory::new()),
-            body: Arc::new(body::Body::new()),
+        let traits = Rc::new(traits::TraitRegistry::new());
+        let body_parts = Rc::new(body::parts::BodyPartsRegistry::new());
+        let player_state = Arc::new(PlayerStatus::empty());
+        let body = Arc::new(body::Body::new(body_parts.clone()));
+        let skills = Arc::new(skills::Skills::new());
+
+        let environment = Arc::new(world::EnvironmentData::from_description(env));
+
+        let controller = ZaraController {
+            health: Arc::new(health::Health::new(traits.clone(), body_parts)),
+            inventory: Arc::new(inventory::Inventory::new(player_state.clone(), skills.clone(), body.clone(),
+                                                          environment.clone())),
+            environment,
+            body,
+            skills,
+            stats: Arc::new(stats::Stats::new()),
+            traits,
 
             update_counter: Cell::new(0.),
             queue_counter: Cell::new(0.),
             last_update_game_time: Cell::new(Duration::new(0,0)),
             last_frame_game_time: Cell::new(Duration::new(0,0)),
-            player_state: Arc::new(PlayerStatus::empty()),
+            player_state,
             is_paused: Cell::new(false),
+            paused_actions_mode: Cell::new(PausedActionsMode::default()),
+            pending_actions: RefCell::new(Vec::new()),
+            death_time: Cell::new(None),
+            profile: Cell::new(profile),
+            day_summary: RefCell::new(daily::DaySummaryC::default()),
+            frame_extensions: RefCell::new(utils::FrameExtensionsC::default()),
+            state_snapshots: RefCell::new(BTreeMap::new()),
+            last_snapshot_id: Cell::new(0),
+            applied_snapshot_id: Cell::new(None),
+            snapshot_environment: RefCell::new(state::EnvironmentStateContract::default()),
+            snapshot_player_status: RefCell::new(state::PlayerStatusContract::default()),
 
             dispatcher: Arc::new(RefCell::new(dispatcher)),
+            is_delivering_events: Cell::new(false),
+            audio_hints: Cell::new(hints::AudioHints::default()),
+            visual_hints: Cell::new(hints::VisualHintsC::default()),
+            visual_hint_curves: Cell::new(hints::VisualHintCurves::default()),
+            update_hooks: RefCell::new(hooks::UpdateHooks::default()),
+            last_frame_summary: RefCell::new(None),
             listener: listener_rc
-        }
+        };
+
+        controller.refresh_profile();
+        controller.reset_day_summary();
+
+        controller
+    }
+
+    /// Sets character profile that describes physiology of this character. Profile is consulted
+    /// by the health math and warmth calculations. Skill effects are applied on top of it
+    ///
+    /// # Parameters
+    /// - `profile`: new [`CharacterProfile`](crate::player::CharacterProfile)
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::player::CharacterProfile;
+    ///
+    /// person.set_profile(CharacterProfile::elderly());
+    /// ```
+    pub fn set_profile(&self, profile: CharacterProfile) {
+        self.profile.set(profile);
+
+        self.refresh_profile();
+    }
+
+    /// Returns base character profile (without skill effects applied). Effective profile
+    /// can be read from `health.profile`
+    ///
+    /// # Examples
+    /// ```
+    /// let profile = person.profile();
+    /// ```
+    pub fn profile(&self) -> CharacterProfile { self.profile.get() }
+
+    /// Sets game-specific data (like altitude or terrain type) that custom monitors and side
+    /// effects can read from the frame summary `extensions` field. One value is kept per type;
+    /// setting a value of the same type replaces the old one. Values are passed to every
+    /// following update until removed
+    ///
+    /// # Parameters
+    /// - `value`: data to pass
+    ///
+    /// # Examples
+    /// ```
+    /// struct Terrain { altitude: f32 }
+    ///
+    /// person.set_frame_extension(Terrain { altitude: 2500. });
+    /// ```
+    pub fn set_frame_extension<T: std::any::Any>(&self, value: T) {
+        self.frame_extensions.borrow_mut().insert(value);
+    }
+
+    /// Removes game-specific frame data of a given type. Returns `false` if it was not set
+    ///
+    /// # Examples
+    /// ```
+    /// person.remove_frame_extension::<Terrain>();
+    /// ```
+    pub fn remove_frame_extension<T: std::any::Any>(&self) -> bool {
+        self.frame_extensions.borrow_mut().remove::<T>()
+    }
+
+    /// Removes all game-specific frame data
+    ///
+    /// # Examples
+    /// ```
+    /// person.clear_frame_extensions();
+    /// ```
+    pub fn clear_frame_extensions(&self) { self.frame_extensions.borrow_mut().clear(); }
+
+    /// Applies skill effects to the base profile and passes result to the nodes
+    pub(crate) fn refresh_profile(&self) {
+        let profile = self.skills.apply_to_profile(&self.profile.get());
+
+        self.health.set_profile(profile);
+        self.body.set_cold_tolerance(profile.cold_tolerance);
     }
 
     /// Consumes the item. Item which name is passed must have the
     /// [`ConsumableDescription`](crate::inventory::items::ConsumableDescription) option present, or
-    /// `Err` will be returned
+    /// `Err` will be returned. Spoiling items roll their poisoning chance (see
+    /// [`set_food_poisoning_factory`](crate::health::Health::set_food_poisoning_factory)),
+    /// contaminated water rolls its disease chance (see
+    /// [`set_dirty_water_factory`](crate::health::Health::set_dirty_water_factory))
     ///
     /// # Parameters
     /// - `item_name`: unique name of the item that is being consumed
@@ -155,16 +364,20 @@ impl<E: Listener + 'static> ZaraController<E> {
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/How-to-eat-or-drink) for more info.
     ///
     /// ## Notes
-    /// Borrows `inventory.items` collection
+    /// Borrows `inventory.items` and `skills.skills` collections. Health callbacks may safely
+    /// add, remove or use inventory items: these changes are applied after the item is consumed
     pub fn consume(&self, item_name: &String) -> Result<(), ItemConsumeErr> {
         if !self.health.is_alive() { return Err(ItemConsumeErr::CharacterIsDead); }
-        if self.is_paused() { return Err(ItemConsumeErr::InstancePaused); }
+        if self.is_paused() {
+            if self.queue_action(PendingAction::Consume(item_name.to_string())) { return Ok(()); }
+            return Err(ItemConsumeErr::InstancePaused);
+        }
 
         let mut consumable = ConsumableC::new();
         {
             let consumed_count = 1_usize;
             let items_count: usize;
-            let inv_items = self.inventory.items.borrow();
+            let inv_items = self.inventory.stored_items.borrow();
 
             let item = match inv_items.get(item_name) {
                 Some(o) => o,
@@ -176,37 +389,78 @@ impl<E: Listener + 'static> ZaraController<E> {
             if !item.get_is_infinite() && (items_count as i32) - (consumed_count as i32) < 0 {
                 return Err(ItemConsumeErr::InsufficientResources);
             }
+            if !item.get_is_infinite() {
+                self.inventory.check_locked(item_name, items_count, consumed_count)
+                    .map_err(ItemConsumeErr::CouldNotUseItem)?;
+            }
 
             let c = match item.consumable() {
                 Some(c) => c,
                 None => return Err(ItemConsumeErr::ItemIsNotConsumable)
             };
 
-            consumable.name = item.get_name();
+            self.inventory.check_item_requirements(item.as_ref())
+                .map_err(ItemConsumeErr::RequirementsNotMet)?;
+
+            consumable.name = item.get_id();
             consumable.is_water = c.is_water();
             consumable.is_food = c.is_food();
             consumable.food_gain = c.food_gain_per_dose();
             consumable.water_gain = c.water_gain_per_dose();
             consumable.consumed_count = consumed_count;
+            consumable.temperature = c.temperature();
+            consumable.digestion_time = c.digestion_minutes();
+            consumable.nutrients = c.nutrients();
+            consumable.toxicity = c.toxicity();
 
             if let Some(s) = c.spoiling() {
-                consumable.fresh_poisoning_chance = s.fresh_poisoning_chance();
-                consumable.spoiled_poisoning_chance = s.spoil_poisoning_chance();
+                let factor = self.traits.poisoning_chance_factor() *
+                    self.health.profile().disease_susceptibility() * self.body.hygiene_infection_factor();
+                let scale = |chance: usize| usize::min(100, (chance as f32 * factor).round() as usize);
+
+                consumable.fresh_poisoning_chance = scale(s.fresh_poisoning_chance());
+                consumable.spoiled_poisoning_chance = scale(s.spoil_poisoning_chance());
                 consumable.spoil_time = Some(s.spoil_time());
+                consumable.spoiled_toxicity = s.spoil_toxicity();
             }
+            consumable.freshness = self.inventory.freshness_of(item_name);
+            consumable.contamination = self.inventory.contamination_of(item_name).unwrap_or(0.);
+            consumable.is_expired = self.inventory.is_expired(item_name).unwrap_or(false);
+            consumable.potency = self.inventory.potency_of(item_name).unwrap_or(100.);
+
+            // Dirty food and bloodied hands can cause an infection
+            let dirty_chance = self.inventory.dirtiness_of(item_name) * DIRTY_CONSUMABLE_INFECTION_CHANCE / 100.;
+            let hands_chance = if self.player_state.are_hands_bloodied() { BLOODIED_HANDS_INFECTION_CHANCE / 100. } else { 0. };
+            let infection_chance = (1. - (1. - dirty_chance) * (1. - hands_chance)) *
+                self.health.profile().disease_susceptibility() * self.body.hygiene_infection_factor() * 100.;
+
+            consumable.infection_chance = usize::min(100, infection_chance.round() as usize);
 
             let game_time = GameTime::from_duration(self.last_update_game_time.get()).to_contract();
 
             // Notify health controller about the event
             self.health.on_consumed(&game_time, &consumable, &*inv_items);
+
+            // Spoiling food and contaminated water can make character sick
+            self.health.roll_food_poisoning(&game_time, &consumable);
+            self.health.roll_dirty_water(&game_time, &consumable);
         }
 
         // Change items count
-        self.inventory.use_item(item_name, consumable.consumed_count)
-            .or_else(|e| Err(ItemConsumeErr::CouldNotUseItem(e)))?;
+        self.inventory.use_item_for(item_name, consumable.consumed_count, InventoryChangeReason::Consumed)
+            .map_err(ItemConsumeErr::CouldNotUseItem)?;
+
+        // Inventory changes made by the health callbacks
+        self.inventory.apply_deferred();
 
         // Send the event
-        self.dispatcher.borrow_mut().dispatch(Event::ItemConsumed(consumable));
+        let event = Event::ItemConsumed(consumable);
+
+        self.skills.on_event(&event);
+        self.day_summary_on_event(&event);
+        self.stats.on_event(&event);
+        self.dispatcher.borrow_mut().dispatch(event);
+        self.deliver_events();
 
         Ok(())
     }
@@ -231,17 +485,27 @@ impl<E: Listener + 'static> ZaraController<E> {
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Appliances) for more info.
     ///
     /// ## Notes
-    /// Borrows `inventory.items` collection, can borrow `body.appliances` collection
+    /// Borrows `inventory.items` and `skills.skills` collections, can borrow `body.appliances` collection.
+    /// Health callbacks may safely add, remove or use inventory items: these changes are applied
+    /// after the appliance is taken
     pub fn take_appliance(&self, item_name: &String, body_part: BodyPart) -> Result<(), ApplianceTakeErr> {
         if !self.health.is_alive() { return Err(ApplianceTakeErr::CharacterIsDead); }
-        if self.is_paused() { return Err(ApplianceTakeErr::InstancePaused); }
-        if body_part == BodyPart::Unknown { return Err(ApplianceTakeErr::UnknownBodyPart); }
+        if self.is_paused() {
+            if self.queue_action(PendingAction::TakeAppliance(item_name.to_string(), body_part)) { return Ok(()); }
+            return Err(ApplianceTakeErr::InstancePaused);
+        }
+        if !self.body.parts.is_registered(body_part) { return Err(ApplianceTakeErr::UnknownBodyPart); }
 
         let mut appliance = ApplianceC::new();
+        let prosthetic_function;
+        let tourniquet_safe_time;
+        let burn_relief;
+        let treats_injury;
+        let game_time;
         {
             let taken_count = 1_usize;
             let items_count: usize;
-            let inv_items = self.inventory.items.borrow();
+            let inv_items = self.inventory.stored_items.borrow();
 
             let item = match inv_items.get(item_name) {
                 Some(o) => o,
@@ -253,43 +517,100 @@ impl<E: Listener + 'static> ZaraController<E> {
             if !item.get_is_infinite() && (items_count as i32) - (taken_count as i32) < 0 {
                 return Err(ApplianceTakeErr::InsufficientResources);
             }
+            if !item.get_is_infinite() {
+                self.inventory.check_locked(item_name, items_count, taken_count)
+                    .map_err(ApplianceTakeErr::CouldNotUseItem)?;
+            }
 
             let a = match item.appliance() {
                 Some(a) => a,
                 None => return Err(ApplianceTakeErr::ItemIsNotAppliance)
             };
 
-            appliance.name = item.get_name();
+            self.inventory.check_item_requirements(item.as_ref())
+                .map_err(ApplianceTakeErr::RequirementsNotMet)?;
+
+            appliance.name = item.get_id();
             appliance.is_body_appliance = a.is_body_appliance();
             appliance.is_injection = a.is_injection();
             appliance.taken_count = taken_count;
+            appliance.infection_chance = usize::min(100, (self.inventory.dirtiness_of(item_name) *
+                DIRTY_APPLIANCE_INFECTION_CHANCE * self.health.profile().disease_susceptibility() *
+                self.body.hygiene_infection_factor()).round() as usize);
+            prosthetic_function = a.prosthetic_function();
+            tourniquet_safe_time = a.tourniquet_safe_time().filter(|_| appliance.is_body_appliance);
+            burn_relief = a.burn_relief().filter(|_| appliance.is_body_appliance);
+
+            // Lost body parts accept prosthetics only
+            match (self.body.part_status(body_part), prosthetic_function) {
+                (BodyPartStatus::Lost, Some(_)) | (BodyPartStatus::Healthy, None) => { },
+                (BodyPartStatus::Healthy, Some(_)) => return Err(ApplianceTakeErr::BodyPartNotLost),
+                _ => return Err(ApplianceTakeErr::BodyPartLost)
+            }
 
             if appliance.is_body_appliance && self.body.is_applied(item_name, body_part) {
                 return Err(ApplianceTakeErr::AlreadyApplied);
             }
 
-            let game_time = GameTime::from_duration(self.last_update_game_time.get()).to_contract();
+            // Tourniquets can be applied to limbs only, one per limb
+            if tourniquet_safe_time.is_some() {
+                if self.body.parts.group_of(body_part) != Some(BodyPartGroup::Limbs) {
+                    return Err(ApplianceTakeErr::NotALimb);
+                }
+                if self.body.tourniquet_on(body_part).is_some() {
+                    return Err(ApplianceTakeErr::TourniquetAlreadyApplied);
+                }
+            }
+
+            treats_injury = appliance.is_body_appliance && self.health.has_active_injury_on(body_part);
+            game_time = GameTime::from_duration(self.last_update_game_time.get()).to_contract();
 
             // Notify health controller about the event
             self.health.on_appliance_taken(&game_time, &appliance, body_part, &*inv_items);
         }
 
         // Change items count
-        self.inventory.use_item(item_name, appliance.taken_count)
-            .or_else(|e| Err(ApplianceTakeErr::CouldNotUseItem(e)))?;
+        self.inventory.use_item_for(item_name, appliance.taken_count, InventoryChangeReason::Consumed)
+            .map_err(ApplianceTakeErr::CouldNotUseItem)?;
+
+        // Inventory changes made by the health callbacks
+        self.inventory.apply_deferred();
+
+        // Treating a wound leaves blood on the hands
+        if treats_injury {
+            self.player_state.hands_bloodied.set(true);
+            self.player_state.mark_dirty();
+        }
 
         if appliance.is_body_appliance {
             // Notify body controller
             self.body.on_body_appliance_put_on(item_name, body_part);
+
+            if let Some(function) = prosthetic_function {
+                self.body.on_prosthetic_on(item_name, body_part, function);
+            }
+            if let Some(safe_time) = tourniquet_safe_time {
+                self.body.on_tourniquet_on(item_name, body_part, safe_time, game_time.to_duration());
+            }
+            if let Some(relief) = burn_relief {
+                self.body.on_burn_dressing_on(item_name, body_part, relief);
+            }
         }
 
         // Send the event
-        self.dispatcher.borrow_mut().dispatch(Event::ApplianceTaken(appliance, body_part));
+        let event = Event::ApplianceTaken(appliance, body_part);
+
+        self.skills.on_event(&event);
+        self.day_summary_on_event(&event);
+        self.stats.on_event(&event);
+        self.dispatcher.borrow_mut().dispatch(event);
+        self.deliver_events();
 
         Ok(())
     }
 
-    /// Removes body appliance. Item is **not** added back to the inventory.
+    /// Removes body appliance. Item is **not** added back to the inventory; use
+    /// [`remove_appliance_with_return`](#method.remove_appliance_with_return) for reusable items.
     ///
     /// # Parameters
     /// - `item_name`: inventory kind of appliance to remove
@@ -307,7 +628,10 @@ impl<E: Listener + 'static> ZaraController<E> {
     /// Borrows `body.appliances` collection
     pub fn remove_appliance(&self, item_name: &String, body_part: BodyPart) -> Result<(), ApplianceRemoveErr> {
         if !self.health.is_alive() { return Err(ApplianceRemoveErr::CharacterIsDead); }
-        if self.is_paused() { return Err(ApplianceRemoveErr::InstancePaused); }
+        if self.is_paused() {
+            if self.queue_action(PendingAction::RemoveAppliance(item_name.to_string(), body_part)) { return Ok(()); }
+            return Err(ApplianceRemoveErr::InstancePaused);
+        }
 
         if !self.body.remove_appliance(item_name, body_part) {
             return Err(ApplianceRemoveErr::ApplianceNotFound);
@@ -316,7 +640,269 @@ impl<E: Listener + 'static> ZaraController<E> {
         Ok(())
     }
 
-    /// Sets controller alive state to `false`
+    /// Removes body appliance and optionally puts it back to the inventory. Returned item is
+    /// created by a factory registered with
+    /// [`register_appliance_return`](crate::inventory::Inventory::register_appliance_return),
+    /// so reusable items like splints and tourniquets can come back as "used" variants.
+    ///
+    /// # Parameters
+    /// - `item_name`: inventory kind of appliance to remove
+    /// - `body_part`: from which body part
+    /// - `return_to_inventory`: put the item back to the inventory. When `false`, works
+    ///   as [`remove_appliance`](#method.remove_appliance)
+    ///
+    /// # Examples
+    /// ```
+    /// person.remove_appliance_with_return(item_name, body_part, true);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Appliances) for more info.
+    ///
+    /// ## Notes
+    /// Borrows `body.appliances` and `inventory.items` collections
+    pub fn remove_appliance_with_return(&self, item_name: &String, body_part: BodyPart,
+                                        return_to_inventory: bool) -> Result<(), ApplianceRemoveErr> {
+        if !return_to_inventory { return self.remove_appliance(item_name, body_part); }
+
+        if !self.health.is_alive() { return Err(ApplianceRemoveErr::CharacterIsDead); }
+        if self.is_paused() {
+            if self.queue_action(PendingAction::RemoveApplianceWithReturn(item_name.to_string(), body_part)) { return Ok(()); }
+            return Err(ApplianceRemoveErr::InstancePaused);
+        }
+        if !self.body.is_applied(item_name, body_part) { return Err(ApplianceRemoveErr::ApplianceNotFound); }
+        if !self.inventory.is_appliance_returnable(item_name) { return Err(ApplianceRemoveErr::NotReturnable); }
+
+        self.body.remove_appliance(item_name, body_part);
+        self.inventory.return_appliance(item_name, body_part);
+
+        Ok(())
+    }
+
+    /// Permanently disables a body part. All appliances are removed from it, all injuries located
+    /// on it are removed, and from now on it accepts only prosthetic appliances.
+    ///
+    /// Lost body parts lower the character [`mobility`](crate::body::Body::mobility).
+    ///
+    /// # Parameters
+    /// - `body_part`: body part to disable
+    /// - `phantom_pain`: optional long-term condition that will be started right away
+    ///
+    /// # Examples
+    /// ```
+    /// person.amputate(BodyPart::LeftFoot, Some(Box::new(PhantomPain::new())));
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `body.appliances`, `health.injuries` and `health.conditions` collections
+    pub fn amputate(&self, body_part: BodyPart, phantom_pain: Option<Box<dyn LongTermCondition>>) -> Result<(), AmputateErr> {
+        if !self.health.is_alive() { return Err(AmputateErr::CharacterIsDead); }
+        if self.is_paused() { return Err(AmputateErr::InstancePaused); }
+        if !self.body.parts.is_registered(body_part) { return Err(AmputateErr::UnknownBodyPart); }
+        if self.body.part_status(body_part) != BodyPartStatus::Healthy { return Err(AmputateErr::AlreadyLost); }
+
+        if let Some(condition) = phantom_pain {
+            let game_time = self.environment.game_time.to_contract();
+
+            self.health.start_condition(condition, game_time)
+                .map_err(AmputateErr::CouldNotStartCondition)?;
+        }
+
+        let appliances: Vec<String> = self.body.appliances.borrow().iter()
+            .filter(|a| a.body_part == body_part)
+            .map(|a| a.item_name.to_string())
+            .collect();
+
+        for item_name in appliances.iter() {
+            self.body.remove_appliance(item_name, body_part);
+        }
+
+        self.health.clear_injuries_on(body_part);
+        self.body.on_part_lost(body_part);
+
+        Ok(())
+    }
+
+    /// Burns a body part, for example when the player touches a campfire or spills boiling water.
+    /// Burn injury is created by the [`burn factory`](crate::health::Health::set_burn_factory),
+    /// and its severity depends on the burned area. `Burned` event is sent
+    ///
+    /// # Parameters
+    /// - `body_part`: burned body part
+    /// - `area`: burned area of the body part (0..100 percents)
+    /// - `source`: what caused the burn. [`HeatSource`](crate::health::burns::BurnSource::HeatSource)
+    ///   burns are possible only near a heat source
+    ///
+    /// # Returns
+    /// Injury instance key on success
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::health::burns::BurnSource;
+    ///
+    /// person.burn(BodyPart::RightBrush, 20, BurnSource::BoilingWater);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `health.injuries` collection
+    pub fn burn(&self, body_part: BodyPart, area: usize, source: BurnSource) -> Result<InjuryKey, BurnErr> {
+        if !self.health.is_alive() { return Err(BurnErr::CharacterIsDead); }
+        if self.is_paused() { return Err(BurnErr::InstancePaused); }
+        if !self.body.parts.is_registered(body_part) { return Err(BurnErr::UnknownBodyPart); }
+        if self.body.part_status(body_part) != BodyPartStatus::Healthy { return Err(BurnErr::BodyPartLost); }
+        if source == BurnSource::HeatSource && !self.player_state.is_near_heat_source.get() {
+            return Err(BurnErr::NoHeatSource);
+        }
+
+        let game_time = self.environment.game_time.to_contract();
+
+        match self.health.burn(body_part, area, source, game_time) {
+            Some(result) => result.map_err(BurnErr::CouldNotSpawn),
+            None => Err(BurnErr::NoBurnFactory)
+        }
+    }
+
+    /// Starts the next step of a multi-step procedure (see
+    /// [`register_procedures`](crate::health::Health::register_procedures)) on an injury. Step
+    /// items are consumed right away; the step ends after its game minutes pass, and can fail
+    /// with its consequence. Failed step must be performed again. Failure chance is scaled by
+    /// the [`PROCEDURE_FAILURE_MODIFIER`](crate::health::procedure::PROCEDURE_FAILURE_MODIFIER)
+    /// skill modifier
+    ///
+    /// # Parameters
+    /// - `procedure_name`: unique name of the procedure
+    /// - `injury_name`: unique name of the injury
+    /// - `body_part`: body part of the injury
+    ///
+    /// # Examples
+    /// ```
+    /// person.perform_procedure_step(&procedure_name, &injury_name, BodyPart::LeftForearm);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `health.procedures` and `health.active_procedures` collections
+    pub fn perform_procedure_step(&self, procedure_name: &String, injury_name: &String,
+                                  body_part: BodyPart) -> Result<(), ProcedureStepErr> {
+        if !self.health.is_alive() { return Err(ProcedureStepErr::CharacterIsDead); }
+        if self.is_paused() { return Err(ProcedureStepErr::InstancePaused); }
+
+        let items = self.health.next_procedure_step_items(procedure_name, injury_name, body_part)?;
+
+        // Every item is checked before any of them is used
+        let mut needed: BTreeMap<String, usize> = BTreeMap::new();
+        for item in items.iter() {
+            *needed.entry(item.to_string()).or_insert(0) += 1;
+        }
+        {
+            let inv_items = self.inventory.stored_items.borrow();
+
+            for (item_name, count) in needed.iter() {
+                let item = match inv_items.get(item_name) {
+                    Some(o) => o,
+                    None => return Err(ProcedureStepErr::InsufficientResources)
+                };
+
+                if item.get_is_infinite() { continue; }
+                if item.get_count() < *count { return Err(ProcedureStepErr::InsufficientResources); }
+
+                self.inventory.check_locked(item_name, item.get_count(), *count)
+                    .map_err(ProcedureStepErr::CouldNotUseItem)?;
+            }
+        }
+
+        for (item_name, count) in needed.iter() {
+            self.inventory.use_item(item_name, *count)
+                .map_err(ProcedureStepErr::CouldNotUseItem)?;
+        }
+
+        let game_time = self.environment.game_time.to_contract();
+        let failure_modifier = self.skills.modifier(PROCEDURE_FAILURE_MODIFIER);
+
+        self.health.start_procedure_step(procedure_name, injury_name, body_part, failure_modifier, &game_time);
+
+        Ok(())
+    }
+
+    /// Tries to use a tool (see [`ToolDescription`](crate::inventory::items::ToolDescription)),
+    /// like striking a match or turning on a flashlight. Current weather can make it fail: cold,
+    /// rain outside of a shelter and character wetness are rolled in this order. Tools that are
+    /// used up lose one item on every attempt. `ToolUsed` or `ToolFailed` event is sent
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the tool item
+    ///
+    /// # Returns
+    /// Whether the tool worked or why it failed
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::inventory::tools::ToolUseOutcome;
+    ///
+    /// if let Ok(ToolUseOutcome::Worked) = person.try_use_tool(&matches) {
+    ///     // Light the campfire
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `inventory.items` collection
+    pub fn try_use_tool(&self, item_name: &String) -> Result<ToolUseOutcome, ToolUseErr> {
+        if !self.health.is_alive() { return Err(ToolUseErr::CharacterIsDead); }
+        if self.is_paused() { return Err(ToolUseErr::InstancePaused); }
+
+        let consumed;
+        {
+            let inv_items = self.inventory.stored_items.borrow();
+            let item = match inv_items.get(item_name) {
+                Some(o) => o,
+                None => return Err(ToolUseErr::ItemNotFound)
+            };
+            let tool = match item.tool() {
+                Some(t) => t,
+                None => return Err(ToolUseErr::ItemIsNotTool)
+            };
+
+            self.inventory.check_item_requirements(item.as_ref())
+                .map_err(ToolUseErr::RequirementsNotMet)?;
+
+            consumed = tool.consumed_on_use() && !item.get_is_infinite();
+
+            if consumed {
+                self.inventory.check_locked(item_name, item.get_count(), 1)
+                    .map_err(ToolUseErr::CouldNotUseItem)?;
+            }
+        }
+
+        let environment = EnvironmentC::new(self.environment.temperature.get(),
+            self.environment.wind_speed.get(), self.environment.rain_intensity.get())
+            .with_humidity(self.environment.humidity.get());
+        let chances = self.inventory.tool_failure_chances(item_name, &environment).unwrap_or_default();
+        let outcome = match chances.iter().find(|(_, chance)| utils::roll_dice(*chance)) {
+            Some((cause, _)) => ToolUseOutcome::Failed(*cause),
+            None => ToolUseOutcome::Worked
+        };
+
+        if consumed {
+            self.inventory.use_item(item_name, 1)
+                .map_err(ToolUseErr::CouldNotUseItem)?;
+        }
+
+        // Send the event
+        let event = match outcome {
+            ToolUseOutcome::Worked => Event::ToolUsed(item_name.to_string()),
+            ToolUseOutcome::Failed(cause) => Event::ToolFailed(item_name.to_string(), cause)
+        };
+
+        self.skills.on_event(&event);
+        self.day_summary_on_event(&event);
+        self.stats.on_event(&event);
+        self.dispatcher.borrow_mut().dispatch(event);
+        self.deliver_events();
+
+        Ok(outcome)
+    }
+
+    /// Sets controller alive state to `false` and switches controller to the post-mortem mode
+    /// (see [`death_time`](#method.death_time)). Does nothing if character is already dead
     ///
     /// # Examples
     /// ```
@@ -326,15 +912,45 @@ impl<E: Listener + 'static> ZaraController<E> {
     /// # Links
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Declare-dead) for more info.
     pub fn declare_dead(&self) -> Result<(), DeclareDeadErr> {
+        if !self.health.is_alive() { return Ok(()); }
         if self.is_paused() { return Err(DeclareDeadErr::InstancePaused); }
         self.health.declare_dead();
 
         // Send the event
         self.dispatcher.borrow_mut().dispatch(Event::DeclaredDead);
 
+        self.enter_post_mortem();
+        self.deliver_events();
+
         Ok(())
     }
 
+    /// Sets minimum severity of the events sent to the listener. Less important events are
+    /// dropped right away, so UI and audio layers can subscribe only to important happenings
+    ///
+    /// # Parameters
+    /// - `severity`: minimum event severity
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::utils::event::EventSeverity;
+    ///
+    /// person.set_min_event_severity(EventSeverity::Danger);
+    /// ```
+    pub fn set_min_event_severity(&self, severity: EventSeverity) {
+        self.dispatcher.borrow_mut().set_min_severity(severity);
+    }
+
+    /// Returns minimum severity of the events sent to the listener
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.min_event_severity();
+    /// ```
+    pub fn min_event_severity(&self) -> EventSeverity {
+        self.dispatcher.borrow().min_severity()
+    }
+
     /// Pause this instance (all `update` calls will be ignored)
     ///
     /// # Examples
@@ -346,7 +962,12 @@ impl<E: Listener + 'static> ZaraController<E> {
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Pausing-Zara) for more info.
     pub fn pause(&self) { self.is_paused.set(true); }
 
-    /// Resume this instance (all `update` calls will be working again)
+    /// Resume this instance (all `update` calls will be working again).
+    ///
+    /// Actions queued while paused (see [`set_paused_actions_mode`](#method.set_paused_actions_mode))
+    /// are executed in the order of issue. Use [`resume_with_results`](#method.resume_with_results)
+    /// to get their results, or [`discard_pending_actions`](#method.discard_pending_actions) before
+    /// resuming to drop them
     ///
     /// # Examples
     /// ```
@@ -355,7 +976,7 @@ impl<E: Listener + 'static> ZaraController<E> {
     ///
     /// # Links
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Pausing-Zara) for more info.
-    pub fn resume(&self) { self.is_paused.set(false); }
+    pub fn resume(&self) { self.resume_with_results(); }
 
     /// Adds given item to the `body.clothes` collection and recalculates inventory weight.
     ///
@@ -377,11 +998,16 @@ impl<E: Listener + 'static> ZaraController<E> {
     /// This method borrows `body.clothes` collection.
     pub fn put_on_clothes(&self, item_name: &String) -> Result<(), ClothesOnActionErr> {
         if !self.health.is_alive() { return Err(ClothesOnActionErr::CharacterIsDead); }
-        if self.is_paused() { return Err(ClothesOnActionErr::InstancePaused); }
+        if self.is_paused() {
+            if self.queue_action(PendingAction::PutOnClothes(item_name.to_string())) { return Ok(()); }
+            return Err(ClothesOnActionErr::InstancePaused);
+        }
 
-        match self.inventory.items.borrow().get(item_name) {
+        match self.inventory.stored_items.borrow().get(item_name) {
             Some(item) => {
-                if item.get_count() <= 0 {
+                // Reserved and equipped units cannot be worn
+                if item.get_count() <= self.inventory.reserved_count_of(item_name) +
+                    self.inventory.equipped_count_of(item_name) {
                     return Err(ClothesOnActionErr::InsufficientResources)
                 }
                 match item.clothes() {
@@ -423,9 +1049,12 @@ impl<E: Listener + 'static> ZaraController<E> {
     /// This method borrows `body.clothes` collection.
     pub fn take_off_clothes(&self, item_name: &String) -> Result<(), ClothesOffActionErr> {
         if !self.health.is_alive() { return Err(ClothesOffActionErr::CharacterIsDead); }
-        if self.is_paused() { return Err(ClothesOffActionErr::InstancePaused); }
+        if self.is_paused() {
+            if self.queue_action(PendingAction::TakeOffClothes(item_name.to_string())) { return Ok(()); }
+            return Err(ClothesOffActionErr::InstancePaused);
+        }
 
-        match self.inventory.items.borrow().get(item_name) {
+        match self.inventory.stored_items.borrow().get(item_name) {
             Some(item) => {
                 if item.get_count() <= 0 {
                     return Err(ClothesOffActionErr::InsufficientResources)
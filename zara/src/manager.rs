@@ -0,0 +1,199 @@
+use crate::ZaraController;
+use crate::error::{CharacterAddErr, ZaraUpdateErr};
+use crate::player::CharacterProfile;
+use crate::utils::EnvironmentC;
+use crate::utils::event::{Event, EventSeverity, Listener};
+
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+/// Controller of a character managed by the [`CharacterManager`]
+pub type Character<L> = ZaraController<CharacterEventListener<L>>;
+
+/// Listener of the events of all characters of a [`CharacterManager`]
+pub trait CharacterListener {
+    /// Is called when Zara event occurs for a character
+    ///
+    /// # Parameters
+    /// - `character_id`: id of the character the event came from
+    /// - `event`: Zara event
+    fn notify(&mut self, character_id: &str, event: &Event);
+
+    /// Events less important than this level are not sent to this listener. All events are
+    /// sent by default
+    ///
+    /// # Examples
+    /// ```
+    /// fn min_severity(&self) -> EventSeverity { EventSeverity::Warning }
+    /// ```
+    fn min_severity(&self) -> EventSeverity { EventSeverity::Info }
+}
+
+/// Listener of a single managed character. Forwards events to the shared
+/// [`CharacterListener`] together with the character id
+pub struct CharacterEventListener<L: CharacterListener> {
+    /// Id of the character
+    character_id: String,
+    /// Shared listener of all characters
+    listener: Rc<RefCell<L>>
+}
+impl<L: CharacterListener> Listener for CharacterEventListener<L> {
+    fn notify(&mut self, event: &Event) {
+        self.listener.borrow_mut().notify(&self.character_id, event);
+    }
+
+    fn min_severity(&self) -> EventSeverity { self.listener.borrow().min_severity() }
+}
+
+/// Owns many `ZaraController` instances keyed by character id, updates them all at once and
+/// sends their events to one listener.
+///
+/// # Examples
+/// ```
+/// use zara::manager::CharacterManager;
+///
+/// let mut world = CharacterManager::new(listener);
+///
+/// world.add_character(&player_id, env, CharacterProfile::default());
+/// world.add_character(&companion_id, env, CharacterProfile::athlete());
+///
+/// // Every frame
+/// world.update(frame_time);
+/// ```
+pub struct CharacterManager<L: CharacterListener + 'static> {
+    /// Managed characters
+    characters: BTreeMap<String, Character<L>>,
+    /// Listener shared by all characters
+    listener: Rc<RefCell<L>>
+}
+impl<L: CharacterListener + 'static> CharacterManager<L> {
+    /// Creates new empty `CharacterManager`
+    ///
+    /// # Parameters
+    /// - `listener`: [`CharacterListener`] instance whose `notify` will be called when Zara
+    ///   event occurs for any of the characters
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::manager::CharacterManager;
+    ///
+    /// let world = CharacterManager::new(listener);
+    /// ```
+    pub fn new(listener: L) -> Self {
+        CharacterManager {
+            characters: BTreeMap::new(),
+            listener: Rc::new(RefCell::new(listener))
+        }
+    }
+
+    /// Creates a new character controller and starts managing it
+    ///
+    /// # Parameters
+    /// - `character_id`: unique id of the character
+    /// - `env`: [`EnvironmentC`](crate::utils::EnvironmentC) object that describes initial state of the environment
+    /// - `profile`: [`CharacterProfile`](crate::player::CharacterProfile) that describes physiology of this character
+    ///
+    /// # Examples
+    /// ```
+    /// world.add_character(&companion_id, env, CharacterProfile::default());
+    /// ```
+    pub fn add_character(&mut self, character_id: &String, env: EnvironmentC, profile: CharacterProfile) -> Result<&Character<L>, CharacterAddErr> {
+        if self.characters.contains_key(character_id) { return Err(CharacterAddErr::AlreadyExists); }
+
+        let listener = CharacterEventListener {
+            character_id: character_id.to_string(),
+            listener: self.listener.clone()
+        };
+        let controller = ZaraController::with_profile(listener, env, profile);
+
+        Ok(self.characters.entry(character_id.to_string()).or_insert(controller))
+    }
+
+    /// Stops managing a character and returns its controller. Controller keeps sending its
+    /// events to the shared listener
+    ///
+    /// # Parameters
+    /// - `character_id`: id of the character
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(person) = world.remove_character(&companion_id) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn remove_character(&mut self, character_id: &String) -> Option<Character<L>> {
+        self.characters.remove(character_id)
+    }
+
+    /// Returns controller of a character
+    ///
+    /// # Parameters
+    /// - `character_id`: id of the character
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(person) = world.character(&player_id) {
+    ///     person.consume(&item_name);
+    /// }
+    /// ```
+    pub fn character(&self, character_id: &String) -> Option<&Character<L>> {
+        self.characters.get(character_id)
+    }
+
+    /// Returns ids of all managed characters, in order
+    ///
+    /// # Examples
+    /// ```
+    /// let ids = world.character_ids();
+    /// ```
+    pub fn character_ids(&self) -> Vec<String> { self.characters.keys().cloned().collect() }
+
+    /// Returns all managed characters with their ids, in order of ids
+    ///
+    /// # Examples
+    /// ```
+    /// for (id, person) in world.characters() {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn characters(&self) -> impl Iterator<Item = (&String, &Character<L>)> { self.characters.iter() }
+
+    /// Returns count of managed characters
+    pub fn len(&self) -> usize { self.characters.len() }
+
+    /// Returns `true` if there are no managed characters
+    pub fn is_empty(&self) -> bool { self.characters.is_empty() }
+
+    /// Returns listener shared by all characters
+    ///
+    /// # Examples
+    /// ```
+    /// let events = world.listener().borrow().events.len();
+    /// ```
+    pub fn listener(&self) -> Rc<RefCell<L>> { self.listener.clone() }
+
+    /// Updates all managed characters, in order of ids. Should be called every frame
+    ///
+    /// # Parameters
+    /// - `frame_time`: time, in seconds, since last `update` call
+    ///
+    /// # Returns
+    /// Characters whose `update` call failed (are dead or paused), with their errors
+    ///
+    /// # Examples
+    /// ```
+    /// world.update(time_delta);
+    /// ```
+    pub fn update(&self, frame_time: f32) -> BTreeMap<String, ZaraUpdateErr> {
+        let mut result = BTreeMap::new();
+
+        for (id, controller) in self.characters.iter() {
+            if let Err(e) = controller.update(frame_time) {
+                result.insert(id.to_string(), e);
+            }
+        }
+
+        result
+    }
+}
@@ -0,0 +1,173 @@
+use crate::ZaraController;
+use crate::utils::event::Listener;
+use crate::body::BodyPart;
+use crate::error::PendingActionErr;
+
+use std::fmt;
+
+/// Describes how controller treats actions (like `consume` or `put_on_clothes`) that were
+/// issued while it is paused
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum PausedActionsMode {
+    /// Actions return `InstancePaused` error and are lost. This is the default mode
+    #[default]
+    Reject,
+    /// Actions are buffered and executed in the same order when controller is resumed
+    Queue
+}
+impl fmt::Display for PausedActionsMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Describes a controller action that was issued while controller was paused
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum PendingAction {
+    /// `consume` call
+    /// # Parameters
+    /// - Item unique name
+    Consume(String),
+    /// `take_appliance` call
+    /// # Parameters
+    /// - Item unique name
+    /// - Body part
+    TakeAppliance(String, BodyPart),
+    /// `remove_appliance` call
+    /// # Parameters
+    /// - Item unique name
+    /// - Body part
+    RemoveAppliance(String, BodyPart),
+    /// `remove_appliance_with_return` call that puts item back to the inventory
+    /// # Parameters
+    /// - Item unique name
+    /// - Body part
+    RemoveApplianceWithReturn(String, BodyPart),
+    /// `put_on_clothes` call
+    /// # Parameters
+    /// - Item unique name
+    PutOnClothes(String),
+    /// `take_off_clothes` call
+    /// # Parameters
+    /// - Item unique name
+    TakeOffClothes(String)
+}
+impl fmt::Display for PendingAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl<E: Listener + 'static> ZaraController<E> {
+    /// Sets how actions issued while controller is paused are treated
+    ///
+    /// # Parameters
+    /// - `mode`: new mode. Switching to `Reject` does not discard already queued actions
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::pending::PausedActionsMode;
+    ///
+    /// person.set_paused_actions_mode(PausedActionsMode::Queue);
+    /// ```
+    pub fn set_paused_actions_mode(&self, mode: PausedActionsMode) { self.paused_actions_mode.set(mode); }
+
+    /// Returns how actions issued while controller is paused are treated
+    ///
+    /// # Examples
+    /// ```
+    /// let mode = person.paused_actions_mode();
+    /// ```
+    pub fn paused_actions_mode(&self) -> PausedActionsMode { self.paused_actions_mode.get() }
+
+    /// Returns a copy of all actions queued while controller was paused, in the order of issue
+    ///
+    /// # Examples
+    /// ```
+    /// for action in person.pending_actions().iter() {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `pending_actions` collection
+    pub fn pending_actions(&self) -> Vec<PendingAction> { self.pending_actions.borrow().clone() }
+
+    /// Discards all queued actions without executing them
+    ///
+    /// # Returns
+    /// Count of actions discarded
+    ///
+    /// # Examples
+    /// ```
+    /// let discarded = person.discard_pending_actions();
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `pending_actions` collection
+    pub fn discard_pending_actions(&self) -> usize {
+        let mut b = self.pending_actions.borrow_mut();
+        let count = b.len();
+
+        b.clear();
+
+        count
+    }
+
+    /// Resumes this instance and executes all queued actions in the order of issue.
+    ///
+    /// Unlike [`resume`](#method.resume), returns result of every executed action
+    ///
+    /// # Returns
+    /// Vec of executed actions with their results
+    ///
+    /// # Examples
+    /// ```
+    /// for (action, result) in person.resume_with_results() {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Pausing-Zara) for more info.
+    ///
+    /// ## Notes
+    /// Borrows `pending_actions` collection
+    pub fn resume_with_results(&self) -> Vec<(PendingAction, Result<(), PendingActionErr>)> {
+        self.is_paused.set(false);
+
+        let actions: Vec<PendingAction> = self.pending_actions.borrow_mut().drain(..).collect();
+
+        actions.into_iter().map(|action| {
+            let result = self.execute_pending_action(&action);
+
+            (action, result)
+        }).collect()
+    }
+
+    /// Queues an action if controller is in `Queue` mode. Returns `true` if action was queued
+    pub(crate) fn queue_action(&self, action: PendingAction) -> bool {
+        if self.paused_actions_mode.get() != PausedActionsMode::Queue { return false; }
+
+        self.pending_actions.borrow_mut().push(action);
+
+        true
+    }
+
+    fn execute_pending_action(&self, action: &PendingAction) -> Result<(), PendingActionErr> {
+        match action {
+            PendingAction::Consume(name) =>
+                self.consume(name).map_err(PendingActionErr::Consume),
+            PendingAction::TakeAppliance(name, body_part) =>
+                self.take_appliance(name, *body_part).map_err(PendingActionErr::TakeAppliance),
+            PendingAction::RemoveAppliance(name, body_part) =>
+                self.remove_appliance(name, *body_part).map_err(PendingActionErr::RemoveAppliance),
+            PendingAction::RemoveApplianceWithReturn(name, body_part) =>
+                self.remove_appliance_with_return(name, *body_part, true).map_err(PendingActionErr::RemoveAppliance),
+            PendingAction::PutOnClothes(name) =>
+                self.put_on_clothes(name).map_err(PendingActionErr::PutOnClothes),
+            PendingAction::TakeOffClothes(name) =>
+                self.take_off_clothes(name).map_err(PendingActionErr::TakeOffClothes)
+        }
+    }
+}
\ No newline at end of file
@@ -1,10 +1,14 @@
+use crate::utils::{HealthC, MovementC, FrameSummaryC};
+use crate::state::DirtyTracked;
+
 use std::cell::Cell;
 use std::fmt;
+use std::cmp::Ordering;
 use std::hash::{Hash, Hasher};
 
 /// Runtime player game state. You can change any of its values at any time
 /// to give Zara up-to-date information on player's status
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct PlayerStatus {
     /// Is player walking now
     pub is_walking: Cell<bool>,
@@ -13,7 +17,27 @@ pub struct PlayerStatus {
     /// Is player swimming now
     pub is_swimming: Cell<bool>,
     /// Is player under the water now
-    pub is_underwater: Cell<bool>
+    pub is_underwater: Cell<bool>,
+    /// Is player doing some arm-intensive activity now (climbing, chopping wood, etc.)
+    pub is_using_arms: Cell<bool>,
+    /// How many hands are busy holding something now (0..2)
+    pub busy_hands: Cell<usize>,
+    /// Is player inside a shelter now (tent, cave, house)
+    pub is_inside_shelter: Cell<bool>,
+    /// How well the shelter protects from the rain and wind (0..1), `1` keeps them out
+    /// completely. Is used only while `is_inside_shelter` is `true`
+    pub shelter_quality: Cell<f32>,
+    /// Is player near a heat source now (campfire, stove)
+    pub is_near_heat_source: Cell<bool>,
+    /// Distance reported with [`report_movement`](#method.report_movement) since the last
+    /// Zara update
+    pub(crate) reported_movement: Cell<MovementC>,
+    /// How noisy player is now (0..100). Is recalculated on every Zara update
+    pub(crate) exertion_noise: Cell<usize>,
+    /// Are player's hands bloodied after treating an injury
+    pub(crate) hands_bloodied: Cell<bool>,
+    /// Is node changed since the last state snapshot
+    is_dirty: Cell<bool>
 }
 impl fmt::Display for PlayerStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -26,8 +50,40 @@ impl Hash for PlayerStatus {
         self.is_running.get().hash(state);
         self.is_swimming.get().hash(state);
         self.is_underwater.get().hash(state);
+        self.is_using_arms.get().hash(state);
+        self.busy_hands.get().hash(state);
+        self.is_inside_shelter.get().hash(state);
+        self.is_near_heat_source.get().hash(state);
+        self.reported_movement.get().hash(state);
+        self.exertion_noise.get().hash(state);
+        self.hands_bloodied.get().hash(state);
+
+        state.write_u32((self.shelter_quality.get()*10_000_f32) as u32);
     }
 }
+impl Ord for PlayerStatus {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.flags().cmp(&other.flags())
+            .then(self.shelter_quality.get().total_cmp(&other.shelter_quality.get()))
+    }
+}
+impl PartialOrd for PlayerStatus {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Eq for PlayerStatus { }
+impl PartialEq for PlayerStatus {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Default for PlayerStatus {
+    fn default() -> Self { PlayerStatus::empty() }
+}
+impl DirtyTracked for PlayerStatus {
+    fn dirty_flag(&self) -> &Cell<bool> { &self.is_dirty }
+}
 impl PlayerStatus {
     /// Creates an empty default player state
     /// 
@@ -42,7 +98,375 @@ impl PlayerStatus {
             is_walking: Cell::new(false),
             is_running: Cell::new(false),
             is_swimming: Cell::new(false),
-            is_underwater: Cell::new(false)
+            is_underwater: Cell::new(false),
+            is_using_arms: Cell::new(false),
+            busy_hands: Cell::new(0),
+            is_inside_shelter: Cell::new(false),
+            shelter_quality: Cell::new(1.),
+            is_near_heat_source: Cell::new(false),
+            reported_movement: Cell::new(MovementC::default()),
+            exertion_noise: Cell::new(0),
+            hands_bloodied: Cell::new(false),
+            is_dirty: Cell::new(false)
+        }
+    }
+
+    /// Reports distance covered by the character. Zara does not know about the character
+    /// position, so the game should call this every time the character moves. Reported distance
+    /// drives running stamina and fatigue costs (which otherwise are estimated from the
+    /// `is_running` flag sampled once per update) and is accumulated in the statistics node
+    ///
+    /// # Parameters
+    /// - `meters`: distance covered since the last call, meters
+    /// - `kind`: how the character moved
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::player::MovementKind;
+    ///
+    /// person.player_state.report_movement(1.2, MovementKind::Running);
+    /// ```
+    pub fn report_movement(&self, meters: f32, kind: MovementKind) {
+        if meters <= 0. { return; }
+
+        let mut m = self.reported_movement.get();
+
+        match kind {
+            MovementKind::Walking => m.meters_walked += meters,
+            MovementKind::Running => m.meters_run += meters,
+            MovementKind::Swimming => m.meters_swum += meters
+        }
+
+        self.reported_movement.set(m);
+    }
+
+    /// Returns distance reported since the last Zara update
+    ///
+    /// # Examples
+    /// ```
+    /// let m = person.player_state.reported_movement();
+    /// ```
+    pub fn reported_movement(&self) -> MovementC { self.reported_movement.get() }
+
+    /// Returns how noisy player is now (0..100), for the stealth mechanics. Noise comes from
+    /// the current activity and gets louder when player is out of breath, exhausted or is limping
+    /// on an injured leg. Value is recalculated on every Zara update
+    ///
+    /// # Examples
+    /// ```
+    /// let noise = person.player_state.exertion_noise();
+    /// ```
+    pub fn exertion_noise(&self) -> usize { self.exertion_noise.get() }
+
+    /// Are player's hands bloodied. Hands get bloody when a body appliance is taken on an
+    /// injured body part, and stay so until washed with [`wash_hands`](#method.wash_hands) or
+    /// by swimming. Eating with bloodied hands raises the infection chance passed with the
+    /// consumable
+    ///
+    /// # Examples
+    /// ```
+    /// if person.player_state.are_hands_bloodied() {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn are_hands_bloodied(&self) -> bool { self.hands_bloodied.get() }
+
+    /// Washes player's hands
+    ///
+    /// # Examples
+    /// ```
+    /// person.player_state.wash_hands();
+    /// ```
+    pub fn wash_hands(&self) {
+        self.hands_bloodied.set(false);
+        self.mark_dirty();
+    }
+
+    /// Sets shelter quality (0..1). `1` keeps the rain and wind out completely, `0` does not
+    /// protect at all. Is used only while `is_inside_shelter` is `true`
+    ///
+    /// # Parameters
+    /// - `quality`: shelter quality (0..1)
+    ///
+    /// # Examples
+    /// ```
+    /// person.player_state.is_inside_shelter.set(true);
+    /// person.player_state.set_shelter_quality(0.6); // Leaky tent
+    /// ```
+    pub fn set_shelter_quality(&self, quality: f32) {
+        self.shelter_quality.set(crate::utils::clamp_01(quality));
+        self.mark_dirty();
+    }
+
+    /// Recalculates the exertion noise level from the frame data
+    pub(crate) fn update_exertion_noise(&self, frame_data: &FrameSummaryC) {
+        const WALKING_NOISE: f32 = 20.;
+        const RUNNING_NOISE: f32 = 60.;
+        const SWIMMING_NOISE: f32 = 40.;
+        const ARMS_WORK_NOISE: f32 = 30.;
+        const MAX_BREATHING_NOISE: f32 = 20.;
+        const MAX_LIMPING_NOISE: f32 = 25.;
+
+        let player = &frame_data.player;
+
+        if player.is_sleeping || player.is_underwater {
+            self.exertion_noise.set(0);
+            return;
+        }
+
+        let activity_noise = [
+            (Activity::Walking, WALKING_NOISE),
+            (Activity::Running, RUNNING_NOISE),
+            (Activity::Swimming, SWIMMING_NOISE),
+            (Activity::ArmsWork, ARMS_WORK_NOISE)
+        ].iter()
+            .filter(|(a, _)| player.is_doing(*a))
+            .map(|(_, n)| *n)
+            .fold(0., f32::max);
+
+        // Heavy breathing when out of breath or exhausted
+        let breath = f32::max(100. - frame_data.health.stamina_level, frame_data.health.fatigue_level) / 100.;
+        let breathing_noise = crate::utils::lerp(0., MAX_BREATHING_NOISE, crate::utils::clamp_01(breath));
+
+        // Limping is loud: moving with an injured leg or with lowered mobility
+        let is_moving = player.is_walking || player.is_running;
+        let injured_leg = frame_data.health.injuries.iter().any(|i| i.is_active &&
+            i.body_part.activities().contains(&Activity::Walking));
+        let limping_noise = if !is_moving {
+            0.
+        } else if injured_leg {
+            MAX_LIMPING_NOISE
+        } else {
+            crate::utils::lerp(MAX_LIMPING_NOISE, 0., crate::utils::clamp_01(player.mobility))
+        };
+
+        let noise = crate::utils::clamp(activity_noise + breathing_noise + limping_noise, 0., 100.);
+
+        self.exertion_noise.set(noise as usize);
+    }
+
+    /// Values of all fields except the shelter quality, in the declaration order. Is used for
+    /// the comparison
+    fn flags(&self) -> (bool, bool, bool, bool, bool, usize, bool, bool, MovementC, usize, bool) {
+        (self.is_walking.get(), self.is_running.get(), self.is_swimming.get(), self.is_underwater.get(),
+         self.is_using_arms.get(), self.busy_hands.get(), self.is_inside_shelter.get(),
+         self.is_near_heat_source.get(), self.reported_movement.get(), self.exertion_noise.get(),
+         self.hands_bloodied.get())
+    }
+}
+
+/// Kind of the character movement reported by the game
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub enum MovementKind {
+    /// Character is walking
+    Walking,
+    /// Character is running
+    Running,
+    /// Character is swimming
+    Swimming
+}
+impl fmt::Display for MovementKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Player activity that uses particular body parts. Is consulted by the localized injury effects
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub enum Activity {
+    /// Player is walking
+    Walking,
+    /// Player is running
+    Running,
+    /// Player is swimming or is under the water
+    Swimming,
+    /// Player is doing some arm-intensive activity
+    ArmsWork
+}
+impl fmt::Display for Activity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Biological sex of a character. Slightly changes healthy vitals baselines
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Sex {
+    /// Baselines are not adjusted
+    #[default]
+    Unspecified,
+    /// Slightly higher blood pressure
+    Male,
+    /// Slightly higher body temperature and lower blood pressure
+    Female
+}
+impl fmt::Display for Sex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Describes physiology of a particular survivor. Is consulted by the health math and
+/// warmth calculations, so different characters behave differently with the same set of
+/// side effects, diseases and injuries.
+///
+/// Use [`average`], [`athlete`] or [`elderly`] presets or fill the fields manually.
+///
+/// [`average`]: #method.average
+/// [`athlete`]: #method.athlete
+/// [`elderly`]: #method.elderly
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CharacterProfile {
+    /// Multiplier for all food and water drains. `1.0` is an average metabolism
+    pub metabolic_rate: f32,
+    /// Resting heart rate, bpm. Other vitals deltas are applied on top of it
+    pub base_heart_rate: f32,
+    /// How many degrees C warmer environment feels for this character. Negative values
+    /// mean character is sensitive to cold
+    pub cold_tolerance: f32,
+    /// Stamina capacity multiplier. All stamina drains are divided by this value
+    pub stamina_capacity: f32,
+    /// Multiplier for natural stamina, blood and oxygen regain rates
+    pub recovery_rate: f32,
+    /// Age of the character, years. Adjusts blood pressure baselines, fatigue accumulation
+    /// and disease susceptibility
+    pub age: usize,
+    /// Biological sex of the character. Adjusts body temperature and blood pressure baselines
+    pub sex: Sex
+}
+impl Default for CharacterProfile {
+    fn default() -> Self { CharacterProfile::average() }
+}
+impl fmt::Display for CharacterProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Character profile: metabolism x{:.2}, heart rate {:.0}, cold tolerance {:.1}, stamina x{:.2}, recovery x{:.2}, age {}, {}",
+               self.metabolic_rate, self.base_heart_rate, self.cold_tolerance, self.stamina_capacity, self.recovery_rate,
+               self.age, self.sex)
+    }
+}
+impl Eq for CharacterProfile { }
+impl Hash for CharacterProfile {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u32((self.metabolic_rate*10_000_f32) as u32);
+        state.write_u32((self.base_heart_rate*10_000_f32) as u32);
+        state.write_i32((self.cold_tolerance*10_000_f32) as i32);
+        state.write_u32((self.stamina_capacity*10_000_f32) as u32);
+        state.write_u32((self.recovery_rate*10_000_f32) as u32);
+        self.age.hash(state);
+        self.sex.hash(state);
+    }
+}
+impl CharacterProfile {
+    /// Average healthy adult. This is the default profile
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::player;
+    ///
+    /// let o = player::CharacterProfile::average();
+    /// ```
+    pub fn average() -> Self {
+        CharacterProfile {
+            metabolic_rate: 1.,
+            base_heart_rate: 64.,
+            cold_tolerance: 0.,
+            stamina_capacity: 1.,
+            recovery_rate: 1.,
+            age: 30,
+            sex: Sex::Unspecified
+        }
+    }
+
+    /// Trained athlete: low resting heart rate, fast metabolism, big stamina capacity
+    /// and fast recovery
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::player;
+    ///
+    /// let o = player::CharacterProfile::athlete();
+    /// ```
+    pub fn athlete() -> Self {
+        CharacterProfile {
+            metabolic_rate: 1.2,
+            base_heart_rate: 52.,
+            cold_tolerance: 2.,
+            stamina_capacity: 1.5,
+            recovery_rate: 1.4,
+            age: 25,
+            sex: Sex::Unspecified
+        }
+    }
+
+    /// Elderly person: slow metabolism, higher resting heart rate, sensitive to cold,
+    /// small stamina capacity and slow recovery
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::player;
+    ///
+    /// let o = player::CharacterProfile::elderly();
+    /// ```
+    pub fn elderly() -> Self {
+        CharacterProfile {
+            metabolic_rate: 0.8,
+            base_heart_rate: 72.,
+            cold_tolerance: -4.,
+            stamina_capacity: 0.6,
+            recovery_rate: 0.6,
+            age: 70,
+            sex: Sex::Unspecified
+        }
+    }
+
+    /// Healthy vitals of this character. Resting heart rate comes from `base_heart_rate`,
+    /// blood pressure rises with age and body temperature and pressure depend on sex
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::player;
+    ///
+    /// let healthy = player::CharacterProfile::elderly().healthy_vitals();
+    /// ```
+    pub fn healthy_vitals(&self) -> HealthC {
+        let healthy = HealthC::healthy();
+        let years_over_30 = self.age.saturating_sub(30) as f32;
+        let (temp_delta, pressure_delta) = match self.sex {
+            Sex::Female => (0.1, -4.),
+            Sex::Male => (0., 2.),
+            Sex::Unspecified => (0., 0.)
+        };
+
+        HealthC {
+            heart_rate: self.base_heart_rate,
+            body_temperature: healthy.body_temperature + temp_delta,
+            top_pressure: healthy.top_pressure + pressure_delta + years_over_30 * 0.5,
+            bottom_pressure: healthy.bottom_pressure + pressure_delta / 2. + years_over_30 * 0.2,
+            ..healthy
+        }
+    }
+
+    /// Fatigue accumulation multiplier. Characters older than 40 get tired faster
+    ///
+    /// # Examples
+    /// ```
+    /// let value = profile.fatigue_rate();
+    /// ```
+    pub fn fatigue_rate(&self) -> f32 { 1. + self.age.saturating_sub(40) as f32 * 0.01 }
+
+    /// Disease susceptibility multiplier. Children and elderly characters are more vulnerable.
+    /// Zara applies it to the food poisoning chances; disease monitors can read it from the
+    /// frame summary `profile` field to scale their own chances
+    ///
+    /// # Examples
+    /// ```
+    /// let value = profile.disease_susceptibility();
+    /// ```
+    pub fn disease_susceptibility(&self) -> f32 {
+        if self.age < 12 {
+            1.3
+        } else {
+            1. + self.age.saturating_sub(60) as f32 * 0.02
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,106 @@
+use crate::ZaraController;
+use crate::utils::event::{Listener, Event};
+use crate::error::ReviveErr;
+
+use std::fmt;
+
+/// Describes how a dead character should be revived
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ReviveOptions {
+    /// Reset all vitals to the healthy values
+    pub restore_vitals: bool,
+    /// Remove all diseases and injuries
+    pub clear_conditions: bool,
+    /// Keep inventory items, clothes and body appliances. If `false`, clothes and appliances
+    /// are taken off and all inventory items are removed
+    pub keep_inventory: bool
+}
+impl Default for ReviveOptions {
+    fn default() -> Self {
+        ReviveOptions {
+            restore_vitals: true,
+            clear_conditions: true,
+            keep_inventory: true
+        }
+    }
+}
+impl fmt::Display for ReviveOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Revive: restore vitals {}, clear conditions {}, keep inventory {}",
+               self.restore_vitals, self.clear_conditions, self.keep_inventory)
+    }
+}
+
+impl<E: Listener + 'static> ZaraController<E> {
+    /// Revives a dead character, leaving the post-mortem mode. All registered monitors,
+    /// side effects, medical agents, clothes groups and crafting combinations are kept.
+    ///
+    /// Controller is resumed and game time of the last update is moved to the current game time,
+    /// so time spent dead is not processed.
+    ///
+    /// # Parameters
+    /// - `options`: what should be reset, see [`ReviveOptions`](crate::revive::ReviveOptions)
+    ///
+    /// # Returns
+    /// Ok on success
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::revive::ReviveOptions;
+    ///
+    /// person.revive(ReviveOptions {
+    ///     restore_vitals: true,
+    ///     clear_conditions: true,
+    ///     keep_inventory: false
+    /// });
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Declare-dead) for more info.
+    ///
+    /// ## Notes
+    /// Can borrow `health.diseases`, `health.injuries`, `health.conditions`, `body.clothes`, `body.appliances` and
+    /// `inventory.items` collections
+    pub fn revive(&self, options: ReviveOptions) -> Result<(), ReviveErr> {
+        if self.health.is_alive() { return Err(ReviveErr::CharacterIsAlive); }
+
+        if options.clear_conditions {
+            self.health.clear_diseases();
+            self.health.clear_injuries();
+            self.health.clear_conditions();
+        }
+
+        if !options.keep_inventory {
+            let clothes = self.body.clothes.borrow().clone();
+            for item_name in clothes.iter() {
+                self.body.request_clothes_off(item_name).ok();
+            }
+
+            let appliances = self.body.appliances.borrow().clone();
+            for appliance in appliances.iter() {
+                self.body.remove_appliance(&appliance.item_name, appliance.body_part);
+            }
+
+            self.inventory.clear_items();
+        }
+
+        self.health.revive(options.restore_vitals);
+
+        // Time spent dead must not be processed on the next update
+        let game_time_duration = self.environment.game_time.duration.get();
+
+        self.last_update_game_time.set(game_time_duration);
+        self.last_frame_game_time.set(game_time_duration);
+        self.update_counter.set(0.);
+
+        self.death_time.set(None);
+        self.pending_actions.borrow_mut().clear();
+        self.is_paused.set(false);
+
+        // Send the event
+        self.dispatcher.borrow_mut().dispatch(Event::Revived);
+        self.deliver_events();
+
+        Ok(())
+    }
+}
\ No newline at end of file
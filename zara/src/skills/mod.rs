@@ -0,0 +1,400 @@
+use crate::utils::FrameSummaryC;
+use crate::utils::event::{Event, MessageQueue};
+use crate::player::CharacterProfile;
+use crate::error::SkillXpErr;
+use crate::state::DirtyTracked;
+
+use std::collections::BTreeMap;
+use std::cell::{Cell, RefCell, RefMut};
+use std::rc::Rc;
+use std::fmt;
+
+pub(crate) mod state;
+
+/// Describes how a skill gains experience
+#[derive(Copy, Clone, Debug)]
+pub enum SkillXpSource {
+    /// Experience per game hour of running
+    RunningHours(f32),
+    /// Experience per game hour of walking
+    WalkingHours(f32),
+    /// Experience per game hour of swimming
+    SwimmingHours(f32),
+    /// Experience per game hour spent under water
+    UnderwaterHours(f32),
+    /// Experience per game hour of sleep
+    SleepingHours(f32),
+    /// Experience per consumed item
+    ItemConsumed(f32),
+    /// Experience per taken appliance (bandage, injection and so on)
+    ApplianceTaken(f32),
+    /// Experience per executed crafting combination
+    CombinationExecuted(f32),
+    /// Experience returned by a given function for every Zara event that passes through
+    /// the controller
+    OnEvent(fn(&Event) -> f32)
+}
+
+/// Describes how a skill level affects the simulation. Every value is applied once per
+/// skill level
+#[derive(Clone, Debug)]
+pub enum SkillEffect {
+    /// Relative change of the character profile `metabolic_rate` per level (`-0.02` means 2%
+    /// less food and water drain per level)
+    MetabolicRate(f32),
+    /// Relative change of the character profile `stamina_capacity` per level
+    StaminaCapacity(f32),
+    /// Relative change of the character profile `recovery_rate` per level
+    RecoveryRate(f32),
+    /// Absolute change of the character profile `cold_tolerance` per level, degrees C
+    ColdTolerance(f32),
+    /// Relative change of a custom game modifier per level (like treatment speed).
+    /// Read it with `Skills.modifier`
+    Custom(String, f32)
+}
+
+/// Describes a skill
+pub struct Skill {
+    /// Unique name of the skill
+    name: String,
+    /// How much experience is needed for one level
+    xp_per_level: f32,
+    /// Maximum level of this skill
+    max_level: usize,
+    /// Experience sources
+    sources: Vec<SkillXpSource>,
+    /// Level effects
+    effects: Vec<SkillEffect>,
+    /// Accumulated experience
+    xp: Cell<f32>
+}
+impl fmt::Display for Skill {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (level {}, {:.1} xp)", self.name, self.level(), self.xp.get())
+    }
+}
+impl Skill {
+    /// Creates a new skill
+    ///
+    /// # Parameters
+    /// - `name`: unique name of the skill
+    /// - `xp_per_level`: how much experience is needed for one level
+    /// - `max_level`: maximum level of this skill
+    /// - `sources`: how this skill gains experience
+    /// - `effects`: how every level of this skill affects the simulation
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::skills::{Skill, SkillXpSource, SkillEffect};
+    ///
+    /// let o = Skill::new("Endurance", 100., 10,
+    ///     vec![SkillXpSource::RunningHours(50.)],
+    ///     vec![SkillEffect::StaminaCapacity(0.05)]
+    /// );
+    /// ```
+    pub fn new(name: &str, xp_per_level: f32, max_level: usize, sources: Vec<SkillXpSource>,
+               effects: Vec<SkillEffect>) -> Self {
+        Skill {
+            name: name.to_string(),
+            xp_per_level,
+            max_level,
+            sources,
+            effects,
+            xp: Cell::new(0.)
+        }
+    }
+
+    /// Unique name of this skill
+    ///
+    /// # Examples
+    /// ```
+    /// let value = skill.get_name();
+    /// ```
+    pub fn get_name(&self) -> String { self.name.to_string() }
+
+    /// Accumulated experience
+    ///
+    /// # Examples
+    /// ```
+    /// let value = skill.xp();
+    /// ```
+    pub fn xp(&self) -> f32 { self.xp.get() }
+
+    /// Current level of this skill
+    ///
+    /// # Examples
+    /// ```
+    /// let value = skill.level();
+    /// ```
+    pub fn level(&self) -> usize {
+        if self.xp_per_level <= 0. { return self.max_level; }
+
+        usize::min((self.xp.get() / self.xp_per_level) as usize, self.max_level)
+    }
+
+    /// Progress towards the next level (0..1). Is `1` when maximum level is reached
+    ///
+    /// # Examples
+    /// ```
+    /// let value = skill.level_progress();
+    /// ```
+    pub fn level_progress(&self) -> f32 {
+        if self.level() >= self.max_level { return 1.; }
+
+        crate::utils::clamp_01((self.xp.get() % self.xp_per_level) / self.xp_per_level)
+    }
+
+    fn xp_for_frame(&self, frame_data: &FrameSummaryC) -> f32 {
+        let hours = frame_data.game_time_delta / 3600.;
+        let player = &frame_data.player;
+        let mut result = 0.;
+
+        for source in self.sources.iter() {
+            result += match source {
+                SkillXpSource::RunningHours(xp) if player.is_running => xp * hours,
+                SkillXpSource::WalkingHours(xp) if player.is_walking => xp * hours,
+                SkillXpSource::SwimmingHours(xp) if player.is_swimming => xp * hours,
+                SkillXpSource::UnderwaterHours(xp) if player.is_underwater => xp * hours,
+                SkillXpSource::SleepingHours(xp) if player.is_sleeping => xp * hours,
+                _ => 0.
+            };
+        }
+
+        result
+    }
+
+    fn xp_for_event(&self, event: &Event) -> f32 {
+        let mut result = 0.;
+
+        for source in self.sources.iter() {
+            result += match (source, event) {
+                (SkillXpSource::ItemConsumed(xp), Event::ItemConsumed(_)) => *xp,
+                (SkillXpSource::ApplianceTaken(xp), Event::ApplianceTaken(_, _)) => *xp,
+                (SkillXpSource::CombinationExecuted(xp), Event::CraftingCombinationExecuted(_)) => *xp,
+                (SkillXpSource::OnEvent(f), e) => f(e),
+                _ => 0.
+            };
+        }
+
+        result
+    }
+}
+
+/// Skills node. Accumulates experience into named skills whose levels affect the
+/// character profile and custom game modifiers
+pub struct Skills {
+    /// All registered skills.
+    ///
+    /// # Important
+    /// Do not alter this collection manually. Use [`register_skills`] and [`add_xp`]
+    /// methods instead, or level-up events will not be sent.
+    ///
+    /// [`register_skills`]: #method.register_skills
+    /// [`add_xp`]: #method.add_xp
+    pub skills: Rc<RefCell<BTreeMap<String, Skill>>>,
+
+    /// Is node changed since the last state snapshot
+    is_dirty: Cell<bool>,
+
+    /// Messages queued for sending on the next frame
+    message_queue: RefCell<BTreeMap<usize, Event>>
+}
+
+impl Skills {
+    pub(crate) fn new() -> Self {
+        Skills {
+            skills: Rc::new(RefCell::new(BTreeMap::new())),
+            is_dirty: Cell::new(false),
+            message_queue: RefCell::new(BTreeMap::new())
+        }
+    }
+
+    /// Registers new skills. Skills with the same names will be replaced
+    ///
+    /// # Parameters
+    /// - `skills`: skills to register
+    ///
+    /// # Examples
+    /// ```
+    /// person.skills.register_skills(vec![endurance, first_aid]);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `skills` collection
+    pub fn register_skills(&self, skills: Vec<Skill>) {
+        self.mark_dirty();
+
+        let mut b = self.skills.borrow_mut();
+
+        for skill in skills {
+            b.insert(skill.get_name(), skill);
+        }
+    }
+
+    /// Adds experience to a skill. Level-up events will be sent on the next frame
+    ///
+    /// # Parameters
+    /// - `skill_name`: unique name of the skill
+    /// - `xp`: amount of experience to add
+    ///
+    /// # Returns
+    /// Ok with the new skill level on success
+    ///
+    /// # Examples
+    /// ```
+    /// person.skills.add_xp(&skill_name, 25.);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `skills` collection
+    pub fn add_xp(&self, skill_name: &String, xp: f32) -> Result<usize, SkillXpErr> {
+        match self.skills.borrow().get(skill_name) {
+            Some(skill) => Ok(self.add_skill_xp(skill, xp)),
+            None => Err(SkillXpErr::SkillNotFound)
+        }
+    }
+
+    /// Returns level of a skill. `None` if skill is not registered
+    ///
+    /// # Parameters
+    /// - `skill_name`: unique name of the skill
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(level) = person.skills.level_of(&skill_name) {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `skills` collection
+    pub fn level_of(&self, skill_name: &String) -> Option<usize> {
+        self.skills.borrow().get(skill_name).map(|s| s.level())
+    }
+
+    /// Returns accumulated experience of a skill. `None` if skill is not registered
+    ///
+    /// # Parameters
+    /// - `skill_name`: unique name of the skill
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(xp) = person.skills.xp_of(&skill_name) {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `skills` collection
+    pub fn xp_of(&self, skill_name: &String) -> Option<f32> {
+        self.skills.borrow().get(skill_name).map(|s| s.xp())
+    }
+
+    /// Returns multiplier of a custom game modifier (declared by `SkillEffect::Custom`)
+    /// according to the current skill levels. `1.0` if no skill affects it
+    ///
+    /// # Parameters
+    /// - `key`: custom modifier name
+    ///
+    /// # Examples
+    /// ```
+    /// let treatment_speed = person.skills.modifier("TreatmentSpeed");
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `skills` collection
+    pub fn modifier(&self, key: &str) -> f32 {
+        let mut result = 1.;
+
+        for (_, skill) in self.skills.borrow().iter() {
+            let level = skill.level() as f32;
+
+            for effect in skill.effects.iter() {
+                if let SkillEffect::Custom(name, value) = effect {
+                    if name == key { result += value * level; }
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Applies skill levels to a given base character profile
+    pub(crate) fn apply_to_profile(&self, profile: &CharacterProfile) -> CharacterProfile {
+        let mut metabolic_rate = 1.;
+        let mut stamina_capacity = 1.;
+        let mut recovery_rate = 1.;
+        let mut cold_tolerance = 0.;
+
+        for (_, skill) in self.skills.borrow().iter() {
+            let level = skill.level() as f32;
+
+            for effect in skill.effects.iter() {
+                match effect {
+                    SkillEffect::MetabolicRate(v) => metabolic_rate += v * level,
+                    SkillEffect::StaminaCapacity(v) => stamina_capacity += v * level,
+                    SkillEffect::RecoveryRate(v) => recovery_rate += v * level,
+                    SkillEffect::ColdTolerance(v) => cold_tolerance += v * level,
+                    SkillEffect::Custom(_, _) => { }
+                }
+            }
+        }
+
+        CharacterProfile {
+            metabolic_rate: profile.metabolic_rate * crate::utils::clamp_bottom(metabolic_rate, 0.),
+            stamina_capacity: profile.stamina_capacity * crate::utils::clamp_bottom(stamina_capacity, 0.),
+            recovery_rate: profile.recovery_rate * crate::utils::clamp_bottom(recovery_rate, 0.),
+            cold_tolerance: profile.cold_tolerance + cold_tolerance,
+            ..*profile
+        }
+    }
+
+    /// Accumulates time-based experience. Is called every `UPDATE_INTERVAL` real seconds
+    pub(crate) fn update(&self, frame_data: &FrameSummaryC) {
+        for (_, skill) in self.skills.borrow().iter() {
+            let xp = skill.xp_for_frame(frame_data);
+
+            if xp > 0. { self.add_skill_xp(skill, xp); }
+        }
+    }
+
+    /// Accumulates event-based experience
+    pub(crate) fn on_event(&self, event: &Event) {
+        for (_, skill) in self.skills.borrow().iter() {
+            let xp = skill.xp_for_event(event);
+
+            if xp > 0. { self.add_skill_xp(skill, xp); }
+        }
+    }
+
+    fn add_skill_xp(&self, skill: &Skill, xp: f32) -> usize {
+        self.mark_dirty();
+
+        let old_level = skill.level();
+
+        skill.xp.set(crate::utils::clamp_bottom(skill.xp.get() + xp, 0.));
+
+        let new_level = skill.level();
+
+        for level in (old_level + 1)..=new_level {
+            self.queue_message(Event::SkillLevelUp(skill.get_name(), level));
+        }
+
+        new_level
+    }
+}
+
+impl MessageQueue for Skills {
+    fn has_messages(&self) -> bool { !self.message_queue.borrow().is_empty() }
+
+    fn queue_message(&self, message: Event) {
+        let mut q = self.message_queue.borrow_mut();
+        let id = q.len();
+
+        q.insert(id, message);
+    }
+
+    fn get_message_queue(&self) -> RefMut<'_, BTreeMap<usize, Event>> {
+        self.message_queue.borrow_mut()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,88 @@
+use crate::skills::Skills;
+use crate::state::DirtyTracked;
+
+use std::cell::Cell;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Contains state snapshot for the skills node (experience of every registered skill)
+#[derive(Clone, Debug, Default)]
+pub struct SkillsStateContract {
+    /// Schema version this state was captured with
+    pub version: u32,
+    /// Captured state of the `skills` field
+    pub skills: Vec<SkillStateContract>
+}
+impl fmt::Display for SkillsStateContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Skills state ({} skills)", self.skills.len())
+    }
+}
+impl Eq for SkillsStateContract { }
+impl PartialEq for SkillsStateContract {
+    fn eq(&self, other: &Self) -> bool {
+        self.version == other.version &&
+        self.skills == other.skills
+    }
+}
+impl Hash for SkillsStateContract {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.version.hash(state);
+        self.skills.hash(state);
+    }
+}
+
+/// Contains state snapshot for a single skill
+#[derive(Clone, Debug, Default)]
+pub struct SkillStateContract {
+    /// Captured state of the `name` field
+    pub name: String,
+    /// Captured state of the `xp` field
+    pub xp: f32
+}
+impl fmt::Display for SkillStateContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {:.1} xp", self.name, self.xp)
+    }
+}
+impl Eq for SkillStateContract { }
+impl PartialEq for SkillStateContract {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.name == other.name &&
+        f32::abs(self.xp - other.xp) < EPS
+    }
+}
+impl Hash for SkillStateContract {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+
+        state.write_u32((self.xp*10_000_f32) as u32);
+    }
+}
+
+impl DirtyTracked for Skills {
+    fn dirty_flag(&self) -> &Cell<bool> { &self.is_dirty }
+}
+impl Skills {
+    pub(crate) fn get_state(&self) -> SkillsStateContract {
+        SkillsStateContract {
+            version: crate::state::STATE_CONTRACT_VERSION,
+            skills: self.skills.borrow().iter().map(|(name, skill)| SkillStateContract {
+                name: name.to_string(),
+                xp: skill.xp.get()
+            }).collect()
+        }
+    }
+    pub(crate) fn restore_state(&self, state: &SkillsStateContract) {
+        let b = self.skills.borrow();
+
+        // Skills themselves are registered by the game, only experience is restored
+        for skill_state in state.skills.iter() {
+            if let Some(skill) = b.get(&skill_state.name) {
+                skill.xp.set(skill_state.xp);
+            }
+        }
+    }
+}
\ No newline at end of file
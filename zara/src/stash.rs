@@ -0,0 +1,253 @@
+use crate::body::Body;
+use crate::body::parts::BodyPartsRegistry;
+use crate::error::StashRestoreErr;
+use crate::inventory::Inventory;
+use crate::inventory::state::InventoryStateContract;
+use crate::player::PlayerStatus;
+use crate::skills::Skills;
+use crate::state::ItemCountStateContract;
+use crate::utils::EnvironmentC;
+use crate::utils::event::{Event, MessageQueue};
+use crate::world::EnvironmentData;
+
+use std::fmt;
+use std::rc::Rc;
+use std::cell::Cell;
+
+/// World item storage, like a chest, a cache or a pile on the ground. Holds items using the
+/// same item model as the character inventory, but has no body or health. Use
+/// [`transfer_item`](crate::inventory::Inventory::transfer_item) to move items between a
+/// stash and a character
+///
+/// # Examples
+/// ```ignore
+/// use zara::stash::Stash;
+///
+/// let chest = Stash::new("Cabin chest");
+///
+/// chest.inventory.add_item(Box::new(Meat { count: 2 }));
+/// chest.inventory.transfer_item(&person.inventory, &meat_name, 1)?;
+/// ```
+///
+/// # Links
+/// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Inventory) for more info.
+pub struct Stash {
+    /// Unique id of the stash
+    pub id: String,
+    /// Items of the stash
+    pub inventory: Inventory,
+
+    // Private fields
+    /// How the place of the stash affects items inside
+    exposure: Cell<StashExposure>
+}
+impl fmt::Display for Stash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Stash {} ({} items, {:.0}g)", self.id, self.inventory.stored_items.borrow().len(),
+               self.inventory.get_weight())
+    }
+}
+impl Stash {
+    /// Creates new empty stash
+    ///
+    /// # Parameters
+    /// - `id`: unique id of the stash
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::stash::Stash;
+    ///
+    /// let cache = Stash::new("Cave cache");
+    /// ```
+    pub fn new(id: &str) -> Self {
+        // Stash has its own nodes that are never updated
+        let body = Body::new(Rc::new(BodyPartsRegistry::new()));
+
+        Stash {
+            id: id.to_string(),
+            inventory: Inventory::new(PlayerStatus::empty().into(), Skills::new().into(), body.into(),
+                                      EnvironmentData::new().into()),
+            exposure: Cell::new(StashExposure::default())
+        }
+    }
+
+    /// Sets how the place of the stash affects items inside
+    ///
+    /// # Parameters
+    /// - `exposure`: stash exposure
+    ///
+    /// # Examples
+    /// ```ignore
+    /// use zara::stash::StashExposure;
+    ///
+    /// // Cold cellar
+    /// cellar.set_exposure(StashExposure { spoilage_multiplier: 0.3, rain_exposure: 0. });
+    /// // Pile on the ground
+    /// pile.set_exposure(StashExposure { spoilage_multiplier: 1., rain_exposure: 1. });
+    /// ```
+    pub fn set_exposure(&self, exposure: StashExposure) { self.exposure.set(exposure); }
+
+    /// Returns how the place of the stash affects items inside
+    ///
+    /// # Examples
+    /// ```ignore
+    /// let exposure = chest.exposure();
+    /// ```
+    pub fn exposure(&self) -> StashExposure { self.exposure.get() }
+
+    /// Ages spoiling and expiring items of the stash, and wets or dries clothes inside. Call
+    /// this together with the character `update` for the stashes that should keep up with the
+    /// game time. [`StashExposure`] of the stash is applied
+    ///
+    /// # Parameters
+    /// - `game_time_delta`: game seconds passed since the last call
+    /// - `environment`: weather at the stash place
+    ///
+    /// # Returns
+    /// Inventory events of the stash (items added, used or removed) since the last call
+    ///
+    /// # Examples
+    /// ```ignore
+    /// for event in chest.update(game_time_delta, &weather) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn update(&self, game_time_delta: f32, environment: &EnvironmentC) -> Vec<Event> {
+        let exposure = self.exposure.get();
+        let rain_exposure = crate::utils::clamp_01(exposure.rain_exposure);
+        let environment = EnvironmentC::new(environment.temperature, environment.wind_speed * rain_exposure,
+                                            environment.rain_intensity * rain_exposure)
+            .with_humidity(environment.humidity);
+
+        self.inventory.update_item_ages(game_time_delta * f32::max(exposure.spoilage_multiplier, 0.));
+        self.inventory.update_exposed_clothes(game_time_delta, &environment);
+        self.inventory.apply_deferred();
+
+        let mut q = self.inventory.get_message_queue();
+        let events = q.values().cloned().collect();
+
+        q.clear();
+
+        events
+    }
+
+    /// Gets stash state snapshot
+    ///
+    /// # Examples
+    /// ```ignore
+    /// let state = chest.get_state();
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management) for more info.
+    pub fn get_state(&self) -> StashStateContract {
+        let mut item_counts: Vec<ItemCountStateContract> = self.inventory.stored_items.borrow().iter()
+            .map(|(name, item)| ItemCountStateContract {
+                item_name: name.to_string(),
+                count: item.get_count()
+            })
+            .collect();
+
+        item_counts.sort_by(|a, b| a.item_name.cmp(&b.item_name));
+
+        StashStateContract {
+            id: self.id.to_string(),
+            inventory: self.inventory.get_state(),
+            item_counts
+        }
+    }
+
+    /// Restores stash state from a snapshot. Items are not created: counts are restored for the
+    /// items that are already in the stash. Items that the snapshot does not list are removed
+    /// from the stash. No removal events are sent
+    ///
+    /// # Parameters
+    /// - `state`: stash state snapshot
+    ///
+    /// # Examples
+    /// ```ignore
+    /// chest.restore_state(&state);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management) for more info.
+    pub fn restore_state(&self, state: &StashStateContract) -> Result<(), StashRestoreErr> {
+        self.inventory.restore_state(&state.inventory);
+
+        let mut missing_items = Vec::new();
+        let mut removed_items = Vec::new();
+        {
+            let mut items = self.inventory.stored_items.borrow_mut();
+
+            // Items that were not in the stash when the state was captured
+            items.retain(|name, _| {
+                let is_captured = state.item_counts.iter().any(|c| &c.item_name == name);
+
+                if !is_captured { removed_items.push(name.to_string()); }
+
+                is_captured
+            });
+
+            for entry in state.item_counts.iter() {
+                match items.get_mut(&entry.item_name) {
+                    Some(item) => item.set_count(entry.count),
+                    None => missing_items.push(entry.item_name.to_string())
+                }
+            }
+        }
+
+        removed_items.iter().for_each(|name| self.inventory.forget_item(name));
+        self.inventory.recalculate_weight();
+
+        if missing_items.is_empty() { return Ok(()); }
+
+        Err(StashRestoreErr::PartiallyRestored(missing_items))
+    }
+}
+
+/// Describes how the place of a stash affects items inside
+///
+/// # Examples
+/// ```
+/// use zara::stash::StashExposure;
+///
+/// let cellar = StashExposure { spoilage_multiplier: 0.3, ..Default::default() };
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct StashExposure {
+    /// How fast items spoil and expire compared to the open air. `1` is normal, cold cellar
+    /// slows it down with values below `1`
+    pub spoilage_multiplier: f32,
+    /// Part of the rain and wind that reaches items inside (0..1). `0` for closed containers,
+    /// `1` for a pile on the ground
+    pub rain_exposure: f32
+}
+impl Default for StashExposure {
+    fn default() -> Self {
+        StashExposure {
+            spoilage_multiplier: 1.,
+            rain_exposure: 0.
+        }
+    }
+}
+impl fmt::Display for StashExposure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Spoilage x{:.2}, rain exposure {:.2}", self.spoilage_multiplier, self.rain_exposure)
+    }
+}
+
+/// Describes captured state of a stash
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StashStateContract {
+    /// Unique id of the stash
+    pub id: String,
+    /// Stash items state
+    pub inventory: InventoryStateContract,
+    /// Stash items counts, sorted by item name
+    pub item_counts: Vec<ItemCountStateContract>
+}
+impl fmt::Display for StashStateContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Stash {} state ({} items)", self.id, self.item_counts.len())
+    }
+}
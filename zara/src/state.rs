@@ -3,12 +3,36 @@ use crate::utils::event::Listener;
 use crate::body::state::BodyStateContract;
 use crate::health::state::HealthStateContract;
 use crate::inventory::state::InventoryStateContract;
+use crate::skills::state::SkillsStateContract;
+use crate::stats::state::StatsStateContract;
+use crate::error::{StateDeltaErr, FullStateRestoreErr};
 
 use std::time::Duration;
+use std::cell::Cell;
+use std::collections::BTreeMap;
 use std::fmt;
 use std::cmp::Ordering;
 use std::hash::{Hash, Hasher};
 
+pub mod migration;
+
+/// Current schema version of the state contracts. Is increased every time state contracts change:
+/// it is one more than the number of Zara's own contract changes
+pub const STATE_CONTRACT_VERSION: u32 = migration::CONTRACT_CHANGES.len() as u32 + 1;
+
+/// Node which changes are tracked between state snapshots, so that deltas capture only the
+/// changed nodes
+pub(crate) trait DirtyTracked {
+    /// Flag that is set when the node changes and is cleared when a snapshot is taken
+    fn dirty_flag(&self) -> &Cell<bool>;
+
+    /// Marks the node as changed since the last snapshot
+    fn mark_dirty(&self) { self.dirty_flag().set(true); }
+
+    /// Returns `true` if the node was changed since the last snapshot and clears the flag
+    fn take_dirty(&self) -> bool { self.dirty_flag().replace(false) }
+}
+
 /// Zara state contract. It **does not** include state of diseases, injuries, side effects monitors,
 /// disease monitors, inventory monitors or inventory items. For those objects you may need to
 /// implement custom methods for saving and restoring their states.
@@ -18,6 +42,11 @@ use std::hash::{Hash, Hasher};
 /// internal fields.
 #[derive(Clone, Debug, Default)]
 pub struct ZaraControllerStateContract {
+    /// Schema version this state was captured with
+    pub version: u32,
+    /// Unique id of this snapshot. Pass it to `get_state_since` to capture only changes made
+    /// after this snapshot was taken. Is not considered when comparing or hashing.
+    pub snapshot_id: usize,
     /// Environment node status snapshot
     pub environment: EnvironmentStateContract,
     /// Player status snapshot
@@ -28,6 +57,10 @@ pub struct ZaraControllerStateContract {
     pub health: HealthStateContract,
     /// Inventory node status snapshot (not including items itself)
     pub inventory: InventoryStateContract,
+    /// Skills node status snapshot (experience of registered skills)
+    pub skills: SkillsStateContract,
+    /// Statistics node status snapshot (lifetime counters)
+    pub stats: StatsStateContract,
 
     /// State of an update counter
     pub update_counter: f32,
@@ -60,11 +93,14 @@ impl PartialEq for ZaraControllerStateContract {
     fn eq(&self, other: &Self) -> bool {
         const EPS: f32 = 0.0001;
 
+        self.version == other.version &&
         self.environment == other.environment &&
         self.player_status == other.player_status &&
         self.body == other.body &&
         self.health == other.health &&
         self.inventory == other.inventory &&
+        self.skills == other.skills &&
+        self.stats == other.stats &&
         self.last_update_game_time == other.last_update_game_time &&
         self.last_frame_game_time == other.last_frame_game_time &&
         self.is_paused == other.is_paused &&
@@ -74,11 +110,14 @@ impl PartialEq for ZaraControllerStateContract {
 }
 impl Hash for ZaraControllerStateContract {
     fn hash<H: Hasher>(&self, state: &mut H) {
+        self.version.hash(state);
         self.environment.hash(state);
         self.player_status.hash(state);
         self.body.hash(state);
         self.health.hash(state);
         self.inventory.hash(state);
+        self.skills.hash(state);
+        self.stats.hash(state);
         self.last_update_game_time.hash(state);
         self.last_frame_game_time.hash(state);
         self.is_paused.hash(state);
@@ -91,6 +130,8 @@ impl Hash for ZaraControllerStateContract {
 /// Describes captured state of an active disease
 #[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
 pub struct ActiveDiseaseStateContract {
+    /// Schema version this state was captured with
+    pub version: u32,
     /// Captured state of the `needs_treatment` field
     pub needs_treatment: bool,
     /// Captured state of the `will_self_heal_on` field
@@ -107,21 +148,33 @@ pub struct ActiveDiseaseStateContract {
     pub last_deltas: crate::health::disease::state::DiseaseDeltasStateContract,
     /// Captured state of the `is_inverted` field
     pub is_inverted: bool,
+    /// Captured state of the `is_healing_paused` field
+    pub is_healing_paused: bool,
+    /// Captured state of the `worst_level_reached` field
+    pub worst_level_reached: crate::health::StageLevel,
     /// Captured state of the `activation_time` field
     pub activation_time: Duration,
     /// Captured state of the `will_end` field
     pub will_end: bool,
     /// Captured state of the `end_time` field
-    pub end_time: Option<Duration>
+    pub end_time: Option<Duration>,
+    /// Captured state of the `scheduled_invert` field
+    pub scheduled_invert: Option<Duration>
 }
 
 /// Describes captured state of an active injury
 #[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
 pub struct ActiveInjuryStateContract {
+    /// Schema version this state was captured with
+    pub version: u32,
     /// Captured state of the `needs_treatment` field
     pub needs_treatment: bool,
     /// Captured state of the `is_fracture` field
     pub is_fracture: bool,
+    /// Captured state of the `is_burn` field
+    pub is_burn: bool,
+    /// Captured state of the `burn_area` field
+    pub burn_area: usize,
     /// Captured state of the `body_part` field
     pub body_part: crate::body::BodyPart,
     /// Captured state of the `will_self_heal_on` field
@@ -138,12 +191,18 @@ pub struct ActiveInjuryStateContract {
     pub last_deltas: crate::health::injury::state::InjuryDeltasStateContract,
     /// Captured state of the `is_inverted` field
     pub is_inverted: bool,
+    /// Captured state of the `is_healing_paused` field
+    pub is_healing_paused: bool,
+    /// Captured state of the `worst_level_reached` field
+    pub worst_level_reached: crate::health::StageLevel,
     /// Captured state of the `activation_time` field
     pub activation_time: Duration,
     /// Captured state of the `will_end` field
     pub will_end: bool,
     /// Captured state of the `end_time` field
-    pub end_time: Option<Duration>
+    pub end_time: Option<Duration>,
+    /// Captured state of the `bleeding_multipliers` field
+    pub bleeding_multipliers: Option<crate::health::bleeding::BleedingMultipliers>
 }
 
 /// Describes captured state of an environment
@@ -156,7 +215,9 @@ pub struct EnvironmentStateContract {
     /// Captured state of the `temperature` field
     pub temperature: f32,
     /// Captured state of the `rain_intensity` field
-    pub rain_intensity: f32
+    pub rain_intensity: f32,
+    /// Captured state of the `humidity` field
+    pub humidity: f32
 }
 impl fmt::Display for EnvironmentStateContract {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -182,7 +243,8 @@ impl PartialEq for EnvironmentStateContract {
         self.game_time == other.game_time &&
         f32::abs(self.temperature - other.temperature) < EPS &&
         f32::abs(self.wind_speed - other.wind_speed) < EPS &&
-        f32::abs(self.rain_intensity - other.rain_intensity) < EPS
+        f32::abs(self.rain_intensity - other.rain_intensity) < EPS &&
+        f32::abs(self.humidity - other.humidity) < EPS
     }
 }
 impl Hash for EnvironmentStateContract {
@@ -192,11 +254,12 @@ impl Hash for EnvironmentStateContract {
         state.write_i32((self.temperature*10_000_f32) as i32);
         state.write_u32((self.wind_speed*10_000_f32) as u32);
         state.write_u32((self.rain_intensity*10_000_f32) as u32);
+        state.write_u32((self.humidity*10_000_f32) as u32);
     }
 }
 
 /// Describes captured state of a player status
-#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct PlayerStatusContract {
     /// Captured state of the `is_walking` field
     pub is_walking: bool,
@@ -205,7 +268,269 @@ pub struct PlayerStatusContract {
     /// Captured state of the `is_swimming` field
     pub is_swimming: bool,
     /// Captured state of the `is_underwater` field
-    pub is_underwater: bool
+    pub is_underwater: bool,
+    /// Captured state of the `is_using_arms` field
+    pub is_using_arms: bool,
+    /// Captured state of the `busy_hands` field
+    pub busy_hands: usize,
+    /// Captured state of the `is_inside_shelter` field
+    pub is_inside_shelter: bool,
+    /// Captured state of the `shelter_quality` field
+    pub shelter_quality: f32,
+    /// Captured state of the `is_near_heat_source` field
+    pub is_near_heat_source: bool,
+    /// Captured state of the `hands_bloodied` field
+    pub are_hands_bloodied: bool
+}
+impl PlayerStatusContract {
+    /// Values of all fields except the shelter quality, in the declaration order
+    fn flags(&self) -> (bool, bool, bool, bool, bool, usize, bool, bool, bool) {
+        (self.is_walking, self.is_running, self.is_swimming, self.is_underwater, self.is_using_arms,
+         self.busy_hands, self.is_inside_shelter, self.is_near_heat_source, self.are_hands_bloodied)
+    }
+}
+impl Ord for PlayerStatusContract {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.flags().cmp(&other.flags())
+            .then(self.shelter_quality.total_cmp(&other.shelter_quality))
+    }
+}
+impl Eq for PlayerStatusContract { }
+impl PartialOrd for PlayerStatusContract {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl PartialEq for PlayerStatusContract {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.flags() == other.flags() &&
+        f32::abs(self.shelter_quality - other.shelter_quality) < EPS
+    }
+}
+impl Hash for PlayerStatusContract {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.flags().hash(state);
+
+        state.write_u32((self.shelter_quality*10_000_f32) as u32);
+    }
+}
+
+/// Full Zara state contract. In addition to the [`ZaraControllerStateContract`], it contains
+/// states of all active diseases and injuries and counts of the inventory items, so that a game
+/// can save and load with a single call.
+///
+/// Use `ZaraController.get_full_state` to capture and `ZaraController.restore_full_state` to
+/// restore.
+#[derive(Clone, Debug, Default)]
+pub struct ZaraStateContract {
+    /// Controller state snapshot
+    pub controller: ZaraControllerStateContract,
+    /// Active diseases snapshots, sorted by disease id
+    pub diseases: Vec<DiseaseEntryStateContract>,
+    /// Active injuries snapshots, sorted by injury id and body part
+    pub injuries: Vec<InjuryEntryStateContract>,
+    /// Inventory items counts, sorted by item name
+    pub item_counts: Vec<ItemCountStateContract>
+}
+impl fmt::Display for ZaraStateContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Zara full state @{} game secs", self.controller.environment.game_time.as_secs_f32())
+    }
+}
+impl Eq for ZaraStateContract { }
+impl PartialEq for ZaraStateContract {
+    fn eq(&self, other: &Self) -> bool {
+        self.controller == other.controller &&
+        self.diseases == other.diseases &&
+        self.injuries == other.injuries &&
+        self.item_counts == other.item_counts
+    }
+}
+impl Hash for ZaraStateContract {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.controller.hash(state);
+        self.diseases.hash(state);
+        self.injuries.hash(state);
+        self.item_counts.hash(state);
+    }
+}
+
+/// Describes captured state of an active disease along with its id
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct DiseaseEntryStateContract {
+    /// Disease id (`get_id`)
+    pub disease_id: String,
+    /// Active disease state
+    pub state: ActiveDiseaseStateContract
+}
+
+/// Describes captured state of an active injury along with its id
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct InjuryEntryStateContract {
+    /// Injury id (`get_id`)
+    pub injury_id: String,
+    /// Active injury state
+    pub state: ActiveInjuryStateContract
+}
+
+/// Describes captured count of an inventory item
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct ItemCountStateContract {
+    /// Inventory item name (key)
+    pub item_name: String,
+    /// Captured count of the item
+    pub count: usize
+}
+
+/// Incremental Zara state contract. Contains only those node snapshots that changed since the
+/// base snapshot was taken; unchanged nodes are `None`. Controller-level counters are always
+/// included.
+///
+/// Use `ZaraController.get_state_since` to capture and `ZaraController.apply_delta` to restore.
+#[derive(Clone, Debug, Default)]
+pub struct ZaraControllerStateDeltaContract {
+    /// Unique id of the snapshot this delta was taken against
+    pub base_snapshot_id: usize,
+    /// Unique id of this snapshot. Can be used as a base for the next delta
+    pub snapshot_id: usize,
+    /// Environment node status snapshot, if changed
+    pub environment: Option<EnvironmentStateContract>,
+    /// Player status snapshot, if changed
+    pub player_status: Option<PlayerStatusContract>,
+    /// Body node status snapshot, if changed
+    pub body: Option<BodyStateContract>,
+    /// Health node status snapshot, if changed
+    pub health: Option<HealthStateContract>,
+    /// Inventory node status snapshot (not including items itself), if changed
+    pub inventory: Option<InventoryStateContract>,
+    /// Skills node status snapshot, if changed
+    pub skills: Option<SkillsStateContract>,
+    /// Statistics node status snapshot, if changed
+    pub stats: Option<StatsStateContract>,
+
+    /// State of an update counter
+    pub update_counter: f32,
+    /// State of a queue counter
+    pub queue_counter: f32,
+    /// State of a game time when `update` was last called
+    pub last_update_game_time: Duration,
+    /// State of a game time when controller was last updated
+    pub last_frame_game_time: Duration,
+    /// Paused state value
+    pub is_paused: bool
+}
+impl fmt::Display for ZaraControllerStateDeltaContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Zara state delta #{} -> #{} ({} nodes changed)", self.base_snapshot_id,
+               self.snapshot_id, self.changed_count())
+    }
+}
+impl Eq for ZaraControllerStateDeltaContract { }
+impl PartialEq for ZaraControllerStateDeltaContract {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.base_snapshot_id == other.base_snapshot_id &&
+        self.snapshot_id == other.snapshot_id &&
+        self.environment == other.environment &&
+        self.player_status == other.player_status &&
+        self.body == other.body &&
+        self.health == other.health &&
+        self.inventory == other.inventory &&
+        self.skills == other.skills &&
+        self.stats == other.stats &&
+        self.last_update_game_time == other.last_update_game_time &&
+        self.last_frame_game_time == other.last_frame_game_time &&
+        self.is_paused == other.is_paused &&
+        f32::abs(self.update_counter - other.update_counter) < EPS &&
+        f32::abs(self.queue_counter - other.queue_counter) < EPS
+    }
+}
+impl Hash for ZaraControllerStateDeltaContract {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.base_snapshot_id.hash(state);
+        self.snapshot_id.hash(state);
+        self.environment.hash(state);
+        self.player_status.hash(state);
+        self.body.hash(state);
+        self.health.hash(state);
+        self.inventory.hash(state);
+        self.skills.hash(state);
+        self.stats.hash(state);
+        self.last_update_game_time.hash(state);
+        self.last_frame_game_time.hash(state);
+        self.is_paused.hash(state);
+
+        state.write_u32((self.update_counter*100_f32) as u32);
+        state.write_u32((self.queue_counter*100_f32) as u32);
+    }
+}
+impl ZaraControllerStateDeltaContract {
+    /// Returns count of nodes that were changed (dirty) since the base snapshot
+    ///
+    /// # Examples
+    /// ```
+    /// let value = delta.changed_count();
+    /// ```
+    pub fn changed_count(&self) -> usize {
+        let mut result = 0;
+
+        if self.environment.is_some() { result += 1; }
+        if self.player_status.is_some() { result += 1; }
+        if self.body.is_some() { result += 1; }
+        if self.health.is_some() { result += 1; }
+        if self.inventory.is_some() { result += 1; }
+        if self.skills.is_some() { result += 1; }
+        if self.stats.is_some() { result += 1; }
+
+        result
+    }
+
+    /// Returns `true` if no nodes were changed since the base snapshot
+    ///
+    /// # Examples
+    /// ```
+    /// let value = delta.is_empty();
+    /// ```
+    pub fn is_empty(&self) -> bool { self.changed_count() == 0 }
+}
+
+/// How many last captured snapshots controller keeps to build deltas against
+pub const MAX_STATE_SNAPSHOTS: usize = 8;
+
+/// Nodes that were changed (dirty) between two state snapshots
+#[derive(Copy, Clone, Debug, Default)]
+pub(crate) struct DirtyNodesC {
+    /// Environment node was changed
+    pub environment: bool,
+    /// Player status was changed
+    pub player_status: bool,
+    /// Body node was changed
+    pub body: bool,
+    /// Health node was changed
+    pub health: bool,
+    /// Inventory node was changed
+    pub inventory: bool,
+    /// Skills node was changed
+    pub skills: bool,
+    /// Statistics node was changed
+    pub stats: bool
+}
+impl DirtyNodesC {
+    /// Returns nodes changed in either of the two
+    fn merge(&self, other: &DirtyNodesC) -> Self {
+        DirtyNodesC {
+            environment: self.environment || other.environment,
+            player_status: self.player_status || other.player_status,
+            body: self.body || other.body,
+            health: self.health || other.health,
+            inventory: self.inventory || other.inventory,
+            skills: self.skills || other.skills,
+            stats: self.stats || other.stats
+        }
+    }
 }
 
 impl<E: Listener + 'static> ZaraController<E> {
@@ -227,22 +552,88 @@ impl<E: Listener + 'static> ZaraController<E> {
     /// # Links
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management) for more info.
     pub fn get_state(&self) -> ZaraControllerStateContract {
+        let dirty = self.take_dirty_nodes();
+        let state = self.capture_state(self.next_snapshot_id());
+
+        self.remember_snapshot(state.snapshot_id, dirty);
+
+        state
+    }
+
+    /// Captures only changes made since the snapshot with the given id was taken. Nodes that
+    /// were not changed (not dirty) since then will be `None` in the resulting delta.
+    ///
+    /// Nodes are marked as changed by their mutating methods and by every processed `update`
+    /// frame. Environment and player status are compared with their values at the last snapshot
+    /// instead, so fields set directly (like `player_state.is_walking`) are picked up, and these
+    /// nodes are left out while their values stay the same.
+    ///
+    /// Controller remembers last `MAX_STATE_SNAPSHOTS` snapshots taken by [`get_state`] or
+    /// `get_state_since`, restored or applied. Restoring a state forgets all snapshots taken before
+    /// it. Resulting delta has its own `snapshot_id` that can be used as a base
+    /// for the next delta.
+    ///
+    /// [`get_state`]: #method.get_state
+    ///
+    /// # Parameters
+    /// - `previous_snapshot_id`: `snapshot_id` of a previously captured full state or delta
+    ///
+    /// # Returns
+    /// Ok with the delta contract on success
+    ///
+    /// # Examples
+    /// ```
+    /// let full = person.get_state();
+    /// // ...
+    /// let delta = person.get_state_since(full.snapshot_id);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management) for more info.
+    pub fn get_state_since(&self, previous_snapshot_id: usize) -> Result<ZaraControllerStateDeltaContract, StateDeltaErr> {
+        if !self.state_snapshots.borrow().contains_key(&previous_snapshot_id) {
+            return Err(StateDeltaErr::SnapshotNotFound);
+        }
+
+        let dirty = self.take_dirty_nodes();
+        // Nodes changed by the later snapshots were changed since the base one too
+        let changed = self.state_snapshots.borrow()
+            .range(previous_snapshot_id + 1..)
+            .fold(dirty, |result, (_, d)| result.merge(d));
+        let snapshot_id = self.next_snapshot_id();
+
+        self.remember_snapshot(snapshot_id, dirty);
+
+        Ok(ZaraControllerStateDeltaContract {
+            base_snapshot_id: previous_snapshot_id,
+            snapshot_id,
+            environment: if changed.environment { Some(self.capture_environment()) } else { None },
+            player_status: if changed.player_status { Some(self.capture_player_status()) } else { None },
+            body: if changed.body { Some(self.body.get_state()) } else { None },
+            health: if changed.health { Some(self.health.get_state()) } else { None },
+            inventory: if changed.inventory { Some(self.inventory.get_state()) } else { None },
+            skills: if changed.skills { Some(self.skills.get_state()) } else { None },
+            stats: if changed.stats { Some(self.stats.get_state()) } else { None },
+
+            update_counter: self.update_counter.get(),
+            queue_counter: self.queue_counter.get(),
+            last_update_game_time: self.last_update_game_time.get(),
+            last_frame_game_time: self.last_frame_game_time.get(),
+            is_paused: self.is_paused.get()
+        })
+    }
+
+    fn capture_state(&self, snapshot_id: usize) -> ZaraControllerStateContract {
         ZaraControllerStateContract {
-            environment: EnvironmentStateContract {
-                game_time: self.environment.game_time.duration.get(),
-                wind_speed: self.environment.wind_speed.get(),
-                temperature: self.environment.temperature.get(),
-                rain_intensity: self.environment.rain_intensity.get()
-            },
-            player_status: PlayerStatusContract {
-                is_walking: self.player_state.is_walking.get(),
-                is_running: self.player_state.is_running.get(),
-                is_swimming: self.player_state.is_swimming.get(),
-                is_underwater: self.player_state.is_underwater.get()
-            },
+            version: STATE_CONTRACT_VERSION,
+            snapshot_id,
+            environment: self.capture_environment(),
+            player_status: self.capture_player_status(),
             body: self.body.get_state(),
             health: self.health.get_state(),
             inventory: self.inventory.get_state(),
+            skills: self.skills.get_state(),
+            stats: self.stats.get_state(),
 
             update_counter: self.update_counter.get(),
             queue_counter: self.queue_counter.get(),
@@ -252,6 +643,79 @@ impl<E: Listener + 'static> ZaraController<E> {
         }
     }
 
+    fn capture_environment(&self) -> EnvironmentStateContract {
+        EnvironmentStateContract {
+            game_time: self.environment.game_time.duration.get(),
+            wind_speed: self.environment.wind_speed.get(),
+            temperature: self.environment.temperature.get(),
+            rain_intensity: self.environment.rain_intensity.get(),
+            humidity: self.environment.humidity.get()
+        }
+    }
+
+    fn capture_player_status(&self) -> PlayerStatusContract {
+        PlayerStatusContract {
+            is_walking: self.player_state.is_walking.get(),
+            is_running: self.player_state.is_running.get(),
+            is_swimming: self.player_state.is_swimming.get(),
+            is_underwater: self.player_state.is_underwater.get(),
+            is_using_arms: self.player_state.is_using_arms.get(),
+            busy_hands: self.player_state.busy_hands.get(),
+            is_inside_shelter: self.player_state.is_inside_shelter.get(),
+            shelter_quality: self.player_state.shelter_quality.get(),
+            is_near_heat_source: self.player_state.is_near_heat_source.get(),
+            are_hands_bloodied: self.player_state.hands_bloodied.get()
+        }
+    }
+
+    /// Returns nodes changed since the last snapshot and clears their dirty flags
+    fn take_dirty_nodes(&self) -> DirtyNodesC {
+        // The game sets environment and player status fields directly, so their values are
+        // compared with the ones of the last snapshot
+        let environment = self.capture_environment();
+        let player_status = self.capture_player_status();
+        let is_environment_changed = self.snapshot_environment.replace(environment.clone()) != environment;
+        let is_player_status_changed = self.snapshot_player_status.replace(player_status.clone()) != player_status;
+
+        DirtyNodesC {
+            environment: self.environment.take_dirty() || is_environment_changed,
+            player_status: self.player_state.take_dirty() || is_player_status_changed,
+            body: self.body.take_dirty(),
+            health: self.health.take_dirty(),
+            inventory: self.inventory.take_dirty(),
+            skills: self.skills.take_dirty(),
+            stats: self.stats.take_dirty()
+        }
+    }
+
+    fn next_snapshot_id(&self) -> usize {
+        self.last_snapshot_id.set(self.last_snapshot_id.get() + 1);
+        self.last_snapshot_id.get()
+    }
+
+    fn remember_snapshot(&self, snapshot_id: usize, dirty: DirtyNodesC) {
+        let mut b = self.state_snapshots.borrow_mut();
+
+        b.insert(snapshot_id, dirty);
+
+        while b.len() > MAX_STATE_SNAPSHOTS {
+            let oldest = match b.keys().next() {
+                Some(k) => *k,
+                None => break
+            };
+
+            b.remove(&oldest);
+        }
+    }
+
+    /// Remembers restored or applied snapshot, current state matches it now
+    fn remember_applied_snapshot(&self, snapshot_id: usize, dirty: DirtyNodesC) {
+        self.take_dirty_nodes();
+        self.remember_snapshot(snapshot_id, dirty);
+        self.last_snapshot_id.set(usize::max(self.last_snapshot_id.get(), snapshot_id));
+        self.applied_snapshot_id.set(Some(snapshot_id));
+    }
+
     /// Restores previously captured state. This will **not** restore active diseases, injuries,
     /// disease/inventory/side effects monitors or inventory items.
     ///
@@ -274,8 +738,10 @@ impl<E: Listener + 'static> ZaraController<E> {
         self.last_update_game_time.set(state.last_update_game_time);
         self.last_frame_game_time.set(state.last_frame_game_time);
         self.is_paused.set(state.is_paused);
+        self.death_time.set(None);
 
         self.environment.rain_intensity.set(state.environment.rain_intensity);
+        self.environment.humidity.set(state.environment.humidity);
         self.environment.temperature.set(state.environment.temperature);
         self.environment.wind_speed.set(state.environment.wind_speed);
         self.environment.game_time.update_from_duration(state.environment.game_time);
@@ -284,9 +750,259 @@ impl<E: Listener + 'static> ZaraController<E> {
         self.player_state.is_running.set(state.player_status.is_running);
         self.player_state.is_swimming.set(state.player_status.is_swimming);
         self.player_state.is_underwater.set(state.player_status.is_underwater);
+        self.player_state.is_using_arms.set(state.player_status.is_using_arms);
+        self.player_state.busy_hands.set(state.player_status.busy_hands);
+        self.player_state.is_inside_shelter.set(state.player_status.is_inside_shelter);
+        self.player_state.set_shelter_quality(state.player_status.shelter_quality);
+        self.player_state.is_near_heat_source.set(state.player_status.is_near_heat_source);
+        self.player_state.hands_bloodied.set(state.player_status.are_hands_bloodied);
 
         self.body.restore_state(&state.body);
         self.health.restore_state(&state.health);
-        self.inventory.restore_state(&state.inventory)
+        self.inventory.restore_state(&state.inventory);
+        self.skills.restore_state(&state.skills);
+        self.stats.restore_state(&state.stats);
+        self.refresh_profile();
+        self.reset_day_summary();
+
+        // Snapshots taken before describe another state
+        self.state_snapshots.borrow_mut().clear();
+        self.remember_applied_snapshot(state.snapshot_id, DirtyNodesC::default());
+    }
+
+    /// Applies previously captured delta on top of the current state. Only nodes present in
+    /// the delta will be restored.
+    ///
+    /// Base snapshot (full state or a previous delta) must be restored first, deltas must be
+    /// applied in the same order they were captured.
+    ///
+    /// # Parameters
+    /// - `delta`: delta contract captured by `get_state_since`
+    ///
+    /// # Returns
+    /// Ok on success. `BaseSnapshotMismatch` error when `delta.base_snapshot_id` is not the id of
+    /// the snapshot restored or applied last; nothing is changed then
+    ///
+    /// # Examples
+    /// ```
+    /// person.restore_state(&full);
+    /// person.apply_delta(&delta)?; // delta.base_snapshot_id == full.snapshot_id
+    /// person.apply_delta(&next_delta)?; // next_delta.base_snapshot_id == delta.snapshot_id
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management) for more info.
+    pub fn apply_delta(&self, delta: &ZaraControllerStateDeltaContract) -> Result<(), StateDeltaErr> {
+        if self.applied_snapshot_id.get() != Some(delta.base_snapshot_id) {
+            return Err(StateDeltaErr::BaseSnapshotMismatch);
+        }
+
+        self.update_counter.set(delta.update_counter);
+        self.queue_counter.set(delta.queue_counter);
+        self.last_update_game_time.set(delta.last_update_game_time);
+        self.last_frame_game_time.set(delta.last_frame_game_time);
+        self.is_paused.set(delta.is_paused);
+
+        if let Some(environment) = &delta.environment {
+            self.environment.rain_intensity.set(environment.rain_intensity);
+            self.environment.humidity.set(environment.humidity);
+            self.environment.temperature.set(environment.temperature);
+            self.environment.wind_speed.set(environment.wind_speed);
+            self.environment.game_time.update_from_duration(environment.game_time);
+            self.reset_day_summary();
+        }
+        if let Some(player_status) = &delta.player_status {
+            self.player_state.is_walking.set(player_status.is_walking);
+            self.player_state.is_running.set(player_status.is_running);
+            self.player_state.is_swimming.set(player_status.is_swimming);
+            self.player_state.is_underwater.set(player_status.is_underwater);
+            self.player_state.is_using_arms.set(player_status.is_using_arms);
+            self.player_state.busy_hands.set(player_status.busy_hands);
+            self.player_state.is_inside_shelter.set(player_status.is_inside_shelter);
+            self.player_state.set_shelter_quality(player_status.shelter_quality);
+            self.player_state.is_near_heat_source.set(player_status.is_near_heat_source);
+            self.player_state.hands_bloodied.set(player_status.are_hands_bloodied);
+        }
+        if let Some(body) = &delta.body {
+            self.body.restore_state(body);
+        }
+        if let Some(health) = &delta.health {
+            self.health.restore_state(health);
+        }
+        if let Some(inventory) = &delta.inventory {
+            self.inventory.restore_state(inventory);
+        }
+        if let Some(skills) = &delta.skills {
+            self.skills.restore_state(skills);
+            self.refresh_profile();
+        }
+        if let Some(stats) = &delta.stats {
+            self.stats.restore_state(stats);
+        }
+
+        self.remember_applied_snapshot(delta.snapshot_id, DirtyNodesC {
+            environment: delta.environment.is_some(),
+            player_status: delta.player_status.is_some(),
+            body: delta.body.is_some(),
+            health: delta.health.is_some(),
+            inventory: delta.inventory.is_some(),
+            skills: delta.skills.is_some(),
+            stats: delta.stats.is_some()
+        });
+
+        Ok(())
+    }
+
+    /// Ids of the registered disease and injury kinds (see `health.register_disease_factories`
+    /// and `health.register_injury_factories`) and of the inventory items by their display names,
+    /// for the kinds whose ids differ from their names. Is used to migrate states of the versions
+    /// that keyed them by names with a
+    /// [`KeyByIdMigrationStep`](crate::state::migration::KeyByIdMigrationStep)
+    ///
+    /// # Examples
+    /// ```
+    /// let mut ids = person.ids_by_names();
+    ///
+    /// // Kinds the character does not have anymore
+    /// ids.insert(format!("Old Bandage"), format!("bandage"));
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management) for more info.
+    ///
+    /// ## Notes
+    /// Borrows `inventory.items` collection
+    pub fn ids_by_names(&self) -> BTreeMap<String, String> {
+        let mut ids = self.health.kind_ids_by_names();
+
+        for (id, item) in self.inventory.stored_items.borrow().iter() {
+            if id != &item.get_name() { ids.insert(item.get_name(), id.to_string()); }
+        }
+
+        ids
+    }
+
+    /// Captures everything in one contract: same state as [`get_state`] does, plus states of all
+    /// active diseases and injuries and counts of the inventory items.
+    ///
+    /// [`get_state`]: #method.get_state
+    ///
+    /// # Examples
+    /// ```
+    /// let state = person.get_full_state();
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management) for more info.
+    ///
+    /// ## Notes
+    /// Borrows `health.diseases`, `health.injuries` and `inventory.items` collections
+    pub fn get_full_state(&self) -> ZaraStateContract {
+        let mut diseases: Vec<DiseaseEntryStateContract> = self.health.active_diseases.borrow().iter()
+            .map(|(id, d)| DiseaseEntryStateContract {
+                disease_id: id.to_string(),
+                state: d.get_state()
+            }).collect();
+        let mut injuries: Vec<InjuryEntryStateContract> = self.health.active_injuries.borrow().iter()
+            .map(|(key, i)| InjuryEntryStateContract {
+                injury_id: key.injury.to_string(),
+                state: i.get_state()
+            }).collect();
+        let mut item_counts: Vec<ItemCountStateContract> = self.inventory.stored_items.borrow().iter()
+            .map(|(name, item)| ItemCountStateContract {
+                item_name: name.to_string(),
+                count: item.get_count()
+            }).collect();
+
+        diseases.sort_by(|a, b| a.disease_id.cmp(&b.disease_id));
+        injuries.sort_by(|a, b| a.injury_id.cmp(&b.injury_id).then(a.state.body_part.cmp(&b.state.body_part)));
+        item_counts.sort_by(|a, b| a.item_name.cmp(&b.item_name));
+
+        ZaraStateContract {
+            controller: self.get_state(),
+            diseases,
+            injuries,
+            item_counts
+        }
+    }
+
+    /// Restores everything captured by [`get_full_state`]. Current diseases and injuries are
+    /// replaced with the captured ones, which are recreated by the factories registered with
+    /// `health.register_disease_factories` and `health.register_injury_factories`. Counts are
+    /// restored for the items that are already in the inventory; items are not created. Items that
+    /// were not captured are removed from the inventory. No removal events are sent
+    ///
+    /// [`get_full_state`]: #method.get_full_state
+    ///
+    /// # Parameters
+    /// - `state`: full state captured by `get_full_state`
+    ///
+    /// # Returns
+    /// `Ok` when everything was restored. On error, everything that could be restored still is
+    ///
+    /// # Examples
+    /// ```
+    /// zara::content::register(&person);
+    ///
+    /// person.restore_full_state(&state);
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management) for more info.
+    ///
+    /// ## Notes
+    /// Borrows `health.diseases`, `health.injuries` and `inventory.items` collections
+    pub fn restore_full_state(&self, state: &ZaraStateContract) -> Result<(), FullStateRestoreErr> {
+        let mut missing_kinds = Vec::new();
+        let mut missing_items = Vec::new();
+
+        // Cleared first, because clearing also drops latent infections and procedures
+        // that the controller state restores. No removal events, the state is replaced as a whole
+        self.health.reset_diseases();
+        self.health.reset_injuries();
+        self.restore_state(&state.controller);
+
+        for entry in state.diseases.iter() {
+            match self.health.create_disease(&entry.disease_id) {
+                Some(disease) => self.health.restore_disease(&entry.state, disease),
+                None => missing_kinds.push(entry.disease_id.to_string())
+            }
+        }
+        for entry in state.injuries.iter() {
+            match self.health.create_injury(&entry.injury_id) {
+                Some(injury) => self.health.restore_injury(&entry.state, injury),
+                None => missing_kinds.push(entry.injury_id.to_string())
+            }
+        }
+        let mut removed_items = Vec::new();
+        {
+            let mut items = self.inventory.stored_items.borrow_mut();
+
+            // Items that were not in the inventory when the state was captured
+            items.retain(|name, _| {
+                let is_captured = state.item_counts.iter().any(|c| &c.item_name == name);
+
+                if !is_captured { removed_items.push(name.to_string()); }
+
+                is_captured
+            });
+
+            for entry in state.item_counts.iter() {
+                match items.get_mut(&entry.item_name) {
+                    Some(item) => item.set_count(entry.count),
+                    None => missing_items.push(entry.item_name.to_string())
+                }
+            }
+        }
+
+        removed_items.iter().for_each(|name| self.inventory.forget_item(name));
+        self.inventory.recalculate_weight();
+
+        if missing_kinds.is_empty() && missing_items.is_empty() { return Ok(()); }
+
+        missing_kinds.sort();
+        missing_kinds.dedup();
+
+        Err(FullStateRestoreErr::PartiallyRestored(missing_kinds, missing_items))
     }
 }
\ No newline at end of file
@@ -0,0 +1,357 @@
+use crate::error::MigrationErr;
+use crate::state::{ZaraControllerStateContract, ZaraStateContract};
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// How a state contract change of Zara is migrated
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum ContractChange {
+    /// Fields were added or existing fields are filled differently. [`StateMigrator`] registers
+    /// a [`PassThroughMigrationStep`] for it
+    PassThrough,
+    /// Entries are keyed by ids instead of display names. Only the game knows ids of its kinds,
+    /// so it registers a [`KeyByIdMigrationStep`] for it
+    KeyedByIds
+}
+
+/// Zara's own state contract changes, oldest first. Change at index `i` turns states of version
+/// `i + 1` into states of version `i + 2`, so every new entry increases
+/// [`STATE_CONTRACT_VERSION`](crate::state::STATE_CONTRACT_VERSION)
+pub(crate) const CONTRACT_CHANGES: &[ContractChange] = &[
+    ContractChange::PassThrough, // `skills` node
+    ContractChange::PassThrough, // `coverage` of clothes
+    ContractChange::PassThrough, // statuses of lost and prosthetic body parts
+    ContractChange::PassThrough, // body part temperatures
+    ContractChange::PassThrough, // `heat_stress` of the body
+    ContractChange::PassThrough, // custom `activation_keyframes` of medical agents
+    ContractChange::PassThrough, // oxygen and blood drains of disease stages
+    ContractChange::PassThrough, // vitals and fatigue targets of injury stages
+    ContractChange::PassThrough, // localized injury stamina drains and `is_using_arms`
+    ContractChange::PassThrough, // `bleeding_multipliers` of injuries
+    ContractChange::PassThrough, // applied tourniquets
+    ContractChange::PassThrough, // `busy_hands` of the player
+    ContractChange::PassThrough, // `is_inside_shelter` and `is_near_heat_source` of the player
+    ContractChange::PassThrough, // `stats` node
+    ContractChange::KeyedByIds, // diseases, injuries and items are keyed by ids instead of display names
+    ContractChange::PassThrough, // item cleanliness and `are_hands_bloodied`
+    ContractChange::PassThrough, // body `hygiene_level`
+    ContractChange::PassThrough, // `consumed_heat` of consumables
+    ContractChange::PassThrough, // digesting meals
+    ContractChange::PassThrough, // nausea level and recent meals
+    ContractChange::PassThrough, // `appetite_loss` of disease stages and last signaled hunger and thirst urgencies
+    ContractChange::PassThrough, // stimulants, `fatigue_mask` and `fatigue_debt`
+    ContractChange::PassThrough, // item reservations
+    ContractChange::PassThrough, // wet clothes and absorbed `water_weight`
+    ContractChange::PassThrough, // equipped items and `equipped_weight_factor`
+    ContractChange::PassThrough, // side effects monitor states
+    ContractChange::PassThrough, // built-in side effects monitors fill their states
+    ContractChange::PassThrough, // burn injuries and burn dressings
+    ContractChange::PassThrough, // latent infections
+    ContractChange::PassThrough, // tracked item ages
+    ContractChange::PassThrough, // item contamination
+    ContractChange::PassThrough, // `side_effects` of medical agents
+    ContractChange::PassThrough, // scheduled disease chain inverts
+    ContractChange::PassThrough, // `is_healing_paused` of diseases and injuries
+    ContractChange::PassThrough, // active procedures
+    ContractChange::PassThrough, // full state contract
+    ContractChange::PassThrough, // active light sources and their fuel burn
+    ContractChange::PassThrough, // active electronics and item charges
+    ContractChange::PassThrough, // `shelter_quality` of the player
+    ContractChange::PassThrough, // humidity
+    ContractChange::PassThrough, // cold and heat exposure with hypothermia and hyperthermia stages
+    ContractChange::PassThrough, // `worst_level_reached` of diseases and injuries
+    ContractChange::PassThrough, // nutrient levels, deficiencies and deficiency diseases
+    ContractChange::PassThrough // toxicity level and stage
+];
+
+/// Trait for describing a single state migration step. Step converts serialized state of
+/// version `source_version` into serialized state of version `source_version + 1`.
+///
+/// # Links
+/// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management) for more info.
+pub trait StateMigrationStep {
+    /// Version of the serialized state this step accepts
+    fn source_version(&self) -> u32;
+    /// Converts serialized state of version `source_version` to the version `source_version + 1`
+    ///
+    /// # Parameters
+    /// - `data`: serialized state of version `source_version`
+    fn migrate(&self, data: &[u8]) -> Result<Vec<u8>, MigrationErr>;
+}
+
+/// Migration step that passes serialized state through as is. Zara registers it for its own
+/// contract changes, so the [`StateFormat`] is expected to decode fields missing in older states
+/// with their defaults. Register a step with the same source version to replace it
+#[derive(Copy, Clone, Debug)]
+pub struct PassThroughMigrationStep {
+    /// Version of the serialized state this step accepts
+    pub source_version: u32
+}
+impl fmt::Display for PassThroughMigrationStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Pass-through migration from v{}", self.source_version)
+    }
+}
+impl StateMigrationStep for PassThroughMigrationStep {
+    fn source_version(&self) -> u32 { self.source_version }
+    fn migrate(&self, data: &[u8]) -> Result<Vec<u8>, MigrationErr> { Ok(data.to_vec()) }
+}
+
+/// Trait for the state contracts that hold entries keyed by display names of the diseases,
+/// injuries and items. Is used by [`KeyByIdMigrationStep`]
+pub trait NameKeyedState {
+    /// Replaces display names of the diseases, injuries and items with their ids
+    ///
+    /// # Parameters
+    /// - `ids`: ids by display names. Names that are not listed are kept as is
+    fn key_by_ids(&mut self, ids: &BTreeMap<String, String>);
+}
+impl NameKeyedState for ZaraControllerStateContract {
+    fn key_by_ids(&mut self, ids: &BTreeMap<String, String>) {
+        let key = |name: &mut String| if let Some(id) = ids.get(name) { *name = id.to_string(); };
+
+        self.body.clothes.iter_mut().for_each(&key);
+        self.body.appliances.iter_mut().for_each(|a| key(&mut a.item_name));
+        self.body.clothes_data.iter_mut().for_each(|c| key(&mut c.key));
+        self.body.tourniquets.iter_mut().for_each(|t| key(&mut t.item_name));
+        self.body.burn_dressings.iter_mut().for_each(|d| key(&mut d.item_name));
+
+        self.health.digestion.iter_mut().for_each(|m| key(&mut m.item_name));
+        self.health.recent_meals.iter_mut().for_each(|m| key(&mut m.item_name));
+        for infection in self.health.latent_infections.iter_mut() {
+            key(&mut infection.disease_name);
+            key(&mut infection.injury_name);
+            infection.prophylaxis.iter_mut().for_each(&key);
+        }
+        self.health.active_procedures.iter_mut().for_each(|p| key(&mut p.injury_name));
+        for agent in self.health.medical_agents.agents.iter_mut() {
+            agent.group.items.iter_mut().for_each(&key);
+            agent.doses.iter_mut().for_each(|d| key(&mut d.item));
+        }
+
+        self.inventory.clothes_cache.iter_mut().for_each(&key);
+        self.inventory.equipment_cache.iter_mut().for_each(&key);
+        self.inventory.cleanliness.iter_mut().for_each(|c| key(&mut c.item_name));
+        self.inventory.reservations.iter_mut().for_each(|r| key(&mut r.item_name));
+        self.inventory.wet_clothes.iter_mut().for_each(|w| key(&mut w.item_name));
+        self.inventory.item_ages.iter_mut().for_each(|a| key(&mut a.item_name));
+        self.inventory.contamination.iter_mut().for_each(|c| key(&mut c.item_name));
+        self.inventory.active_lights.iter_mut().for_each(&key);
+        self.inventory.light_burn.iter_mut().for_each(|l| key(&mut l.item_name));
+        self.inventory.active_electronics.iter_mut().for_each(&key);
+        self.inventory.charges.iter_mut().for_each(|c| key(&mut c.item_name));
+
+        self.stats.items_consumed.iter_mut().for_each(|i| key(&mut i.item_name));
+    }
+}
+impl NameKeyedState for ZaraStateContract {
+    fn key_by_ids(&mut self, ids: &BTreeMap<String, String>) {
+        let key = |name: &mut String| if let Some(id) = ids.get(name) { *name = id.to_string(); };
+
+        self.controller.key_by_ids(ids);
+        self.diseases.iter_mut().for_each(|d| key(&mut d.disease_id));
+        self.injuries.iter_mut().for_each(|i| key(&mut i.injury_id));
+        self.item_counts.iter_mut().for_each(|c| key(&mut c.item_name));
+    }
+}
+
+/// Migration step for the contract change that keyed diseases, injuries and items by their ids
+/// instead of display names. Decodes the state with the [`StateFormat`], replaces display names
+/// with ids and encodes it back, so the format must implement [`encode`](StateFormat::encode).
+/// States older than this change cannot be migrated until the step is registered
+///
+/// # Examples
+/// ```
+/// use zara::state::migration::{StateMigrator, KeyByIdMigrationStep};
+///
+/// let migrator = StateMigrator::new(zara::state::STATE_CONTRACT_VERSION, Box::new(MyFormat));
+///
+/// migrator.register_step(Box::new(KeyByIdMigrationStep::new(Box::new(MyFormat), person.ids_by_names())));
+/// ```
+///
+/// # Links
+/// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management) for more info.
+pub struct KeyByIdMigrationStep<T> {
+    /// Serialization format description
+    format: Box<dyn StateFormat<T>>,
+    /// Ids by display names
+    ids: BTreeMap<String, String>
+}
+impl<T> fmt::Display for KeyByIdMigrationStep<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Key by id migration from v{} ({} ids)", self.source_version_of_change(), self.ids.len())
+    }
+}
+impl<T> KeyByIdMigrationStep<T> {
+    /// Creates new key by id migration step
+    ///
+    /// # Parameters
+    /// - `format`: serialization format description, that can also encode states
+    /// - `ids`: ids of the diseases, injuries and items by their display names. Use
+    ///   [`ids_by_names`](crate::ZaraController::ids_by_names) and add the kinds the character
+    ///   does not have anymore
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::state::migration::KeyByIdMigrationStep;
+    ///
+    /// let step = KeyByIdMigrationStep::new(Box::new(MyFormat), person.ids_by_names());
+    /// ```
+    pub fn new(format: Box<dyn StateFormat<T>>, ids: BTreeMap<String, String>) -> Self {
+        KeyByIdMigrationStep { format, ids }
+    }
+
+    fn source_version_of_change(&self) -> u32 {
+        CONTRACT_CHANGES.iter().position(|c| *c == ContractChange::KeyedByIds).map_or(0, |i| i as u32 + 1)
+    }
+}
+impl<T: NameKeyedState> StateMigrationStep for KeyByIdMigrationStep<T> {
+    fn source_version(&self) -> u32 { self.source_version_of_change() }
+    fn migrate(&self, data: &[u8]) -> Result<Vec<u8>, MigrationErr> {
+        let mut state = self.format.decode(data)?;
+
+        state.key_by_ids(&self.ids);
+
+        self.format.encode(&state)
+    }
+}
+
+/// Trait for describing the serialization format used by the game to store states.
+/// Zara does not serialize anything by itself.
+///
+/// # Links
+/// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management) for more info.
+pub trait StateFormat<T> {
+    /// Reads schema version of the serialized state
+    ///
+    /// # Parameters
+    /// - `data`: serialized state
+    fn read_version(&self, data: &[u8]) -> Result<u32, MigrationErr>;
+    /// Deserializes state of the current schema version into the contract
+    ///
+    /// # Parameters
+    /// - `data`: serialized state of the current schema version
+    fn decode(&self, data: &[u8]) -> Result<T, MigrationErr>;
+    /// Serializes state contract with the current schema version. Is needed only by the steps
+    /// that change state contents, like [`KeyByIdMigrationStep`]
+    ///
+    /// # Parameters
+    /// - `state`: state contract to serialize
+    fn encode(&self, _state: &T) -> Result<Vec<u8>, MigrationErr> { Err(MigrationErr::EncodeNotSupported) }
+}
+
+/// Migrates serialized states of older schema versions to the current one using registered
+/// migration steps. Steps for Zara's own contract changes are registered on creation.
+///
+/// # Examples
+/// ```
+/// use zara::state::migration::StateMigrator;
+///
+/// let migrator = StateMigrator::new(zara::state::STATE_CONTRACT_VERSION, Box::new(MyFormat));
+///
+/// migrator.register_step(Box::new(MyFromV1ToV2));
+///
+/// let state = migrator.migrate(&old_version_bytes);
+/// ```
+///
+/// # Links
+/// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/State-Management) for more info.
+pub struct StateMigrator<T> {
+    /// Version all states will be migrated to
+    target_version: u32,
+    /// Serialization format description
+    format: Box<dyn StateFormat<T>>,
+    /// Registered migration steps (by source version)
+    steps: RefCell<BTreeMap<u32, Box<dyn StateMigrationStep>>>
+}
+impl<T> fmt::Display for StateMigrator<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "State migrator to v{} ({} steps)", self.target_version, self.steps.borrow().len())
+    }
+}
+
+impl<T> StateMigrator<T> {
+    /// Creates new state migrator with a [`PassThroughMigrationStep`] registered for each of
+    /// Zara's own contract changes that keep the serialized data as is. States from before the
+    /// change that keyed entries by ids also need a [`KeyByIdMigrationStep`]
+    ///
+    /// # Parameters
+    /// - `target_version`: version all states will be migrated to. Usually it is
+    ///   [`STATE_CONTRACT_VERSION`](crate::state::STATE_CONTRACT_VERSION)
+    /// - `format`: serialization format description
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::state::migration::StateMigrator;
+    ///
+    /// let migrator = StateMigrator::new(zara::state::STATE_CONTRACT_VERSION, Box::new(MyFormat));
+    /// ```
+    pub fn new(target_version: u32, format: Box<dyn StateFormat<T>>) -> Self {
+        let mut steps: BTreeMap<u32, Box<dyn StateMigrationStep>> = BTreeMap::new();
+
+        for (index, change) in CONTRACT_CHANGES.iter().enumerate() {
+            let source_version = index as u32 + 1;
+
+            if *change == ContractChange::PassThrough {
+                steps.insert(source_version, Box::new(PassThroughMigrationStep { source_version }));
+            }
+        }
+
+        StateMigrator {
+            target_version,
+            format,
+            steps: RefCell::new(steps)
+        }
+    }
+
+    /// Registers a migration step. Step with the same source version will be replaced.
+    ///
+    /// # Parameters
+    /// - `step`: an object that implements
+    ///   [`StateMigrationStep`](crate::state::migration::StateMigrationStep) trait
+    ///
+    /// # Examples
+    /// ```
+    /// migrator.register_step(Box::new(MyFromV1ToV2));
+    /// ```
+    pub fn register_step(&self, step: Box<dyn StateMigrationStep>) {
+        self.steps.borrow_mut().insert(step.source_version(), step);
+    }
+
+    /// Migrates serialized state of any older version to the target version and decodes it
+    ///
+    /// # Parameters
+    /// - `old_version_bytes`: serialized state of any supported version
+    ///
+    /// # Returns
+    /// Ok with the decoded state contract on success
+    ///
+    /// # Examples
+    /// ```
+    /// let state = migrator.migrate(&old_version_bytes);
+    /// ```
+    pub fn migrate(&self, old_version_bytes: &[u8]) -> Result<T, MigrationErr> {
+        let mut version = self.format.read_version(old_version_bytes)?;
+
+        if version > self.target_version {
+            return Err(MigrationErr::VersionNotSupported(version));
+        }
+
+        let mut data = old_version_bytes.to_vec();
+        let steps = self.steps.borrow();
+
+        while version < self.target_version {
+            let step = match steps.get(&version) {
+                Some(s) => s,
+                None => return Err(MigrationErr::StepNotFound(version))
+            };
+
+            data = step.migrate(&data)?;
+            version += 1;
+        }
+
+        self.format.decode(&data)
+    }
+}
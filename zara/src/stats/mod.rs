@@ -0,0 +1,176 @@
+use crate::utils::FrameSummaryC;
+use crate::utils::event::Event;
+use crate::health::RemovalReason;
+use crate::state::DirtyTracked;
+
+use std::collections::BTreeMap;
+use std::cell::{Cell, RefCell};
+
+pub(crate) mod state;
+
+/// Blood, oxygen, food or water level below which character is considered nearly dead
+const NEAR_DEATH_THRESHOLD: f32 = 10.;
+/// All of blood, oxygen, food and water levels must be above this to count the next near death
+const NEAR_DEATH_RECOVERY_THRESHOLD: f32 = 20.;
+
+/// Statistics node. Accumulates lifetime counters of a character (for achievements and
+/// end-game screens)
+pub struct Stats {
+    /// Consumed items count by item name
+    items_consumed: RefCell<BTreeMap<String, usize>>,
+    /// How many diseases ended while character was alive
+    diseases_survived: Cell<usize>,
+    /// How many injuries ended while character was alive
+    injuries_survived: Cell<usize>,
+    /// Total game hours slept
+    hours_slept: Cell<f32>,
+    /// Total distance reported by the game, meters
+    meters_walked: Cell<f32>,
+    /// How many times character was nearly dead
+    near_deaths: Cell<usize>,
+    /// Is character nearly dead now
+    is_near_death: Cell<bool>,
+    /// Is node changed since the last state snapshot
+    is_dirty: Cell<bool>
+}
+
+impl Stats {
+    pub(crate) fn new() -> Self {
+        Stats {
+            items_consumed: RefCell::new(BTreeMap::new()),
+            diseases_survived: Cell::new(0),
+            injuries_survived: Cell::new(0),
+            hours_slept: Cell::new(0.),
+            meters_walked: Cell::new(0.),
+            near_deaths: Cell::new(0),
+            is_near_death: Cell::new(false),
+            is_dirty: Cell::new(false)
+        }
+    }
+
+    /// Returns consumed items count by item name
+    ///
+    /// # Examples
+    /// ```
+    /// for (name, count) in person.stats.items_consumed() {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items_consumed` collection
+    pub fn items_consumed(&self) -> BTreeMap<String, usize> { self.items_consumed.borrow().clone() }
+
+    /// Returns how many items of a given kind were consumed
+    ///
+    /// # Parameters
+    /// - `item_name`: unique name of the item kind
+    ///
+    /// # Examples
+    /// ```
+    /// let count = person.stats.consumed_count_of(&item_name);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `items_consumed` collection
+    pub fn consumed_count_of(&self, item_name: &String) -> usize {
+        self.items_consumed.borrow().get(item_name).cloned().unwrap_or(0)
+    }
+
+    /// Returns how many diseases ended (expired or removed) while character was alive
+    ///
+    /// # Examples
+    /// ```
+    /// let count = person.stats.diseases_survived();
+    /// ```
+    pub fn diseases_survived(&self) -> usize { self.diseases_survived.get() }
+
+    /// Returns how many injuries ended (expired or removed) while character was alive
+    ///
+    /// # Examples
+    /// ```
+    /// let count = person.stats.injuries_survived();
+    /// ```
+    pub fn injuries_survived(&self) -> usize { self.injuries_survived.get() }
+
+    /// Returns total game hours slept
+    ///
+    /// # Examples
+    /// ```
+    /// let hours = person.stats.hours_slept();
+    /// ```
+    pub fn hours_slept(&self) -> f32 { self.hours_slept.get() }
+
+    /// Returns total distance reported by the game with
+    /// [`report_distance`](#method.report_distance) or `player_state.report_movement`, kilometers
+    ///
+    /// # Examples
+    /// ```
+    /// let km = person.stats.kilometers_walked();
+    /// ```
+    pub fn kilometers_walked(&self) -> f32 { self.meters_walked.get() / 1000. }
+
+    /// Returns how many times blood, oxygen, food or water level dropped to a nearly deadly value
+    ///
+    /// # Examples
+    /// ```
+    /// let count = person.stats.near_deaths();
+    /// ```
+    pub fn near_deaths(&self) -> usize { self.near_deaths.get() }
+
+    /// Adds distance covered by the character. Zara does not know about the character
+    /// position, so distance must be reported by the game
+    ///
+    /// # Parameters
+    /// - `meters`: distance covered since the last call, meters
+    ///
+    /// # Examples
+    /// ```
+    /// person.stats.report_distance(12.5);
+    /// ```
+    pub fn report_distance(&self, meters: f32) {
+        if meters <= 0. { return; }
+
+        self.meters_walked.set(self.meters_walked.get() + meters);
+        self.mark_dirty();
+    }
+
+    /// Accumulates time-based counters
+    pub(crate) fn update(&self, frame_data: &FrameSummaryC) {
+        self.mark_dirty();
+
+        if frame_data.player.is_sleeping {
+            self.hours_slept.set(self.hours_slept.get() + frame_data.game_time_delta / 3600.);
+        }
+
+        self.report_distance(frame_data.player.movement.total());
+
+        let h = &frame_data.health;
+        let lowest = [h.blood_level, h.oxygen_level, h.food_level, h.water_level].iter()
+            .cloned()
+            .fold(f32::MAX, f32::min);
+
+        if !self.is_near_death.get() && lowest < NEAR_DEATH_THRESHOLD {
+            self.is_near_death.set(true);
+            self.near_deaths.set(self.near_deaths.get() + 1);
+        } else if self.is_near_death.get() && lowest > NEAR_DEATH_RECOVERY_THRESHOLD {
+            self.is_near_death.set(false);
+        }
+    }
+
+    /// Accumulates event-based counters
+    pub(crate) fn on_event(&self, event: &Event) {
+        self.mark_dirty();
+
+        match event {
+            Event::ItemConsumed(c) => {
+                *self.items_consumed.borrow_mut().entry(c.name.to_string()).or_insert(0) += c.consumed_count;
+            },
+            Event::DiseaseRemoved(_, RemovalReason::Cured | RemovalReason::Expired, _) =>
+                self.diseases_survived.set(self.diseases_survived.get() + 1),
+            Event::InjuryRemoved(_, _, RemovalReason::Cured | RemovalReason::Expired, _) =>
+                self.injuries_survived.set(self.injuries_survived.get() + 1),
+            _ => { }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,107 @@
+use crate::stats::Stats;
+use crate::state::DirtyTracked;
+
+use std::cell::Cell;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Contains state snapshot for the statistics node
+#[derive(Clone, Debug, Default)]
+pub struct StatsStateContract {
+    /// Schema version this state was captured with
+    pub version: u32,
+    /// Captured state of the `items_consumed` field
+    pub items_consumed: Vec<ItemConsumedStateContract>,
+    /// Captured state of the `diseases_survived` field
+    pub diseases_survived: usize,
+    /// Captured state of the `injuries_survived` field
+    pub injuries_survived: usize,
+    /// Captured state of the `hours_slept` field
+    pub hours_slept: f32,
+    /// Captured state of the `meters_walked` field
+    pub meters_walked: f32,
+    /// Captured state of the `near_deaths` field
+    pub near_deaths: usize,
+    /// Captured state of the `is_near_death` field
+    pub is_near_death: bool
+}
+impl fmt::Display for StatsStateContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Stats state ({} item kinds consumed)", self.items_consumed.len())
+    }
+}
+impl Eq for StatsStateContract { }
+impl PartialEq for StatsStateContract {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        self.version == other.version &&
+        self.items_consumed == other.items_consumed &&
+        self.diseases_survived == other.diseases_survived &&
+        self.injuries_survived == other.injuries_survived &&
+        self.near_deaths == other.near_deaths &&
+        self.is_near_death == other.is_near_death &&
+        f32::abs(self.hours_slept - other.hours_slept) < EPS &&
+        f32::abs(self.meters_walked - other.meters_walked) < EPS
+    }
+}
+impl Hash for StatsStateContract {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.version.hash(state);
+        self.items_consumed.hash(state);
+        self.diseases_survived.hash(state);
+        self.injuries_survived.hash(state);
+        self.near_deaths.hash(state);
+        self.is_near_death.hash(state);
+
+        state.write_u32((self.hours_slept*10_000_f32) as u32);
+        state.write_u32((self.meters_walked*100_f32) as u32);
+    }
+}
+
+/// Contains state snapshot for a consumed item counter
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
+pub struct ItemConsumedStateContract {
+    /// Captured state of the item name key
+    pub item_name: String,
+    /// Captured state of the consumed count
+    pub count: usize
+}
+
+impl DirtyTracked for Stats {
+    fn dirty_flag(&self) -> &Cell<bool> { &self.is_dirty }
+}
+impl Stats {
+    pub(crate) fn get_state(&self) -> StatsStateContract {
+        StatsStateContract {
+            version: crate::state::STATE_CONTRACT_VERSION,
+            items_consumed: self.items_consumed.borrow().iter().map(|(k, x)|
+                ItemConsumedStateContract { item_name: k.to_string(), count: *x }
+            ).collect(),
+            diseases_survived: self.diseases_survived.get(),
+            injuries_survived: self.injuries_survived.get(),
+            hours_slept: self.hours_slept.get(),
+            meters_walked: self.meters_walked.get(),
+            near_deaths: self.near_deaths.get(),
+            is_near_death: self.is_near_death.get()
+        }
+    }
+
+    pub(crate) fn restore_state(&self, state: &StatsStateContract) {
+        self.diseases_survived.set(state.diseases_survived);
+        self.injuries_survived.set(state.injuries_survived);
+        self.hours_slept.set(state.hours_slept);
+        self.meters_walked.set(state.meters_walked);
+        self.near_deaths.set(state.near_deaths);
+        self.is_near_death.set(state.is_near_death);
+        {
+            let mut b = self.items_consumed.borrow_mut();
+
+            b.clear();
+
+            for i in &state.items_consumed {
+                b.insert(i.item_name.to_string(), i.count);
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -1,5 +1,6 @@
 use crate::ZaraController;
 use crate::utils::event::Listener;
+use crate::utils::{GameTimeC, FrameSummaryC};
 
 impl<E: Listener + 'static> ZaraController<E> {
     /// State of this character
@@ -23,4 +24,42 @@ impl<E: Listener + 'static> ZaraController<E> {
     /// # Links
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Pausing-Zara) for more info.
     pub fn is_paused(&self) -> bool{ self.is_paused.get() }
+
+    /// Game time when this character died, `None` while character is alive.
+    ///
+    /// After death controller switches to the read-only post-mortem mode: it is paused, all
+    /// getters, summaries and state export keep working, `update` only delivers remaining events
+    /// and conditions stop advancing. Controller actions (`consume`, `take_appliance`,
+    /// `remove_appliance`, `amputate`, `burn`, `perform_procedure_step`, `try_use_tool`,
+    /// `put_on_clothes`, `take_off_clothes` and `barter`) fail with `CharacterIsDead` error.
+    ///
+    /// ## Notes
+    /// Methods of the nodes (like `inventory` or `body`) are not guarded, game should not call
+    /// them for a dead character
+    /// 
+    /// # Examples
+    /// ```
+    /// if let Some(game_time) = person.death_time() {
+    ///     // Show death screen
+    /// }
+    /// ```
+    /// 
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Declare-dead) for more info.
+    pub fn death_time(&self) -> Option<GameTimeC> {
+        self.death_time.get().map(GameTimeC::from_duration)
+    }
+
+    /// Snapshot of the frame summary Zara used the last time it processed a frame. `None` if
+    /// no frame was processed yet
+    ///
+    /// # Examples
+    /// ```
+    /// if let Some(frame) = person.last_frame_summary() {
+    ///     let heart_rate = frame.health.heart_rate;
+    /// }
+    /// ```
+    pub fn last_frame_summary(&self) -> Option<FrameSummaryC> {
+        self.last_frame_summary.borrow().clone()
+    }
 }
\ No newline at end of file
@@ -0,0 +1,257 @@
+use crate::utils::event::Event;
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Name of the built-in trait that halves food poisoning chance
+pub const IRON_STOMACH: &str = "IronStomach";
+/// Name of the built-in trait that wakes character up on any event
+pub const LIGHT_SLEEPER: &str = "LightSleeper";
+/// Name of the built-in trait that doubles blood drain
+pub const HEMOPHILIA: &str = "Hemophilia";
+
+/// Describes how a character trait changes the simulation
+#[derive(Clone, PartialEq, Debug)]
+pub enum TraitEffect {
+    /// Multiplies fresh and spoiled poisoning chances of the consumed items
+    PoisoningChance(f32),
+    /// Multiplies blood drain caused by injuries
+    BloodDrain(f32),
+    /// Any event that reaches the listener while character is sleeping wakes him up
+    /// (`SleepStarted` and `WokeUp` events excluded)
+    WakeOnEvents,
+    /// Multiplier that is not consulted by Zara and can be read by the game code
+    /// or custom monitors with [`TraitRegistry::custom`](crate::traits::TraitRegistry::custom)
+    Custom(String, f32)
+}
+impl fmt::Display for TraitEffect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Describes a perk or a trait of a character, declared as data. Traits are stored in the
+/// [`TraitRegistry`](crate::traits::TraitRegistry) and are consulted by the relevant subsystems
+#[derive(Clone, PartialEq, Debug)]
+pub struct CharacterTrait {
+    /// Unique name of the trait
+    name: String,
+    /// What this trait does
+    effects: Vec<TraitEffect>
+}
+impl fmt::Display for CharacterTrait {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({} effects)", self.name, self.effects.len())
+    }
+}
+impl CharacterTrait {
+    /// Creates a new trait
+    ///
+    /// # Parameters
+    /// - `name`: unique name of the trait
+    /// - `effects`: how this trait changes the simulation
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::traits::{CharacterTrait, TraitEffect};
+    ///
+    /// let o = CharacterTrait::new("Clumsy", vec![TraitEffect::BloodDrain(1.2)]);
+    /// ```
+    pub fn new(name: &str, effects: Vec<TraitEffect>) -> Self {
+        CharacterTrait {
+            name: name.to_string(),
+            effects
+        }
+    }
+
+    /// `IronStomach` trait: halves poisoning chance of the consumed items
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::traits::CharacterTrait;
+    ///
+    /// let o = CharacterTrait::iron_stomach();
+    /// ```
+    pub fn iron_stomach() -> Self {
+        CharacterTrait::new(IRON_STOMACH, vec![TraitEffect::PoisoningChance(0.5)])
+    }
+
+    /// `LightSleeper` trait: character wakes up on any event
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::traits::CharacterTrait;
+    ///
+    /// let o = CharacterTrait::light_sleeper();
+    /// ```
+    pub fn light_sleeper() -> Self {
+        CharacterTrait::new(LIGHT_SLEEPER, vec![TraitEffect::WakeOnEvents])
+    }
+
+    /// `Hemophilia` trait: doubles blood drain caused by injuries
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::traits::CharacterTrait;
+    ///
+    /// let o = CharacterTrait::hemophilia();
+    /// ```
+    pub fn hemophilia() -> Self {
+        CharacterTrait::new(HEMOPHILIA, vec![TraitEffect::BloodDrain(2.)])
+    }
+
+    /// Returns unique name of this trait
+    pub fn get_name(&self) -> String { self.name.to_string() }
+
+    /// Returns effects of this trait
+    pub fn effects(&self) -> &Vec<TraitEffect> { &self.effects }
+}
+
+/// Central registry of the character traits. Is shared between the controller and the
+/// subsystems that consult it
+pub struct TraitRegistry {
+    /// Registered traits
+    pub traits: RefCell<BTreeMap<String, CharacterTrait>>
+}
+impl fmt::Display for TraitRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Traits registry ({} traits)", self.traits.borrow().len())
+    }
+}
+impl TraitRegistry {
+    pub(crate) fn new() -> Self {
+        TraitRegistry {
+            traits: RefCell::new(BTreeMap::new())
+        }
+    }
+
+    /// Adds traits to the character. Trait with the same name will be replaced
+    ///
+    /// # Parameters
+    /// - `traits`: traits to add
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::traits::CharacterTrait;
+    ///
+    /// person.traits.add_traits(vec![CharacterTrait::iron_stomach(), CharacterTrait::hemophilia()]);
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `traits` collection
+    pub fn add_traits(&self, traits: Vec<CharacterTrait>) {
+        let mut b = self.traits.borrow_mut();
+
+        for t in traits {
+            b.insert(t.get_name(), t);
+        }
+    }
+
+    /// Removes a trait from the character
+    ///
+    /// # Parameters
+    /// - `name`: unique name of the trait
+    ///
+    /// # Returns
+    /// `true` if trait was removed
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::traits;
+    ///
+    /// person.traits.remove_trait(&traits::HEMOPHILIA.to_string());
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `traits` collection
+    pub fn remove_trait(&self, name: &String) -> bool {
+        self.traits.borrow_mut().remove(name).is_some()
+    }
+
+    /// Removes all traits from the character
+    ///
+    /// # Examples
+    /// ```
+    /// person.traits.clear();
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `traits` collection
+    pub fn clear(&self) { self.traits.borrow_mut().clear(); }
+
+    /// Checks if character has a given trait
+    ///
+    /// # Parameters
+    /// - `name`: unique name of the trait
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::traits;
+    ///
+    /// let value = person.traits.has_trait(&traits::IRON_STOMACH.to_string());
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `traits` collection
+    pub fn has_trait(&self, name: &String) -> bool { self.traits.borrow().contains_key(name) }
+
+    /// Returns names of all traits of the character
+    ///
+    /// # Examples
+    /// ```
+    /// let names = person.traits.trait_names();
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `traits` collection
+    pub fn trait_names(&self) -> Vec<String> { self.traits.borrow().keys().cloned().collect() }
+
+    /// Combined multiplier of the food poisoning chance
+    ///
+    /// ## Notes
+    /// Borrows `traits` collection
+    pub fn poisoning_chance_factor(&self) -> f32 {
+        self.product(|e| match e { TraitEffect::PoisoningChance(m) => Some(*m), _ => None })
+    }
+
+    /// Combined multiplier of the injuries blood drain
+    ///
+    /// ## Notes
+    /// Borrows `traits` collection
+    pub fn blood_drain_factor(&self) -> f32 {
+        self.product(|e| match e { TraitEffect::BloodDrain(m) => Some(*m), _ => None })
+    }
+
+    /// Returns combined multiplier of all `Custom` effects with a given key. 1.0 if none
+    ///
+    /// # Parameters
+    /// - `key`: custom effect key
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.traits.custom("NightVision");
+    /// ```
+    ///
+    /// ## Notes
+    /// Borrows `traits` collection
+    pub fn custom(&self, key: &str) -> f32 {
+        self.product(|e| match e { TraitEffect::Custom(k, m) if k == key => Some(*m), _ => None })
+    }
+
+    /// Should character wake up when a given event happens while he is sleeping
+    pub(crate) fn wakes_on(&self, event: &Event) -> bool {
+        match event {
+            Event::SleepStarted(_) | Event::WokeUp => false,
+            _ => self.traits.borrow().values()
+                .any(|t| t.effects.contains(&TraitEffect::WakeOnEvents))
+        }
+    }
+
+    fn product<F: Fn(&TraitEffect) -> Option<f32>>(&self, f: F) -> f32 {
+        self.traits.borrow().values()
+            .flat_map(|t| t.effects.iter())
+            .filter_map(f)
+            .product()
+    }
+}
\ No newline at end of file
@@ -3,6 +3,7 @@ use crate::utils::{FrameC, EnvironmentC, HealthC, FrameSummaryC, PlayerStatusC,
 use crate::utils::event::{Listener, Event, MessageQueue};
 use crate::error::ZaraUpdateErr;
 use crate::health::StageLevel;
+use crate::body::clothes::PERIPHERAL_TEMPERATURE_GRADIENT;
 
 use std::time::Duration;
 use std::collections::BTreeMap;
@@ -35,7 +36,15 @@ impl<E: Listener + 'static> ZaraController<E> {
     /// # Links
     /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Getting-Started#progressing-zara-state) for more info.
     pub fn update(&self, frame_time: f32) -> Result<(), ZaraUpdateErr>{
-        if !self.health.is_alive() { return Err(ZaraUpdateErr::CharacterIsDead); }
+        if !self.health.is_alive() {
+            // Post-mortem mode: conditions are no longer advanced, but events that are still
+            // queued must reach the listener
+            self.enter_post_mortem();
+            self.process_queued_events();
+            self.deliver_events();
+
+            return Err(ZaraUpdateErr::CharacterIsDead);
+        }
         if self.is_paused() { return Err(ZaraUpdateErr::InstancePaused); }
 
         let elapsed = self.update_counter.get() + frame_time;
@@ -47,9 +56,7 @@ impl<E: Listener + 'static> ZaraController<E> {
             self.queue_counter.set(0.);
 
             // Send pending events
-            self.process_health_events();
-            self.process_inventory_events();
-            self.process_body_events();
+            self.process_queued_events();
         } else {
             self.queue_counter.set(elapsed_for_queue);
         }
@@ -67,9 +74,26 @@ impl<E: Listener + 'static> ZaraController<E> {
             );
         }
 
+        let mut processed_frame = None;
+
         if elapsed >= ceiling {
+            // Skill levels may have changed since the last frame
+            self.refresh_profile();
+
+            // Weather simulation changes the environment before the frame reads it
+            let weather_delta = (game_time_duration - self.last_update_game_time.get()).as_secs_f32();
+
+            for event in self.environment.evolve_weather(weather_delta) {
+                self.dispatcher.borrow_mut().dispatch(event);
+            }
+
             // Retrieve the summary for sub-controllers
-            let summary = &self.get_summary();
+            let mut frame_summary = self.get_summary();
+
+            // Host systems can inject data for this frame
+            self.run_before_update_hooks(&mut frame_summary);
+
+            let summary = &frame_summary;
 
             // Form the frame data structure
             let mut frame_data = &mut FrameC {
@@ -81,17 +105,54 @@ impl<E: Listener + 'static> ZaraController<E> {
             self.health.update(&mut frame_data);
             self.inventory.update(&mut frame_data);
             self.body.update(&mut frame_data);
+            // Wet clothes weight follows the body wetness
+            self.inventory.update_wet_clothes(summary.game_time_delta);
+            // Carried food spoils
+            self.inventory.update_item_ages(summary.game_time_delta);
+            // Lights burn their fuel
+            self.inventory.update_lights(summary.game_time_delta);
+            // Electronics drain their charge
+            self.inventory.update_electronics(summary.game_time_delta);
+            // Compass or a working GPS keeps character oriented
+            self.health.update_orientation(self.inventory.navigation_aid());
+            self.inventory.apply_deferred();
+            self.skills.update(summary);
+            self.stats.update(summary);
+            self.player_state.update_exertion_noise(summary);
+
+            // Swimming washes the blood off
+            if summary.player.is_swimming || summary.player.is_underwater {
+                self.player_state.wash_hands();
+            }
+            self.track_day(summary, frame_data.events);
+            self.update_hints();
 
             // Reset the counter and set last update game time
             self.last_update_game_time.set(game_time_duration);
             self.update_counter.set(0.);
+
+            processed_frame = Some(frame_summary);
         } else {
             self.update_counter.set(elapsed);
         }
 
+        // Frame borrows are released, hooks may call back into the controller
+        if let Some(summary) = processed_frame {
+            self.last_frame_summary.replace(Some(summary.clone()));
+            self.run_after_update_hooks(&summary);
+        }
+
         // Set last frame game time
         self.last_frame_game_time.set(Duration::from(game_time_duration));
 
+        if !self.health.is_alive() {
+            // Character died during this frame
+            self.enter_post_mortem();
+            self.process_queued_events();
+        }
+
+        self.deliver_events();
+
         Ok(())
     }
 
@@ -104,10 +165,11 @@ impl<E: Listener + 'static> ZaraController<E> {
         let game_time_contract = &self.environment.game_time.to_contract();
 
         // Collect active diseases data
-        for (_, disease) in self.health.diseases.borrow().iter() {
+        for (_, disease) in self.health.active_diseases.borrow().iter() {
             match disease.get_active_stage(game_time_contract) {
                 Some(st) => {
                     active_diseases.push(ActiveDiseaseC {
+                        id: disease.disease.get_id(),
                         name: disease.disease.get_name(),
                         is_active: true,
                         scheduled_time: disease.activation_time(),
@@ -120,6 +182,7 @@ impl<E: Listener + 'static> ZaraController<E> {
                 },
                 None => {
                     active_diseases.push(ActiveDiseaseC {
+                        id: disease.disease.get_id(),
                         name: disease.disease.get_name(),
                         is_active: false,
                         scheduled_time: disease.activation_time(),
@@ -134,10 +197,11 @@ impl<E: Listener + 'static> ZaraController<E> {
         };
 
         // Collect active injuries data
-        for (_, injury) in self.health.injuries.borrow().iter() {
+        for (_, injury) in self.health.active_injuries.borrow().iter() {
             match injury.get_active_stage(game_time_contract) {
                 Some(st) => {
                     active_injuries.push(ActiveInjuryC {
+                        id: injury.injury.get_id(),
                         name: injury.injury.get_name(),
                         is_active: true,
                         scheduled_time: injury.activation_time(),
@@ -148,11 +212,13 @@ impl<E: Listener + 'static> ZaraController<E> {
                         needs_treatment: injury.needs_treatment,
                         is_blood_stopped: injury.is_blood_stopped(),
                         body_part: injury.body_part,
-                        is_fracture: injury.is_fracture
+                        is_fracture: injury.is_fracture,
+                        burn_area: injury.burn_area()
                     });
                 },
                 None => {
                     active_injuries.push(ActiveInjuryC {
+                        id: injury.injury.get_id(),
                         name: injury.injury.get_name(),
                         is_active: false,
                         scheduled_time: injury.activation_time(),
@@ -163,7 +229,8 @@ impl<E: Listener + 'static> ZaraController<E> {
                         needs_treatment: injury.needs_treatment,
                         is_blood_stopped: injury.is_blood_stopped(),
                         body_part: injury.body_part,
-                        is_fracture: injury.is_fracture
+                        is_fracture: injury.is_fracture,
+                        burn_area: injury.burn_area()
                     });
                 }
             }
@@ -172,11 +239,16 @@ impl<E: Listener + 'static> ZaraController<E> {
         FrameSummaryC {
             game_time: self.environment.game_time.to_contract(),
             game_time_delta: time_delta.as_secs_f32(),
+            profile: self.health.profile(),
+            extensions: self.frame_extensions.borrow().clone(),
             player: PlayerStatusC {
                 is_walking: self.player_state.is_walking.get(),
                 is_running: self.player_state.is_running.get(),
                 is_swimming: self.player_state.is_swimming.get(),
                 is_underwater: self.player_state.is_underwater.get(),
+                is_using_arms: self.player_state.is_using_arms.get(),
+                is_inside_shelter: self.player_state.is_inside_shelter.get(),
+                shelter_quality: self.player_state.shelter_quality.get(),
                 is_sleeping: self.body.is_sleeping(),
                 last_slept_duration: self.body.last_sleep_duration(),
                 last_slept: self.body.last_sleep_time().as_ref().map(|x| x.clone()),
@@ -185,17 +257,26 @@ impl<E: Listener + 'static> ZaraController<E> {
                 clothes: self.body.clothes.borrow().clone(),
                 clothes_group: self.body.clothes_group(),
                 appliances: self.body.appliances.borrow().clone(),
+                tourniquets: self.body.tourniquets(),
+                burn_dressings: self.body.burn_dressings(),
                 total_water_resistance: self.body.total_water_resistance(),
                 total_cold_resistance: self.body.total_cold_resistance(),
-                inventory_weight: self.inventory.get_weight()
+                inventory_weight: self.inventory.get_weight(),
+                mobility: self.body.mobility(),
+                heat_stress: self.body.heat_stress(),
+                hygiene_level: self.body.hygiene_level(),
+                movement: self.player_state.reported_movement.take()
             },
             environment: EnvironmentC {
                 wind_speed: self.environment.wind_speed.get(),
                 rain_intensity: self.environment.rain_intensity.get(),
+                humidity: self.environment.humidity.get(),
                 temperature: self.environment.temperature.get()
             },
             health: HealthC {
                 body_temperature: self.health.body_temperature(),
+                peripheral_temperature: self.body.peripheral_temperature()
+                    .unwrap_or(self.health.body_temperature() - PERIPHERAL_TEMPERATURE_GRADIENT),
                 blood_level: self.health.blood_level(),
                 heart_rate: self.health.heart_rate(),
                 water_level: self.health.water_level(),
@@ -203,8 +284,9 @@ impl<E: Listener + 'static> ZaraController<E> {
                 top_pressure: self.health.top_pressure(),
                 bottom_pressure: self.health.bottom_pressure(),
                 stamina_level: self.health.stamina_level(),
-                fatigue_level: self.health.fatigue_level(),
+                fatigue_level: self.health.true_fatigue_level(),
                 oxygen_level: self.health.oxygen_level(),
+                toxicity_level: self.health.toxicity_level(),
 
                 diseases: active_diseases,
                 injuries: active_injuries
@@ -224,6 +306,65 @@ impl<E: Listener + 'static> ZaraController<E> {
         }
     }
 
+    /// Switches controller to the read-only post-mortem mode: remembers time of death,
+    /// pauses the controller and drops actions that were queued while paused
+    pub(crate) fn enter_post_mortem(&self) {
+        if self.death_time.get().is_some() { return; }
+
+        self.death_time.set(Some(self.environment.game_time.duration.get()));
+        self.is_paused.set(true);
+        self.pending_actions.borrow_mut().clear();
+        self.audio_hints.set(crate::hints::AudioHints::default());
+        self.visual_hints.set(crate::hints::VisualHintsC::default());
+    }
+
+    /// Delivers dispatched events to the listener. Nothing is borrowed at this point, so the
+    /// listener can call back into the controller. Events dispatched by these calls are delivered
+    /// by the same loop, after the current event
+    pub(crate) fn deliver_events(&self) {
+        // Listener called back into the controller; outer loop will deliver new events
+        if self.is_delivering_events.get() { return; }
+
+        self.is_delivering_events.set(true);
+
+        loop {
+            let (events, listeners) = {
+                let mut d = self.dispatcher.borrow_mut();
+
+                (d.take_pending(), d.live_listeners())
+            };
+
+            if events.is_empty() { break; }
+
+            for event in events.iter() {
+                let severity = event.severity();
+
+                for l in listeners.iter() {
+                    let mut l = l.borrow_mut();
+
+                    if severity < l.min_severity() { continue; }
+
+                    l.notify(event);
+                }
+            }
+        }
+
+        self.is_delivering_events.set(false);
+    }
+
+    fn process_queued_events(&self) {
+        self.process_health_events();
+        self.process_inventory_events();
+        self.process_body_events();
+        self.process_skills_events();
+    }
+
+    fn process_skills_events(&self) {
+        if self.skills.has_messages() {
+            self.process_events(self.skills.get_message_queue());
+        }
+    }
+
     fn process_health_events(&self) {
         if self.health.has_messages() {
             self.process_events(self.health.get_message_queue());
@@ -234,13 +375,22 @@ impl<E: Listener + 'static> ZaraController<E> {
         if q.len() == 0 { return }
 
         let mut dispatcher = self.dispatcher.borrow_mut();
+        let game_time = self.environment.game_time.duration.get();
         let mut key = 0;
 
         loop {
             match q.get(&key) {
                 Some(event) => {
+                    self.skills.on_event(event);
+                    self.day_summary_on_event(event);
+                    self.stats.on_event(event);
                     dispatcher.dispatch(event.clone());
 
+                    // Light sleepers wake up on any event
+                    if self.body.is_sleeping() && self.traits.wakes_on(event) {
+                        self.body.wake_up(&mut dispatcher, &game_time);
+                    }
+
                     q.remove(&key);
                 },
                 None => break
@@ -3,6 +3,16 @@
 
 use crate::inventory::items::{ConsumableC, ApplianceC};
 use crate::body::BodyPart;
+use crate::daily::DaySummaryC;
+use crate::health::appetite::Urgency;
+use crate::health::burns::BurnSource;
+use crate::health::{StageLevel, RemovalReason};
+use crate::health::nutrition::Nutrient;
+use crate::health::toxicity::ToxicityStage;
+use crate::inventory::tools::ToolFailureCause;
+use crate::inventory::InventoryChangeReason;
+use crate::utils::EnvironmentC;
+use crate::barter::BarterItemC;
 
 use std::sync::{Arc, Weak};
 use std::cell::{RefCell, RefMut};
@@ -39,117 +49,254 @@ pub enum Event {
     /// When water level is less than 5%
     WaterDrained,
 
+    /// When hunger urgency changes (see `set_hunger_thresholds`)
+    /// # Parameters
+    /// - New hunger urgency
+    HungerUrgencyChanged(Urgency),
+    /// When thirst urgency changes (see `set_thirst_thresholds`)
+    /// # Parameters
+    /// - New thirst urgency
+    ThirstUrgencyChanged(Urgency),
+    /// When cold exposure disease stage changes (see `set_thermal_conditions`)
+    /// # Parameters
+    /// - New stage, `None` when character has recovered
+    HypothermiaStageChanged(Option<StageLevel>),
+    /// When heat exposure disease stage changes (see `set_thermal_conditions`)
+    /// # Parameters
+    /// - New stage, `None` when character has recovered
+    HyperthermiaStageChanged(Option<StageLevel>),
+    /// When nutrient level drops below the deficiency threshold (see `set_nutrition`)
+    /// # Parameters
+    /// - Nutrient character is now deficient in
+    NutrientDeficiency(Nutrient),
+    /// When nutrient level is restored above the recovery threshold (see `set_nutrition`)
+    /// # Parameters
+    /// - Nutrient that is no longer deficient
+    NutrientDeficiencyEnded(Nutrient),
+    /// When toxicity level crosses a threshold (see `set_toxicity_config`)
+    /// # Parameters
+    /// - New toxicity stage
+    ToxicityStageChanged(ToxicityStage),
+
     /// When fatigue level is more than 70%
     Tired,
     /// When fatigue level is more than 90%
     Exhausted,
+    /// When stimulant wears off and hidden fatigue comes back
+    /// # Parameters
+    /// - Medical agent id
+    /// - Extra fatigue added on top of the true fatigue (0..100)
+    StimulantCrashed(String, f32),
 
     /// When medical agent is getting activated
     /// # Parameters
-    /// - Medical agent unique name
+    /// - Medical agent id
     MedicalAgentActivated(String),
     /// When medical agent is getting deactivated
     /// # Parameters
-    /// - Medical agent unique name
+    /// - Medical agent id
     MedicalAgentDeactivated(String),
     /// When medical agent receives a new dose
     /// # Parameters
-    /// - Medical agent unique name
-    /// - Appliance item unique name
+    /// - Medical agent id
+    /// - Appliance item id (`get_id`)
     MedicalAgentDoseReceived(String, String),
 
     /// When body appliance is put on
     /// # Parameters
-    /// - Appliance item unique name
+    /// - Appliance item id (`get_id`)
     /// - Body part
     BodyApplianceOn(String, BodyPart),
     /// When body appliance is taken off
     /// # Parameters
-    /// - Appliance item unique name
+    /// - Appliance item id (`get_id`)
     /// - Body part
     BodyApplianceOff(String, BodyPart),
+    /// When body part is permanently lost
+    /// # Parameters
+    /// - Body part
+    BodyPartLost(BodyPart),
+    /// When tourniquet safe time is about to run out
+    /// # Parameters
+    /// - Tourniquet item id (`get_id`)
+    /// - Body part
+    /// - Game minutes left until tissue damage begins
+    TourniquetTimeRunningOut(String, BodyPart, f32),
+    /// When tourniquet stayed on a limb for longer than its safe time
+    /// # Parameters
+    /// - Tourniquet item id (`get_id`)
+    /// - Body part
+    TourniquetSafeTimeExceeded(String, BodyPart),
     /// When clothes item is put on
     /// # Parameters
-    /// - Clothes item unique name
+    /// - Clothes item id (`get_id`)
     ClothesOn(String),
     /// When clothes item is taken off
     /// # Parameters
-    /// - Clothes item unique name
+    /// - Clothes item id (`get_id`)
     ClothesOff(String),
 
     /// When disease stage death chance is satisfied
     /// # Parameters
-    /// - Disease unique name
+    /// - Disease id (`get_id`)
     DeathFromDisease(String),
     /// When injury stage death chance is satisfied
     /// # Parameters
-    /// - Injury unique name
+    /// - Injury id (`get_id`)
     /// - Body part
     DeathFromInjury(String, BodyPart),
 
     /// When disease is spawned or scheduled
     /// # Parameters
-    /// - Unique disease name
+    /// - Unique disease id (`get_id`)
     DiseaseSpawned(String),
     /// When disease is removed
     /// # Parameters
-    /// - Unique disease name
-    DiseaseRemoved(String),
+    /// - Unique disease id (`get_id`)
+    /// - Why disease was removed
+    /// - The most serious stage level it has reached, if it was active
+    DiseaseRemoved(String, RemovalReason, Option<StageLevel>),
     /// When disease starts self-healing process
     /// # Parameters
-    /// - Unique disease name
+    /// - Unique disease id (`get_id`)
     DiseaseSelfHealStarted(String),
     /// When disease chain is inverted
     /// # Parameters
-    /// - Unique disease name
+    /// - Unique disease id (`get_id`)
     DiseaseInverted(String),
     /// When disease chain is inverted back
     /// # Parameters
-    /// - Unique disease name
+    /// - Unique disease id (`get_id`)
     DiseaseResumed(String),
+    /// When healing disease stopped progressing because its healing conditions are not met
+    /// # Parameters
+    /// - Unique disease id (`get_id`)
+    DiseaseHealingPaused(String),
+    /// When healing disease continues, because its healing conditions are met again
+    /// # Parameters
+    /// - Unique disease id (`get_id`)
+    DiseaseHealingResumed(String),
+    /// When disease was shifted one stage level down
+    /// # Parameters
+    /// - Unique disease id (`get_id`)
+    /// - New stage level
+    DiseaseDowngraded(String, StageLevel),
     /// When disease passed its lifetime
     /// # Parameters
-    /// - Unique disease name
+    /// - Unique disease id (`get_id`)
     DiseaseExpired(String),
+    /// When the worst (last) disease stage reached 100% of its activity
+    /// # Parameters
+    /// - Unique disease id (`get_id`)
+    /// - Worst stage level
+    DiseaseReachedPeak(String, StageLevel),
+    /// When enough prophylaxis doses prevented a latent disease
+    /// # Parameters
+    /// - Unique disease id (`get_id`)
+    LatentDiseasePrevented(String),
+    /// When prophylaxis dose was taken after the latent disease point of no return
+    /// # Parameters
+    /// - Unique disease id (`get_id`)
+    LatentDiseaseTreatmentFailed(String),
+    /// When eating a spoiling item caused food poisoning
+    /// # Parameters
+    /// - Consumed item name
+    /// - Unique id of the spawned disease (`get_id`)
+    FoodPoisoned(String, String),
+    /// When character drinks visibly bad (murky or smelly) water
+    /// # Parameters
+    /// - Consumed item name
+    /// - Water contamination (0..100)
+    BadWaterDrunk(String, usize),
 
     /// When injury is spawned or scheduled
     /// # Parameters
-    /// - Unique injury name
+    /// - Unique injury id (`get_id`)
     /// - Body part
     InjurySpawned(String, BodyPart),
     /// When injury is removed
     /// # Parameters
-    /// - Unique injury name
+    /// - Unique injury id (`get_id`)
     /// - Body part
-    InjuryRemoved(String, BodyPart),
+    /// - Why injury was removed
+    /// - The most serious stage level it has reached, if it was active
+    InjuryRemoved(String, BodyPart, RemovalReason, Option<StageLevel>),
     /// When injury starts self-healing process
     /// # Parameters
-    /// - Unique injury name
+    /// - Unique injury id (`get_id`)
     /// - Body part
     InjurySelfHealStarted(String, BodyPart),
+    /// When character got burned by `ZaraController.burn`
+    /// # Parameters
+    /// - Burned body part
+    /// - Burned area of the body part (0..100 percents)
+    /// - What caused the burn
+    Burned(BodyPart, usize, BurnSource),
     /// When injury chain is inverted
     /// # Parameters
-    /// - Unique injury name
+    /// - Unique injury id (`get_id`)
     /// - Body part
     InjuryInverted(String, BodyPart),
     /// When injury chain is inverted back
     /// # Parameters
-    /// - Unique injury name
+    /// - Unique injury id (`get_id`)
     /// - Body part
     InjuryResumed(String, BodyPart),
+    /// When healing injury stopped progressing because its healing conditions are not met
+    /// # Parameters
+    /// - Unique injury id (`get_id`)
+    /// - Body part
+    InjuryHealingPaused(String, BodyPart),
+    /// When healing injury continues, because its healing conditions are met again
+    /// # Parameters
+    /// - Unique injury id (`get_id`)
+    /// - Body part
+    InjuryHealingResumed(String, BodyPart),
+    /// When injury was shifted one stage level down
+    /// # Parameters
+    /// - Unique injury id (`get_id`)
+    /// - Body part
+    /// - New stage level
+    InjuryDowngraded(String, BodyPart, StageLevel),
+    /// When a procedure step was started
+    /// # Parameters
+    /// - Procedure name
+    /// - Step name
+    ProcedureStepStarted(String, String),
+    /// When a procedure step was performed successfully
+    /// # Parameters
+    /// - Procedure name
+    /// - Step name
+    ProcedureStepCompleted(String, String),
+    /// When a procedure step failed and must be performed again
+    /// # Parameters
+    /// - Procedure name
+    /// - Step name
+    ProcedureStepFailed(String, String),
+    /// When the last procedure step was performed and injury started healing
+    /// # Parameters
+    /// - Procedure name
+    /// - Unique injury id (`get_id`)
+    /// - Body part
+    ProcedureCompleted(String, String, BodyPart),
     /// When injury passed its lifetime
     /// # Parameters
-    /// - Unique injury name
+    /// - Unique injury id (`get_id`)
     /// - Body part
     InjuryExpired(String, BodyPart),
+    /// When the worst (last) injury stage reached 100% of its activity
+    /// # Parameters
+    /// - Unique injury id (`get_id`)
+    /// - Body part
+    InjuryReachedPeak(String, BodyPart),
     /// When injury blood loss forcibly stopped
     /// # Parameters
-    /// - Unique injury name
+    /// - Unique injury id (`get_id`)
     /// - Body part
     BloodLossStopped(String, BodyPart),
     /// When injury blood loss forcibly resumed
     /// # Parameters
-    /// - Unique injury name
+    /// - Unique injury id (`get_id`)
     /// - Body part
     BloodLossResumed(String, BodyPart),
 
@@ -165,12 +312,16 @@ pub enum Event {
 
     /// When inventory item is added
     /// # Parameters
-    /// - Item unique name
-    InventoryItemAdded(String),
+    /// - Item id (`get_id`)
+    /// - Count of items of this kind in the inventory now
+    /// - Total weight of items of this kind now (grams)
+    /// - Why item was added
+    InventoryItemAdded(String, usize, f32, InventoryChangeReason),
     /// When inventory item is removed
     /// # Parameters
-    /// - Item unique name
-    InventoryItemRemoved(String),
+    /// - Item id (`get_id`)
+    /// - Why item was removed
+    InventoryItemRemoved(String, InventoryChangeReason),
     /// When inventory crafting combination successfully executed
     /// # Parameters
     /// - Combination unique key
@@ -179,17 +330,43 @@ pub enum Event {
     /// # Parameters
     /// - Old weight value (grams)
     /// - New weight value (grams)
-    InventoryWeightChanged(f32, f32),
+    /// - What changed the weight
+    InventoryWeightChanged(f32, f32, InventoryChangeReason),
     /// When inventory item is used (wasted) completely and removed from the inventory
     /// # Parameters
-    /// - Unique item name
+    /// - Unique item id (`get_id`)
     /// - Amount of items of this kind used
-    InventoryItemUsedAll(String, usize),
+    /// - Why item was used
+    InventoryItemUsedAll(String, usize, InventoryChangeReason),
     /// When inventory item is used (wasted) partially
     /// # Parameters
-    /// - Unique item name
+    /// - Unique item id (`get_id`)
     /// - Amount of items of this kind used
-    InventoryItemUsedPartially(String, usize),
+    /// - Count of items of this kind left
+    /// - Total weight of items of this kind left (grams)
+    /// - Why item was used
+    InventoryItemUsedPartially(String, usize, usize, f32, InventoryChangeReason),
+    /// When a tool was used successfully
+    /// # Parameters
+    /// - Unique item id (`get_id`)
+    ToolUsed(String),
+    /// When weather made a tool fail
+    /// # Parameters
+    /// - Unique item id (`get_id`)
+    /// - What made it fail
+    ToolFailed(String, ToolFailureCause),
+    /// When rain or water put out a light
+    /// # Parameters
+    /// - Unique item id (`get_id`)
+    LightExtinguished(String),
+    /// When a light used up its fuel and went out
+    /// # Parameters
+    /// - Unique item id (`get_id`)
+    LightBurnedOut(String),
+    /// When an electronic device ran out of charge and turned off
+    /// # Parameters
+    /// - Unique item id (`get_id`)
+    ChargeDepleted(String),
 
     /// When blood pressure is too high
     HighBloodPressureDanger,
@@ -204,17 +381,132 @@ pub enum Event {
     /// When body temperature is too low
     LowBodyTemperatureDanger,
     /// When character forcibly declared dead
-    DeclaredDead
+    DeclaredDead,
+    /// When dead character is revived
+    Revived,
+    /// When character vomits
+    /// # Parameters
+    /// - Food level lost (0..100)
+    /// - Water level lost (0..100)
+    /// - Ids of medical agents which doses were cancelled
+    Vomited(f32, f32, Vec<String>),
+
+    /// When skill reaches a new level
+    /// # Parameters
+    /// - Skill unique name
+    /// - New skill level
+    SkillLevelUp(String, usize),
+    /// When long-term condition is started
+    /// # Parameters
+    /// - Unique condition name
+    ConditionStarted(String),
+    /// When long-term condition is ended or is over
+    /// # Parameters
+    /// - Unique condition name
+    ConditionEnded(String),
+    /// When game day is over
+    /// # Parameters
+    /// - Aggregates of the day that passed
+    DayPassed(DaySummaryC),
+    /// When weather simulation changed the weather
+    /// # Parameters
+    /// - New weather
+    WeatherChanged(EnvironmentC),
+    /// When weather simulation started a rain
+    RainStarted,
+    /// When weather simulation stopped the rain
+    RainStopped,
+    /// When barter with another character was completed
+    /// # Parameters
+    /// - Items this character gave
+    /// - Items this character received
+    BarterCompleted(Vec<BarterItemC>, Vec<BarterItemC>)
 }
 impl fmt::Display for Event {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?}", self)
     }
 }
+impl Event {
+    /// Returns how important this event is
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::utils::event::EventSeverity;
+    ///
+    /// if event.severity() >= EventSeverity::Danger {
+    ///     // Play alarm sound
+    /// }
+    /// ```
+    pub fn severity(&self) -> EventSeverity {
+        match self {
+            Event::DeathFromDisease(_) | Event::DeathFromInjury(_, _) | Event::DeclaredDead |
+            Event::BodyPartLost(_) | Event::BloodDrained | Event::OxygenDrained => EventSeverity::Critical,
+
+            Event::FoodDrained | Event::WaterDrained | Event::Exhausted |
+            Event::TourniquetSafeTimeExceeded(_, _) |
+            Event::HighBloodPressureDanger | Event::LowBloodPressureDanger |
+            Event::HighHeartRateDanger | Event::LowHeartRateDanger |
+            Event::HighBodyTemperatureDanger | Event::LowBodyTemperatureDanger => EventSeverity::Danger,
+
+            Event::StaminaDrained | Event::Tired | Event::TourniquetTimeRunningOut(_, _, _) |
+            Event::DiseaseSpawned(_) | Event::DiseaseResumed(_) | Event::LatentDiseaseTreatmentFailed(_) |
+            Event::DiseaseReachedPeak(_, _) | Event::InjuryReachedPeak(_, _) |
+            Event::FoodPoisoned(_, _) | Event::BadWaterDrunk(_, _) |
+            Event::InjurySpawned(_, _) | Event::InjuryResumed(_, _) | Event::Burned(_, _, _) |
+            Event::BloodLossResumed(_, _) | Event::ConditionStarted(_) |
+            Event::Vomited(_, _, _) | Event::StimulantCrashed(_, _) |
+            Event::ProcedureStepFailed(_, _) => EventSeverity::Warning,
+
+            Event::HungerUrgencyChanged(u) | Event::ThirstUrgencyChanged(u)
+                if *u >= Urgency::Strong => EventSeverity::Warning,
+
+            Event::HypothermiaStageChanged(Some(l)) | Event::HyperthermiaStageChanged(Some(l))
+                if *l == StageLevel::Critical => EventSeverity::Danger,
+            Event::HypothermiaStageChanged(Some(l)) | Event::HyperthermiaStageChanged(Some(l))
+                if *l >= StageLevel::Progressing => EventSeverity::Warning,
+
+            Event::NutrientDeficiency(_) => EventSeverity::Warning,
+
+            Event::ToxicityStageChanged(ToxicityStage::Severe) => EventSeverity::Danger,
+            Event::ToxicityStageChanged(ToxicityStage::Mild) => EventSeverity::Warning,
+
+            _ => EventSeverity::Info
+        }
+    }
+}
+
+/// How important an event is. Levels are ordered from the least to the most important
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
+pub enum EventSeverity {
+    /// Regular happenings, like inventory changes or medical agents activation
+    #[default]
+    Info,
+    /// Something the player should notice, like a new disease or getting tired
+    Warning,
+    /// Something that threatens the character, like dangerous vitals
+    Danger,
+    /// Death or a loss that cannot be undone
+    Critical
+}
+impl fmt::Display for EventSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
 
 /// Zara game events listener trait
 pub trait Listener {
     fn notify(&mut self, event: &Event);
+
+    /// Events less important than this level are not sent to this listener. All events are
+    /// sent by default
+    ///
+    /// # Examples
+    /// ```
+    /// fn min_severity(&self) -> EventSeverity { EventSeverity::Warning }
+    /// ```
+    fn min_severity(&self) -> EventSeverity { EventSeverity::Info }
 }
 
 /// Zara game events dispatcher trait
@@ -229,7 +521,11 @@ pub struct Dispatcher<T>
     where T: Listener
 {
     /// A list of synchronous weak refs to listeners
-    listeners: Vec<Weak<RefCell<T>>>
+    listeners: Vec<Weak<RefCell<T>>>,
+    /// Dispatched events that were not delivered to listeners yet
+    pending: Vec<Event>,
+    /// Events less important than this are dropped on dispatch
+    min_severity: EventSeverity
 }
 
 impl<T> Dispatchable<T> for Dispatcher<T>
@@ -253,7 +549,32 @@ impl<T> Dispatcher<T>
     /// let o = utils::Dispatcher::new();
     /// ```
     pub fn new() -> Dispatcher<T> {
-        Dispatcher { listeners: Vec::new() }
+        Dispatcher { listeners: Vec::new(), pending: Vec::new(), min_severity: EventSeverity::Info }
+    }
+
+    /// Sets minimum severity of the events to dispatch. Less important events are dropped
+    ///
+    /// # Parameters
+    /// - `severity`: minimum event severity
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::utils::event::EventSeverity;
+    ///
+    /// dispatcher.set_min_severity(EventSeverity::Warning);
+    /// ```
+    pub fn set_min_severity(&mut self, severity: EventSeverity) {
+        self.min_severity = severity;
+    }
+
+    /// Returns minimum severity of the events to dispatch
+    ///
+    /// # Examples
+    /// ```
+    /// let value = dispatcher.min_severity();
+    /// ```
+    pub fn min_severity(&self) -> EventSeverity {
+        self.min_severity
     }
 
     /// Returns count of active listeners
@@ -266,19 +587,53 @@ impl<T> Dispatcher<T>
         self.listeners.len()
     }
 
-    /// Dispatches a message to all active listeners
+    /// Queues a message for all active listeners. Queued messages are sent by
+    /// [`deliver`](#method.deliver)
+    ///
+    /// Zara controller delivers its events right after the current update or action is done,
+    /// when nothing is borrowed anymore. So listeners can safely call back into the controller
     /// 
     /// # Examples
     /// ```
     /// dispatcher.dispatch(event);
     /// ```
     pub fn dispatch(&mut self, event: Event) {
+        if event.severity() < self.min_severity { return; }
+
+        self.pending.push(event);
+    }
+
+    /// Sends all queued messages to all active listeners
+    ///
+    /// # Examples
+    /// ```
+    /// dispatcher.deliver();
+    /// ```
+    pub fn deliver(&mut self) {
+        for event in self.take_pending() {
+            for l in self.live_listeners() {
+                let mut l = l.borrow_mut();
+
+                if event.severity() < l.min_severity() { continue; }
+
+                l.notify(&event);
+            }
+        }
+    }
+
+    /// Takes all queued messages out of the dispatcher
+    pub(crate) fn take_pending(&mut self) -> Vec<Event> {
+        self.pending.drain(..).collect()
+    }
+
+    /// Returns strong refs to all active listeners, cleaning up dropped ones
+    pub(crate) fn live_listeners(&mut self) -> Vec<Arc<RefCell<T>>> {
         let mut cleanup = false;
-        // Call the listeners
+        let mut result = Vec::new();
+
         for l in self.listeners.iter() {
             if let Some(listener_rc) = l.upgrade() {
-                let mut listener = listener_rc.borrow_mut();
-                listener.notify(&event);
+                result.push(listener_rc);
             } else {
                 println!("Cannot get listener, cleanup necessary");
                 cleanup = true;
@@ -296,5 +651,7 @@ impl<T> Dispatcher<T>
                 }
             });
         }
+
+        result
     }
 }
\ No newline at end of file
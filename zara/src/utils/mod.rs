@@ -1,8 +1,14 @@
 use crate::health::StageLevel;
 use crate::body::{BodyPart, BodyAppliance};
+use crate::body::tourniquet::Tourniquet;
+use crate::body::dressing::BurnDressing;
+use crate::player::{CharacterProfile, Activity};
 
 use std::time::{Duration};
 use std::cell::Cell;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::rc::Rc;
 use rand::Rng;
 
 use event::{Dispatcher, Listener};
@@ -23,6 +29,7 @@ pub struct FrameC<'a, E: Listener + 'static> {
 }
 
 /// Structure for storing frame meta info
+#[derive(Clone)]
 pub struct FrameSummaryC {
     /// Game time snapshot for this frame
     pub game_time: GameTimeC,
@@ -34,6 +41,80 @@ pub struct FrameSummaryC {
     pub health: HealthC,
     /// How many game seconds passed since last call
     pub game_time_delta: f32,
+    /// Character profile
+    pub profile: CharacterProfile,
+    /// Game-specific data set by [`set_frame_extension`](crate::ZaraController::set_frame_extension)
+    pub extensions: FrameExtensionsC
+}
+
+/// Typed collection of the game-specific frame data (like altitude, terrain or local
+/// temperature), one value per type. Lets custom monitors and side effects read host data
+///
+/// # Examples
+/// ```
+/// struct Terrain { altitude: f32 }
+///
+/// person.set_frame_extension(Terrain { altitude: 2500. });
+///
+/// // In a custom side effect
+/// if let Some(terrain) = frame_data.extensions.get::<Terrain>() {
+///     // ...
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct FrameExtensionsC {
+    values: HashMap<TypeId, Rc<dyn Any>>
+}
+impl FrameExtensionsC {
+    /// Returns value of a given type, or `None` if it was not set
+    ///
+    /// # Examples
+    /// ```
+    /// let terrain = frame_data.extensions.get::<Terrain>();
+    /// ```
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.values.get(&TypeId::of::<T>())?.downcast_ref::<T>()
+    }
+
+    /// Returns `true` if value of a given type is set
+    ///
+    /// # Examples
+    /// ```
+    /// let value = frame_data.extensions.contains::<Terrain>();
+    /// ```
+    pub fn contains<T: Any>(&self) -> bool { self.values.contains_key(&TypeId::of::<T>()) }
+
+    /// Returns how many values are set
+    pub fn len(&self) -> usize { self.values.len() }
+
+    /// Returns `true` if no values are set
+    pub fn is_empty(&self) -> bool { self.values.is_empty() }
+
+    /// Sets value of a given type for this frame only, replacing the old one. To pass a value
+    /// to every frame, use [`set_frame_extension`](crate::ZaraController::set_frame_extension)
+    ///
+    /// # Parameters
+    /// - `value`: data to pass
+    ///
+    /// # Examples
+    /// ```
+    /// frame_data.extensions.insert(Terrain { altitude: 2500. });
+    /// ```
+    pub fn insert<T: Any>(&mut self, value: T) {
+        self.values.insert(TypeId::of::<T>(), Rc::new(value));
+    }
+
+    /// Removes value of a given type for this frame only. Returns `false` if it was not set
+    ///
+    /// # Examples
+    /// ```
+    /// frame_data.extensions.remove::<Terrain>();
+    /// ```
+    pub fn remove<T: Any>(&mut self) -> bool {
+        self.values.remove(&TypeId::of::<T>()).is_some()
+    }
+
+    pub(crate) fn clear(&mut self) { self.values.clear(); }
 }
 
 /// Structure that holds game time.
@@ -395,9 +476,12 @@ impl fmt::Display for ClothesGroupC {
 }
 
 /// Structure for storing health snapshot
+#[derive(Clone)]
 pub struct HealthC {
-    /// Body temperature (degrees C)
+    /// Core body temperature (degrees C). Drives hypo- and hyperthermia thresholds
     pub body_temperature: f32,
+    /// Average temperature of the extremities (degrees C)
+    pub peripheral_temperature: f32,
     /// Heart rate (bpm)
     pub heart_rate: f32,
     /// Top body pressure (mmHg)
@@ -412,10 +496,12 @@ pub struct HealthC {
     pub water_level: f32,
     /// Stamina level (0..100)
     pub stamina_level: f32,
-    /// Fatigue level (0..100)
+    /// Fatigue level (0..100), without the stimulants mask
     pub fatigue_level: f32,
     /// Oxygen level (0..100)
     pub oxygen_level: f32,
+    /// Toxicity level (0..100)
+    pub toxicity_level: f32,
     /// List of active (or scheduled) diseases
     pub diseases: Vec<ActiveDiseaseC>,
     /// List of active (or scheduled) injuries
@@ -435,6 +521,7 @@ impl HealthC {
         HealthC {
             blood_level: 100.,
             body_temperature: 36.6,
+            peripheral_temperature: 34.1,
             top_pressure: 120.,
             bottom_pressure: 70.,
             food_level: 100.,
@@ -443,6 +530,7 @@ impl HealthC {
             stamina_level: 100.,
             fatigue_level: 0.,
             oxygen_level: 100.,
+            toxicity_level: 0.,
             diseases: Vec::new(),
             injuries: Vec::new()
         }
@@ -452,7 +540,9 @@ impl HealthC {
 /// Structure for storing active disease simplified contract
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
 pub struct ActiveDiseaseC {
-    /// Name of a disease
+    /// Unique id of a disease
+    pub id: String,
+    /// Display name (or localization key) of a disease
     pub name: String,
     /// Time when this disease becomes active
     pub scheduled_time: GameTimeC,
@@ -478,7 +568,9 @@ impl fmt::Display for ActiveDiseaseC {
 /// Structure for storing active injury simplified contract
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
 pub struct ActiveInjuryC {
-    /// Name of the injury
+    /// Unique id of the injury
+    pub id: String,
+    /// Display name (or localization key) of the injury
     pub name: String,
     /// Time when this injury becomes active
     pub scheduled_time: GameTimeC,
@@ -499,7 +591,9 @@ pub struct ActiveInjuryC {
     /// Body part where this injury resides
     pub body_part: BodyPart,
     /// Is this injury a fracture
-    pub is_fracture: bool
+    pub is_fracture: bool,
+    /// Burned area of the body part (0..100 percents) if this injury is a burn
+    pub burn_area: Option<usize>
 }
 impl fmt::Display for ActiveInjuryC {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -519,11 +613,15 @@ pub struct EnvironmentC {
     /// Temperature, degrees C
     pub temperature : f32,
     /// Rain intensity, 0..1
-    pub rain_intensity : f32
+    pub rain_intensity : f32,
+    /// Relative air humidity, 0..1. `0` means dry air: humidity does not make cold feel
+    /// colder and does not slow down drying
+    pub humidity: f32
 }
 impl fmt::Display for EnvironmentC {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "World: temp {:.1}C, wind {:.1} m/s, rain {:.1}", self.temperature, self.wind_speed, self.rain_intensity)
+        write!(f, "World: temp {:.1}C, wind {:.1} m/s, rain {:.1}, humidity {:.1}", self.temperature, self.wind_speed,
+               self.rain_intensity, self.humidity)
     }
 }
 impl Eq for EnvironmentC { }
@@ -533,7 +631,8 @@ impl PartialEq for EnvironmentC {
 
         f32::abs(self.wind_speed - other.wind_speed) < EPS &&
         f32::abs(self.temperature - other.temperature) < EPS &&
-        f32::abs(self.rain_intensity - other.rain_intensity) < EPS
+        f32::abs(self.rain_intensity - other.rain_intensity) < EPS &&
+        f32::abs(self.humidity - other.humidity) < EPS
     }
 }
 impl Hash for EnvironmentC {
@@ -541,6 +640,7 @@ impl Hash for EnvironmentC {
         state.write_i32((self.temperature*10_000_f32) as i32);
         state.write_u32((self.wind_speed*10_000_f32) as u32);
         state.write_u32((self.rain_intensity*10_000_f32) as u32);
+        state.write_u32((self.humidity*10_000_f32) as u32);
     }
 }
 impl EnvironmentC {
@@ -569,10 +669,27 @@ impl EnvironmentC {
         EnvironmentC {
             wind_speed,
             temperature,
-            rain_intensity
+            rain_intensity,
+            humidity: 0.
         }
     }
 
+    /// Sets relative air humidity of this environment description
+    ///
+    /// # Parameters
+    /// - `humidity`: relative air humidity, 0..1
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::utils;
+    ///
+    /// let env = utils::EnvironmentC::new(3., 5., 0.).with_humidity(0.9);
+    /// ```
+    pub fn with_humidity(mut self, humidity: f32) -> Self {
+        self.humidity = humidity;
+        self
+    }
+
     /// Creates default environment description object.
     ///
     /// To create environment description with default values (26 degrees C, no rain, no wind),
@@ -592,6 +709,59 @@ impl EnvironmentC {
     pub fn default() -> EnvironmentC { EnvironmentC::new(26., 0., 0.) }
 }
 
+/// Distance covered by the character, split by movement kind
+#[derive(Copy, Clone, Debug, Default)]
+pub struct MovementC {
+    /// Distance walked, meters
+    pub meters_walked: f32,
+    /// Distance run, meters
+    pub meters_run: f32,
+    /// Distance swum, meters
+    pub meters_swum: f32
+}
+impl fmt::Display for MovementC {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Movement: walked {:.1}m, run {:.1}m, swum {:.1}m", self.meters_walked, self.meters_run,
+               self.meters_swum)
+    }
+}
+impl Ord for MovementC {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total().partial_cmp(&other.total()).unwrap_or(Ordering::Equal)
+    }
+}
+impl Eq for MovementC { }
+impl PartialOrd for MovementC {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl PartialEq for MovementC {
+    fn eq(&self, other: &Self) -> bool {
+        const EPS: f32 = 0.0001;
+
+        f32::abs(self.meters_walked - other.meters_walked) < EPS &&
+        f32::abs(self.meters_run - other.meters_run) < EPS &&
+        f32::abs(self.meters_swum - other.meters_swum) < EPS
+    }
+}
+impl Hash for MovementC {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u32((self.meters_walked*10_000_f32) as u32);
+        state.write_u32((self.meters_run*10_000_f32) as u32);
+        state.write_u32((self.meters_swum*10_000_f32) as u32);
+    }
+}
+impl MovementC {
+    /// Total distance covered, meters
+    ///
+    /// # Examples
+    /// ```
+    /// let meters = frame_data.player.movement.total();
+    /// ```
+    pub fn total(&self) -> f32 { self.meters_walked + self.meters_run + self.meters_swum }
+}
+
 /// Simplified player state for a single frame
 #[derive(Clone, Debug, Default)]
 pub struct PlayerStatusC {
@@ -603,6 +773,12 @@ pub struct PlayerStatusC {
     pub is_swimming: bool,
     /// Is player under the water now
     pub is_underwater: bool,
+    /// Is player doing some arm-intensive activity now
+    pub is_using_arms: bool,
+    /// Is player inside a shelter now
+    pub is_inside_shelter: bool,
+    /// How well the shelter protects from the rain and wind (0..1)
+    pub shelter_quality: f32,
     /// Is player sleeping now
     pub is_sleeping: bool,
     /// Last time player slept (if any)
@@ -617,6 +793,10 @@ pub struct PlayerStatusC {
     pub clothes: Vec<String>,
     /// Body appliances player is wearing now
     pub appliances: Vec<BodyAppliance>,
+    /// Tourniquets applied to limbs now
+    pub tourniquets: Vec<Tourniquet>,
+    /// Burn dressings applied to body parts now
+    pub burn_dressings: Vec<BurnDressing>,
     /// Current clothes group (is any)
     pub clothes_group: Option<ClothesGroupC>,
     /// Total calculated water resistance value (0..100)
@@ -624,7 +804,15 @@ pub struct PlayerStatusC {
     /// Total calculated cold resistance value (0..100)
     pub total_cold_resistance: usize,
     /// Player's current inventory weight
-    pub inventory_weight: f32
+    pub inventory_weight: f32,
+    /// Player's current mobility (0..1), lowered by lost body parts
+    pub mobility: f32,
+    /// Player's current heat stress level (0..100)
+    pub heat_stress: f32,
+    /// Player's current hygiene level (0..100)
+    pub hygiene_level: f32,
+    /// Distance reported by the game since the last update
+    pub movement: MovementC
 }
 impl fmt::Display for PlayerStatusC {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -641,17 +829,26 @@ impl PartialEq for PlayerStatusC {
         self.is_running == other.is_running &&
         self.is_swimming == other.is_swimming &&
         self.is_underwater == other.is_underwater &&
+        self.is_using_arms == other.is_using_arms &&
+        self.is_inside_shelter == other.is_inside_shelter &&
         self.is_sleeping == other.is_sleeping &&
         self.last_slept == other.last_slept &&
         self.clothes == other.clothes &&
         self.appliances == other.appliances &&
+        self.tourniquets == other.tourniquets &&
+        self.burn_dressings == other.burn_dressings &&
         self.clothes_group == other.clothes_group &&
         self.total_water_resistance == other.total_water_resistance &&
         self.total_cold_resistance == other.total_cold_resistance &&
+        self.movement == other.movement &&
         f32::abs(self.last_slept_duration - other.last_slept_duration) < EPS &&
         f32::abs(self.warmth_level - other.warmth_level) < EPS &&
         f32::abs(self.wetness_level - other.wetness_level) < EPS &&
-        f32::abs(self.inventory_weight - other.inventory_weight) < EPS
+        f32::abs(self.inventory_weight - other.inventory_weight) < EPS &&
+        f32::abs(self.mobility - other.mobility) < EPS &&
+        f32::abs(self.heat_stress - other.heat_stress) < EPS &&
+        f32::abs(self.hygiene_level - other.hygiene_level) < EPS &&
+        f32::abs(self.shelter_quality - other.shelter_quality) < EPS
     }
 }
 impl Hash for PlayerStatusC {
@@ -660,18 +857,109 @@ impl Hash for PlayerStatusC {
         self.is_running.hash(state);
         self.is_swimming.hash(state);
         self.is_underwater.hash(state);
+        self.is_using_arms.hash(state);
+        self.is_inside_shelter.hash(state);
         self.is_sleeping.hash(state);
         self.last_slept.hash(state);
         self.clothes.hash(state);
         self.appliances.hash(state);
+        self.tourniquets.hash(state);
+        self.burn_dressings.hash(state);
         self.clothes_group.hash(state);
         self.total_water_resistance.hash(state);
         self.total_cold_resistance.hash(state);
+        self.movement.hash(state);
 
         state.write_u32((self.last_slept_duration*10_000_f32) as u32);
         state.write_i32((self.warmth_level*10_000_f32) as i32);
         state.write_u32((self.wetness_level*10_000_f32) as u32);
         state.write_u32((self.inventory_weight*1_000_f32) as u32);
+        state.write_u32((self.mobility*10_000_f32) as u32);
+        state.write_u32((self.heat_stress*10_000_f32) as u32);
+        state.write_u32((self.hygiene_level*10_000_f32) as u32);
+        state.write_u32((self.shelter_quality*10_000_f32) as u32);
+    }
+}
+
+impl PlayerStatusC {
+    /// Returns how much of the rain and wind reaches the player (0..1). `1` outside of a
+    /// shelter, `1 - shelter_quality` inside of it
+    ///
+    /// # Examples
+    /// ```
+    /// let rain = frame_data.environment.rain_intensity * frame_data.player.shelter_exposure();
+    /// ```
+    pub fn shelter_exposure(&self) -> f32 {
+        if self.is_inside_shelter { 1. - clamp_01(self.shelter_quality) } else { 1. }
+    }
+
+    /// Checks if player is doing a given activity now
+    ///
+    /// # Parameters
+    /// - `activity`: activity to check
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::player::Activity;
+    ///
+    /// let value = frame_data.player.is_doing(Activity::Running);
+    /// ```
+    pub fn is_doing(&self, activity: Activity) -> bool {
+        match activity {
+            Activity::Walking => self.is_walking,
+            Activity::Running => self.is_running,
+            Activity::Swimming => self.is_swimming || self.is_underwater,
+            Activity::ArmsWork => self.is_using_arms
+        }
+    }
+
+    /// Checks if player uses a given body part now. Body parts that are not tied to any
+    /// activity (head, torso, custom parts) are always considered used
+    ///
+    /// # Parameters
+    /// - `body_part`: body part to check
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::body::BodyPart;
+    ///
+    /// let value = frame_data.player.is_using(BodyPart::LeftShin);
+    /// ```
+    pub fn is_using(&self, body_part: BodyPart) -> bool {
+        let activities = body_part.activities();
+
+        activities.is_empty() || activities.iter().any(|a| self.is_doing(*a))
+    }
+
+    /// Checks if a given limb has a tourniquet on it
+    ///
+    /// # Parameters
+    /// - `body_part`: body part to check
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::body::BodyPart;
+    ///
+    /// let value = frame_data.player.has_tourniquet_on(BodyPart::LeftForearm);
+    /// ```
+    pub fn has_tourniquet_on(&self, body_part: BodyPart) -> bool {
+        self.tourniquets.iter().any(|t| t.body_part == body_part)
+    }
+
+    /// Returns how much of the burn pain (0..100 scale) a dressing on a given body part relieves.
+    /// 0 if there is no burn dressing on it
+    ///
+    /// # Parameters
+    /// - `body_part`: body part to check
+    ///
+    /// # Examples
+    /// ```
+    /// use zara::body::BodyPart;
+    ///
+    /// let value = frame_data.player.burn_dressing_relief(BodyPart::LeftForearm);
+    /// ```
+    pub fn burn_dressing_relief(&self, body_part: BodyPart) -> usize {
+        self.burn_dressings.iter().find(|d| d.body_part == body_part).map_or(0, |d| d.relief)
     }
 }
 
@@ -777,4 +1065,43 @@ pub fn range(a: f32, b: f32) -> f32 {
     let mut rng = rand::thread_rng();
 
     rng.gen_range(a..b)
-}
\ No newline at end of file
+}
+
+/// Execution order of the registered monitors. Monitors with higher priority run first,
+/// monitors with equal priority run in the order of registration
+#[derive(Default)]
+pub(crate) struct MonitorPriorities {
+    values: std::cell::RefCell<HashMap<usize, i32>>,
+    last_key: std::cell::Cell<usize>
+}
+impl MonitorPriorities {
+    /// Returns a new unique monitor key. Keys are never reused, so they keep the order of registration
+    pub(crate) fn next_key(&self) -> usize {
+        let key = self.last_key.get() + 1;
+
+        self.last_key.set(key);
+
+        key
+    }
+
+    pub(crate) fn set(&self, key: usize, priority: i32) {
+        self.values.borrow_mut().insert(key, priority);
+    }
+
+    pub(crate) fn get(&self, key: usize) -> i32 {
+        self.values.borrow().get(&key).copied().unwrap_or(0)
+    }
+
+    pub(crate) fn remove(&self, key: usize) {
+        self.values.borrow_mut().remove(&key);
+    }
+
+    /// Returns monitors with their keys in the execution order
+    pub(crate) fn ordered<'a, T: ?Sized>(&self, monitors: &'a HashMap<usize, Box<T>>) -> Vec<(usize, &'a T)> {
+        let mut keys: Vec<&usize> = monitors.keys().collect();
+
+        keys.sort_by_key(|k| (std::cmp::Reverse(self.get(**k)), **k));
+
+        keys.iter().filter_map(|k| monitors.get(*k).map(|m| (**k, m.as_ref()))).collect()
+    }
+}
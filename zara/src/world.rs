@@ -1,10 +1,45 @@
-use crate::utils::{GameTime, EnvironmentC};
+use crate::utils::{GameTime, GameTimeC, EnvironmentC};
+use crate::utils::event::Event;
+use crate::state::DirtyTracked;
 
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 
+/// Game hour when daylight starts by default
+const DEFAULT_SUNRISE_HOUR: u64 = 6;
+/// Game hour when daylight ends by default
+const DEFAULT_SUNSET_HOUR: u64 = 20;
+
+/// Weather simulation that evolves temperature, wind and rain over the game time (fronts,
+/// storms, gradual transitions). Is called by Zara on every `update` when set with
+/// [`set_weather_controller`](EnvironmentData::set_weather_controller)
+///
+/// # Examples
+/// ```
+/// use zara::world::WeatherController;
+///
+/// struct Storm;
+/// impl WeatherController for Storm {
+///     fn update(&mut self, game_time: &GameTimeC, game_time_delta: f32, current: &EnvironmentC) -> EnvironmentC {
+///         EnvironmentC::new(current.temperature, 12., 0.8)
+///     }
+/// }
+/// ```
+///
+/// # Links
+/// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Environment) for more info.
+pub trait WeatherController {
+    /// Returns weather for the given game time
+    ///
+    /// # Parameters
+    /// - `game_time`: current game time
+    /// - `game_time_delta`: game seconds passed since the last call
+    /// - `current`: current weather
+    fn update(&mut self, game_time: &GameTimeC, game_time_delta: f32, current: &EnvironmentC) -> EnvironmentC;
+}
+
 /// Contains runtime environment data and game time
 #[derive(Clone, Default)]
 pub struct EnvironmentData {
@@ -15,12 +50,24 @@ pub struct EnvironmentData {
     /// Temperature, degrees C
     pub temperature: Cell<f32>,
     /// Rain intensity, 0..1
-    pub rain_intensity: Cell<f32>
+    pub rain_intensity: Cell<f32>,
+    /// Relative air humidity, 0..1
+    pub humidity: Cell<f32>,
+    /// Game hour when daylight starts (0..23)
+    pub sunrise_hour: Cell<u64>,
+    /// Game hour when daylight ends (0..23)
+    pub sunset_hour: Cell<u64>,
+
+    // Private fields
+    /// Weather simulation. Clones of this `EnvironmentData` share it
+    weather_controller: Rc<RefCell<Option<Box<dyn WeatherController>>>>,
+    /// Is node changed since the last state snapshot
+    is_dirty: Cell<bool>
 }
 impl fmt::Display for EnvironmentData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "World: {}, temp {:.1}C, wind {:.1} m/s, rain {:.1}", self.game_time,
-               self.temperature.get(), self.wind_speed.get(), self.rain_intensity.get())
+        write!(f, "World: {}, temp {:.1}C, wind {:.1} m/s, rain {:.1}, humidity {:.1}", self.game_time,
+               self.temperature.get(), self.wind_speed.get(), self.rain_intensity.get(), self.humidity.get())
     }
 }
 impl Eq for EnvironmentData { }
@@ -31,18 +78,27 @@ impl PartialEq for EnvironmentData {
         self.game_time.to_contract() == other.game_time.to_contract() &&
         f32::abs(self.temperature.get() - other.temperature.get()) < EPS &&
         f32::abs(self.wind_speed.get() - other.wind_speed.get()) < EPS &&
-        f32::abs(self.rain_intensity.get() - other.rain_intensity.get()) < EPS
+        self.sunrise_hour.get() == other.sunrise_hour.get() &&
+        self.sunset_hour.get() == other.sunset_hour.get() &&
+        f32::abs(self.rain_intensity.get() - other.rain_intensity.get()) < EPS &&
+        f32::abs(self.humidity.get() - other.humidity.get()) < EPS
     }
 }
 impl Hash for EnvironmentData {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.game_time.to_contract().hash(state);
+        self.sunrise_hour.get().hash(state);
+        self.sunset_hour.get().hash(state);
 
         state.write_i32((self.temperature.get()*10_000_f32) as i32);
         state.write_u32((self.wind_speed.get()*10_000_f32) as u32);
         state.write_u32((self.rain_intensity.get()*10_000_f32) as u32);
+        state.write_u32((self.humidity.get()*10_000_f32) as u32);
     }
 }
+impl DirtyTracked for EnvironmentData {
+    fn dirty_flag(&self) -> &Cell<bool> { &self.is_dirty }
+}
 impl EnvironmentData {
     /// Creates new `EnvironmentData`.
     ///
@@ -63,7 +119,12 @@ impl EnvironmentData {
             game_time: Rc::new(GameTime::new()),
             wind_speed : Cell::new(0.),
             rain_intensity: Cell::new(0.),
-            temperature: Cell::new(0.)
+            humidity: Cell::new(0.),
+            temperature: Cell::new(0.),
+            sunrise_hour: Cell::new(DEFAULT_SUNRISE_HOUR),
+            sunset_hour: Cell::new(DEFAULT_SUNSET_HOUR),
+            weather_controller: Rc::new(RefCell::new(None)),
+            is_dirty: Cell::new(false)
         }
     }
 
@@ -89,7 +150,83 @@ impl EnvironmentData {
         e.wind_speed.set(ed.wind_speed);
         e.temperature.set(ed.temperature);
         e.rain_intensity.set(ed.rain_intensity);
+        e.humidity.set(ed.humidity);
 
         e
     }
+    /// Checks if it is daylight now, based on the game time hour and `sunrise_hour`/`sunset_hour`
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.environment.is_daylight();
+    /// ```
+    ///
+    /// # Links
+    /// See [this wiki article](https://github.com/vagrod/zara-rust/wiki/Environment) for more info.
+    pub fn is_daylight(&self) -> bool {
+        let hour = self.game_time.hour.get();
+
+        hour >= self.sunrise_hour.get() && hour < self.sunset_hour.get()
+    }
+    /// Sets weather simulation that will change temperature, wind and rain on every `update`.
+    /// Replaces the previous one
+    ///
+    /// # Parameters
+    /// - `controller`: [`WeatherController`] instance
+    ///
+    /// # Examples
+    /// ```
+    /// person.environment.set_weather_controller(Box::new(Storm));
+    /// ```
+    pub fn set_weather_controller(&self, controller: Box<dyn WeatherController>) {
+        self.weather_controller.replace(Some(controller));
+    }
+    /// Removes weather simulation, so that the game controls weather again. Returns removed one
+    ///
+    /// # Examples
+    /// ```
+    /// person.environment.remove_weather_controller();
+    /// ```
+    pub fn remove_weather_controller(&self) -> Option<Box<dyn WeatherController>> {
+        self.weather_controller.replace(None)
+    }
+    /// Returns `true` if weather simulation is set
+    ///
+    /// # Examples
+    /// ```
+    /// let value = person.environment.has_weather_controller();
+    /// ```
+    pub fn has_weather_controller(&self) -> bool { self.weather_controller.borrow().is_some() }
+
+    /// Asks weather simulation for the new weather and applies it. Returns weather events
+    pub(crate) fn evolve_weather(&self, game_time_delta: f32) -> Vec<Event> {
+        const EPS: f32 = 0.0001;
+
+        let mut result = Vec::new();
+        let current = EnvironmentC::new(self.temperature.get(), self.wind_speed.get(), self.rain_intensity.get())
+            .with_humidity(self.humidity.get());
+        let next = match self.weather_controller.borrow_mut().as_mut() {
+            Some(c) => c.update(&self.game_time.to_contract(), game_time_delta, &current),
+            None => return result
+        };
+        let next = EnvironmentC::new(next.temperature, f32::max(next.wind_speed, 0.),
+                                     crate::utils::clamp_01(next.rain_intensity))
+            .with_humidity(crate::utils::clamp_01(next.humidity));
+
+        if next == current { return result; }
+
+        self.temperature.set(next.temperature);
+        self.wind_speed.set(next.wind_speed);
+        self.rain_intensity.set(next.rain_intensity);
+        self.humidity.set(next.humidity);
+
+        if current.rain_intensity < EPS && next.rain_intensity >= EPS {
+            result.push(Event::RainStarted);
+        } else if current.rain_intensity >= EPS && next.rain_intensity < EPS {
+            result.push(Event::RainStopped);
+        }
+        result.push(Event::WeatherChanged(next));
+
+        result
+    }
 }
\ No newline at end of file